@@ -4,18 +4,22 @@ use prelude::FluentBuilder as _;
 use serde::Deserialize;
 use std::{sync::Arc, time::Duration};
 use story::{
-    ButtonStory, CalendarStory, DropdownStory, IconStory, ImageStory, InputStory, ListStory,
-    ModalStory, PopupStory, ProgressStory, ResizableStory, ScrollableStory, StoryContainer,
-    SwitchStory, TableStory, TextStory, TooltipStory,
+    AlertStory, AvatarStory, BreadcrumbStory, ButtonStory, CalendarStory, CarouselStory,
+    ChartStory, CheckboxStory, DropdownStory, EmptyStory, EventCalendarStory, FormStory, IconStory,
+    ImageStory, InputStory, ListStory, MarkdownStory, ModalStory, PopupStory, ProgressStory,
+    RadioStory, RatingStory, ResizableStory, ScrollableStory, SidebarStory, StatisticStory,
+    StepsStory, StoryContainer, SwitchStory, TableStory, TabsStory, TagStory, TextStory,
+    TimePickerStory, TooltipStory, TransferStory, TreeStory,
 };
 use ui::{
     button::{Button, ButtonStyled as _},
     color_picker::{ColorPicker, ColorPickerEvent},
-    dock::{DockArea, DockEvent, DockItem, DockItemState},
+    dock::{DockArea, DockAreaState, DockEvent, DockItem, DockPlacement},
     h_flex,
+    notification::NotificationCenter,
     popup_menu::PopupMenuExt,
-    theme::{ActiveTheme, Colorize as _, Theme},
-    ContextModal, IconName, Root, Sizable,
+    theme::{ActiveTheme, Breakpoint, Theme},
+    ContextModal, IconName, Responsive as _, Root, Selectable, Sizable,
 };
 use workspace::TitleBar;
 
@@ -26,10 +30,18 @@ struct SelectLocale(SharedString);
 
 impl_actions!(locale_switcher, [SelectLocale]);
 
-actions!(workspace, [Open, CloseWindow]);
+actions!(workspace, [Open, CloseWindow, ZoomIn, ZoomOut, ResetZoom]);
 
 pub fn init(_app_state: Arc<AppState>, cx: &mut AppContext) {
     cx.on_action(|_action: &Open, _cx: &mut AppContext| {});
+    cx.on_action(|_: &ZoomIn, cx: &mut AppContext| Theme::set_scale(cx.theme().scale + 0.1, cx));
+    cx.on_action(|_: &ZoomOut, cx: &mut AppContext| Theme::set_scale(cx.theme().scale - 0.1, cx));
+    cx.on_action(|_: &ResetZoom, cx: &mut AppContext| Theme::set_scale(1., cx));
+    cx.bind_keys([
+        KeyBinding::new("cmd-=", ZoomIn, None),
+        KeyBinding::new("cmd--", ZoomOut, None),
+        KeyBinding::new("cmd-0", ResetZoom, None),
+    ]);
 
     ui::init(cx);
     story::init(cx);
@@ -39,29 +51,17 @@ pub struct StoryWorkspace {
     dock_area: View<DockArea>,
     locale_selector: View<LocaleSelector>,
     theme_color_picker: View<ColorPicker>,
-    last_layout_state: Option<DockItemState>,
+    last_layout_state: Option<DockAreaState>,
     _save_layout_task: Option<Task<()>>,
 }
 
 impl StoryWorkspace {
     pub fn new(_app_state: Arc<AppState>, cx: &mut ViewContext<Self>) -> Self {
-        cx.observe_window_appearance(|_workspace, cx| {
-            Theme::sync_system_appearance(cx);
-        })
-        .detach();
-
         let dock_area = cx.new_view(|cx| DockArea::new("main-dock", cx));
-        let weak_dock_area = dock_area.downgrade();
-
-        let dock_item = match Self::load_layout(&weak_dock_area, cx) {
-            Ok(item) => item,
-            Err(err) => {
-                eprintln!("load layout error: {:?}", err);
-                Self::init_default_layout(&weak_dock_area, cx)
-            }
-        };
-
-        dock_area.update(cx, |view, cx| view.set_root(dock_item, cx));
+        if let Err(err) = Self::load_layout(&dock_area, cx) {
+            eprintln!("load layout error: {:?}", err);
+            Self::init_default_layout(&dock_area, cx);
+        }
 
         cx.subscribe(&dock_area, |this, dock_area, ev: &DockEvent, cx| match ev {
             DockEvent::LayoutChanged => this.save_layout(dock_area, cx),
@@ -94,11 +94,7 @@ impl StoryWorkspace {
             |_, _, ev: &ColorPickerEvent, cx| match ev {
                 ColorPickerEvent::Change(color) => {
                     if let Some(color) = color {
-                        let theme = cx.global_mut::<Theme>();
-                        theme.primary = *color;
-                        theme.primary_hover = color.lighten(0.1);
-                        theme.primary_active = color.darken(0.1);
-                        cx.refresh();
+                        Theme::set_accent(Some(*color), cx);
                     }
                 }
             },
@@ -135,86 +131,106 @@ impl StoryWorkspace {
         }));
     }
 
-    fn save_state(state: &DockItemState) -> Result<()> {
+    fn save_state(state: &DockAreaState) -> Result<()> {
         println!("Save layout...");
         let json = serde_json::to_string_pretty(state)?;
         std::fs::write("layout.json", json)?;
         Ok(())
     }
 
-    fn load_layout(dock_area: &WeakView<DockArea>, cx: &mut WindowContext) -> Result<DockItem> {
+    fn load_layout(dock_area: &View<DockArea>, cx: &mut WindowContext) -> Result<()> {
         let fname = "layout.json";
         let json = std::fs::read_to_string(fname)?;
-        let state = serde_json::from_str::<DockItemState>(&json)?;
+        let state = serde_json::from_str::<DockAreaState>(&json)?;
+        state.load(dock_area, cx);
 
-        return Ok(state.to_item(dock_area.clone(), cx));
+        Ok(())
     }
 
-    fn init_default_layout(dock_area: &WeakView<DockArea>, cx: &mut WindowContext) -> DockItem {
-        DockItem::split_with_sizes(
+    fn init_default_layout(dock_area: &View<DockArea>, cx: &mut WindowContext) {
+        let weak_dock_area = dock_area.downgrade();
+        let dock_area_ref = &weak_dock_area;
+
+        let center = DockItem::split(
             Axis::Horizontal,
+            vec![DockItem::tabs(
+                vec![
+                    Arc::new(StoryContainer::panel::<ButtonStory>(cx)),
+                    Arc::new(StoryContainer::panel::<AvatarStory>(cx)),
+                    Arc::new(StoryContainer::panel::<AlertStory>(cx)),
+                    Arc::new(StoryContainer::panel::<EmptyStory>(cx)),
+                    Arc::new(StoryContainer::panel::<CarouselStory>(cx)),
+                    Arc::new(StoryContainer::panel::<InputStory>(cx)),
+                    Arc::new(StoryContainer::panel::<FormStory>(cx)),
+                    Arc::new(StoryContainer::panel::<DropdownStory>(cx)),
+                    Arc::new(StoryContainer::panel::<ModalStory>(cx)),
+                    Arc::new(StoryContainer::panel::<PopupStory>(cx)),
+                    Arc::new(StoryContainer::panel::<ListStory>(cx)),
+                    Arc::new(StoryContainer::panel::<MarkdownStory>(cx)),
+                    Arc::new(StoryContainer::panel::<TreeStory>(cx)),
+                    Arc::new(StoryContainer::panel::<TransferStory>(cx)),
+                    Arc::new(StoryContainer::panel::<SwitchStory>(cx)),
+                    Arc::new(StoryContainer::panel::<CheckboxStory>(cx)),
+                    Arc::new(StoryContainer::panel::<RadioStory>(cx)),
+                    Arc::new(StoryContainer::panel::<RatingStory>(cx)),
+                    Arc::new(StoryContainer::panel::<TimePickerStory>(cx)),
+                    Arc::new(StoryContainer::panel::<EventCalendarStory>(cx)),
+                    Arc::new(StoryContainer::panel::<TableStory>(cx)),
+                    Arc::new(StoryContainer::panel::<TabsStory>(cx)),
+                    Arc::new(StoryContainer::panel::<TagStory>(cx)),
+                    Arc::new(StoryContainer::panel::<BreadcrumbStory>(cx)),
+                    Arc::new(StoryContainer::panel::<StatisticStory>(cx)),
+                    Arc::new(StoryContainer::panel::<ChartStory>(cx)),
+                    Arc::new(StoryContainer::panel::<StepsStory>(cx)),
+                    Arc::new(StoryContainer::panel::<ResizableStory>(cx)),
+                    Arc::new(StoryContainer::panel::<ScrollableStory>(cx)),
+                    Arc::new(StoryContainer::panel::<SidebarStory>(cx)),
+                ],
+                None,
+                dock_area_ref,
+                cx,
+            )],
+            dock_area_ref,
+            cx,
+        );
+        let left = DockItem::split(
+            Axis::Vertical,
             vec![
-                DockItem::split(
-                    Axis::Vertical,
-                    vec![
-                        DockItem::tab(StoryContainer::panel::<IconStory>(cx), &dock_area, cx),
-                        DockItem::tab(StoryContainer::panel::<CalendarStory>(cx), &dock_area, cx),
-                    ],
-                    &dock_area,
-                    cx,
-                ),
-                DockItem::split_with_sizes(
-                    Axis::Vertical,
-                    vec![
-                        DockItem::tabs(
-                            vec![
-                                Arc::new(StoryContainer::panel::<ButtonStory>(cx)),
-                                Arc::new(StoryContainer::panel::<InputStory>(cx)),
-                                Arc::new(StoryContainer::panel::<DropdownStory>(cx)),
-                                Arc::new(StoryContainer::panel::<ModalStory>(cx)),
-                                Arc::new(StoryContainer::panel::<PopupStory>(cx)),
-                                Arc::new(StoryContainer::panel::<ListStory>(cx)),
-                                Arc::new(StoryContainer::panel::<SwitchStory>(cx)),
-                                Arc::new(StoryContainer::panel::<ProgressStory>(cx)),
-                                Arc::new(StoryContainer::panel::<TableStory>(cx)),
-                                Arc::new(StoryContainer::panel::<ImageStory>(cx)),
-                                Arc::new(StoryContainer::panel::<ResizableStory>(cx)),
-                                Arc::new(StoryContainer::panel::<ScrollableStory>(cx)),
-                            ],
-                            None,
-                            &dock_area,
-                            cx,
-                        ),
-                        DockItem::tabs(
-                            vec![
-                                Arc::new(StoryContainer::panel::<ProgressStory>(cx)),
-                                Arc::new(StoryContainer::panel::<TextStory>(cx)),
-                            ],
-                            None,
-                            &dock_area,
-                            cx,
-                        ),
-                    ],
-                    vec![None, None, Some(px(300.))],
-                    &dock_area,
-                    cx,
-                ),
-                DockItem::split_with_sizes(
-                    Axis::Vertical,
-                    vec![
-                        DockItem::tab(StoryContainer::panel::<TooltipStory>(cx), &dock_area, cx),
-                        DockItem::tab(StoryContainer::panel::<CalendarStory>(cx), &dock_area, cx),
-                        DockItem::tab(StoryContainer::panel::<ImageStory>(cx), &dock_area, cx),
-                    ],
-                    vec![None, None, Some(px(300.))],
-                    &dock_area,
+                DockItem::tab(StoryContainer::panel::<IconStory>(cx), dock_area_ref, cx),
+                DockItem::tab(
+                    StoryContainer::panel::<CalendarStory>(cx),
+                    dock_area_ref,
                     cx,
                 ),
             ],
-            vec![Some(px(300.)), None, Some(px(350.))],
-            &dock_area,
+            dock_area_ref,
             cx,
-        )
+        );
+        let bottom = DockItem::tabs(
+            vec![
+                Arc::new(StoryContainer::panel::<ProgressStory>(cx)),
+                Arc::new(StoryContainer::panel::<TextStory>(cx)),
+            ],
+            None,
+            dock_area_ref,
+            cx,
+        );
+        let right = DockItem::split(
+            Axis::Vertical,
+            vec![
+                DockItem::tab(StoryContainer::panel::<TooltipStory>(cx), dock_area_ref, cx),
+                DockItem::tab(StoryContainer::panel::<ImageStory>(cx), dock_area_ref, cx),
+            ],
+            dock_area_ref,
+            cx,
+        );
+
+        dock_area.update(cx, |view, cx| {
+            view.set_root(center, cx);
+            view.set_dock(left, DockPlacement::Left, Some(px(300.)), true, cx);
+            view.set_dock(bottom, DockPlacement::Bottom, Some(px(300.)), true, cx);
+            view.set_dock(right, DockPlacement::Right, Some(px(350.)), true, cx);
+        });
     }
 
     pub fn new_local(
@@ -284,7 +300,6 @@ impl Render for StoryWorkspace {
         let notifications_count = cx.notifications().len();
 
         div()
-            .font_family(".SystemUIFont")
             .relative()
             .size_full()
             .flex()
@@ -309,7 +324,36 @@ impl Render for StoryWorkspace {
                             .justify_end()
                             .px_2()
                             .gap_2()
-                            .child(self.theme_color_picker.clone())
+                            .children(
+                                [
+                                    (DockPlacement::Left, IconName::PanelLeft),
+                                    (DockPlacement::Bottom, IconName::PanelBottom),
+                                    (DockPlacement::Right, IconName::PanelRight),
+                                ]
+                                .into_iter()
+                                .map(|(placement, icon)| {
+                                    let dock_area = self.dock_area.clone();
+                                    Button::new(SharedString::from(format!(
+                                        "toggle-dock-{:?}",
+                                        placement
+                                    )))
+                                    .icon(icon)
+                                    .small()
+                                    .ghost()
+                                    .selected(self.dock_area.read(cx).is_dock_open(placement, cx))
+                                    .on_click(
+                                        move |_, cx| {
+                                            dock_area.update(cx, |view, cx| {
+                                                view.toggle_dock(placement, cx)
+                                            })
+                                        },
+                                    )
+                                }),
+                            )
+                            // Keep the title bar compact in a narrow window.
+                            .when_breakpoint(Breakpoint::Md, cx, |this| {
+                                this.child(self.theme_color_picker.clone())
+                            })
                             .child(
                                 Button::new("theme-mode")
                                     .map(|this| {
@@ -331,15 +375,19 @@ impl Render for StoryWorkspace {
                                     }),
                             )
                             .child(self.locale_selector.clone())
-                            .child(
-                                Button::new("github")
-                                    .icon(IconName::GitHub)
-                                    .small()
-                                    .ghost()
-                                    .on_click(|_, cx| {
-                                        cx.open_url("https://github.com/huacnlee/gpui-component")
-                                    }),
-                            )
+                            .when_breakpoint(Breakpoint::Md, cx, |this| {
+                                this.child(
+                                    Button::new("github")
+                                        .icon(IconName::GitHub)
+                                        .small()
+                                        .ghost()
+                                        .on_click(|_, cx| {
+                                            cx.open_url(
+                                                "https://github.com/huacnlee/gpui-component",
+                                            )
+                                        }),
+                                )
+                            })
                             .child(
                                 div()
                                     .relative()
@@ -348,7 +396,16 @@ impl Render for StoryWorkspace {
                                             .small()
                                             .ghost()
                                             .compact()
-                                            .icon(IconName::Bell),
+                                            .icon(IconName::Bell)
+                                            .on_click(|_, cx| {
+                                                let center = NotificationCenter::view(cx);
+                                                cx.open_drawer(move |drawer, _| {
+                                                    drawer
+                                                        .margin_top(px(33.))
+                                                        .size(px(360.))
+                                                        .child(center.clone())
+                                                });
+                                            }),
                                     )
                                     .when(notifications_count > 0, |this| {
                                         this.child(
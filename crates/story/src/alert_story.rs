@@ -0,0 +1,100 @@
+use gpui::{
+    prelude::FluentBuilder as _, IntoElement, ParentElement as _, Render, Styled as _, View,
+    ViewContext, VisualContext as _, WindowContext,
+};
+use ui::{alert::Alert, v_flex, ContextModal as _, IconName};
+
+use crate::section;
+
+pub struct AlertStory {
+    focus_handle: gpui::FocusHandle,
+    dismissed: bool,
+}
+
+impl super::Story for AlertStory {
+    fn title() -> &'static str {
+        "Alert"
+    }
+
+    fn description() -> &'static str {
+        "An inline banner for the persistent messages in the page."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl AlertStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            dismissed: false,
+        }
+    }
+}
+
+impl gpui::FocusableView for AlertStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for AlertStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_6()
+            .child(
+                section("Types", cx).child(
+                    v_flex()
+                        .w_full()
+                        .gap_3()
+                        .child(Alert::info(
+                            "info",
+                            "A new version is available, restart to update.",
+                        ))
+                        .child(Alert::success("success", "Your changes have been saved."))
+                        .child(Alert::warning(
+                            "warning",
+                            "Your trial will expire in 3 days.",
+                        ))
+                        .child(Alert::error(
+                            "error",
+                            "Failed to connect to the server, please check your network.",
+                        )),
+                ),
+            )
+            .child(
+                section("Title, Action and Dismiss", cx).child(
+                    v_flex()
+                        .w_full()
+                        .gap_3()
+                        .child(
+                            Alert::warning(
+                                "storage",
+                                "You have used 95% of the storage, upgrade to get more space.",
+                            )
+                            .title("Storage is almost full")
+                            .icon(IconName::Inbox)
+                            .action("Upgrade", |_, cx| {
+                                cx.push_notification("Upgrade is clicked.");
+                            }),
+                        )
+                        .when(!self.dismissed, |this| {
+                            this.child(
+                                Alert::info("dismiss", "This alert can be dismissed.")
+                                    .title("Welcome")
+                                    .on_dismiss(cx.listener(|this, _, cx| {
+                                        this.dismissed = true;
+                                        cx.notify();
+                                    })),
+                            )
+                        }),
+                ),
+            )
+    }
+}
@@ -0,0 +1,94 @@
+use gpui::{
+    IntoElement, ParentElement as _, Render, Styled as _, View, ViewContext, VisualContext as _,
+    WindowContext,
+};
+use ui::{
+    avatar::{Avatar, AvatarGroup, AvatarStatus},
+    v_flex, IconName, Sizable as _,
+};
+
+use crate::section;
+
+const AVATAR_URL: &str = "https://avatars.githubusercontent.com/u/5518?s=96";
+
+pub struct AvatarStory {
+    focus_handle: gpui::FocusHandle,
+}
+
+impl super::Story for AvatarStory {
+    fn title() -> &'static str {
+        "Avatar"
+    }
+
+    fn description() -> &'static str {
+        "An image element with a fallback for representing the user."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl AvatarStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+        }
+    }
+}
+
+impl gpui::FocusableView for AvatarStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for AvatarStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let names = [
+            "Jason Lee",
+            "Floyd Wang",
+            "Mary Jane",
+            "Peter Parker",
+            "Tony Stark",
+        ];
+
+        v_flex()
+            .gap_6()
+            .child(
+                section("Avatar", cx)
+                    .child(Avatar::new().src(AVATAR_URL).name("Jason Lee"))
+                    .child(Avatar::new().name("Jason Lee"))
+                    .child(Avatar::new())
+                    .child(Avatar::new().icon(IconName::Star)),
+            )
+            .child(
+                section("Sizes", cx)
+                    .child(Avatar::new().src(AVATAR_URL).xsmall())
+                    .child(Avatar::new().src(AVATAR_URL).small())
+                    .child(Avatar::new().src(AVATAR_URL))
+                    .child(Avatar::new().src(AVATAR_URL).large()),
+            )
+            .child(
+                section("Status", cx)
+                    .child(Avatar::new().name("Online").status(AvatarStatus::Online))
+                    .child(Avatar::new().name("Away").status(AvatarStatus::Away))
+                    .child(Avatar::new().name("Busy").status(AvatarStatus::Busy))
+                    .child(Avatar::new().name("Offline").status(AvatarStatus::Offline)),
+            )
+            .child(
+                section("Avatar Group", cx)
+                    .child(AvatarGroup::new().children(names.map(|name| Avatar::new().name(name))))
+                    .child(
+                        AvatarGroup::new()
+                            .max(3)
+                            .small()
+                            .children(names.map(|name| Avatar::new().name(name))),
+                    ),
+            )
+    }
+}
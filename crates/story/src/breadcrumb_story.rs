@@ -0,0 +1,131 @@
+use gpui::{
+    px, IntoElement, ParentElement as _, Render, SharedString, Styled as _, View, ViewContext,
+    VisualContext as _, WeakView, WindowContext,
+};
+use ui::{
+    breadcrumb::{Breadcrumb, BreadcrumbItem},
+    v_flex, IconName,
+};
+
+use crate::section;
+
+const PATH: [&str; 7] = [
+    "Home",
+    "Projects",
+    "gpui-component",
+    "crates",
+    "ui",
+    "src",
+    "lib.rs",
+];
+
+pub struct BreadcrumbStory {
+    focus_handle: gpui::FocusHandle,
+    breadcrumb: View<Breadcrumb>,
+    narrow_breadcrumb: View<Breadcrumb>,
+    slash_breadcrumb: View<Breadcrumb>,
+    message: SharedString,
+}
+
+impl super::Story for BreadcrumbStory {
+    fn title() -> &'static str {
+        "Breadcrumb"
+    }
+
+    fn description() -> &'static str {
+        "Displays the path to the current resource, the middle items are collapsed when there is no enough space."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl BreadcrumbStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let story = cx.view().downgrade();
+        let items = Self::path_items(PATH.len(), &story);
+
+        let breadcrumb = cx.new_view(|cx| {
+            items
+                .iter()
+                .cloned()
+                .fold(Breadcrumb::new(cx), |this, item| this.item(item))
+        });
+        let narrow_breadcrumb = cx.new_view(|cx| {
+            items
+                .iter()
+                .cloned()
+                .fold(Breadcrumb::new(cx), |this, item| this.item(item))
+        });
+        let slash_breadcrumb = cx.new_view(|cx| {
+            Breadcrumb::new(cx)
+                .separator_text("/")
+                .item(BreadcrumbItem::new("Settings"))
+                .item(BreadcrumbItem::new("Account"))
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            breadcrumb,
+            narrow_breadcrumb,
+            slash_breadcrumb,
+            message: "".into(),
+        }
+    }
+
+    /// Return the items of the first `len` segments of the path, click an item to go back to it.
+    fn path_items(len: usize, story: &WeakView<Self>) -> Vec<BreadcrumbItem> {
+        PATH[..len]
+            .iter()
+            .enumerate()
+            .map(|(ix, label)| {
+                let story = story.clone();
+                let item = BreadcrumbItem::new(*label).on_click(move |cx| {
+                    _ = story.update(cx, |this, cx| this.go_to(ix + 1, cx));
+                });
+
+                if ix == 0 {
+                    item.icon(IconName::Folder)
+                } else {
+                    item
+                }
+            })
+            .collect()
+    }
+
+    fn go_to(&mut self, len: usize, cx: &mut ViewContext<Self>) {
+        let items = Self::path_items(len, &cx.view().downgrade());
+        self.breadcrumb
+            .update(cx, |breadcrumb, cx| breadcrumb.set_items(items, cx));
+        self.message = format!("Go to: {}", PATH[..len].join("/")).into();
+        cx.notify();
+    }
+}
+
+impl gpui::FocusableView for BreadcrumbStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for BreadcrumbStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_6()
+            .child(
+                section("Breadcrumb", cx)
+                    .child(self.breadcrumb.clone())
+                    .child(self.message.clone()),
+            )
+            .child(
+                section("Collapsed", cx)
+                    .child(v_flex().w(px(260.)).child(self.narrow_breadcrumb.clone())),
+            )
+            .child(section("Text Separator", cx).child(self.slash_breadcrumb.clone()))
+    }
+}
@@ -1,5 +1,5 @@
 use gpui::{
-    px, ClickEvent, FocusableView, IntoElement, ParentElement as _, Render, Styled as _, View,
+    div, px, ClickEvent, FocusableView, IntoElement, ParentElement as _, Render, Styled as _, View,
     ViewContext, VisualContext as _, WindowContext,
 };
 
@@ -9,7 +9,9 @@ use ui::{
     checkbox::Checkbox,
     h_flex,
     prelude::FluentBuilder,
+    segmented_control::{SegmentItem, SegmentedControl},
     theme::{ActiveTheme, Theme},
+    toolbar::{Toolbar, ToolbarButton, ToolbarGroup},
     v_flex, Disableable as _, Icon, IconName, Selectable as _, Sizable as _,
 };
 
@@ -22,6 +24,10 @@ pub struct ButtonStory {
     selected: bool,
     compact: bool,
     toggle_multiple: bool,
+    toolbar: View<Toolbar>,
+    narrow_toolbar: View<Toolbar>,
+    segmented_control: View<SegmentedControl>,
+    multiple_segmented_control: View<SegmentedControl>,
 }
 
 impl ButtonStory {
@@ -33,9 +39,88 @@ impl ButtonStory {
             selected: false,
             compact: false,
             toggle_multiple: false,
+            toolbar: cx.new_view(Self::toolbar),
+            narrow_toolbar: cx.new_view(Self::toolbar),
+            segmented_control: cx.new_view(|cx| {
+                SegmentedControl::new(cx).items([
+                    SegmentItem::new("Day"),
+                    SegmentItem::new("Week"),
+                    SegmentItem::new("Month").with_icon(IconName::Calendar),
+                ])
+            }),
+            multiple_segmented_control: cx.new_view(|cx| {
+                SegmentedControl::new(cx)
+                    .small()
+                    .multiple(true)
+                    .selected(vec![0, 2])
+                    .items([
+                        SegmentItem::icon(IconName::Star),
+                        SegmentItem::icon(IconName::Heart),
+                        SegmentItem::icon(IconName::Bell),
+                    ])
+            }),
         })
     }
 
+    fn toolbar(cx: &mut ViewContext<Toolbar>) -> Toolbar {
+        Toolbar::new(cx)
+            .group(
+                ToolbarGroup::new()
+                    .child(
+                        ToolbarButton::new("undo")
+                            .icon(IconName::ArrowLeft)
+                            .tooltip("Undo")
+                            .on_click(|_| println!("Undo")),
+                    )
+                    .child(
+                        ToolbarButton::new("redo")
+                            .icon(IconName::ArrowRight)
+                            .tooltip("Redo")
+                            .on_click(|_| println!("Redo")),
+                    ),
+            )
+            .separator()
+            .group(
+                ToolbarGroup::new()
+                    .toggle(true)
+                    .selected(vec![0])
+                    .child(ToolbarButton::new("align-left").label("Left"))
+                    .child(ToolbarButton::new("align-center").label("Center"))
+                    .child(ToolbarButton::new("align-right").label("Right"))
+                    .on_change(|selected, _| println!("Align: {:?}", selected)),
+            )
+            .separator()
+            .group(
+                ToolbarGroup::new()
+                    .toggle(true)
+                    .multiple(true)
+                    .child(
+                        ToolbarButton::new("star")
+                            .icon(IconName::Star)
+                            .tooltip("Star"),
+                    )
+                    .child(
+                        ToolbarButton::new("heart")
+                            .icon(IconName::Heart)
+                            .tooltip("Like"),
+                    )
+                    .on_change(|selected, _| println!("Marks: {:?}", selected)),
+            )
+            .separator()
+            .button(
+                ToolbarButton::new("copy")
+                    .icon(IconName::Copy)
+                    .label("Copy")
+                    .on_click(|_| println!("Copy")),
+            )
+            .button(
+                ToolbarButton::new("delete")
+                    .icon(IconName::Delete)
+                    .label("Delete")
+                    .on_click(|_| println!("Delete")),
+            )
+    }
+
     fn on_click(ev: &ClickEvent, _: &mut WindowContext) {
         println!("Button clicked! {:?}", ev);
     }
@@ -546,6 +631,20 @@ impl Render for ButtonStory {
                             ),
                     ),
             )
+            .child(
+                section("Toolbar", cx).child(
+                    v_flex()
+                        .w_full()
+                        .gap_3()
+                        .child(self.toolbar.clone())
+                        .child(div().w(px(240.)).child(self.narrow_toolbar.clone())),
+                ),
+            )
+            .child(
+                section("Segmented Control", cx)
+                    .child(self.segmented_control.clone())
+                    .child(self.multiple_segmented_control.clone()),
+            )
             .child(
                 section("Icon Button", cx)
                     .child(
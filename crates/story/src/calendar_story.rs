@@ -5,6 +5,7 @@ use gpui::{
 };
 use ui::{
     date_picker::{DatePicker, DatePickerEvent},
+    date_range_picker::{DateRangePicker, DateRangePickerEvent},
     v_flex, Sizable as _,
 };
 
@@ -15,6 +16,7 @@ pub struct CalendarStory {
     date_picker_value: Option<String>,
     date_range_picker: View<DatePicker>,
     default_range_mode_picker: View<DatePicker>,
+    presets_range_picker: View<DateRangePicker>,
 }
 
 impl super::Story for CalendarStory {
@@ -94,12 +96,26 @@ impl CalendarStory {
         })
         .detach();
 
+        let presets_range_picker = cx.new_view(|cx| {
+            DateRangePicker::new("presets_range_picker", cx)
+                .width(px(300.))
+                .cleanable()
+        });
+
+        cx.subscribe(&presets_range_picker, |this, _, ev, _| match ev {
+            DateRangePickerEvent::Change(date) => {
+                this.date_picker_value = date.format("%Y-%m-%d").map(|s| s.to_string());
+            }
+        })
+        .detach();
+
         Self {
             date_picker,
             date_picker_large,
             date_picker_small,
             date_range_picker,
             default_range_mode_picker,
+            presets_range_picker,
             date_picker_value: None,
         }
     }
@@ -120,6 +136,7 @@ impl Render for CalendarStory {
             .child(self.date_picker_large.clone())
             .child(self.date_range_picker.clone())
             .child(self.default_range_mode_picker.clone())
+            .child(self.presets_range_picker.clone())
             .child(format!("Date picker value: {:?}", self.date_picker_value).into_element())
     }
 }
@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use gpui::{
+    div, px, Hsla, IntoElement, ParentElement as _, Render, Styled as _, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+use ui::{carousel::Carousel, v_flex, StyledExt as _};
+
+use crate::section;
+
+pub struct CarouselStory {
+    focus_handle: gpui::FocusHandle,
+    carousel: View<Carousel>,
+    autoplay_carousel: View<Carousel>,
+}
+
+impl super::Story for CarouselStory {
+    fn title() -> &'static str {
+        "Carousel"
+    }
+
+    fn description() -> &'static str {
+        "A slider of the slides, swipe or use the arrow keys to switch."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+fn slide(title: &'static str, color: Hsla) -> impl Fn(&mut WindowContext) -> gpui::Div {
+    move |_| {
+        v_flex()
+            .size_full()
+            .items_center()
+            .justify_center()
+            .bg(color)
+            .text_color(ui::white())
+            .child(div().text_2xl().font_semibold().child(title))
+    }
+}
+
+impl CarouselStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let carousel = cx.new_view(|cx| {
+            Carousel::new(cx)
+                .slide(slide("Welcome", ui::blue_500()))
+                .slide(slide("Create a project", ui::green_600()))
+                .slide(slide("Invite your team", ui::purple_500()))
+        });
+        let autoplay_carousel = cx.new_view(|cx| {
+            Carousel::new(cx)
+                .arrows(false)
+                .slide(slide("1", ui::orange_500()))
+                .slide(slide("2", ui::pink_500()))
+                .slide(slide("3", ui::teal_500()))
+                .slide(slide("4", ui::indigo_500()))
+                .autoplay(Duration::from_secs(3), cx)
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            carousel,
+            autoplay_carousel,
+        }
+    }
+}
+
+impl gpui::FocusableView for CarouselStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for CarouselStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_6()
+            .child(
+                section("Carousel", cx)
+                    .child(div().w(px(480.)).h(px(240.)).child(self.carousel.clone())),
+            )
+            .child(
+                section("Autoplay, pause on hover", cx).child(
+                    div()
+                        .w(px(480.))
+                        .h(px(240.))
+                        .child(self.autoplay_carousel.clone()),
+                ),
+            )
+    }
+}
@@ -0,0 +1,195 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, px, IntoElement, ParentElement as _, Render, SharedString, Styled as _, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+use ui::{
+    button::Button,
+    chart::{Chart, ChartKind, Series, Sparkline, SparklineKind},
+    h_flex,
+    theme::ActiveTheme as _,
+    v_flex,
+};
+
+use crate::section;
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+const REVENUE: [f64; 12] = [
+    1200., 1800., 1500., 2400., 2100., 2900., 3300., 3100., 3800., 3500., 4200., 4800.,
+];
+const COST: [f64; 12] = [
+    900., 1100., 1300., 1400., 1600., 1700., 2100., 2000., 2300., 2500., 2600., 2900.,
+];
+
+pub struct ChartStory {
+    focus_handle: gpui::FocusHandle,
+    line_chart: View<Chart>,
+    area_chart: View<Chart>,
+    bar_chart: View<Chart>,
+    pie_chart: View<Chart>,
+    shift: usize,
+    /// The daily prices of the stocks for the sparklines.
+    stocks: Vec<(SharedString, Rc<[f64]>)>,
+}
+
+impl super::Story for ChartStory {
+    fn title() -> &'static str {
+        "Chart"
+    }
+
+    fn description() -> &'static str {
+        "The line, area, bar and pie charts with the axes, legend and tooltip."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl ChartStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let chart = |kind: ChartKind, cx: &mut ViewContext<Self>| {
+            cx.new_view(|_| {
+                Chart::new(kind)
+                    .labels(MONTHS)
+                    .series(Series::new("Revenue", REVENUE))
+                    .series(Series::new("Cost", COST))
+                    .formatter(|value| format!("${}", value).into())
+            })
+        };
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            line_chart: chart(ChartKind::Line, cx),
+            area_chart: cx.new_view(|_| {
+                Chart::new(ChartKind::Area)
+                    .labels(MONTHS)
+                    .series(Series::new(
+                        "Active Users",
+                        [
+                            320., 410., 380., 520., 610., 580., 700., 820., 790., 910., 1050.,
+                            1200.,
+                        ],
+                    ))
+                    .legend(false)
+            }),
+            bar_chart: chart(ChartKind::Bar, cx),
+            pie_chart: cx.new_view(|_| {
+                Chart::new(ChartKind::Pie)
+                    .labels(["Desktop", "Mobile", "Tablet", "Other"])
+                    .series(Series::new("Traffic", [56., 32., 9., 3.]))
+            }),
+            shift: 0,
+            stocks: ["AAPL", "GOOG", "MSFT", "NVDA", "TSLA"]
+                .into_iter()
+                .enumerate()
+                .map(|(ix, name)| {
+                    let prices = (0..90)
+                        .map(|day| {
+                            let day = day as f64;
+                            100. + day * (ix as f64 - 2.) * 0.3
+                                + (day * 0.3 + ix as f64).sin() * 8.
+                                + (day * 1.7).cos() * 3.
+                        })
+                        .collect::<Vec<_>>();
+                    (name.into(), prices.into())
+                })
+                .collect(),
+        }
+    }
+
+    /// Rotate the months of the data, to show the charts are updated.
+    fn update_data(&mut self, cx: &mut ViewContext<Self>) {
+        self.shift = (self.shift + 1) % MONTHS.len();
+        let shift = self.shift;
+        let rotate = |values: &[f64]| {
+            let mut values = values.to_vec();
+            values.rotate_left(shift);
+            values
+        };
+        let mut labels: Vec<SharedString> = MONTHS.iter().map(|month| (*month).into()).collect();
+        labels.rotate_left(shift);
+
+        for chart in [&self.line_chart, &self.bar_chart] {
+            chart.update(cx, |chart, cx| {
+                chart.set_labels(labels.clone(), cx);
+                chart.set_series(
+                    vec![
+                        Series::new("Revenue", rotate(&REVENUE)),
+                        Series::new("Cost", rotate(&COST)),
+                    ],
+                    cx,
+                );
+            });
+        }
+    }
+}
+
+impl gpui::FocusableView for ChartStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for ChartStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_6()
+            .child(
+                h_flex().child(
+                    Button::new("update-data")
+                        .label("Update Data")
+                        .on_click(cx.listener(|this, _, cx| this.update_data(cx))),
+                ),
+            )
+            .child(
+                section("Line Chart", cx)
+                    .child(div().w_full().h(px(260.)).child(self.line_chart.clone())),
+            )
+            .child(
+                section("Area Chart", cx)
+                    .child(div().w_full().h(px(220.)).child(self.area_chart.clone())),
+            )
+            .child(
+                section("Bar Chart", cx)
+                    .child(div().w_full().h(px(260.)).child(self.bar_chart.clone())),
+            )
+            .child(
+                section("Pie Chart", cx)
+                    .child(div().w_full().h(px(260.)).child(self.pie_chart.clone())),
+            )
+            .child(
+                section("Sparkline", cx).child(v_flex().w(px(480.)).text_sm().children(
+                    self.stocks.iter().map(|(name, prices)| {
+                        let last = prices[prices.len() - 1];
+                        h_flex()
+                            .gap_4()
+                            .py_1()
+                            .border_b_1()
+                            .border_color(cx.theme().border)
+                            .child(div().w_16().child(name.clone()))
+                            .child(Sparkline::new(prices.clone()).markers(true).w_32())
+                            .child(
+                                Sparkline::new(prices.clone())
+                                    .kind(SparklineKind::Bar)
+                                    .baseline(prices[0])
+                                    .w_32(),
+                            )
+                            .child(
+                                h_flex()
+                                    .flex_1()
+                                    .justify_end()
+                                    .child(format!("{:.2}", last)),
+                            )
+                    }),
+                )),
+            )
+    }
+}
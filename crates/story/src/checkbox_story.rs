@@ -0,0 +1,125 @@
+use gpui::{
+    div, IntoElement, ParentElement, Render, SharedString, Styled, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+
+use ui::{
+    checkbox::{Checkbox, CheckboxGroup, CheckboxGroupEvent, CheckboxGroupItem},
+    h_flex,
+    theme::ActiveTheme as _,
+    v_flex, Disableable as _,
+};
+
+pub struct CheckboxStory {
+    focus_handle: gpui::FocusHandle,
+    check1: bool,
+    check1_handle: gpui::FocusHandle,
+    group: View<CheckboxGroup>,
+    group_values: SharedString,
+}
+
+impl super::Story for CheckboxStory {
+    fn title() -> &'static str {
+        "Checkbox"
+    }
+
+    fn description() -> &'static str {
+        "A checkbox with the indeterminate state, press Space to toggle when focused."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl CheckboxStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let group = cx.new_view(|cx| {
+            CheckboxGroup::new(cx)
+                .select_all("All notifications")
+                .items(vec![
+                    CheckboxGroupItem::new("email", "Email").description("Get notified by email."),
+                    CheckboxGroupItem::new("push", "Push")
+                        .description("Get notified on your devices."),
+                    CheckboxGroupItem::new("sms", "SMS").disabled(true),
+                ])
+                .values(vec!["email".into()])
+        });
+
+        cx.subscribe(
+            &group,
+            |this, _, event: &CheckboxGroupEvent, cx| match event {
+                CheckboxGroupEvent::Change(values) => {
+                    this.group_values = values
+                        .iter()
+                        .map(|value| value.as_ref())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                        .into();
+                    cx.notify();
+                }
+            },
+        )
+        .detach();
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            check1: false,
+            check1_handle: cx.focus_handle(),
+            group,
+            group_values: "email".into(),
+        }
+    }
+}
+
+impl gpui::FocusableView for CheckboxStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for CheckboxStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_6()
+            .child(
+                h_flex()
+                    .gap_6()
+                    .items_start()
+                    .child(
+                        Checkbox::new("check1")
+                            .label("Accept the terms")
+                            .description("You agree to our Terms of Service.")
+                            .checked(self.check1)
+                            .track_focus(&self.check1_handle)
+                            .on_click(cx.listener(|this, checked, cx| {
+                                this.check1 = *checked;
+                                cx.notify();
+                            })),
+                    )
+                    .child(
+                        Checkbox::new("check2")
+                            .label("Indeterminate")
+                            .indeterminate(true),
+                    )
+                    .child(
+                        Checkbox::new("check3")
+                            .label("Disabled")
+                            .checked(true)
+                            .disabled(true),
+                    ),
+            )
+            .child(
+                v_flex().gap_2().child(self.group.clone()).child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(format!("Checked: {}", self.group_values)),
+                ),
+            )
+    }
+}
@@ -1,11 +1,19 @@
+use std::time::Duration;
+
 use gpui::{
     actions, px, AppContext, InteractiveElement, IntoElement, KeyBinding, ParentElement, Render,
     SharedString, Styled, View, ViewContext, VisualContext, WindowContext,
 };
 
 use ui::{
-    dropdown::{Dropdown, DropdownEvent, DropdownItem, SearchableVec},
+    cascader::{Cascader, CascaderEvent, CascaderOption},
+    combobox::{Combobox, ComboboxEvent},
+    dropdown::{
+        Dropdown, DropdownEvent, DropdownItem, DropdownItemGroup, SearchableGroupedVec,
+        SearchableVec,
+    },
     h_flex,
+    multi_select::{MultiSelect, MultiSelectEvent},
     theme::ActiveTheme,
     v_flex, FocusableCycle, IconName, Sizable,
 };
@@ -20,9 +28,11 @@ pub fn init(cx: &mut AppContext) {
     ])
 }
 
+#[derive(Clone)]
 struct Country {
     name: SharedString,
     code: SharedString,
+    disabled: bool,
 }
 
 impl Country {
@@ -30,8 +40,14 @@ impl Country {
         Self {
             name: name.into(),
             code: code.into(),
+            disabled: false,
         }
     }
+
+    pub fn disabled(mut self) -> Self {
+        self.disabled = true;
+        self
+    }
 }
 
 impl DropdownItem for Country {
@@ -44,11 +60,20 @@ impl DropdownItem for Country {
     fn value(&self) -> &Self::Value {
         &self.code
     }
+
+    fn disabled(&self) -> bool {
+        self.disabled
+    }
 }
 
 pub struct DropdownStory {
     country_dropdown: View<Dropdown<Vec<Country>>>,
     fruit_dropdown: View<Dropdown<SearchableVec<SharedString>>>,
+    grouped_dropdown: View<Dropdown<SearchableGroupedVec<SharedString>>>,
+    tag_dropdown: View<Dropdown<SearchableVec<SharedString>>>,
+    country_combobox: View<Combobox<Country>>,
+    country_multi_select: View<MultiSelect<Country>>,
+    city_cascader: View<Cascader>,
     simple_dropdown1: View<Dropdown<Vec<SharedString>>>,
     simple_dropdown2: View<Dropdown<SearchableVec<SharedString>>>,
     simple_dropdown3: View<Dropdown<Vec<SharedString>>>,
@@ -87,10 +112,78 @@ impl DropdownStory {
             Country::new("China", "CN"),
             Country::new("Peru", "PE"),
             Country::new("Colombia", "CO"),
-            Country::new("Venezuela", "VE"),
+            Country::new("Venezuela", "VE").disabled(),
             Country::new("Ecuador", "EC"),
         ];
 
+        let country_combobox = cx.new_view(|cx| {
+            let countries = countries.clone();
+            let mut combobox = Combobox::new(
+                "combobox-country",
+                move |query, cx| {
+                    let query = query.to_lowercase();
+                    let countries = countries.clone();
+                    let executor = cx.background_executor().clone();
+                    cx.background_executor().spawn(async move {
+                        // Simulate a slow search backend.
+                        executor.timer(Duration::from_millis(300)).await;
+                        countries
+                            .into_iter()
+                            .filter(|country| country.name.to_lowercase().contains(&query))
+                            .collect()
+                    })
+                },
+                cx,
+            );
+            combobox.set_placeholder("Search country...", cx);
+            combobox
+        });
+
+        let country_multi_select = cx.new_view(|cx| {
+            let mut multi_select = MultiSelect::new("multi-select-country", countries.clone(), cx)
+                .placeholder("Select countries")
+                .max_chips(2);
+            multi_select.set_selected_values(vec!["US".into(), "CA".into(), "CN".into()], cx);
+            multi_select
+        });
+
+        let city_cascader = cx.new_view(|cx| {
+            Cascader::new(
+                "cascader-city",
+                vec![
+                    CascaderOption::new("us", "United States").children(vec![
+                        CascaderOption::new("ca", "California").children(vec![
+                            CascaderOption::new("sf", "San Francisco"),
+                            CascaderOption::new("la", "Los Angeles"),
+                            CascaderOption::new("sd", "San Diego"),
+                        ]),
+                        CascaderOption::new("ny", "New York").children(vec![
+                            CascaderOption::new("nyc", "New York City"),
+                            CascaderOption::new("buffalo", "Buffalo"),
+                        ]),
+                        CascaderOption::new("tx", "Texas").disabled(true),
+                    ]),
+                    CascaderOption::new("cn", "China").children(vec![
+                        CascaderOption::new("gd", "Guangdong").children(vec![
+                            CascaderOption::new("gz", "Guangzhou"),
+                            CascaderOption::new("sz", "Shenzhen"),
+                        ]),
+                        CascaderOption::new("zj", "Zhejiang").children(vec![
+                            CascaderOption::new("hz", "Hangzhou"),
+                            CascaderOption::new("nb", "Ningbo"),
+                        ]),
+                    ]),
+                    CascaderOption::new("jp", "Japan").children(vec![
+                        CascaderOption::new("tokyo", "Tokyo"),
+                        CascaderOption::new("osaka", "Osaka"),
+                    ]),
+                ],
+                cx,
+            )
+            .width(px(300.))
+            .placeholder("Select city")
+        });
+
         let country_dropdown =
             cx.new_view(|cx| Dropdown::new("dropdown-country", countries, Some(6), cx).cleanable());
 
@@ -110,13 +203,57 @@ impl DropdownStory {
                 .menu_width(px(320.))
         });
 
+        // 26 groups with 5200 items in total, to show the menu list is virtualized.
+        let groups = ('A'..='Z')
+            .map(|letter| {
+                DropdownItemGroup::new(
+                    format!("Group {}", letter),
+                    (1..=200)
+                        .map(|ix| SharedString::from(format!("{}-{:03}", letter, ix)))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let grouped_dropdown = cx.new_view(|cx| {
+            Dropdown::new(
+                "dropdown-grouped",
+                SearchableGroupedVec::new(groups),
+                None,
+                cx,
+            )
+            .width(px(200.))
+            .placeholder("Grouped items")
+        });
+
+        let tags = SearchableVec::new(vec!["bug".into(), "feature".into(), "docs".into()]);
+        let tag_dropdown = cx.new_view(|cx| {
+            let mut dropdown = Dropdown::new("dropdown-tags", tags, None, cx)
+                .width(px(200.))
+                .placeholder("Tag");
+            dropdown.set_creatable(true, cx);
+            dropdown
+        });
+
         cx.new_view(|cx| {
+            cx.subscribe(&tag_dropdown, Self::on_tag_dropdown_event)
+                .detach();
             cx.subscribe(&country_dropdown, Self::on_dropdown_event)
                 .detach();
+            cx.subscribe(&country_combobox, Self::on_combobox_event)
+                .detach();
+            cx.subscribe(&country_multi_select, Self::on_multi_select_event)
+                .detach();
+            cx.subscribe(&city_cascader, Self::on_cascader_event)
+                .detach();
 
             Self {
                 country_dropdown,
                 fruit_dropdown,
+                grouped_dropdown,
+                tag_dropdown,
+                country_combobox,
+                country_multi_select,
+                city_cascader,
                 simple_dropdown1: cx.new_view(|cx| {
                     Dropdown::new(
                         "string-list1",
@@ -176,6 +313,55 @@ impl DropdownStory {
     ) {
         match event {
             DropdownEvent::Confirm(value) => println!("Selected country: {:?}", value),
+            DropdownEvent::Create(_) => {}
+        }
+    }
+
+    fn on_tag_dropdown_event(
+        &mut self,
+        dropdown: View<Dropdown<SearchableVec<SharedString>>>,
+        event: &DropdownEvent<SearchableVec<SharedString>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            DropdownEvent::Confirm(value) => println!("Selected tag: {:?}", value),
+            DropdownEvent::Create(tag) => dropdown.update(cx, |dropdown, cx| {
+                dropdown.update_delegate(|tags| tags.push(tag.clone()), cx);
+                dropdown.set_selected_value(tag, cx);
+            }),
+        }
+    }
+
+    fn on_combobox_event(
+        &mut self,
+        _: View<Combobox<Country>>,
+        event: &ComboboxEvent<Country>,
+        _cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            ComboboxEvent::Confirm(value) => println!("Combobox country: {:?}", value),
+        }
+    }
+
+    fn on_multi_select_event(
+        &mut self,
+        _: View<MultiSelect<Country>>,
+        event: &MultiSelectEvent<Country>,
+        _cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            MultiSelectEvent::Change(values) => println!("Multi selected countries: {:?}", values),
+        }
+    }
+
+    fn on_cascader_event(
+        &mut self,
+        _: View<Cascader>,
+        event: &CascaderEvent,
+        _cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            CascaderEvent::Change(path) => println!("Cascader city: {:?}", path),
         }
     }
 
@@ -198,6 +384,11 @@ impl FocusableCycle for DropdownStory {
         vec![
             self.country_dropdown.focus_handle(cx),
             self.fruit_dropdown.focus_handle(cx),
+            self.grouped_dropdown.focus_handle(cx),
+            self.tag_dropdown.focus_handle(cx),
+            self.country_combobox.focus_handle(cx),
+            self.country_multi_select.focus_handle(cx),
+            self.city_cascader.focus_handle(cx),
             self.simple_dropdown1.focus_handle(cx),
             self.simple_dropdown2.focus_handle(cx),
             self.simple_dropdown3.focus_handle(cx),
@@ -220,8 +411,17 @@ impl Render for DropdownStory {
                     .items_center()
                     .gap_4()
                     .child(self.country_dropdown.clone())
-                    .child(self.fruit_dropdown.clone()),
+                    .child(self.fruit_dropdown.clone())
+                    .child(self.grouped_dropdown.clone())
+                    .child(self.tag_dropdown.clone()),
+            )
+            .child(h_flex().w(px(300.)).child(self.country_combobox.clone()))
+            .child(
+                h_flex()
+                    .w(px(400.))
+                    .child(self.country_multi_select.clone()),
             )
+            .child(self.city_cascader.clone())
             .child(
                 v_flex()
                     .w_full()
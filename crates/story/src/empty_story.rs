@@ -0,0 +1,116 @@
+use gpui::{
+    px, IntoElement, ParentElement as _, Render, Styled as _, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+use ui::{
+    button::{Button, ButtonStyled as _},
+    empty_state::EmptyState,
+    h_flex,
+    result::{Result, ResultStatus},
+    theme::ActiveTheme as _,
+    v_flex, ContextModal as _, IconName,
+};
+
+use crate::section;
+
+pub struct EmptyStory {
+    focus_handle: gpui::FocusHandle,
+}
+
+impl super::Story for EmptyStory {
+    fn title() -> &'static str {
+        "Empty & Result"
+    }
+
+    fn description() -> &'static str {
+        "The placeholder for the empty content, and the full pane outcome of an operation or a page."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl EmptyStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+        }
+    }
+}
+
+impl gpui::FocusableView for EmptyStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for EmptyStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let pane = |cx: &WindowContext| {
+            v_flex()
+                .flex_1()
+                .h(px(320.))
+                .border_1()
+                .border_color(cx.theme().border)
+                .rounded_md()
+        };
+
+        v_flex()
+            .gap_6()
+            .child(
+                section("Empty State", cx).child(
+                    h_flex()
+                        .w_full()
+                        .gap_4()
+                        .child(pane(cx).child(EmptyState::new()))
+                        .child(
+                            pane(cx).child(
+                                EmptyState::new()
+                                    .icon(IconName::Folder)
+                                    .title("No projects yet")
+                                    .description(
+                                        "Create a project to start to work with your team.",
+                                    )
+                                    .action(
+                                        Button::new("create")
+                                            .primary()
+                                            .icon(IconName::Plus)
+                                            .label("Create Project")
+                                            .on_click(|_, cx| {
+                                                cx.push_notification("Create Project is clicked.");
+                                            }),
+                                    ),
+                            ),
+                        ),
+                ),
+            )
+            .child(
+                section("Result", cx).child(
+                    h_flex()
+                        .w_full()
+                        .gap_4()
+                        .child(
+                            pane(cx).child(
+                                Result::new(ResultStatus::Success)
+                                    .title("Payment successful")
+                                    .description("The order will be shipped in 1-2 days.")
+                                    .action(Button::new("orders").primary().label("View Orders"))
+                                    .action(Button::new("continue").outline().label("Continue")),
+                            ),
+                        )
+                        .child(
+                            pane(cx).child(
+                                Result::new(ResultStatus::NotFound)
+                                    .description("The page you visited does not exist.")
+                                    .action(Button::new("home").primary().label("Back Home")),
+                            ),
+                        ),
+                ),
+            )
+    }
+}
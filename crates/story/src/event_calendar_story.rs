@@ -0,0 +1,110 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+use gpui::{
+    AppContext, IntoElement, ParentElement as _, Render, SharedString, Styled as _, View,
+    ViewContext, VisualContext as _, WindowContext,
+};
+use ui::{
+    event_calendar::{
+        DayEvent, EventCalendar, EventCalendarDelegate, EventCalendarEvent, EventDisplay,
+    },
+    green_500, red_500, v_flex, yellow_500,
+};
+
+struct ScheduleDelegate;
+
+impl EventCalendarDelegate for ScheduleDelegate {
+    fn events(&self, date: NaiveDate, _: &AppContext) -> Vec<DayEvent> {
+        let mut events = vec![];
+        if date.weekday() == Weekday::Mon {
+            events.push(DayEvent::new("Weekly meeting"));
+        }
+        if date.day() % 5 == 0 {
+            events.push(DayEvent::new("Code review").color(green_500()));
+        }
+        if date.day() % 7 == 0 {
+            events.push(DayEvent::new("Release").color(red_500()));
+            events.push(DayEvent::new("Deploy to production").color(yellow_500()));
+        }
+        if date.day() == 15 {
+            events.push(DayEvent::new("Team lunch").color(green_500()));
+        }
+
+        events
+    }
+
+    fn is_day_disabled(&self, date: NaiveDate) -> bool {
+        date.weekday() == Weekday::Sun
+    }
+}
+
+pub struct EventCalendarStory {
+    calendar: View<EventCalendar<ScheduleDelegate>>,
+    message: SharedString,
+}
+
+impl super::Story for EventCalendarStory {
+    fn title() -> &'static str {
+        "EventCalendar"
+    }
+
+    fn description() -> &'static str {
+        "A calendar to show the events by month or week."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl EventCalendarStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let calendar = cx.new_view(|cx| {
+            EventCalendar::new(ScheduleDelegate, cx)
+                .display(EventDisplay::Chip)
+                .multiple(true)
+                .on_day_click(|date, _| println!("Clicked day: {}", date))
+        });
+
+        cx.subscribe(&calendar, |this, _, ev, _| match ev {
+            EventCalendarEvent::SelectionChange(dates) => {
+                this.message = format!(
+                    "Selected: {}",
+                    dates
+                        .iter()
+                        .map(|date| date.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+                .into();
+            }
+            EventCalendarEvent::RangeChange(start, end) => {
+                this.message = format!("Visible: {} - {}", start, end).into();
+            }
+        })
+        .detach();
+
+        Self {
+            calendar,
+            message: "".into(),
+        }
+    }
+}
+
+impl gpui::FocusableView for EventCalendarStory {
+    fn focus_handle(&self, cx: &gpui::AppContext) -> gpui::FocusHandle {
+        self.calendar.focus_handle(cx)
+    }
+}
+
+impl Render for EventCalendarStory {
+    fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_3()
+            .child(self.calendar.clone())
+            .child(self.message.clone())
+    }
+}
@@ -0,0 +1,142 @@
+use std::time::Duration;
+
+use gpui::{
+    div, px, IntoElement, ParentElement, Render, SharedString, Styled, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+use serde::Deserialize;
+
+use ui::{
+    form::{FieldValue, Form, FormField},
+    input::TextInput,
+    theme::ActiveTheme as _,
+    v_flex,
+};
+
+#[derive(Deserialize)]
+struct SignUp {
+    username: String,
+    email: String,
+    website: String,
+}
+
+pub struct FormStory {
+    focus_handle: gpui::FocusHandle,
+    form: View<Form>,
+    submitted: SharedString,
+}
+
+impl super::Story for FormStory {
+    fn title() -> &'static str {
+        "Form"
+    }
+
+    fn description() -> &'static str {
+        "A form with labels, help text, sync and async validation."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl FormStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let username = cx.new_view(|cx| TextInput::new(cx).placeholder("jason"));
+        let email = cx.new_view(|cx| TextInput::new(cx).placeholder("jason@example.com"));
+        let website = cx.new_view(|cx| TextInput::new(cx).placeholder("https://"));
+
+        let view = cx.view().downgrade();
+        let form = cx.new_view(|cx| {
+            let mut form = Form::new(cx)
+                .submit_label("Sign Up")
+                .on_submit(move |values, cx| {
+                    let submitted: SharedString = match values.parse::<SignUp>() {
+                        Ok(sign_up) => format!(
+                            "Signed up {} <{}> {}",
+                            sign_up.username, sign_up.email, sign_up.website
+                        )
+                        .into(),
+                        Err(err) => err.to_string().into(),
+                    };
+                    _ = view.update(cx, |this, cx| {
+                        this.submitted = submitted;
+                        cx.notify();
+                    });
+                });
+
+            form.add_field(
+                FormField::input("username", &username)
+                    .label("Username")
+                    .help("The \"admin\" is already taken.")
+                    .required(true)
+                    .validate(|value| match value {
+                        FieldValue::Text(text) if text.len() < 3 => {
+                            Err("At least 3 characters.".into())
+                        }
+                        _ => Ok(()),
+                    })
+                    .validate_async(|value, cx| {
+                        // Simulate checking the username on the server.
+                        let value = value.clone();
+                        let timer = cx.background_executor().timer(Duration::from_millis(500));
+                        cx.background_executor().spawn(async move {
+                            timer.await;
+                            if value == FieldValue::Text("admin".into()) {
+                                Err("The username is already taken.".into())
+                            } else {
+                                Ok(())
+                            }
+                        })
+                    }),
+                cx,
+            );
+            form.add_field(
+                FormField::input("email", &email)
+                    .label("Email")
+                    .required(true)
+                    .validate(|value| match value {
+                        FieldValue::Text(text) if !text.contains('@') => {
+                            Err("Invalid email address.".into())
+                        }
+                        _ => Ok(()),
+                    }),
+                cx,
+            );
+            form.add_field(
+                FormField::input("website", &website)
+                    .label("Website")
+                    .help("Optional, your homepage or blog."),
+                cx,
+            );
+            form
+        });
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            form,
+            submitted: "".into(),
+        }
+    }
+}
+
+impl gpui::FocusableView for FormStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for FormStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex().gap_4().w(px(420.)).child(self.form.clone()).child(
+            div()
+                .text_sm()
+                .text_color(cx.theme().muted_foreground)
+                .child(self.submitted.clone()),
+        )
+    }
+}
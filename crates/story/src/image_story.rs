@@ -1,5 +1,5 @@
 use gpui::{px, ParentElement as _, Render, Styled, View, VisualContext as _, WindowContext};
-use ui::{h_flex, svg_img, v_flex, SvgImg};
+use ui::{h_flex, image_viewer::ImageViewer, svg_img, v_flex, SvgImg};
 
 const GOOGLE_LOGO: &str = include_str!("./fixtures/google.svg");
 const PIE_JSON: &str = include_str!("./fixtures/pie.json");
@@ -9,6 +9,7 @@ pub struct ImageStory {
     google_logo: SvgImg,
     pie_chart: SvgImg,
     inbox_img: SvgImg,
+    image_viewer: View<ImageViewer>,
 }
 
 impl super::Story for ImageStory {
@@ -30,6 +31,14 @@ impl ImageStory {
             google_logo: svg_img().source(GOOGLE_LOGO.as_bytes(), px(300.), px(300.)),
             pie_chart: svg_img().source(chart.svg().unwrap().as_bytes(), px(400.), px(400.)),
             inbox_img: svg_img().source("icons/inbox.svg", px(300.), px(300.)),
+            image_viewer: cx.new_view(|cx| {
+                ImageViewer::new(cx).thumbnail_size(px(120.)).images([
+                    "https://picsum.photos/id/10/1600/1000",
+                    "https://picsum.photos/id/29/1600/1000",
+                    "https://picsum.photos/id/57/1000/1600",
+                    "https://picsum.photos/id/104/1600/1000",
+                ])
+            }),
         }
     }
 
@@ -50,6 +59,7 @@ impl Render for ImageStory {
             .gap_4()
             .size_full()
             .items_center()
+            .child(self.image_viewer.clone())
             .child(
                 h_flex()
                     .size_full()
@@ -1,5 +1,5 @@
 use gpui::{
-    actions, div, px, AppContext, FocusHandle, InteractiveElement, IntoElement, KeyBinding,
+    actions, px, AppContext, FocusHandle, InteractiveElement, IntoElement, KeyBinding,
     ParentElement as _, Render, SharedString, Styled, View, ViewContext, VisualContext,
     WindowContext,
 };
@@ -9,7 +9,10 @@ use ui::{
     button::Button,
     checkbox::Checkbox,
     h_flex,
-    input::{InputEvent, OtpInput, TextInput},
+    input::{
+        InputEvent, NumberInput, NumberInputEvent, OtpInput, SearchInput, SearchInputEvent,
+        TagInput, TagInputEvent, TextInput,
+    },
     prelude::FluentBuilder as _,
     scroll::ScrollbarAxis,
     v_flex, FocusableCycle, IconName, Sizable, StyledExt,
@@ -29,13 +32,22 @@ pub fn init(cx: &mut AppContext) {
 pub struct InputStory {
     input1: View<TextInput>,
     input2: View<TextInput>,
+    phone_input: View<TextInput>,
     mash_input: View<TextInput>,
     disabled_input: View<TextInput>,
+    validated_input: View<TextInput>,
     prefix_input1: View<TextInput>,
     suffix_input1: View<TextInput>,
     both_input1: View<TextInput>,
+    unit_input: View<TextInput>,
+    search_input: View<SearchInput>,
+    tag_input: View<TagInput>,
     large_input: View<TextInput>,
     small_input: View<TextInput>,
+    textarea: View<TextInput>,
+    number_input: View<NumberInput>,
+    currency_input: View<NumberInput>,
+    chat_input: View<TextInput>,
     otp_masked: bool,
     otp_input: View<OtpInput>,
     otp_value: Option<SharedString>,
@@ -83,34 +95,133 @@ impl InputStory {
 
         cx.subscribe(&input2, Self::on_input_event).detach();
 
+        let phone_input = cx.new_view(|cx| {
+            TextInput::new(cx)
+                .mask("(999) 999-9999")
+                .placeholder("Phone number: (999) 999-9999")
+        });
+        cx.subscribe(
+            &phone_input,
+            |_, input, event: &InputEvent, cx| match event {
+                InputEvent::Change(text) => {
+                    println!("Phone: {}, raw: {}", text, input.read(cx).raw_text())
+                }
+                _ => {}
+            },
+        )
+        .detach();
+
         let mask_input = cx.new_view(|cx| {
-            let mut input = TextInput::new(cx).cleanable();
-            input.set_masked(true, cx);
+            let mut input = TextInput::new(cx).password().cleanable();
             input.set_text("this-is-password", cx);
             input
         });
 
         let prefix_input1 = cx.new_view(|cx| {
             TextInput::new(cx)
-                .prefix(|_| div().child(IconName::Search).ml_3())
+                .prefix(|_| IconName::Search)
                 .placeholder("Search some thing...")
                 .cleanable()
         });
         let suffix_input1 = cx.new_view(|cx| {
             TextInput::new(cx)
-                .suffix(|_| div().child(IconName::Info).mr_3())
+                .suffix(|_| IconName::Info)
                 .placeholder("This input only support [a-zA-Z0-9] characters.")
                 .pattern(regex::Regex::new(r"^[a-zA-Z0-9]*$").unwrap())
                 .cleanable()
         });
         let both_input1 = cx.new_view(|cx| {
             TextInput::new(cx)
-                .prefix(|_| div().child(IconName::Search).ml_3())
-                .suffix(|_| div().child(IconName::Info).mr_3())
+                .prefix(|_| IconName::Search)
+                .suffix(|_| IconName::Info)
                 .cleanable()
                 .placeholder("This input have prefix and suffix.")
         });
 
+        let textarea = cx.new_view(|cx| {
+            let mut input = TextInput::new(cx).multi_line().rows(4);
+            input.set_text(
+                "Hello 世界，this is GPUI component.\nThe TextInput in multi-line mode supports soft wrapping, and scrolls vertically when the text is longer than the visible rows.",
+                cx,
+            );
+            input
+        });
+        cx.subscribe(&textarea, Self::on_input_event).detach();
+
+        let chat_input = cx.new_view(|cx| {
+            TextInput::new(cx)
+                .multi_line()
+                .submit_on_enter(true)
+                .placeholder("Enter to send, type @ to mention, / for commands.")
+                .trigger('@', |query, _| {
+                    ["jason", "john", "kate", "lily", "michael"]
+                        .into_iter()
+                        .filter(|name| name.starts_with(&query.to_lowercase()))
+                        .map(SharedString::from)
+                        .collect()
+                })
+                .trigger('/', |query, _| {
+                    ["help", "invite", "leave", "mute"]
+                        .into_iter()
+                        .filter(|command| command.starts_with(query))
+                        .map(SharedString::from)
+                        .collect()
+                })
+        });
+        cx.subscribe(&chat_input, Self::on_input_event).detach();
+
+        let number_input = cx.new_view(|cx| {
+            let mut input = NumberInput::new(cx).min(0.).max(100.);
+            input.set_placeholder("Number from 0 to 100", cx);
+            input
+        });
+        cx.subscribe(
+            &number_input,
+            |_, _, event: &NumberInputEvent, _| match event {
+                NumberInputEvent::Change(value) => println!("Number changed: {}", value),
+                _ => {}
+            },
+        )
+        .detach();
+
+        let currency_input = cx.new_view(|cx| {
+            let mut input = NumberInput::new(cx)
+                .step(0.5)
+                .precision(2)
+                .thousands_separator(true);
+            input.set_value(1234567.89, cx);
+            input
+        });
+
+        let unit_input = cx.new_view(|cx| {
+            let mut input = TextInput::new(cx)
+                .suffix(|_| "px")
+                .loading(true)
+                .pattern(regex::Regex::new(r"^[0-9]*$").unwrap());
+            input.set_text("16", cx);
+            input
+        });
+
+        let search_input = cx.new_view(SearchInput::new);
+        cx.subscribe(
+            &search_input,
+            |_, _, event: &SearchInputEvent, _| match event {
+                SearchInputEvent::Search(query) => println!("Search: {}", query),
+            },
+        )
+        .detach();
+
+        let tag_input = cx.new_view(|cx| {
+            let mut input = TagInput::new(cx).max_tags(5);
+            input.set_placeholder("Type and press Enter to add a tag", cx);
+            input.set_tags(vec!["rust".into(), "gpui".into()], cx);
+            input
+        });
+        cx.subscribe(&tag_input, |_, _, event: &TagInputEvent, _| match event {
+            TagInputEvent::Change(tags) => println!("Tags: {:?}", tags),
+        })
+        .detach();
+
         let otp_input = cx.new_view(|cx| OtpInput::new(6, cx).masked(true));
         cx.subscribe(&otp_input, |this, _, ev: &InputEvent, cx| match ev {
             InputEvent::Change(text) => {
@@ -124,6 +235,7 @@ impl InputStory {
         Self {
             input1,
             input2,
+            phone_input,
             mash_input: mask_input,
             disabled_input: cx.new_view(|cx| {
                 let mut input = TextInput::new(cx);
@@ -131,6 +243,17 @@ impl InputStory {
                 input.set_disabled(true, cx);
                 input
             }),
+            validated_input: cx.new_view(|cx| {
+                TextInput::new(cx)
+                    .placeholder("Enter your email")
+                    .validator(|text| {
+                        if text.contains('@') {
+                            Ok(())
+                        } else {
+                            Err("Please enter a valid email address.".into())
+                        }
+                    })
+            }),
             large_input: cx.new_view(|cx| TextInput::new(cx).large().placeholder("Large input")),
             small_input: cx.new_view(|cx| {
                 TextInput::new(cx)
@@ -141,6 +264,13 @@ impl InputStory {
             prefix_input1,
             suffix_input1,
             both_input1,
+            unit_input,
+            search_input,
+            tag_input,
+            textarea,
+            chat_input,
+            number_input,
+            currency_input,
             otp_masked: true,
             otp_input,
             otp_value: None,
@@ -208,13 +338,22 @@ impl FocusableCycle for InputStory {
         [
             self.input1.focus_handle(cx),
             self.input2.focus_handle(cx),
+            self.phone_input.focus_handle(cx),
             self.disabled_input.focus_handle(cx),
             self.mash_input.focus_handle(cx),
+            self.validated_input.focus_handle(cx),
             self.prefix_input1.focus_handle(cx),
             self.both_input1.focus_handle(cx),
             self.suffix_input1.focus_handle(cx),
+            self.unit_input.focus_handle(cx),
+            self.search_input.focus_handle(cx),
+            self.tag_input.focus_handle(cx),
             self.large_input.focus_handle(cx),
             self.small_input.focus_handle(cx),
+            self.textarea.focus_handle(cx),
+            self.chat_input.focus_handle(cx),
+            self.number_input.focus_handle(cx),
+            self.currency_input.focus_handle(cx),
             self.otp_input.focus_handle(cx),
         ]
         .to_vec()
@@ -245,12 +384,14 @@ impl Render for InputStory {
                     .child(
                         section("Normal Input", cx)
                             .child(self.input1.clone())
-                            .child(self.input2.clone()),
+                            .child(self.input2.clone())
+                            .child(self.phone_input.clone()),
                     )
                     .child(
                         section("Input State", cx)
                             .child(self.disabled_input.clone())
-                            .child(self.mash_input.clone()),
+                            .child(self.mash_input.clone())
+                            .child(self.validated_input.clone()),
                     ),
             )
             .child(
@@ -261,7 +402,9 @@ impl Render for InputStory {
                         section("Prefix and Suffix", cx)
                             .child(self.prefix_input1.clone())
                             .child(self.both_input1.clone())
-                            .child(self.suffix_input1.clone()),
+                            .child(self.suffix_input1.clone())
+                            .child(self.unit_input.clone())
+                            .child(self.search_input.clone()),
                     )
                     .child(
                         section("Input Size", cx)
@@ -269,6 +412,17 @@ impl Render for InputStory {
                             .child(self.small_input.clone()),
                     ),
             )
+            .child(section("Tag Input", cx).child(self.tag_input.clone()))
+            .child(
+                section("Number Input", cx)
+                    .child(self.number_input.clone())
+                    .child(self.currency_input.clone()),
+            )
+            .child(
+                section("Multi-line Input", cx)
+                    .child(self.textarea.clone())
+                    .child(self.chat_input.clone()),
+            )
             .child(
                 section(
                     h_flex()
@@ -1,38 +1,78 @@
+mod alert_story;
+mod avatar_story;
+mod breadcrumb_story;
 mod button_story;
 mod calendar_story;
+mod chart_story;
+mod carousel_story;
+mod checkbox_story;
 mod dropdown_story;
+mod empty_story;
+mod event_calendar_story;
+mod form_story;
 mod icon_story;
 mod image_story;
 mod input_story;
 mod list_story;
+mod markdown_story;
 mod modal_story;
 mod popup_story;
 mod progress_story;
+mod radio_story;
+mod rating_story;
 mod resizable_story;
 mod scrollable_story;
+mod sidebar_story;
+mod statistic_story;
+mod steps_story;
 mod switch_story;
 mod table_story;
+mod tag_story;
+mod tabs_story;
 mod text_story;
+mod time_picker_story;
 mod tooltip_story;
+mod transfer_story;
+mod tree_story;
 mod webview_story;
 
+pub use alert_story::AlertStory;
+pub use avatar_story::AvatarStory;
+pub use breadcrumb_story::BreadcrumbStory;
 pub use button_story::ButtonStory;
 pub use calendar_story::CalendarStory;
+pub use chart_story::ChartStory;
+pub use carousel_story::CarouselStory;
+pub use checkbox_story::CheckboxStory;
 pub use dropdown_story::DropdownStory;
+pub use empty_story::EmptyStory;
+pub use event_calendar_story::EventCalendarStory;
+pub use form_story::FormStory;
 pub use icon_story::IconStory;
 pub use image_story::ImageStory;
 pub use input_story::InputStory;
 pub use list_story::ListStory;
+pub use markdown_story::MarkdownStory;
 pub use modal_story::ModalStory;
 pub use popup_story::PopupStory;
 pub use progress_story::ProgressStory;
+pub use radio_story::RadioStory;
+pub use rating_story::RatingStory;
 pub use resizable_story::ResizableStory;
 pub use scrollable_story::ScrollableStory;
+pub use sidebar_story::SidebarStory;
 use serde::{Deserialize, Serialize};
+pub use statistic_story::StatisticStory;
+pub use steps_story::StepsStory;
 pub use switch_story::SwitchStory;
 pub use table_story::TableStory;
+pub use tag_story::TagStory;
+pub use tabs_story::TabsStory;
 pub use text_story::TextStory;
+pub use time_picker_story::TimePickerStory;
 pub use tooltip_story::TooltipStory;
+pub use transfer_story::TransferStory;
+pub use tree_story::TreeStory;
 pub use webview_story::WebViewStory;
 
 use gpui::{
@@ -49,14 +89,16 @@ use ui::{
     label::Label,
     notification::Notification,
     popup_menu::PopupMenu,
-    theme::ActiveTheme,
-    v_flex, ContextModal,
+    theme::{ActiveTheme, Breakpoint},
+    v_flex, ContextModal, Responsive as _,
 };
 
 pub fn init(cx: &mut AppContext) {
     input_story::init(cx);
+    modal_story::init(cx);
     dropdown_story::init(cx);
     popup_story::init(cx);
+    tooltip_story::init(cx);
 
     register_panel(cx, "StoryContainer", |_, info, cx| {
         let story_state = match info {
@@ -95,6 +137,10 @@ pub fn section(title: impl IntoElement, cx: &WindowContext) -> Div {
         .border_color(theme.border)
         .flex_wrap()
         .justify_around()
+        // Stack the items in a narrow window.
+        .when_below_breakpoint(Breakpoint::Sm, cx, |this| {
+            this.flex_col().items_start().p_2()
+        })
         .child(div().flex_none().w_full().child(title))
 }
 
@@ -235,22 +281,42 @@ impl StoryState {
         }
 
         match self.story_klass.to_string().as_str() {
+            "AlertStory" => story!(AlertStory),
+            "AvatarStory" => story!(AvatarStory),
+            "BreadcrumbStory" => story!(BreadcrumbStory),
             "ButtonStory" => story!(ButtonStory),
             "CalendarStory" => story!(CalendarStory),
+            "ChartStory" => story!(ChartStory),
+            "CarouselStory" => story!(CarouselStory),
+            "CheckboxStory" => story!(CheckboxStory),
             "DropdownStory" => story!(DropdownStory),
+            "EmptyStory" => story!(EmptyStory),
+            "EventCalendarStory" => story!(EventCalendarStory),
+            "FormStory" => story!(FormStory),
             "IconStory" => story!(IconStory),
             "ImageStory" => story!(ImageStory),
             "InputStory" => story!(InputStory),
             "ListStory" => story!(ListStory),
+            "MarkdownStory" => story!(MarkdownStory),
             "ModalStory" => story!(ModalStory),
             "PopupStory" => story!(PopupStory),
             "ProgressStory" => story!(ProgressStory),
+            "RadioStory" => story!(RadioStory),
+            "RatingStory" => story!(RatingStory),
             "ResizableStory" => story!(ResizableStory),
             "ScrollableStory" => story!(ScrollableStory),
+            "SidebarStory" => story!(SidebarStory),
+            "StatisticStory" => story!(StatisticStory),
+            "StepsStory" => story!(StepsStory),
             "SwitchStory" => story!(SwitchStory),
             "TableStory" => story!(TableStory),
+            "TagStory" => story!(TagStory),
+            "TabsStory" => story!(TabsStory),
             "TextStory" => story!(TextStory),
+            "TimePickerStory" => story!(TimePickerStory),
             "TooltipStory" => story!(TooltipStory),
+            "TransferStory" => story!(TransferStory),
+            "TreeStory" => story!(TreeStory),
             "WebViewStory" => story!(WebViewStory),
             _ => {
                 unreachable!("Invalid story klass: {}", self.story_klass)
@@ -334,6 +400,7 @@ impl Render for StoryContainer {
                         .overflow_scroll()
                         .size_full()
                         .p_4()
+                        .when_below_breakpoint(Breakpoint::Md, cx, |this| this.p_2())
                         .child(story),
                 )
             })
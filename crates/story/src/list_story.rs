@@ -1,16 +1,17 @@
 use core::time;
+use std::ops::Range;
 
 use fake::Fake;
 use gpui::{
     actions, div, px, relative, AnyElement, ElementId, FocusHandle, FocusableView,
-    InteractiveElement, IntoElement, ParentElement, Render, RenderOnce, Styled, Task, Timer, View,
-    ViewContext, VisualContext, WindowContext,
+    InteractiveElement, IntoElement, ParentElement, Render, RenderOnce, SharedString, Styled,
+    Timer, View, ViewContext, VisualContext, WindowContext,
 };
 
 use ui::{
     h_flex,
     label::Label,
-    list::{List, ListDelegate, ListItem},
+    list::{List, ListDelegate, ListEvent, ListItem},
     theme::{hsl, ActiveTheme},
     v_flex,
 };
@@ -42,6 +43,7 @@ struct CompanyListItem {
     ix: usize,
     company: Company,
     selected: bool,
+    highlights: Vec<Range<usize>>,
 }
 
 impl CompanyListItem {
@@ -51,8 +53,15 @@ impl CompanyListItem {
             ix,
             base: ListItem::new(id),
             selected,
+            highlights: vec![],
         }
     }
+
+    /// Highlight the matched ranges of the company name.
+    fn highlights(mut self, highlights: Vec<Range<usize>>) -> Self {
+        self.highlights = highlights;
+        self
+    }
 }
 
 impl RenderOnce for CompanyListItem {
@@ -94,7 +103,11 @@ impl RenderOnce for CompanyListItem {
                             .max_w(px(500.))
                             .overflow_x_hidden()
                             .flex_nowrap()
-                            .child(Label::new(self.company.name.clone()).whitespace_nowrap())
+                            .child(
+                                Label::new(self.company.name.clone())
+                                    .highlights(self.highlights)
+                                    .whitespace_nowrap(),
+                            )
                             .child(
                                 div().text_sm().overflow_x_hidden().child(
                                     Label::new(self.company.industry.clone())
@@ -130,33 +143,62 @@ impl RenderOnce for CompanyListItem {
     }
 }
 
+enum CompanyRow {
+    /// The section header with the first letter of the company names.
+    Section(SharedString, usize),
+    /// The index of the company in the companies.
+    Company(usize),
+}
+
 struct CompanyListDelegate {
     companies: Vec<Company>,
-    matched_companies: Vec<Company>,
+    rows: Vec<CompanyRow>,
     selected_index: usize,
     confirmed_index: Option<usize>,
 }
 
 impl ListDelegate for CompanyListDelegate {
-    type Item = CompanyListItem;
+    type Item = AnyElement;
 
     fn items_count(&self) -> usize {
-        self.matched_companies.len()
+        self.rows.len()
+    }
+
+    fn is_section_header(&self, ix: usize) -> bool {
+        matches!(self.rows.get(ix), Some(CompanyRow::Section(..)))
     }
 
     fn confirmed_index(&self) -> Option<usize> {
         self.confirmed_index
     }
 
-    fn perform_search(&mut self, query: &str, _: &mut ViewContext<List<Self>>) -> Task<()> {
-        self.matched_companies = self
-            .companies
-            .iter()
-            .filter(|company| company.name.to_lowercase().contains(&query.to_lowercase()))
-            .cloned()
-            .collect();
+    fn item_text(&self, ix: usize) -> Option<SharedString> {
+        match self.rows.get(ix)? {
+            CompanyRow::Company(company_ix) => {
+                Some(self.companies[*company_ix].name.clone().into())
+            }
+            CompanyRow::Section(..) => None,
+        }
+    }
 
-        Task::Ready(Some(()))
+    fn render_matched_item(
+        &self,
+        ix: usize,
+        ranges: &[Range<usize>],
+        cx: &mut ViewContext<List<Self>>,
+    ) -> Option<Self::Item> {
+        let selected = ix == self.selected_index || Some(ix) == self.confirmed_index;
+        match self.rows.get(ix)? {
+            CompanyRow::Company(company_ix) => {
+                let company = self.companies.get(*company_ix)?;
+                Some(
+                    CompanyListItem::new(ix, company.clone(), *company_ix, selected)
+                        .highlights(ranges.to_vec())
+                        .into_any_element(),
+                )
+            }
+            CompanyRow::Section(..) => self.render_item(ix, cx),
+        }
     }
 
     fn confirm(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {
@@ -184,7 +226,6 @@ impl ListDelegate for CompanyListDelegate {
             })
             .collect::<Vec<_>>();
 
-
         let element = v_flex()
             .p_4()
             .child(
@@ -204,19 +245,78 @@ impl ListDelegate for CompanyListDelegate {
         }
     }
 
-    fn render_item(&self, ix: usize, _cx: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
+    fn render_item(&self, ix: usize, cx: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
         let selected = ix == self.selected_index || Some(ix) == self.confirmed_index;
-        if let Some(company) = self.matched_companies.get(ix) {
-            return Some(CompanyListItem::new(ix, company.clone(), ix, selected));
+        match self.rows.get(ix)? {
+            CompanyRow::Section(title, count) => Some(
+                v_flex()
+                    .px_3()
+                    .py_1()
+                    .gap_1()
+                    .bg(cx.theme().list_head)
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .child(Label::new(title.clone()))
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(format!("{} companies", count)),
+                    )
+                    .into_any_element(),
+            ),
+            CompanyRow::Company(company_ix) => {
+                let company = self.companies.get(*company_ix)?;
+                Some(
+                    CompanyListItem::new(ix, company.clone(), *company_ix, selected)
+                        .into_any_element(),
+                )
+            }
         }
-
-        None
     }
 }
 
 impl CompanyListDelegate {
+    fn new(mut companies: Vec<Company>) -> Self {
+        companies.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let mut this = Self {
+            companies,
+            rows: vec![],
+            selected_index: 0,
+            confirmed_index: None,
+        };
+        this.update_rows();
+        this
+    }
+
+    /// Group the companies by the first letter of their names.
+    fn update_rows(&mut self) {
+        self.rows.clear();
+
+        let mut section_ix = 0;
+        let mut last_letter = None;
+        for (ix, company) in self.companies.iter().enumerate() {
+            let letter = company.name.chars().next().map(|c| c.to_ascii_uppercase());
+            if letter != last_letter {
+                last_letter = letter;
+                section_ix = self.rows.len();
+                let title = letter.map(String::from).unwrap_or_default();
+                self.rows.push(CompanyRow::Section(title.into(), 0));
+            }
+
+            if let Some(CompanyRow::Section(_, count)) = self.rows.get_mut(section_ix) {
+                *count += 1;
+            }
+            self.rows.push(CompanyRow::Company(ix));
+        }
+    }
+
     fn selected_company(&self) -> Option<Company> {
-        self.companies.get(self.selected_index).cloned()
+        match self.rows.get(self.selected_index)? {
+            CompanyRow::Company(ix) => self.companies.get(*ix).cloned(),
+            CompanyRow::Section(..) => None,
+        }
     }
 }
 
@@ -250,17 +350,15 @@ impl ListStory {
             .map(|_| random_company())
             .collect::<Vec<Company>>();
 
-        let company_list = cx.new_view(|cx| {
-            List::new(
-                CompanyListDelegate {
-                    matched_companies: companies.clone(),
-                    companies,
-                    selected_index: 0,
-                    confirmed_index: None,
-                },
-                cx,
-            )
-        });
+        let company_list = cx.new_view(|cx| List::new(CompanyListDelegate::new(companies), cx));
+
+        cx.subscribe(&company_list, |this, _, event: &ListEvent, cx| {
+            if let ListEvent::Cancel = event {
+                this.selected_company = None;
+                cx.notify();
+            }
+        })
+        .detach();
 
         // Spawn a background to random refresh the list
         cx.spawn(move |this, mut cx| async move {
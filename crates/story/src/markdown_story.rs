@@ -0,0 +1,128 @@
+use gpui::{
+    div, IntoElement, ParentElement as _, Render, SharedString, Styled as _, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+use ui::{
+    markdown::{Markdown, ParsedMarkdown},
+    theme::ActiveTheme as _,
+    v_flex,
+};
+
+use crate::section;
+
+const RELEASE_NOTES: &str = r#"# Release Notes
+
+This release adds the **Markdown** element, with *emphasis*, ~~strikethrough~~ and `inline code`.
+See the [CommonMark spec](https://commonmark.org) for the syntax.
+
+## Changes
+
+1. New components:
+   - Carousel
+   - Image viewer
+2. Fixed the scrollbar flickering.
+
+- [x] Headings and lists
+- [ ] Syntax highlighting
+
+> The block quote for the notes,
+> in the muted color.
+
+```rust
+fn main() {
+    println!("Hello, world!");
+}
+```
+
+| Component | Status | Since |
+| :-------- | :----: | ----: |
+| Markdown  | New    | 0.2.0 |
+| Carousel  | Stable | 0.1.0 |
+
+---
+
+Thanks to all the contributors.
+"#;
+
+pub struct MarkdownStory {
+    focus_handle: gpui::FocusHandle,
+    clicked_link: Option<SharedString>,
+    release_notes: ParsedMarkdown,
+    default_link: ParsedMarkdown,
+}
+
+impl super::Story for MarkdownStory {
+    fn title() -> &'static str {
+        "Markdown"
+    }
+
+    fn description() -> &'static str {
+        "Render the CommonMark text with the active theme."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl MarkdownStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            clicked_link: None,
+            release_notes: ParsedMarkdown::new(RELEASE_NOTES),
+            default_link: ParsedMarkdown::new(
+                "Links open in the browser by default, e.g.: [GPUI](https://www.gpui.rs).",
+            ),
+        }
+    }
+}
+
+impl gpui::FocusableView for MarkdownStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for MarkdownStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+
+        v_flex()
+            .gap_6()
+            .child(
+                section("Release Notes", cx).child(
+                    v_flex()
+                        .w_full()
+                        .gap_3()
+                        .child(
+                            Markdown::new("release-notes", &self.release_notes).on_link_click(
+                                move |url, cx| {
+                                    view.update(cx, |this, cx| {
+                                        this.clicked_link = Some(url.clone());
+                                        cx.notify();
+                                    })
+                                },
+                            ),
+                        )
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(format!(
+                                    "Clicked link: {}",
+                                    self.clicked_link.as_deref().unwrap_or("-")
+                                )),
+                        ),
+                ),
+            )
+            .child(
+                section("Open in Browser", cx)
+                    .child(Markdown::new("default-link", &self.default_link)),
+            )
+    }
+}
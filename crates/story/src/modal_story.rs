@@ -2,25 +2,35 @@ use std::{sync::Arc, time::Duration};
 
 use fake::Fake;
 use gpui::{
-    actions, div, prelude::FluentBuilder as _, px, FocusHandle, FocusableView,
-    InteractiveElement as _, IntoElement, ParentElement, Render, SharedString, Styled, Task, Timer,
-    View, ViewContext, VisualContext as _, WeakView, WindowContext,
+    actions, div, prelude::FluentBuilder as _, px, AppContext, FocusHandle, FocusableView,
+    InteractiveElement as _, IntoElement, KeyBinding, ParentElement, Render, SharedString, Styled,
+    Task, Timer, View, ViewContext, VisualContext as _, WeakView, WindowContext,
 };
 
 use ui::{
     button::{Button, ButtonStyle, ButtonStyled as _},
     checkbox::Checkbox,
+    command_palette::{Command, CommandPalette},
     date_picker::DatePicker,
+    dialog::Dialog,
     dropdown::Dropdown,
     h_flex,
     input::TextInput,
+    link::Link,
     list::{List, ListDelegate, ListItem},
-    notification::{Notification, NotificationType},
+    notification::{self, Notification, NotificationType},
     theme::ActiveTheme as _,
-    v_flex, ContextModal as _, Icon, IconName, Placement,
+    v_flex, ContextModal as _, Icon, IconName, Placement, Root, Sizable as _,
 };
 
-actions!(modal_story, [TestAction]);
+actions!(modal_story, [TestAction, ToggleCommandPalette]);
+
+pub fn init(cx: &mut AppContext) {
+    #[cfg(target_os = "macos")]
+    cx.bind_keys([KeyBinding::new("cmd-k", ToggleCommandPalette, None)]);
+    #[cfg(not(target_os = "macos"))]
+    cx.bind_keys([KeyBinding::new("ctrl-k", ToggleCommandPalette, None)]);
+}
 
 pub struct ListItemDeletegate {
     story: WeakView<ModalStory>,
@@ -97,6 +107,14 @@ impl ListDelegate for ListItemDeletegate {
         }
     }
 
+    fn move_item(&mut self, from: usize, to: usize, _: &mut ViewContext<List<Self>>) {
+        let item = self.matches.remove(from);
+        self.matches.insert(to, item);
+        if self.matches.len() == self.items.len() {
+            self.items = self.matches.clone();
+        }
+    }
+
     fn render_empty(&self, cx: &mut ViewContext<List<Self>>) -> impl IntoElement {
         v_flex()
             .size_full()
@@ -151,9 +169,13 @@ pub struct ModalStory {
     input2: View<TextInput>,
     date_picker: View<DatePicker>,
     dropdown: View<Dropdown<Vec<String>>>,
+    command_palette: View<CommandPalette>,
     modal_overlay: bool,
+    modal_overlay_closable: bool,
+    modal_keyboard: bool,
     model_show_close: bool,
     model_padding: bool,
+    notification_stacked: bool,
 }
 
 impl super::Story for ModalStory {
@@ -240,7 +262,7 @@ impl ModalStory {
             matches: items.clone(),
         };
         let list = cx.new_view(|cx| {
-            let mut list = List::new(delegate, cx);
+            let mut list = List::new(delegate, cx).reorderable(true);
             list.focus(cx);
             list
         });
@@ -262,6 +284,39 @@ impl ModalStory {
             )
         });
 
+        let story = cx.view().downgrade();
+        let command_palette = cx.new_view(|cx| {
+            CommandPalette::new(
+                vec![
+                    Command::new("test-action", "Test Dispatch Action")
+                        .icon(IconName::Check)
+                        .action(Box::new(TestAction)),
+                    Command::new("open-drawer", "Open Drawer").icon(IconName::Menu),
+                    Command::new("open-modal", "Open Modal").icon(IconName::Inbox),
+                    Command::new("nested-modal", "Open Nested Modal"),
+                    Command::new("notify", "Show Notification").icon(IconName::Bell),
+                ],
+                cx,
+            )
+            .on_execute(move |command, cx| {
+                let Some(story) = story.upgrade() else {
+                    return;
+                };
+                match command.id().as_ref() {
+                    "open-drawer" => {
+                        story.update(cx, |this, cx| this.open_drawer_at(Placement::Left, cx))
+                    }
+                    "open-modal" => story.update(cx, |this, cx| this.show_modal(cx)),
+                    "nested-modal" => Self::open_nested_modal(cx),
+                    "notify" => cx.push_notification(SharedString::from(format!(
+                        "Executed: {}",
+                        command.label()
+                    ))),
+                    _ => {}
+                }
+            })
+        });
+
         Self {
             focus_handle: cx.focus_handle(),
             drawer_placement: None,
@@ -271,9 +326,13 @@ impl ModalStory {
             input2,
             date_picker,
             dropdown,
+            command_palette,
             modal_overlay: true,
+            modal_overlay_closable: true,
+            modal_keyboard: true,
             model_show_close: true,
             model_padding: true,
+            notification_stacked: false,
         }
     }
 
@@ -330,6 +389,8 @@ impl ModalStory {
 
     fn show_modal(&mut self, cx: &mut ViewContext<Self>) {
         let overlay = self.modal_overlay;
+        let overlay_closable = self.modal_overlay_closable;
+        let keyboard = self.modal_keyboard;
         let modal_show_close = self.model_show_close;
         let modal_padding = self.model_padding;
         let input1 = self.input1.clone();
@@ -341,6 +402,25 @@ impl ModalStory {
             modal
                 .title("Form Modal")
                 .overlay(overlay)
+                .overlay_closable(overlay_closable)
+                .keyboard(keyboard)
+                .on_before_close({
+                    let input1 = input1.clone();
+                    move |cx| {
+                        if input1.read(cx).text().is_empty() {
+                            return Task::ready(true);
+                        }
+
+                        let discard = Dialog::confirm(
+                            "Unsaved changes",
+                            "You have unsaved changes, discard them?",
+                        )
+                        .ok_text("Discard")
+                        .danger(true)
+                        .prompt(cx);
+                        cx.foreground_executor().spawn(discard)
+                    }
+                })
                 .show_close(modal_show_close)
                 .when(!modal_padding, |this| this.p(px(0.)))
                 .child(
@@ -385,6 +465,16 @@ impl ModalStory {
                                 });
                             },
                         ))
+                        .child(Button::new("modal-drawer").label("Open Drawer").on_click(
+                            move |_, cx| {
+                                cx.open_drawer(move |drawer, _| {
+                                    drawer
+                                        .margin_top(px(33.))
+                                        .title("Drawer above Modal")
+                                        .child("This drawer is stacked above the modal.")
+                                });
+                            },
+                        ))
                         .child(
                             Button::new("cancel")
                                 .label("Cancel")
@@ -398,9 +488,105 @@ impl ModalStory {
         self.input1.focus_handle(cx).focus(cx);
     }
 
+    fn open_nested_modal(cx: &mut WindowContext) {
+        let layer = cx.modal_stack().len() + 1;
+        cx.open_modal(move |modal, _| {
+            modal
+                .title(format!("Layer {}", layer))
+                .child(format!(
+                    "This modal is the layer {} of the stack, press Escape to close it.",
+                    layer
+                ))
+                .footer(
+                    h_flex()
+                        .gap_2()
+                        .child(
+                            Button::new("nested-open")
+                                .primary()
+                                .label("Open Nested Modal")
+                                .on_click(|_, cx| Self::open_nested_modal(cx)),
+                        )
+                        .when(layer > 1, |this| {
+                            this.child(
+                                Button::new("nested-close-below")
+                                    .label("Close Layer Below")
+                                    .on_click(|_, cx| {
+                                        let stack = cx.modal_stack();
+                                        if let Some(ix) = stack.focused_layer(cx) {
+                                            stack.close(ix.saturating_sub(1), cx);
+                                        }
+                                    }),
+                            )
+                        })
+                        .child(
+                            Button::new("nested-close-all")
+                                .label("Close All")
+                                .on_click(|_, cx| cx.close_all_modals()),
+                        ),
+                )
+        });
+    }
+
+    fn set_notification_placement(
+        &mut self,
+        placement: notification::Placement,
+        cx: &mut ViewContext<Self>,
+    ) {
+        Root::update(cx, move |root, cx| {
+            root.notification
+                .update(cx, |list, cx| list.set_placement(placement, cx));
+        });
+    }
+
+    fn toggle_notification_stacked(&mut self, cx: &mut ViewContext<Self>) {
+        self.notification_stacked = !self.notification_stacked;
+        let stacked = self.notification_stacked;
+        Root::update(cx, move |root, cx| {
+            root.notification
+                .update(cx, |list, cx| list.set_stacked(stacked, cx));
+        });
+        cx.notify();
+    }
+
+    fn show_progress_notification(&mut self, cx: &mut ViewContext<Self>) {
+        let handle = cx.push_notification(Notification::progress("Uploading... 0%").value(0.));
+
+        cx.spawn(|_, mut cx| async move {
+            for step in 1..=10 {
+                Timer::after(Duration::from_millis(300)).await;
+                let value = step as f32 * 10.;
+
+                _ = cx.update(|cx| {
+                    handle.update(cx, move |note, _| {
+                        if value < 100. {
+                            note.set_message(format!("Uploading... {}%", value));
+                            note.set_value(Some(value));
+                        } else {
+                            note.set_message("The file has been uploaded.");
+                            note.set_type(NotificationType::Success);
+                            note.set_loading(false);
+                            note.set_value(None);
+                            note.set_autohide(true);
+                        }
+                    })
+                });
+            }
+        })
+        .detach();
+    }
+
     fn on_action_test_action(&mut self, _: &TestAction, cx: &mut ViewContext<Self>) {
         cx.push_notification("You have clicked the TestAction.");
     }
+
+    fn on_action_toggle_command_palette(
+        &mut self,
+        _: &ToggleCommandPalette,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.command_palette
+            .update(cx, |palette, cx| palette.open(cx));
+    }
 }
 
 impl FocusableView for ModalStory {
@@ -415,6 +601,7 @@ impl Render for ModalStory {
             .id("modal-story")
             .track_focus(&self.focus_handle)
             .on_action(cx.listener(Self::on_action_test_action))
+            .on_action(cx.listener(Self::on_action_toggle_command_palette))
             .size_full()
             .child(
                 v_flex()
@@ -432,6 +619,24 @@ impl Render for ModalStory {
                                         cx.notify();
                                     })),
                             )
+                            .child(
+                                Checkbox::new("modal-overlay-closable")
+                                    .label("Overlay Closable")
+                                    .checked(self.modal_overlay_closable)
+                                    .on_click(cx.listener(|view, _, cx| {
+                                        view.modal_overlay_closable = !view.modal_overlay_closable;
+                                        cx.notify();
+                                    })),
+                            )
+                            .child(
+                                Checkbox::new("modal-keyboard")
+                                    .label("Escape to Close")
+                                    .checked(self.modal_keyboard)
+                                    .on_click(cx.listener(|view, _, cx| {
+                                        view.modal_keyboard = !view.modal_keyboard;
+                                        cx.notify();
+                                    })),
+                            )
                             .child(
                                 Checkbox::new("modal-show-close")
                                     .label("Model Close Button")
@@ -507,9 +712,111 @@ impl Render for ModalStory {
                         )
                     })
                     .child(
-                        Button::new("show-modal")
-                            .label("Open Modal...")
-                            .on_click(cx.listener(|this, _, cx| this.show_modal(cx))),
+                        h_flex()
+                            .gap_3()
+                            .child(
+                                Button::new("show-modal")
+                                    .label("Open Modal...")
+                                    .on_click(cx.listener(|this, _, cx| this.show_modal(cx))),
+                            )
+                            .child(
+                                Button::new("show-nested-modal")
+                                    .label("Nested Modal")
+                                    .on_click(|_, cx| Self::open_nested_modal(cx)),
+                            )
+                            .child(
+                                Button::new("show-command-palette")
+                                    .label("Command Palette")
+                                    .on_click(cx.listener(|this, _, cx| {
+                                        this.command_palette
+                                            .update(cx, |palette, cx| palette.open(cx))
+                                    })),
+                            )
+                            .child(
+                                Button::new("show-confirm-dialog")
+                                    .label("Confirm Dialog")
+                                    .on_click(|_, cx| {
+                                        Dialog::confirm(
+                                            "Save changes",
+                                            "Do you want to save the changes before closing?",
+                                        )
+                                        .on_result(|ok, cx| {
+                                            cx.push_notification(if ok {
+                                                "The changes have been saved."
+                                            } else {
+                                                "The changes have been discarded."
+                                            });
+                                        })
+                                        .open(cx)
+                                    }),
+                            )
+                            .child(
+                                Button::new("show-danger-dialog")
+                                    .danger()
+                                    .label("Delete Item")
+                                    .on_click(cx.listener(|_, _, cx| {
+                                        let result = Dialog::confirm(
+                                            "Delete item",
+                                            "This item will be deleted permanently, this action cannot be undone.",
+                                        )
+                                        .ok_text("Delete")
+                                        .danger(true)
+                                        .prompt(cx);
+
+                                        cx.spawn(|_, mut cx| async move {
+                                            if result.await {
+                                                _ = cx.update(|cx| {
+                                                    cx.push_notification("The item has been deleted.");
+                                                });
+                                            }
+                                        })
+                                        .detach();
+                                    })),
+                            )
+                            .child(
+                                Button::new("show-alert-dialog")
+                                    .label("Alert Dialog")
+                                    .on_click(|_, cx| {
+                                        Dialog::alert(
+                                            "Update available",
+                                            "A new version is available, restart the app to update.",
+                                        )
+                                        .open(cx)
+                                    }),
+                            ),
+                    )
+                    .child(
+                        h_flex()
+                            .gap_3()
+                            .child("Notification Placement")
+                            .children(
+                                [
+                                    ("Top Left", notification::Placement::TopLeft),
+                                    ("Top Center", notification::Placement::TopCenter),
+                                    ("Top Right", notification::Placement::TopRight),
+                                    ("Bottom Left", notification::Placement::BottomLeft),
+                                    ("Bottom Center", notification::Placement::BottomCenter),
+                                    ("Bottom Right", notification::Placement::BottomRight),
+                                ]
+                                .into_iter()
+                                .enumerate()
+                                .map(|(ix, (label, placement))| {
+                                    Button::new(("notification-placement", ix))
+                                        .label(label)
+                                        .small()
+                                        .on_click(cx.listener(move |view, _, cx| {
+                                            view.set_notification_placement(placement, cx)
+                                        }))
+                                }),
+                            )
+                            .child(
+                                Checkbox::new("notification-stacked")
+                                    .label("Stacked")
+                                    .checked(self.notification_stacked)
+                                    .on_click(cx.listener(|view, _, cx| {
+                                        view.toggle_notification_stacked(cx)
+                                    })),
+                            ),
                     )
                     .child(
                         h_flex()
@@ -520,27 +827,31 @@ impl Render for ModalStory {
                                     .on_click(cx.listener(|_, _, cx| {
                                         cx.push_notification(
                                             "You have been saved file successfully.",
-                                        )
+                                        );
                                     })),
                             )
                             .child(
                                 Button::new("show-notify-error")
                                     .label("Error Notify...")
                                     .on_click(cx.listener(|_, _, cx| {
-                                        cx.push_notification((
-                                        NotificationType::Error,
-                                        "There have some error occurred. Please try again later.",
-                                    ))
+                                        cx.push_notification(
+                                            Notification::error(
+                                                "There have some error occurred. Please try again later.",
+                                            )
+                                            .duration(Duration::from_secs(15)),
+                                        );
                                     })),
                             )
                             .child(
                                 Button::new("show-notify-success")
                                     .label("Success Notify...")
                                     .on_click(cx.listener(|_, _, cx| {
-                                        cx.push_notification((
-                                            NotificationType::Success,
-                                            "We have received your payment successfully.",
-                                        ))
+                                        cx.push_notification(
+                                            Notification::success(
+                                                "We have received your payment successfully.",
+                                            )
+                                            .duration(Duration::from_secs(2)),
+                                        );
                                     })),
                             )
                             .child(
@@ -550,7 +861,57 @@ impl Render for ModalStory {
                                         struct WarningNotification;
                                         cx.push_notification(Notification::warning(
                                         "The network is not stable, please check your connection.",
-                                    ).id1::<WarningNotification>("test"))
+                                    ).id1::<WarningNotification>("test"));
+                                    })),
+                            )
+                            .child(
+                                Button::new("show-notify-actions")
+                                    .label("Notification with Actions")
+                                    .on_click(cx.listener(|_, _, cx| {
+                                        cx.push_notification(
+                                            Notification::new("The file has been deleted.")
+                                                .action("Undo", |_, cx| {
+                                                    cx.push_notification("The file has been restored.");
+                                                })
+                                                .action("Dismiss", |_, _| {}),
+                                        );
+                                    })),
+                            )
+                            .child(
+                                Button::new("show-notify-content")
+                                    .label("Notification with Element")
+                                    .on_click(cx.listener(|_, _, cx| {
+                                        cx.push_notification(
+                                            Notification::new("A new version is available.")
+                                                .title("Update Available")
+                                                .content_element(|cx| {
+                                                    v_flex()
+                                                        .gap_1()
+                                                        .text_sm()
+                                                        .child("A new version is available, run:")
+                                                        .child(
+                                                            div()
+                                                                .px_1()
+                                                                .rounded_md()
+                                                                .bg(cx.theme().muted)
+                                                                .font_family(cx.theme().mono_font_family.clone())
+                                                                .child("cargo update -p ui"),
+                                                        )
+                                                        .child(
+                                                            Link::new("release-notes")
+                                                                .href("https://github.com/huacnlee/gpui-component")
+                                                                .child("Release Notes"),
+                                                        )
+                                                        .into_any_element()
+                                                }),
+                                        );
+                                    })),
+                            )
+                            .child(
+                                Button::new("show-notify-progress")
+                                    .label("Progress Notification")
+                                    .on_click(cx.listener(|view, _, cx| {
+                                        view.show_progress_notification(cx)
                                     })),
                             )
                             .child(
@@ -573,8 +934,16 @@ impl Render for ModalStory {
                                                     Some("Notification clicked".into());
                                                 cx.notify();
                                             }),
+                                        )
+                                        .on_close(
+                                            cx.listener(|view, reason, cx| {
+                                                view.selected_value = Some(
+                                                    format!("Notification closed: {:?}", reason).into(),
+                                                );
+                                                cx.notify();
+                                            }),
                                         ),
-                                    )
+                                    );
                                     })),
                             ),
                     ),
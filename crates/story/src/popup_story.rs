@@ -1,8 +1,8 @@
 use gpui::{
     actions, div, impl_actions, px, AnchorCorner, AppContext, DismissEvent, Element, EventEmitter,
     FocusHandle, FocusableView, InteractiveElement, IntoElement, KeyBinding, MouseButton,
-    MouseDownEvent, ParentElement as _, Render, Styled as _, View, ViewContext, VisualContext,
-    WindowContext,
+    MouseDownEvent, ParentElement as _, Render, SharedString, Styled as _, View, ViewContext,
+    VisualContext, WindowContext,
 };
 use serde::Deserialize;
 use ui::{
@@ -11,20 +11,24 @@ use ui::{
     divider::Divider,
     h_flex,
     input::TextInput,
+    popconfirm::Popconfirm,
     popover::{Popover, PopoverContent},
     popup_menu::PopupMenuExt,
     switch::Switch,
-    v_flex, ContextModal, IconName, Sizable,
+    v_flex, ContextModal, IconName, Placement, Sizable,
 };
 
 #[derive(Clone, PartialEq, Deserialize)]
 struct Info(usize);
 
+#[derive(Clone, PartialEq, Deserialize)]
+struct SelectAlign(usize);
+
 actions!(
     popover_story,
     [Copy, Paste, Cut, SearchAll, ToggleWindowMode]
 );
-impl_actions!(popover_story, [Info]);
+impl_actions!(popover_story, [Info, SelectAlign]);
 
 pub fn init(cx: &mut AppContext) {
     cx.bind_keys([
@@ -77,6 +81,7 @@ pub struct PopupStory {
     form: View<Form>,
     message: String,
     window_mode: bool,
+    align: usize,
 }
 
 impl super::Story for PopupStory {
@@ -105,6 +110,7 @@ impl PopupStory {
             focus_handle: cx.focus_handle(),
             message: "".to_string(),
             window_mode: false,
+            align: 0,
         }
     }
 
@@ -128,6 +134,10 @@ impl PopupStory {
         self.window_mode = !self.window_mode;
         cx.notify()
     }
+    fn on_select_align(&mut self, align: &SelectAlign, cx: &mut ViewContext<Self>) {
+        self.align = align.0;
+        cx.notify()
+    }
     fn on_action_info(&mut self, info: &Info, cx: &mut ViewContext<Self>) {
         self.message = format!("You have clicked info: {}", info.0);
         cx.notify()
@@ -144,6 +154,7 @@ impl Render for PopupStory {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let form = self.form.clone();
         let window_mode = self.window_mode;
+        let align = self.align;
 
         v_flex()
             .track_focus(&self.focus_handle)
@@ -153,6 +164,7 @@ impl Render for PopupStory {
             .on_action(cx.listener(Self::on_search_all))
             .on_action(cx.listener(Self::on_toggle_window_mode))
             .on_action(cx.listener(Self::on_action_info))
+            .on_action(cx.listener(Self::on_select_align))
             .p_4()
             .mb_5()
             .size_full()
@@ -166,6 +178,7 @@ impl Render for PopupStory {
                         .menu("Cut", Box::new(Cut))
                         .menu("Copy", Box::new(Copy))
                         .menu("Paste", Box::new(Paste))
+                        .menu_with_disabled("Paste as Plain Text", Box::new(Paste), true)
                         .separator()
                         .separator()
                         .submenu("Settings", cx, move |menu, _| {
@@ -246,6 +259,69 @@ impl Render for PopupStory {
                             }),
                     ),
             )
+            .child(
+                h_flex().gap_3().children(
+                    [
+                        Placement::Top,
+                        Placement::Bottom,
+                        Placement::Left,
+                        Placement::Right,
+                    ]
+                    .into_iter()
+                    .map(|placement| {
+                        Popover::new(SharedString::from(format!("placement-{}", placement)))
+                            .placement(placement)
+                            .arrow(true)
+                            .trigger(
+                                Button::new(SharedString::from(format!(
+                                    "placement-trigger-{}",
+                                    placement
+                                )))
+                                .label(placement.to_string()),
+                            )
+                            .content(move |cx| {
+                                cx.new_view(|cx| {
+                                    PopoverContent::new(cx, move |_| {
+                                        v_flex()
+                                            .w_64()
+                                            .child(format!(
+                                                "This Popover prefers the {} side, \
+                                                it flips or shifts to fit the window.",
+                                                placement
+                                            ))
+                                            .into_any()
+                                    })
+                                })
+                            })
+                    }),
+                ),
+            )
+            .child(
+                h_flex()
+                    .gap_3()
+                    .child(
+                        Popconfirm::new("popconfirm-delete")
+                            .trigger(Button::new("popconfirm-delete").danger().label("Delete"))
+                            .title("Delete this item?")
+                            .description("This action cannot be undone.")
+                            .ok_text("Delete")
+                            .danger(true)
+                            .on_confirm(|cx| {
+                                cx.push_notification("The item has been deleted.");
+                            }),
+                    )
+                    .child(
+                        Popconfirm::new("popconfirm-archive")
+                            .placement(Placement::Bottom)
+                            .trigger(Button::new("popconfirm-archive").label("Archive"))
+                            .on_confirm(|cx| {
+                                cx.push_notification("The item has been archived.");
+                            })
+                            .on_cancel(|cx| {
+                                cx.push_notification("Canceled.");
+                            }),
+                    ),
+            )
             .child(
                 h_flex()
                     .gap_3()
@@ -265,6 +341,22 @@ impl Render for PopupStory {
                                         Box::new(ToggleWindowMode),
                                     )
                                     .separator()
+                                    .menu_with_radio(
+                                        "Align Left",
+                                        align == 0,
+                                        Box::new(SelectAlign(0)),
+                                    )
+                                    .menu_with_radio(
+                                        "Align Center",
+                                        align == 1,
+                                        Box::new(SelectAlign(1)),
+                                    )
+                                    .menu_with_radio(
+                                        "Align Right",
+                                        align == 2,
+                                        Box::new(SelectAlign(2)),
+                                    )
+                                    .separator()
                                     .submenu("Links", cx, |menu, _| {
                                         menu.link_with_icon(
                                             "GitHub Repository",
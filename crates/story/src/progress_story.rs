@@ -7,9 +7,11 @@ use ui::{
     divider::Divider,
     h_flex,
     indicator::Indicator,
-    progress::Progress,
-    skeleton::Skeleton,
-    slider::{Slider, SliderEvent},
+    progress::{CircularProgress, Progress, ProgressStatus},
+    skeleton::{Skeleton, SkeletonList, SkeletonTable},
+    spinner::{LoadingOverlay, Spinner},
+    theme::ActiveTheme as _,
+    slider::{RangeSlider, RangeSliderEvent, Slider, SliderEvent},
     v_flex, IconName, Sizable,
 };
 
@@ -20,6 +22,9 @@ pub struct ProgressStory {
     slider1_value: f32,
     slider2: View<Slider>,
     slider2_value: f32,
+    range_slider: View<RangeSlider>,
+    range_value: (f32, f32),
+    loading: bool,
 }
 
 impl super::Story for ProgressStory {
@@ -38,8 +43,8 @@ impl ProgressStory {
     }
 
     fn new(cx: &mut ViewContext<Self>) -> Self {
-        let slider1 = cx.new_view(|_| {
-            Slider::horizontal()
+        let slider1 = cx.new_view(|cx| {
+            Slider::horizontal(cx)
                 .min(-255.)
                 .max(255.)
                 .default_value(15.)
@@ -53,7 +58,13 @@ impl ProgressStory {
         })
         .detach();
 
-        let slider2 = cx.new_view(|_| Slider::horizontal().min(0.).max(5.).step(1.0));
+        let slider2 = cx.new_view(|cx| {
+            Slider::horizontal(cx)
+                .min(0.)
+                .max(5.)
+                .step(1.0)
+                .ticks(true)
+        });
         cx.subscribe(&slider2, |this, _, event: &SliderEvent, cx| match event {
             SliderEvent::Change(value) => {
                 this.slider2_value = *value;
@@ -62,6 +73,24 @@ impl ProgressStory {
         })
         .detach();
 
+        let range_slider = cx.new_view(|cx| {
+            RangeSlider::horizontal(cx)
+                .min(0.)
+                .max(1000.)
+                .step(50.)
+                .default_range(200., 600.)
+        });
+        cx.subscribe(
+            &range_slider,
+            |this, _, event: &RangeSliderEvent, cx| match event {
+                RangeSliderEvent::Change(start, end) => {
+                    this.range_value = (*start, *end);
+                    cx.notify();
+                }
+            },
+        )
+        .detach();
+
         Self {
             focus_handle: cx.focus_handle(),
             value: 50.,
@@ -69,6 +98,9 @@ impl ProgressStory {
             slider2_value: 1.,
             slider1,
             slider2,
+            range_slider,
+            range_value: (200., 600.),
+            loading: true,
         }
     }
 
@@ -121,6 +153,44 @@ impl Render for ProgressStory {
                     ),
             )
             .child(div().w_1_2().child(Progress::new().value(self.value)))
+            .child(
+                v_flex()
+                    .w_1_2()
+                    .gap_3()
+                    .child(Progress::new().small().indeterminate(true))
+                    .child(
+                        Progress::new()
+                            .value(self.value)
+                            .status(ProgressStatus::Success),
+                    )
+                    .child(
+                        Progress::new()
+                            .large()
+                            .value(self.value)
+                            .status(ProgressStatus::Error),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .gap_x_3()
+                    .items_center()
+                    .child(CircularProgress::new().small().value(self.value))
+                    .child(CircularProgress::new().value(self.value))
+                    .child(
+                        CircularProgress::new()
+                            .large()
+                            .value(self.value)
+                            .status(ProgressStatus::Success),
+                    )
+                    .child(
+                        Button::new("button-uploading").child(
+                            h_flex()
+                                .gap_2()
+                                .child(CircularProgress::new().xsmall().value(self.value))
+                                .child("Uploading"),
+                        ),
+                    ),
+            )
             .child(
                 h_flex()
                     .gap_x_2()
@@ -153,6 +223,42 @@ impl Render for ProgressStory {
                     )
                     .child(Indicator::new().with_size(px(64.))),
             )
+            .child(
+                h_flex()
+                    .gap_x_2()
+                    .child(Spinner::new().xsmall())
+                    .child(Spinner::new().small())
+                    .child(Spinner::new())
+                    .child(Spinner::new().large()),
+            )
+            .child(
+                h_flex()
+                    .gap_x_3()
+                    .items_start()
+                    .child(
+                        LoadingOverlay::new(self.loading)
+                            .label("Loading...")
+                            .w(px(300.))
+                            .rounded_md()
+                            .border_1()
+                            .border_color(cx.theme().border)
+                            .child(
+                                v_flex()
+                                    .p_4()
+                                    .gap_2()
+                                    .child("The content is blocked while loading.")
+                                    .child(Button::new("button-overlay-inner").label("Click me")),
+                            ),
+                    )
+                    .child(
+                        Button::new("button-toggle-loading")
+                            .label("Toggle loading")
+                            .on_click(cx.listener(|this, _, cx| {
+                                this.loading = !this.loading;
+                                cx.notify();
+                            })),
+                    ),
+            )
             .child(Divider::horizontal().mt_10().label("Slider"))
             .child(self.slider1.clone())
             .child(format!("Slider 1: {}", self.slider1_value))
@@ -163,17 +269,30 @@ impl Render for ProgressStory {
                     .child(self.slider2.clone())
                     .child(format!("Slider 2: {}", self.slider2_value)),
             )
+            .child(
+                v_flex()
+                    .gap_3()
+                    .w(px(300.))
+                    .child(self.range_slider.clone())
+                    .child(format!(
+                        "Price: ${} - ${}",
+                        self.range_value.0, self.range_value.1
+                    )),
+            )
             .child(
                 h_flex()
                     .mt_5()
                     .gap_4()
-                    .child(Skeleton::new().size_12().rounded_full())
+                    .child(Skeleton::circle().size_12())
                     .child(
                         v_flex()
                             .gap_2()
-                            .child(Skeleton::new().w(px(250.)).h_4())
-                            .child(Skeleton::new().w(px(240.)).h_4()),
+                            .child(Skeleton::text().w(px(250.)))
+                            .child(Skeleton::text().w(px(240.))),
                     ),
             )
+            .child(Skeleton::rect().w(px(400.)))
+            .child(div().w(px(400.)).child(SkeletonList::new(3)))
+            .child(div().w(px(600.)).child(SkeletonTable::new(4, 3)))
     }
 }
@@ -0,0 +1,116 @@
+use gpui::{
+    div, px, IntoElement, ParentElement, Render, SharedString, Styled, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+
+use ui::{
+    label::Label,
+    radio::{RadioGroup, RadioGroupEvent, RadioGroupItem},
+    theme::ActiveTheme as _,
+    v_flex,
+};
+
+pub struct RadioStory {
+    focus_handle: gpui::FocusHandle,
+    size_group: View<RadioGroup>,
+    plan_group: View<RadioGroup>,
+    plan: SharedString,
+}
+
+impl super::Story for RadioStory {
+    fn title() -> &'static str {
+        "Radio"
+    }
+
+    fn description() -> &'static str {
+        "A group of radios with only one selected, use the arrow keys to change the selection."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl RadioStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let size_group = cx.new_view(|cx| {
+            RadioGroup::new(cx)
+                .horizontal()
+                .items(vec![
+                    RadioGroupItem::new("small", "Small"),
+                    RadioGroupItem::new("medium", "Medium"),
+                    RadioGroupItem::new("large", "Large"),
+                    RadioGroupItem::new("xlarge", "Extra Large").disabled(true),
+                ])
+                .selected_value("medium")
+        });
+
+        let plan_group = cx.new_view(|cx| {
+            RadioGroup::new(cx)
+                .card()
+                .items(vec![
+                    RadioGroupItem::new("free", "Free")
+                        .description("For personal projects, up to 3 members."),
+                    RadioGroupItem::new("pro", "Pro")
+                        .description("For small teams, up to 20 members."),
+                    RadioGroupItem::new("enterprise", "Enterprise")
+                        .description("For large organizations, unlimited members."),
+                ])
+                .selected_value("pro")
+        });
+
+        cx.subscribe(
+            &plan_group,
+            |this, _, event: &RadioGroupEvent, cx| match event {
+                RadioGroupEvent::Change(value) => {
+                    this.plan = value.clone();
+                    cx.notify();
+                }
+            },
+        )
+        .detach();
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            size_group,
+            plan_group,
+            plan: "pro".into(),
+        }
+    }
+}
+
+impl gpui::FocusableView for RadioStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for RadioStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_6()
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(Label::new("Size"))
+                    .child(self.size_group.clone()),
+            )
+            .child(
+                v_flex()
+                    .gap_2()
+                    .w(px(360.))
+                    .child(Label::new("Plan"))
+                    .child(self.plan_group.clone())
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(format!("Selected: {}", self.plan)),
+                    ),
+            )
+    }
+}
@@ -0,0 +1,88 @@
+use gpui::{
+    div, IntoElement, ParentElement as _, Render, Styled as _, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+use ui::{rating::Rating, theme::ActiveTheme as _, v_flex, IconName, Sizable as _};
+
+use crate::section;
+
+pub struct RatingStory {
+    focus_handle: gpui::FocusHandle,
+    rating: View<Rating>,
+    half_rating: View<Rating>,
+    heart_rating: View<Rating>,
+    readonly_rating: View<Rating>,
+}
+
+impl super::Story for RatingStory {
+    fn title() -> &'static str {
+        "Rating"
+    }
+
+    fn description() -> &'static str {
+        "A row of the stars to rate, with the hover preview and the half star precision."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl RatingStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let view = cx.view().downgrade();
+        let rating = cx.new_view(|cx| {
+            Rating::new(cx).value(3.).on_change(move |_, cx| {
+                _ = view.update(cx, |_, cx| cx.notify());
+            })
+        });
+        let half_rating = cx.new_view(|cx| Rating::new(cx).half(true).value(2.5).large());
+        let heart_rating = cx.new_view(|cx| {
+            Rating::new(cx)
+                .icon(IconName::Heart)
+                .color(ui::red_500())
+                .count(10)
+                .value(7.)
+                .small()
+        });
+        let readonly_rating = cx.new_view(|cx| Rating::new(cx).readonly(true).value(4.5));
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            rating,
+            half_rating,
+            heart_rating,
+            readonly_rating,
+        }
+    }
+}
+
+impl gpui::FocusableView for RatingStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for RatingStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let value = self.rating.read(cx).current_value();
+
+        v_flex()
+            .gap_6()
+            .child(
+                section("Rating", cx).child(self.rating.clone()).child(
+                    div()
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(format!("Value: {}", value)),
+                ),
+            )
+            .child(section("Half Star", cx).child(self.half_rating.clone()))
+            .child(section("Custom Icon and Count", cx).child(self.heart_rating.clone()))
+            .child(section("Read Only", cx).child(self.readonly_rating.clone()))
+    }
+}
@@ -20,7 +20,7 @@ impl super::Story for ResizableStory {
     }
 
     fn description() -> &'static str {
-        "The resizable panels."
+        "The resizable panels, double click the handle to reset the size."
     }
 
     fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
@@ -58,13 +58,15 @@ impl ResizableStory {
                         .child(
                             resizable_panel()
                                 .size(px(300.))
+                                .min_size(px(120.))
                                 .content(|cx| panel_box("Left 1 (Min 120px)", cx)),
                             cx,
                         )
                         .child(
                             resizable_panel()
                                 .size(px(400.))
-                                .content(|cx| panel_box("Center 1", cx)),
+                                .max_size(px(500.))
+                                .content(|cx| panel_box("Center 1 (Max 500px)", cx)),
                             cx,
                         )
                         .child(
@@ -94,7 +96,8 @@ impl ResizableStory {
                 .child(
                     resizable_panel()
                         .size(px(300.))
-                        .content(|cx| panel_box("Left 2", cx)),
+                        .collapsible(true)
+                        .content(|cx| panel_box("Left 2 (Collapsible)", cx)),
                     cx,
                 )
                 .child(
@@ -2,12 +2,13 @@ use std::cell::Cell;
 use std::rc::Rc;
 
 use gpui::{
-    canvas, div, px, Entity, InteractiveElement, ParentElement, Pixels, Render, ScrollHandle,
-    StatefulInteractiveElement as _, Styled, View, ViewContext, VisualContext, WindowContext,
+    canvas, div, point, px, Entity, InteractiveElement, ParentElement, Pixels, Render,
+    ScrollHandle, StatefulInteractiveElement as _, Styled, View, ViewContext, VisualContext,
+    WindowContext,
 };
 use ui::button::Button;
 use ui::divider::Divider;
-use ui::scroll::{Scrollbar, ScrollbarAxis, ScrollbarState};
+use ui::scroll::{ScrollArea, ScrollAreaHandle, Scrollbar, ScrollbarAxis, ScrollbarState};
 use ui::theme::ActiveTheme;
 use ui::{h_flex, v_flex, StyledExt as _};
 
@@ -19,6 +20,7 @@ pub struct ScrollableStory {
     items: Vec<String>,
     test_width: Pixels,
     axis: ScrollbarAxis,
+    scroll_area: ScrollAreaHandle,
 }
 
 impl ScrollableStory {
@@ -31,6 +33,7 @@ impl ScrollableStory {
             items: (0..500).map(|i| format!("Item {}", i)).collect::<Vec<_>>(),
             test_width: px(3000.),
             axis: ScrollbarAxis::Both,
+            scroll_area: ScrollAreaHandle::new(cx),
         }
     }
 
@@ -203,6 +206,50 @@ impl Render for ScrollableStory {
                             ),
                     ),
             )
+            .child(
+                h_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("scroll-to-top")
+                            .label("Scroll to Top")
+                            .on_click(cx.listener(|view, _, cx| {
+                                view.scroll_area.scroll_to(point(px(0.), px(0.)), cx);
+                            })),
+                    )
+                    .child(
+                        Button::new("scroll-to-section")
+                            .label("Scroll to Section 8")
+                            .on_click(cx.listener(|view, _, cx| {
+                                view.scroll_area.scroll_to_element(("section", 8), cx);
+                            })),
+                    ),
+            )
+            .child(
+                div()
+                    .w_full()
+                    .h(px(200.))
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .child(
+                        ScrollArea::new("scroll-area", &self.scroll_area)
+                            .vertical()
+                            .p_3()
+                            .children((0..10).map(|ix| {
+                                self.scroll_area.anchor(
+                                    ("section", ix),
+                                    v_flex()
+                                        .gap_1()
+                                        .pb_3()
+                                        .child(format!("Section {}", ix))
+                                        .children((0..5).map(|i| {
+                                            div()
+                                                .bg(cx.theme().card)
+                                                .child(format!("Item {}-{}", ix, i))
+                                        })),
+                                )
+                            })),
+                    ),
+            )
             .child({
                 let items = self.items.clone();
                 let test_width = self.test_width;
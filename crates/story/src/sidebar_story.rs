@@ -0,0 +1,108 @@
+use gpui::{
+    div, px, IntoElement, ParentElement, Render, SharedString, Styled, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+
+use ui::{
+    h_flex,
+    sidebar::{Sidebar, SidebarEvent, SidebarGroup, SidebarItem},
+    theme::ActiveTheme as _,
+    v_flex, IconName,
+};
+
+pub struct SidebarStory {
+    focus_handle: gpui::FocusHandle,
+    sidebar: View<Sidebar>,
+    selected: SharedString,
+}
+
+impl super::Story for SidebarStory {
+    fn title() -> &'static str {
+        "Sidebar"
+    }
+
+    fn description() -> &'static str {
+        "A navigation sidebar with grouped items, it can be collapsed to icons."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl SidebarStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let sidebar = cx.new_view(|cx| {
+            let mut sidebar = Sidebar::new(cx).groups(vec![
+                SidebarGroup::new(vec![
+                    SidebarItem::new("inbox", "Inbox")
+                        .icon(IconName::Inbox)
+                        .badge("12"),
+                    SidebarItem::new("starred", "Starred").icon(IconName::Star),
+                    SidebarItem::new("notifications", "Notifications")
+                        .icon(IconName::Bell)
+                        .badge("3"),
+                ])
+                .label("Mail"),
+                SidebarGroup::new(vec![
+                    SidebarItem::new("projects", "Projects")
+                        .icon(IconName::Folder)
+                        .children(vec![
+                            SidebarItem::new("gpui-component", "GPUI Component"),
+                            SidebarItem::new("zed", "Zed").children(vec![
+                                SidebarItem::new("zed-editor", "Editor"),
+                                SidebarItem::new("zed-workspace", "Workspace"),
+                            ]),
+                        ]),
+                    SidebarItem::new("documents", "Documents").icon(IconName::File),
+                    SidebarItem::new("explore", "Explore").icon(IconName::Globe),
+                ])
+                .label("Workspace"),
+            ]);
+            sidebar.set_active("inbox", cx);
+            sidebar
+        });
+
+        cx.subscribe(&sidebar, |this, _, event: &SidebarEvent, cx| match event {
+            SidebarEvent::Select(id) => {
+                this.selected = id.clone();
+                cx.notify();
+            }
+        })
+        .detach();
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            sidebar,
+            selected: "inbox".into(),
+        }
+    }
+}
+
+impl gpui::FocusableView for SidebarStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for SidebarStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        h_flex()
+            .h(px(480.))
+            .items_start()
+            .border_1()
+            .border_color(cx.theme().border)
+            .child(self.sidebar.clone())
+            .child(
+                v_flex().flex_1().p_4().gap_2().child("Selected").child(
+                    div()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(self.selected.clone()),
+                ),
+            )
+    }
+}
@@ -0,0 +1,118 @@
+use gpui::{
+    IntoElement, ParentElement as _, Render, Styled as _, View, ViewContext, VisualContext as _,
+    WindowContext,
+};
+use ui::{button::Button, h_flex, statistic::Statistic, v_flex};
+
+use crate::section;
+
+pub struct StatisticStory {
+    focus_handle: gpui::FocusHandle,
+    loading: bool,
+}
+
+impl super::Story for StatisticStory {
+    fn title() -> &'static str {
+        "Statistic"
+    }
+
+    fn description() -> &'static str {
+        "A large formatted number with a label and the trend, e.g.: the KPI on the dashboard."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl StatisticStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            loading: false,
+        }
+    }
+}
+
+impl gpui::FocusableView for StatisticStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for StatisticStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_6()
+            .child(
+                section("Statistic", cx)
+                    .child(Statistic::new("Active Users").value(112893.))
+                    .child(
+                        Statistic::new("Account Balance")
+                            .value(9182.5)
+                            .precision(2)
+                            .prefix("$"),
+                    )
+                    .child(
+                        Statistic::new("Uptime")
+                            .value(99.98)
+                            .precision(2)
+                            .suffix("%"),
+                    ),
+            )
+            .child(
+                section("KPI Card", cx).child(
+                    v_flex()
+                        .w_full()
+                        .gap_3()
+                        .child(
+                            Button::new("toggle-loading")
+                                .label("Toggle Loading")
+                                .on_click(cx.listener(|this, _, cx| {
+                                    this.loading = !this.loading;
+                                    cx.notify();
+                                })),
+                        )
+                        .child(
+                            h_flex()
+                                .w_full()
+                                .gap_4()
+                                .child(
+                                    Statistic::new("Revenue")
+                                        .value(45231.89)
+                                        .precision(2)
+                                        .prefix("$")
+                                        .delta(20.1)
+                                        .loading(self.loading)
+                                        .card(true)
+                                        .flex_1(),
+                                )
+                                .child(
+                                    Statistic::new("Bounce Rate")
+                                        .value(32.4)
+                                        .suffix("%")
+                                        .delta(-4.3)
+                                        .invert_trend(true)
+                                        .loading(self.loading)
+                                        .card(true)
+                                        .flex_1(),
+                                )
+                                .child(
+                                    Statistic::new("Latency")
+                                        .value(182.)
+                                        .suffix("ms")
+                                        .delta(12.)
+                                        .invert_trend(true)
+                                        .loading(self.loading)
+                                        .card(true)
+                                        .flex_1(),
+                                ),
+                        ),
+                ),
+            )
+    }
+}
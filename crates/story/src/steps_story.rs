@@ -0,0 +1,139 @@
+use gpui::{
+    div, IntoElement, ParentElement as _, Render, SharedString, Styled as _, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+use ui::{
+    input::TextInput,
+    steps::{StepItem, Steps, Wizard, WizardEvent},
+    theme::ActiveTheme as _,
+    v_flex, IconName,
+};
+
+use crate::section;
+
+struct StepContent {
+    text: SharedString,
+}
+
+impl Render for StepContent {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        div()
+            .p_4()
+            .text_color(cx.theme().muted_foreground)
+            .child(self.text.clone())
+    }
+}
+
+pub struct StepsStory {
+    focus_handle: gpui::FocusHandle,
+    wizard: View<Wizard>,
+    message: SharedString,
+}
+
+impl super::Story for StepsStory {
+    fn title() -> &'static str {
+        "Steps"
+    }
+
+    fn description() -> &'static str {
+        "Show the progress of a multi-step process, and a wizard to go through the steps."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl StepsStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let name_input =
+            cx.new_view(|cx| TextInput::new(cx).placeholder("Project name (required)"));
+        let options = cx.new_view(|_| StepContent {
+            text: "Choose the options of the project.".into(),
+        });
+        let review = cx.new_view(|_| StepContent {
+            text: "Review and create the project.".into(),
+        });
+
+        let wizard = cx.new_view(|cx| {
+            let input = name_input.clone();
+            Wizard::new(cx)
+                .step(
+                    StepItem::new("Name").description("Name the project"),
+                    name_input,
+                )
+                .step(StepItem::new("Options").description("Configure"), options)
+                .step(StepItem::new("Review"), review)
+                .before_next(move |ix, cx| ix != 0 || !input.read(cx).text().trim().is_empty())
+        });
+        cx.subscribe(&wizard, |this, _, event: &WizardEvent, cx| {
+            this.message = match event {
+                WizardEvent::Change(ix) => format!("Step: {}", ix + 1),
+                WizardEvent::Finish => "Finished".to_string(),
+            }
+            .into();
+            cx.notify();
+        })
+        .detach();
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            wizard,
+            message: "".into(),
+        }
+    }
+
+    fn step_items() -> Vec<StepItem> {
+        vec![
+            StepItem::new("Account").description("Create the account"),
+            StepItem::new("Profile").description("Fill the profile"),
+            StepItem::new("Payment").icon(IconName::Heart),
+            StepItem::new("Done"),
+        ]
+    }
+}
+
+impl gpui::FocusableView for StepsStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for StepsStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_6()
+            .child(
+                section("Horizontal", cx).child(
+                    Steps::new("horizontal")
+                        .items(Self::step_items())
+                        .current(1),
+                ),
+            )
+            .child(
+                section("Error", cx).child(
+                    Steps::new("error")
+                        .items(Self::step_items())
+                        .current(2)
+                        .error(true),
+                ),
+            )
+            .child(
+                section("Vertical", cx).child(
+                    Steps::new("vertical")
+                        .items(Self::step_items())
+                        .current(2)
+                        .vertical(),
+                ),
+            )
+            .child(
+                section("Wizard", cx)
+                    .child(div().w_full().child(self.wizard.clone()))
+                    .child(self.message.clone()),
+            )
+    }
+}
@@ -1,5 +1,7 @@
+use std::time::Duration;
+
 use gpui::{
-    Div, IntoElement, ParentElement, Render, SharedString, Styled, View, ViewContext,
+    Div, IntoElement, ParentElement, Render, SharedString, Styled, Task, View, ViewContext,
     VisualContext as _, WindowContext,
 };
 
@@ -8,7 +10,7 @@ use ui::{
     label::Label,
     switch::{LabelSide, Switch},
     theme::ActiveTheme,
-    v_flex, Disableable as _, Sizable, StyledExt,
+    v_flex, Disableable as _, IconName, Sizable, StyledExt,
 };
 
 pub struct SwitchStory {
@@ -16,6 +18,9 @@ pub struct SwitchStory {
     switch1: bool,
     switch2: bool,
     switch3: bool,
+    switch4: bool,
+    switch5: bool,
+    saving: Option<Task<()>>,
 }
 
 impl super::Story for SwitchStory {
@@ -43,8 +48,24 @@ impl SwitchStory {
             switch1: true,
             switch2: false,
             switch3: true,
+            switch4: false,
+            switch5: true,
+            saving: None,
         }
     }
+
+    /// Simulate saving the new state to the server.
+    fn save_switch5(&mut self, checked: bool, cx: &mut ViewContext<Self>) {
+        self.saving = Some(cx.spawn(|this, mut cx| async move {
+            cx.background_executor().timer(Duration::from_secs(1)).await;
+            _ = this.update(&mut cx, |this, cx| {
+                this.switch5 = checked;
+                this.saving = None;
+                cx.notify();
+            });
+        }));
+        cx.notify();
+    }
 }
 
 impl gpui::FocusableView for SwitchStory {
@@ -131,6 +152,45 @@ impl Render for SwitchStory {
                                 }),
                         ))
                 )
+                .child(
+                    card(cx)
+                        .v_flex()
+                        .items_start()
+                        .child(title("Track Content"))
+                        .child(
+                            h_flex()
+                                .items_center()
+                                .gap_6()
+                                .child(
+                                    Switch::new("switch4")
+                                        .checked(self.switch4)
+                                        .track_labels("ON", "OFF")
+                                        .on_click(cx.listener(move |view, checked, cx| {
+                                            view.switch4 = *checked;
+                                            cx.notify();
+                                        })),
+                                )
+                                .child(
+                                    Switch::new("switch4_1")
+                                        .checked(self.switch4)
+                                        .track_icons(IconName::Sun, IconName::Moon)
+                                        .large()
+                                        .on_click(cx.listener(move |view, checked, cx| {
+                                            view.switch4 = *checked;
+                                            cx.notify();
+                                        })),
+                                )
+                                .child(
+                                    Switch::new("switch5")
+                                        .checked(self.switch5)
+                                        .loading(self.saving.is_some())
+                                        .label("Sync to cloud")
+                                        .on_click(cx.listener(move |view, checked, cx| {
+                                            view.save_switch5(*checked, cx);
+                                        })),
+                                ),
+                        ),
+                )
                 .child(
                     card(cx).v_flex()
                         .items_start().child(title("Small Switchs")).child(
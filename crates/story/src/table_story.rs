@@ -2,8 +2,8 @@ use std::time::{self, Duration};
 
 use fake::{Fake, Faker};
 use gpui::{
-    div, AnyElement, ClickEvent, IntoElement, ParentElement, Pixels, Render, SharedString, Styled,
-    Timer, View, ViewContext, VisualContext as _, WindowContext,
+    div, px, AnyElement, ClickEvent, ElementId, IntoElement, ParentElement, Pixels, Render,
+    SharedString, Styled, Timer, View, ViewContext, VisualContext as _, WindowContext,
 };
 use ui::{
     button::{Button, ButtonStyled},
@@ -12,8 +12,10 @@ use ui::{
     indicator::Indicator,
     input::{InputEvent, TextInput},
     label::Label,
+    pagination::Pagination,
     prelude::FluentBuilder as _,
-    table::{ColSort, Table, TableDelegate, TableEvent},
+    table::{CellEditor, ColFixed, ColSort, Table, TableDelegate, TableEvent},
+    theme::ActiveTheme as _,
     v_flex, Selectable, Sizable, Size,
 };
 
@@ -367,12 +369,140 @@ impl TableDelegate for StockTableDelegate {
         }
     }
 
+    fn cell_editor(&self, _: usize, col_ix: usize) -> Option<CellEditor> {
+        match self.columns.get(col_ix)?.id.as_ref() {
+            "name" => Some(CellEditor::Input),
+            "price" => Some(CellEditor::Number),
+            _ => None,
+        }
+    }
+
+    fn cell_value(&self, row_ix: usize, col_ix: usize) -> SharedString {
+        let (Some(stock), Some(col)) = (self.stocks.get(row_ix), self.columns.get(col_ix)) else {
+            return SharedString::default();
+        };
+
+        match col.id.as_ref() {
+            "id" => stock.id.to_string(),
+            "symbol" => stock.symbol.clone(),
+            "name" => stock.name.clone(),
+            "price" => stock.price.to_string(),
+            "change" => stock.change.to_string(),
+            "change_percent" => stock.change_percent.to_string(),
+            "volume" => stock.volume.to_string(),
+            "turnover" => stock.turnover.to_string(),
+            "market_cap" => stock.market_cap.to_string(),
+            "ttm" => stock.ttm.to_string(),
+            "five_mins_ranking" => stock.five_mins_ranking.to_string(),
+            "th60_days_ranking" => stock.th60_days_ranking.to_string(),
+            "year_change_percent" => stock.year_change_percent.to_string(),
+            "bid" => stock.bid.to_string(),
+            "bid_volume" => stock.bid_volume.to_string(),
+            "ask" => stock.ask.to_string(),
+            "ask_volume" => stock.ask_volume.to_string(),
+            "open" => stock.open.to_string(),
+            "prev_close" => stock.prev_close.to_string(),
+            "high" => stock.high.to_string(),
+            "low" => stock.low.to_string(),
+            "turnover_rate" => stock.turnover_rate.to_string(),
+            "rise_rate" => stock.rise_rate.to_string(),
+            "amplitude" => stock.amplitude.to_string(),
+            "pe_status" => stock.pe_status.to_string(),
+            "pb_status" => stock.pb_status.to_string(),
+            "volume_ratio" => stock.volume_ratio.to_string(),
+            "bid_ask_ratio" => stock.bid_ask_ratio.to_string(),
+            "latest_pre_close" => stock.latest_pre_close.to_string(),
+            "latest_post_close" => stock.latest_post_close.to_string(),
+            "pre_market_cap" => stock.pre_market_cap.to_string(),
+            "pre_market_percent" => stock.pre_market_percent.to_string(),
+            "pre_market_change" => stock.pre_market_change.to_string(),
+            "post_market_cap" => stock.post_market_cap.to_string(),
+            "post_market_percent" => stock.post_market_percent.to_string(),
+            "post_market_change" => stock.post_market_change.to_string(),
+            "float_cap" => stock.float_cap.to_string(),
+            "shares" => stock.shares.to_string(),
+            "shares_float" => stock.shares_float.to_string(),
+            "day_5_ranking" => stock.day_5_ranking.to_string(),
+            "day_10_ranking" => stock.day_10_ranking.to_string(),
+            "day_30_ranking" => stock.day_30_ranking.to_string(),
+            "day_120_ranking" => stock.day_120_ranking.to_string(),
+            "day_250_ranking" => stock.day_250_ranking.to_string(),
+            _ => "--".to_string(),
+        }
+        .into()
+    }
+
+    fn on_cell_edited(
+        &mut self,
+        row_ix: usize,
+        col_ix: usize,
+        value: SharedString,
+        _: &mut ViewContext<Table<Self>>,
+    ) {
+        let (Some(stock), Some(col)) = (self.stocks.get_mut(row_ix), self.columns.get(col_ix))
+        else {
+            return;
+        };
+
+        match col.id.as_ref() {
+            "name" => stock.name = value.to_string(),
+            "price" => stock.price = value.parse().unwrap_or(stock.price),
+            _ => {}
+        }
+    }
+
+    fn row_id(&self, row_ix: usize) -> Option<ElementId> {
+        self.stocks.get(row_ix).map(|stock| stock.id.into())
+    }
+
+    fn render_row_detail(
+        &self,
+        row_ix: usize,
+        cx: &mut ViewContext<Table<Self>>,
+    ) -> impl IntoElement {
+        let Some(stock) = self.stocks.get(row_ix) else {
+            return div().into_any_element();
+        };
+
+        v_flex()
+            .gap_1()
+            .px_4()
+            .py_2()
+            .text_sm()
+            .text_color(cx.theme().muted_foreground)
+            .child(format!("{} ({})", stock.name, stock.symbol))
+            .child(format!(
+                "Bid: {:.3} x {:.0}, Ask: {:.3} x {:.0}",
+                stock.bid, stock.bid_volume, stock.ask, stock.ask_volume
+            ))
+            .child(format!("High: {:.3}, Low: {:.3}", stock.high, stock.low))
+            // Make the detail heights different to show the variable height rows.
+            .when(stock.id % 2 == 0, |this| {
+                this.child(format!(
+                    "Shares: {}, Float Shares: {}",
+                    stock.shares, stock.shares_float
+                ))
+            })
+            .into_any_element()
+    }
+
     fn can_loop_select(&self) -> bool {
         self.loop_selection
     }
 
-    fn can_move_col(&self, _: usize) -> bool {
-        self.col_order
+    fn can_move_col(&self, col_ix: usize) -> bool {
+        // Keep the pinned first and last columns fixed.
+        self.col_order && col_ix > 0 && col_ix < self.columns.len() - 1
+    }
+
+    fn col_fixed(&self, col_ix: usize) -> Option<ColFixed> {
+        if col_ix == 0 {
+            Some(ColFixed::Left)
+        } else if col_ix == self.columns.len() - 1 {
+            Some(ColFixed::Right)
+        } else {
+            None
+        }
     }
 
     fn move_col(&mut self, col_ix: usize, to_ix: usize) {
@@ -395,7 +525,8 @@ impl TableDelegate for StockTableDelegate {
 
         if let Some(col) = self.columns.get_mut(col_ix) {
             col.sort = Some(sort);
-            let asc = matches!(sort, ColSort::Ascending);
+            // Clearing the sort restores the original order by id.
+            let asc = !matches!(sort, ColSort::Descending);
 
             match col.id.as_ref() {
                 "id" => self.stocks.sort_by(|a, b| {
@@ -424,6 +555,10 @@ impl TableDelegate for StockTableDelegate {
         150
     }
 
+    fn loading(&self) -> bool {
+        self.loading
+    }
+
     fn load_more(&mut self, cx: &mut ViewContext<Table<Self>>) {
         self.loading = true;
 
@@ -432,10 +567,18 @@ impl TableDelegate for StockTableDelegate {
             Timer::after(Duration::from_secs(1)).await;
 
             cx.update(|cx| {
-                let _ = view.update(cx, |view, _| {
-                    view.delegate_mut().stocks.extend(random_stocks(200));
+                let _ = view.update(cx, |view, cx| {
+                    // Continue the ids of the loaded stocks.
+                    let start = view.delegate().stocks.len();
+                    view.delegate_mut()
+                        .stocks
+                        .extend(random_stocks(200).into_iter().map(|mut stock| {
+                            stock.id += start;
+                            stock
+                        }));
                     view.delegate_mut().loading = false;
                     view.delegate_mut().is_eof = view.delegate().stocks.len() >= 6000;
+                    cx.notify();
                 });
             })
         })
@@ -443,9 +586,175 @@ impl TableDelegate for StockTableDelegate {
     }
 }
 
+/// A file entry of the tree, the entries are stored in depth-first order.
+struct FileEntry {
+    name: SharedString,
+    size: Option<u64>,
+    depth: usize,
+    expanded: bool,
+}
+
+impl FileEntry {
+    fn dir(name: &str, depth: usize) -> Self {
+        Self {
+            name: name.to_string().into(),
+            size: None,
+            depth,
+            expanded: false,
+        }
+    }
+
+    fn file(name: &str, size: u64, depth: usize) -> Self {
+        Self {
+            name: name.to_string().into(),
+            size: Some(size),
+            depth,
+            expanded: false,
+        }
+    }
+}
+
+struct FileTreeDelegate {
+    entries: Vec<FileEntry>,
+    /// The entry indices of the visible rows.
+    visible_entries: Vec<usize>,
+}
+
+impl FileTreeDelegate {
+    fn new() -> Self {
+        let mut this = Self {
+            entries: vec![
+                FileEntry::dir("crates", 0),
+                FileEntry::dir("ui", 1),
+                FileEntry::dir("src", 2),
+                FileEntry::file("table.rs", 72_410, 3),
+                FileEntry::file("list.rs", 18_233, 3),
+                FileEntry::file("lib.rs", 2_104, 3),
+                FileEntry::file("Cargo.toml", 812, 2),
+                FileEntry::dir("story", 1),
+                FileEntry::dir("src", 2),
+                FileEntry::file("table_story.rs", 31_942, 3),
+                FileEntry::file("Cargo.toml", 640, 2),
+                FileEntry::file("Cargo.toml", 420, 0),
+                FileEntry::file("README.md", 2_350, 0),
+            ],
+            visible_entries: vec![],
+        };
+        this.update_visible_entries();
+        this
+    }
+
+    fn has_children(&self, entry_ix: usize) -> bool {
+        self.entries
+            .get(entry_ix + 1)
+            .map_or(false, |next| next.depth > self.entries[entry_ix].depth)
+    }
+
+    /// Flatten the tree to the visible entries, the descendants of the collapsed entries are hidden.
+    fn update_visible_entries(&mut self) {
+        self.visible_entries.clear();
+        let mut collapsed_depth = None;
+        for (entry_ix, entry) in self.entries.iter().enumerate() {
+            if let Some(depth) = collapsed_depth {
+                if entry.depth > depth {
+                    continue;
+                }
+                collapsed_depth = None;
+            }
+
+            self.visible_entries.push(entry_ix);
+            if !entry.expanded && self.has_children(entry_ix) {
+                collapsed_depth = Some(entry.depth);
+            }
+        }
+    }
+
+    fn entry(&self, row_ix: usize) -> Option<&FileEntry> {
+        self.entries.get(*self.visible_entries.get(row_ix)?)
+    }
+}
+
+impl TableDelegate for FileTreeDelegate {
+    fn cols_count(&self) -> usize {
+        2
+    }
+
+    fn rows_count(&self) -> usize {
+        self.visible_entries.len()
+    }
+
+    fn col_name(&self, col_ix: usize) -> SharedString {
+        match col_ix {
+            0 => "Name".into(),
+            _ => "Size".into(),
+        }
+    }
+
+    fn col_width(&self, col_ix: usize) -> Option<Pixels> {
+        match col_ix {
+            0 => Some(px(300.)),
+            _ => Some(px(120.)),
+        }
+    }
+
+    fn render_td(
+        &self,
+        row_ix: usize,
+        col_ix: usize,
+        _: &mut ViewContext<Table<Self>>,
+    ) -> impl IntoElement {
+        let Some(entry) = self.entry(row_ix) else {
+            return "--".to_string();
+        };
+
+        match col_ix {
+            0 => entry.name.to_string(),
+            _ => entry
+                .size
+                .map(|size| format!("{} bytes", size))
+                .unwrap_or_default(),
+        }
+    }
+
+    fn row_depth(&self, row_ix: usize) -> usize {
+        self.entry(row_ix).map_or(0, |entry| entry.depth)
+    }
+
+    fn row_has_children(&self, row_ix: usize) -> bool {
+        self.visible_entries
+            .get(row_ix)
+            .map_or(false, |&entry_ix| self.has_children(entry_ix))
+    }
+
+    fn row_children_expanded(&self, row_ix: usize) -> bool {
+        self.entry(row_ix).map_or(false, |entry| entry.expanded)
+    }
+
+    fn set_row_children_expanded(
+        &mut self,
+        row_ix: usize,
+        expanded: bool,
+        _: &mut ViewContext<Table<Self>>,
+    ) {
+        let Some(&entry_ix) = self.visible_entries.get(row_ix) else {
+            return;
+        };
+
+        self.entries[entry_ix].expanded = expanded;
+        self.update_visible_entries();
+    }
+
+    fn can_load_more(&self) -> bool {
+        false
+    }
+}
+
 pub struct TableStory {
     table: View<Table<StockTableDelegate>>,
+    tree_table: View<Table<FileTreeDelegate>>,
     num_stocks_input: View<TextInput>,
+    pagination: View<Pagination>,
+    page_size: usize,
     stripe: bool,
     refresh_data: bool,
     size: Size,
@@ -491,12 +800,30 @@ impl TableStory {
         });
 
         let delegate = StockTableDelegate::new(5000);
-        let table = cx.new_view(|cx| Table::new(delegate, cx));
+        let table = cx.new_view(|cx| Table::new(delegate, cx).multi_select(true).expandable(true));
 
         cx.subscribe(&table, Self::on_table_event).detach();
+
+        let tree_table = cx.new_view(|cx| Table::new(FileTreeDelegate::new(), cx).tree(true));
         cx.subscribe(&num_stocks_input, Self::on_num_stocks_input_change)
             .detach();
 
+        let story = cx.view().downgrade();
+        let pagination = cx.new_view(|cx| {
+            Pagination::new(5000, cx)
+                .page_sizes(vec![20, 50, 100], cx)
+                .jumper(true)
+                .on_page_size_change({
+                    let story = story.clone();
+                    move |page_size, cx| {
+                        _ = story.update(cx, |this, _| this.page_size = page_size);
+                    }
+                })
+                .on_page_change(move |page, cx| {
+                    _ = story.update(cx, |this, cx| this.scroll_to_page(page, cx));
+                })
+        });
+
         // Spawn a background to random refresh the list
         cx.spawn(move |this, mut cx| async move {
             loop {
@@ -528,7 +855,10 @@ impl TableStory {
 
         Self {
             table,
+            tree_table,
             num_stocks_input,
+            pagination,
+            page_size: 20,
             stripe: false,
             refresh_data: false,
             size: Size::default(),
@@ -550,6 +880,8 @@ impl TableStory {
                     self.table.update(cx, |table, _| {
                         table.delegate_mut().update_stocks(num);
                     });
+                    self.pagination
+                        .update(cx, |pagination, cx| pagination.set_total(num, cx));
                     cx.notify();
                 }
             }
@@ -557,6 +889,12 @@ impl TableStory {
         }
     }
 
+    fn scroll_to_page(&mut self, page: usize, cx: &mut ViewContext<Self>) {
+        let row_ix = (page - 1) * self.page_size;
+        self.table
+            .update(cx, |table, cx| table.scroll_to_row(row_ix, cx));
+    }
+
     fn toggle_loop_selection(&mut self, checked: &bool, cx: &mut ViewContext<Self>) {
         let table = self.table.clone();
         table.update(cx, |table, cx| {
@@ -607,6 +945,28 @@ impl TableStory {
         });
     }
 
+    fn export_csv(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
+        let csv = self.table.read(cx).export_csv();
+        for line in csv.lines().take(3) {
+            println!("{}", line);
+        }
+        println!("Exported {} bytes of CSV.", csv.len());
+    }
+
+    fn copy_selection(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
+        self.table
+            .update(cx, |table, cx| table.copy_selection_to_clipboard(cx));
+    }
+
+    fn expand_all(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
+        self.tree_table.update(cx, |table, cx| table.expand_all(cx));
+    }
+
+    fn collapse_all(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
+        self.tree_table
+            .update(cx, |table, cx| table.collapse_all(cx));
+    }
+
     fn toggle_size(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
         self.size = match self.size {
             Size::XSmall => Size::Small,
@@ -628,23 +988,32 @@ impl TableStory {
 
     fn on_table_event(
         &mut self,
-        _: View<Table<StockTableDelegate>>,
+        table: View<Table<StockTableDelegate>>,
         event: &TableEvent,
-        _cx: &mut ViewContext<Self>,
+        cx: &mut ViewContext<Self>,
     ) {
         match event {
-            TableEvent::ColWidthsChanged(col_widths) => {
-                println!("Col widths changed: {:?}", col_widths)
+            TableEvent::ColWidthsChanged(_) => {
+                // The layout can be saved and restored by `Table::set_column_layout`.
+                let layout = table.read(cx).column_layout();
+                println!(
+                    "Column layout changed: {}",
+                    serde_json::to_string(&layout).unwrap_or_default()
+                )
             }
             TableEvent::SelectCol(ix) => println!("Select col: {}", ix),
             TableEvent::SelectRow(ix) => println!("Select row: {}", ix),
+            TableEvent::Sort(sorts) => println!("Sort: {:?}", sorts),
+            TableEvent::MoveCol(from, to) => println!("Move col: {} -> {}", from, to),
+            TableEvent::SelectionChanged(rows) => println!("Selected rows: {}", rows.len()),
         }
     }
 }
 
 impl Render for TableStory {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl gpui::IntoElement {
-        let delegate = self.table.read(cx).delegate();
+        let table = self.table.read(cx);
+        let delegate = table.delegate();
 
         v_flex()
             .size_full()
@@ -697,6 +1066,22 @@ impl Render for TableStory {
                             .label(format!("size: {:?}", self.size))
                             .on_click(cx.listener(Self::toggle_size)),
                     )
+                    .child(
+                        Button::new("export-csv")
+                            .small()
+                            .compact()
+                            .outline()
+                            .label("Export CSV")
+                            .on_click(cx.listener(Self::export_csv)),
+                    )
+                    .child(
+                        Button::new("copy-selection")
+                            .small()
+                            .compact()
+                            .outline()
+                            .label("Copy Selection")
+                            .on_click(cx.listener(Self::copy_selection)),
+                    )
                     .child(
                         Checkbox::new("refresh-data")
                             .label("Refresh Data")
@@ -705,23 +1090,55 @@ impl Render for TableStory {
                     ),
             )
             .child(
-                h_flex().items_center().gap_2().child(
-                    h_flex()
-                        .items_center()
-                        .gap_1()
-                        .child(Label::new("Number of Stocks:"))
-                        .child(
-                            h_flex()
-                                .min_w_32()
-                                .child(self.num_stocks_input.clone())
-                                .into_any_element(),
-                        )
-                        .when(delegate.loading, |this| {
-                            this.child(h_flex().gap_1().child(Indicator::new()).child("Loading..."))
-                        })
-                        .child(format!("Total Rows: {}", delegate.rows_count()))
-                        .when(delegate.is_eof, |this| this.child("All data loaded.")),
-                ),
+                h_flex()
+                    .items_center()
+                    .gap_2()
+                    .child(
+                        h_flex()
+                            .items_center()
+                            .gap_1()
+                            .child(Label::new("Number of Stocks:"))
+                            .child(
+                                h_flex()
+                                    .min_w_32()
+                                    .child(self.num_stocks_input.clone())
+                                    .into_any_element(),
+                            )
+                            .when(delegate.loading, |this| {
+                                this.child(
+                                    h_flex().gap_1().child(Indicator::new()).child("Loading..."),
+                                )
+                            })
+                            .child(format!("Total Rows: {}", delegate.rows_count()))
+                            .child(format!("Visible Rows: {:?}", table.visible_range()))
+                            .when(delegate.is_eof, |this| this.child("All data loaded.")),
+                    )
+                    .child(self.pagination.clone()),
+            )
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child(
+                                Button::new("expand-all")
+                                    .small()
+                                    .compact()
+                                    .outline()
+                                    .label("Expand All")
+                                    .on_click(cx.listener(Self::expand_all)),
+                            )
+                            .child(
+                                Button::new("collapse-all")
+                                    .small()
+                                    .compact()
+                                    .outline()
+                                    .label("Collapse All")
+                                    .on_click(cx.listener(Self::collapse_all)),
+                            ),
+                    )
+                    .child(div().h(px(240.)).child(self.tree_table.clone())),
             )
             .child(self.table.clone())
     }
@@ -0,0 +1,127 @@
+use gpui::{
+    div, px, IntoElement, ParentElement, Render, SharedString, Styled, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+
+use ui::{
+    button::Button,
+    h_flex,
+    tab::{TabItem, Tabs, TabsEvent},
+    theme::ActiveTheme as _,
+    v_flex,
+};
+
+struct TabContent {
+    text: SharedString,
+}
+
+impl Render for TabContent {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        div()
+            .p_4()
+            .text_color(cx.theme().muted_foreground)
+            .child(self.text.clone())
+    }
+}
+
+pub struct TabsStory {
+    focus_handle: gpui::FocusHandle,
+    tabs: View<Tabs>,
+    last_event: SharedString,
+    tabs_count: usize,
+}
+
+impl super::Story for TabsStory {
+    fn title() -> &'static str {
+        "Tabs"
+    }
+
+    fn description() -> &'static str {
+        "Closable and reorderable tabs, use Ctrl+Tab or Cmd+1..9 to switch."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl TabsStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let tabs_count = 12;
+        let items = (0..tabs_count)
+            .map(|ix| Self::tab_item(ix, cx))
+            .collect::<Vec<_>>();
+        let tabs = cx.new_view(|cx| Tabs::new(cx).items(items));
+
+        cx.subscribe(&tabs, |this, _, event: &TabsEvent, cx| {
+            this.last_event = match event {
+                TabsEvent::Change(ix) => format!("Change to {}", ix),
+                TabsEvent::Close(ix) => format!("Close {}", ix),
+                TabsEvent::Move { from, to } => format!("Move {} to {}", from, to),
+            }
+            .into();
+            cx.notify();
+        })
+        .detach();
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            tabs,
+            last_event: "".into(),
+            tabs_count,
+        }
+    }
+
+    fn tab_item(ix: usize, cx: &mut WindowContext) -> TabItem {
+        let content = cx.new_view(|_| TabContent {
+            text: format!("This is the content of Document {}.", ix + 1).into(),
+        });
+
+        TabItem::new(format!("Document {}", ix + 1))
+            .closable(ix > 0)
+            .content(content)
+    }
+}
+
+impl gpui::FocusableView for TabsStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TabsStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_4()
+            .child(
+                h_flex()
+                    .gap_3()
+                    .child(
+                        Button::new("add-tab")
+                            .label("Add Tab")
+                            .on_click(cx.listener(|this, _, cx| {
+                                let item = Self::tab_item(this.tabs_count, cx);
+                                this.tabs_count += 1;
+                                this.tabs.update(cx, |tabs, cx| tabs.add_tab(item, cx));
+                            })),
+                    )
+                    .child(
+                        div()
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(self.last_event.clone()),
+                    ),
+            )
+            .child(
+                div()
+                    .h(px(200.))
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .child(self.tabs.clone()),
+            )
+    }
+}
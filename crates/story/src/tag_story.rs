@@ -0,0 +1,103 @@
+use gpui::{
+    px, IntoElement, ParentElement as _, Render, SharedString, Styled as _, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+use ui::{h_flex, tag::Tag, v_flex, IconName, Sizable as _};
+
+use crate::section;
+
+pub struct TagStory {
+    focus_handle: gpui::FocusHandle,
+    labels: Vec<SharedString>,
+}
+
+impl super::Story for TagStory {
+    fn title() -> &'static str {
+        "Tag"
+    }
+
+    fn description() -> &'static str {
+        "A small label to categorize the items, with the color variants and the close button."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl TagStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            labels: vec!["bug".into(), "enhancement".into(), "help wanted".into()],
+        }
+    }
+}
+
+impl gpui::FocusableView for TagStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TagStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let colors = [
+            ("Gray", ui::gray_500()),
+            ("Red", ui::red_500()),
+            ("Yellow", ui::yellow_500()),
+            ("Green", ui::green_500()),
+            ("Blue", ui::blue_500()),
+            ("Purple", ui::purple_500()),
+        ];
+
+        v_flex()
+            .gap_6()
+            .child(
+                section("Solid", cx).children(
+                    colors
+                        .iter()
+                        .enumerate()
+                        .map(|(ix, (label, color))| Tag::new(("solid", ix), *label).color(*color)),
+                ),
+            )
+            .child(
+                section("Outline", cx).children(colors.iter().enumerate().map(
+                    |(ix, (label, color))| {
+                        Tag::new(("outline", ix), *label).outline().color(*color)
+                    },
+                )),
+            )
+            .child(section("Soft", cx).children(
+                colors.iter().enumerate().map(|(ix, (label, color))| {
+                    Tag::new(("soft", ix), *label).soft().color(*color)
+                }),
+            ))
+            .child(
+                section("Icon and Size", cx)
+                    .child(Tag::new("star", "Starred").icon(IconName::Star))
+                    .child(Tag::new("small", "Small").soft().small())
+                    .child(
+                        Tag::new("truncated", "A very long label is truncated with a tooltip")
+                            .outline()
+                            .max_w(px(160.)),
+                    ),
+            )
+            .child(
+                section("Removable", cx).child(h_flex().gap_2().children(
+                    self.labels.iter().enumerate().map(|(ix, label)| {
+                        Tag::new(("label", ix), label.clone())
+                            .soft()
+                            .on_remove(cx.listener(move |this, _, cx| {
+                                this.labels.remove(ix);
+                                cx.notify();
+                            }))
+                    }),
+                )),
+            )
+    }
+}
@@ -0,0 +1,114 @@
+use chrono::{Days, NaiveTime};
+use gpui::{
+    px, IntoElement, ParentElement as _, Render, Styled as _, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+use ui::{
+    date_time_picker::{DateTimePicker, DateTimePickerEvent},
+    time_picker::{TimePicker, TimePickerEvent},
+    v_flex, Sizable as _,
+};
+
+pub struct TimePickerStory {
+    time_picker: View<TimePicker>,
+    twelve_hour_picker: View<TimePicker>,
+    step_picker: View<TimePicker>,
+    date_time_picker: View<DateTimePicker>,
+    value: Option<String>,
+}
+
+impl super::Story for TimePickerStory {
+    fn title() -> &'static str {
+        "TimePicker"
+    }
+
+    fn description() -> &'static str {
+        "A time picker and a date time picker with the calendar."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl TimePickerStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let now = chrono::Local::now().naive_local();
+
+        let time_picker = cx.new_view(|cx| {
+            let mut picker = TimePicker::new("time_picker", cx)
+                .seconds(true)
+                .cleanable()
+                .width(px(220.));
+            picker.set_time(Some(now.time()), cx);
+            picker
+        });
+        let twelve_hour_picker = cx.new_view(|cx| {
+            TimePicker::new("twelve_hour_picker", cx)
+                .twelve_hour(true)
+                .small()
+                .width(px(180.))
+        });
+        let step_picker = cx.new_view(|cx| {
+            TimePicker::new("step_picker", cx)
+                .minute_step(15)
+                .min(NaiveTime::from_hms_opt(9, 0, 0).unwrap())
+                .max(NaiveTime::from_hms_opt(18, 0, 0).unwrap())
+                .placeholder("Working hours, every 15 minutes")
+                .width(px(300.))
+        });
+        let date_time_picker = cx.new_view(|cx| {
+            DateTimePicker::new("date_time_picker", cx)
+                .minute_step(5)
+                .min(now)
+                .max(now.checked_add_days(Days::new(30)).unwrap())
+                .cleanable()
+                .width(px(300.))
+        });
+
+        for picker in [&time_picker, &twelve_hour_picker, &step_picker] {
+            cx.subscribe(picker, |this, _, ev, _| match ev {
+                TimePickerEvent::Change(time) => {
+                    this.value = time.map(|t| t.format("%H:%M:%S").to_string());
+                }
+            })
+            .detach();
+        }
+        cx.subscribe(&date_time_picker, |this, _, ev, _| match ev {
+            DateTimePickerEvent::Change(value) => {
+                this.value = value.map(|v| v.format("%Y-%m-%d %H:%M:%S").to_string());
+            }
+        })
+        .detach();
+
+        Self {
+            time_picker,
+            twelve_hour_picker,
+            step_picker,
+            date_time_picker,
+            value: None,
+        }
+    }
+}
+
+impl gpui::FocusableView for TimePickerStory {
+    fn focus_handle(&self, cx: &gpui::AppContext) -> gpui::FocusHandle {
+        self.time_picker.focus_handle(cx)
+    }
+}
+
+impl Render for TimePickerStory {
+    fn render(&mut self, _cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_3()
+            .child(self.time_picker.clone())
+            .child(self.twelve_hour_picker.clone())
+            .child(self.step_picker.clone())
+            .child(self.date_time_picker.clone())
+            .child(format!("Time picker value: {:?}", self.value).into_element())
+    }
+}
@@ -1,17 +1,31 @@
+use std::time::Duration;
+
 use gpui::{
-    div, CursorStyle, InteractiveElement, ParentElement, Render, StatefulInteractiveElement,
-    Styled, View, ViewContext, VisualContext as _, WindowContext,
+    actions, div, AppContext, CursorStyle, InteractiveElement, KeyBinding, Keystroke,
+    ParentElement, Render, StatefulInteractiveElement, Styled, View, ViewContext,
+    VisualContext as _, WindowContext,
 };
 
 use ui::{
     button::{Button, ButtonStyle},
     checkbox::Checkbox,
     h_flex,
+    kbd::Kbd,
     label::Label,
-    tooltip::Tooltip,
+    theme::ActiveTheme as _,
+    tooltip::{Tooltip, TooltipExt as _},
     v_flex,
 };
 
+actions!(tooltip_story, [Save]);
+
+pub fn init(cx: &mut AppContext) {
+    #[cfg(target_os = "macos")]
+    cx.bind_keys([KeyBinding::new("cmd-s", Save, None)]);
+    #[cfg(not(target_os = "macos"))]
+    cx.bind_keys([KeyBinding::new("ctrl-s", Save, None)]);
+}
+
 pub struct TooltipStory {
     focus_handle: gpui::FocusHandle,
 }
@@ -47,7 +61,7 @@ impl gpui::FocusableView for TooltipStory {
     }
 }
 impl Render for TooltipStory {
-    fn render(&mut self, _: &mut gpui::ViewContext<Self>) -> impl gpui::IntoElement {
+    fn render(&mut self, cx: &mut gpui::ViewContext<Self>) -> impl gpui::IntoElement {
         v_flex()
             .p_4()
             .gap_5()
@@ -77,5 +91,58 @@ impl Render for TooltipStory {
                     .id("tooltip-4")
                     .tooltip(|cx| Tooltip::new("Checked!", cx)),
             )
+            .child(
+                h_flex()
+                    .gap_3()
+                    .child(
+                        Button::new("save")
+                            .label("Save")
+                            .with_tooltip("tooltip-save", |cx| {
+                                Tooltip::for_action("Save", &Save, cx)
+                            }),
+                    )
+                    .child(
+                        Button::new("multi-line")
+                            .label("Multi-line")
+                            .with_tooltip("tooltip-multi-line", |cx| {
+                                Tooltip::new(
+                                    "This is a multi-line tooltip.\nPress Escape or scroll to dismiss it.",
+                                    cx,
+                                )
+                            }),
+                    )
+                    .child(
+                        Button::new("rich")
+                            .label("Rich Content")
+                            .with_tooltip("tooltip-rich", |cx| {
+                                Tooltip::element(
+                                    |cx| {
+                                        v_flex()
+                                            .child(div().font_semibold().child("Rich Content"))
+                                            .child(
+                                                div()
+                                                    .text_color(cx.theme().muted_foreground)
+                                                    .child("The tooltip can render any element."),
+                                            )
+                                    },
+                                    cx,
+                                )
+                            })
+                            .delay(Duration::from_millis(100))
+                            .hide_delay(Duration::from_millis(300)),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .gap_3()
+                    .child(Label::new("Keyboard Shortcuts"))
+                    .children(Kbd::binding_for_action(&Save, cx))
+                    .child(Kbd::new(Keystroke::parse("cmd-shift-p").unwrap()))
+                    .child(Kbd::keystrokes(vec![
+                        Keystroke::parse("cmd-k").unwrap(),
+                        Keystroke::parse("cmd-s").unwrap(),
+                    ]))
+                    .child(Kbd::new(Keystroke::parse("alt-enter").unwrap()).appearance(false)),
+            )
     }
 }
@@ -0,0 +1,103 @@
+use gpui::{
+    div, IntoElement, ParentElement as _, Render, Styled as _, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+use ui::{theme::ActiveTheme as _, transfer::Transfer, v_flex};
+
+use crate::section;
+
+const LANGUAGES: [&str; 12] = [
+    "C",
+    "C++",
+    "Go",
+    "Java",
+    "JavaScript",
+    "Kotlin",
+    "Python",
+    "Ruby",
+    "Rust",
+    "Swift",
+    "TypeScript",
+    "Zig",
+];
+
+pub struct TransferStory {
+    focus_handle: gpui::FocusHandle,
+    transfer: View<Transfer<String>>,
+    plain_transfer: View<Transfer<String>>,
+    selected: Vec<String>,
+}
+
+impl super::Story for TransferStory {
+    fn title() -> &'static str {
+        "Transfer"
+    }
+
+    fn description() -> &'static str {
+        "Two searchable lists to move the items between the available and the selected."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl gpui::FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl TransferStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let items: Vec<String> = LANGUAGES.iter().map(|s| s.to_string()).collect();
+        let view = cx.view().downgrade();
+        let transfer = cx.new_view(|cx| {
+            let mut transfer = Transfer::new("languages", items.clone(), cx)
+                .titles("Languages", "Favorites")
+                .on_change(move |_, selected, cx| {
+                    let selected = selected.to_vec();
+                    _ = view.update(cx, |this, cx| {
+                        this.selected = selected;
+                        cx.notify();
+                    });
+                });
+            transfer.set_selected_values(vec!["Rust".to_string()], cx);
+            transfer
+        });
+        let plain_transfer = cx.new_view(|cx| Transfer::new("plain", items, cx).searchable(false));
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            transfer,
+            plain_transfer,
+            selected: vec!["Rust".to_string()],
+        }
+    }
+}
+
+impl gpui::FocusableView for TransferStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> gpui::FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TransferStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_6()
+            .child(
+                section("Transfer", cx).child(
+                    v_flex()
+                        .w_full()
+                        .gap_3()
+                        .child(self.transfer.clone())
+                        .child(
+                            div()
+                                .text_sm()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(format!("Favorites: {}", self.selected.join(", "))),
+                        ),
+                ),
+            )
+            .child(section("Without Search", cx).child(self.plain_transfer.clone()))
+    }
+}
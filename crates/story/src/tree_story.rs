@@ -0,0 +1,124 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use fake::Fake;
+use gpui::{
+    px, FocusHandle, FocusableView, IntoElement, ParentElement, Render, SharedString, Styled, Task,
+    Timer, View, ViewContext, VisualContext as _, WindowContext,
+};
+
+use ui::{
+    label::Label,
+    theme::ActiveTheme as _,
+    tree::{TreeDelegate, TreeEvent, TreeNode, TreeView},
+    v_flex, IconName,
+};
+
+/// A fake file system, the children are loaded with a random delay.
+struct FileTreeDelegate;
+
+impl TreeDelegate for FileTreeDelegate {
+    fn load_children(
+        &mut self,
+        node: Option<&TreeNode>,
+        cx: &mut ViewContext<TreeView<Self>>,
+    ) -> Task<Result<Vec<TreeNode>>> {
+        let parent_id = node.map(|node| node.id.clone()).unwrap_or_default();
+        cx.background_executor().spawn(async move {
+            // Simulate a slow file system.
+            let delay = (0.2..0.8).fake::<f64>();
+            Timer::after(Duration::from_secs_f64(delay)).await;
+
+            let depth = parent_id.matches('/').count();
+            let dirs_count = if depth < 3 { 3 } else { 0 };
+            let mut nodes = (0..dirs_count)
+                .map(|ix| {
+                    TreeNode::new(
+                        format!("{}/dir-{}", parent_id, ix),
+                        format!("Folder {}", ix),
+                    )
+                    .icon(IconName::Folder)
+                    .expandable(true)
+                })
+                .collect::<Vec<_>>();
+            nodes.extend((0..4).map(|ix| {
+                TreeNode::new(
+                    format!("{}/file-{}", parent_id, ix),
+                    format!("file-{}.rs", ix),
+                )
+                .icon(IconName::File)
+            }));
+            Ok(nodes)
+        })
+    }
+}
+
+pub struct TreeStory {
+    focus_handle: FocusHandle,
+    tree: View<TreeView<FileTreeDelegate>>,
+    selected_id: Option<SharedString>,
+}
+
+impl super::Story for TreeStory {
+    fn title() -> &'static str {
+        "TreeView"
+    }
+
+    fn description() -> &'static str {
+        "A tree view with lazy loaded children, like a file explorer."
+    }
+
+    fn new_view(cx: &mut WindowContext) -> View<impl FocusableView> {
+        Self::view(cx)
+    }
+}
+
+impl TreeStory {
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        cx.new_view(Self::new)
+    }
+
+    fn new(cx: &mut ViewContext<Self>) -> Self {
+        let tree = cx.new_view(|cx| TreeView::new(FileTreeDelegate, cx));
+        cx.subscribe(&tree, |this, _, event: &TreeEvent, cx| {
+            if let TreeEvent::Select(node) = event {
+                this.selected_id = Some(node.id.clone());
+                cx.notify();
+            }
+        })
+        .detach();
+
+        Self {
+            focus_handle: cx.focus_handle(),
+            tree,
+            selected_id: None,
+        }
+    }
+}
+
+impl FocusableView for TreeStory {
+    fn focus_handle(&self, _: &gpui::AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TreeStory {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .gap_4()
+            .child(
+                v_flex()
+                    .w(px(320.))
+                    .h(px(400.))
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .rounded_md()
+                    .overflow_hidden()
+                    .child(self.tree.clone()),
+            )
+            .child(Label::new(format!(
+                "Selected: {}",
+                self.selected_id.clone().unwrap_or_default()
+            )))
+    }
+}
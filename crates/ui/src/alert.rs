@@ -0,0 +1,148 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, ClickEvent, ElementId, IntoElement, ParentElement as _,
+    RenderOnce, SharedString, Styled as _, WindowContext,
+};
+
+use crate::{
+    button::{Button, ButtonStyled as _},
+    h_flex,
+    notification::NotificationType,
+    theme::ActiveTheme as _,
+    v_flex, Icon, IconName, Sizable as _, StyledExt as _,
+};
+
+/// An inline banner for the persistent messages in the page, use the [`crate::notification::Notification`] for the transient ones.
+#[derive(IntoElement)]
+pub struct Alert {
+    id: ElementId,
+    type_: NotificationType,
+    title: Option<SharedString>,
+    description: SharedString,
+    icon: Option<Icon>,
+    action: Option<(SharedString, Rc<dyn Fn(&ClickEvent, &mut WindowContext)>)>,
+    on_dismiss: Option<Rc<dyn Fn(&ClickEvent, &mut WindowContext)>>,
+}
+
+impl Alert {
+    pub fn new(id: impl Into<ElementId>, description: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            type_: NotificationType::Info,
+            title: None,
+            description: description.into(),
+            icon: None,
+            action: None,
+            on_dismiss: None,
+        }
+    }
+
+    pub fn info(id: impl Into<ElementId>, description: impl Into<SharedString>) -> Self {
+        Self::new(id, description).with_type(NotificationType::Info)
+    }
+
+    pub fn success(id: impl Into<ElementId>, description: impl Into<SharedString>) -> Self {
+        Self::new(id, description).with_type(NotificationType::Success)
+    }
+
+    pub fn warning(id: impl Into<ElementId>, description: impl Into<SharedString>) -> Self {
+        Self::new(id, description).with_type(NotificationType::Warning)
+    }
+
+    pub fn error(id: impl Into<ElementId>, description: impl Into<SharedString>) -> Self {
+        Self::new(id, description).with_type(NotificationType::Error)
+    }
+
+    /// Set the type of the alert, default is NotificationType::Info.
+    pub fn with_type(mut self, type_: NotificationType) -> Self {
+        self.type_ = type_;
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<SharedString>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the icon to replace the default icon of the type.
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Add an action button on the right, e.g.: "Retry", "Upgrade".
+    pub fn action(
+        mut self,
+        label: impl Into<SharedString>,
+        on_click: impl Fn(&ClickEvent, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.action = Some((label.into(), Rc::new(on_click)));
+        self
+    }
+
+    /// Show the dismiss button, the alert should be removed by the parent in the handler.
+    pub fn on_dismiss(
+        mut self,
+        handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_dismiss = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl RenderOnce for Alert {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let color = self.type_.color();
+        let icon = self
+            .icon
+            .map(|icon| icon.text_color(color))
+            .unwrap_or_else(|| self.type_.icon());
+
+        h_flex()
+            .id(self.id)
+            .w_full()
+            .items_start()
+            .gap_3()
+            .px_4()
+            .py_3()
+            .rounded_md()
+            .border_1()
+            .border_color(cx.theme().translucent(color, 0.5))
+            .bg(cx.theme().translucent(color, 0.08))
+            .child(div().flex_shrink_0().py_0p5().child(icon))
+            .child(
+                v_flex()
+                    .flex_1()
+                    .gap_1()
+                    .overflow_hidden()
+                    .text_sm()
+                    .when_some(self.title, |this, title| {
+                        this.child(div().font_semibold().child(title))
+                    })
+                    .child(
+                        div()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(self.description),
+                    ),
+            )
+            .when_some(self.action, |this, (label, on_click)| {
+                this.child(
+                    Button::new("action")
+                        .label(label)
+                        .outline()
+                        .small()
+                        .on_click(move |event, cx| on_click(event, cx)),
+                )
+            })
+            .when_some(self.on_dismiss, |this, on_dismiss| {
+                this.child(
+                    Button::new("dismiss")
+                        .icon(IconName::Close)
+                        .ghost()
+                        .xsmall()
+                        .on_click(move |event, cx| on_dismiss(event, cx)),
+                )
+            })
+    }
+}
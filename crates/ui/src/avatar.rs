@@ -0,0 +1,294 @@
+use gpui::{
+    div, img, prelude::FluentBuilder as _, px, Div, Hsla, ImageSource, IntoElement,
+    ParentElement as _, Pixels, RenderOnce, SharedString, Styled, WindowContext,
+};
+
+use crate::{
+    h_flex,
+    theme::{ActiveTheme as _, ComponentTheme, Theme},
+    Icon, IconName, Sizable, Size,
+};
+
+/// The style tokens of the [`Avatar`] and [`AvatarGroup`], override them by [`Theme::override_component`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AvatarTheme {
+    /// The background of the fallback initials or icon.
+    pub background: Hsla,
+    pub foreground: Hsla,
+    /// The ring color around the overlapped avatars and the status dot.
+    pub ring: Hsla,
+    pub online: Hsla,
+    pub away: Hsla,
+    pub busy: Hsla,
+    pub offline: Hsla,
+}
+
+impl ComponentTheme for AvatarTheme {
+    fn from_theme(theme: &Theme) -> Self {
+        Self {
+            background: theme.secondary,
+            foreground: theme.secondary_foreground,
+            ring: theme.background,
+            online: crate::green_500(),
+            away: crate::yellow_500(),
+            busy: theme.destructive,
+            offline: crate::gray_400(),
+        }
+    }
+}
+
+/// The presence status of the [`Avatar`], shown as a dot on the bottom right.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvatarStatus {
+    Online,
+    Away,
+    Busy,
+    Offline,
+}
+
+impl AvatarStatus {
+    fn color(&self, theme: &AvatarTheme) -> Hsla {
+        match self {
+            Self::Online => theme.online,
+            Self::Away => theme.away,
+            Self::Busy => theme.busy,
+            Self::Offline => theme.offline,
+        }
+    }
+}
+
+/// Return the diameter of the avatar in the size.
+fn avatar_size(size: Size) -> Pixels {
+    match size {
+        Size::Size(size) => size,
+        Size::XSmall => px(20.),
+        Size::Small => px(24.),
+        Size::Medium => px(32.),
+        Size::Large => px(48.),
+    }
+}
+
+/// Return the initials of the name, e.g.: "Jason Lee" -> "JL", "jason" -> "J".
+fn initials(name: &str) -> String {
+    let mut words = name.split_whitespace();
+    let first = words.next().and_then(|word| word.chars().next());
+    let last = words.last().and_then(|word| word.chars().next());
+
+    first
+        .into_iter()
+        .chain(last)
+        .flat_map(|c| c.to_uppercase())
+        .collect()
+}
+
+/// A user image in a circle, fallback to the initials of the name or an icon.
+#[derive(IntoElement)]
+pub struct Avatar {
+    base: Div,
+    src: Option<ImageSource>,
+    name: Option<SharedString>,
+    icon: Icon,
+    status: Option<AvatarStatus>,
+    size: Size,
+}
+
+impl Avatar {
+    pub fn new() -> Self {
+        Self {
+            base: div(),
+            src: None,
+            name: None,
+            icon: Icon::new(IconName::User),
+            status: None,
+            size: Size::Medium,
+        }
+    }
+
+    /// Set the image source, the fallback is shown until the image is loaded.
+    pub fn src(mut self, src: impl Into<ImageSource>) -> Self {
+        self.src = Some(src.into());
+        self
+    }
+
+    /// Set the name to show the initials as the fallback.
+    pub fn name(mut self, name: impl Into<SharedString>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the fallback icon when there is no name, default: [`IconName::User`].
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = icon.into();
+        self
+    }
+
+    pub fn status(mut self, status: AvatarStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+}
+
+impl Sizable for Avatar {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl Styled for Avatar {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Avatar {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let theme = cx.theme().component::<AvatarTheme>();
+        let diameter = avatar_size(self.size);
+        let dot_size = (diameter * 0.3).max(px(6.));
+
+        self.base
+            .relative()
+            .flex_shrink_0()
+            .size(diameter)
+            .rounded_full()
+            .child(
+                h_flex()
+                    .relative()
+                    .size_full()
+                    .justify_center()
+                    .overflow_hidden()
+                    .rounded_full()
+                    .bg(theme.background)
+                    .text_color(theme.foreground)
+                    .text_size(diameter * 0.4)
+                    .map(|this| match self.name.as_deref().map(initials) {
+                        Some(initials) if !initials.is_empty() => this.child(initials),
+                        _ => this.child(self.icon.with_size(Size::Size(diameter * 0.5))),
+                    })
+                    // The image is drawn over the fallback, so the fallback is visible when loading or failed.
+                    .when_some(self.src, |this, src| {
+                        this.child(img(src).absolute().inset_0().size_full().rounded_full())
+                    }),
+            )
+            .when_some(self.status, |this, status| {
+                this.child(
+                    div()
+                        .absolute()
+                        .right_0()
+                        .bottom_0()
+                        .size(dot_size)
+                        .rounded_full()
+                        .border_2()
+                        .border_color(theme.ring)
+                        .bg(status.color(&theme)),
+                )
+            })
+    }
+}
+
+/// Return the number of the visible avatars and the collapsed rest.
+fn split_count(total: usize, max: Option<usize>) -> (usize, usize) {
+    match max {
+        Some(max) if total > max => (max, total - max),
+        _ => (total, 0),
+    }
+}
+
+/// A row of the overlapped [`Avatar`]s, the avatars over the `max` are collapsed into a "+N".
+#[derive(IntoElement)]
+pub struct AvatarGroup {
+    avatars: Vec<Avatar>,
+    max: Option<usize>,
+    size: Size,
+}
+
+impl AvatarGroup {
+    pub fn new() -> Self {
+        Self {
+            avatars: Vec::new(),
+            max: None,
+            size: Size::Medium,
+        }
+    }
+
+    pub fn child(mut self, avatar: Avatar) -> Self {
+        self.avatars.push(avatar);
+        self
+    }
+
+    pub fn children(mut self, avatars: impl IntoIterator<Item = Avatar>) -> Self {
+        self.avatars.extend(avatars);
+        self
+    }
+
+    /// Set the max number of the visible avatars, default is show all.
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+}
+
+impl Sizable for AvatarGroup {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl RenderOnce for AvatarGroup {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let theme = cx.theme().component::<AvatarTheme>();
+        let diameter = avatar_size(self.size);
+        let overlap = -(diameter * 0.25);
+        let (visible, rest) = split_count(self.avatars.len(), self.max);
+        let size = self.size;
+
+        h_flex()
+            .children(self.avatars.into_iter().take(visible).enumerate().map(
+                move |(ix, avatar)| {
+                    avatar
+                        .with_size(size)
+                        .border_2()
+                        .border_color(theme.ring)
+                        .when(ix > 0, |this| this.ml(overlap))
+                },
+            ))
+            .when(rest > 0, |this| {
+                this.child(
+                    h_flex()
+                        .flex_shrink_0()
+                        .size(diameter)
+                        .ml(overlap)
+                        .justify_center()
+                        .rounded_full()
+                        .border_2()
+                        .border_color(theme.ring)
+                        .bg(cx.theme().muted)
+                        .text_color(cx.theme().muted_foreground)
+                        .text_size(diameter * 0.35)
+                        .child(format!("+{}", rest)),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{initials, split_count};
+
+    #[test]
+    fn test_initials() {
+        assert_eq!(initials("Jason Lee"), "JL");
+        assert_eq!(initials("jason"), "J");
+        assert_eq!(initials("  mary jane watson "), "MW");
+        assert_eq!(initials(""), "");
+    }
+
+    #[test]
+    fn test_split_count() {
+        assert_eq!(split_count(5, None), (5, 0));
+        assert_eq!(split_count(5, Some(3)), (3, 2));
+        assert_eq!(split_count(2, Some(3)), (2, 0));
+    }
+}
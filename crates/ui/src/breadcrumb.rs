@@ -0,0 +1,241 @@
+use std::{ops::Range, rc::Rc};
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnyElement, InteractiveElement as _, IntoElement,
+    ParentElement as _, Pixels, Render, SharedString, StatefulInteractiveElement as _, Styled as _,
+    ViewContext, WindowContext,
+};
+
+use crate::{
+    button::{Button, ButtonStyled as _},
+    h_flex,
+    overflow::OverflowWidths,
+    popup_menu::PopupMenuExt as _,
+    theme::ActiveTheme as _,
+    Icon, IconName, Sizable as _, StyledExt as _,
+};
+
+/// The width of the ellipsis button with its separator.
+const ELLIPSIS_WIDTH: Pixels = px(40.);
+
+/// A segment of the [`Breadcrumb`].
+#[derive(Clone)]
+pub struct BreadcrumbItem {
+    label: SharedString,
+    icon: Option<Icon>,
+    on_click: Option<Rc<dyn Fn(&mut WindowContext)>>,
+}
+
+impl BreadcrumbItem {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            icon: None,
+            on_click: None,
+        }
+    }
+
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the handler when the item is clicked, the item without the handler is not clickable.
+    pub fn on_click(mut self, handler: impl Fn(&mut WindowContext) + 'static) -> Self {
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+}
+
+#[derive(Clone)]
+enum Separator {
+    Icon(Icon),
+    Text(SharedString),
+}
+
+/// Return the range of the items to collapse into the ellipsis, the first and the last items are always visible.
+///
+/// The `widths` are the widths of the items with their leading separators.
+fn collapsed_range(widths: &[Pixels], available: Pixels) -> Option<Range<usize>> {
+    let total = widths.iter().fold(px(0.), |sum, width| sum + *width);
+    if widths.len() <= 2 || total <= available {
+        return None;
+    }
+
+    let mut width = total + ELLIPSIS_WIDTH;
+    for end in 2..widths.len() {
+        width = width - widths[end - 1];
+        if width <= available {
+            return Some(1..end);
+        }
+    }
+
+    Some(1..widths.len() - 1)
+}
+
+/// A navigation path of the segments, the middle segments are collapsed into an ellipsis menu when there is no enough space.
+pub struct Breadcrumb {
+    items: Vec<BreadcrumbItem>,
+    separator: Separator,
+    widths: OverflowWidths,
+}
+
+impl Breadcrumb {
+    pub fn new(_: &mut ViewContext<Self>) -> Self {
+        Self {
+            items: Vec::new(),
+            separator: Separator::Icon(Icon::new(IconName::ChevronRight)),
+            widths: OverflowWidths::new(),
+        }
+    }
+
+    pub fn item(mut self, item: BreadcrumbItem) -> Self {
+        self.items.push(item);
+        self.widths.push();
+        self
+    }
+
+    /// Set the icon between the items, default: [`IconName::ChevronRight`].
+    pub fn separator(mut self, icon: impl Into<Icon>) -> Self {
+        self.separator = Separator::Icon(icon.into());
+        self
+    }
+
+    /// Set the text between the items, e.g.: "/".
+    pub fn separator_text(mut self, text: impl Into<SharedString>) -> Self {
+        self.separator = Separator::Text(text.into());
+        self
+    }
+
+    pub fn items(&self) -> &[BreadcrumbItem] {
+        &self.items
+    }
+
+    /// Replace the items, e.g.: when the path is changed.
+    pub fn set_items(&mut self, items: Vec<BreadcrumbItem>, cx: &mut ViewContext<Self>) {
+        self.widths.reset(items.len());
+        self.items = items;
+        cx.notify();
+    }
+
+    fn render_separator(&self, cx: &WindowContext) -> impl IntoElement {
+        div()
+            .flex_shrink_0()
+            .px_1p5()
+            .text_color(cx.theme().muted_foreground)
+            .map(|this| match self.separator.clone() {
+                Separator::Icon(icon) => this.child(icon.small()),
+                Separator::Text(text) => this.child(text),
+            })
+    }
+
+    fn render_item(&self, ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let item = &self.items[ix];
+        let is_last = ix + 1 == self.items.len();
+        let view = cx.view().clone();
+
+        h_flex()
+            .relative()
+            .flex_shrink_0()
+            .when(ix > 0, |this| this.child(self.render_separator(cx)))
+            .child(
+                h_flex()
+                    .id(("breadcrumb-item", ix))
+                    .gap_1()
+                    .rounded_md()
+                    .map(|this| {
+                        if is_last {
+                            this.text_color(cx.theme().foreground).font_medium()
+                        } else {
+                            this.text_color(cx.theme().muted_foreground)
+                        }
+                    })
+                    .when_some(item.icon.clone(), |this, icon| this.child(icon.small()))
+                    .child(item.label.clone())
+                    .when_some(item.on_click.clone(), |this, on_click| {
+                        this.cursor_pointer()
+                            .hover(|this| this.text_color(cx.theme().foreground))
+                            .on_click(move |_, cx| on_click(cx))
+                    }),
+            )
+            .child(OverflowWidths::measure(
+                view,
+                Some(ix),
+                |this: &mut Self| &mut this.widths,
+            ))
+    }
+
+    fn render_ellipsis(&self, range: Range<usize>, cx: &WindowContext) -> impl IntoElement {
+        let items = self.items[range].to_vec();
+
+        h_flex()
+            .flex_shrink_0()
+            .child(self.render_separator(cx))
+            .child(
+                Button::new("breadcrumb-ellipsis")
+                    .icon(IconName::Ellipsis)
+                    .ghost()
+                    .xsmall()
+                    .popup_menu(move |menu, _| {
+                        items.iter().fold(menu, |menu, item| {
+                            let on_click = item.on_click.clone();
+                            menu.menu_with_handler(
+                                item.label.clone(),
+                                item.icon.clone(),
+                                move |cx| {
+                                    if let Some(on_click) = &on_click {
+                                        on_click(cx);
+                                    }
+                                },
+                            )
+                        })
+                    }),
+            )
+    }
+}
+
+impl Render for Breadcrumb {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let collapsed = collapsed_range(&self.widths.items, self.widths.width);
+
+        let mut children: Vec<AnyElement> = Vec::with_capacity(self.items.len());
+        for ix in 0..self.items.len() {
+            match &collapsed {
+                Some(range) if range.start == ix => {
+                    children.push(self.render_ellipsis(range.clone(), cx).into_any_element())
+                }
+                Some(range) if range.contains(&ix) => {}
+                _ => children.push(self.render_item(ix, cx).into_any_element()),
+            }
+        }
+
+        h_flex()
+            .relative()
+            .w_full()
+            .overflow_hidden()
+            .text_sm()
+            .children(children)
+            .child(OverflowWidths::measure(view, None, |this: &mut Self| {
+                &mut this.widths
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::px;
+
+    use super::collapsed_range;
+
+    #[test]
+    fn test_collapsed_range() {
+        let widths = [px(50.), px(80.), px(80.), px(80.), px(60.)];
+        assert_eq!(collapsed_range(&widths, px(400.)), None);
+        assert_eq!(collapsed_range(&widths, px(320.)), Some(1..2));
+        assert_eq!(collapsed_range(&widths, px(300.)), Some(1..3));
+        assert_eq!(collapsed_range(&widths, px(200.)), Some(1..4));
+        assert_eq!(collapsed_range(&widths, px(50.)), Some(1..4));
+        assert_eq!(collapsed_range(&widths[..2], px(50.)), None);
+    }
+}
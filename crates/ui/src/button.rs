@@ -1,7 +1,7 @@
 use crate::{
     h_flex,
     indicator::Indicator,
-    theme::{ActiveTheme, Colorize as _},
+    theme::{ActiveTheme, Colorize as _, ComponentTheme, Theme},
     tooltip::Tooltip,
     Disableable, Icon, Selectable, Sizable, Size,
 };
@@ -75,15 +75,9 @@ pub trait ButtonStyled: Sized {
 }
 
 impl ButtonCustomStyle {
+    /// Create a custom style from the secondary style of the [`ButtonTheme`].
     pub fn new(cx: &WindowContext) -> Self {
-        Self {
-            color: cx.theme().secondary,
-            foreground: cx.theme().secondary_foreground,
-            border: cx.theme().border,
-            hover: cx.theme().secondary_hover,
-            active: cx.theme().secondary_active,
-            shadow: true,
-        }
+        cx.theme().component::<ButtonTheme>().secondary
     }
 
     pub fn color(mut self, color: Hsla) -> Self {
@@ -117,6 +111,50 @@ impl ButtonCustomStyle {
     }
 }
 
+/// The style tokens of the [`Button`], override them by [`Theme::override_component`].
+///
+/// The `secondary` colors are also used by the outline and ghost buttons, with a transparent background.
+#[derive(Clone, Copy, PartialEq)]
+pub struct ButtonTheme {
+    /// The radius of the `ButtonRounded::Medium`, the small is half of it and the large is double.
+    pub radius: Pixels,
+    pub primary: ButtonCustomStyle,
+    pub secondary: ButtonCustomStyle,
+    pub danger: ButtonCustomStyle,
+}
+
+impl ComponentTheme for ButtonTheme {
+    fn from_theme(theme: &Theme) -> Self {
+        Self {
+            radius: px(theme.radius),
+            primary: ButtonCustomStyle {
+                color: theme.primary,
+                foreground: theme.primary_foreground,
+                border: theme.primary,
+                hover: theme.primary_hover,
+                active: theme.primary_active,
+                shadow: true,
+            },
+            secondary: ButtonCustomStyle {
+                color: theme.secondary,
+                foreground: theme.secondary_foreground,
+                border: theme.border,
+                hover: theme.secondary_hover,
+                active: theme.secondary_active,
+                shadow: true,
+            },
+            danger: ButtonCustomStyle {
+                color: theme.destructive,
+                foreground: theme.destructive_foreground,
+                border: theme.destructive,
+                hover: theme.destructive_hover,
+                active: theme.destructive_active,
+                shadow: true,
+            },
+        }
+    }
+}
+
 #[derive(Clone, Copy, PartialEq, Eq)]
 pub enum ButtonStyle {
     Primary,
@@ -150,7 +188,7 @@ pub struct Button {
     icon: Option<Icon>,
     label: Option<SharedString>,
     children: Vec<AnyElement>,
-    disabled: bool,
+    pub(crate) disabled: bool,
     pub(crate) selected: bool,
     style: ButtonStyle,
     rounded: ButtonRounded,
@@ -309,6 +347,7 @@ impl RenderOnce for Button {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
         let style: ButtonStyle = self.style;
         let normal_style = style.normal(cx);
+        let radius = cx.theme().component::<ButtonTheme>().radius;
         let icon_size = match self.size {
             Size::Size(v) => Size::Size(v * 0.75),
             _ => self.size,
@@ -325,30 +364,35 @@ impl RenderOnce for Button {
                 this.shadow_sm()
             })
             .when(!style.no_padding(), |this| {
+                let theme = cx.theme();
                 if self.label.is_none() && self.children.is_empty() {
                     // Icon Button
                     match self.size {
                         Size::Size(px) => this.size(px),
-                        Size::XSmall => this.size_5(),
-                        Size::Small => this.size_6(),
-                        Size::Large | Size::Medium => this.size_8(),
+                        Size::XSmall => this.size(theme.spacing(px(20.))),
+                        Size::Small => this.size(theme.spacing(px(24.))),
+                        Size::Large | Size::Medium => this.size(theme.spacing(px(32.))),
                     }
                 } else {
                     // Normal Button
                     match self.size {
                         Size::Size(size) => this.px(size * 0.2),
-                        Size::XSmall => this.h_5().px_1(),
-                        Size::Small => this.h_6().px_3().when(self.compact, |this| this.px_1p5()),
-                        _ => this.h_8().px_4().when(self.compact, |this| this.px_2()),
+                        Size::XSmall => this.h(theme.spacing(px(20.))).px(theme.spacing(px(4.))),
+                        Size::Small => this
+                            .h(theme.spacing(px(24.)))
+                            .px(theme.spacing(if self.compact { px(6.) } else { px(12.) })),
+                        _ => this
+                            .h(theme.spacing(px(32.)))
+                            .px(theme.spacing(if self.compact { px(8.) } else { px(16.) })),
                     }
                 }
             })
             .when(
                 self.border_corners.top_left && self.border_corners.bottom_left,
                 |this| match self.rounded {
-                    ButtonRounded::Small => this.rounded_l(px(cx.theme().radius * 0.5)),
-                    ButtonRounded::Medium => this.rounded_l(px(cx.theme().radius)),
-                    ButtonRounded::Large => this.rounded_l(px(cx.theme().radius * 2.0)),
+                    ButtonRounded::Small => this.rounded_l(radius * 0.5),
+                    ButtonRounded::Medium => this.rounded_l(radius),
+                    ButtonRounded::Large => this.rounded_l(radius * 2.0),
                     ButtonRounded::Size(px) => this.rounded_l(px),
                     ButtonRounded::None => this.rounded_none(),
                 },
@@ -356,9 +400,9 @@ impl RenderOnce for Button {
             .when(
                 self.border_corners.top_right && self.border_corners.bottom_right,
                 |this| match self.rounded {
-                    ButtonRounded::Small => this.rounded_r(px(cx.theme().radius * 0.5)),
-                    ButtonRounded::Medium => this.rounded_r(px(cx.theme().radius)),
-                    ButtonRounded::Large => this.rounded_r(px(cx.theme().radius * 2.0)),
+                    ButtonRounded::Small => this.rounded_r(radius * 0.5),
+                    ButtonRounded::Medium => this.rounded_r(radius),
+                    ButtonRounded::Large => this.rounded_r(radius * 2.0),
                     ButtonRounded::Size(px) => this.rounded_r(px),
                     ButtonRounded::None => this.rounded_none(),
                 },
@@ -440,7 +484,9 @@ impl RenderOnce for Button {
                     })
                     .children(self.children)
             })
-            .when(self.loading, |this| this.bg(normal_style.bg.opacity(0.8)))
+            .when(self.loading, |this| {
+                this.bg(cx.theme().translucent(normal_style.bg, 0.8))
+            })
             .when_some(self.tooltip.clone(), |this, tooltip| {
                 this.tooltip(move |cx| Tooltip::new(tooltip.clone(), cx))
             })
@@ -456,39 +502,43 @@ struct ButtonStyles {
 }
 
 impl ButtonStyle {
+    /// Return the colors of the style from the [`ButtonTheme`], None for the link and text styles.
+    fn colors(&self, cx: &WindowContext) -> Option<ButtonCustomStyle> {
+        let button = cx.theme().component::<ButtonTheme>();
+        match self {
+            ButtonStyle::Primary => Some(button.primary),
+            ButtonStyle::Secondary | ButtonStyle::Outline | ButtonStyle::Ghost => {
+                Some(button.secondary)
+            }
+            ButtonStyle::Danger => Some(button.danger),
+            ButtonStyle::Custom(colors) => Some(*colors),
+            ButtonStyle::Link | ButtonStyle::Text => None,
+        }
+    }
+
     fn bg_color(&self, cx: &WindowContext) -> Hsla {
         match self {
-            ButtonStyle::Primary => cx.theme().primary,
-            ButtonStyle::Secondary => cx.theme().secondary,
-            ButtonStyle::Danger => cx.theme().destructive,
             ButtonStyle::Outline | ButtonStyle::Ghost | ButtonStyle::Link | ButtonStyle::Text => {
                 cx.theme().transparent
             }
-            ButtonStyle::Custom(colors) => colors.color,
+            _ => self.colors(cx).map_or(cx.theme().transparent, |c| c.color),
         }
     }
 
     fn text_color(&self, cx: &WindowContext) -> Hsla {
         match self {
-            ButtonStyle::Primary => cx.theme().primary_foreground,
-            ButtonStyle::Secondary | ButtonStyle::Outline | ButtonStyle::Ghost => {
-                cx.theme().secondary_foreground
-            }
-            ButtonStyle::Danger => cx.theme().destructive_foreground,
             ButtonStyle::Link => cx.theme().link,
             ButtonStyle::Text => cx.theme().foreground,
-            ButtonStyle::Custom(colors) => colors.foreground,
+            _ => self
+                .colors(cx)
+                .map_or(cx.theme().foreground, |c| c.foreground),
         }
     }
 
     fn border_color(&self, cx: &WindowContext) -> Hsla {
         match self {
-            ButtonStyle::Primary => cx.theme().primary,
-            ButtonStyle::Secondary => cx.theme().border,
-            ButtonStyle::Danger => cx.theme().destructive,
-            ButtonStyle::Outline => cx.theme().border,
             ButtonStyle::Ghost | ButtonStyle::Link | ButtonStyle::Text => cx.theme().transparent,
-            ButtonStyle::Custom(colors) => colors.border,
+            _ => self.colors(cx).map_or(cx.theme().transparent, |c| c.border),
         }
     }
 
@@ -499,10 +549,12 @@ impl ButtonStyle {
         }
     }
 
-    fn shadow(&self, _: &WindowContext) -> bool {
+    fn shadow(&self, cx: &WindowContext) -> bool {
         match self {
-            ButtonStyle::Primary | ButtonStyle::Secondary | ButtonStyle::Danger => true,
-            ButtonStyle::Custom(c) => c.shadow,
+            ButtonStyle::Primary
+            | ButtonStyle::Secondary
+            | ButtonStyle::Danger
+            | ButtonStyle::Custom(_) => self.colors(cx).map_or(false, |c| c.shadow),
             _ => false,
         }
     }
@@ -524,15 +576,9 @@ impl ButtonStyle {
     }
 
     fn hovered(&self, cx: &WindowContext) -> ButtonStyles {
-        let bg = match self {
-            ButtonStyle::Primary => cx.theme().primary_hover,
-            ButtonStyle::Secondary | ButtonStyle::Outline => cx.theme().secondary_hover,
-            ButtonStyle::Danger => cx.theme().destructive_hover,
-            ButtonStyle::Ghost => cx.theme().secondary_hover,
-            ButtonStyle::Link => cx.theme().transparent,
-            ButtonStyle::Text => cx.theme().transparent,
-            ButtonStyle::Custom(colors) => colors.hover,
-        };
+        let bg = self
+            .colors(cx)
+            .map_or(cx.theme().transparent, |colors| colors.hover);
         let border = self.border_color(cx);
         let fg = match self {
             ButtonStyle::Link => cx.theme().link_hover,
@@ -551,20 +597,13 @@ impl ButtonStyle {
     }
 
     fn active(&self, cx: &WindowContext) -> ButtonStyles {
-        let bg = match self {
-            ButtonStyle::Primary => cx.theme().primary_active,
-            ButtonStyle::Secondary | ButtonStyle::Outline | ButtonStyle::Ghost => {
-                cx.theme().secondary_active
-            }
-            ButtonStyle::Danger => cx.theme().destructive_active,
-            ButtonStyle::Link => cx.theme().transparent,
-            ButtonStyle::Text => cx.theme().transparent,
-            ButtonStyle::Custom(colors) => colors.active,
-        };
+        let bg = self
+            .colors(cx)
+            .map_or(cx.theme().transparent, |colors| colors.active);
         let border = self.border_color(cx);
         let fg = match self {
             ButtonStyle::Link => cx.theme().link_active,
-            ButtonStyle::Text => cx.theme().foreground.opacity(0.7),
+            ButtonStyle::Text => cx.theme().translucent(cx.theme().foreground, 0.7),
             _ => self.text_color(cx),
         };
         let underline = self.underline(cx);
@@ -580,20 +619,13 @@ impl ButtonStyle {
     }
 
     fn selected(&self, cx: &WindowContext) -> ButtonStyles {
-        let bg = match self {
-            ButtonStyle::Primary => cx.theme().primary_active,
-            ButtonStyle::Secondary | ButtonStyle::Outline | ButtonStyle::Ghost => {
-                cx.theme().secondary_active
-            }
-            ButtonStyle::Danger => cx.theme().destructive_active,
-            ButtonStyle::Link => cx.theme().transparent,
-            ButtonStyle::Text => cx.theme().transparent,
-            ButtonStyle::Custom(colors) => colors.active,
-        };
+        let bg = self
+            .colors(cx)
+            .map_or(cx.theme().transparent, |colors| colors.active);
         let border = self.border_color(cx);
         let fg = match self {
             ButtonStyle::Link => cx.theme().link_active,
-            ButtonStyle::Text => cx.theme().foreground.opacity(0.7),
+            ButtonStyle::Text => cx.theme().translucent(cx.theme().foreground, 0.7),
             _ => self.text_color(cx),
         };
         let underline = self.underline(cx);
@@ -611,10 +643,16 @@ impl ButtonStyle {
     fn disabled(&self, cx: &WindowContext) -> ButtonStyles {
         let bg = match self {
             ButtonStyle::Link | ButtonStyle::Ghost | ButtonStyle::Text => cx.theme().transparent,
-            ButtonStyle::Primary => cx.theme().primary.opacity(0.15),
-            ButtonStyle::Danger => cx.theme().destructive.opacity(0.15),
-            ButtonStyle::Custom(style) => style.color.opacity(0.15),
-            _ => cx.theme().secondary.darken(0.2).grayscale(),
+            ButtonStyle::Primary | ButtonStyle::Danger | ButtonStyle::Custom(_) => {
+                self.colors(cx).map_or(cx.theme().transparent, |colors| {
+                    cx.theme().translucent(colors.color, 0.15)
+                })
+            }
+            _ => self
+                .colors(cx)
+                .map_or(cx.theme().transparent, |colors| colors.color)
+                .darken(0.2)
+                .grayscale(),
         };
         let fg = match self {
             ButtonStyle::Link | ButtonStyle::Text | ButtonStyle::Ghost => {
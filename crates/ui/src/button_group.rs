@@ -50,7 +50,18 @@ impl ButtonGroup {
 
     /// Adds a button as a child to the ButtonGroup.
     pub fn child(mut self, child: Button) -> Self {
-        self.children.push(child.disabled(self.disabled));
+        let disabled = self.disabled || child.disabled;
+        self.children.push(child.disabled(disabled));
+        self
+    }
+
+    /// Adds buttons as children to the ButtonGroup.
+    pub fn children(mut self, children: impl IntoIterator<Item = Button>) -> Self {
+        let disabled = self.disabled;
+        self.children.extend(children.into_iter().map(|child| {
+            let disabled = disabled || child.disabled;
+            child.disabled(disabled)
+        }));
         self
     }
 
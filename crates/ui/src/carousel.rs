@@ -0,0 +1,375 @@
+use std::{rc::Rc, time::Duration};
+
+use gpui::{
+    actions, canvas, div, ease_in_out, prelude::FluentBuilder as _, px, Animation,
+    AnimationExt as _, AnyElement, AppContext, Bounds, EventEmitter, FocusHandle, FocusableView,
+    InteractiveElement as _, IntoElement, KeyBinding, MouseButton, MouseDownEvent, MouseMoveEvent,
+    MouseUpEvent, ParentElement as _, Pixels, Render, StatefulInteractiveElement as _, Styled as _,
+    Task, ViewContext, WindowContext,
+};
+use smol::Timer;
+
+use crate::{
+    button::{Button, ButtonStyled as _},
+    h_flex,
+    theme::ActiveTheme as _,
+    Disableable as _, IconName, Sizable as _,
+};
+
+actions!(carousel, [Prev, Next]);
+
+const CONTEXT: &str = "Carousel";
+/// The ratio of the width to drag to switch the slide.
+const SWIPE_THRESHOLD: f32 = 0.2;
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("left", Prev, Some(CONTEXT)),
+        KeyBinding::new("right", Next, Some(CONTEXT)),
+    ]);
+}
+
+pub enum CarouselEvent {
+    /// The current slide is changed, with the index of the slide.
+    Change(usize),
+}
+
+/// Return the index of the slide at the `offset` from the current, wraps around if `looped`.
+fn slide_index(current: usize, offset: isize, count: usize, looped: bool) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+
+    let ix = current as isize + offset;
+    if looped {
+        Some(ix.rem_euclid(count as isize) as usize)
+    } else if ix >= 0 && (ix as usize) < count {
+        Some(ix as usize)
+    } else {
+        None
+    }
+}
+
+/// A slider of the slides, e.g.: the onboarding screens or the image previews.
+///
+/// Only the current slide and its neighbours are rendered.
+pub struct Carousel {
+    focus_handle: FocusHandle,
+    slides: Vec<Rc<dyn Fn(&mut WindowContext) -> AnyElement>>,
+    current: usize,
+    looped: bool,
+    arrows: bool,
+    dots: bool,
+    hovered: bool,
+    bounds: Bounds<Pixels>,
+    /// The x position of the mouse down, and the dragged distance.
+    drag: Option<(Pixels, Pixels)>,
+    /// The offset of the slides to animate from after the change.
+    transition_from: Pixels,
+    animation_ix: usize,
+    _autoplay_task: Option<Task<()>>,
+}
+
+impl EventEmitter<CarouselEvent> for Carousel {}
+
+impl Carousel {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            slides: Vec::new(),
+            current: 0,
+            looped: false,
+            arrows: true,
+            dots: true,
+            hovered: false,
+            bounds: Bounds::default(),
+            drag: None,
+            transition_from: px(0.),
+            animation_ix: 0,
+            _autoplay_task: None,
+        }
+    }
+
+    /// Add a slide, the builder is only called when the slide is visible.
+    pub fn slide<E>(mut self, builder: impl Fn(&mut WindowContext) -> E + 'static) -> Self
+    where
+        E: IntoElement,
+    {
+        self.slides
+            .push(Rc::new(move |cx| builder(cx).into_any_element()));
+        self
+    }
+
+    /// Set true to go to the first slide after the last one, default: false.
+    pub fn looped(mut self, looped: bool) -> Self {
+        self.looped = looped;
+        self
+    }
+
+    /// Set false to hide the prev and next buttons, default: true.
+    pub fn arrows(mut self, arrows: bool) -> Self {
+        self.arrows = arrows;
+        self
+    }
+
+    /// Set false to hide the dot indicators, default: true.
+    pub fn dots(mut self, dots: bool) -> Self {
+        self.dots = dots;
+        self
+    }
+
+    /// Go to the next slide in every interval, it's paused when hovered.
+    pub fn autoplay(mut self, interval: Duration, cx: &mut ViewContext<Self>) -> Self {
+        self.looped = true;
+        self._autoplay_task = Some(cx.spawn(|view, mut cx| async move {
+            loop {
+                Timer::after(interval).await;
+                let result = view.update(&mut cx, |view, cx| {
+                    if !view.hovered && view.drag.is_none() {
+                        view.go_by(1, cx);
+                    }
+                });
+                if result.is_err() {
+                    break;
+                }
+            }
+        }));
+        self
+    }
+
+    /// Return the index of the current slide.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Set the current slide without the event.
+    pub fn set_current(&mut self, current: usize, cx: &mut ViewContext<Self>) {
+        let current = current.min(self.slides.len().saturating_sub(1));
+        if current == self.current {
+            return;
+        }
+
+        let step = if current > self.current { 1 } else { -1 };
+        self.transition_to(current, step, cx);
+    }
+
+    fn transition_to(&mut self, current: usize, step: isize, cx: &mut ViewContext<Self>) {
+        let dragged = self.drag.map_or(px(0.), |(_, distance)| distance);
+        self.transition_from = self.bounds.size.width * step as f32 + dragged;
+        self.animation_ix += 1;
+        self.current = current;
+        cx.notify();
+    }
+
+    fn go_by(&mut self, step: isize, cx: &mut ViewContext<Self>) {
+        let Some(ix) = slide_index(self.current, step, self.slides.len(), self.looped) else {
+            return;
+        };
+        if ix == self.current {
+            return;
+        }
+
+        self.transition_to(ix, step, cx);
+        cx.emit(CarouselEvent::Change(ix));
+    }
+
+    fn prev(&mut self, _: &Prev, cx: &mut ViewContext<Self>) {
+        self.go_by(-1, cx);
+    }
+
+    fn next(&mut self, _: &Next, cx: &mut ViewContext<Self>) {
+        self.go_by(1, cx);
+    }
+
+    fn on_mouse_down(&mut self, event: &MouseDownEvent, cx: &mut ViewContext<Self>) {
+        cx.focus(&self.focus_handle);
+        self.drag = Some((event.position.x, px(0.)));
+    }
+
+    fn on_mouse_move(&mut self, event: &MouseMoveEvent, cx: &mut ViewContext<Self>) {
+        if let Some((start, distance)) = self.drag {
+            if event.position.x - start != distance {
+                self.drag = Some((start, event.position.x - start));
+                cx.notify();
+            }
+        }
+    }
+
+    fn on_mouse_up(&mut self, _: &MouseUpEvent, cx: &mut ViewContext<Self>) {
+        let Some((_, distance)) = self.drag else {
+            return;
+        };
+        // A click without dragging, e.g.: on the arrows or the dots.
+        if distance == px(0.) {
+            self.drag = None;
+            return;
+        }
+
+        let animation_ix = self.animation_ix;
+        let threshold = self.bounds.size.width * SWIPE_THRESHOLD;
+        if distance > threshold {
+            self.go_by(-1, cx);
+        } else if distance < -threshold {
+            self.go_by(1, cx);
+        }
+        // Not switched, move back to the current slide.
+        if self.animation_ix == animation_ix {
+            self.transition_from = distance;
+            self.animation_ix += 1;
+        }
+        self.drag = None;
+        cx.notify();
+    }
+}
+
+impl FocusableView for Carousel {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for Carousel {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let width = self.bounds.size.width;
+        let count = self.slides.len();
+        let dragged = self
+            .drag
+            .map(|(_, distance)| distance)
+            .filter(|distance| *distance != px(0.));
+        let from = self.transition_from;
+        let can_prev = slide_index(self.current, -1, count, self.looped).is_some();
+        let can_next = slide_index(self.current, 1, count, self.looped).is_some();
+
+        div()
+            .id("carousel")
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::prev))
+            .on_action(cx.listener(Self::next))
+            .on_hover(cx.listener(|this, hovered: &bool, _| this.hovered = *hovered))
+            .on_mouse_down(MouseButton::Left, cx.listener(Self::on_mouse_down))
+            .on_mouse_move(cx.listener(Self::on_mouse_move))
+            .on_mouse_up(MouseButton::Left, cx.listener(Self::on_mouse_up))
+            .on_mouse_up_out(MouseButton::Left, cx.listener(Self::on_mouse_up))
+            .relative()
+            .size_full()
+            .overflow_hidden()
+            .rounded_lg()
+            .child(
+                canvas(
+                    move |bounds, cx| {
+                        view.update(cx, |this, cx| {
+                            if this.bounds != bounds {
+                                this.bounds = bounds;
+                                cx.notify();
+                            }
+                        })
+                    },
+                    |_, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+            .children([-1, 0, 1].into_iter().filter_map(|offset: isize| {
+                let ix = slide_index(self.current, offset, count, self.looped)?;
+                // Skip the duplicated neighbour when there are only 2 slides.
+                if offset == -1 && count == 2 && self.looped {
+                    return None;
+                }
+                let left = width * offset as f32;
+                let slide = div()
+                    .absolute()
+                    .top_0()
+                    .h_full()
+                    .w(width)
+                    .child((self.slides[ix])(cx));
+
+                Some(match dragged {
+                    Some(dragged) => slide.left(left + dragged).into_any_element(),
+                    None => slide
+                        .with_animation(
+                            (
+                                "carousel-slide",
+                                self.animation_ix * 3 + (offset + 1) as usize,
+                            ),
+                            Animation::new(Duration::from_millis(300)).with_easing(ease_in_out),
+                            move |this, delta| this.left(left + from * (1. - delta)),
+                        )
+                        .into_any_element(),
+                })
+            }))
+            .when(self.arrows && count > 1, |this| {
+                this.child(
+                    Button::new("prev")
+                        .icon(IconName::ChevronLeft)
+                        .ghost()
+                        .small()
+                        .rounded_full()
+                        .absolute()
+                        .left_2()
+                        .top(self.bounds.size.height / 2. - px(12.))
+                        .bg(cx.theme().translucent(cx.theme().background, 0.7))
+                        .disabled(!can_prev)
+                        .on_click(cx.listener(|this, _, cx| this.go_by(-1, cx))),
+                )
+                .child(
+                    Button::new("next")
+                        .icon(IconName::ChevronRight)
+                        .ghost()
+                        .small()
+                        .rounded_full()
+                        .absolute()
+                        .right_2()
+                        .top(self.bounds.size.height / 2. - px(12.))
+                        .bg(cx.theme().translucent(cx.theme().background, 0.7))
+                        .disabled(!can_next)
+                        .on_click(cx.listener(|this, _, cx| this.go_by(1, cx))),
+                )
+            })
+            .when(self.dots && count > 1, |this| {
+                this.child(
+                    h_flex()
+                        .absolute()
+                        .bottom_3()
+                        .w_full()
+                        .justify_center()
+                        .gap_1p5()
+                        .children((0..count).map(|ix| {
+                            let active = ix == self.current;
+                            div()
+                                .id(("carousel-dot", ix))
+                                .h_2()
+                                .map(|this| if active { this.w_5() } else { this.w_2() })
+                                .rounded_full()
+                                .cursor_pointer()
+                                .bg(if active {
+                                    cx.theme().primary
+                                } else {
+                                    cx.theme().translucent(cx.theme().primary, 0.3)
+                                })
+                                .on_click(cx.listener(move |this, _, cx| {
+                                    if ix != this.current {
+                                        this.set_current(ix, cx);
+                                        cx.emit(CarouselEvent::Change(ix));
+                                    }
+                                }))
+                        })),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::slide_index;
+
+    #[test]
+    fn test_slide_index() {
+        assert_eq!(slide_index(0, 1, 3, false), Some(1));
+        assert_eq!(slide_index(0, -1, 3, false), None);
+        assert_eq!(slide_index(0, -1, 3, true), Some(2));
+        assert_eq!(slide_index(2, 1, 3, true), Some(0));
+        assert_eq!(slide_index(0, 1, 0, true), None);
+    }
+}
@@ -0,0 +1,544 @@
+use gpui::{
+    actions, anchored, canvas, deferred, div, prelude::FluentBuilder, px, rems, AppContext, Bounds,
+    ClickEvent, ElementId, EventEmitter, FocusHandle, FocusableView, InteractiveElement,
+    IntoElement, KeyBinding, Length, ParentElement, Pixels, Render, SharedString,
+    StatefulInteractiveElement, Styled, ViewContext, WindowContext,
+};
+use rust_i18n::t;
+
+use crate::{
+    h_flex, list::step_index, theme::ActiveTheme, v_flex, Icon, IconName, Sizable, Size,
+    StyleSized, StyledExt,
+};
+
+actions!(cascader, [Up, Down, Left, Right, Enter, Escape]);
+
+const CONTEXT: &str = "Cascader";
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("up", Up, Some(CONTEXT)),
+        KeyBinding::new("down", Down, Some(CONTEXT)),
+        KeyBinding::new("left", Left, Some(CONTEXT)),
+        KeyBinding::new("right", Right, Some(CONTEXT)),
+        KeyBinding::new("enter", Enter, Some(CONTEXT)),
+        KeyBinding::new("escape", Escape, Some(CONTEXT)),
+    ])
+}
+
+/// An option of the [`Cascader`], the children are shown in the next column.
+#[derive(Debug, Clone)]
+pub struct CascaderOption {
+    pub value: SharedString,
+    pub label: SharedString,
+    pub children: Vec<CascaderOption>,
+    pub disabled: bool,
+}
+
+impl CascaderOption {
+    pub fn new(value: impl Into<SharedString>, label: impl Into<SharedString>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+            children: vec![],
+            disabled: false,
+        }
+    }
+
+    pub fn children(mut self, children: impl Into<Vec<CascaderOption>>) -> Self {
+        self.children = children.into();
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+pub enum CascaderEvent {
+    /// The selected path has been changed, the values from the first level to the leaf option.
+    Change(Vec<SharedString>),
+}
+
+/// A select with multi-level options, e.g.: Country → State → City.
+///
+/// Choosing an option opens its children in the adjacent column, until a leaf option is chosen.
+pub struct Cascader {
+    id: ElementId,
+    focus_handle: FocusHandle,
+    options: Vec<CascaderOption>,
+    /// The selected values path.
+    value: Vec<SharedString>,
+    /// The active option index in each opened column.
+    active_path: Vec<usize>,
+    open: bool,
+    size: Size,
+    placeholder: Option<SharedString>,
+    width: Length,
+    disabled: bool,
+    /// Store the bounds of the input
+    bounds: Bounds<Pixels>,
+}
+
+impl Cascader {
+    pub fn new(
+        id: impl Into<ElementId>,
+        options: Vec<CascaderOption>,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            focus_handle: cx.focus_handle(),
+            options,
+            value: vec![],
+            active_path: vec![],
+            open: false,
+            size: Size::Medium,
+            placeholder: None,
+            width: Length::Auto,
+            disabled: false,
+            bounds: Bounds::default(),
+        }
+    }
+
+    /// Set the placeholder for display when no value is selected.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Set the width of the input, default: Length::Auto
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Set the disable state for the cascader.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Return the selected values path.
+    pub fn value(&self) -> &[SharedString] {
+        &self.value
+    }
+
+    /// Set the selected values path, the invalid path will be ignored.
+    pub fn set_value(&mut self, value: Vec<SharedString>, cx: &mut ViewContext<Self>) {
+        if option_indices(&self.options, &value).is_some() {
+            self.value = value;
+            cx.notify();
+        }
+    }
+
+    pub fn focus(&self, cx: &mut WindowContext) {
+        self.focus_handle.focus(cx);
+    }
+
+    fn set_open(&mut self, open: bool, cx: &mut ViewContext<Self>) {
+        self.open = open;
+        if open {
+            // Expand the columns to the selected option.
+            self.active_path = option_indices(&self.options, &self.value).unwrap_or_default();
+        }
+        cx.notify();
+    }
+
+    /// Activate the option at the `ix` of the `column`, and open its children in the next column.
+    fn activate(&mut self, column: usize, ix: usize, cx: &mut ViewContext<Self>) {
+        if self.active_path.len() == column + 1 && self.active_path[column] == ix {
+            return;
+        }
+
+        self.active_path.truncate(column);
+        self.active_path.push(ix);
+        cx.notify();
+    }
+
+    /// Confirm the active option if it is a leaf, otherwise open its children.
+    fn confirm(&mut self, column: usize, ix: usize, cx: &mut ViewContext<Self>) {
+        self.activate(column, ix, cx);
+
+        let options = active_options(&self.options, &self.active_path);
+        let Some(option) = options.last() else {
+            return;
+        };
+        if option.disabled {
+            return;
+        }
+
+        if option.is_leaf() {
+            self.value = options.iter().map(|option| option.value.clone()).collect();
+            self.open = false;
+            cx.emit(CascaderEvent::Change(self.value.clone()));
+        } else {
+            self.expand(cx);
+        }
+        cx.notify();
+    }
+
+    /// Move the active option in the last column, skip the disabled options.
+    fn move_active(&mut self, forward: bool, cx: &mut ViewContext<Self>) {
+        let column = self.active_path.len().saturating_sub(1);
+        let options = columns(&self.options, &self.active_path)
+            .get(column)
+            .copied()
+            .unwrap_or_default();
+        let current = self.active_path.get(column).copied();
+        let next = step_index(options.len(), current, forward, |ix| !options[ix].disabled);
+
+        if let Some(ix) = next {
+            self.activate(column, ix, cx);
+        }
+    }
+
+    /// Open the children of the active option, and activate the first one.
+    fn expand(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(option) = active_options(&self.options, &self.active_path).pop() else {
+            return;
+        };
+        if option.disabled {
+            return;
+        }
+        if let Some(ix) = option.children.iter().position(|option| !option.disabled) {
+            self.active_path.push(ix);
+            cx.notify();
+        }
+    }
+
+    fn up(&mut self, _: &Up, cx: &mut ViewContext<Self>) {
+        if !self.open {
+            cx.propagate();
+            return;
+        }
+        self.move_active(false, cx);
+    }
+
+    fn down(&mut self, _: &Down, cx: &mut ViewContext<Self>) {
+        if !self.open {
+            self.set_open(true, cx);
+            return;
+        }
+        self.move_active(true, cx);
+    }
+
+    fn left(&mut self, _: &Left, cx: &mut ViewContext<Self>) {
+        if !self.open {
+            cx.propagate();
+            return;
+        }
+        if self.active_path.len() > 1 {
+            self.active_path.pop();
+            cx.notify();
+        }
+    }
+
+    fn right(&mut self, _: &Right, cx: &mut ViewContext<Self>) {
+        if !self.open {
+            cx.propagate();
+            return;
+        }
+        self.expand(cx);
+    }
+
+    fn enter(&mut self, _: &Enter, cx: &mut ViewContext<Self>) {
+        if !self.open {
+            // Propagate the event to the parent view, for example to the Modal to support ENTER to confirm.
+            cx.propagate();
+            self.set_open(true, cx);
+            return;
+        }
+
+        if let Some(&ix) = self.active_path.last() {
+            self.confirm(self.active_path.len() - 1, ix, cx);
+        }
+    }
+
+    fn escape(&mut self, _: &Escape, cx: &mut ViewContext<Self>) {
+        if !self.open {
+            // Propagate the event to the parent view, for example to the Modal to support ESC to close.
+            cx.propagate();
+            return;
+        }
+
+        self.set_open(false, cx);
+    }
+
+    fn toggle_menu(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
+        self.set_open(!self.open, cx);
+        self.focus_handle.focus(cx);
+    }
+
+    fn display_title(&self, cx: &WindowContext) -> impl IntoElement {
+        let labels = option_indices(&self.options, &self.value)
+            .filter(|indices| !indices.is_empty())
+            .map(|indices| {
+                active_options(&self.options, &indices)
+                    .iter()
+                    .map(|option| option.label.to_string())
+                    .collect::<Vec<_>>()
+                    .join(" / ")
+            });
+
+        match labels {
+            Some(labels) => div().child(labels),
+            None => div().text_color(cx.theme().accent_foreground).child(
+                self.placeholder
+                    .clone()
+                    .unwrap_or_else(|| t!("Dropdown.placeholder").into()),
+            ),
+        }
+    }
+
+    fn render_column(
+        &self,
+        column: usize,
+        options: &[CascaderOption],
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let active_ix = self.active_path.get(column).copied();
+        let is_last_column = column + 1 == self.active_path.len();
+
+        v_flex()
+            .id(("cascader-column", column))
+            .min_w(rems(8.))
+            .max_h(rems(20.))
+            .p_1()
+            .overflow_y_scroll()
+            .when(column > 0, |this| {
+                this.border_l_1().border_color(cx.theme().border)
+            })
+            .children(options.iter().enumerate().map(|(ix, option)| {
+                let active = active_ix == Some(ix);
+
+                h_flex()
+                    .id(("cascader-option", ix))
+                    .gap_2()
+                    .justify_between()
+                    .rounded(px(cx.theme().radius))
+                    .list_size(self.size, cx)
+                    .when(active, |this| {
+                        this.bg(if is_last_column {
+                            cx.theme().list_active
+                        } else {
+                            cx.theme().list_hover
+                        })
+                    })
+                    .map(|this| {
+                        if option.disabled {
+                            this.cursor_not_allowed()
+                                .text_color(cx.theme().muted_foreground)
+                        } else {
+                            this.cursor_pointer()
+                                .when(!active, |this| {
+                                    this.hover(|this| this.bg(cx.theme().list_hover))
+                                })
+                                .on_mouse_move(cx.listener(move |this, _, cx| {
+                                    this.activate(column, ix, cx);
+                                }))
+                                .on_click(cx.listener(move |this, _, cx| {
+                                    this.confirm(column, ix, cx);
+                                }))
+                        }
+                    })
+                    .child(div().whitespace_nowrap().child(option.label.clone()))
+                    .when(!option.is_leaf(), |this| {
+                        this.child(
+                            Icon::new(IconName::ChevronRight)
+                                .xsmall()
+                                .text_color(cx.theme().muted_foreground),
+                        )
+                    })
+            }))
+    }
+}
+
+/// Return the index of each option in the values path, or None if the path is not found.
+fn option_indices(options: &[CascaderOption], values: &[SharedString]) -> Option<Vec<usize>> {
+    let mut options = options;
+    let mut indices = vec![];
+    for value in values {
+        let ix = options.iter().position(|option| &option.value == value)?;
+        indices.push(ix);
+        options = &options[ix].children;
+    }
+    Some(indices)
+}
+
+/// Return the options in the `active_path`, stop at the first invalid index.
+fn active_options<'a>(
+    options: &'a [CascaderOption],
+    active_path: &[usize],
+) -> Vec<&'a CascaderOption> {
+    let mut options = options;
+    let mut result = vec![];
+    for &ix in active_path {
+        let Some(option) = options.get(ix) else {
+            break;
+        };
+        result.push(option);
+        options = &option.children;
+    }
+    result
+}
+
+/// Return the options of the opened columns: the first level, and the children of each active option.
+fn columns<'a>(options: &'a [CascaderOption], active_path: &[usize]) -> Vec<&'a [CascaderOption]> {
+    let mut columns = vec![options];
+    for option in active_options(options, active_path) {
+        if option.is_leaf() {
+            break;
+        }
+        columns.push(&option.children);
+    }
+    columns
+}
+
+impl Sizable for Cascader {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl EventEmitter<CascaderEvent> for Cascader {}
+
+impl FocusableView for Cascader {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for Cascader {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let bounds = self.bounds;
+        let is_focused = self.focus_handle.is_focused(cx);
+        let outline_visible = self.open || is_focused && !self.disabled;
+
+        div()
+            .id(self.id.clone())
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::up))
+            .on_action(cx.listener(Self::down))
+            .on_action(cx.listener(Self::left))
+            .on_action(cx.listener(Self::right))
+            .on_action(cx.listener(Self::enter))
+            .on_action(cx.listener(Self::escape))
+            .relative()
+            .map(|this| match self.width {
+                Length::Definite(l) => this.flex_none().w(l),
+                Length::Auto => this.w_full(),
+            })
+            .input_text_size(self.size)
+            .child(
+                h_flex()
+                    .id("cascader-input")
+                    .w_full()
+                    .input_h(self.size, cx)
+                    .input_px(self.size, cx)
+                    .gap_1()
+                    .justify_between()
+                    .bg(cx.theme().background)
+                    .border_1()
+                    .border_color(cx.theme().input)
+                    .rounded(px(cx.theme().radius))
+                    .when(cx.theme().shadow, |this| this.shadow_sm())
+                    .when(outline_visible, |this| this.outline(cx))
+                    .map(|this| {
+                        if self.disabled {
+                            this.cursor_not_allowed()
+                                .text_color(cx.theme().muted_foreground)
+                        } else {
+                            this.cursor_pointer()
+                                .on_click(cx.listener(Self::toggle_menu))
+                        }
+                    })
+                    .child(
+                        div()
+                            .flex_1()
+                            .overflow_hidden()
+                            .whitespace_nowrap()
+                            .child(self.display_title(cx)),
+                    )
+                    .child(
+                        Icon::new(IconName::ChevronDown).text_color(cx.theme().muted_foreground),
+                    ),
+            )
+            .child(
+                canvas(
+                    move |bounds, cx| view.update(cx, |r, _| r.bounds = bounds),
+                    |_, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+            .when(self.open, |this| {
+                let columns = columns(&self.options, &self.active_path);
+
+                this.child(
+                    deferred(
+                        anchored().snap_to_window_with_margin(px(8.)).child(
+                            h_flex()
+                                .occlude()
+                                .mt_1p5()
+                                .min_w(bounds.size.width)
+                                .items_start()
+                                .bg(cx.theme().background)
+                                .border_1()
+                                .border_color(cx.theme().border)
+                                .rounded(px(cx.theme().radius))
+                                .shadow_md()
+                                .children(columns.into_iter().enumerate().map(
+                                    |(column, options)| self.render_column(column, options, cx),
+                                ))
+                                .on_mouse_down_out(cx.listener(|this, _, cx| {
+                                    this.set_open(false, cx);
+                                })),
+                        ),
+                    )
+                    .with_priority(1),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{columns, option_indices, CascaderOption};
+
+    #[test]
+    fn test_option_indices_and_columns() {
+        let options = vec![
+            CascaderOption::new("us", "United States").children(vec![
+                CascaderOption::new("ca", "California").children(vec![
+                    CascaderOption::new("sf", "San Francisco"),
+                    CascaderOption::new("la", "Los Angeles"),
+                ]),
+                CascaderOption::new("ny", "New York"),
+            ]),
+            CascaderOption::new("cn", "China"),
+        ];
+
+        assert_eq!(
+            option_indices(&options, &["us".into(), "ca".into(), "la".into()]),
+            Some(vec![0, 0, 1])
+        );
+        assert_eq!(option_indices(&options, &[]), Some(vec![]));
+        assert_eq!(option_indices(&options, &["us".into(), "la".into()]), None);
+
+        assert_eq!(columns(&options, &[]).len(), 1);
+        assert_eq!(columns(&options, &[0]).len(), 2);
+        assert_eq!(columns(&options, &[0, 0]).len(), 3);
+        // The leaf option doesn't open a new column.
+        assert_eq!(columns(&options, &[0, 0, 1]).len(), 3);
+        assert_eq!(columns(&options, &[0, 1]).len(), 2);
+        assert_eq!(columns(&options, &[1]).len(), 1);
+    }
+}
@@ -0,0 +1,710 @@
+use std::{
+    f32::consts::{FRAC_PI_2, TAU},
+    rc::Rc,
+};
+
+use gpui::{
+    canvas, div, fill, point, prelude::FluentBuilder as _, px, size, Bounds, Hsla,
+    InteractiveElement as _, IntoElement, MouseMoveEvent, ParentElement as _, Path, Pixels, Point,
+    Render, SharedString, StatefulInteractiveElement as _, Styled as _, ViewContext, WindowContext,
+};
+
+use super::scale::{format_compact, nice_ticks};
+use crate::{
+    h_flex,
+    theme::{ActiveTheme as _, ComponentTheme, Theme},
+    v_flex, StyledExt as _,
+};
+
+const TICK_COUNT: usize = 5;
+const Y_AXIS_WIDTH: f32 = 48.;
+/// The min width of each label on the x axis, the labels are skipped if there is no room.
+const X_LABEL_WIDTH: f32 = 64.;
+/// The ratio of the bars in the band of each label.
+const BAR_RATIO: f32 = 0.7;
+/// The offset of the hovered pie slice.
+const PIE_HOVER_OFFSET: f32 = 6.;
+
+/// The style tokens of the [`Chart`], override them by [`Theme::override_component`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartTheme {
+    /// The colors of the series or the pie slices in order, repeated if there are more of them.
+    pub series: Vec<Hsla>,
+    pub grid: Hsla,
+    /// The color of the axis labels.
+    pub axis: Hsla,
+    pub crosshair: Hsla,
+}
+
+impl ComponentTheme for ChartTheme {
+    fn from_theme(theme: &Theme) -> Self {
+        let is_dark = theme.mode.is_dark();
+        let pick = |light: Hsla, dark: Hsla| if is_dark { dark } else { light };
+
+        Self {
+            series: vec![
+                theme.primary,
+                pick(crate::emerald_500(), crate::emerald_400()),
+                pick(crate::amber_500(), crate::amber_400()),
+                pick(crate::rose_500(), crate::rose_400()),
+                pick(crate::violet_500(), crate::violet_400()),
+                pick(crate::cyan_500(), crate::cyan_400()),
+            ],
+            grid: theme.border,
+            axis: theme.muted_foreground,
+            crosshair: theme.muted_foreground,
+        }
+    }
+}
+
+impl ChartTheme {
+    pub(super) fn series_color(&self, ix: usize) -> Hsla {
+        match self.series.len() {
+            0 => self.axis,
+            len => self.series[ix % len],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChartKind {
+    Line,
+    /// The line chart with the filled area below the lines.
+    Area,
+    Bar,
+    /// Only the first series is used, the slices are named by the labels.
+    Pie,
+}
+
+/// The values of a [`Chart`], in the order of the labels.
+#[derive(Debug, Clone)]
+pub struct Series {
+    name: SharedString,
+    values: Vec<f64>,
+    color: Option<Hsla>,
+}
+
+impl Series {
+    pub fn new(name: impl Into<SharedString>, values: impl IntoIterator<Item = f64>) -> Self {
+        Self {
+            name: name.into(),
+            values: values.into_iter().collect(),
+            color: None,
+        }
+    }
+
+    /// Set the color, default: picked from the [`ChartTheme`] by the order.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// Return the x of the label at `ix`, the bars are centered in the bands.
+pub(super) fn x_position(ix: usize, count: usize, width: f32, banded: bool) -> f32 {
+    if banded {
+        width * (ix as f32 + 0.5) / count as f32
+    } else if count <= 1 {
+        width / 2.
+    } else {
+        width * ix as f32 / (count - 1) as f32
+    }
+}
+
+pub(super) fn y_position(value: f64, (min, max): (f64, f64), height: f32) -> f32 {
+    if max == min {
+        return height;
+    }
+
+    height * (1. - ((value - min) / (max - min)) as f32)
+}
+
+/// Return the index of the label nearest to the `x`.
+fn nearest_index(x: f32, width: f32, count: usize, banded: bool) -> Option<usize> {
+    if count == 0 || width <= 0. || x < 0. || x > width {
+        return None;
+    }
+
+    let ix = if banded {
+        (x / width * count as f32).floor() as usize
+    } else if count == 1 {
+        0
+    } else {
+        (x / width * (count - 1) as f32).round() as usize
+    };
+    Some(ix.min(count - 1))
+}
+
+/// Return the index of the pie slice at the `angle`, clockwise from the top.
+fn slice_at(values: &[f64], angle: f32) -> Option<usize> {
+    let total: f64 = values.iter().map(|value| value.max(0.)).sum();
+    if total <= 0. {
+        return None;
+    }
+
+    let mut end = 0.;
+    for (ix, value) in values.iter().enumerate() {
+        end += value.max(0.) / total * TAU as f64;
+        if (angle as f64) < end && *value > 0. {
+            return Some(ix);
+        }
+    }
+    None
+}
+
+/// A line, area, bar or pie chart, painted with the paths.
+///
+/// ```ignore
+/// cx.new_view(|_| {
+///     Chart::new(ChartKind::Bar)
+///         .labels(["Jan", "Feb", "Mar"])
+///         .series(Series::new("Revenue", [120., 180., 150.]))
+/// })
+/// ```
+pub struct Chart {
+    kind: ChartKind,
+    labels: Vec<SharedString>,
+    series: Vec<Series>,
+    legend: bool,
+    formatter: Rc<dyn Fn(f64) -> SharedString>,
+    /// The bounds of the plot area, without the axes and the legend.
+    bounds: Bounds<Pixels>,
+    /// The hovered label, or the pie slice.
+    hovered: Option<usize>,
+    /// The mouse position in the plot area, for the tooltip.
+    mouse: Point<Pixels>,
+}
+
+impl Chart {
+    pub fn new(kind: ChartKind) -> Self {
+        Self {
+            kind,
+            labels: Vec::new(),
+            series: Vec::new(),
+            legend: true,
+            formatter: Rc::new(format_compact),
+            bounds: Bounds::default(),
+            hovered: None,
+            mouse: Point::default(),
+        }
+    }
+
+    /// Set the labels of the x axis, or the names of the pie slices.
+    pub fn labels(mut self, labels: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Add a series, it can be called multiple times.
+    pub fn series(mut self, series: Series) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// Set false to hide the legend, default: true.
+    pub fn legend(mut self, legend: bool) -> Self {
+        self.legend = legend;
+        self
+    }
+
+    /// Set the format of the values on the y axis and the tooltip, default: `1.5K`, `12M`.
+    pub fn formatter(mut self, formatter: impl Fn(f64) -> SharedString + 'static) -> Self {
+        self.formatter = Rc::new(formatter);
+        self
+    }
+
+    pub fn set_labels(
+        &mut self,
+        labels: impl IntoIterator<Item = impl Into<SharedString>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self.hovered = None;
+        cx.notify();
+    }
+
+    pub fn set_series(&mut self, series: Vec<Series>, cx: &mut ViewContext<Self>) {
+        self.series = series;
+        self.hovered = None;
+        cx.notify();
+    }
+
+    fn is_pie(&self) -> bool {
+        self.kind == ChartKind::Pie
+    }
+
+    /// The count of the labels, or the pie slices.
+    fn count(&self) -> usize {
+        if self.is_pie() {
+            return self.series.first().map_or(0, |series| series.values.len());
+        }
+
+        self.series
+            .iter()
+            .map(|series| series.values.len())
+            .max()
+            .unwrap_or(0)
+            .max(self.labels.len())
+    }
+
+    fn label(&self, ix: usize) -> SharedString {
+        self.labels.get(ix).cloned().unwrap_or_default()
+    }
+
+    /// The colors of the series, or the pie slices.
+    fn colors(&self, theme: &ChartTheme) -> Vec<Hsla> {
+        if self.is_pie() {
+            return (0..self.count()).map(|ix| theme.series_color(ix)).collect();
+        }
+
+        self.series
+            .iter()
+            .enumerate()
+            .map(|(ix, series)| series.color.unwrap_or(theme.series_color(ix)))
+            .collect()
+    }
+
+    fn ticks(&self) -> Vec<f64> {
+        let values = self.series.iter().flat_map(|series| series.values.iter());
+        let (min, max) = values.fold((0., 0.), |(min, max), value| {
+            (f64::min(min, *value), f64::max(max, *value))
+        });
+        nice_ticks(min, max, TICK_COUNT)
+    }
+
+    fn on_mouse_move(&mut self, event: &MouseMoveEvent, cx: &mut ViewContext<Self>) {
+        let mouse = event.position - self.bounds.origin;
+        let hovered = if self.is_pie() {
+            let center = point(self.bounds.size.width / 2., self.bounds.size.height / 2.);
+            let radius = self.bounds.size.width.min(self.bounds.size.height) / 2.;
+            let (dx, dy) = ((mouse.x - center.x).0, (mouse.y - center.y).0);
+            if dx.hypot(dy) <= radius.0 {
+                let angle = (dy.atan2(dx) + FRAC_PI_2).rem_euclid(TAU);
+                self.series
+                    .first()
+                    .and_then(|series| slice_at(&series.values, angle))
+            } else {
+                None
+            }
+        } else {
+            nearest_index(
+                mouse.x.0,
+                self.bounds.size.width.0,
+                self.count(),
+                self.kind == ChartKind::Bar,
+            )
+        };
+
+        if hovered.is_some() || hovered != self.hovered {
+            self.hovered = hovered;
+            self.mouse = mouse;
+            cx.notify();
+        }
+    }
+
+    fn render_swatch(color: Hsla) -> impl IntoElement {
+        div().flex_shrink_0().size_2().rounded_sm().bg(color)
+    }
+
+    fn render_legend(&self, colors: &[Hsla]) -> impl IntoElement {
+        let names: Vec<SharedString> = if self.is_pie() {
+            (0..self.count()).map(|ix| self.label(ix)).collect()
+        } else {
+            self.series
+                .iter()
+                .map(|series| series.name.clone())
+                .collect()
+        };
+
+        h_flex()
+            .flex_wrap()
+            .justify_center()
+            .gap_x_3()
+            .gap_y_1()
+            .text_xs()
+            .children(names.into_iter().zip(colors).map(|(name, color)| {
+                h_flex()
+                    .gap_1()
+                    .child(Self::render_swatch(*color))
+                    .child(name)
+            }))
+    }
+
+    fn render_tooltip(
+        &self,
+        ix: usize,
+        colors: &[Hsla],
+        cx: &ViewContext<Self>,
+    ) -> impl IntoElement {
+        let rows: Vec<(Hsla, SharedString, SharedString)> = if self.is_pie() {
+            let values = self.series.first().map_or(&[][..], |series| &series.values);
+            let total: f64 = values.iter().map(|value| value.max(0.)).sum();
+            let value = values.get(ix).copied().unwrap_or_default();
+            vec![(
+                colors[ix],
+                (self.formatter)(value),
+                format!("{:.1}%", value / total * 100.).into(),
+            )]
+        } else {
+            self.series
+                .iter()
+                .zip(colors)
+                .filter_map(|(series, color)| {
+                    let value = series.values.get(ix)?;
+                    Some((*color, series.name.clone(), (self.formatter)(*value)))
+                })
+                .collect()
+        };
+
+        // Show on the other side of the mouse in the right half.
+        let offset = px(12.);
+        let on_left = self.mouse.x > self.bounds.size.width / 2.;
+
+        v_flex()
+            .absolute()
+            .top(self.mouse.y + offset)
+            .map(|this| {
+                if on_left {
+                    this.right(self.bounds.size.width - self.mouse.x + offset)
+                } else {
+                    this.left(self.mouse.x + offset)
+                }
+            })
+            .gap_1()
+            .p_2()
+            .rounded_md()
+            .border_1()
+            .border_color(cx.theme().border)
+            .bg(cx.theme().popover)
+            .text_color(cx.theme().popover_foreground)
+            .shadow_md()
+            .text_xs()
+            .whitespace_nowrap()
+            .child(div().font_semibold().child(self.label(ix)))
+            .children(rows.into_iter().map(|(color, name, value)| {
+                h_flex()
+                    .gap_2()
+                    .child(Self::render_swatch(color))
+                    .child(div().flex_1().child(name))
+                    .child(div().font_medium().child(value))
+            }))
+    }
+
+    fn render_y_axis(&self, ticks: &[f64], theme: &ChartTheme) -> impl IntoElement {
+        let range = (ticks[0], ticks[ticks.len() - 1]);
+        let height = self.bounds.size.height.0;
+
+        div()
+            .relative()
+            .flex_shrink_0()
+            .w(px(Y_AXIS_WIDTH))
+            .h_full()
+            .text_xs()
+            .text_color(theme.axis)
+            .children(ticks.iter().map(|tick| {
+                h_flex()
+                    .absolute()
+                    .right_2()
+                    .top(px(y_position(*tick, range, height) - 8.))
+                    .h_4()
+                    .child((self.formatter)(*tick))
+            }))
+    }
+
+    fn render_x_axis(&self, theme: &ChartTheme) -> impl IntoElement {
+        let count = self.count();
+        let width = self.bounds.size.width.0;
+        let banded = self.kind == ChartKind::Bar;
+        let step = ((count as f32 * X_LABEL_WIDTH / width).ceil() as usize).max(1);
+
+        div()
+            .relative()
+            .ml(px(Y_AXIS_WIDTH))
+            .h_4()
+            .text_xs()
+            .text_color(theme.axis)
+            .children((0..count).step_by(step).map(|ix| {
+                h_flex()
+                    .absolute()
+                    .left(px(x_position(ix, count, width, banded) - X_LABEL_WIDTH / 2.))
+                    .w(px(X_LABEL_WIDTH))
+                    .justify_center()
+                    .overflow_hidden()
+                    .child(self.label(ix))
+            }))
+    }
+}
+
+/// Paint the shapes of the [`Chart`] in the plot area.
+struct ChartPainter {
+    kind: ChartKind,
+    series: Vec<Vec<f64>>,
+    colors: Vec<Hsla>,
+    count: usize,
+    ticks: Vec<f64>,
+    hovered: Option<usize>,
+    theme: ChartTheme,
+}
+
+impl ChartPainter {
+    fn paint(&self, bounds: Bounds<Pixels>, cx: &mut WindowContext) {
+        if self.count == 0 {
+            return;
+        }
+        if self.kind == ChartKind::Pie {
+            return self.paint_pie(bounds, cx);
+        }
+
+        let (width, height) = (bounds.size.width.0, bounds.size.height.0);
+        let range = (self.ticks[0], self.ticks[self.ticks.len() - 1]);
+        let banded = self.kind == ChartKind::Bar;
+        let band = width / self.count as f32;
+
+        for tick in &self.ticks {
+            let y = bounds.top() + px(y_position(*tick, range, height));
+            cx.paint_quad(fill(
+                Bounds::new(point(bounds.left(), y), size(bounds.size.width, px(1.))),
+                self.theme.grid,
+            ));
+        }
+
+        if let Some(ix) = self.hovered {
+            if banded {
+                let origin = point(bounds.left() + px(band * ix as f32), bounds.top());
+                let color = cx.theme().translucent(self.theme.crosshair, 0.1);
+                cx.paint_quad(fill(
+                    Bounds::new(origin, size(px(band), bounds.size.height)),
+                    color,
+                ));
+            } else {
+                let x = bounds.left() + px(x_position(ix, self.count, width, banded));
+                cx.paint_quad(fill(
+                    Bounds::new(point(x, bounds.top()), size(px(1.), bounds.size.height)),
+                    self.theme.crosshair,
+                ));
+            }
+        }
+
+        let zero = bounds.top() + px(y_position(0., range, height));
+        let bar_width = band * BAR_RATIO / self.series.len() as f32;
+        for (series_ix, (values, color)) in self.series.iter().zip(&self.colors).enumerate() {
+            let points: Vec<Point<Pixels>> = values
+                .iter()
+                .enumerate()
+                .map(|(ix, value)| {
+                    point(
+                        bounds.left() + px(x_position(ix, self.count, width, banded)),
+                        bounds.top() + px(y_position(*value, range, height)),
+                    )
+                })
+                .collect();
+
+            if banded {
+                for (ix, p) in points.iter().enumerate() {
+                    let x =
+                        band * (ix as f32 + (1. - BAR_RATIO) / 2.) + bar_width * series_ix as f32;
+                    let (top, bottom) = (p.y.min(zero), p.y.max(zero));
+                    cx.paint_quad(
+                        fill(
+                            Bounds::new(
+                                point(bounds.left() + px(x), top),
+                                size(px(bar_width), bottom - top),
+                            ),
+                            *color,
+                        )
+                        .corner_radii(px(2.)),
+                    );
+                }
+                continue;
+            }
+
+            let area_color = cx.theme().translucent(*color, 0.2);
+            for segment in points.windows(2) {
+                let (from, to) = (segment[0], segment[1]);
+                if self.kind == ChartKind::Area {
+                    let mut path = Path::new(from);
+                    path.line_to(to);
+                    path.line_to(point(to.x, zero));
+                    path.line_to(point(from.x, zero));
+                    cx.paint_path(path, area_color);
+                }
+                paint_line(from, to, px(2.), *color, cx);
+            }
+            for (ix, p) in points.iter().enumerate() {
+                let radius = if self.hovered == Some(ix) {
+                    px(4.)
+                } else {
+                    px(2.5)
+                };
+                cx.paint_quad(
+                    fill(
+                        Bounds::new(
+                            point(p.x - radius, p.y - radius),
+                            size(radius * 2., radius * 2.),
+                        ),
+                        *color,
+                    )
+                    .corner_radii(radius),
+                );
+            }
+        }
+    }
+
+    fn paint_pie(&self, bounds: Bounds<Pixels>, cx: &mut WindowContext) {
+        let Some(values) = self.series.first() else {
+            return;
+        };
+        let total: f64 = values.iter().map(|value| value.max(0.)).sum();
+        if total <= 0. {
+            return;
+        }
+
+        let center = bounds.center();
+        let radius = bounds.size.width.min(bounds.size.height) / 2. - px(PIE_HOVER_OFFSET);
+        let mut start = -FRAC_PI_2;
+        for (ix, value) in values.iter().enumerate() {
+            if *value <= 0. {
+                continue;
+            }
+
+            let sweep = TAU * (*value / total) as f32;
+            let radius = if self.hovered == Some(ix) {
+                radius + px(PIE_HOVER_OFFSET)
+            } else {
+                radius
+            };
+            // A fan of the triangles from the center, a segment in every ~3 degrees.
+            let steps = ((sweep / 0.05).ceil() as usize).max(1);
+            let mut path = Path::new(center);
+            for step in 0..=steps {
+                let angle = start + sweep * step as f32 / steps as f32;
+                path.line_to(point(
+                    center.x + radius * angle.cos(),
+                    center.y + radius * angle.sin(),
+                ));
+            }
+            cx.paint_path(path, self.colors[ix]);
+            start += sweep;
+        }
+    }
+}
+
+/// Paint a straight line as a quad path with the `thickness`.
+pub(super) fn paint_line(
+    from: Point<Pixels>,
+    to: Point<Pixels>,
+    thickness: Pixels,
+    color: Hsla,
+    cx: &mut WindowContext,
+) {
+    let (dx, dy) = ((to.x - from.x).0, (to.y - from.y).0);
+    let length = dx.hypot(dy);
+    if length == 0. {
+        return;
+    }
+
+    let half = thickness.0 / 2.;
+    let normal = point(px(-dy / length * half), px(dx / length * half));
+    let mut path = Path::new(from + normal);
+    path.line_to(to + normal);
+    path.line_to(to - normal);
+    path.line_to(from - normal);
+    cx.paint_path(path, color);
+}
+
+impl Render for Chart {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let theme = cx.theme().component::<ChartTheme>();
+        let colors = self.colors(&theme);
+        let ticks = self.ticks();
+        let painter = ChartPainter {
+            kind: self.kind,
+            series: self
+                .series
+                .iter()
+                .map(|series| series.values.clone())
+                .collect(),
+            colors: colors.clone(),
+            count: self.count(),
+            ticks: ticks.clone(),
+            hovered: self.hovered,
+            theme: theme.clone(),
+        };
+
+        let plot = div()
+            .id("chart-plot")
+            .relative()
+            .flex_1()
+            .h_full()
+            .on_mouse_move(cx.listener(Self::on_mouse_move))
+            .on_hover(cx.listener(|this, hovered: &bool, cx| {
+                if !*hovered {
+                    this.hovered = None;
+                    cx.notify();
+                }
+            }))
+            .child(
+                canvas(
+                    move |bounds, cx| {
+                        view.update(cx, |this, cx| {
+                            if this.bounds != bounds {
+                                this.bounds = bounds;
+                                cx.notify();
+                            }
+                        })
+                    },
+                    move |bounds, _, cx| painter.paint(bounds, cx),
+                )
+                .absolute()
+                .size_full(),
+            )
+            .when_some(self.hovered, |this, ix| {
+                this.child(self.render_tooltip(ix, &colors, cx))
+            });
+
+        v_flex()
+            .size_full()
+            .gap_2()
+            .map(|this| {
+                if self.is_pie() {
+                    this.child(h_flex().flex_1().child(plot))
+                } else {
+                    this.child(
+                        h_flex()
+                            .flex_1()
+                            .child(self.render_y_axis(&ticks, &theme))
+                            .child(plot),
+                    )
+                    .child(self.render_x_axis(&theme))
+                }
+            })
+            .when(self.legend, |this| this.child(self.render_legend(&colors)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::PI;
+
+    use super::{nearest_index, slice_at};
+
+    #[test]
+    fn test_nearest_index() {
+        assert_eq!(nearest_index(0., 100., 3, false), Some(0));
+        assert_eq!(nearest_index(30., 100., 3, false), Some(1));
+        assert_eq!(nearest_index(30., 100., 3, true), Some(0));
+        assert_eq!(nearest_index(100., 100., 3, true), Some(2));
+        assert_eq!(nearest_index(120., 100., 3, true), None);
+        assert_eq!(nearest_index(50., 100., 0, false), None);
+    }
+
+    #[test]
+    fn test_slice_at() {
+        let values = [1., 0., 1., 2.];
+        assert_eq!(slice_at(&values, 0.1), Some(0));
+        assert_eq!(slice_at(&values, PI * 0.6), Some(2));
+        assert_eq!(slice_at(&values, PI * 1.5), Some(3));
+        assert_eq!(slice_at(&[], 0.), None);
+    }
+}
@@ -0,0 +1,6 @@
+mod chart;
+mod scale;
+mod sparkline;
+
+pub use chart::*;
+pub use sparkline::*;
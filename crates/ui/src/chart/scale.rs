@@ -0,0 +1,60 @@
+use gpui::SharedString;
+
+/// Return the ticks covering `min..=max` with about `count` steps, the step is 1, 2 or 5 × 10ⁿ.
+pub(crate) fn nice_ticks(min: f64, max: f64, count: usize) -> Vec<f64> {
+    let (min, max) = if min == max {
+        (min - 1., max + 1.)
+    } else {
+        (min.min(max), min.max(max))
+    };
+
+    let rough_step = (max - min) / count.max(1) as f64;
+    let magnitude = 10f64.powf(rough_step.log10().floor());
+    let step = [1., 2., 5., 10.]
+        .into_iter()
+        .map(|factor| factor * magnitude)
+        .find(|step| *step >= rough_step)
+        .unwrap_or(10. * magnitude);
+
+    let start = (min / step).floor();
+    let end = (max / step).ceil();
+    (0..=(end - start) as usize)
+        .map(|ix| (start + ix as f64) * step)
+        .collect()
+}
+
+/// Format the value in short, e.g.: `1.5K`, `12M`, `0.25`.
+pub(crate) fn format_compact(value: f64) -> SharedString {
+    let (value, suffix) = match value.abs() {
+        abs if abs >= 1e9 => (value / 1e9, "B"),
+        abs if abs >= 1e6 => (value / 1e6, "M"),
+        abs if abs >= 1e3 => (value / 1e3, "K"),
+        _ => (value, ""),
+    };
+
+    let text = format!("{:.2}", value);
+    let text = text.trim_end_matches('0').trim_end_matches('.');
+    let text = if text == "-0" { "0" } else { text };
+    format!("{}{}", text, suffix).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_compact, nice_ticks};
+
+    #[test]
+    fn test_nice_ticks() {
+        assert_eq!(nice_ticks(0., 95., 5), vec![0., 20., 40., 60., 80., 100.]);
+        assert_eq!(nice_ticks(-3., 7., 4), vec![-5., 0., 5., 10.]);
+        assert_eq!(nice_ticks(3., 3., 2), vec![2., 3., 4.]);
+    }
+
+    #[test]
+    fn test_format_compact() {
+        assert_eq!(format_compact(0.), "0");
+        assert_eq!(format_compact(0.25), "0.25");
+        assert_eq!(format_compact(1500.), "1.5K");
+        assert_eq!(format_compact(-12_000_000.), "-12M");
+        assert_eq!(format_compact(2.5e9), "2.5B");
+    }
+}
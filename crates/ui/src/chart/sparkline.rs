@@ -0,0 +1,246 @@
+use std::{borrow::Cow, rc::Rc};
+
+use gpui::{
+    canvas, div, fill, point, px, size, Bounds, Div, Hsla, IntoElement, ParentElement as _, Pixels,
+    RenderOnce, Styled, WindowContext,
+};
+
+use super::chart::{paint_line, x_position, y_position, ChartTheme};
+use crate::theme::ActiveTheme as _;
+
+const MARKER_RADIUS: f32 = 2.;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SparklineKind {
+    #[default]
+    Line,
+    Bar,
+}
+
+/// Return the averages of the values in `count` buckets, or the values if there are fewer of them.
+fn downsample(values: &[f64], count: usize) -> Cow<[f64]> {
+    if count == 0 || values.len() <= count {
+        return Cow::Borrowed(values);
+    }
+
+    Cow::Owned(
+        (0..count)
+            .map(|ix| {
+                let start = ix * values.len() / count;
+                let end = ((ix + 1) * values.len() / count).max(start + 1);
+                let bucket = &values[start..end];
+                bucket.iter().sum::<f64>() / bucket.len() as f64
+            })
+            .collect(),
+    )
+}
+
+/// Return the indexes of the min and max values.
+fn extremes(values: &[f64]) -> Option<(usize, usize)> {
+    let mut iter = values.iter().enumerate();
+    let (first, _) = iter.next()?;
+    Some(iter.fold((first, first), |(min, max), (ix, value)| {
+        (
+            if *value < values[min] { ix } else { min },
+            if *value > values[max] { ix } else { max },
+        )
+    }))
+}
+
+/// A tiny line or bar chart without the axes, to show the trend in the table cells or cards.
+///
+/// It's painted in a single canvas, the values are averaged to about a point per 2 pixels.
+#[derive(IntoElement)]
+pub struct Sparkline {
+    base: Div,
+    values: Rc<[f64]>,
+    kind: SparklineKind,
+    color: Option<Hsla>,
+    markers: bool,
+    baseline: Option<f64>,
+}
+
+impl Sparkline {
+    /// Create a sparkline, share the values by `Rc<[f64]>` to avoid copying in every frame.
+    pub fn new(values: impl Into<Rc<[f64]>>) -> Self {
+        Self {
+            base: div().flex_shrink_0().w_20().h_6(),
+            values: values.into(),
+            kind: SparklineKind::default(),
+            color: None,
+            markers: false,
+            baseline: None,
+        }
+    }
+
+    pub fn kind(mut self, kind: SparklineKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Set the color, default: the first series color of the [`ChartTheme`].
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Set true to mark the min value in red and the max value in green.
+    pub fn markers(mut self, markers: bool) -> Self {
+        self.markers = markers;
+        self
+    }
+
+    /// Show a line at the value, e.g.: the target or the average, the bars start from it.
+    pub fn baseline(mut self, baseline: f64) -> Self {
+        self.baseline = Some(baseline);
+        self
+    }
+}
+
+impl Styled for Sparkline {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+struct SparklinePainter {
+    values: Rc<[f64]>,
+    kind: SparklineKind,
+    color: Hsla,
+    markers: bool,
+    baseline: Option<f64>,
+    baseline_color: Hsla,
+}
+
+impl SparklinePainter {
+    fn paint(&self, bounds: Bounds<Pixels>, cx: &mut WindowContext) {
+        let inset = px(MARKER_RADIUS);
+        let bounds = Bounds::new(
+            point(bounds.left() + inset, bounds.top() + inset),
+            size(
+                bounds.size.width - inset * 2.,
+                bounds.size.height - inset * 2.,
+            ),
+        );
+        let (width, height) = (bounds.size.width.0, bounds.size.height.0);
+        if width <= 0. || height <= 0. {
+            return;
+        }
+
+        let banded = self.kind == SparklineKind::Bar;
+        let values = downsample(
+            &self.values,
+            (width / if banded { 3. } else { 2. }) as usize,
+        );
+        let Some((min_ix, max_ix)) = extremes(&values) else {
+            return;
+        };
+
+        // The bars start from the baseline or zero.
+        let origin = self.baseline.unwrap_or(0.);
+        let mut range = (values[min_ix], values[max_ix]);
+        if banded || self.baseline.is_some() {
+            range = (range.0.min(origin), range.1.max(origin));
+        }
+        if range.0 == range.1 {
+            range = (range.0 - 1., range.1 + 1.);
+        }
+
+        let count = values.len();
+        let x = |ix: usize| bounds.left() + px(x_position(ix, count, width, banded));
+        let y = |value: f64| bounds.top() + px(y_position(value, range, height));
+        let marker_color = |ix: usize| {
+            if self.markers && ix == max_ix {
+                crate::green_500()
+            } else if self.markers && ix == min_ix {
+                crate::red_500()
+            } else {
+                self.color
+            }
+        };
+
+        if let Some(baseline) = self.baseline {
+            cx.paint_quad(fill(
+                Bounds::new(
+                    point(bounds.left(), y(baseline)),
+                    size(bounds.size.width, px(1.)),
+                ),
+                self.baseline_color,
+            ));
+        }
+
+        if banded {
+            let bar_width = (width / count as f32 * 0.7).max(1.);
+            for (ix, value) in values.iter().enumerate() {
+                let (top, bottom) = (y(*value).min(y(origin)), y(*value).max(y(origin)));
+                cx.paint_quad(fill(
+                    Bounds::new(
+                        point(x(ix) - px(bar_width / 2.), top),
+                        size(px(bar_width), (bottom - top).max(px(1.))),
+                    ),
+                    marker_color(ix),
+                ));
+            }
+            return;
+        }
+
+        for ix in 1..count {
+            let from = point(x(ix - 1), y(values[ix - 1]));
+            let to = point(x(ix), y(values[ix]));
+            paint_line(from, to, px(1.5), self.color, cx);
+        }
+
+        let markers = if self.markers {
+            vec![min_ix, max_ix]
+        } else if count == 1 {
+            vec![0]
+        } else {
+            vec![]
+        };
+        for ix in markers {
+            let radius = px(MARKER_RADIUS);
+            cx.paint_quad(
+                fill(
+                    Bounds::new(
+                        point(x(ix) - radius, y(values[ix]) - radius),
+                        size(radius * 2., radius * 2.),
+                    ),
+                    marker_color(ix),
+                )
+                .corner_radii(radius),
+            );
+        }
+    }
+}
+
+impl RenderOnce for Sparkline {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let theme = cx.theme().component::<ChartTheme>();
+        let painter = SparklinePainter {
+            values: self.values,
+            kind: self.kind,
+            color: self.color.unwrap_or(theme.series_color(0)),
+            markers: self.markers,
+            baseline: self.baseline,
+            baseline_color: cx.theme().translucent(theme.axis, 0.5),
+        };
+
+        self.base
+            .child(canvas(|_, _| {}, move |bounds, _, cx| painter.paint(bounds, cx)).size_full())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{downsample, extremes};
+
+    #[test]
+    fn test_downsample() {
+        let values = [1., 3., 2., 4., 6., 8.];
+        assert_eq!(downsample(&values, 10).as_ref(), &values);
+        assert_eq!(downsample(&values, 3).as_ref(), &[2., 3., 7.]);
+        assert_eq!(downsample(&values, 4).as_ref(), &[1., 2.5, 4., 7.]);
+        assert_eq!(extremes(&values), Some((0, 5)));
+        assert_eq!(extremes(&[]), None);
+    }
+}
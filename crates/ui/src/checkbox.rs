@@ -1,7 +1,9 @@
+use std::rc::Rc;
+
 use gpui::{
-    div, prelude::FluentBuilder as _, relative, svg, ElementId, InteractiveElement, IntoElement,
-    ParentElement, RenderOnce, SharedString, StatefulInteractiveElement as _, Styled as _,
-    WindowContext,
+    actions, div, prelude::FluentBuilder as _, relative, svg, AppContext, ElementId, EventEmitter,
+    FocusHandle, InteractiveElement, IntoElement, KeyBinding, ParentElement, Render, RenderOnce,
+    SharedString, StatefulInteractiveElement as _, Styled as _, ViewContext, WindowContext,
 };
 
 use crate::{
@@ -10,22 +12,70 @@ use crate::{
     v_flex, Disableable, IconName, Selectable,
 };
 
+actions!(checkbox, [Toggle]);
+
+const CONTEXT: &str = "Checkbox";
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([KeyBinding::new("space", Toggle, Some(CONTEXT))]);
+}
+
+/// The state of the [`Checkbox`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CheckboxState {
+    #[default]
+    Unchecked,
+    Checked,
+    /// Some of the children are checked, e.g.: the "Select all" of a [`CheckboxGroup`].
+    Indeterminate,
+}
+
+impl CheckboxState {
+    /// Return the state of a parent checkbox by the number of the checked children.
+    pub fn from_count(checked: usize, total: usize) -> Self {
+        if checked == 0 {
+            Self::Unchecked
+        } else if checked >= total {
+            Self::Checked
+        } else {
+            Self::Indeterminate
+        }
+    }
+
+    pub fn is_checked(&self) -> bool {
+        *self == Self::Checked
+    }
+}
+
+impl From<bool> for CheckboxState {
+    fn from(checked: bool) -> Self {
+        if checked {
+            Self::Checked
+        } else {
+            Self::Unchecked
+        }
+    }
+}
+
 #[derive(IntoElement)]
 pub struct Checkbox {
     id: ElementId,
-    checked: bool,
+    state: CheckboxState,
     disabled: bool,
     label: Option<SharedString>,
-    on_click: Option<Box<dyn Fn(&bool, &mut WindowContext) + 'static>>,
+    description: Option<SharedString>,
+    focus_handle: Option<FocusHandle>,
+    on_click: Option<Rc<dyn Fn(&bool, &mut WindowContext) + 'static>>,
 }
 
 impl Checkbox {
     pub fn new(id: impl Into<ElementId>) -> Self {
         Self {
             id: id.into(),
-            checked: false,
+            state: CheckboxState::Unchecked,
             disabled: false,
             label: None,
+            description: None,
+            focus_handle: None,
             on_click: None,
         }
     }
@@ -35,13 +85,39 @@ impl Checkbox {
         self
     }
 
+    /// Set the description below the label.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
     pub fn checked(mut self, checked: bool) -> Self {
-        self.checked = checked;
+        self.state = checked.into();
+        self
+    }
+
+    /// Set true to show the indeterminate state, it's checked when clicked.
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        if indeterminate {
+            self.state = CheckboxState::Indeterminate;
+        }
+        self
+    }
+
+    pub fn state(mut self, state: CheckboxState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Track the focus of the checkbox, to toggle it by pressing `space` when focused.
+    pub fn track_focus(mut self, focus_handle: &FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle.clone());
         self
     }
 
+    /// Set the click handler, the argument is the new checked state.
     pub fn on_click(mut self, handler: impl Fn(&bool, &mut WindowContext) + 'static) -> Self {
-        self.on_click = Some(Box::new(handler));
+        self.on_click = Some(Rc::new(handler));
         self
     }
 }
@@ -64,32 +140,63 @@ impl RenderOnce for Checkbox {
         let theme = cx.theme();
 
         let group_id = format!("checkbox_group_{:?}", self.id);
+        let focused = self
+            .focus_handle
+            .as_ref()
+            .map_or(false, |handle| handle.is_focused(cx));
 
-        let (color, icon_color) = if self.disabled {
-            (
-                theme.primary.opacity(0.5),
-                theme.primary_foreground.opacity(0.5),
-            )
+        let (border_color, color, icon_color) = if self.disabled {
+            (theme.border, theme.muted, theme.muted_foreground)
+        } else if focused {
+            (theme.ring, theme.primary, theme.primary_foreground)
         } else {
-            (theme.primary, theme.primary_foreground)
+            (theme.primary, theme.primary, theme.primary_foreground)
+        };
+
+        let icon = match self.state {
+            CheckboxState::Checked => Some(IconName::Check),
+            CheckboxState::Indeterminate => Some(IconName::Minus),
+            CheckboxState::Unchecked => None,
         };
+        // The indeterminate state is checked when clicked.
+        let checked = !self.state.is_checked();
+        let on_click = self.on_click.filter(|_| !self.disabled);
 
         h_flex()
             .id(self.id)
             .group(group_id.clone())
             .gap_2()
-            .items_center()
+            .map(|this| {
+                if self.description.is_some() {
+                    this.items_start()
+                } else {
+                    this.items_center()
+                }
+            })
             .line_height(relative(1.))
+            .when_some(
+                self.focus_handle.filter(|_| !self.disabled),
+                |this, focus_handle| {
+                    this.track_focus(&focus_handle)
+                        .key_context(CONTEXT)
+                        .when_some(on_click.clone(), |this, on_click| {
+                            this.on_action(move |_: &Toggle, cx| {
+                                on_click(&checked, cx);
+                                cx.refresh()
+                            })
+                        })
+                },
+            )
             .child(
                 v_flex()
                     .relative()
                     .border_1()
-                    .border_color(color)
+                    .border_color(border_color)
                     .rounded_sm()
                     .size_4()
                     .flex_shrink_0()
-                    .map(|this| match self.checked {
-                        false => this.bg(theme.transparent),
+                    .map(|this| match self.state {
+                        CheckboxState::Unchecked => this.bg(theme.transparent),
                         _ => this.bg(color),
                     })
                     .group_hover(group_id, |this| {
@@ -106,39 +213,293 @@ impl RenderOnce for Checkbox {
                             .left_px()
                             .size_3()
                             .text_color(icon_color)
-                            .map(|this| match self.checked {
-                                true => this.path(IconName::Check.path()),
-                                _ => this,
-                            }),
+                            .when_some(icon, |this, icon| this.path(icon.path())),
                     ),
             )
             .map(|this| {
-                if let Some(label) = self.label {
-                    this.text_color(cx.theme().foreground).child(
-                        div()
-                            .w_full()
-                            .overflow_x_hidden()
-                            .text_ellipsis()
-                            .line_height(relative(1.))
-                            .child(label),
-                    )
-                } else {
-                    this
+                if self.label.is_none() && self.description.is_none() {
+                    return this;
                 }
+
+                this.text_color(cx.theme().foreground).child(
+                    v_flex()
+                        .w_full()
+                        .gap_1()
+                        .overflow_x_hidden()
+                        .when_some(self.label, |this, label| {
+                            this.child(
+                                div()
+                                    .w_full()
+                                    .overflow_x_hidden()
+                                    .text_ellipsis()
+                                    .line_height(relative(1.))
+                                    .child(label),
+                            )
+                        })
+                        .when_some(self.description, |this, description| {
+                            this.child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(description),
+                            )
+                        }),
+                )
             })
             .when(self.disabled, |this| {
                 this.cursor_not_allowed()
                     .text_color(cx.theme().muted_foreground)
             })
-            .when_some(
-                self.on_click.filter(|_| !self.disabled),
-                |this, on_click| {
-                    this.on_click(move |_, cx| {
-                        let checked = !self.checked;
-                        on_click(&checked, cx);
-                        cx.refresh()
+            .when_some(on_click, |this, on_click| {
+                this.on_click(move |_, cx| {
+                    on_click(&checked, cx);
+                    cx.refresh()
+                })
+            })
+    }
+}
+
+/// An option of the [`CheckboxGroup`].
+#[derive(Clone)]
+pub struct CheckboxGroupItem {
+    value: SharedString,
+    label: SharedString,
+    description: Option<SharedString>,
+    disabled: bool,
+}
+
+impl CheckboxGroupItem {
+    pub fn new(value: impl Into<SharedString>, label: impl Into<SharedString>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+            description: None,
+            disabled: false,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// Events emitted by the [`CheckboxGroup`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum CheckboxGroupEvent {
+    /// The checked values are changed, in the order of the items.
+    Change(Vec<SharedString>),
+}
+
+/// A group of checkboxes to manage a set of values, with an optional "Select all" checkbox.
+pub struct CheckboxGroup {
+    items: Vec<CheckboxGroupItem>,
+    values: Vec<SharedString>,
+    select_all: Option<SharedString>,
+    horizontal: bool,
+    disabled: bool,
+    focus_handles: Vec<FocusHandle>,
+}
+
+impl CheckboxGroup {
+    pub fn new(_: &mut ViewContext<Self>) -> Self {
+        Self {
+            items: vec![],
+            values: vec![],
+            select_all: None,
+            horizontal: false,
+            disabled: false,
+            focus_handles: vec![],
+        }
+    }
+
+    pub fn items(mut self, items: Vec<CheckboxGroupItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    /// Set the initial checked values.
+    pub fn values(mut self, values: Vec<SharedString>) -> Self {
+        self.values = values;
+        self
+    }
+
+    /// Show a "Select all" checkbox with the label at the top, it's indeterminate if some values are checked.
+    pub fn select_all(mut self, label: impl Into<SharedString>) -> Self {
+        self.select_all = Some(label.into());
+        self
+    }
+
+    /// Lay out the checkboxes in a row, default is in a column.
+    pub fn horizontal(mut self) -> Self {
+        self.horizontal = true;
+        self
+    }
+
+    /// Return the checked values, in the order of the items.
+    pub fn checked(&self) -> &[SharedString] {
+        &self.values
+    }
+
+    pub fn is_checked(&self, value: &str) -> bool {
+        self.values.iter().any(|v| v.as_ref() == value)
+    }
+
+    /// Return the state of the "Select all" checkbox, the disabled items are not counted.
+    pub fn state(&self) -> CheckboxState {
+        let enabled = self.items.iter().filter(|item| !item.disabled);
+        let (checked, total) = enabled.fold((0, 0), |(checked, total), item| {
+            (checked + self.is_checked(&item.value) as usize, total + 1)
+        });
+        CheckboxState::from_count(checked, total)
+    }
+
+    pub fn set_values(&mut self, values: Vec<SharedString>, cx: &mut ViewContext<Self>) {
+        self.values = checked_values(&self.items, |value| values.contains(value));
+        cx.notify();
+    }
+
+    pub fn set_disabled(&mut self, disabled: bool, cx: &mut ViewContext<Self>) {
+        self.disabled = disabled;
+        cx.notify();
+    }
+
+    /// Check or uncheck the value, and emit the change.
+    pub fn toggle(&mut self, value: &str, checked: bool, cx: &mut ViewContext<Self>) {
+        self.values = checked_values(&self.items, |v| {
+            if v.as_ref() == value {
+                checked
+            } else {
+                self.values.contains(v)
+            }
+        });
+        cx.emit(CheckboxGroupEvent::Change(self.values.clone()));
+        cx.notify();
+    }
+
+    /// Check or uncheck all the enabled items, and emit the change.
+    pub fn toggle_all(&mut self, checked: bool, cx: &mut ViewContext<Self>) {
+        self.values = checked_values(&self.items, |v| {
+            let disabled = self
+                .items
+                .iter()
+                .any(|item| item.disabled && &item.value == v);
+            if disabled {
+                self.values.contains(v)
+            } else {
+                checked
+            }
+        });
+        cx.emit(CheckboxGroupEvent::Change(self.values.clone()));
+        cx.notify();
+    }
+}
+
+/// Return the values of the items that are checked, in the order of the items.
+fn checked_values(
+    items: &[CheckboxGroupItem],
+    is_checked: impl Fn(&SharedString) -> bool,
+) -> Vec<SharedString> {
+    items
+        .iter()
+        .filter(|item| is_checked(&item.value))
+        .map(|item| item.value.clone())
+        .collect()
+}
+
+impl EventEmitter<CheckboxGroupEvent> for CheckboxGroup {}
+
+impl Render for CheckboxGroup {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        // One more focus handle for the "Select all" checkbox.
+        let len = self.items.len() + 1;
+        if self.focus_handles.len() != len {
+            self.focus_handles.resize_with(len, || cx.focus_handle());
+        }
+
+        let checkboxes = self.items.iter().enumerate().map(|(ix, item)| {
+            let value = item.value.clone();
+            Checkbox::new(("checkbox-group-item", ix))
+                .label(item.label.clone())
+                .when_some(item.description.clone(), |this, description| {
+                    this.description(description)
+                })
+                .checked(self.is_checked(&item.value))
+                .disabled(self.disabled || item.disabled)
+                .track_focus(&self.focus_handles[ix + 1])
+                .on_click(cx.listener(move |this, checked, cx| {
+                    this.toggle(&value, *checked, cx);
+                }))
+        });
+
+        v_flex()
+            .gap_3()
+            .when_some(self.select_all.clone(), |this, label| {
+                this.child(
+                    Checkbox::new("checkbox-group-all")
+                        .label(label)
+                        .state(self.state())
+                        .disabled(self.disabled)
+                        .track_focus(&self.focus_handles[0])
+                        .on_click(cx.listener(|this, checked, cx| {
+                            this.toggle_all(*checked, cx);
+                        })),
+                )
+            })
+            .child(
+                div()
+                    .flex()
+                    .gap_3()
+                    .map(|this| {
+                        if self.horizontal {
+                            this.flex_row().flex_wrap()
+                        } else {
+                            this.flex_col()
+                        }
                     })
-                },
+                    .when(self.select_all.is_some() && !self.horizontal, |this| {
+                        this.pl_6()
+                    })
+                    .children(checkboxes),
             )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::SharedString;
+
+    use super::{checked_values, CheckboxGroupItem, CheckboxState};
+
+    #[test]
+    fn test_state_from_count() {
+        assert_eq!(CheckboxState::from_count(0, 3), CheckboxState::Unchecked);
+        assert_eq!(
+            CheckboxState::from_count(2, 3),
+            CheckboxState::Indeterminate
+        );
+        assert_eq!(CheckboxState::from_count(3, 3), CheckboxState::Checked);
+        assert_eq!(CheckboxState::from_count(0, 0), CheckboxState::Unchecked);
+    }
+
+    #[test]
+    fn test_checked_values() {
+        let items = vec![
+            CheckboxGroupItem::new("a", "A"),
+            CheckboxGroupItem::new("b", "B"),
+            CheckboxGroupItem::new("c", "C"),
+        ];
+
+        // The values are kept in the order of the items.
+        assert_eq!(
+            checked_values(&items, |v| v.as_ref() == "c" || v.as_ref() == "a"),
+            vec![SharedString::from("a"), SharedString::from("c")]
+        );
+        assert!(checked_values(&items, |_| false).is_empty());
+    }
+}
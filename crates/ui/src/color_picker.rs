@@ -1,9 +1,9 @@
 use gpui::{
-    anchored, canvas, deferred, div, prelude::FluentBuilder as _, px, relative, AnchorCorner,
-    AppContext, Bounds, ElementId, EventEmitter, FocusHandle, FocusableView, Hsla,
-    InteractiveElement as _, IntoElement, KeyBinding, MouseButton, ParentElement, Pixels, Point,
-    Render, SharedString, StatefulInteractiveElement as _, Styled, View, ViewContext,
-    VisualContext,
+    anchored, canvas, deferred, div, fill, hsla, point, prelude::FluentBuilder as _, px, relative,
+    size, AnchorCorner, AppContext, Bounds, DragMoveEvent, ElementId, EntityId, EventEmitter,
+    FocusHandle, FocusableView, Hsla, InteractiveElement as _, IntoElement, KeyBinding,
+    MouseButton, MouseDownEvent, ParentElement, Pixels, Point, Render, SharedString,
+    StatefulInteractiveElement as _, Styled, View, ViewContext, VisualContext, WindowContext,
 };
 
 use crate::{
@@ -17,6 +17,7 @@ use crate::{
 };
 
 const KEY_CONTEXT: &'static str = "ColorPicker";
+const MAX_RECENT_COLORS: usize = 11;
 
 pub fn init(cx: &mut AppContext) {
     cx.bind_keys([KeyBinding::new("escape", Escape, Some(KEY_CONTEXT))])
@@ -55,42 +56,281 @@ fn color_palettes() -> Vec<Vec<Hsla>> {
     ]
 }
 
+/// The color in HSV, it's used by the saturation and value area.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Hsva {
+    h: f32,
+    s: f32,
+    v: f32,
+    a: f32,
+}
+
+impl Default for Hsva {
+    fn default() -> Self {
+        Self {
+            h: 0.,
+            s: 0.,
+            v: 0.,
+            a: 1.,
+        }
+    }
+}
+
+impl Hsva {
+    /// Convert from the color, the hue and saturation of `prev` are kept when they are lost, e.g.: gray or black.
+    fn from_hsla(color: Hsla, prev: Hsva) -> Self {
+        let v = color.l + color.s * color.l.min(1. - color.l);
+        let s = if v > 0. {
+            2. * (1. - color.l / v)
+        } else {
+            prev.s
+        };
+        let h = if s > 0. && v > 0. { color.h } else { prev.h };
+
+        Self {
+            h,
+            s,
+            v,
+            a: color.a,
+        }
+    }
+
+    fn to_hsla(&self) -> Hsla {
+        let l = self.v * (1. - self.s / 2.);
+        let s = if l > 0. && l < 1. {
+            (self.v - l) / l.min(1. - l)
+        } else {
+            0.
+        };
+
+        hsla(self.h, s, l, self.a)
+    }
+}
+
+/// The text formats of the color inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColorFormat {
+    Hex,
+    Rgb,
+    Hsl,
+}
+
+impl ColorFormat {
+    const ALL: [ColorFormat; 3] = [Self::Hex, Self::Rgb, Self::Hsl];
+
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Hex => "HEX",
+            Self::Rgb => "RGB",
+            Self::Hsl => "HSL",
+        }
+    }
+
+    /// Format the color, e.g.: `#3B82F6`, `59, 130, 246` or `217, 91%, 60%`, the alpha is appended if not opaque.
+    fn format(&self, color: Hsla) -> String {
+        let alpha = if color.a < 1. {
+            format!(", {}", (color.a * 100.).round() / 100.)
+        } else {
+            String::new()
+        };
+
+        match self {
+            Self::Hex => color.to_hex_string(),
+            Self::Rgb => {
+                let rgb = color.to_rgb();
+                format!(
+                    "{}, {}, {}{}",
+                    (rgb.r * 255.).round(),
+                    (rgb.g * 255.).round(),
+                    (rgb.b * 255.).round(),
+                    alpha
+                )
+            }
+            Self::Hsl => format!(
+                "{}, {}%, {}%{}",
+                (color.h * 360.).round(),
+                (color.s * 100.).round(),
+                (color.l * 100.).round(),
+                alpha
+            ),
+        }
+    }
+
+    /// Parse the text of the format, the `rgb(...)` and `hsl(...)` like CSS are also accepted.
+    fn parse(&self, text: &str) -> Option<Hsla> {
+        match self {
+            Self::Hex => Hsla::parse_hex_string(text.trim()).ok(),
+            Self::Rgb => {
+                let parts = parse_parts(text, "rgb")?;
+                let (rgb, a) = parts.split_at(3);
+                if rgb.iter().any(|v| *v > 255.) {
+                    return None;
+                }
+
+                let color = gpui::Rgba {
+                    r: rgb[0] / 255.,
+                    g: rgb[1] / 255.,
+                    b: rgb[2] / 255.,
+                    a: parse_alpha(a)?,
+                };
+                Some(color.into())
+            }
+            Self::Hsl => {
+                let parts = parse_parts(text, "hsl")?;
+                let (hsl, a) = parts.split_at(3);
+                if hsl[0] > 360. || hsl[1] > 100. || hsl[2] > 100. {
+                    return None;
+                }
+
+                Some(hsla(
+                    hsl[0] / 360.,
+                    hsl[1] / 100.,
+                    hsl[2] / 100.,
+                    parse_alpha(a)?,
+                ))
+            }
+        }
+    }
+}
+
+/// Split the text like `rgb(1, 2, 3)` or `1, 2, 3, 0.5` into the numbers, the `%` is ignored.
+fn parse_parts(text: &str, prefix: &str) -> Option<Vec<f32>> {
+    let text = text.trim();
+    let text = text
+        .strip_prefix(prefix)
+        .map(|text| text.trim_start_matches('a'))
+        .unwrap_or(text)
+        .trim()
+        .trim_start_matches('(')
+        .trim_end_matches(')');
+
+    let parts = text
+        .split(',')
+        .map(|part| part.trim().trim_end_matches('%').parse::<f32>().ok())
+        .collect::<Option<Vec<_>>>()?;
+
+    if parts.iter().any(|v| *v < 0.) {
+        return None;
+    }
+    matches!(parts.len(), 3 | 4).then_some(parts)
+}
+
+fn parse_alpha(parts: &[f32]) -> Option<f32> {
+    let a = parts.first().copied().unwrap_or(1.);
+    (a <= 1.).then_some(a)
+}
+
+/// Put the color to the front of the recent colors, the same color is moved instead of duplicated.
+fn push_recent_color(colors: &mut Vec<Hsla>, color: Hsla) {
+    let hex = color.to_hex_string();
+    colors.retain(|c| c.to_hex_string() != hex);
+    colors.insert(0, color);
+    colors.truncate(MAX_RECENT_COLORS);
+}
+
+/// The draggable areas of the color picker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColorArea {
+    SaturationValue,
+    Hue,
+    Alpha,
+}
+
+impl ColorArea {
+    fn ix(&self) -> usize {
+        *self as usize
+    }
+}
+
+/// The drag payload of the color areas.
+#[derive(Clone, Render)]
+struct DragColor(EntityId, ColorArea);
+
+/// Paint the saturation and value area, or the hue and alpha bars, by the small quads.
+fn paint_area(area: ColorArea, hsva: Hsva, bounds: Bounds<Pixels>, cx: &mut WindowContext) {
+    let (cols, rows) = match area {
+        ColorArea::SaturationValue => (32, 20),
+        ColorArea::Hue | ColorArea::Alpha => (60, 1),
+    };
+    let cell = size(
+        bounds.size.width / cols as f32,
+        bounds.size.height / rows as f32,
+    );
+
+    for row in 0..rows {
+        for col in 0..cols {
+            let x = (col as f32 + 0.5) / cols as f32;
+            let y = (row as f32 + 0.5) / rows as f32;
+            let color = match area {
+                ColorArea::SaturationValue => Hsva {
+                    s: x,
+                    v: 1. - y,
+                    a: 1.,
+                    ..hsva
+                }
+                .to_hsla(),
+                ColorArea::Hue => hsla(x, 1., 0.5, 1.),
+                ColorArea::Alpha => Hsva { a: x, ..hsva }.to_hsla(),
+            };
+
+            let origin = point(
+                bounds.left() + cell.width * col as f32,
+                bounds.top() + cell.height * row as f32,
+            );
+            // Overlap the cells a little to avoid the seams.
+            let cell_size = size(cell.width + px(0.5), cell.height + px(0.5));
+            cx.paint_quad(fill(Bounds::new(origin, cell_size), color));
+        }
+    }
+}
+
 pub struct ColorPicker {
     id: ElementId,
     focus_handle: FocusHandle,
     value: Option<Hsla>,
+    hsva: Hsva,
     featured_colors: Vec<Hsla>,
+    recent_colors: Vec<Hsla>,
     hovered_color: Option<Hsla>,
     label: Option<SharedString>,
     size: Size,
     anchor: AnchorCorner,
-    color_input: View<TextInput>,
+    hex_input: View<TextInput>,
+    rgb_input: View<TextInput>,
+    hsl_input: View<TextInput>,
 
     open: bool,
     bounds: Bounds<Pixels>,
+    area_bounds: [Bounds<Pixels>; 3],
 }
 
 impl ColorPicker {
     pub fn new(id: impl Into<ElementId>, cx: &mut ViewContext<Self>) -> Self {
-        let color_input = cx.new_view(|cx| TextInput::new(cx).xsmall());
+        let hex_input = cx.new_view(|cx| TextInput::new(cx).xsmall());
+        let rgb_input = cx.new_view(|cx| TextInput::new(cx).xsmall());
+        let hsl_input = cx.new_view(|cx| TextInput::new(cx).xsmall());
 
-        cx.subscribe(&color_input, |this, _, ev: &InputEvent, cx| match ev {
-            InputEvent::Change(value) => {
-                if let Ok(color) = Hsla::parse_hex_string(value) {
-                    this.value = Some(color);
-                    this.hovered_color = Some(color);
-                }
-            }
-            InputEvent::PressEnter => {
-                let val = this.color_input.read(cx).text();
-                if let Ok(color) = Hsla::parse_hex_string(&val) {
-                    this.open = false;
-                    this.update_value(Some(color), true, cx);
+        for (format, input) in [
+            (ColorFormat::Hex, &hex_input),
+            (ColorFormat::Rgb, &rgb_input),
+            (ColorFormat::Hsl, &hsl_input),
+        ] {
+            cx.subscribe(input, move |this, input, ev: &InputEvent, cx| match ev {
+                InputEvent::Change(text) => {
+                    // Only the typing changes, the inputs are also changed when synced from the others.
+                    if !input.focus_handle(cx).is_focused(cx) {
+                        return;
+                    }
+
+                    if let Some(color) = format.parse(text) {
+                        this.update_value(Some(color), Some(format), true, cx);
+                    }
                 }
-            }
-            _ => {}
-        })
-        .detach();
+                InputEvent::PressEnter => this.close(cx),
+                _ => {}
+            })
+            .detach();
+        }
 
         Self {
             id: id.into(),
@@ -108,14 +348,19 @@ impl ColorPicker {
                 crate::indigo_600(),
                 crate::purple_600(),
             ],
+            recent_colors: vec![],
             value: None,
+            hsva: Hsva::default(),
             hovered_color: None,
             size: Size::Medium,
             label: None,
             anchor: AnchorCorner::TopLeft,
-            color_input,
+            hex_input,
+            rgb_input,
+            hsl_input,
             open: false,
             bounds: Bounds::default(),
+            area_bounds: [Bounds::default(); 3],
         }
     }
 
@@ -128,9 +373,16 @@ impl ColorPicker {
         self
     }
 
+    /// Set the recent colors, the colors are added when the picker is closed with a new value.
+    pub fn recent_colors(mut self, colors: Vec<Hsla>) -> Self {
+        self.recent_colors = colors;
+        self.recent_colors.truncate(MAX_RECENT_COLORS);
+        self
+    }
+
     /// Set current color value.
     pub fn set_value(&mut self, value: Hsla, cx: &mut ViewContext<Self>) {
-        self.update_value(Some(value), false, cx)
+        self.update_value(Some(value), None, false, cx)
     }
 
     /// Set the size of the color picker, default is `Size::Medium`.
@@ -157,26 +409,55 @@ impl ColorPicker {
 
     fn on_escape(&mut self, _: &Escape, cx: &mut ViewContext<Self>) {
         cx.propagate();
+        self.close(cx);
+    }
 
-        self.open = false;
+    fn close(&mut self, cx: &mut ViewContext<Self>) {
+        if self.open {
+            self.open = false;
+            if let Some(value) = self.value {
+                push_recent_color(&mut self.recent_colors, value);
+            }
+        }
         cx.notify();
     }
 
+    fn input(&self, format: ColorFormat) -> &View<TextInput> {
+        match format {
+            ColorFormat::Hex => &self.hex_input,
+            ColorFormat::Rgb => &self.rgb_input,
+            ColorFormat::Hsl => &self.hsl_input,
+        }
+    }
+
     fn toggle_picker(&mut self, _: &gpui::ClickEvent, cx: &mut ViewContext<Self>) {
         self.open = !self.open;
         cx.notify();
     }
 
-    fn update_value(&mut self, value: Option<Hsla>, emit: bool, cx: &mut ViewContext<Self>) {
+    /// Update the value and sync the inputs, except the `source` input that the value is typed in.
+    fn update_value(
+        &mut self,
+        value: Option<Hsla>,
+        source: Option<ColorFormat>,
+        emit: bool,
+        cx: &mut ViewContext<Self>,
+    ) {
         self.value = value;
         self.hovered_color = value;
-        self.color_input.update(cx, |view, cx| {
-            if let Some(value) = value {
-                view.set_text(value.to_hex_string(), cx);
-            } else {
-                view.set_text("", cx);
+        if let Some(value) = value {
+            self.hsva = Hsva::from_hsla(value, self.hsva);
+        }
+
+        for format in ColorFormat::ALL {
+            if source == Some(format) {
+                continue;
             }
-        });
+
+            let text = value.map(|value| format.format(value)).unwrap_or_default();
+            self.input(format)
+                .update(cx, |view, cx| view.set_text(text, cx));
+        }
         if emit {
             cx.emit(ColorPickerEvent::Change(value));
         }
@@ -212,16 +493,138 @@ impl ColorPicker {
                         cx.notify();
                     }))
                     .on_click(cx.listener(move |view, _, cx| {
-                        view.update_value(Some(color), true, cx);
-                        view.open = false;
-                        cx.notify();
+                        view.update_value(Some(color), None, true, cx);
+                        view.close(cx);
                     }))
             })
     }
 
+    /// Move the thumb of the area to the mouse position.
+    fn move_area(&mut self, area: ColorArea, position: Point<Pixels>, cx: &mut ViewContext<Self>) {
+        let bounds = self.area_bounds[area.ix()];
+        if bounds.size.width <= px(0.) || bounds.size.height <= px(0.) {
+            return;
+        }
+
+        let x = ((position.x - bounds.left()) / bounds.size.width).clamp(0., 1.);
+        let y = ((position.y - bounds.top()) / bounds.size.height).clamp(0., 1.);
+        match area {
+            ColorArea::SaturationValue => {
+                self.hsva.s = x;
+                self.hsva.v = 1. - y;
+            }
+            ColorArea::Hue => self.hsva.h = x,
+            ColorArea::Alpha => self.hsva.a = x,
+        }
+
+        self.update_value(Some(self.hsva.to_hsla()), None, true, cx);
+    }
+
+    fn render_area(&self, area: ColorArea, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let entity_id = cx.entity_id();
+        let view = cx.view().clone();
+        let hsva = self.hsva;
+        let (x, y, thumb_color) = match area {
+            ColorArea::SaturationValue => (hsva.s, 1. - hsva.v, Hsva { a: 1., ..hsva }.to_hsla()),
+            ColorArea::Hue => (hsva.h, 0.5, hsla(hsva.h, 1., 0.5, 1.)),
+            ColorArea::Alpha => (hsva.a, 0.5, hsva.to_hsla()),
+        };
+
+        div()
+            .id(("color-area", area.ix()))
+            .relative()
+            .w_full()
+            .map(|this| match area {
+                ColorArea::SaturationValue => this.h(px(150.)),
+                ColorArea::Hue | ColorArea::Alpha => this.h_3(),
+            })
+            .cursor_pointer()
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(move |view, e: &MouseDownEvent, cx| {
+                    view.move_area(area, e.position, cx)
+                }),
+            )
+            .on_drag(DragColor(entity_id, area), |drag, cx| {
+                cx.stop_propagation();
+                cx.new_view(|_| drag.clone())
+            })
+            .on_drag_move(cx.listener(
+                move |view, e: &DragMoveEvent<DragColor>, cx| match e.drag(cx) {
+                    DragColor(id, drag_area) => {
+                        if *id != entity_id || *drag_area != area {
+                            return;
+                        }
+
+                        view.move_area(area, e.event.position, cx)
+                    }
+                },
+            ))
+            .child(
+                canvas(
+                    move |bounds, cx| view.update(cx, |r, _| r.area_bounds[area.ix()] = bounds),
+                    move |bounds, _, cx| paint_area(area, hsva, bounds, cx),
+                )
+                .absolute()
+                .size_full(),
+            )
+            .child(
+                div()
+                    .absolute()
+                    .left(relative(x))
+                    .top(relative(y))
+                    .ml(-px(6.))
+                    .mt(-px(6.))
+                    .size_3()
+                    .rounded_full()
+                    .border_2()
+                    .border_color(crate::white())
+                    .shadow_md()
+                    .bg(thumb_color),
+            )
+    }
+
     fn render_colors(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let preview_color = self.hovered_color.or(self.value);
+
         v_flex()
             .gap_3()
+            .child(self.render_area(ColorArea::SaturationValue, cx))
+            .child(
+                h_flex()
+                    .gap_3()
+                    .child(
+                        div()
+                            .flex_shrink_0()
+                            .size_8()
+                            .border_1()
+                            .border_color(cx.theme().border)
+                            .rounded(px(cx.theme().radius))
+                            .when_some(preview_color, |this, color| {
+                                this.bg(color).border_color(color.darken(0.2))
+                            }),
+                    )
+                    .child(
+                        v_flex()
+                            .flex_1()
+                            .gap_3()
+                            .child(self.render_area(ColorArea::Hue, cx))
+                            .child(self.render_area(ColorArea::Alpha, cx)),
+                    ),
+            )
+            .child(v_flex().gap_1().children(ColorFormat::ALL.map(|format| {
+                h_flex()
+                    .gap_2()
+                    .child(
+                        div()
+                            .w_8()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(format.label()),
+                    )
+                    .child(div().flex_1().child(self.input(format).clone()))
+            })))
+            .child(Divider::horizontal())
             .child(
                 h_flex().gap_1().children(
                     self.featured_colors
@@ -229,6 +632,15 @@ impl ColorPicker {
                         .map(|color| self.render_item(*color, true, cx)),
                 ),
             )
+            .when(!self.recent_colors.is_empty(), |this| {
+                this.child(
+                    h_flex().id("recent-colors").gap_1().children(
+                        self.recent_colors
+                            .iter()
+                            .map(|color| self.render_item(*color, true, cx)),
+                    ),
+                )
+            })
             .child(Divider::horizontal())
             .child(
                 v_flex()
@@ -242,23 +654,6 @@ impl ColorPicker {
                         )
                     })),
             )
-            .when_some(self.hovered_color, |this, hovered_color| {
-                this.child(Divider::horizontal()).child(
-                    h_flex()
-                        .gap_2()
-                        .items_center()
-                        .child(
-                            div()
-                                .bg(hovered_color)
-                                .flex_shrink_0()
-                                .border_1()
-                                .border_color(hovered_color.darken(0.2))
-                                .size_5()
-                                .rounded(px(cx.theme().radius)),
-                        )
-                        .child(self.color_input.clone()),
-                )
-            })
     }
 
     fn resolved_corner(&self, bounds: Bounds<Pixels>) -> Point<Pixels> {
@@ -365,7 +760,14 @@ impl Render for ColorPicker {
                                     .bg(cx.theme().background)
                                     .on_mouse_up_out(
                                         MouseButton::Left,
-                                        cx.listener(|view, _, cx| view.on_escape(&Escape, cx)),
+                                        cx.listener(|view, _, cx| {
+                                            // Keep open when the thumb is dragged out of the popover.
+                                            if cx.has_active_drag() {
+                                                return;
+                                            }
+
+                                            view.on_escape(&Escape, cx)
+                                        }),
                                     )
                                     .child(self.render_colors(cx)),
                             ),
@@ -375,3 +777,68 @@ impl Render for ColorPicker {
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::{hsla, rgb, rgba, Hsla};
+
+    use super::{push_recent_color, ColorFormat, Hsva, MAX_RECENT_COLORS};
+    use crate::ColorExt as _;
+
+    #[test]
+    fn test_hsva() {
+        let color: Hsla = rgb(0x3b82f6).into();
+        let hsva = Hsva::from_hsla(color, Hsva::default());
+        assert_eq!(hsva.to_hsla().to_hex_string(), "#3B82F6");
+
+        // The hue is kept when the color is gray.
+        let hsva = Hsva::from_hsla(hsla(0., 0., 0.5, 1.), hsva);
+        assert!((hsva.h - color.h).abs() < 0.001);
+        assert_eq!(hsva.s, 0.);
+    }
+
+    #[test]
+    fn test_color_format() {
+        let color: Hsla = rgb(0x3b82f6).into();
+        assert_eq!(ColorFormat::Hex.format(color), "#3B82F6");
+        assert_eq!(ColorFormat::Rgb.format(color), "59, 130, 246");
+        assert_eq!(ColorFormat::Hsl.format(color), "217, 91%, 60%");
+
+        let color: Hsla = rgba(0x3b82f680).into();
+        assert_eq!(ColorFormat::Rgb.format(color), "59, 130, 246, 0.5");
+
+        for text in ["59, 130, 246", "rgb(59, 130, 246)", " rgba(59,130,246, 1) "] {
+            let parsed = ColorFormat::Rgb.parse(text).unwrap();
+            assert_eq!(parsed.to_hex_string(), "#3B82F6");
+        }
+        let parsed = ColorFormat::Hsl.parse("hsl(217, 91%, 60%)").unwrap();
+        assert_eq!(parsed.to_hex_string(), "#3C83F6");
+        assert_eq!(
+            ColorFormat::Hex.parse("#3B82F6").unwrap().to_hex_string(),
+            "#3B82F6"
+        );
+
+        assert_eq!(ColorFormat::Rgb.parse("256, 0, 0"), None);
+        assert_eq!(ColorFormat::Rgb.parse("1, 2"), None);
+        assert_eq!(ColorFormat::Rgb.parse("1, 2, 3, 2"), None);
+        assert_eq!(ColorFormat::Hsl.parse("0, 101%, 50%"), None);
+        assert_eq!(ColorFormat::Hex.parse("#3B82"), None);
+    }
+
+    #[test]
+    fn test_push_recent_color() {
+        let red: Hsla = rgb(0xff0000).into();
+        let blue: Hsla = rgb(0x0000ff).into();
+
+        let mut colors = vec![];
+        push_recent_color(&mut colors, red);
+        push_recent_color(&mut colors, blue);
+        push_recent_color(&mut colors, red);
+        assert_eq!(colors, vec![red, blue]);
+
+        for i in 0..20 {
+            push_recent_color(&mut colors, hsla(i as f32 / 20., 0.5, 0.5, 1.));
+        }
+        assert_eq!(colors.len(), MAX_RECENT_COLORS);
+    }
+}
@@ -18,25 +18,26 @@ impl ColorExt for Hsla {
         if rgb.a < 1. {
             return format!(
                 "#{:02X}{:02X}{:02X}{:02X}",
-                ((rgb.r * 255.) as u32),
-                ((rgb.g * 255.) as u32),
-                ((rgb.b * 255.) as u32),
-                ((self.a * 255.) as u32)
+                ((rgb.r * 255.).round() as u32),
+                ((rgb.g * 255.).round() as u32),
+                ((rgb.b * 255.).round() as u32),
+                ((self.a * 255.).round() as u32)
             );
         }
 
         format!(
             "#{:02X}{:02X}{:02X}",
-            ((rgb.r * 255.) as u32),
-            ((rgb.g * 255.) as u32),
-            ((rgb.b * 255.) as u32)
+            ((rgb.r * 255.).round() as u32),
+            ((rgb.g * 255.).round() as u32),
+            ((rgb.b * 255.).round() as u32)
         )
     }
 
     fn parse_hex_string(hex: &str) -> Result<Hsla> {
         let hex = hex.trim_start_matches('#');
         let len = hex.len();
-        if len != 6 && len != 8 {
+        // Check the digits before slicing, the multi-byte chars can't be sliced by the byte index.
+        if (len != 6 && len != 8) || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
             return Err(anyhow::anyhow!("invalid hex color"));
         }
 
@@ -208,6 +209,46 @@ pub fn white() -> Hsla {
     DEFAULT_COLOR.white.hsla
 }
 
+/// The scales of a color, e.g.: `blue_50` to `blue_950`.
+pub const COLOR_SCALES: [usize; 11] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950];
+
+/// A 50 to 950 color scale generated by [`color_scale`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorScale([Hsla; 11]);
+
+impl ColorScale {
+    /// Return the color of the scale, e.g.: `500`, other values return the nearest scale.
+    pub fn get(&self, scale: usize) -> Hsla {
+        let ix = COLOR_SCALES
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, s)| s.abs_diff(scale))
+            .map_or(5, |(ix, _)| ix);
+        self.0[ix]
+    }
+}
+
+/// Generate a color scale like `blue_50` to `blue_950` from the accent color, the accent is the `500`.
+///
+/// The hue and saturation are kept, the lighter scales move the lightness towards white and the darker ones towards black.
+pub fn color_scale(accent: Hsla) -> ColorScale {
+    const LIGHTEST: f32 = 0.98;
+    const DARKEST: f32 = 0.04;
+    // The ratio to move the lightness towards the lightest (positive) or the darkest (negative).
+    const STEPS: [f32; 11] = [
+        0.95, 0.9, 0.75, 0.55, 0.3, 0., -0.15, -0.32, -0.48, -0.62, -0.78,
+    ];
+
+    ColorScale(STEPS.map(|step| {
+        let l = if step >= 0. {
+            (accent.l + (LIGHTEST - accent.l) * step).max(accent.l)
+        } else {
+            (accent.l + (accent.l - DARKEST) * step).min(accent.l)
+        };
+        Hsla { l, ..accent }
+    }))
+}
+
 color_methods!(slate);
 color_methods!(gray);
 color_methods!(zinc);
@@ -254,6 +295,25 @@ mod tests {
         assert_eq!(indigo_500(), hsl(238.7, 83.5, 66.7));
     }
 
+    #[test]
+    fn test_color_scale() {
+        let accent = hsl(262.1, 83.3, 57.8);
+        let scale = color_scale(accent);
+        assert_eq!(scale.get(500), accent);
+        assert_eq!(scale.get(520), accent);
+        assert_eq!(scale.get(0), scale.get(50));
+        assert_eq!(scale.get(1000), scale.get(950));
+
+        for pair in COLOR_SCALES.windows(2) {
+            let (lighter, darker) = (scale.get(pair[0]), scale.get(pair[1]));
+            assert!(lighter.l > darker.l);
+            assert_eq!(lighter.h, accent.h);
+            assert_eq!(lighter.s, accent.s);
+        }
+        assert!(scale.get(50).l > 0.95);
+        assert!(scale.get(950).l < 0.2);
+    }
+
     #[test]
     fn test_to_hex_string() {
         let color: Hsla = rgb(0xf8fafc).into();
@@ -276,5 +336,9 @@ mod tests {
 
         let color: Hsla = Hsla::parse_hex_string("#0413FCAA").unwrap();
         assert_eq!(color, rgba(0x0413fcaa).into());
+
+        assert!(Hsla::parse_hex_string("#F8FAF").is_err());
+        assert!(Hsla::parse_hex_string("#ééé").is_err());
+        assert!(Hsla::parse_hex_string("#+1+2+3").is_err());
     }
 }
@@ -0,0 +1,304 @@
+use std::rc::Rc;
+
+use gpui::{
+    actions, anchored, canvas, deferred, div, prelude::FluentBuilder, px, rems, AppContext, Bounds,
+    ElementId, EventEmitter, FocusHandle, FocusableView, InteractiveElement, IntoElement,
+    KeyBinding, ParentElement, Pixels, Render, SharedString, StatefulInteractiveElement, Styled,
+    Task, View, ViewContext, VisualContext, WindowContext,
+};
+use rust_i18n::t;
+
+use crate::{
+    dropdown::DropdownItem,
+    h_flex,
+    indicator::Indicator,
+    input::{self, InputEvent, TextInput},
+    list::ListItem,
+    theme::ActiveTheme,
+    v_flex,
+};
+
+actions!(combobox, [Escape]);
+
+const CONTEXT: &str = "Combobox";
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([KeyBinding::new("escape", Escape, Some(CONTEXT))])
+}
+
+pub enum ComboboxEvent<T: DropdownItem + 'static> {
+    /// A suggestion has been confirmed.
+    Confirm(T::Value),
+}
+
+/// A text input with a suggestion popover, the suggestions are provided by an async function
+/// with the current query.
+pub struct Combobox<T: DropdownItem + Clone + 'static> {
+    id: ElementId,
+    input: View<TextInput>,
+    provider: Rc<dyn Fn(&str, &mut WindowContext) -> Task<Vec<T>>>,
+    items: Vec<T>,
+    selected_index: Option<usize>,
+    selected_value: Option<T::Value>,
+    open: bool,
+    loading: bool,
+    /// Skip the search of the next change, it is caused by confirming a suggestion.
+    skip_search: bool,
+    /// Store the bounds of the input
+    bounds: Bounds<Pixels>,
+    _search_task: Task<()>,
+}
+
+impl<T> Combobox<T>
+where
+    T: DropdownItem + Clone + 'static,
+{
+    /// Create a Combobox with a function to provide the suggestions for a query.
+    ///
+    /// ```ignore
+    /// Combobox::new("city", |query, cx| {
+    ///     let query = query.to_lowercase();
+    ///     cx.background_executor().spawn(async move { search_cities(&query).await })
+    /// }, cx)
+    /// ```
+    pub fn new(
+        id: impl Into<ElementId>,
+        provider: impl Fn(&str, &mut WindowContext) -> Task<Vec<T>> + 'static,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        let input = cx.new_view(TextInput::new);
+        cx.subscribe(&input, Self::on_input_event).detach();
+
+        Self {
+            id: id.into(),
+            input,
+            provider: Rc::new(provider),
+            items: vec![],
+            selected_index: None,
+            selected_value: None,
+            open: false,
+            loading: false,
+            skip_search: false,
+            bounds: Bounds::default(),
+            _search_task: Task::Ready(None),
+        }
+    }
+
+    /// Set the placeholder text of the input field.
+    pub fn set_placeholder(
+        &mut self,
+        placeholder: impl Into<SharedString>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let placeholder = placeholder.into();
+        self.input.update(cx, |input, cx| {
+            input.set_placeholder(placeholder);
+            cx.notify();
+        });
+    }
+
+    /// Return the current query of the input field.
+    pub fn query(&self, cx: &AppContext) -> SharedString {
+        self.input.read(cx).text()
+    }
+
+    /// Return the value of the last confirmed suggestion.
+    pub fn selected_value(&self) -> Option<&T::Value> {
+        self.selected_value.as_ref()
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: View<TextInput>,
+        event: &InputEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            InputEvent::Change(_) if self.skip_search => self.skip_search = false,
+            InputEvent::Change(query) => self.search(query.to_string(), cx),
+            InputEvent::PressEnter => self.confirm(cx),
+            _ => {}
+        }
+    }
+
+    fn search(&mut self, query: String, cx: &mut ViewContext<Self>) {
+        if query.is_empty() {
+            self.items.clear();
+            self.open = false;
+            self._search_task = Task::Ready(None);
+            cx.notify();
+            return;
+        }
+
+        self.open = true;
+        self.loading = true;
+        cx.notify();
+
+        // Replacing the task will cancel the previous search.
+        let search = (self.provider)(&query, cx);
+        self._search_task = cx.spawn(|view, mut cx| async move {
+            let items = search.await;
+            _ = view.update(&mut cx, |view, cx| {
+                view.selected_index = if items.is_empty() { None } else { Some(0) };
+                view.items = items;
+                view.loading = false;
+                cx.notify();
+            });
+        });
+    }
+
+    fn confirm(&mut self, cx: &mut ViewContext<Self>) {
+        if !self.open {
+            return;
+        }
+        let Some(item) = self
+            .selected_index
+            .and_then(|ix| self.items.get(ix))
+            .cloned()
+        else {
+            return;
+        };
+
+        self.open = false;
+        self.loading = false;
+        self._search_task = Task::Ready(None);
+        self.selected_value = Some(item.value().clone());
+
+        self.skip_search = true;
+        let title = item.title();
+        self.input.update(cx, |input, cx| input.set_text(title, cx));
+
+        cx.emit(ComboboxEvent::Confirm(item.value().clone()));
+        cx.notify();
+    }
+
+    fn up(&mut self, _: &input::Up, cx: &mut ViewContext<Self>) {
+        if !self.open || self.items.is_empty() {
+            cx.propagate();
+            return;
+        }
+
+        let ix = self.selected_index.unwrap_or(0);
+        self.selected_index = Some(if ix == 0 {
+            self.items.len() - 1
+        } else {
+            ix - 1
+        });
+        cx.notify();
+    }
+
+    fn down(&mut self, _: &input::Down, cx: &mut ViewContext<Self>) {
+        if !self.open || self.items.is_empty() {
+            cx.propagate();
+            return;
+        }
+
+        let ix = self.selected_index.map_or(0, |ix| ix + 1);
+        self.selected_index = Some(if ix >= self.items.len() { 0 } else { ix });
+        cx.notify();
+    }
+
+    fn escape(&mut self, _: &Escape, cx: &mut ViewContext<Self>) {
+        if !self.open {
+            // Propagate the event to the parent view, for example to the Modal to support ESC to close.
+            cx.propagate();
+            return;
+        }
+
+        self.open = false;
+        cx.notify();
+    }
+
+    fn render_menu(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .occlude()
+            .mt_1p5()
+            .p_1()
+            .max_h(rems(20.))
+            .overflow_hidden()
+            .bg(cx.theme().background)
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(px(cx.theme().radius))
+            .shadow_md()
+            .map(|this| {
+                if self.loading && self.items.is_empty() {
+                    this.child(h_flex().justify_center().py_2().child(Indicator::new()))
+                } else if self.items.is_empty() {
+                    this.child(
+                        h_flex()
+                            .justify_center()
+                            .py_2()
+                            .text_sm()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(t!("Combobox.No results").to_string()),
+                    )
+                } else {
+                    this.children(self.items.iter().enumerate().map(|(ix, item)| {
+                        ListItem::new(("combobox-item", ix))
+                            .selected(self.selected_index == Some(ix))
+                            .child(item.title())
+                            .on_click(cx.listener(move |this, _, cx| {
+                                this.selected_index = Some(ix);
+                                this.confirm(cx);
+                            }))
+                    }))
+                }
+            })
+    }
+}
+
+impl<T> EventEmitter<ComboboxEvent<T>> for Combobox<T> where T: DropdownItem + Clone + 'static {}
+
+impl<T> FocusableView for Combobox<T>
+where
+    T: DropdownItem + Clone + 'static,
+{
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.input.focus_handle(cx)
+    }
+}
+
+impl<T> Render for Combobox<T>
+where
+    T: DropdownItem + Clone + 'static,
+{
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let bounds = self.bounds;
+
+        div()
+            .id(self.id.clone())
+            .key_context(CONTEXT)
+            .on_action(cx.listener(Self::up))
+            .on_action(cx.listener(Self::down))
+            .on_action(cx.listener(Self::escape))
+            .relative()
+            .w_full()
+            .child(self.input.clone())
+            .child(
+                canvas(
+                    move |bounds, cx| view.update(cx, |r, _| r.bounds = bounds),
+                    |_, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+            .when(self.open, |this| {
+                this.child(
+                    deferred(
+                        anchored().snap_to_window_with_margin(px(8.)).child(
+                            div()
+                                .occlude()
+                                .w(bounds.size.width)
+                                .child(self.render_menu(cx))
+                                .on_mouse_down_out(cx.listener(|this, _, cx| {
+                                    this.open = false;
+                                    cx.notify();
+                                })),
+                        ),
+                    )
+                    .with_priority(1),
+                )
+            })
+    }
+}
@@ -0,0 +1,324 @@
+use std::{ops::Range, rc::Rc};
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, Action, AppContext, FocusHandle, FocusableView,
+    IntoElement, ParentElement, Render, SharedString, Styled, View, ViewContext,
+    VisualContext as _, WindowContext,
+};
+use rust_i18n::t;
+
+use crate::{
+    h_flex,
+    input::TextInput,
+    kbd::Kbd,
+    label::Label,
+    list::{List, ListDelegate, ListEvent, ListItem},
+    theme::ActiveTheme as _,
+    v_flex, ContextModal as _, Icon, IconName, Sizable as _,
+};
+
+/// The max number of the recent commands.
+const MAX_RECENT: usize = 5;
+
+/// A command in the [`CommandPalette`].
+pub struct Command {
+    id: SharedString,
+    label: SharedString,
+    icon: Option<Icon>,
+    action: Option<Box<dyn Action>>,
+}
+
+impl Clone for Command {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            label: self.label.clone(),
+            icon: self.icon.clone(),
+            action: self.action.as_ref().map(|action| action.boxed_clone()),
+        }
+    }
+}
+
+impl Command {
+    pub fn new(id: impl Into<SharedString>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            icon: None,
+            action: None,
+        }
+    }
+
+    /// Set the icon of the command.
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the action to dispatch when the command is executed, the key binding of it is shown as hint.
+    pub fn action(mut self, action: Box<dyn Action>) -> Self {
+        self.action = Some(action);
+        self
+    }
+
+    pub fn id(&self) -> &SharedString {
+        &self.id
+    }
+
+    pub fn label(&self) -> &SharedString {
+        &self.label
+    }
+}
+
+enum CommandRow {
+    Header(SharedString),
+    Recent(usize),
+    Command(usize),
+}
+
+pub struct CommandListDelegate {
+    commands: Vec<Command>,
+    /// The indices of the recent executed commands, the latest first.
+    recent: Vec<usize>,
+    rows: Vec<CommandRow>,
+    selected_index: Option<usize>,
+}
+
+impl CommandListDelegate {
+    fn new(commands: Vec<Command>) -> Self {
+        let mut this = Self {
+            commands,
+            recent: vec![],
+            rows: vec![],
+            selected_index: None,
+        };
+        this.update_rows();
+        this
+    }
+
+    fn update_rows(&mut self) {
+        self.rows.clear();
+        if !self.recent.is_empty() {
+            self.rows
+                .push(CommandRow::Header(t!("CommandPalette.Recent").into()));
+            self.rows
+                .extend(self.recent.iter().map(|ix| CommandRow::Recent(*ix)));
+            self.rows
+                .push(CommandRow::Header(t!("CommandPalette.Commands").into()));
+        }
+        self.rows
+            .extend((0..self.commands.len()).map(CommandRow::Command));
+    }
+
+    fn push_recent(&mut self, command_ix: usize) {
+        self.recent.retain(|ix| *ix != command_ix);
+        self.recent.insert(0, command_ix);
+        self.recent.truncate(MAX_RECENT);
+        self.update_rows();
+    }
+
+    fn command_ix(&self, ix: usize) -> Option<usize> {
+        match self.rows.get(ix)? {
+            CommandRow::Header(_) => None,
+            CommandRow::Recent(ix) | CommandRow::Command(ix) => Some(*ix),
+        }
+    }
+
+    fn render_command(
+        &self,
+        ix: usize,
+        ranges: &[Range<usize>],
+        cx: &mut ViewContext<List<Self>>,
+    ) -> Option<ListItem> {
+        let selected = self.selected_index == Some(ix);
+
+        if let CommandRow::Header(title) = self.rows.get(ix)? {
+            return Some(
+                ListItem::new(("command-header", ix)).disabled(true).child(
+                    div()
+                        .text_sm()
+                        .font_semibold()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(title.clone()),
+                ),
+            );
+        }
+
+        let command = self.commands.get(self.command_ix(ix)?)?;
+        let key_binding = command
+            .action
+            .as_ref()
+            .and_then(|action| Kbd::binding_for_action(action.as_ref(), cx));
+
+        Some(
+            ListItem::new(("command", ix))
+                .selected(selected)
+                .child(
+                    h_flex()
+                        .gap_2()
+                        .map(|this| match command.icon.clone() {
+                            Some(icon) => this.child(icon.small()),
+                            None => this.child(Icon::empty().small()),
+                        })
+                        .child(Label::new(command.label.clone()).highlights(ranges.to_vec())),
+                )
+                .when_some(key_binding, |this, key_binding| {
+                    this.suffix(move |_| key_binding.clone())
+                }),
+        )
+    }
+}
+
+impl ListDelegate for CommandListDelegate {
+    type Item = ListItem;
+
+    fn items_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn is_section_header(&self, ix: usize) -> bool {
+        matches!(self.rows.get(ix), Some(CommandRow::Header(_)))
+    }
+
+    fn item_text(&self, ix: usize) -> Option<SharedString> {
+        // The recent commands are also in the commands, only match them once.
+        match self.rows.get(ix)? {
+            CommandRow::Command(ix) => Some(self.commands[*ix].label.clone()),
+            _ => None,
+        }
+    }
+
+    fn render_item(&self, ix: usize, cx: &mut ViewContext<List<Self>>) -> Option<Self::Item> {
+        self.render_command(ix, &[], cx)
+    }
+
+    fn render_matched_item(
+        &self,
+        ix: usize,
+        ranges: &[Range<usize>],
+        cx: &mut ViewContext<List<Self>>,
+    ) -> Option<Self::Item> {
+        self.render_command(ix, ranges, cx)
+    }
+
+    fn render_empty(&self, cx: &mut ViewContext<List<Self>>) -> impl IntoElement {
+        h_flex()
+            .justify_center()
+            .py_6()
+            .text_sm()
+            .text_color(cx.theme().muted_foreground)
+            .child(t!("CommandPalette.No commands found").to_string())
+    }
+
+    fn set_selected_index(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {
+        self.selected_index = ix;
+        cx.notify();
+    }
+}
+
+/// A Cmd/Ctrl + K style command palette in a Modal, to search and execute the commands.
+///
+/// Keep the view to remember the recent commands, and call [`CommandPalette::open`] to show it.
+pub struct CommandPalette {
+    list: View<List<CommandListDelegate>>,
+    on_execute: Option<Rc<dyn Fn(&Command, &mut WindowContext)>>,
+}
+
+impl CommandPalette {
+    pub fn new(commands: Vec<Command>, cx: &mut ViewContext<Self>) -> Self {
+        let list = cx.new_view(|cx| {
+            let mut list = List::new(CommandListDelegate::new(commands), cx).max_h(px(400.));
+            let query_input = cx.new_view(|cx| {
+                TextInput::new(cx)
+                    .appearance(false)
+                    .prefix(|_| IconName::Search)
+                    .placeholder(t!("CommandPalette.placeholder"))
+                    .cleanable()
+            });
+            list.set_query_input(query_input, cx);
+            list
+        });
+
+        cx.subscribe(&list, |this, list, event: &ListEvent, cx| match event {
+            ListEvent::Confirm(ix) => {
+                let command = list.update(cx, |list, cx| {
+                    let delegate = list.delegate_mut();
+                    let command_ix = delegate.command_ix(*ix)?;
+                    delegate.push_recent(command_ix);
+                    cx.notify();
+                    delegate.commands.get(command_ix).cloned()
+                });
+                if let Some(command) = command {
+                    this.execute(command, cx);
+                }
+            }
+            ListEvent::Cancel => cx.close_modal(),
+            _ => {}
+        })
+        .detach();
+
+        Self {
+            list,
+            on_execute: None,
+        }
+    }
+
+    /// Set the callback when a command is executed.
+    pub fn on_execute(
+        mut self,
+        on_execute: impl Fn(&Command, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_execute = Some(Rc::new(on_execute));
+        self
+    }
+
+    /// Replace the commands, the recent commands are cleared.
+    pub fn set_commands(&mut self, commands: Vec<Command>, cx: &mut ViewContext<Self>) {
+        self.list.update(cx, |list, cx| {
+            *list.delegate_mut() = CommandListDelegate::new(commands);
+            cx.notify();
+        });
+    }
+
+    /// Open the command palette in a Modal, with the query cleared.
+    pub fn open(&mut self, cx: &mut ViewContext<Self>) {
+        let view = cx.view().clone();
+        cx.open_modal(move |modal, _| {
+            modal
+                .width(px(560.))
+                .margin_top(px(80.))
+                .show_close(false)
+                .p_0()
+                .min_h_0()
+                .child(view.clone())
+        });
+
+        self.list.update(cx, |list, cx| {
+            list.set_query("", cx);
+            list.focus(cx);
+        });
+    }
+
+    fn execute(&mut self, command: Command, cx: &mut ViewContext<Self>) {
+        // Close the modal first to focus back, so the action can be received by the previous focused view.
+        cx.close_modal();
+        if let Some(on_execute) = self.on_execute.as_ref() {
+            on_execute(&command, cx);
+        }
+        if let Some(action) = command.action {
+            cx.dispatch_action(action);
+        }
+    }
+}
+
+impl FocusableView for CommandPalette {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.list.focus_handle(cx)
+    }
+}
+
+impl Render for CommandPalette {
+    fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex().py_1().child(self.list.clone())
+    }
+}
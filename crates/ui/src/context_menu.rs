@@ -12,6 +12,10 @@ use crate::popup_menu::PopupMenu;
 pub fn init(_cx: &mut AppContext) {}
 
 pub trait ContextMenuExt: ParentElement + Sized {
+    /// Add a context menu to the element, opened by right click at the mouse position.
+    ///
+    /// The menu is triggered within the bounds of the nearest positioned (e.g.: `relative`) element.
+    /// The element must have an id, e.g.: `div().id("editor")`, the menu state is kept under it.
     fn context_menu(
         self,
         f: impl Fn(PopupMenu, &mut ViewContext<PopupMenu>) -> PopupMenu + 'static,
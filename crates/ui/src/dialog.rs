@@ -0,0 +1,174 @@
+use std::{cell::RefCell, future::Future, rc::Rc};
+
+use gpui::{div, prelude::FluentBuilder, px, ParentElement, SharedString, Styled, WindowContext};
+use rust_i18n::t;
+
+use crate::{
+    button::{Button, ButtonStyled as _},
+    h_flex,
+    theme::ActiveTheme as _,
+    ContextModal,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DialogKind {
+    Confirm,
+    Alert,
+}
+
+/// A confirm or alert dialog on top of the [`Modal`](crate::modal::Modal).
+///
+/// ```ignore
+/// Dialog::confirm("Delete file", "Are you sure to delete this file?")
+///     .danger(true)
+///     .on_result(|ok, cx| { ... })
+///     .open(cx);
+/// ```
+pub struct Dialog {
+    kind: DialogKind,
+    title: SharedString,
+    message: SharedString,
+    ok_text: Option<SharedString>,
+    cancel_text: Option<SharedString>,
+    danger: bool,
+    on_result: Option<Rc<dyn Fn(bool, &mut WindowContext) + 'static>>,
+}
+
+impl Dialog {
+    fn new(
+        kind: DialogKind,
+        title: impl Into<SharedString>,
+        message: impl Into<SharedString>,
+    ) -> Self {
+        Self {
+            kind,
+            title: title.into(),
+            message: message.into(),
+            ok_text: None,
+            cancel_text: None,
+            danger: false,
+            on_result: None,
+        }
+    }
+
+    /// Create a confirm dialog with OK and Cancel buttons.
+    pub fn confirm(title: impl Into<SharedString>, message: impl Into<SharedString>) -> Self {
+        Self::new(DialogKind::Confirm, title, message)
+    }
+
+    /// Create an alert dialog with only the OK button.
+    pub fn alert(title: impl Into<SharedString>, message: impl Into<SharedString>) -> Self {
+        Self::new(DialogKind::Alert, title, message)
+    }
+
+    /// Set the text of the OK button, defaults to "OK".
+    pub fn ok_text(mut self, ok_text: impl Into<SharedString>) -> Self {
+        self.ok_text = Some(ok_text.into());
+        self
+    }
+
+    /// Set the text of the Cancel button, defaults to "Cancel".
+    pub fn cancel_text(mut self, cancel_text: impl Into<SharedString>) -> Self {
+        self.cancel_text = Some(cancel_text.into());
+        self
+    }
+
+    /// Set true to show the OK button as danger, for the destructive actions.
+    pub fn danger(mut self, danger: bool) -> Self {
+        self.danger = danger;
+        self
+    }
+
+    /// Set the callback with the user's choice, true for OK.
+    ///
+    /// Closing the dialog by Escape, clicking the overlay or the close button is same as Cancel.
+    pub fn on_result(mut self, on_result: impl Fn(bool, &mut WindowContext) + 'static) -> Self {
+        self.on_result = Some(Rc::new(on_result));
+        self
+    }
+
+    /// Open the dialog, and return a future resolving to the user's choice, true for OK.
+    pub fn prompt(self, cx: &mut WindowContext) -> impl Future<Output = bool> {
+        let (tx, rx) = smol::channel::bounded(1);
+        let on_result = self.on_result.clone();
+        self.on_result(move |ok, cx| {
+            _ = tx.try_send(ok);
+            if let Some(on_result) = on_result.as_ref() {
+                on_result(ok, cx);
+            }
+        })
+        .open(cx);
+
+        async move { rx.recv().await.unwrap_or(false) }
+    }
+
+    /// Open the dialog.
+    pub fn open(self, cx: &mut WindowContext) {
+        let kind = self.kind;
+        let title = self.title;
+        let message = self.message;
+        let ok_text = self.ok_text.unwrap_or_else(|| t!("Dialog.OK").into());
+        let cancel_text = self
+            .cancel_text
+            .unwrap_or_else(|| t!("Dialog.Cancel").into());
+        let danger = self.danger;
+
+        // The modal builder is called on each render, make sure the result is only reported once.
+        let on_result = Rc::new(RefCell::new(self.on_result));
+        let report: Rc<dyn Fn(bool, &mut WindowContext)> = Rc::new(move |ok, cx| {
+            if let Some(on_result) = on_result.borrow_mut().take() {
+                on_result(ok, cx);
+            }
+        });
+
+        cx.open_modal(move |modal, cx| {
+            let report = report.clone();
+
+            modal
+                .title(title.clone())
+                .width(px(400.))
+                .min_h_0()
+                .child(
+                    div()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(message.clone()),
+                )
+                .on_close({
+                    let report = report.clone();
+                    move |_, cx| report(false, cx)
+                })
+                .footer(
+                    h_flex()
+                        .justify_end()
+                        .gap_2()
+                        .when(kind == DialogKind::Confirm, |this| {
+                            let report = report.clone();
+                            this.child(
+                                Button::new("dialog-cancel")
+                                    .outline()
+                                    .label(cancel_text.clone())
+                                    .on_click(move |_, cx| {
+                                        report(false, cx);
+                                        cx.close_modal();
+                                    }),
+                            )
+                        })
+                        .child(
+                            Button::new("dialog-ok")
+                                .map(|this| {
+                                    if danger {
+                                        this.danger()
+                                    } else {
+                                        this.primary()
+                                    }
+                                })
+                                .label(ok_text.clone())
+                                .on_click(move |_, cx| {
+                                    report(true, cx);
+                                    cx.close_modal();
+                                }),
+                        ),
+                )
+        });
+    }
+}
@@ -0,0 +1,235 @@
+use std::sync::Arc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AppContext, Axis, DragMoveEvent, EventEmitter,
+    InteractiveElement as _, IntoElement, ParentElement, Pixels, Render,
+    StatefulInteractiveElement as _, Styled, ViewContext, VisualContext as _, WeakView,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::theme::ActiveTheme as _;
+
+use super::{DockArea, DockItem, DockItemState, PanelEvent, PanelView, TabPanel};
+
+/// The default size of the [`Dock`].
+pub(super) const DEFAULT_SIZE: Pixels = px(250.);
+const MIN_SIZE: Pixels = px(100.);
+const HANDLE_SIZE: Pixels = px(4.);
+
+/// The placement of the [`Dock`] in the [`DockArea`], the center is the root item of the dock area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DockPlacement {
+    Left,
+    Bottom,
+    Right,
+}
+
+impl DockPlacement {
+    /// The axis to stack the panels in the dock.
+    fn stack_axis(&self) -> Axis {
+        match self {
+            Self::Left | Self::Right => Axis::Vertical,
+            Self::Bottom => Axis::Horizontal,
+        }
+    }
+}
+
+#[derive(Clone, Render)]
+struct ResizeDock(DockPlacement);
+
+/// The serialized state of the [`Dock`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DockState {
+    pub panel: DockItemState,
+    pub size: Pixels,
+    pub open: bool,
+}
+
+/// A dock on the left, bottom or right side of the [`DockArea`], it can be resized and toggled.
+///
+/// The panels can be dragged between the docks and the center by the tabs.
+pub struct Dock {
+    placement: DockPlacement,
+    dock_area: WeakView<DockArea>,
+    panel: DockItem,
+    size: Pixels,
+    open: bool,
+}
+
+impl Dock {
+    pub(super) fn new(
+        dock_area: WeakView<DockArea>,
+        placement: DockPlacement,
+        item: DockItem,
+        size: Pixels,
+        open: bool,
+        cx: &mut ViewContext<Self>,
+    ) -> Self {
+        // The TabPanel must be in a StackPanel to split.
+        let panel = match item {
+            DockItem::Tabs { .. } => {
+                DockItem::split(placement.stack_axis(), vec![item], &dock_area, cx)
+            }
+            item => item,
+        };
+
+        if let DockItem::Split { view, .. } = &panel {
+            cx.subscribe(view, |_, _, event, cx| {
+                if let PanelEvent::LayoutChanged = event {
+                    cx.emit(PanelEvent::LayoutChanged);
+                    cx.notify();
+                }
+            })
+            .detach();
+        }
+
+        Self {
+            placement,
+            dock_area,
+            panel,
+            size: size.max(MIN_SIZE),
+            open,
+        }
+    }
+
+    pub fn placement(&self) -> DockPlacement {
+        self.placement
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Return the width of the left and right dock, or the height of the bottom dock.
+    pub fn size(&self) -> Pixels {
+        self.size
+    }
+
+    pub fn set_open(&mut self, open: bool, cx: &mut ViewContext<Self>) {
+        self.open = open;
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    pub fn toggle_open(&mut self, cx: &mut ViewContext<Self>) {
+        self.set_open(!self.open, cx);
+    }
+
+    pub fn set_size(&mut self, size: Pixels, cx: &mut ViewContext<Self>) {
+        self.size = size.max(MIN_SIZE);
+        cx.emit(PanelEvent::LayoutChanged);
+        cx.notify();
+    }
+
+    /// Return true if there is no panel in the dock.
+    pub fn is_empty(&self, cx: &AppContext) -> bool {
+        match &self.panel {
+            DockItem::Split { view, .. } => view.read(cx).panels_len() == 0,
+            DockItem::Tabs { view, .. } => view.read(cx).panels.is_empty(),
+        }
+    }
+
+    /// Add the panel to the first tabs of the dock, and open the dock.
+    pub(super) fn add_panel(&mut self, panel: Arc<dyn PanelView>, cx: &mut ViewContext<Self>) {
+        let DockItem::Split {
+            view: stack_panel, ..
+        } = &self.panel
+        else {
+            return;
+        };
+
+        let tab_panel = stack_panel
+            .read(cx)
+            .panels
+            .iter()
+            .find_map(|panel| panel.view().downcast::<TabPanel>().ok());
+        match tab_panel {
+            Some(tab_panel) => tab_panel.update(cx, |view, cx| view.add_panel(panel, cx)),
+            None => {
+                let dock_area = self.dock_area.clone();
+                let tab_panel = cx.new_view(|cx| {
+                    let mut tab_panel = TabPanel::new(None, dock_area.clone(), cx);
+                    tab_panel.add_panel(panel, cx);
+                    tab_panel
+                });
+                stack_panel.update(cx, |view, cx| {
+                    view.add_panel(Arc::new(tab_panel), None, dock_area, cx)
+                });
+            }
+        }
+
+        self.set_open(true, cx);
+    }
+
+    pub fn dump(&self, cx: &AppContext) -> DockState {
+        DockState {
+            panel: self.panel.view().dump(cx),
+            size: self.size,
+            open: self.open,
+        }
+    }
+
+    fn on_resize(&mut self, event: &DragMoveEvent<ResizeDock>, cx: &mut ViewContext<Self>) {
+        if event.drag(cx).0 != self.placement {
+            return;
+        }
+
+        let bounds = event.bounds;
+        let position = event.event.position;
+        let size = match self.placement {
+            DockPlacement::Left => position.x - bounds.left(),
+            DockPlacement::Right => bounds.right() - position.x,
+            DockPlacement::Bottom => bounds.bottom() - position.y,
+        };
+        self.set_size(size, cx);
+    }
+
+    fn render_resize_handle(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let placement = self.placement;
+
+        div()
+            .id("dock-resize-handle")
+            .occlude()
+            .absolute()
+            .map(|this| match placement {
+                DockPlacement::Left => this.top_0().right_0().h_full().w(HANDLE_SIZE),
+                DockPlacement::Right => this.top_0().left_0().h_full().w(HANDLE_SIZE),
+                DockPlacement::Bottom => this.top_0().left_0().w_full().h(HANDLE_SIZE),
+            })
+            .map(|this| match placement {
+                DockPlacement::Left | DockPlacement::Right => this.cursor_col_resize(),
+                DockPlacement::Bottom => this.cursor_row_resize(),
+            })
+            .hover(|this| this.bg(cx.theme().drag_border))
+            .on_drag(ResizeDock(placement), |drag, cx| {
+                cx.stop_propagation();
+                cx.new_view(|_| drag.clone())
+            })
+    }
+}
+
+impl EventEmitter<PanelEvent> for Dock {}
+
+impl Render for Dock {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        if !self.open || self.is_empty(cx) {
+            return div().id("dock");
+        }
+
+        div()
+            .id("dock")
+            .relative()
+            .flex_shrink_0()
+            .overflow_hidden()
+            .border_color(cx.theme().border)
+            .map(|this| match self.placement {
+                DockPlacement::Left => this.h_full().w(self.size).border_r_1(),
+                DockPlacement::Right => this.h_full().w(self.size).border_l_1(),
+                DockPlacement::Bottom => this.w_full().h(self.size).border_t_1(),
+            })
+            .on_drag_move(cx.listener(Self::on_resize))
+            .child(self.panel.view().view())
+            .child(self.render_resize_handle(cx))
+    }
+}
@@ -1,3 +1,5 @@
+#[allow(clippy::module_inception)]
+mod dock;
 mod invalid_panel;
 mod panel;
 mod stack_panel;
@@ -6,10 +8,14 @@ mod tab_panel;
 use std::sync::Arc;
 
 use gpui::{
-    actions, div, prelude::FluentBuilder, AnyElement, AnyView, AppContext, Axis, EventEmitter,
+    actions, div, prelude::FluentBuilder, px, AnyElement, AnyView, AppContext, Axis, EventEmitter,
     InteractiveElement as _, IntoElement, ParentElement as _, Pixels, Render, SharedString, Styled,
     View, ViewContext, VisualContext, WeakView, WindowContext,
 };
+use serde::{Deserialize, Serialize};
+
+use crate::{h_flex, theme::ActiveTheme as _, v_flex};
+pub use dock::*;
 pub use panel::*;
 pub use stack_panel::*;
 pub use tab_panel::*;
@@ -28,11 +34,51 @@ pub enum DockEvent {
     LayoutChanged,
 }
 
-/// The main area of the dock.
+/// The width of the drop zone to drag a panel into the closed or empty dock.
+const DROP_ZONE_SIZE: Pixels = px(48.);
+
+/// The main area of the dock, the `items` is in the center, and the optional docks around it.
 pub struct DockArea {
     id: SharedString,
     items: DockItem,
     zoom_view: Option<AnyView>,
+    left_dock: Option<View<Dock>>,
+    bottom_dock: Option<View<Dock>>,
+    right_dock: Option<View<Dock>>,
+}
+
+/// The serialized layout of the [`DockArea`], it can be restored by [`DockAreaState::load`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DockAreaState {
+    pub center: DockItemState,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub left_dock: Option<DockState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bottom_dock: Option<DockState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub right_dock: Option<DockState>,
+}
+
+impl DockAreaState {
+    /// Restore the layout to the dock area.
+    pub fn load(&self, dock_area: &View<DockArea>, cx: &mut WindowContext) {
+        let weak_dock_area = dock_area.downgrade();
+        let center = self.center.to_item(weak_dock_area.clone(), cx);
+        dock_area.update(cx, |view, cx| view.set_root(center, cx));
+
+        for (placement, dock) in [
+            (DockPlacement::Left, &self.left_dock),
+            (DockPlacement::Bottom, &self.bottom_dock),
+            (DockPlacement::Right, &self.right_dock),
+        ] {
+            if let Some(dock) = dock {
+                let item = dock.panel.to_item(weak_dock_area.clone(), cx);
+                dock_area.update(cx, |view, cx| {
+                    view.set_dock(item, placement, Some(dock.size), dock.open, cx)
+                });
+            }
+        }
+    }
 }
 
 /// DockItem is a tree structure that represents the layout of the dock.
@@ -179,6 +225,9 @@ impl DockArea {
             id: id.into(),
             items: dock_item,
             zoom_view: None,
+            left_dock: None,
+            bottom_dock: None,
+            right_dock: None,
         }
     }
 
@@ -191,12 +240,138 @@ impl DockArea {
         cx.notify();
     }
 
-    /// Dump the dock panels layout to DockItemState.
-    ///
-    /// See also `DockItemState::to_item` for the load DockItem from DockItemState.
-    pub fn dump(&self, cx: &AppContext) -> DockItemState {
-        let root = self.items.view();
-        root.dump(cx)
+    /// Set the DockItem as the dock at the `placement`, the `size` is the width of the left and
+    /// right dock, or the height of the bottom dock.
+    pub fn set_dock(
+        &mut self,
+        item: DockItem,
+        placement: DockPlacement,
+        size: Option<Pixels>,
+        open: bool,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let view = cx.view().clone();
+        // Create the dock later, it may add panels to the dock area when wrap the tabs.
+        cx.window_context().defer(move |cx| {
+            let dock_area = view.downgrade();
+            let dock = cx.new_view(|cx| {
+                Dock::new(
+                    dock_area,
+                    placement,
+                    item,
+                    size.unwrap_or(DEFAULT_SIZE),
+                    open,
+                    cx,
+                )
+            });
+
+            view.update(cx, |view, cx| {
+                cx.subscribe(&dock, |_, _, event, cx| {
+                    if let PanelEvent::LayoutChanged = event {
+                        cx.emit(DockEvent::LayoutChanged);
+                    }
+                })
+                .detach();
+
+                *view.dock_mut(placement) = Some(dock);
+                cx.emit(DockEvent::LayoutChanged);
+                cx.notify();
+            });
+        });
+    }
+
+    /// Return the dock at the `placement`.
+    pub fn dock(&self, placement: DockPlacement) -> Option<&View<Dock>> {
+        match placement {
+            DockPlacement::Left => self.left_dock.as_ref(),
+            DockPlacement::Bottom => self.bottom_dock.as_ref(),
+            DockPlacement::Right => self.right_dock.as_ref(),
+        }
+    }
+
+    fn dock_mut(&mut self, placement: DockPlacement) -> &mut Option<View<Dock>> {
+        match placement {
+            DockPlacement::Left => &mut self.left_dock,
+            DockPlacement::Bottom => &mut self.bottom_dock,
+            DockPlacement::Right => &mut self.right_dock,
+        }
+    }
+
+    /// Return true if the dock at the `placement` is open.
+    pub fn is_dock_open(&self, placement: DockPlacement, cx: &AppContext) -> bool {
+        self.dock(placement)
+            .map(|dock| dock.read(cx).is_open())
+            .unwrap_or(false)
+    }
+
+    /// Toggle the dock at the `placement` to open or close.
+    pub fn toggle_dock(&self, placement: DockPlacement, cx: &mut ViewContext<Self>) {
+        if let Some(dock) = self.dock(placement) {
+            dock.update(cx, |dock, cx| dock.toggle_open(cx));
+        }
+    }
+
+    /// Dump the layout of the center and the docks, see [`DockAreaState::load`] to restore it.
+    pub fn dump(&self, cx: &AppContext) -> DockAreaState {
+        DockAreaState {
+            center: self.items.view().dump(cx),
+            left_dock: self.left_dock.as_ref().map(|dock| dock.read(cx).dump(cx)),
+            bottom_dock: self.bottom_dock.as_ref().map(|dock| dock.read(cx).dump(cx)),
+            right_dock: self.right_dock.as_ref().map(|dock| dock.read(cx).dump(cx)),
+        }
+    }
+
+    /// Move the dragged panel into the dock at the `placement`, create the dock if it not exists.
+    fn on_drop_to_dock(
+        &mut self,
+        placement: DockPlacement,
+        drag: &DragPanel,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let panel = drag.panel.clone();
+        let tab_panel = drag.tab_panel.clone();
+        let view = cx.view().clone();
+
+        // Update the panels out of this view, they will update the dock area to subscribe events.
+        cx.window_context().defer(move |cx| {
+            tab_panel.update(cx, |view, cx| {
+                view.detach_panel(panel.clone(), cx);
+                view.remove_self_if_empty(cx);
+            });
+
+            if let Some(dock) = view.read(cx).dock(placement).cloned() {
+                dock.update(cx, |dock, cx| dock.add_panel(panel, cx));
+            } else {
+                let item = DockItem::tabs(vec![panel], None, &view.downgrade(), cx);
+                view.update(cx, |view, cx| {
+                    view.set_dock(item, placement, None, true, cx)
+                });
+            }
+        });
+    }
+
+    fn render_drop_zone(
+        &self,
+        placement: DockPlacement,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        div()
+            .invisible()
+            .occlude()
+            .absolute()
+            .bg(cx.theme().drop_target)
+            .map(|this| match placement {
+                DockPlacement::Left => this.top_0().left_0().h_full().w(DROP_ZONE_SIZE),
+                DockPlacement::Right => this.top_0().right_0().h_full().w(DROP_ZONE_SIZE),
+                DockPlacement::Bottom => this.bottom_0().left_0().w_full().h(DROP_ZONE_SIZE),
+            })
+            .group_drag_over::<DragPanel>("dock-area", |this| this.visible())
+            .drag_over::<DragPanel>(|this, _, cx| {
+                this.border_1().border_color(cx.theme().drag_border)
+            })
+            .on_drop(cx.listener(move |this, drag: &DragPanel, cx| {
+                this.on_drop_to_dock(placement, drag, cx)
+            }))
     }
 
     /// Subscribe event on the panels
@@ -276,16 +451,62 @@ impl EventEmitter<DockEvent> for DockArea {}
 impl Render for DockArea {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         // println!("Rendering dock area");
+        let drop_zones: Vec<DockPlacement> = if cx.has_active_drag() {
+            [
+                DockPlacement::Left,
+                DockPlacement::Bottom,
+                DockPlacement::Right,
+            ]
+            .into_iter()
+            .filter(|placement| match self.dock(*placement) {
+                Some(dock) => {
+                    let dock = dock.read(cx);
+                    !dock.is_open() || dock.is_empty(cx)
+                }
+                None => true,
+            })
+            .collect()
+        } else {
+            vec![]
+        };
+
         div()
             .id("dock-area")
+            .group("dock-area")
+            .relative()
             .size_full()
             .overflow_hidden()
             .map(|this| {
                 if let Some(zoom_view) = self.zoom_view.clone() {
-                    this.child(zoom_view)
-                } else {
-                    this.child(self.render_items(cx))
+                    return this.child(zoom_view);
                 }
+
+                this.child(
+                    h_flex()
+                        .size_full()
+                        .children(self.left_dock.clone())
+                        .child(
+                            v_flex()
+                                .flex_1()
+                                .h_full()
+                                .overflow_hidden()
+                                .child(
+                                    div()
+                                        .flex_1()
+                                        .w_full()
+                                        .overflow_hidden()
+                                        .child(self.render_items(cx)),
+                                )
+                                .children(self.bottom_dock.clone()),
+                        )
+                        .children(self.right_dock.clone()),
+                )
+                // Show the drop zones to drag panels into the closed or empty docks.
+                .children(
+                    drop_zones
+                        .into_iter()
+                        .map(|placement| self.render_drop_zone(placement, cx)),
+                )
             })
     }
 }
@@ -49,7 +49,7 @@ impl Render for DragPanel {
             .rounded_md()
             .text_color(cx.theme().tab_foreground)
             .bg(cx.theme().tab_active)
-            .opacity(0.75)
+            .opacity(cx.theme().element_opacity(0.75))
             .child(self.panel.title(cx))
     }
 }
@@ -223,7 +223,7 @@ impl TabPanel {
         cx.emit(PanelEvent::LayoutChanged);
     }
 
-    fn detach_panel(&mut self, panel: Arc<dyn PanelView>, cx: &mut ViewContext<Self>) {
+    pub(super) fn detach_panel(&mut self, panel: Arc<dyn PanelView>, cx: &mut ViewContext<Self>) {
         let panel_view = panel.view();
         self.panels.retain(|p| p.view() != panel_view);
         if self.active_ix >= self.panels.len() {
@@ -232,7 +232,7 @@ impl TabPanel {
     }
 
     /// Check to remove self from the parent StackPanel, if there is no panel left
-    fn remove_self_if_empty(&self, cx: &mut ViewContext<Self>) {
+    pub(super) fn remove_self_if_empty(&self, cx: &mut ViewContext<Self>) {
         if !self.panels.is_empty() {
             return;
         }
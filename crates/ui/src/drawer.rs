@@ -2,28 +2,36 @@ use std::{rc::Rc, time::Duration};
 
 use gpui::{
     anchored, div, point, prelude::FluentBuilder as _, px, Animation, AnimationExt as _,
-    AnyElement, ClickEvent, DefiniteLength, DismissEvent, Div, EventEmitter, FocusHandle,
-    InteractiveElement as _, IntoElement, MouseButton, ParentElement, Pixels, RenderOnce, Styled,
-    WindowContext,
+    AnyElement, ClickEvent, DefiniteLength, DismissEvent, Div, DragMoveEvent, EventEmitter,
+    FocusHandle, InteractiveElement as _, IntoElement, MouseButton, ParentElement, Pixels, Render,
+    RenderOnce, StatefulInteractiveElement as _, Styled, Task, VisualContext as _, WindowContext,
 };
 
 use crate::{
     button::{Button, ButtonStyled as _},
     h_flex,
-    modal::overlay_color,
-    root::ContextModal as _,
+    modal::{overlay_color, request_close, BeforeCloseFn},
+    root::{ContextModal as _, Root},
     scroll::ScrollbarAxis,
     theme::ActiveTheme,
     v_flex, IconName, Placement, Sizable, StyledExt as _,
 };
 
+const DRAWER_MIN_SIZE: Pixels = px(100.);
+const HANDLE_SIZE: Pixels = px(8.);
+
+#[derive(Clone, Render)]
+struct DragDrawer;
+
 #[derive(IntoElement)]
 pub struct Drawer {
     focus_handle: FocusHandle,
     placement: Placement,
-    size: DefiniteLength,
+    /// The size will be replaced by the size of user resized.
+    pub(crate) size: DefiniteLength,
     resizable: bool,
     on_close: Rc<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>,
+    on_before_close: Option<BeforeCloseFn>,
     title: Option<AnyElement>,
     footer: Option<AnyElement>,
     content: Div,
@@ -44,6 +52,7 @@ impl Drawer {
             margin_top: px(0.),
             overlay: true,
             on_close: Rc::new(|_, _| {}),
+            on_before_close: None,
         }
     }
 
@@ -84,7 +93,7 @@ impl Drawer {
         self.placement = placement;
     }
 
-    /// Sets whether the drawer is resizable, default is `true`.
+    /// Sets whether the drawer is resizable by dragging the inner edge, default is `true`.
     pub fn resizable(mut self, resizable: bool) -> Self {
         self.resizable = resizable;
         self
@@ -104,6 +113,18 @@ impl Drawer {
         self.on_close = Rc::new(on_close);
         self
     }
+
+    /// Sets the callback to decide whether the drawer can be closed by the user,
+    /// the returned task resolves to `true` to allow closing.
+    ///
+    /// See also [`Modal::on_before_close`](crate::modal::Modal::on_before_close).
+    pub fn on_before_close(
+        mut self,
+        on_before_close: impl Fn(&mut WindowContext) -> Task<bool> + 'static,
+    ) -> Self {
+        self.on_before_close = Some(Rc::new(on_before_close));
+        self
+    }
 }
 
 impl EventEmitter<DismissEvent> for Drawer {}
@@ -124,7 +145,21 @@ impl RenderOnce for Drawer {
         let placement = self.placement;
         let titlebar_height = self.margin_top;
         let size = cx.viewport_size();
-        let on_close = self.on_close.clone();
+        let close = {
+            let on_close = self.on_close.clone();
+            let on_before_close = self.on_before_close.clone();
+            Rc::new(move |cx: &mut WindowContext| {
+                let on_close = on_close.clone();
+                request_close(
+                    on_before_close.clone(),
+                    move |cx| {
+                        on_close(&ClickEvent::default(), cx);
+                        cx.close_drawer();
+                    },
+                    cx,
+                );
+            })
+        };
 
         anchored()
             .position(point(px(0.), titlebar_height))
@@ -135,13 +170,25 @@ impl RenderOnce for Drawer {
                     .w(size.width)
                     .h(size.height - titlebar_height)
                     .bg(overlay_color(self.overlay, cx))
+                    .on_drag_move(move |e: &DragMoveEvent<DragDrawer>, cx| {
+                        let position = e.event.position;
+                        let (drawer_size, max_size) = match placement {
+                            Placement::Left => (position.x, size.width),
+                            Placement::Right => (size.width - position.x, size.width),
+                            Placement::Top => {
+                                (position.y - titlebar_height, size.height - titlebar_height)
+                            }
+                            Placement::Bottom => {
+                                (size.height - position.y, size.height - titlebar_height)
+                            }
+                        };
+                        let drawer_size = drawer_size.max(DRAWER_MIN_SIZE).min(max_size);
+                        Root::update(cx, move |root, cx| root.resize_drawer(drawer_size, cx));
+                    })
                     .when(self.overlay, |this| {
                         this.on_mouse_down(MouseButton::Left, {
-                            let on_close = self.on_close.clone();
-                            move |_, cx| {
-                                on_close(&ClickEvent::default(), cx);
-                                cx.close_drawer();
-                            }
+                            let close = close.clone();
+                            move |_, cx| close(cx)
                         })
                     })
                     .child(
@@ -169,6 +216,9 @@ impl RenderOnce for Drawer {
                                 }
                                 Placement::Left => this.top_0().left_0().bottom_0().border_r_1(),
                             })
+                            .when(self.resizable, |this| {
+                                this.child(render_resize_handle(placement))
+                            })
                             .child(
                                 // TitleBar
                                 h_flex()
@@ -182,10 +232,7 @@ impl RenderOnce for Drawer {
                                             .small()
                                             .ghost()
                                             .icon(IconName::Close)
-                                            .on_click(move |_, cx| {
-                                                on_close(&ClickEvent::default(), cx);
-                                                cx.close_drawer();
-                                            }),
+                                            .on_click(move |_, cx| close(cx)),
                                     ),
                             )
                             .child(
@@ -227,3 +274,30 @@ impl RenderOnce for Drawer {
             )
     }
 }
+
+/// The handle on the inner edge of the drawer to resize it.
+fn render_resize_handle(placement: Placement) -> impl IntoElement {
+    let offset = -HANDLE_SIZE / 2.;
+
+    div()
+        .id("drawer-resize-handle")
+        .occlude()
+        .absolute()
+        .map(|this| match placement {
+            Placement::Left => this.top_0().bottom_0().right(offset).w(HANDLE_SIZE),
+            Placement::Right => this.top_0().bottom_0().left(offset).w(HANDLE_SIZE),
+            Placement::Top => this.left_0().right_0().bottom(offset).h(HANDLE_SIZE),
+            Placement::Bottom => this.left_0().right_0().top(offset).h(HANDLE_SIZE),
+        })
+        .map(|this| {
+            if placement.is_horizontal() {
+                this.cursor_col_resize()
+            } else {
+                this.cursor_row_resize()
+            }
+        })
+        .on_drag(DragDrawer, |drag, cx| {
+            cx.stop_propagation();
+            cx.new_view(|_| drag.clone())
+        })
+}
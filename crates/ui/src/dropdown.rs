@@ -1,16 +1,16 @@
 use gpui::{
     actions, anchored, canvas, deferred, div, prelude::FluentBuilder, px, rems, AnyElement,
     AppContext, Bounds, ClickEvent, DismissEvent, ElementId, EventEmitter, FocusHandle,
-    FocusableView, InteractiveElement, IntoElement, KeyBinding, Length, ParentElement, Pixels,
-    Render, SharedString, StatefulInteractiveElement, Styled, Task, View, ViewContext,
-    VisualContext, WeakView, WindowContext,
+    FocusableView, InteractiveElement, IntoElement, KeyBinding, KeyDownEvent, Length,
+    ParentElement, Pixels, Render, SharedString, StatefulInteractiveElement, Styled, Task, View,
+    ViewContext, VisualContext, WeakView, WindowContext,
 };
 use rust_i18n::t;
 
 use crate::{
     h_flex,
     input::ClearButton,
-    list::{self, List, ListDelegate, ListItem},
+    list::{self, List, ListDelegate, ListItem, TypeAhead},
     theme::ActiveTheme,
     v_flex, Disableable, Icon, IconName, Sizable, Size, StyleSized, StyledExt,
 };
@@ -27,11 +27,18 @@ pub fn init(cx: &mut AppContext) {
     ])
 }
 
+/// The type-ahead query is cleared after this duration without typing.
+
 /// A trait for items that can be displayed in a dropdown.
 pub trait DropdownItem {
     type Value: Clone;
     fn title(&self) -> SharedString;
     fn value(&self) -> &Self::Value;
+
+    /// Return true to show the item as disabled, it can't be selected.
+    fn disabled(&self) -> bool {
+        false
+    }
 }
 
 impl DropdownItem for String {
@@ -69,6 +76,13 @@ pub trait DropdownDelegate: Sized {
 
     fn get(&self, ix: usize) -> Option<&Self::Item>;
 
+    /// Return the title if the row at the given index is a group header.
+    ///
+    /// The group headers can't be selected, `get` should return None for them.
+    fn group_title(&self, _ix: usize) -> Option<SharedString> {
+        None
+    }
+
     fn position<V>(&self, value: &V) -> Option<usize>
     where
         Self::Item: DropdownItem<Value = V>,
@@ -110,6 +124,30 @@ struct DropdownListDelegate<D: DropdownDelegate + 'static> {
     delegate: D,
     dropdown: WeakView<Dropdown<D>>,
     selected_index: Option<usize>,
+    creatable: bool,
+    /// The query to create a new item, shown as the last row when no item title equals to it.
+    create_query: Option<SharedString>,
+}
+
+impl<D> DropdownListDelegate<D>
+where
+    D: DropdownDelegate + 'static,
+{
+    fn update_create_query(&mut self, query: &str) {
+        let query = query.trim();
+        let exists = (0..self.delegate.len()).any(|ix| {
+            self.delegate.get(ix).map_or(false, |item| {
+                item.title().to_lowercase() == query.to_lowercase()
+            })
+        });
+
+        self.create_query =
+            (self.creatable && !query.is_empty() && !exists).then(|| query.to_string().into());
+    }
+
+    fn is_create_row(&self, ix: usize) -> bool {
+        self.create_query.is_some() && ix == self.delegate.len()
+    }
 }
 
 impl<D> ListDelegate for DropdownListDelegate<D>
@@ -119,7 +157,7 @@ where
     type Item = ListItem;
 
     fn items_count(&self) -> usize {
-        self.delegate.len()
+        self.delegate.len() + self.create_query.is_some() as usize
     }
 
     fn confirmed_index(&self) -> Option<usize> {
@@ -135,13 +173,54 @@ where
             .upgrade()
             .map_or(Size::Medium, |dropdown| dropdown.read(cx).size);
 
+        if let Some(query) = self
+            .create_query
+            .as_ref()
+            .filter(|_| self.is_create_row(ix))
+        {
+            return Some(
+                ListItem::new("list-item-create")
+                    .cursor_pointer()
+                    .input_text_size(size)
+                    .list_size(size, cx)
+                    .child(
+                        h_flex()
+                            .gap_2()
+                            .child(Icon::new(IconName::Plus).small())
+                            .child(
+                                div()
+                                    .whitespace_nowrap()
+                                    .child(t!("Dropdown.Create", query = query).to_string()),
+                            ),
+                    ),
+            );
+        }
+
+        if let Some(title) = self.delegate.group_title(ix) {
+            return Some(
+                ListItem::new(("list-group", ix))
+                    .disabled(true)
+                    .bg(cx.theme().background)
+                    .input_text_size(size)
+                    .list_size(size, cx)
+                    .child(
+                        div()
+                            .whitespace_nowrap()
+                            .font_semibold()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(title),
+                    ),
+            );
+        }
+
         if let Some(item) = self.delegate.get(ix) {
             let list_item = ListItem::new(("list-item", ix))
                 .check_icon(IconName::Check)
-                .cursor_pointer()
+                .when(!item.disabled(), |this| this.cursor_pointer())
+                .disabled(item.disabled())
                 .selected(selected)
                 .input_text_size(size)
-                .list_size(size)
+                .list_size(size, cx)
                 .child(div().whitespace_nowrap().child(item.title().to_string()));
             Some(list_item)
         } else {
@@ -149,6 +228,14 @@ where
         }
     }
 
+    fn is_section_header(&self, ix: usize) -> bool {
+        self.delegate.group_title(ix).is_some()
+    }
+
+    fn is_item_disabled(&self, ix: usize) -> bool {
+        self.delegate.get(ix).map_or(false, |item| item.disabled())
+    }
+
     fn cancel(&mut self, cx: &mut ViewContext<List<Self>>) {
         let dropdown = self.dropdown.clone();
         cx.defer(move |_, cx| {
@@ -160,6 +247,22 @@ where
     }
 
     fn confirm(&mut self, ix: Option<usize>, cx: &mut ViewContext<List<Self>>) {
+        if let Some(query) = self
+            .create_query
+            .clone()
+            .filter(|_| ix.map_or(false, |ix| self.is_create_row(ix)))
+        {
+            let dropdown = self.dropdown.clone();
+            cx.defer(move |_, cx| {
+                _ = dropdown.update(cx, |this, cx| {
+                    cx.emit(DropdownEvent::Create(query));
+                    this.open = false;
+                    this.focus(cx);
+                });
+            });
+            return;
+        }
+
         self.selected_index = ix;
 
         let selected_value = self
@@ -179,11 +282,24 @@ where
     }
 
     fn perform_search(&mut self, query: &str, cx: &mut ViewContext<List<Self>>) -> Task<()> {
-        self.dropdown
+        let search = self
+            .dropdown
             .upgrade()
             .map_or(Task::Ready(None), |dropdown| {
                 dropdown.update(cx, |_, cx| self.delegate.perform_search(query, cx))
-            })
+            });
+        if !self.creatable {
+            return search;
+        }
+
+        // Update the create row after the search is done, because the matched items may be changed.
+        let query = query.to_string();
+        cx.spawn(|this, mut cx| async move {
+            search.await;
+            _ = this.update(&mut cx, |list, _| {
+                list.delegate_mut().update_create_query(&query);
+            });
+        })
     }
 
     fn set_selected_index(&mut self, ix: Option<usize>, _: &mut ViewContext<List<Self>>) {
@@ -201,7 +317,7 @@ where
             h_flex()
                 .justify_center()
                 .py_6()
-                .text_color(cx.theme().muted_foreground.opacity(0.6))
+                .text_color(cx.theme().translucent(cx.theme().muted_foreground, 0.6))
                 .child(Icon::new(IconName::Inbox).size(px(28.)))
                 .into_any_element()
         }
@@ -210,6 +326,10 @@ where
 
 pub enum DropdownEvent<D: DropdownDelegate + 'static> {
     Confirm(Option<<D::Item as DropdownItem>::Value>),
+    /// The "Create" row has been chosen in the creatable mode, with the typed query.
+    ///
+    /// The application should append the new item to the delegate, see [`Dropdown::update_delegate`].
+    Create(SharedString),
 }
 
 pub struct Dropdown<D: DropdownDelegate + 'static> {
@@ -229,6 +349,8 @@ pub struct Dropdown<D: DropdownDelegate + 'static> {
     /// Store the bounds of the input
     bounds: Bounds<Pixels>,
     disabled: bool,
+    /// The typed characters to select the item by the title prefix.
+    type_ahead: TypeAhead,
 }
 
 pub struct SearchableVec<T> {
@@ -244,6 +366,12 @@ impl<T: DropdownItem + Clone> SearchableVec<T> {
             matched_items: items,
         }
     }
+
+    /// Append an item, it is also added to the matched items.
+    pub fn push(&mut self, item: T) {
+        self.items.push(item.clone());
+        self.matched_items.push(item);
+    }
 }
 
 impl<T: DropdownItem + Clone> DropdownDelegate for SearchableVec<T> {
@@ -296,6 +424,104 @@ impl From<Vec<SharedString>> for SearchableVec<SharedString> {
     }
 }
 
+/// A group of the dropdown items, the title is shown as a non-selectable header.
+#[derive(Clone)]
+pub struct DropdownItemGroup<T> {
+    pub title: SharedString,
+    pub items: Vec<T>,
+}
+
+impl<T> DropdownItemGroup<T> {
+    pub fn new(title: impl Into<SharedString>, items: impl Into<Vec<T>>) -> Self {
+        Self {
+            title: title.into(),
+            items: items.into(),
+        }
+    }
+}
+
+/// A searchable dropdown delegate with the items in groups.
+///
+/// The groups without any matched items are hidden when searching.
+pub struct SearchableGroupedVec<T> {
+    groups: Vec<DropdownItemGroup<T>>,
+    matched_groups: Vec<DropdownItemGroup<T>>,
+    /// The rows of the matched groups, `(group_ix, None)` is a group header.
+    rows: Vec<(usize, Option<usize>)>,
+}
+
+impl<T: DropdownItem + Clone> SearchableGroupedVec<T> {
+    pub fn new(groups: impl Into<Vec<DropdownItemGroup<T>>>) -> Self {
+        let groups = groups.into();
+        let mut this = Self {
+            groups: groups.clone(),
+            matched_groups: groups,
+            rows: vec![],
+        };
+        this.update_rows();
+        this
+    }
+
+    fn update_rows(&mut self) {
+        self.rows = self
+            .matched_groups
+            .iter()
+            .enumerate()
+            .flat_map(|(group_ix, group)| {
+                std::iter::once((group_ix, None))
+                    .chain((0..group.items.len()).map(move |ix| (group_ix, Some(ix))))
+            })
+            .collect();
+    }
+
+    fn filter(&mut self, query: &str) {
+        let query = query.to_lowercase();
+        self.matched_groups = self
+            .groups
+            .iter()
+            .filter_map(|group| {
+                let items = group
+                    .items
+                    .iter()
+                    .filter(|item| item.title().to_lowercase().contains(&query))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                (!items.is_empty()).then(|| DropdownItemGroup::new(group.title.clone(), items))
+            })
+            .collect();
+        self.update_rows();
+    }
+}
+
+impl<T: DropdownItem + Clone> DropdownDelegate for SearchableGroupedVec<T> {
+    type Item = T;
+
+    fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn get(&self, ix: usize) -> Option<&Self::Item> {
+        let (group_ix, item_ix) = *self.rows.get(ix)?;
+        self.matched_groups[group_ix].items.get(item_ix?)
+    }
+
+    fn group_title(&self, ix: usize) -> Option<SharedString> {
+        match *self.rows.get(ix)? {
+            (group_ix, None) => Some(self.matched_groups[group_ix].title.clone()),
+            _ => None,
+        }
+    }
+
+    fn can_search(&self) -> bool {
+        true
+    }
+
+    fn perform_search(&mut self, query: &str, _cx: &mut ViewContext<Dropdown<Self>>) -> Task<()> {
+        self.filter(query);
+        Task::Ready(Some(()))
+    }
+}
+
 impl<D> Dropdown<D>
 where
     D: DropdownDelegate + 'static,
@@ -311,6 +537,8 @@ where
             delegate,
             dropdown: cx.view().downgrade(),
             selected_index,
+            creatable: false,
+            create_query: None,
         };
 
         let searchable = delegate.delegate.can_search();
@@ -342,6 +570,7 @@ where
             menu_width: Length::Auto,
             bounds: Bounds::default(),
             disabled: false,
+            type_ahead: TypeAhead::default(),
         };
         this.set_selected_index(selected_index, cx);
         this
@@ -393,6 +622,30 @@ where
         self
     }
 
+    /// Set true to show a "Create" row when the search query doesn't match any item title,
+    /// choosing it emits [`DropdownEvent::Create`]. Only works with the searchable delegate.
+    pub fn set_creatable(&mut self, creatable: bool, cx: &mut ViewContext<Self>) {
+        self.list.update(cx, |list, cx| {
+            let delegate = list.delegate_mut();
+            delegate.creatable = creatable;
+            if !creatable {
+                delegate.create_query = None;
+            }
+            cx.notify();
+        });
+    }
+
+    /// Update the delegate, e.g.: append the created item.
+    pub fn update_delegate(&mut self, f: impl FnOnce(&mut D), cx: &mut ViewContext<Self>) {
+        self.list.update(cx, |list, cx| {
+            let delegate = list.delegate_mut();
+            f(&mut delegate.delegate);
+            let query = delegate.create_query.clone().unwrap_or_default();
+            delegate.update_create_query(&query);
+            cx.notify();
+        });
+    }
+
     pub fn empty<E, F>(mut self, f: F) -> Self
     where
         E: IntoElement,
@@ -502,6 +755,54 @@ where
         cx.notify();
     }
 
+    /// Select the item by typing the beginning of its title, when the dropdown is not searchable.
+    ///
+    /// Typing the same character repeatedly cycles through the items starting with it.
+    fn on_key_down(&mut self, event: &KeyDownEvent, cx: &mut ViewContext<Self>) {
+        let keystroke = &event.keystroke;
+        let modifiers = &keystroke.modifiers;
+        if self.disabled || modifiers.control || modifiers.alt || modifiers.platform {
+            return;
+        }
+
+        let list = self.list.read(cx);
+        let delegate = &list.delegate().delegate;
+        if delegate.can_search() {
+            return;
+        }
+
+        let key = keystroke.ime_key.as_ref().unwrap_or(&keystroke.key);
+        if !self.type_ahead.push(key) {
+            return;
+        }
+
+        let Some(ix) = self
+            .type_ahead
+            .find(list.selected_index(), delegate.len(), |ix| {
+                delegate
+                    .get(ix)
+                    .filter(|item| !item.disabled())
+                    .map(|item| item.title())
+            })
+        else {
+            return;
+        };
+
+        cx.stop_propagation();
+        if self.open {
+            // Only move the highlight, confirm it by Enter.
+            self.list.update(cx, |list, cx| {
+                list.set_selected_index(Some(ix), cx);
+                list.scroll_to_selected_item(cx);
+                cx.notify();
+            });
+        } else {
+            self.set_selected_index(Some(ix), cx);
+            cx.emit(DropdownEvent::Confirm(self.selected_value.clone()));
+        }
+        cx.notify();
+    }
+
     fn clean(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
         self.set_selected_index(None, cx);
         cx.emit(DropdownEvent::Confirm(None));
@@ -591,6 +892,7 @@ where
             .on_action(cx.listener(Self::down))
             .on_action(cx.listener(Self::enter))
             .on_action(cx.listener(Self::escape))
+            .on_key_down(cx.listener(Self::on_key_down))
             .size_full()
             .relative()
             .input_text_size(self.size)
@@ -620,7 +922,7 @@ where
                         Length::Auto => this.w_full(),
                     })
                     .when(outline_visible, |this| this.outline(cx))
-                    .input_size(self.size)
+                    .input_size(self.size, cx)
                     .when(allow_open, |this| {
                         this.on_click(cx.listener(Self::toggle_menu))
                     })
@@ -695,6 +997,7 @@ where
                                         .on_mouse_down_out(|_, cx| {
                                             cx.dispatch_action(Box::new(Escape));
                                         })
+                                        .on_key_down(cx.listener(Self::on_key_down))
                                         .child(self.list.clone()),
                                 )
                                 .on_mouse_down_out(cx.listener(|this, _, cx| {
@@ -707,3 +1010,33 @@ where
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::SharedString;
+
+    use super::{DropdownDelegate, DropdownItemGroup, SearchableGroupedVec};
+
+    #[test]
+    fn test_searchable_grouped_vec() {
+        let mut delegate = SearchableGroupedVec::<SharedString>::new(vec![
+            DropdownItemGroup::new("Fruits", vec!["Apple".into(), "Banana".into()]),
+            DropdownItemGroup::new("Vegetables", vec!["Carrot".into(), "Potato".into()]),
+        ]);
+        assert_eq!(delegate.len(), 6);
+        assert_eq!(delegate.group_title(0), Some("Fruits".into()));
+        assert_eq!(delegate.get(0), None);
+        assert_eq!(delegate.get(2), Some(&"Banana".into()));
+        assert_eq!(delegate.group_title(3), Some("Vegetables".into()));
+        assert_eq!(delegate.position(&SharedString::from("Potato")), Some(5));
+
+        // The groups without matched items are hidden.
+        delegate.filter("an");
+        assert_eq!(delegate.len(), 2);
+        assert_eq!(delegate.group_title(0), Some("Fruits".into()));
+        assert_eq!(delegate.get(1), Some(&"Banana".into()));
+
+        delegate.filter("xyz");
+        assert_eq!(delegate.len(), 0);
+    }
+}
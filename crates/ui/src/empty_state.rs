@@ -0,0 +1,91 @@
+use gpui::{
+    div, img, prelude::FluentBuilder as _, px, ImageSource, IntoElement, ParentElement as _,
+    RenderOnce, SharedString, Styled as _, WindowContext,
+};
+
+use crate::{button::Button, theme::ActiveTheme as _, v_flex, Icon, IconName, StyledExt as _};
+
+/// A placeholder for the empty content, e.g.: no items in a list, no search results.
+#[derive(IntoElement)]
+pub struct EmptyState {
+    icon: Icon,
+    image: Option<ImageSource>,
+    title: Option<SharedString>,
+    description: Option<SharedString>,
+    action: Option<Button>,
+}
+
+impl EmptyState {
+    pub fn new() -> Self {
+        Self {
+            icon: Icon::new(IconName::Inbox),
+            image: None,
+            title: None,
+            description: None,
+            action: None,
+        }
+    }
+
+    /// Set the icon, default: [`IconName::Inbox`].
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = icon.into();
+        self
+    }
+
+    /// Set the illustration image to replace the icon.
+    pub fn image(mut self, image: impl Into<ImageSource>) -> Self {
+        self.image = Some(image.into());
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<SharedString>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the call to action button, e.g.: "Create a project".
+    pub fn action(mut self, button: Button) -> Self {
+        self.action = Some(button);
+        self
+    }
+}
+
+impl RenderOnce for EmptyState {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        v_flex()
+            .size_full()
+            .items_center()
+            .justify_center()
+            .gap_2()
+            .p_6()
+            .text_center()
+            .map(|this| match self.image {
+                Some(image) => this.child(img(image).w(px(160.)).h(px(120.))),
+                None => this.child(
+                    self.icon
+                        .size_12()
+                        .text_color(cx.theme().translucent(cx.theme().muted_foreground, 0.6)),
+                ),
+            })
+            .when_some(self.title, |this, title| {
+                this.child(div().mt_2().font_semibold().child(title))
+            })
+            .when_some(self.description, |this, description| {
+                this.child(
+                    div()
+                        .max_w(px(360.))
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(description),
+                )
+            })
+            .when_some(self.action, |this, action| {
+                this.child(div().mt_2().child(action))
+            })
+    }
+}
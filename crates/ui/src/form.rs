@@ -0,0 +1,530 @@
+use std::{collections::BTreeMap, rc::Rc};
+
+use anyhow::Result;
+use gpui::{
+    div, prelude::FluentBuilder as _, AnyView, AppContext, EventEmitter, IntoElement,
+    ParentElement, Render, SharedString, Styled, Subscription, Task, View, ViewContext,
+    WindowContext,
+};
+use rust_i18n::t;
+use serde::de::DeserializeOwned;
+
+use crate::{
+    button::{Button, ButtonStyled as _},
+    h_flex,
+    input::{InputEvent, TextInput},
+    theme::ActiveTheme as _,
+    v_flex, Disableable as _, StyledExt as _,
+};
+
+/// The value of a [`FormField`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldValue {
+    Text(SharedString),
+    Number(f64),
+    Bool(bool),
+    None,
+}
+
+impl FieldValue {
+    /// Return true if the value is `None` or an empty text.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Text(text) => text.trim().is_empty(),
+            Self::None => true,
+            _ => false,
+        }
+    }
+}
+
+impl From<&FieldValue> for serde_json::Value {
+    fn from(value: &FieldValue) -> Self {
+        match value {
+            FieldValue::Text(text) => serde_json::Value::String(text.to_string()),
+            FieldValue::Number(number) => serde_json::json!(number),
+            FieldValue::Bool(value) => serde_json::Value::Bool(*value),
+            FieldValue::None => serde_json::Value::Null,
+        }
+    }
+}
+
+/// The values of the [`Form`] by the field names, collected on submit.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FormValues(BTreeMap<SharedString, FieldValue>);
+
+impl FormValues {
+    pub fn get(&self, name: &str) -> Option<&FieldValue> {
+        self.0.get(name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&SharedString, &FieldValue)> {
+        self.0.iter()
+    }
+
+    /// Deserialize the values into a typed struct, the field names are the keys.
+    pub fn parse<T: DeserializeOwned>(&self) -> Result<T> {
+        let map = self
+            .0
+            .iter()
+            .map(|(name, value)| (name.to_string(), serde_json::Value::from(value)))
+            .collect::<serde_json::Map<_, _>>();
+        Ok(serde_json::from_value(serde_json::Value::Object(map))?)
+    }
+}
+
+type ValidateFn = Rc<dyn Fn(&FieldValue) -> Result<(), SharedString>>;
+type ValidateAsyncFn =
+    Rc<dyn Fn(&FieldValue, &mut WindowContext) -> Task<Result<(), SharedString>>>;
+
+/// A field of the [`Form`], with the label, the control, the help text and the validators.
+pub struct FormField {
+    name: SharedString,
+    label: Option<SharedString>,
+    help: Option<SharedString>,
+    required: bool,
+    control: AnyView,
+    value: Rc<dyn Fn(&AppContext) -> FieldValue>,
+    validators: Vec<ValidateFn>,
+    async_validator: Option<ValidateAsyncFn>,
+}
+
+impl FormField {
+    /// Create a field with the control view, and a function to read the value from it.
+    ///
+    /// Call [`Form::touch`] when the control is blurred, it's done for the [`TextInput`].
+    pub fn new(
+        name: impl Into<SharedString>,
+        control: impl Into<AnyView>,
+        value: impl Fn(&AppContext) -> FieldValue + 'static,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            label: None,
+            help: None,
+            required: false,
+            control: control.into(),
+            value: Rc::new(value),
+            validators: vec![],
+            async_validator: None,
+        }
+    }
+
+    /// Create a field of the [`TextInput`], the value is the text of it.
+    pub fn input(name: impl Into<SharedString>, input: &View<TextInput>) -> Self {
+        let view = input.clone();
+        Self::new(name, input.clone(), move |cx| {
+            FieldValue::Text(view.read(cx).text())
+        })
+    }
+
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Set the help text below the control, it's replaced by the error message if invalid.
+    pub fn help(mut self, help: impl Into<SharedString>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
+
+    /// Set true to require a non-empty value, default is `false`.
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// Add a validator, the validators are run in order until the first error.
+    pub fn validate(
+        mut self,
+        f: impl Fn(&FieldValue) -> Result<(), SharedString> + 'static,
+    ) -> Self {
+        self.validators.push(Rc::new(f));
+        self
+    }
+
+    /// Set the async validator, e.g.: check the username is available on the server.
+    ///
+    /// It's run after all the sync validators are passed.
+    pub fn validate_async(
+        mut self,
+        f: impl Fn(&FieldValue, &mut WindowContext) -> Task<Result<(), SharedString>> + 'static,
+    ) -> Self {
+        self.async_validator = Some(Rc::new(f));
+        self
+    }
+
+    /// Run the required check and the sync validators.
+    fn validate_sync(&self, value: &FieldValue) -> Result<(), SharedString> {
+        if self.required && value.is_empty() {
+            return Err(t!("Form.This field is required").into());
+        }
+
+        self.validators
+            .iter()
+            .try_for_each(|validator| validator(value))
+    }
+}
+
+struct FieldState {
+    field: FormField,
+    initial: FieldValue,
+    /// The value is changed from the initial value.
+    dirty: bool,
+    /// The control has been blurred or the form has been submitted.
+    touched: bool,
+    error: Option<SharedString>,
+    /// The running async validation, the field can't be submitted until it's done.
+    validating: Option<Task<()>>,
+    /// Closed when the running validation is done or replaced, to wait for it without polling.
+    validated: Option<smol::channel::Receiver<()>>,
+}
+
+/// Events emitted by the [`Form`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum FormEvent {
+    /// The form is submitted with the valid values.
+    Submit(FormValues),
+}
+
+/// A form to lay out the fields consistently, and validate them before submit.
+///
+/// ```ignore
+/// cx.new_view(|cx| {
+///     let mut form = Form::new(cx).on_submit(|values, cx| {
+///         let user: User = values.parse().unwrap();
+///     });
+///     form.add_field(FormField::input("name", &name_input).label("Name").required(true), cx);
+///     form
+/// })
+/// ```
+pub struct Form {
+    fields: Vec<FieldState>,
+    submit_label: Option<SharedString>,
+    /// Waiting for the async validators to submit.
+    submitting: Option<Task<()>>,
+    on_submit: Option<Rc<dyn Fn(&FormValues, &mut WindowContext)>>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl Form {
+    pub fn new(_: &mut ViewContext<Self>) -> Self {
+        Self {
+            fields: vec![],
+            submit_label: None,
+            submitting: None,
+            on_submit: None,
+            _subscriptions: vec![],
+        }
+    }
+
+    /// Set the label of the submit button, defaults to "Submit".
+    pub fn submit_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.submit_label = Some(label.into());
+        self
+    }
+
+    /// Set the callback when the form is submitted with the valid values.
+    pub fn on_submit(mut self, f: impl Fn(&FormValues, &mut WindowContext) + 'static) -> Self {
+        self.on_submit = Some(Rc::new(f));
+        self
+    }
+
+    /// Add a field, the current value of it is the initial value to check dirty.
+    pub fn add_field(&mut self, field: FormField, cx: &mut ViewContext<Self>) {
+        if let Ok(input) = field.control.clone().downcast::<TextInput>() {
+            let name = field.name.clone();
+            self._subscriptions.push(cx.subscribe(
+                &input,
+                move |this, _, event: &InputEvent, cx| match event {
+                    InputEvent::Change(_) => this.on_change(&name, cx),
+                    InputEvent::Blur => this.touch(&name, cx),
+                    _ => {}
+                },
+            ));
+        }
+
+        let initial = (field.value)(cx);
+        self.fields.push(FieldState {
+            field,
+            initial,
+            dirty: false,
+            touched: false,
+            error: None,
+            validating: None,
+            validated: None,
+        });
+        cx.notify();
+    }
+
+    /// Return the current values of the fields.
+    pub fn values(&self, cx: &AppContext) -> FormValues {
+        FormValues(
+            self.fields
+                .iter()
+                .map(|state| (state.field.name.clone(), (state.field.value)(cx)))
+                .collect(),
+        )
+    }
+
+    /// Return true if any field is changed from the initial value.
+    pub fn is_dirty(&self) -> bool {
+        self.fields.iter().any(|state| state.dirty)
+    }
+
+    pub fn is_field_dirty(&self, name: &str) -> bool {
+        self.field(name).map_or(false, |state| state.dirty)
+    }
+
+    pub fn is_field_touched(&self, name: &str) -> bool {
+        self.field(name).map_or(false, |state| state.touched)
+    }
+
+    pub fn field_error(&self, name: &str) -> Option<&SharedString> {
+        self.field(name).and_then(|state| state.error.as_ref())
+    }
+
+    /// Return true if no field has an error, the untouched fields are not validated yet.
+    pub fn is_valid(&self) -> bool {
+        self.fields.iter().all(|state| state.error.is_none())
+    }
+
+    /// Return true if any async validator is running.
+    pub fn is_validating(&self) -> bool {
+        self.fields.iter().any(|state| state.validating.is_some())
+    }
+
+    /// Set the error of a field, e.g.: from a server side validation.
+    pub fn set_field_error(
+        &mut self,
+        name: &str,
+        error: Option<SharedString>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if let Some(ix) = self.field_ix(name) {
+            self.fields[ix].error = error;
+            cx.notify();
+        }
+    }
+
+    /// Mark the field as touched and validate it, call this when the control is blurred.
+    pub fn touch(&mut self, name: &str, cx: &mut ViewContext<Self>) {
+        if let Some(ix) = self.field_ix(name) {
+            self.fields[ix].touched = true;
+            self.validate_field(ix, cx);
+        }
+    }
+
+    /// Reset the dirty and touched state and the errors, the current values are the new initial values.
+    pub fn reset(&mut self, cx: &mut ViewContext<Self>) {
+        for state in self.fields.iter_mut() {
+            state.initial = (state.field.value)(cx);
+            state.dirty = false;
+            state.touched = false;
+            state.error = None;
+            state.validating = None;
+            state.validated = None;
+        }
+        self.submitting = None;
+        cx.notify();
+    }
+
+    /// Validate all the fields, and submit if they are valid after the async validators are done.
+    pub fn submit(&mut self, cx: &mut ViewContext<Self>) {
+        for ix in 0..self.fields.len() {
+            self.fields[ix].touched = true;
+            self.validate_field(ix, cx);
+        }
+
+        if !self.is_valid() {
+            return;
+        }
+
+        self.submitting = Some(cx.spawn(|this, mut cx| async move {
+            // Wait for the running async validators before submit,
+            // the fields may be validated again while waiting, so check until none is running.
+            while let Ok(pending) = this.update(&mut cx, |this, _| {
+                this.fields
+                    .iter()
+                    .filter_map(|state| state.validated.clone())
+                    .collect::<Vec<_>>()
+            }) {
+                if pending.is_empty() {
+                    break;
+                }
+                for validated in pending {
+                    _ = validated.recv().await;
+                }
+            }
+
+            _ = this.update(&mut cx, |this, cx| {
+                this.submitting = None;
+                if this.is_valid() {
+                    let values = this.values(cx);
+                    if let Some(on_submit) = this.on_submit.clone() {
+                        on_submit(&values, cx);
+                    }
+                    cx.emit(FormEvent::Submit(values));
+                }
+                cx.notify();
+            });
+        }));
+        cx.notify();
+    }
+
+    fn field(&self, name: &str) -> Option<&FieldState> {
+        self.fields
+            .iter()
+            .find(|state| state.field.name.as_ref() == name)
+    }
+
+    fn field_ix(&self, name: &str) -> Option<usize> {
+        self.fields
+            .iter()
+            .position(|state| state.field.name.as_ref() == name)
+    }
+
+    fn on_change(&mut self, name: &str, cx: &mut ViewContext<Self>) {
+        let Some(ix) = self.field_ix(name) else {
+            return;
+        };
+
+        let state = &mut self.fields[ix];
+        state.dirty = (state.field.value)(cx) != state.initial;
+        // Only show the errors after the field is touched, to avoid the errors when typing the first time.
+        if state.touched {
+            self.validate_field(ix, cx);
+        } else {
+            cx.notify();
+        }
+    }
+
+    fn validate_field(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        let state = &mut self.fields[ix];
+        let value = (state.field.value)(cx);
+        state.validating = None;
+        state.validated = None;
+        state.error = state.field.validate_sync(&value).err();
+
+        if let (None, Some(validator)) = (&state.error, state.field.async_validator.clone()) {
+            let task = validator(&value, cx);
+            let (done, validated) = smol::channel::bounded::<()>(1);
+            state.validated = Some(validated);
+            state.validating = Some(cx.spawn(|this, mut cx| async move {
+                // Dropped when the validation is done or cancelled, this closes the channel.
+                let _done = done;
+                let result = task.await;
+                _ = this.update(&mut cx, |this, cx| {
+                    if let Some(state) = this.fields.get_mut(ix) {
+                        state.error = result.err();
+                        state.validating = None;
+                        state.validated = None;
+                    }
+                    cx.notify();
+                });
+            }));
+        }
+        cx.notify();
+    }
+
+    fn render_field(&self, state: &FieldState, cx: &ViewContext<Self>) -> impl IntoElement {
+        let field = &state.field;
+
+        v_flex()
+            .gap_1p5()
+            .when_some(field.label.clone(), |this, label| {
+                this.child(
+                    h_flex()
+                        .gap_0p5()
+                        .text_sm()
+                        .font_medium()
+                        .child(label)
+                        .when(field.required, |this| {
+                            this.child(div().text_color(cx.theme().destructive).child("*"))
+                        }),
+                )
+            })
+            .child(field.control.clone())
+            .map(|this| match (&state.error, &field.help) {
+                (Some(error), _) => this.child(
+                    div()
+                        .text_xs()
+                        .text_color(cx.theme().destructive)
+                        .child(error.clone()),
+                ),
+                (None, Some(help)) => this.child(
+                    div()
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(help.clone()),
+                ),
+                (None, None) => this,
+            })
+    }
+}
+
+impl EventEmitter<FormEvent> for Form {}
+
+impl Render for Form {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let submit_label = self
+            .submit_label
+            .clone()
+            .unwrap_or_else(|| t!("Form.Submit").into());
+        let busy = self.submitting.is_some() || self.is_validating();
+
+        v_flex()
+            .gap_4()
+            .children(self.fields.iter().map(|state| self.render_field(state, cx)))
+            .child(
+                h_flex().justify_end().child(
+                    Button::new("submit")
+                        .primary()
+                        .label(submit_label)
+                        .loading(busy)
+                        // Block the submit while invalid, it's enabled again after the errors are fixed.
+                        .disabled(!self.is_valid())
+                        .on_click(cx.listener(|this, _, cx| this.submit(cx))),
+                ),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use serde::Deserialize;
+
+    use super::{FieldValue, FormValues};
+
+    #[test]
+    fn test_parse_values() {
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct User {
+            name: String,
+            age: f64,
+            subscribe: bool,
+            bio: Option<String>,
+        }
+
+        let values = FormValues(BTreeMap::from([
+            ("name".into(), FieldValue::Text("Jason".into())),
+            ("age".into(), FieldValue::Number(30.)),
+            ("subscribe".into(), FieldValue::Bool(true)),
+            ("bio".into(), FieldValue::None),
+        ]));
+
+        assert_eq!(
+            values.parse::<User>().unwrap(),
+            User {
+                name: "Jason".into(),
+                age: 30.,
+                subscribe: true,
+                bio: None,
+            }
+        );
+        assert!(FieldValue::Text(" ".into()).is_empty());
+        assert!(!FieldValue::Bool(false).is_empty());
+    }
+}
@@ -17,6 +17,7 @@ pub struct History<I: HistoryItem> {
     pub(crate) ignore: bool,
     max_undo: usize,
     group_interval: Option<Duration>,
+    group_break: bool,
 }
 
 impl<I> History<I>
@@ -32,6 +33,7 @@ where
             version: 0,
             max_undo: 1000,
             group_interval: None,
+            group_break: false,
         }
     }
 
@@ -50,14 +52,31 @@ where
     /// Increment the version number if the last change was made more than `GROUP_INTERVAL` milliseconds ago.
     fn inc_version(&mut self) -> usize {
         let t = Instant::now();
-        if Some(self.last_changed_at.elapsed()) > self.group_interval {
+        if self.group_break || Some(self.last_changed_at.elapsed()) > self.group_interval {
             self.version += 1;
         }
 
+        self.group_break = false;
         self.last_changed_at = t;
         self.version
     }
 
+    /// Break the current group, the next pushed item will start a new group
+    /// even if it is pushed within the group interval.
+    pub fn break_group(&mut self) {
+        self.group_break = true;
+    }
+
+    /// Returns true if there are changes to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undos.is_empty()
+    }
+
+    /// Returns true if there are changes to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redos.is_empty()
+    }
+
     /// Get the current version number.
     pub fn version(&self) -> usize {
         self.version
@@ -189,4 +208,29 @@ mod tests {
 
         assert_eq!(history.undo().is_none(), true);
     }
+
+    #[test]
+    fn test_history_group() {
+        let mut history: History<TabIndex> = History::new().group_interval(Duration::from_secs(60));
+        history.push(0.into());
+        history.push(1.into());
+        history.break_group();
+        history.push(2.into());
+        history.push(3.into());
+
+        assert_eq!(history.version(), 1);
+        assert!(history.can_undo());
+        assert!(!history.can_redo());
+
+        let changes = history.undo().unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].tab_index, 3);
+        assert_eq!(changes[1].tab_index, 2);
+        assert!(history.can_redo());
+
+        let changes = history.undo().unwrap();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].tab_index, 1);
+        assert!(!history.can_undo());
+    }
 }
@@ -21,6 +21,7 @@ pub enum IconName {
     ChevronsUpDown,
     CircleCheck,
     CircleX,
+    Clock,
     Close,
     Copy,
     Dash,
@@ -29,7 +30,11 @@ pub enum IconName {
     EllipsisVertical,
     Eye,
     EyeOff,
+    File,
+    Folder,
+    FolderOpen,
     GitHub,
+    GripVertical,
     Globe,
     Heart,
     HeartOff,
@@ -43,7 +48,12 @@ pub enum IconName {
     Minus,
     Moon,
     Palette,
+    PanelBottom,
+    PanelLeft,
+    PanelRight,
     Plus,
+    RotateCcw,
+    RotateCw,
     Search,
     SortAscending,
     SortDescending,
@@ -53,6 +63,9 @@ pub enum IconName {
     ThumbsDown,
     ThumbsUp,
     TriangleAlert,
+    User,
+    ZoomIn,
+    ZoomOut,
 }
 
 impl IconName {
@@ -73,6 +86,7 @@ impl IconName {
             IconName::ChevronsUpDown => "icons/chevrons-up-down.svg",
             IconName::CircleCheck => "icons/circle-check.svg",
             IconName::CircleX => "icons/circle-x.svg",
+            IconName::Clock => "icons/clock.svg",
             IconName::Close => "icons/close.svg",
             IconName::Copy => "icons/copy.svg",
             IconName::Dash => "icons/dash.svg",
@@ -81,7 +95,11 @@ impl IconName {
             IconName::EllipsisVertical => "icons/ellipsis-vertical.svg",
             IconName::Eye => "icons/eye.svg",
             IconName::EyeOff => "icons/eye-off.svg",
+            IconName::File => "icons/file.svg",
+            IconName::Folder => "icons/folder.svg",
+            IconName::FolderOpen => "icons/folder-open.svg",
             IconName::GitHub => "icons/github.svg",
+            IconName::GripVertical => "icons/grip-vertical.svg",
             IconName::Globe => "icons/globe.svg",
             IconName::Heart => "icons/heart.svg",
             IconName::HeartOff => "icons/heart-off.svg",
@@ -95,7 +113,12 @@ impl IconName {
             IconName::Minus => "icons/minus.svg",
             IconName::Moon => "icons/moon.svg",
             IconName::Palette => "icons/palette.svg",
+            IconName::PanelBottom => "icons/panel-bottom.svg",
+            IconName::PanelLeft => "icons/panel-left.svg",
+            IconName::PanelRight => "icons/panel-right.svg",
             IconName::Plus => "icons/plus.svg",
+            IconName::RotateCcw => "icons/rotate-ccw.svg",
+            IconName::RotateCw => "icons/rotate-cw.svg",
             IconName::Search => "icons/search.svg",
             IconName::SortAscending => "icons/sort-ascending.svg",
             IconName::SortDescending => "icons/sort-descending.svg",
@@ -105,6 +128,9 @@ impl IconName {
             IconName::ThumbsDown => "icons/thumbs-down.svg",
             IconName::ThumbsUp => "icons/thumbs-up.svg",
             IconName::TriangleAlert => "icons/triangle-alert.svg",
+            IconName::User => "icons/user.svg",
+            IconName::ZoomIn => "icons/zoom-in.svg",
+            IconName::ZoomOut => "icons/zoom-out.svg",
         }
         .into()
     }
@@ -0,0 +1,601 @@
+use std::{
+    hash::{Hash, Hasher},
+    path::PathBuf,
+    sync::Arc,
+};
+
+use gpui::{
+    actions, anchored, deferred, div, img, point, prelude::FluentBuilder as _, px, AppContext,
+    Asset, CursorStyle, FocusHandle, FocusableView, ImageCacheError, ImageSource,
+    InteractiveElement as _, IntoElement, KeyBinding, MouseButton, MouseDownEvent, MouseMoveEvent,
+    MouseUpEvent, ObjectFit, ParentElement as _, Pixels, Point, Render, RenderImage,
+    ScrollWheelEvent, StatefulInteractiveElement as _, Styled as _, ViewContext, WindowContext,
+};
+use image::{imageops, Frame, RgbaImage};
+use smallvec::SmallVec;
+
+use crate::{
+    button::{Button, ButtonStyled as _},
+    h_flex,
+    theme::ActiveTheme as _,
+    Disableable as _, IconName, Sizable as _,
+};
+
+actions!(
+    image_viewer,
+    [
+        Close,
+        Prev,
+        Next,
+        ZoomIn,
+        ZoomOut,
+        ResetZoom,
+        ToggleFit,
+        RotateLeft,
+        RotateRight
+    ]
+);
+
+const CONTEXT: &str = "ImageViewer";
+const MIN_ZOOM: f32 = 0.25;
+const MAX_ZOOM: f32 = 8.;
+/// The zoom factor of each step of the zoom buttons and the keys.
+const ZOOM_STEP: f32 = 1.25;
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("escape", Close, Some(CONTEXT)),
+        KeyBinding::new("left", Prev, Some(CONTEXT)),
+        KeyBinding::new("right", Next, Some(CONTEXT)),
+        KeyBinding::new("=", ZoomIn, Some(CONTEXT)),
+        KeyBinding::new("-", ZoomOut, Some(CONTEXT)),
+        KeyBinding::new("0", ResetZoom, Some(CONTEXT)),
+        KeyBinding::new("f", ToggleFit, Some(CONTEXT)),
+        KeyBinding::new("r", RotateRight, Some(CONTEXT)),
+        KeyBinding::new("shift-r", RotateLeft, Some(CONTEXT)),
+    ]);
+}
+
+/// Return the zoom multiplied by the factor, in the range of [`MIN_ZOOM`] and [`MAX_ZOOM`].
+fn zoomed(zoom: f32, factor: f32) -> f32 {
+    (zoom * factor).clamp(MIN_ZOOM, MAX_ZOOM)
+}
+
+/// Return the pan after zooming by the factor, to keep the point at the `anchor` still,
+/// the `anchor` and the pan are relative to the center of the viewer.
+fn zoomed_pan(pan: Point<Pixels>, anchor: Point<Pixels>, factor: f32) -> Point<Pixels> {
+    anchor - (anchor - pan).map(|v| v * factor)
+}
+
+/// The decoded image to rotate, only the local files and the rendered images can be rotated.
+#[derive(Clone)]
+enum RotateFrom {
+    File(Arc<PathBuf>),
+    Render(Arc<RenderImage>),
+}
+
+impl RotateFrom {
+    fn new(image: &ImageSource) -> Option<Self> {
+        match image {
+            ImageSource::File(path) => Some(Self::File(path.clone())),
+            ImageSource::Render(image) => Some(Self::Render(image.clone())),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct RotatedSource {
+    image: RotateFrom,
+    /// The quarter turns clockwise.
+    turns: u8,
+}
+
+impl Hash for RotatedSource {
+    /// Hash to control the Asset cache.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match &self.image {
+            RotateFrom::File(path) => path.hash(state),
+            RotateFrom::Render(image) => image.id.hash(state),
+        }
+        self.turns.hash(state);
+    }
+}
+
+/// The image rotated by rotating the decoded data, cached by the [`Asset`].
+enum RotatedImage {}
+
+impl Asset for RotatedImage {
+    type Source = RotatedSource;
+    type Output = Result<Arc<RenderImage>, ImageCacheError>;
+
+    fn load(
+        source: Self::Source,
+        _: &mut AppContext,
+    ) -> impl std::future::Future<Output = Self::Output> + Send + 'static {
+        async move {
+            let buffer = match source.image {
+                RotateFrom::File(path) => {
+                    let bytes = std::fs::read(path.as_ref())
+                        .map_err(|e| ImageCacheError::Io(Arc::new(e)))?;
+                    let mut buffer = image::load_from_memory(&bytes)?.into_rgba8();
+                    // Convert from RGBA to BGRA.
+                    for pixel in buffer.chunks_exact_mut(4) {
+                        pixel.swap(0, 2);
+                    }
+                    buffer
+                }
+                RotateFrom::Render(image) => {
+                    let size = image.size(0);
+                    image
+                        .as_bytes(0)
+                        .and_then(|bytes| {
+                            RgbaImage::from_raw(
+                                size.width.0 as u32,
+                                size.height.0 as u32,
+                                bytes.to_vec(),
+                            )
+                        })
+                        .ok_or_else(|| {
+                            ImageCacheError::Io(Arc::new(std::io::Error::other(
+                                "invalid image buffer",
+                            )))
+                        })?
+                }
+            };
+
+            let buffer = match source.turns % 4 {
+                1 => imageops::rotate90(&buffer),
+                2 => imageops::rotate180(&buffer),
+                3 => imageops::rotate270(&buffer),
+                _ => buffer,
+            };
+
+            Ok(Arc::new(RenderImage::new(SmallVec::from_elem(
+                Frame::new(buffer),
+                1,
+            ))))
+        }
+    }
+}
+
+/// How the image is fitted into the viewer at 100% zoom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFit {
+    /// Show the whole image.
+    Fit,
+    /// Fill the viewer, the overflowed part is cropped.
+    Fill,
+}
+
+/// A full window overlay to view a set of images, with the zoom, pan and the navigation.
+///
+/// The thumbnails are rendered in place, click one to open the overlay.
+/// Scroll or pinch to zoom at the cursor, and drag to pan the image.
+pub struct ImageViewer {
+    focus_handle: FocusHandle,
+    images: Vec<ImageSource>,
+    thumbnails: bool,
+    thumbnail_size: Pixels,
+    open: bool,
+    current: usize,
+    zoom: f32,
+    fit: ImageFit,
+    pan: Point<Pixels>,
+    /// The quarter turns clockwise of the current image.
+    rotation: u8,
+    /// The mouse position and the pan when the drag started.
+    drag: Option<(Point<Pixels>, Point<Pixels>)>,
+}
+
+impl ImageViewer {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            images: Vec::new(),
+            thumbnails: true,
+            thumbnail_size: px(80.),
+            open: false,
+            current: 0,
+            zoom: 1.,
+            fit: ImageFit::Fit,
+            pan: Point::default(),
+            rotation: 0,
+            drag: None,
+        }
+    }
+
+    pub fn images(mut self, images: impl IntoIterator<Item = impl Into<ImageSource>>) -> Self {
+        self.images = images.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Set false to not render the thumbnails, and open the viewer by [`ImageViewer::open`].
+    pub fn thumbnails(mut self, thumbnails: bool) -> Self {
+        self.thumbnails = thumbnails;
+        self
+    }
+
+    /// Set the size of the thumbnails, default: 80px.
+    pub fn thumbnail_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.thumbnail_size = size.into();
+        self
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Open the viewer with the image at `ix`.
+    pub fn open(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if self.images.is_empty() {
+            return;
+        }
+
+        self.open = true;
+        self.show(ix.min(self.images.len() - 1), cx);
+        cx.focus(&self.focus_handle);
+    }
+
+    pub fn close(&mut self, cx: &mut ViewContext<Self>) {
+        self.open = false;
+        self.drag = None;
+        cx.notify();
+    }
+
+    /// Show the image at `ix` with the zoom and the rotation reset.
+    fn show(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if ix != self.current {
+            self.rotation = 0;
+        }
+        self.current = ix;
+        self.zoom = 1.;
+        self.pan = Point::default();
+        cx.notify();
+    }
+
+    fn set_zoom(&mut self, zoom: f32, cx: &mut ViewContext<Self>) {
+        // Keep the same point at the center.
+        self.set_zoom_at(zoom, Point::default(), cx);
+    }
+
+    /// Zoom and keep the point at the `anchor` still, the `anchor` is relative to the center of the viewer.
+    fn set_zoom_at(&mut self, zoom: f32, anchor: Point<Pixels>, cx: &mut ViewContext<Self>) {
+        self.pan = zoomed_pan(self.pan, anchor, zoom / self.zoom);
+        self.zoom = zoom;
+        cx.notify();
+    }
+
+    fn can_rotate(&self) -> bool {
+        self.images
+            .get(self.current)
+            .map_or(false, |image| RotateFrom::new(image).is_some())
+    }
+
+    fn rotate(&mut self, clockwise: bool, cx: &mut ViewContext<Self>) {
+        if !self.can_rotate() {
+            return;
+        }
+
+        self.rotation = (self.rotation + if clockwise { 1 } else { 3 }) % 4;
+        self.pan = Point::default();
+        cx.notify();
+    }
+
+    /// Return the current image rotated, or the original image while the rotated one is loading.
+    fn current_image(&self, cx: &mut WindowContext) -> Option<ImageSource> {
+        let image = self.images.get(self.current)?;
+        if self.rotation == 0 {
+            return Some(image.clone());
+        }
+
+        let rotated = RotateFrom::new(image).and_then(|from| {
+            cx.use_asset::<RotatedImage>(&RotatedSource {
+                image: from,
+                turns: self.rotation,
+            })
+        });
+
+        match rotated {
+            Some(Ok(rotated)) => Some(ImageSource::Render(rotated)),
+            _ => Some(image.clone()),
+        }
+    }
+
+    fn on_close(&mut self, _: &Close, cx: &mut ViewContext<Self>) {
+        self.close(cx);
+    }
+
+    fn prev(&mut self, _: &Prev, cx: &mut ViewContext<Self>) {
+        if self.current > 0 {
+            self.show(self.current - 1, cx);
+        }
+    }
+
+    fn next(&mut self, _: &Next, cx: &mut ViewContext<Self>) {
+        if self.current + 1 < self.images.len() {
+            self.show(self.current + 1, cx);
+        }
+    }
+
+    fn zoom_in(&mut self, _: &ZoomIn, cx: &mut ViewContext<Self>) {
+        self.set_zoom(zoomed(self.zoom, ZOOM_STEP), cx);
+    }
+
+    fn zoom_out(&mut self, _: &ZoomOut, cx: &mut ViewContext<Self>) {
+        self.set_zoom(zoomed(self.zoom, 1. / ZOOM_STEP), cx);
+    }
+
+    fn reset_zoom(&mut self, _: &ResetZoom, cx: &mut ViewContext<Self>) {
+        self.show(self.current, cx);
+    }
+
+    fn rotate_left(&mut self, _: &RotateLeft, cx: &mut ViewContext<Self>) {
+        self.rotate(false, cx);
+    }
+
+    fn rotate_right(&mut self, _: &RotateRight, cx: &mut ViewContext<Self>) {
+        self.rotate(true, cx);
+    }
+
+    fn toggle_fit(&mut self, _: &ToggleFit, cx: &mut ViewContext<Self>) {
+        self.fit = match self.fit {
+            ImageFit::Fit => ImageFit::Fill,
+            ImageFit::Fill => ImageFit::Fit,
+        };
+        self.show(self.current, cx);
+    }
+
+    fn on_scroll_wheel(&mut self, event: &ScrollWheelEvent, cx: &mut ViewContext<Self>) {
+        let delta = event.delta.pixel_delta(px(16.)).y;
+        let view_size = cx.viewport_size();
+        let anchor = event.position - point(view_size.width / 2., view_size.height / 2.);
+
+        // The pinch on the touchpad is sent as the scroll with the ctrl key,
+        // zoom by the distance of the fingers instead of the fixed steps.
+        let factor = if event.modifiers.control || event.modifiers.platform {
+            (1. + delta.0 / 100.).clamp(0.5, 2.)
+        } else if delta > px(0.) {
+            1.1
+        } else if delta < px(0.) {
+            1. / 1.1
+        } else {
+            return;
+        };
+
+        self.set_zoom_at(zoomed(self.zoom, factor), anchor, cx);
+    }
+
+    fn on_mouse_down(&mut self, event: &MouseDownEvent, _: &mut ViewContext<Self>) {
+        self.drag = Some((event.position, self.pan));
+    }
+
+    fn on_mouse_move(&mut self, event: &MouseMoveEvent, cx: &mut ViewContext<Self>) {
+        if let Some((start, pan)) = self.drag {
+            self.pan = pan + (event.position - start);
+            cx.notify();
+        }
+    }
+
+    fn on_mouse_up(&mut self, _: &MouseUpEvent, _: &mut ViewContext<Self>) {
+        self.drag = None;
+    }
+
+    fn render_thumbnails(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        h_flex()
+            .flex_wrap()
+            .gap_2()
+            .children(self.images.iter().enumerate().map(|(ix, image)| {
+                div()
+                    .id(("thumbnail", ix))
+                    .size(self.thumbnail_size)
+                    .rounded_md()
+                    .overflow_hidden()
+                    .cursor_pointer()
+                    .child(img(image.clone()).size_full().object_fit(ObjectFit::Cover))
+                    .on_click(cx.listener(move |this, _, cx| this.open(ix, cx)))
+            }))
+    }
+
+    fn render_toolbar(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let count = self.images.len();
+
+        h_flex()
+            .gap_1()
+            .px_2()
+            .py_1()
+            .rounded_full()
+            .bg(cx.theme().translucent(crate::black(), 0.6))
+            .text_sm()
+            .text_color(crate::white())
+            .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+            .child(
+                Button::new("prev")
+                    .icon(IconName::ChevronLeft)
+                    .ghost()
+                    .small()
+                    .disabled(self.current == 0)
+                    .on_click(cx.listener(|this, _, cx| this.prev(&Prev, cx))),
+            )
+            .child(format!("{} / {}", self.current + 1, count))
+            .child(
+                Button::new("next")
+                    .icon(IconName::ChevronRight)
+                    .ghost()
+                    .small()
+                    .disabled(self.current + 1 >= count)
+                    .on_click(cx.listener(|this, _, cx| this.next(&Next, cx))),
+            )
+            .child(
+                Button::new("zoom-out")
+                    .icon(IconName::ZoomOut)
+                    .ghost()
+                    .small()
+                    .disabled(self.zoom <= MIN_ZOOM)
+                    .on_click(cx.listener(|this, _, cx| this.zoom_out(&ZoomOut, cx))),
+            )
+            .child(
+                div()
+                    .id("zoom")
+                    .min_w(px(44.))
+                    .text_center()
+                    .cursor_pointer()
+                    .child(format!("{}%", (self.zoom * 100.).round()))
+                    .on_click(cx.listener(|this, _, cx| this.reset_zoom(&ResetZoom, cx))),
+            )
+            .child(
+                Button::new("zoom-in")
+                    .icon(IconName::ZoomIn)
+                    .ghost()
+                    .small()
+                    .disabled(self.zoom >= MAX_ZOOM)
+                    .on_click(cx.listener(|this, _, cx| this.zoom_in(&ZoomIn, cx))),
+            )
+            .child(
+                Button::new("rotate-left")
+                    .icon(IconName::RotateCcw)
+                    .ghost()
+                    .small()
+                    .disabled(!self.can_rotate())
+                    .on_click(cx.listener(|this, _, cx| this.rotate_left(&RotateLeft, cx))),
+            )
+            .child(
+                Button::new("rotate-right")
+                    .icon(IconName::RotateCw)
+                    .ghost()
+                    .small()
+                    .disabled(!self.can_rotate())
+                    .on_click(cx.listener(|this, _, cx| this.rotate_right(&RotateRight, cx))),
+            )
+            .child(
+                Button::new("fit")
+                    .map(|this| match self.fit {
+                        ImageFit::Fit => this.icon(IconName::Maximize),
+                        ImageFit::Fill => this.icon(IconName::Minimize),
+                    })
+                    .ghost()
+                    .small()
+                    .on_click(cx.listener(|this, _, cx| this.toggle_fit(&ToggleFit, cx))),
+            )
+    }
+
+    fn render_overlay(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view_size = cx.viewport_size();
+        let image_size = view_size.map(|v| v * self.zoom);
+        let left = (view_size.width - image_size.width) / 2. + self.pan.x;
+        let top = (view_size.height - image_size.height) / 2. + self.pan.y;
+        let image = self.current_image(cx);
+
+        deferred(
+            anchored().snap_to_window().child(
+                div()
+                    .id("image-viewer")
+                    .key_context(CONTEXT)
+                    .track_focus(&self.focus_handle)
+                    .on_action(cx.listener(Self::on_close))
+                    .on_action(cx.listener(Self::prev))
+                    .on_action(cx.listener(Self::next))
+                    .on_action(cx.listener(Self::zoom_in))
+                    .on_action(cx.listener(Self::zoom_out))
+                    .on_action(cx.listener(Self::reset_zoom))
+                    .on_action(cx.listener(Self::toggle_fit))
+                    .on_action(cx.listener(Self::rotate_left))
+                    .on_action(cx.listener(Self::rotate_right))
+                    .on_scroll_wheel(cx.listener(Self::on_scroll_wheel))
+                    .on_mouse_down(MouseButton::Left, cx.listener(Self::on_mouse_down))
+                    .on_mouse_move(cx.listener(Self::on_mouse_move))
+                    .on_mouse_up(MouseButton::Left, cx.listener(Self::on_mouse_up))
+                    .on_mouse_up_out(MouseButton::Left, cx.listener(Self::on_mouse_up))
+                    .occlude()
+                    .relative()
+                    .w(view_size.width)
+                    .h(view_size.height)
+                    .overflow_hidden()
+                    .bg(cx.theme().translucent(crate::black(), 0.9))
+                    .when(self.drag.is_some(), |this| {
+                        this.cursor(CursorStyle::ClosedHand)
+                    })
+                    .when_some(image, |this, image| {
+                        this.child(
+                            img(image)
+                                .absolute()
+                                .left(left)
+                                .top(top)
+                                .w(image_size.width)
+                                .h(image_size.height)
+                                .object_fit(match self.fit {
+                                    ImageFit::Fit => ObjectFit::Contain,
+                                    ImageFit::Fill => ObjectFit::Cover,
+                                }),
+                        )
+                    })
+                    .child(
+                        Button::new("close")
+                            .icon(IconName::Close)
+                            .ghost()
+                            .absolute()
+                            .top_3()
+                            .right_3()
+                            .on_click(cx.listener(|this, _, cx| this.close(cx))),
+                    )
+                    .child(
+                        h_flex()
+                            .absolute()
+                            .bottom_6()
+                            .w_full()
+                            .justify_center()
+                            .child(self.render_toolbar(cx)),
+                    ),
+            ),
+        )
+        .with_priority(1)
+    }
+}
+
+impl FocusableView for ImageViewer {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for ImageViewer {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        div()
+            .when(self.thumbnails, |this| {
+                this.child(self.render_thumbnails(cx))
+            })
+            .when(self.open, |this| this.child(self.render_overlay(cx)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{point, px, Point};
+
+    use super::{zoomed, zoomed_pan, MAX_ZOOM, MIN_ZOOM};
+
+    #[test]
+    fn test_zoomed() {
+        assert_eq!(zoomed(1., 2.), 2.);
+        assert_eq!(zoomed(4., 4.), MAX_ZOOM);
+        assert_eq!(zoomed(0.5, 0.1), MIN_ZOOM);
+    }
+
+    #[test]
+    fn test_zoomed_pan() {
+        // Zoom at the center.
+        assert_eq!(
+            zoomed_pan(point(px(10.), px(-20.)), Point::default(), 2.),
+            point(px(20.), px(-40.))
+        );
+        // The point under the cursor stays still.
+        assert_eq!(
+            zoomed_pan(Point::default(), point(px(100.), px(50.)), 2.),
+            point(px(-100.), px(-50.))
+        );
+        assert_eq!(
+            zoomed_pan(point(px(-100.), px(-50.)), point(px(100.), px(50.)), 0.5),
+            Point::default()
+        );
+    }
+}
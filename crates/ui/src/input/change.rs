@@ -28,6 +28,34 @@ impl Change {
     }
 }
 
+/// The kind of a change, used to decide where to break the undo groups.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ChangeKind {
+    Insert,
+    Whitespace,
+    Delete,
+}
+
+impl ChangeKind {
+    pub(crate) fn new(new_text: &str) -> Self {
+        if new_text.is_empty() {
+            Self::Delete
+        } else if new_text.chars().all(char::is_whitespace) {
+            Self::Whitespace
+        } else {
+            Self::Insert
+        }
+    }
+
+    /// Returns true if a change of this kind can be grouped after a change of the `prev` kind.
+    ///
+    /// The trailing whitespace is grouped with the word before it, so that undo removes a word
+    /// with its following space.
+    pub(crate) fn can_group_after(&self, prev: ChangeKind) -> bool {
+        *self == prev || (prev == Self::Insert && *self == Self::Whitespace)
+    }
+}
+
 impl HistoryItem for Change {
     fn version(&self) -> usize {
         self.version
@@ -3,27 +3,38 @@
 //! Based on the `Input` example from the `gpui` crate.
 //! https://github.com/zed-industries/zed/blob/main/crates/gpui/examples/input.rs
 
+use std::cell::Cell;
 use std::ops::Range;
+use std::rc::Rc;
 
 use super::blink_cursor::BlinkCursor;
-use super::change::Change;
+use super::change::{Change, ChangeKind};
+use super::trigger::{shift_tokens, trigger_at, ActiveTrigger, Trigger};
 use super::ClearButton;
+use super::MaskPattern;
 use crate::history::History;
 use crate::indicator::Indicator;
-use crate::theme::ActiveTheme;
+use crate::list::ListItem;
+use crate::scroll::{Scrollbar, ScrollbarState};
+use crate::theme::{ActiveTheme, ComponentTheme, Theme};
 use crate::StyledExt as _;
+use crate::{
+    button::{Button, ButtonStyled as _},
+    v_flex, IconName, Sizable, StyleSized,
+};
 use crate::{event::InteractiveElementExt as _, Size};
-use crate::{Sizable, StyleSized};
 use gpui::prelude::FluentBuilder as _;
 use gpui::{
-    actions, div, fill, point, px, relative, rems, size, AnyElement, AppContext, Bounds,
-    ClickEvent, ClipboardItem, Context as _, Element, ElementId, ElementInputHandler, EventEmitter,
-    FocusHandle, FocusableView, GlobalElementId, InteractiveElement as _, IntoElement, KeyBinding,
-    KeyDownEvent, LayoutId, Model, MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent,
-    PaintQuad, ParentElement as _, Pixels, Point, Render, ShapedLine, SharedString, Style,
-    Styled as _, TextRun, UTF16Selection, UnderlineStyle, View, ViewContext, ViewInputHandler,
-    WindowContext,
+    actions, anchored, deferred, div, fill, point, px, relative, rems, size, AnyElement,
+    AppContext, AvailableSpace, Bounds, ClickEvent, ClipboardItem, Context as _, Element,
+    ElementId, ElementInputHandler, EventEmitter, FocusHandle, FocusableView, GlobalElementId,
+    Hsla, InteractiveElement as _, IntoElement, KeyBinding, KeyDownEvent, LayoutId, Model,
+    MouseButton, MouseDownEvent, MouseMoveEvent, MouseUpEvent, PaintQuad, ParentElement as _,
+    Pixels, Point, Render, ScrollHandle, ShapedLine, SharedString, StatefulInteractiveElement as _,
+    Style, Styled as _, TextRun, UTF16Selection, UnderlineStyle, View, ViewContext,
+    ViewInputHandler, WindowContext, WrappedLine,
 };
+use smallvec::SmallVec;
 use unicode_segmentation::*;
 
 actions!(
@@ -32,10 +43,16 @@ actions!(
         Backspace,
         Delete,
         Enter,
+        Escape,
+        Newline,
         Left,
         Right,
+        Up,
+        Down,
         SelectLeft,
         SelectRight,
+        SelectUp,
+        SelectDown,
         SelectAll,
         Home,
         End,
@@ -49,34 +66,82 @@ actions!(
         Redo,
         MoveToStartOfLine,
         MoveToEndOfLine,
+        MoveToPreviousWord,
+        MoveToNextWord,
+        SelectToPreviousWord,
+        SelectToNextWord,
+        DeleteToPreviousWord,
+        DeleteToNextWord,
         TextChanged,
     ]
 );
 
 #[derive(Clone)]
 pub enum InputEvent {
+    /// The text has been changed, by typing, pasting, cutting or undo/redo.
     Change(SharedString),
+    /// The Enter key was pressed, use this to submit the input.
     PressEnter,
     Focus,
     Blur,
 }
 
 const CONTEXT: &str = "Input";
+/// The character to display instead of the text in masked mode.
+const MASK_CHAR: char = '•';
 
 pub fn init(cx: &mut AppContext) {
     cx.bind_keys([
         KeyBinding::new("backspace", Backspace, Some(CONTEXT)),
         KeyBinding::new("delete", Delete, Some(CONTEXT)),
         KeyBinding::new("enter", Enter, Some(CONTEXT)),
+        KeyBinding::new("escape", Escape, Some(CONTEXT)),
+        KeyBinding::new("shift-enter", Newline, Some(CONTEXT)),
         KeyBinding::new("left", Left, Some(CONTEXT)),
         KeyBinding::new("right", Right, Some(CONTEXT)),
+        KeyBinding::new("up", Up, Some(CONTEXT)),
+        KeyBinding::new("down", Down, Some(CONTEXT)),
         KeyBinding::new("shift-left", SelectLeft, Some(CONTEXT)),
         KeyBinding::new("shift-right", SelectRight, Some(CONTEXT)),
+        KeyBinding::new("shift-up", SelectUp, Some(CONTEXT)),
+        KeyBinding::new("shift-down", SelectDown, Some(CONTEXT)),
         KeyBinding::new("home", Home, Some(CONTEXT)),
         KeyBinding::new("end", End, Some(CONTEXT)),
         KeyBinding::new("shift-home", SelectToHome, Some(CONTEXT)),
         KeyBinding::new("shift-end", SelectToEnd, Some(CONTEXT)),
         #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-left", MoveToStartOfLine, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-right", MoveToEndOfLine, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-shift-left", SelectToHome, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("cmd-shift-right", SelectToEnd, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("alt-left", MoveToPreviousWord, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("alt-right", MoveToNextWord, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("alt-shift-left", SelectToPreviousWord, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("alt-shift-right", SelectToNextWord, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("alt-backspace", DeleteToPreviousWord, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
+        KeyBinding::new("alt-delete", DeleteToNextWord, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-left", MoveToPreviousWord, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-right", MoveToNextWord, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-shift-left", SelectToPreviousWord, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-shift-right", SelectToNextWord, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-backspace", DeleteToPreviousWord, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-delete", DeleteToNextWord, Some(CONTEXT)),
+        #[cfg(target_os = "macos")]
         KeyBinding::new("ctrl-cmd-space", ShowCharacterPalette, Some(CONTEXT)),
         #[cfg(target_os = "macos")]
         KeyBinding::new("cmd-a", SelectAll, Some(CONTEXT)),
@@ -106,13 +171,42 @@ pub fn init(cx: &mut AppContext) {
         KeyBinding::new("ctrl-z", Undo, Some(CONTEXT)),
         #[cfg(not(target_os = "macos"))]
         KeyBinding::new("ctrl-y", Redo, Some(CONTEXT)),
+        #[cfg(not(target_os = "macos"))]
+        KeyBinding::new("ctrl-shift-z", Redo, Some(CONTEXT)),
     ]);
 }
 
+/// The style tokens of the [`TextInput`], override them by [`Theme::override_component`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InputTheme {
+    pub background: Hsla,
+    pub disabled_background: Hsla,
+    pub border: Hsla,
+    /// The border color when the input has an error.
+    pub error_border: Hsla,
+    pub radius: Pixels,
+    pub shadow: bool,
+}
+
+impl ComponentTheme for InputTheme {
+    fn from_theme(theme: &Theme) -> Self {
+        Self {
+            background: theme.background,
+            disabled_background: theme.muted,
+            border: theme.input,
+            error_border: theme.destructive,
+            radius: px(theme.radius),
+            shadow: theme.shadow,
+        }
+    }
+}
+
 pub struct TextInput {
     focus_handle: FocusHandle,
     text: SharedString,
     history: History<Change>,
+    /// The end offset and the kind of the last change, used to group the undo history.
+    last_change: Option<(usize, ChangeKind)>,
     blink_cursor: Model<BlinkCursor>,
     prefix: Option<Box<dyn Fn(&mut ViewContext<Self>) -> AnyElement + 'static>>,
     suffix: Option<Box<dyn Fn(&mut ViewContext<Self>) -> AnyElement + 'static>>,
@@ -122,16 +216,33 @@ pub struct TextInput {
     selection_reversed: bool,
     marked_range: Option<Range<usize>>,
     last_layout: Option<ShapedLine>,
+    /// The wrapped lines of the last paint, only used in multi-line mode.
+    last_lines: Option<SmallVec<[WrappedLine; 1]>>,
+    last_line_height: Pixels,
     last_bounds: Option<Bounds<Pixels>>,
+    last_cursor_offset: Option<usize>,
     scroll_offset: Point<Pixels>,
+    scroll_handle: ScrollHandle,
+    scrollbar_state: Rc<Cell<ScrollbarState>>,
+    multi_line: bool,
+    rows: usize,
+    submit_on_enter: bool,
     is_selecting: bool,
     disabled: bool,
     masked: bool,
+    password: bool,
     appearance: bool,
     cleanable: bool,
     size: Size,
     pattern: Option<regex::Regex>,
     validate: Option<Box<dyn Fn(&str) -> bool + 'static>>,
+    validator: Option<Box<dyn Fn(&str) -> Result<(), SharedString> + 'static>>,
+    mask: Option<MaskPattern>,
+    error: Option<SharedString>,
+    triggers: Vec<Trigger>,
+    active_trigger: Option<ActiveTrigger>,
+    /// The ranges of the tokens inserted by the triggers.
+    tokens: Vec<Range<usize>>,
 }
 
 impl EventEmitter<InputEvent> for TextInput {}
@@ -146,16 +257,26 @@ impl TextInput {
             text: "".into(),
             blink_cursor,
             history,
+            last_change: None,
             placeholder: "".into(),
             selected_range: 0..0,
             selection_reversed: false,
             marked_range: None,
             last_layout: None,
+            last_lines: None,
+            last_line_height: px(20.),
             last_bounds: None,
+            last_cursor_offset: None,
             scroll_offset: point(px(0.), px(0.)),
+            scroll_handle: ScrollHandle::new(),
+            scrollbar_state: Rc::new(Cell::new(ScrollbarState::new())),
+            multi_line: false,
+            rows: 2,
+            submit_on_enter: false,
             is_selecting: false,
             disabled: false,
             masked: false,
+            password: false,
             appearance: true,
             cleanable: false,
             loading: false,
@@ -164,6 +285,12 @@ impl TextInput {
             size: Size::Medium,
             pattern: None,
             validate: None,
+            validator: None,
+            mask: None,
+            error: None,
+            triggers: vec![],
+            active_trigger: None,
+            tokens: vec![],
         };
 
         // Observe the blink cursor to repaint the view when it changes.
@@ -215,6 +342,16 @@ impl TextInput {
         cx.notify();
     }
 
+    /// Use password mode, the text will be masked and a toggle button is displayed
+    /// to show or hide the text.
+    ///
+    /// The text can not be copied or cut in masked mode.
+    pub fn password(mut self) -> Self {
+        self.masked = true;
+        self.password = true;
+        self
+    }
+
     /// Set the prefix element of the input field.
     pub fn set_prefix<F, E>(&mut self, builder: F, cx: &mut ViewContext<Self>)
     where
@@ -241,6 +378,26 @@ impl TextInput {
         cx.notify();
     }
 
+    /// Use multi-line mode, the text will be soft wrapped and scrolled vertically,
+    /// and the Enter key will insert a new line.
+    pub fn multi_line(mut self) -> Self {
+        self.multi_line = true;
+        self
+    }
+
+    /// Set the number of visible rows in multi-line mode, default is 2.
+    pub fn rows(mut self, rows: usize) -> Self {
+        self.rows = rows.max(1);
+        self
+    }
+
+    /// Set true to emit [`InputEvent::PressEnter`] on Enter in multi-line mode,
+    /// and insert a new line with Shift+Enter, for chat-style inputs.
+    pub fn submit_on_enter(mut self, submit_on_enter: bool) -> Self {
+        self.submit_on_enter = submit_on_enter;
+        self
+    }
+
     /// Set the appearance of the input field.
     pub fn appearance(mut self, appearance: bool) -> Self {
         self.appearance = appearance;
@@ -290,12 +447,89 @@ impl TextInput {
         self
     }
 
+    /// Set the mask of the input field to format the text as the user types,
+    /// e.g.: `MaskPattern::new("(999) 999-9999")`.
+    ///
+    /// Use [`TextInput::raw_text`] to get the text without the mask literals.
+    pub fn mask(mut self, mask: impl Into<MaskPattern>) -> Self {
+        self.mask = Some(mask.into());
+        self
+    }
+
     /// Set the validation function of the input field.
     pub fn validate(mut self, f: impl Fn(&str) -> bool + 'static) -> Self {
         self.validate = Some(Box::new(f));
         self
     }
 
+    /// Set the validator of the input field, it will be called on every change,
+    /// and the returned error will be displayed below the input field.
+    ///
+    /// Unlike [`TextInput::validate`], the invalid text is still accepted.
+    pub fn validator(mut self, f: impl Fn(&str) -> Result<(), SharedString> + 'static) -> Self {
+        self.validator = Some(Box::new(f));
+        self
+    }
+
+    /// Set the error message of the input field, for example from a server side validation.
+    ///
+    /// The error will be replaced by the validator result on the next change.
+    pub fn set_error(&mut self, error: Option<SharedString>, cx: &mut ViewContext<Self>) {
+        self.error = error;
+        cx.notify();
+    }
+
+    /// Return the error message of the input field.
+    pub fn error(&self) -> Option<&SharedString> {
+        self.error.as_ref()
+    }
+
+    /// Return true if the input field has no error.
+    pub fn is_valid(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Run the validator with the current text, returns true if the text is valid.
+    pub fn validate_text(&mut self, cx: &mut ViewContext<Self>) -> bool {
+        if let Some(validator) = &self.validator {
+            self.error = validator(&self.text).err();
+            cx.notify();
+        }
+
+        self.is_valid()
+    }
+
+    /// Add a trigger character, e.g.: `@` for mentions or `/` for commands.
+    ///
+    /// When the user types the character at the start of a word, a suggestion menu is opened
+    /// at the caret with the candidates returned by the `provider` for the typed query,
+    /// the selected candidate is inserted as a styled token.
+    pub fn trigger(
+        mut self,
+        char: char,
+        provider: impl Fn(&str, &mut WindowContext) -> Vec<SharedString> + 'static,
+    ) -> Self {
+        self.triggers.push(Trigger {
+            char,
+            provider: Rc::new(provider),
+        });
+        self
+    }
+
+    /// Return the texts of the tokens inserted by the triggers, including the trigger character.
+    pub fn tokens(&self) -> Vec<SharedString> {
+        self.tokens
+            .iter()
+            .map(|range| self.text[range.clone()].to_string().into())
+            .collect()
+    }
+
+    /// Set true to show indicator at the input right.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
     /// Set true to show indicator at the input right.
     pub fn set_loading(&mut self, loading: bool, cx: &mut ViewContext<Self>) {
         self.loading = loading;
@@ -307,6 +541,14 @@ impl TextInput {
         self.text.clone()
     }
 
+    /// Return the text without the mask literals, it is the same as the text if there is no mask.
+    pub fn raw_text(&self) -> SharedString {
+        match &self.mask {
+            Some(mask) => mask.unmask(&self.text).into(),
+            None => self.text.clone(),
+        }
+    }
+
     pub fn disabled(&self) -> bool {
         self.disabled
     }
@@ -347,22 +589,92 @@ impl TextInput {
         self.select_to(self.text.len(), cx)
     }
 
+    fn up(&mut self, _: &Up, cx: &mut ViewContext<Self>) {
+        if self.select_trigger_item(-1, cx) {
+            return;
+        }
+        if !self.multi_line {
+            cx.propagate();
+            return;
+        }
+
+        self.pause_blink_cursor(cx);
+        self.move_to(self.offset_for_vertical_move(self.cursor_offset(), -1.), cx);
+    }
+
+    fn down(&mut self, _: &Down, cx: &mut ViewContext<Self>) {
+        if self.select_trigger_item(1, cx) {
+            return;
+        }
+        if !self.multi_line {
+            cx.propagate();
+            return;
+        }
+
+        self.pause_blink_cursor(cx);
+        self.move_to(self.offset_for_vertical_move(self.cursor_offset(), 1.), cx);
+    }
+
+    fn select_up(&mut self, _: &SelectUp, cx: &mut ViewContext<Self>) {
+        if !self.multi_line {
+            cx.propagate();
+            return;
+        }
+
+        self.select_to(self.offset_for_vertical_move(self.cursor_offset(), -1.), cx);
+    }
+
+    fn select_down(&mut self, _: &SelectDown, cx: &mut ViewContext<Self>) {
+        if !self.multi_line {
+            cx.propagate();
+            return;
+        }
+
+        self.select_to(self.offset_for_vertical_move(self.cursor_offset(), 1.), cx);
+    }
+
     fn home(&mut self, _: &Home, cx: &mut ViewContext<Self>) {
         self.pause_blink_cursor(cx);
-        self.move_to(0, cx);
+        self.move_to(self.start_of_line(self.cursor_offset()), cx);
     }
 
     fn end(&mut self, _: &End, cx: &mut ViewContext<Self>) {
         self.pause_blink_cursor(cx);
-        self.move_to(self.text.len(), cx);
+        self.move_to(self.end_of_line(self.cursor_offset()), cx);
+    }
+
+    fn move_to_start_of_line(&mut self, _: &MoveToStartOfLine, cx: &mut ViewContext<Self>) {
+        self.home(&Home, cx);
+    }
+
+    fn move_to_end_of_line(&mut self, _: &MoveToEndOfLine, cx: &mut ViewContext<Self>) {
+        self.end(&End, cx);
+    }
+
+    fn move_to_previous_word(&mut self, _: &MoveToPreviousWord, cx: &mut ViewContext<Self>) {
+        self.pause_blink_cursor(cx);
+        self.move_to(self.previous_word_start(self.cursor_offset()), cx);
+    }
+
+    fn move_to_next_word(&mut self, _: &MoveToNextWord, cx: &mut ViewContext<Self>) {
+        self.pause_blink_cursor(cx);
+        self.move_to(self.next_word_end(self.cursor_offset()), cx);
+    }
+
+    fn select_to_previous_word(&mut self, _: &SelectToPreviousWord, cx: &mut ViewContext<Self>) {
+        self.select_to(self.previous_word_start(self.cursor_offset()), cx);
+    }
+
+    fn select_to_next_word(&mut self, _: &SelectToNextWord, cx: &mut ViewContext<Self>) {
+        self.select_to(self.next_word_end(self.cursor_offset()), cx);
     }
 
     fn select_to_home(&mut self, _: &SelectToHome, cx: &mut ViewContext<Self>) {
-        self.select_to(0, cx);
+        self.select_to(self.start_of_line(self.cursor_offset()), cx);
     }
 
     fn select_to_end(&mut self, _: &SelectToEnd, cx: &mut ViewContext<Self>) {
-        self.select_to(self.text.len(), cx);
+        self.select_to(self.end_of_line(self.cursor_offset()), cx);
     }
 
     fn backspace(&mut self, _: &Backspace, cx: &mut ViewContext<Self>) {
@@ -381,14 +693,63 @@ impl TextInput {
         self.pause_blink_cursor(cx);
     }
 
+    fn delete_to_previous_word(&mut self, _: &DeleteToPreviousWord, cx: &mut ViewContext<Self>) {
+        if self.selected_range.is_empty() {
+            self.select_to(self.previous_word_start(self.cursor_offset()), cx)
+        }
+        self.replace_text_in_range(None, "", cx);
+        self.pause_blink_cursor(cx);
+    }
+
+    fn delete_to_next_word(&mut self, _: &DeleteToNextWord, cx: &mut ViewContext<Self>) {
+        if self.selected_range.is_empty() {
+            self.select_to(self.next_word_end(self.cursor_offset()), cx)
+        }
+        self.replace_text_in_range(None, "", cx);
+        self.pause_blink_cursor(cx);
+    }
+
     fn enter(&mut self, _: &Enter, cx: &mut ViewContext<Self>) {
+        if self.confirm_trigger_item(None, cx) {
+            return;
+        }
+        if self.multi_line && !self.submit_on_enter {
+            self.replace_text_in_range(None, "\n", cx);
+            self.pause_blink_cursor(cx);
+            return;
+        }
+
         cx.emit(InputEvent::PressEnter);
     }
 
+    fn escape(&mut self, _: &Escape, cx: &mut ViewContext<Self>) {
+        if self.active_trigger.take().is_none() {
+            cx.propagate();
+            return;
+        }
+
+        cx.notify();
+    }
+
+    fn newline(&mut self, _: &Newline, cx: &mut ViewContext<Self>) {
+        if !self.multi_line {
+            self.enter(&Enter, cx);
+            return;
+        }
+
+        self.replace_text_in_range(None, "\n", cx);
+        self.pause_blink_cursor(cx);
+    }
+
     fn clean(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
         self.replace_text("", cx);
     }
 
+    fn toggle_mask(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
+        self.masked = !self.masked;
+        cx.notify();
+    }
+
     fn on_mouse_down(&mut self, event: &MouseDownEvent, cx: &mut ViewContext<Self>) {
         self.is_selecting = true;
         let offset = self.index_for_mouse_position(event.position);
@@ -415,7 +776,7 @@ impl TextInput {
     }
 
     fn copy(&mut self, _: &Copy, cx: &mut ViewContext<Self>) {
-        if self.selected_range.is_empty() {
+        if self.selected_range.is_empty() || self.masked {
             return;
         }
 
@@ -424,7 +785,7 @@ impl TextInput {
     }
 
     fn cut(&mut self, _: &Cut, cx: &mut ViewContext<Self>) {
-        if self.selected_range.is_empty() {
+        if self.selected_range.is_empty() || self.masked {
             return;
         }
 
@@ -435,7 +796,10 @@ impl TextInput {
 
     fn paste(&mut self, _: &Paste, cx: &mut ViewContext<Self>) {
         if let Some(clipboard) = cx.read_from_clipboard() {
-            let new_text = clipboard.text().unwrap_or_default().replace('\n', "");
+            let mut new_text = clipboard.text().unwrap_or_default();
+            if !self.multi_line {
+                new_text = new_text.replace('\n', "");
+            }
             self.replace_text_in_range(None, &new_text, cx);
         }
     }
@@ -451,6 +815,18 @@ impl TextInput {
 
         let new_range = range.start..range.start + new_text.len();
 
+        // Start a new undo group when the change is not continuing the last one, for example
+        // when the cursor has moved, or switching between typing and deleting, or starting a
+        // new word after a whitespace.
+        let kind = ChangeKind::new(new_text);
+        let continued = self.last_change.map_or(false, |(offset, last_kind)| {
+            (offset == range.start || offset == range.end) && kind.can_group_after(last_kind)
+        });
+        if !continued {
+            self.history.break_group();
+        }
+        self.last_change = Some((new_range.end, kind));
+
         self.history.push(Change::new(
             range.clone(),
             &old_text,
@@ -459,7 +835,8 @@ impl TextInput {
         ));
     }
 
-    fn undo(&mut self, _: &Undo, cx: &mut ViewContext<Self>) {
+    /// Undo the last group of changes.
+    pub fn undo(&mut self, cx: &mut ViewContext<Self>) {
         self.history.ignore = true;
         if let Some(changes) = self.history.undo() {
             for change in changes {
@@ -468,17 +845,39 @@ impl TextInput {
             }
         }
         self.history.ignore = false;
+        self.last_change = None;
     }
 
-    fn redo(&mut self, _: &Redo, cx: &mut ViewContext<Self>) {
+    /// Redo the last undone group of changes.
+    pub fn redo(&mut self, cx: &mut ViewContext<Self>) {
         self.history.ignore = true;
         if let Some(changes) = self.history.redo() {
-            for change in changes {
+            // The changes are returned from the latest, redo them in the original order.
+            for change in changes.into_iter().rev() {
                 let range_utf16 = self.range_to_utf16(&change.old_range);
                 self.replace_text_in_range(Some(range_utf16), &change.new_text, cx);
             }
         }
         self.history.ignore = false;
+        self.last_change = None;
+    }
+
+    /// Returns true if there are changes to undo.
+    pub fn can_undo(&self) -> bool {
+        self.history.can_undo()
+    }
+
+    /// Returns true if there are changes to redo.
+    pub fn can_redo(&self) -> bool {
+        self.history.can_redo()
+    }
+
+    fn on_action_undo(&mut self, _: &Undo, cx: &mut ViewContext<Self>) {
+        self.undo(cx);
+    }
+
+    fn on_action_redo(&mut self, _: &Redo, cx: &mut ViewContext<Self>) {
+        self.redo(cx);
     }
 
     fn move_to(&mut self, offset: usize, cx: &mut ViewContext<Self>) {
@@ -501,6 +900,11 @@ impl TextInput {
             return 0;
         }
 
+        if self.multi_line {
+            let bounds = self.last_bounds.unwrap_or_default();
+            return self.offset_for_position(position - bounds.origin);
+        }
+
         let (Some(bounds), Some(line)) = (self.last_bounds.as_ref(), self.last_layout.as_ref())
         else {
             return 0;
@@ -511,7 +915,30 @@ impl TextInput {
         if position.y > bounds.bottom() {
             return self.text.len();
         }
-        line.closest_index_for_x(position.x - bounds.left())
+        self.offset_from_display(line.closest_index_for_x(position.x - bounds.left()))
+    }
+
+    /// Convert the offset of the text to the offset of the displayed text,
+    /// they are different in masked mode.
+    fn offset_to_display(&self, offset: usize) -> usize {
+        if !self.masked {
+            return offset;
+        }
+
+        self.text[..offset].chars().count() * MASK_CHAR.len_utf8()
+    }
+
+    /// Convert the offset of the displayed text to the offset of the text.
+    fn offset_from_display(&self, offset: usize) -> usize {
+        if !self.masked {
+            return offset;
+        }
+
+        self.text
+            .char_indices()
+            .nth(offset / MASK_CHAR.len_utf8())
+            .map(|(ix, _)| ix)
+            .unwrap_or(self.text.len())
     }
 
     fn select_to(&mut self, offset: usize, cx: &mut ViewContext<Self>) {
@@ -621,6 +1048,181 @@ impl TextInput {
             .unwrap_or(self.text.len())
     }
 
+    /// Returns the offset of the start of the line that contains the given offset.
+    fn start_of_line(&self, offset: usize) -> usize {
+        self.text[..offset]
+            .rfind('\n')
+            .map(|ix| ix + 1)
+            .unwrap_or(0)
+    }
+
+    /// Returns the offset of the end of the line that contains the given offset.
+    fn end_of_line(&self, offset: usize) -> usize {
+        self.text[offset..]
+            .find('\n')
+            .map(|ix| offset + ix)
+            .unwrap_or(self.text.len())
+    }
+
+    /// Returns the position of the given offset relative to the origin of the text,
+    /// only available in multi-line mode after the text has been painted.
+    fn position_for_offset(&self, offset: usize) -> Option<Point<Pixels>> {
+        let lines = self.last_lines.as_ref()?;
+        let line_height = self.last_line_height;
+
+        let mut line_start = 0;
+        let mut y = px(0.);
+        for line in lines.iter() {
+            let line_end = line_start + line.len();
+            if offset <= line_end {
+                let position = line.position_for_index(offset - line_start, line_height)?;
+                return Some(point(position.x, position.y + y));
+            }
+
+            // Skip the `\n` between the lines.
+            line_start = line_end + 1;
+            y += line.size(line_height).height;
+        }
+
+        None
+    }
+
+    /// Returns the offset of the given position relative to the origin of the text in multi-line mode.
+    fn offset_for_position(&self, position: Point<Pixels>) -> usize {
+        let Some(lines) = self.last_lines.as_ref() else {
+            return 0;
+        };
+        if position.y < px(0.) {
+            return 0;
+        }
+
+        let line_height = self.last_line_height;
+        let mut line_start = 0;
+        let mut y = px(0.);
+        for line in lines.iter() {
+            let height = line.size(line_height).height;
+            if position.y < y + height {
+                let ix =
+                    match line.index_for_position(point(position.x, position.y - y), line_height) {
+                        Ok(ix) | Err(ix) => ix,
+                    };
+                return line_start + ix;
+            }
+
+            line_start += line.len() + 1;
+            y += height;
+        }
+
+        self.text.len()
+    }
+
+    /// Returns the offset after moving the cursor up (negative) or down (positive) by the given rows.
+    fn offset_for_vertical_move(&self, offset: usize, rows: f32) -> usize {
+        let Some(position) = self.position_for_offset(offset) else {
+            return offset;
+        };
+
+        let line_height = self.last_line_height;
+        let y = position.y + line_height * rows + line_height / 2.;
+        if y < px(0.) {
+            return 0;
+        }
+        self.offset_for_position(point(position.x, y))
+    }
+
+    /// Returns the start offset of the word before the given offset, skipping any whitespace and
+    /// punctuation between them.
+    fn previous_word_start(&self, offset: usize) -> usize {
+        let mut start = offset;
+        let mut in_word = false;
+        for (ix, c) in self.text[..offset].char_indices().rev() {
+            let is_word = c.is_alphanumeric() || c == '_';
+            if in_word && !is_word {
+                break;
+            }
+            in_word |= is_word;
+            start = ix;
+        }
+        start
+    }
+
+    /// Returns the end offset of the word after the given offset, skipping any whitespace and
+    /// punctuation between them.
+    fn next_word_end(&self, offset: usize) -> usize {
+        let mut end = offset;
+        let mut in_word = false;
+        for (ix, c) in self.text[offset..].char_indices() {
+            let is_word = c.is_alphanumeric() || c == '_';
+            if in_word && !is_word {
+                break;
+            }
+            in_word |= is_word;
+            end = offset + ix + c.len_utf8();
+        }
+        end
+    }
+
+    /// Open, update or close the trigger menu by the word before the cursor.
+    fn update_trigger(&mut self, cx: &mut ViewContext<Self>) {
+        self.active_trigger = None;
+        if self.triggers.is_empty() || !self.selected_range.is_empty() {
+            return;
+        }
+
+        let chars = self.triggers.iter().map(|t| t.char).collect::<Vec<_>>();
+        let cursor = self.cursor_offset();
+        let Some((char, start)) = trigger_at(&self.text, cursor, &chars) else {
+            return;
+        };
+        let Some(trigger) = self.triggers.iter().find(|t| t.char == char).cloned() else {
+            return;
+        };
+
+        let query = self.text[start + char.len_utf8()..cursor].to_string();
+        let items = (trigger.provider)(&query, cx);
+        self.active_trigger = Some(ActiveTrigger {
+            char,
+            start,
+            items,
+            selected_index: 0,
+        });
+    }
+
+    /// Move the selection of the trigger menu, returns false if the menu is not opened.
+    fn select_trigger_item(&mut self, delta: isize, cx: &mut ViewContext<Self>) -> bool {
+        let Some(trigger) = self
+            .active_trigger
+            .as_mut()
+            .filter(|trigger| !trigger.items.is_empty())
+        else {
+            return false;
+        };
+
+        let len = trigger.items.len() as isize;
+        trigger.selected_index = (trigger.selected_index as isize + delta).rem_euclid(len) as usize;
+        cx.notify();
+        true
+    }
+
+    /// Replace the trigger query with the token of the item, returns false if there is nothing to confirm.
+    fn confirm_trigger_item(&mut self, ix: Option<usize>, cx: &mut ViewContext<Self>) -> bool {
+        let Some(trigger) = self.active_trigger.take() else {
+            return false;
+        };
+        let Some(item) = trigger.items.get(ix.unwrap_or(trigger.selected_index)) else {
+            return false;
+        };
+
+        let token = format!("{}{}", trigger.char, item);
+        self.selected_range = trigger.start..self.cursor_offset();
+        self.replace_text_in_range(None, &format!("{} ", token), cx);
+        if self.text[trigger.start..].starts_with(&token) {
+            self.tokens.push(trigger.start..trigger.start + token.len());
+            self.tokens.sort_by_key(|token| token.start);
+        }
+        true
+    }
+
     /// Returns the true to let InputElement to render cursor, when Input is focused and current BlinkCursor is visible.
     pub(crate) fn show_cursor(&self, cx: &WindowContext) -> bool {
         self.focus_handle.is_focused(cx) && self.blink_cursor.read(cx).visible()
@@ -634,6 +1236,7 @@ impl TextInput {
     }
 
     fn on_blur(&mut self, cx: &mut ViewContext<Self>) {
+        self.active_trigger = None;
         self.unselect(cx);
         self.blink_cursor.update(cx, |cursor, cx| {
             cursor.stop(cx);
@@ -656,7 +1259,7 @@ impl TextInput {
             return;
         }
 
-        if self.last_layout.is_none() {
+        if self.last_layout.is_none() && self.last_lines.is_none() {
             return;
         }
 
@@ -675,7 +1278,12 @@ impl TextInput {
     fn offset_of_position(&self, position: Point<Pixels>) -> usize {
         let bounds = self.last_bounds.unwrap_or_default();
         let position = position - bounds.origin;
-        self.last_layout
+        if self.multi_line {
+            return self.offset_for_position(position);
+        }
+
+        let offset = self
+            .last_layout
             .as_ref()
             .map(|line| match line.index_for_x(position.x) {
                 Some(ix) => ix,
@@ -690,7 +1298,8 @@ impl TextInput {
                     }
                 }
             })
-            .unwrap_or(0)
+            .unwrap_or(0);
+        self.offset_from_display(offset)
     }
 
     fn is_valid_input(&self, new_text: &str) -> bool {
@@ -711,6 +1320,24 @@ impl TextInput {
     }
 }
 
+impl std::fmt::Debug for TextInput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Never expose the text of a password input.
+        let text = if self.password || self.masked {
+            "<masked>"
+        } else {
+            self.text.as_ref()
+        };
+
+        f.debug_struct("TextInput")
+            .field("text", &text)
+            .field("placeholder", &self.placeholder)
+            .field("disabled", &self.disabled)
+            .field("error", &self.error)
+            .finish()
+    }
+}
+
 impl Sizable for TextInput {
     fn with_size(mut self, size: impl Into<Size>) -> Self {
         self.size = size.into();
@@ -765,16 +1392,37 @@ impl ViewInputHandler for TextInput {
             .or(self.marked_range.clone())
             .unwrap_or(self.selected_range.clone());
 
+        // With a mask, the whole text is formatted again, and the cursor is kept
+        // after the same raw characters.
+        let (range, new_text, cursor) = match &self.mask {
+            Some(mask) => {
+                let before_cursor = self.text[0..range.start].to_owned() + new_text;
+                let raw = mask.unmask(&(before_cursor.clone() + &self.text[range.end..]));
+                let masked = mask.mask(&raw);
+                let raw_count = mask.unmask(&before_cursor).chars().count();
+                let cursor = mask.offset_for_raw_count(&masked, raw_count);
+                (0..self.text.len(), masked, cursor)
+            }
+            None => (
+                range.clone(),
+                new_text.to_string(),
+                range.start + new_text.len(),
+            ),
+        };
+
         let pending_text: SharedString =
-            (self.text[0..range.start].to_owned() + new_text + &self.text[range.end..]).into();
+            (self.text[0..range.start].to_owned() + &new_text + &self.text[range.end..]).into();
         if !self.is_valid_input(&pending_text) {
             return;
         }
 
-        self.push_history(&range, new_text, cx);
+        self.push_history(&range, &new_text, cx);
+        shift_tokens(&mut self.tokens, &range, new_text.len());
         self.text = pending_text;
-        self.selected_range = range.start + new_text.len()..range.start + new_text.len();
+        self.selected_range = cursor..cursor;
         self.marked_range.take();
+        self.validate_text(cx);
+        self.update_trigger(cx);
         cx.emit(InputEvent::Change(self.text.clone()));
         cx.notify();
     }
@@ -802,6 +1450,7 @@ impl ViewInputHandler for TextInput {
         }
 
         self.push_history(&range, new_text, cx);
+        shift_tokens(&mut self.tokens, &range, new_text.len());
         self.text = pending_text;
         self.marked_range = Some(range.start..range.start + new_text.len());
         self.selected_range = new_selected_range_utf16
@@ -809,6 +1458,7 @@ impl ViewInputHandler for TextInput {
             .map(|range_utf16| self.range_from_utf16(range_utf16))
             .map(|new_range| new_range.start + range.start..new_range.end + range.end)
             .unwrap_or_else(|| range.start + new_text.len()..range.start + new_text.len());
+        self.validate_text(cx);
         cx.emit(InputEvent::Change(self.text.clone()));
         cx.notify();
     }
@@ -819,15 +1469,25 @@ impl ViewInputHandler for TextInput {
         bounds: Bounds<Pixels>,
         _cx: &mut ViewContext<Self>,
     ) -> Option<Bounds<Pixels>> {
-        let last_layout = self.last_layout.as_ref()?;
         let range = self.range_from_utf16(&range_utf16);
+        if self.multi_line {
+            let origin = self.last_bounds?.origin;
+            let start = self.position_for_offset(range.start)?;
+            let end = self.position_for_offset(range.end)?;
+            return Some(Bounds::from_corners(
+                origin + start,
+                origin + point(end.x, end.y + self.last_line_height),
+            ));
+        }
+
+        let last_layout = self.last_layout.as_ref()?;
         Some(Bounds::from_corners(
             point(
-                bounds.left() + last_layout.x_for_index(range.start),
+                bounds.left() + last_layout.x_for_index(self.offset_to_display(range.start)),
                 bounds.top(),
             ),
             point(
-                bounds.left() + last_layout.x_for_index(range.end),
+                bounds.left() + last_layout.x_for_index(self.offset_to_display(range.end)),
                 bounds.bottom(),
             ),
         ))
@@ -857,61 +1517,21 @@ impl TextElement {
             }
         });
     }
-}
-struct PrepaintState {
-    scroll_offset: Point<Pixels>,
-    line: Option<ShapedLine>,
-    cursor: Option<PaintQuad>,
-    selection: Option<PaintQuad>,
-    bounds: Bounds<Pixels>,
-}
 
-impl IntoElement for TextElement {
-    type Element = Self;
-
-    fn into_element(self) -> Self::Element {
-        self
-    }
-}
-
-impl Element for TextElement {
-    type RequestLayoutState = ();
-    type PrepaintState = PrepaintState;
-
-    fn id(&self) -> Option<ElementId> {
-        None
-    }
-
-    fn request_layout(
-        &mut self,
-        _id: Option<&GlobalElementId>,
-        cx: &mut WindowContext,
-    ) -> (LayoutId, Self::RequestLayoutState) {
-        let mut style = Style::default();
-        style.size.width = relative(1.).into();
-        style.size.height = cx.line_height().into();
-        (cx.request_layout(style, []), ())
-    }
-
-    fn prepaint(
-        &mut self,
-        _id: Option<&GlobalElementId>,
-        bounds: Bounds<Pixels>,
-        _request_layout: &mut Self::RequestLayoutState,
-        cx: &mut WindowContext,
-    ) -> Self::PrepaintState {
-        let input = self.input.read(cx);
+    /// Returns the text to display and the text runs to shape it with.
+    fn display_text_and_runs(
+        input: &TextInput,
+        cx: &WindowContext,
+    ) -> (SharedString, Vec<TextRun>) {
         let text = input.text.clone();
         let placeholder = input.placeholder.clone();
-        let selected_range = input.selected_range.clone();
-        let cursor = input.cursor_offset();
         let style = cx.text_style();
 
         let (display_text, text_color) = if text.is_empty() {
             (placeholder, cx.theme().muted_foreground)
         } else if input.masked {
             (
-                "*".repeat(text.chars().count()).into(),
+                MASK_CHAR.to_string().repeat(text.chars().count()).into(),
                 cx.theme().foreground,
             )
         } else {
@@ -927,7 +1547,12 @@ impl Element for TextElement {
             strikethrough: None,
         };
 
-        let runs = if let Some(marked_range) = input.marked_range.as_ref() {
+        let marked_range = input
+            .marked_range
+            .as_ref()
+            .filter(|_| !input.text.is_empty())
+            .map(|range| input.offset_to_display(range.start)..input.offset_to_display(range.end));
+        let runs = if let Some(marked_range) = marked_range {
             vec![
                 TextRun {
                     len: marked_range.start,
@@ -950,10 +1575,212 @@ impl Element for TextElement {
             .into_iter()
             .filter(|run| run.len > 0)
             .collect()
+        } else if !input.masked && !input.text.is_empty() && !input.tokens.is_empty() {
+            let token_run = TextRun {
+                color: cx.theme().link,
+                background_color: Some(cx.theme().accent),
+                ..run.clone()
+            };
+
+            let mut runs = vec![];
+            let mut offset = 0;
+            for token in input.tokens.iter() {
+                runs.push(TextRun {
+                    len: token.start - offset,
+                    ..run.clone()
+                });
+                runs.push(TextRun {
+                    len: token.end - token.start,
+                    ..token_run.clone()
+                });
+                offset = token.end;
+            }
+            runs.push(TextRun {
+                len: display_text.len() - offset,
+                ..run
+            });
+            runs.into_iter().filter(|run| run.len > 0).collect()
         } else {
             vec![run]
         };
 
+        (display_text, runs)
+    }
+
+    fn prepaint_multi_line(
+        &mut self,
+        bounds: Bounds<Pixels>,
+        cx: &mut WindowContext,
+    ) -> PrepaintState {
+        let input = self.input.read(cx);
+        let selected_range = input.selected_range.clone();
+        let cursor = input.cursor_offset();
+        let show_cursor = input.show_cursor(cx);
+        let line_height = cx.line_height();
+        let font_size = cx.text_style().font_size.to_pixels(cx.rem_size());
+        let (display_text, runs) = Self::display_text_and_runs(input, cx);
+
+        let lines = cx
+            .text_system()
+            .shape_text(display_text, font_size, &runs, Some(bounds.size.width))
+            .unwrap_or_default();
+
+        // Find the position of an offset in the shaped lines.
+        let position_for_offset = |offset: usize| -> Point<Pixels> {
+            let mut line_start = 0;
+            let mut y = px(0.);
+            for line in lines.iter() {
+                let line_end = line_start + line.len();
+                if offset <= line_end {
+                    let position = line
+                        .position_for_index(offset - line_start, line_height)
+                        .unwrap_or_default();
+                    return point(position.x, position.y + y);
+                }
+                line_start = line_end + 1;
+                y += line.size(line_height).height;
+            }
+            point(px(0.), y)
+        };
+
+        let inset = px(0.5);
+        let mut selections = vec![];
+        let mut cursor_quad = None;
+        let cursor_pos = position_for_offset(cursor);
+        if selected_range.is_empty() {
+            if show_cursor {
+                cursor_quad = Some(fill(
+                    Bounds::new(
+                        bounds.origin + point(cursor_pos.x, cursor_pos.y + inset),
+                        size(px(2.), line_height - inset * 2),
+                    ),
+                    crate::blue_500(),
+                ));
+            }
+        } else {
+            let start = position_for_offset(selected_range.start);
+            let end = position_for_offset(selected_range.end);
+            let right = bounds.size.width;
+
+            if start.y == end.y {
+                selections.push((start, point(end.x, end.y + line_height)));
+            } else {
+                // The first row from the selection start to the right edge.
+                selections.push((start, point(right, start.y + line_height)));
+                // The full rows between the first and the last row.
+                if end.y > start.y + line_height {
+                    selections.push((point(px(0.), start.y + line_height), point(right, end.y)));
+                }
+                // The last row from the left edge to the selection end.
+                selections.push((point(px(0.), end.y), point(end.x, end.y + line_height)));
+            }
+        }
+
+        let selections = selections
+            .into_iter()
+            .map(|(start, end)| {
+                fill(
+                    Bounds::from_corners(bounds.origin + start, bounds.origin + end),
+                    cx.theme().selection,
+                )
+            })
+            .collect();
+
+        PrepaintState {
+            scroll_offset: point(px(0.), px(0.)),
+            bounds,
+            line: None,
+            lines: Some(lines),
+            cursor: cursor_quad,
+            cursor_position: Some(cursor_pos),
+            selections,
+        }
+    }
+}
+
+struct PrepaintState {
+    scroll_offset: Point<Pixels>,
+    line: Option<ShapedLine>,
+    lines: Option<SmallVec<[WrappedLine; 1]>>,
+    cursor: Option<PaintQuad>,
+    /// The cursor position relative to the text origin, only used in multi-line mode.
+    cursor_position: Option<Point<Pixels>>,
+    selections: Vec<PaintQuad>,
+    bounds: Bounds<Pixels>,
+}
+
+impl IntoElement for TextElement {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for TextElement {
+    type RequestLayoutState = ();
+    type PrepaintState = PrepaintState;
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let mut style = Style::default();
+        style.size.width = relative(1.).into();
+
+        let input = self.input.read(cx);
+        if !input.multi_line {
+            style.size.height = cx.line_height().into();
+            return (cx.request_layout(style, []), ());
+        }
+
+        // The height of the multi-line text depends on the wrap width, so measure it in layout.
+        let line_height = cx.line_height();
+        let font_size = cx.text_style().font_size.to_pixels(cx.rem_size());
+        let (display_text, runs) = Self::display_text_and_runs(input, cx);
+        let layout_id = cx.request_measured_layout(style, move |known, available, cx| {
+            let wrap_width = known.width.or(match available.width {
+                AvailableSpace::Definite(width) => Some(width),
+                _ => None,
+            });
+
+            let lines = cx
+                .text_system()
+                .shape_text(display_text.clone(), font_size, &runs, wrap_width)
+                .unwrap_or_default();
+            let height = lines.iter().fold(px(0.), |height, line| {
+                height + line.size(line_height).height
+            });
+
+            size(wrap_width.unwrap_or_default(), height.max(line_height))
+        });
+
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) -> Self::PrepaintState {
+        if self.input.read(cx).multi_line {
+            return self.prepaint_multi_line(bounds, cx);
+        }
+
+        let input = self.input.read(cx);
+        let selected_range = input.offset_to_display(input.selected_range.start)
+            ..input.offset_to_display(input.selected_range.end);
+        let cursor = input.offset_to_display(input.cursor_offset());
+        let style = cx.text_style();
+        let (display_text, runs) = Self::display_text_and_runs(input, cx);
+
         let font_size = style.font_size.to_pixels(cx.rem_size());
         let line = cx
             .text_system()
@@ -1029,8 +1856,10 @@ impl Element for TextElement {
             scroll_offset,
             bounds,
             line: Some(line),
+            lines: None,
             cursor,
-            selection,
+            cursor_position: None,
+            selections: selection.into_iter().collect(),
         }
     }
 
@@ -1045,50 +1874,210 @@ impl Element for TextElement {
         let focus_handle = self.input.read(cx).focus_handle.clone();
         let focused = focus_handle.is_focused(cx);
         let bounds = prepaint.bounds;
+        let line_height = cx.line_height();
 
         cx.handle_input(
             &focus_handle,
             ElementInputHandler::new(bounds, self.input.clone()),
         );
-        if let Some(selection) = prepaint.selection.take() {
+        for selection in prepaint.selections.drain(..) {
             cx.paint_quad(selection)
         }
-        let line = prepaint.line.take().unwrap();
-        line.paint(bounds.origin, cx.line_height(), cx).unwrap();
+
+        let line = prepaint.line.take();
+        if let Some(line) = line.as_ref() {
+            line.paint(bounds.origin, line_height, cx).unwrap();
+        }
+        let lines = prepaint.lines.take();
+        if let Some(lines) = lines.as_ref() {
+            let mut origin = bounds.origin;
+            for line in lines.iter() {
+                line.paint(origin, line_height, cx).unwrap();
+                origin.y += line.size(line_height).height;
+            }
+        }
 
         if focused {
             if let Some(cursor) = prepaint.cursor.take() {
                 cx.paint_quad(cursor);
             }
         }
-        self.input.update(cx, |input, _cx| {
+
+        let cursor_position = prepaint.cursor_position;
+        let needs_refresh = self.input.update(cx, |input, _cx| {
             input.scroll_offset = prepaint.scroll_offset;
-            input.last_layout = Some(line);
+            input.last_layout = line;
+            input.last_lines = lines;
+            input.last_line_height = line_height;
             input.last_bounds = Some(bounds);
+
+            // Scroll vertically to keep the cursor in view when it moved.
+            let cursor = input.cursor_offset();
+            if input.last_cursor_offset == Some(cursor) {
+                return false;
+            }
+            input.last_cursor_offset = Some(cursor);
+
+            let Some(cursor_position) = cursor_position else {
+                return false;
+            };
+            let viewport = input.scroll_handle.bounds();
+            let cursor_top = bounds.top() + cursor_position.y;
+            let cursor_bottom = cursor_top + line_height;
+            let mut offset = input.scroll_handle.offset();
+            if cursor_top < viewport.top() {
+                offset.y += viewport.top() - cursor_top;
+            } else if cursor_bottom > viewport.bottom() {
+                offset.y -= cursor_bottom - viewport.bottom();
+            } else {
+                return false;
+            }
+            input.scroll_handle.set_offset(offset);
+            true
         });
+        if needs_refresh {
+            cx.refresh();
+        }
 
         self.paint_mouse_listeners(cx);
     }
 }
 
+impl TextInput {
+    fn render_multi_line(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let scroll_size = self.last_bounds.map(|b| b.size).unwrap_or_default();
+
+        div()
+            .relative()
+            .flex_grow()
+            .h(rems(1.25 * self.rows as f32))
+            .child(
+                div()
+                    .id("TextElement")
+                    .size_full()
+                    .overflow_y_scroll()
+                    .track_scroll(&self.scroll_handle)
+                    .cursor_text()
+                    .child(TextElement {
+                        input: cx.view().clone(),
+                    }),
+            )
+            .child(
+                div()
+                    .absolute()
+                    .top_0()
+                    .left_0()
+                    .right_0()
+                    .bottom_0()
+                    .child(Scrollbar::vertical(
+                        cx.view().entity_id(),
+                        self.scrollbar_state.clone(),
+                        self.scroll_handle.clone(),
+                        scroll_size,
+                    )),
+            )
+    }
+}
+
+impl TextInput {
+    fn render_trigger_menu(&mut self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        let trigger = self
+            .active_trigger
+            .as_ref()
+            .filter(|trigger| !trigger.items.is_empty())?;
+        let char = trigger.char;
+        let items = trigger.items.clone();
+        let selected_index = trigger.selected_index;
+
+        // Open the menu below the trigger character.
+        let range = self.range_to_utf16(&(trigger.start..trigger.start));
+        let last_bounds = self.last_bounds?;
+        let caret = self.bounds_for_range(range, last_bounds, cx)?;
+
+        Some(
+            deferred(
+                anchored()
+                    .position(point(caret.left(), caret.bottom()))
+                    .snap_to_window_with_margin(px(8.))
+                    .child(
+                        v_flex()
+                            .occlude()
+                            .mt_1()
+                            .p_1()
+                            .min_w(px(160.))
+                            .max_h(rems(20.))
+                            .overflow_hidden()
+                            .bg(cx.theme().background)
+                            .border_1()
+                            .border_color(cx.theme().border)
+                            .rounded(px(cx.theme().radius))
+                            .shadow_md()
+                            .children(items.into_iter().enumerate().map(|(ix, item)| {
+                                ListItem::new(("trigger-item", ix))
+                                    .selected(ix == selected_index)
+                                    .child(format!("{}{}", char, item))
+                                    .on_click(cx.listener(move |this, _, cx| {
+                                        this.confirm_trigger_item(Some(ix), cx);
+                                    }))
+                            }))
+                            .on_mouse_down_out(cx.listener(|this, _, cx| {
+                                this.active_trigger = None;
+                                cx.notify();
+                            })),
+                    ),
+            )
+            .with_priority(1),
+        )
+    }
+}
+
 impl Render for TextInput {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let focused = self.focus_handle.is_focused(cx);
+        let trigger_menu = if focused {
+            self.render_trigger_menu(cx)
+        } else {
+            None
+        };
 
-        let prefix = self.prefix.as_ref().map(|build| build(cx));
-        let suffix = self.suffix.as_ref().map(|build| build(cx));
+        // The prefix and suffix are rendered inside the input border, the icons and units are
+        // rendered with the muted color.
+        let prefix = self.prefix.as_ref().map(|build| {
+            div()
+                .flex()
+                .flex_none()
+                .items_center()
+                .text_color(cx.theme().muted_foreground)
+                .child(build(cx))
+        });
+        let suffix = self.suffix.as_ref().map(|build| {
+            div()
+                .flex()
+                .flex_none()
+                .items_center()
+                .text_color(cx.theme().muted_foreground)
+                .child(build(cx))
+        });
 
-        div()
+        let input = div()
             .flex()
             .key_context(CONTEXT)
             .track_focus(&self.focus_handle)
             .when(!self.disabled, |this| {
                 this.on_action(cx.listener(Self::backspace))
                     .on_action(cx.listener(Self::delete))
+                    .on_action(cx.listener(Self::delete_to_previous_word))
+                    .on_action(cx.listener(Self::delete_to_next_word))
                     .on_action(cx.listener(Self::enter))
+                    .on_action(cx.listener(Self::escape))
+                    .on_action(cx.listener(Self::newline))
             })
             .on_action(cx.listener(Self::left))
             .on_action(cx.listener(Self::right))
+            .on_action(cx.listener(Self::up))
+            .on_action(cx.listener(Self::down))
+            .on_action(cx.listener(Self::select_up))
+            .on_action(cx.listener(Self::select_down))
             .on_action(cx.listener(Self::select_left))
             .on_action(cx.listener(Self::select_right))
             .on_action(cx.listener(Self::select_all))
@@ -1096,13 +2085,18 @@ impl Render for TextInput {
             .on_action(cx.listener(Self::select_to_end))
             .on_action(cx.listener(Self::home))
             .on_action(cx.listener(Self::end))
+            .on_action(cx.listener(Self::move_to_start_of_line))
+            .on_action(cx.listener(Self::move_to_end_of_line))
+            .on_action(cx.listener(Self::move_to_previous_word))
+            .on_action(cx.listener(Self::move_to_next_word))
+            .on_action(cx.listener(Self::select_to_previous_word))
+            .on_action(cx.listener(Self::select_to_next_word))
             .on_action(cx.listener(Self::show_character_palette))
             .on_action(cx.listener(Self::copy))
             .on_action(cx.listener(Self::paste))
             .on_action(cx.listener(Self::cut))
-            .on_action(cx.listener(Self::undo))
-            .on_action(cx.listener(Self::redo))
-            .on_action(cx.listener(Self::redo))
+            .on_action(cx.listener(Self::on_action_undo))
+            .on_action(cx.listener(Self::on_action_redo))
             // Double click to select all
             .on_double_click(cx.listener(|view, _, cx| {
                 view.select_all(&SelectAll, cx);
@@ -1115,40 +2109,78 @@ impl Render for TextInput {
             .size_full()
             .line_height(rems(1.25))
             .text_size(rems(0.875))
-            .input_py(self.size)
-            .input_h(self.size)
+            .input_py(self.size, cx)
+            .when(!self.multi_line, |this| this.input_h(self.size, cx))
             .when(self.appearance, |this| {
+                let input = cx.theme().component::<InputTheme>();
                 this.bg(if self.disabled {
-                    cx.theme().muted
+                    input.disabled_background
                 } else {
-                    cx.theme().background
+                    input.background
                 })
-                .border_color(cx.theme().input)
+                .border_color(input.border)
                 .border_1()
-                .rounded(px(cx.theme().radius))
-                .when(cx.theme().shadow, |this| this.shadow_sm())
+                .rounded(input.radius)
+                .when(input.shadow, |this| this.shadow_sm())
                 .when(focused, |this| this.outline(cx))
-                .when(prefix.is_none(), |this| this.input_pl(self.size))
-                .when(suffix.is_none(), |this| this.input_pr(self.size))
+                .when(self.error.is_some(), |this| {
+                    this.border_color(input.error_border)
+                })
+                .input_pl(self.size, cx)
+                .input_pr(self.size, cx)
             })
             .children(prefix)
             .gap_1()
-            .items_center()
-            .child(
-                div()
-                    .id("TextElement")
-                    .flex_grow()
-                    .overflow_x_hidden()
-                    .cursor_text()
-                    .child(TextElement {
-                        input: cx.view().clone(),
-                    }),
-            )
+            .map(|this| {
+                if self.multi_line {
+                    this.items_start().child(self.render_multi_line(cx))
+                } else {
+                    this.items_center().child(
+                        div()
+                            .id("TextElement")
+                            .flex_grow()
+                            .overflow_x_hidden()
+                            .cursor_text()
+                            .child(TextElement {
+                                input: cx.view().clone(),
+                            }),
+                    )
+                }
+            })
             .when(self.loading, |this| this.child(Indicator::new()))
             .when(
                 self.cleanable && !self.loading && !self.text.is_empty(),
                 |this| this.child(ClearButton::new(cx).on_click(cx.listener(Self::clean))),
             )
+            .when(self.password, |this| {
+                this.child(
+                    Button::new("toggle-mask")
+                        .icon(if self.masked {
+                            IconName::Eye
+                        } else {
+                            IconName::EyeOff
+                        })
+                        .ghost()
+                        .with_size(px(14.))
+                        .on_click(cx.listener(Self::toggle_mask)),
+                )
+            })
             .children(suffix)
+            .children(trigger_menu);
+
+        match self.error.clone() {
+            Some(error) => v_flex()
+                .w_full()
+                .gap_1()
+                .child(input)
+                .child(
+                    div()
+                        .text_xs()
+                        .text_color(cx.theme().destructive)
+                        .child(error),
+                )
+                .into_any_element(),
+            None => input.into_any_element(),
+        }
     }
 }
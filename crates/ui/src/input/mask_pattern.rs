@@ -0,0 +1,149 @@
+use std::rc::Rc;
+
+use gpui::SharedString;
+
+/// The mask of a [`super::TextInput`] to format the text as the user types.
+///
+/// The raw value of a masked text is the alphanumeric characters of it,
+/// so the literal characters of a mask should not be alphanumeric.
+#[derive(Clone)]
+pub enum MaskPattern {
+    /// A pattern like `(999) 999-9999`, where:
+    ///
+    /// - `9` is a digit.
+    /// - `a` is a letter.
+    /// - `*` is a digit or a letter.
+    ///
+    /// Other characters are literals, they are inserted automatically.
+    Pattern(SharedString),
+    /// A custom function to format the raw value.
+    Formatter(Rc<dyn Fn(&str) -> String>),
+}
+
+impl MaskPattern {
+    /// Create a mask from a pattern like `(999) 999-9999`.
+    pub fn new(pattern: impl Into<SharedString>) -> Self {
+        Self::Pattern(pattern.into())
+    }
+
+    /// Create a mask with a custom function to format the raw value.
+    pub fn formatter(f: impl Fn(&str) -> String + 'static) -> Self {
+        Self::Formatter(Rc::new(f))
+    }
+
+    /// Returns the raw value of the text, without the literal characters.
+    pub fn unmask(&self, text: &str) -> String {
+        text.chars().filter(|c| c.is_alphanumeric()).collect()
+    }
+
+    /// Format the raw value with the mask.
+    pub fn mask(&self, raw: &str) -> String {
+        match self {
+            Self::Pattern(pattern) => Self::mask_pattern(pattern, raw),
+            Self::Formatter(f) => f(raw),
+        }
+    }
+
+    /// Returns the offset in the masked text after the given count of raw characters.
+    pub(crate) fn offset_for_raw_count(&self, masked: &str, count: usize) -> usize {
+        if count == 0 {
+            return 0;
+        }
+
+        let mut raw_count = 0;
+        for (ix, c) in masked.char_indices() {
+            if c.is_alphanumeric() {
+                raw_count += 1;
+                if raw_count == count {
+                    return ix + c.len_utf8();
+                }
+            }
+        }
+
+        masked.len()
+    }
+
+    fn mask_pattern(pattern: &str, raw: &str) -> String {
+        let mut raw = raw.chars();
+        let mut masked = String::new();
+        // The length of the masked text at the last filled slot, to drop the trailing literals.
+        let mut filled_len = 0;
+
+        for p in pattern.chars() {
+            match p {
+                '9' | 'a' | '*' => {
+                    let Some(c) = raw.by_ref().find(|c| Self::is_match(p, *c)) else {
+                        break;
+                    };
+                    masked.push(c);
+                    filled_len = masked.len();
+                }
+                _ => masked.push(p),
+            }
+        }
+
+        masked.truncate(filled_len);
+        masked
+    }
+
+    fn is_match(slot: char, c: char) -> bool {
+        match slot {
+            '9' => c.is_ascii_digit(),
+            'a' => c.is_alphabetic(),
+            _ => c.is_alphanumeric(),
+        }
+    }
+}
+
+impl From<&'static str> for MaskPattern {
+    fn from(pattern: &'static str) -> Self {
+        Self::new(pattern)
+    }
+}
+
+impl From<SharedString> for MaskPattern {
+    fn from(pattern: SharedString) -> Self {
+        Self::new(pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MaskPattern;
+
+    #[test]
+    fn test_mask_pattern() {
+        let mask = MaskPattern::new("(999) 999-9999");
+        assert_eq!(mask.mask(""), "");
+        assert_eq!(mask.mask("5"), "(5");
+        assert_eq!(mask.mask("555"), "(555");
+        assert_eq!(mask.mask("5551"), "(555) 1");
+        assert_eq!(mask.mask("5551234567"), "(555) 123-4567");
+        assert_eq!(mask.mask("55512345678"), "(555) 123-4567");
+        assert_eq!(mask.mask("55a5"), "(555");
+        assert_eq!(mask.unmask("(555) 123-4567"), "5551234567");
+
+        let mask = MaskPattern::new("aa-99");
+        assert_eq!(mask.mask("ab12"), "ab-12");
+        assert_eq!(mask.mask("a1b2"), "ab-2");
+
+        assert_eq!(mask.offset_for_raw_count("(555) 1", 0), 0);
+        assert_eq!(mask.offset_for_raw_count("(555) 1", 3), 4);
+        assert_eq!(mask.offset_for_raw_count("(555) 1", 4), 7);
+        assert_eq!(mask.offset_for_raw_count("(555) 1", 10), 7);
+    }
+
+    #[test]
+    fn test_mask_formatter() {
+        let mask = MaskPattern::formatter(|raw| {
+            raw.chars()
+                .collect::<Vec<_>>()
+                .chunks(4)
+                .map(|chunk| chunk.iter().collect::<String>())
+                .collect::<Vec<_>>()
+                .join(" ")
+        });
+        assert_eq!(mask.mask("12345678"), "1234 5678");
+        assert_eq!(mask.unmask("1234 5678"), "12345678");
+    }
+}
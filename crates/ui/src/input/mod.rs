@@ -2,8 +2,17 @@ mod blink_cursor;
 mod change;
 mod clear_button;
 mod input;
+mod mask_pattern;
+mod number_input;
 mod otp_input;
+mod search_input;
+mod tag_input;
+mod trigger;
 
 pub(crate) use clear_button::*;
 pub use input::*;
+pub use mask_pattern::*;
+pub use number_input::*;
 pub use otp_input::*;
+pub use search_input::*;
+pub use tag_input::*;
@@ -0,0 +1,265 @@
+use gpui::{
+    div, px, AppContext, EventEmitter, FocusHandle, FocusableView, InteractiveElement as _,
+    IntoElement, ParentElement as _, Render, SharedString, Styled as _, Subscription, View,
+    ViewContext, VisualContext as _,
+};
+
+use crate::{
+    button::{Button, ButtonStyled as _},
+    v_flex, IconName, Sizable as _,
+};
+
+use super::{Down, InputEvent, TextInput, Up};
+
+pub enum NumberInputEvent {
+    /// The value has been changed to a valid number.
+    Change(f64),
+    /// The Enter key was pressed, the value has been formatted.
+    PressEnter,
+    /// The input has lost focus, the value has been formatted.
+    Blur,
+}
+
+/// A numeric input with stepper buttons, the Up/Down keys also increment/decrement the value.
+///
+/// The value is clamped to `min..=max` and formatted on blur.
+pub struct NumberInput {
+    input: View<TextInput>,
+    min: f64,
+    max: f64,
+    step: f64,
+    precision: Option<usize>,
+    thousands_separator: bool,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl NumberInput {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let view = cx.view().downgrade();
+        let input = cx.new_view(|cx| {
+            TextInput::new(cx)
+                .pattern(regex::Regex::new(r"^-?[0-9,]*\.?[0-9]*$").unwrap())
+                .suffix(move |_| {
+                    let increment_view = view.clone();
+                    let decrement_view = view.clone();
+
+                    v_flex()
+                        .child(
+                            Button::new("increment")
+                                .icon(IconName::ChevronUp)
+                                .ghost()
+                                .with_size(px(14.))
+                                .on_click(move |_, cx| {
+                                    _ = increment_view.update(cx, |this, cx| this.increment(cx));
+                                }),
+                        )
+                        .child(
+                            Button::new("decrement")
+                                .icon(IconName::ChevronDown)
+                                .ghost()
+                                .with_size(px(14.))
+                                .on_click(move |_, cx| {
+                                    _ = decrement_view.update(cx, |this, cx| this.decrement(cx));
+                                }),
+                        )
+                })
+        });
+
+        let _subscriptions = vec![cx.subscribe(&input, Self::on_input_event)];
+
+        Self {
+            input,
+            min: f64::MIN,
+            max: f64::MAX,
+            step: 1.,
+            precision: None,
+            thousands_separator: false,
+            _subscriptions,
+        }
+    }
+
+    /// Set the minimum value, default is `f64::MIN`.
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Set the maximum value, default is `f64::MAX`.
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Set the step used by the stepper buttons and the Up/Down keys, default is 1.
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Set the number of decimals to display when formatting the value.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Set true to format the value with thousands separators, e.g.: `1,234,567`.
+    pub fn thousands_separator(mut self, thousands_separator: bool) -> Self {
+        self.thousands_separator = thousands_separator;
+        self
+    }
+
+    /// Set the placeholder text of the input field.
+    pub fn set_placeholder(
+        &mut self,
+        placeholder: impl Into<SharedString>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let placeholder = placeholder.into();
+        self.input.update(cx, |input, cx| {
+            input.set_placeholder(placeholder);
+            cx.notify();
+        });
+    }
+
+    /// Return the current value, or `None` if the text is empty or not a valid number.
+    pub fn value(&self, cx: &AppContext) -> Option<f64> {
+        parse_number(&self.input.read(cx).text())
+    }
+
+    /// Set the value, it will be clamped and formatted.
+    pub fn set_value(&mut self, value: f64, cx: &mut ViewContext<Self>) {
+        let text = format_number(self.clamp(value), self.precision, self.thousands_separator);
+        self.input.update(cx, |input, cx| input.set_text(text, cx));
+    }
+
+    /// Increment the value by the step.
+    pub fn increment(&mut self, cx: &mut ViewContext<Self>) {
+        let value = self.value(cx).unwrap_or(0.) + self.step;
+        self.set_value(value, cx);
+    }
+
+    /// Decrement the value by the step.
+    pub fn decrement(&mut self, cx: &mut ViewContext<Self>) {
+        let value = self.value(cx).unwrap_or(0.) - self.step;
+        self.set_value(value, cx);
+    }
+
+    fn clamp(&self, value: f64) -> f64 {
+        value.max(self.min).min(self.max)
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: View<TextInput>,
+        event: &InputEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            InputEvent::Change(text) => {
+                if let Some(value) = parse_number(text) {
+                    cx.emit(NumberInputEvent::Change(value));
+                }
+            }
+            InputEvent::Blur | InputEvent::PressEnter => {
+                if let Some(value) = self.value(cx) {
+                    self.set_value(value, cx);
+                }
+                cx.emit(match event {
+                    InputEvent::Blur => NumberInputEvent::Blur,
+                    _ => NumberInputEvent::PressEnter,
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn on_up(&mut self, _: &Up, cx: &mut ViewContext<Self>) {
+        self.increment(cx);
+    }
+
+    fn on_down(&mut self, _: &Down, cx: &mut ViewContext<Self>) {
+        self.decrement(cx);
+    }
+}
+
+/// Parse the number from the text, the thousands separators are ignored.
+fn parse_number(text: &str) -> Option<f64> {
+    let text = text.replace(',', "");
+    if text.is_empty() {
+        return None;
+    }
+
+    text.parse::<f64>().ok()
+}
+
+/// Format the number with the given decimals and optional thousands separators.
+pub(crate) fn format_number(
+    value: f64,
+    precision: Option<usize>,
+    thousands_separator: bool,
+) -> String {
+    let text = match precision {
+        Some(precision) => format!("{:.*}", precision, value),
+        None => value.to_string(),
+    };
+    if !thousands_separator {
+        return text;
+    }
+
+    let (sign, text) = match text.strip_prefix('-') {
+        Some(text) => ("-", text),
+        None => ("", text.as_str()),
+    };
+    let (integer, fraction) = text.split_at(text.find('.').unwrap_or(text.len()));
+
+    let mut formatted = String::with_capacity(text.len() + integer.len() / 3 + 1);
+    formatted.push_str(sign);
+    for (ix, c) in integer.chars().enumerate() {
+        if ix > 0 && (integer.len() - ix) % 3 == 0 {
+            formatted.push(',');
+        }
+        formatted.push(c);
+    }
+    formatted.push_str(fraction);
+    formatted
+}
+
+impl EventEmitter<NumberInputEvent> for NumberInput {}
+
+impl FocusableView for NumberInput {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.input.focus_handle(cx)
+    }
+}
+
+impl Render for NumberInput {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        div()
+            .flex()
+            .on_action(cx.listener(Self::on_up))
+            .on_action(cx.listener(Self::on_down))
+            .child(self.input.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_number, parse_number};
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(parse_number(""), None);
+        assert_eq!(parse_number("-"), None);
+        assert_eq!(parse_number("12.5"), Some(12.5));
+        assert_eq!(parse_number("-1,234,567.25"), Some(-1234567.25));
+    }
+
+    #[test]
+    fn test_format_number() {
+        assert_eq!(format_number(12., None, false), "12");
+        assert_eq!(format_number(12.346, Some(2), false), "12.35");
+        assert_eq!(format_number(1234567., None, true), "1,234,567");
+        assert_eq!(format_number(-1234.5, Some(2), true), "-1,234.50");
+        assert_eq!(format_number(123., Some(1), true), "123.0");
+    }
+}
@@ -107,6 +107,17 @@ impl OtpInput {
 
         let key = event.keystroke.key.as_str();
 
+        #[cfg(target_os = "macos")]
+        let is_paste = key == "v" && event.keystroke.modifiers.platform;
+        #[cfg(not(target_os = "macos"))]
+        let is_paste = key == "v" && event.keystroke.modifiers.control;
+        if is_paste {
+            self.paste(cx);
+            cx.prevent_default();
+            cx.stop_propagation();
+            return;
+        }
+
         match key {
             "backspace" => {
                 if ix > 0 {
@@ -134,8 +145,30 @@ impl OtpInput {
         }
 
         self.pause_blink_cursor(cx);
-        self.value = SharedString::from(chars.iter().collect::<String>());
+        self.update_value(chars.iter().collect::<String>().into(), cx);
+    }
+
+    /// Paste the digits from the clipboard, this allows to paste the full code at once.
+    fn paste(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(text) = cx.read_from_clipboard().and_then(|item| item.text()) else {
+            return;
+        };
+
+        let digits: String = self
+            .value
+            .chars()
+            .chain(text.chars().filter(|c| c.is_ascii_digit()))
+            .take(self.length)
+            .collect();
+
+        self.pause_blink_cursor(cx);
+        self.update_value(digits.into(), cx);
+    }
+
+    fn update_value(&mut self, value: SharedString, cx: &mut ViewContext<Self>) {
+        self.value = value;
 
+        // Emit the change event when the code is complete.
         if self.value.chars().count() == self.length {
             cx.emit(InputEvent::Change(self.value.clone()));
         }
@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use gpui::{
+    AppContext, EventEmitter, FocusHandle, FocusableView, IntoElement, Render, SharedString,
+    Subscription, Task, View, ViewContext, VisualContext as _,
+};
+use smol::Timer;
+
+use crate::IconName;
+
+use super::{InputEvent, TextInput};
+
+const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(300);
+
+pub enum SearchInputEvent {
+    /// The query has been changed and the user has stopped typing for the debounce interval,
+    /// or pressed Enter.
+    Search(SharedString),
+}
+
+/// A search input with a magnifier prefix and a clear button, the search event is debounced.
+pub struct SearchInput {
+    input: View<TextInput>,
+    debounce: Duration,
+    _search_task: Option<Task<()>>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl SearchInput {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let input = cx.new_view(|cx| {
+            TextInput::new(cx)
+                .prefix(|_| IconName::Search)
+                .placeholder("Search...")
+                .cleanable()
+        });
+
+        let _subscriptions = vec![cx.subscribe(&input, Self::on_input_event)];
+
+        Self {
+            input,
+            debounce: DEFAULT_DEBOUNCE,
+            _search_task: None,
+            _subscriptions,
+        }
+    }
+
+    /// Set the debounce interval of the search event, default is 300ms.
+    pub fn debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Set the placeholder text of the input field.
+    pub fn set_placeholder(
+        &mut self,
+        placeholder: impl Into<SharedString>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let placeholder = placeholder.into();
+        self.input.update(cx, |input, cx| {
+            input.set_placeholder(placeholder);
+            cx.notify();
+        });
+    }
+
+    /// Return the current query.
+    pub fn query(&self, cx: &AppContext) -> SharedString {
+        self.input.read(cx).text()
+    }
+
+    /// Set the query, the search event will be emitted after the debounce interval.
+    pub fn set_query(&mut self, query: impl Into<SharedString>, cx: &mut ViewContext<Self>) {
+        let query = query.into();
+        self.input.update(cx, |input, cx| input.set_text(query, cx));
+    }
+
+    fn on_input_event(
+        &mut self,
+        _: View<TextInput>,
+        event: &InputEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        match event {
+            // Clearing the query should show the full result immediately.
+            InputEvent::Change(query) if query.is_empty() => self.search(query.clone(), cx),
+            InputEvent::Change(query) => {
+                let query = query.clone();
+                let debounce = self.debounce;
+                self._search_task = Some(cx.spawn(|view, mut cx| async move {
+                    Timer::after(debounce).await;
+                    _ = view.update(&mut cx, |view, cx| view.search(query, cx));
+                }));
+            }
+            InputEvent::PressEnter => {
+                let query = self.query(cx);
+                self.search(query, cx);
+            }
+            _ => {}
+        }
+    }
+
+    fn search(&mut self, query: SharedString, cx: &mut ViewContext<Self>) {
+        self._search_task = None;
+        cx.emit(SearchInputEvent::Search(query));
+    }
+}
+
+impl EventEmitter<SearchInputEvent> for SearchInput {}
+
+impl FocusableView for SearchInput {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.input.focus_handle(cx)
+    }
+}
+
+impl Render for SearchInput {
+    fn render(&mut self, _: &mut ViewContext<Self>) -> impl IntoElement {
+        self.input.clone()
+    }
+}
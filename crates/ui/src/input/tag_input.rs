@@ -0,0 +1,201 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AppContext, EventEmitter, FocusHandle, FocusableView,
+    InteractiveElement as _, IntoElement, MouseButton, ParentElement as _, Render, SharedString,
+    Styled as _, Subscription, View, ViewContext, VisualContext as _,
+};
+
+use crate::{
+    button::{Button, ButtonStyled as _},
+    h_flex,
+    theme::ActiveTheme,
+    IconName, Sizable as _, StyledExt as _,
+};
+
+use super::{Backspace, InputEvent, TextInput};
+
+pub enum TagInputEvent {
+    /// The tags have been changed.
+    Change(Vec<SharedString>),
+}
+
+/// An input to edit a list of tags, press Enter or type a comma to create a tag,
+/// press Backspace in an empty input to remove the last tag.
+pub struct TagInput {
+    input: View<TextInput>,
+    tags: Vec<SharedString>,
+    max_tags: Option<usize>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl TagInput {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        let input = cx.new_view(|cx| TextInput::new(cx).appearance(false));
+        let _subscriptions = vec![cx.subscribe(&input, Self::on_input_event)];
+
+        Self {
+            input,
+            tags: vec![],
+            max_tags: None,
+            _subscriptions,
+        }
+    }
+
+    /// Set the maximum number of tags, the input will ignore new tags when the limit is reached.
+    pub fn max_tags(mut self, max_tags: usize) -> Self {
+        self.max_tags = Some(max_tags);
+        self
+    }
+
+    /// Set the placeholder text of the input field.
+    pub fn set_placeholder(
+        &mut self,
+        placeholder: impl Into<SharedString>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let placeholder = placeholder.into();
+        self.input.update(cx, |input, cx| {
+            input.set_placeholder(placeholder);
+            cx.notify();
+        });
+    }
+
+    /// Return the tags.
+    pub fn tags(&self) -> &[SharedString] {
+        &self.tags
+    }
+
+    /// Set the tags, the duplicated tags and the tags over the limit are ignored.
+    pub fn set_tags(&mut self, tags: Vec<SharedString>, cx: &mut ViewContext<Self>) {
+        self.tags.clear();
+        for tag in tags {
+            self.push_tag(tag);
+        }
+        cx.emit(TagInputEvent::Change(self.tags.clone()));
+        cx.notify();
+    }
+
+    /// Add a tag, returns false if the tag is empty, duplicated or the limit is reached.
+    pub fn add_tag(&mut self, tag: impl Into<SharedString>, cx: &mut ViewContext<Self>) -> bool {
+        if !self.push_tag(tag.into()) {
+            return false;
+        }
+
+        cx.emit(TagInputEvent::Change(self.tags.clone()));
+        cx.notify();
+        true
+    }
+
+    /// Remove the tag at the given index.
+    pub fn remove_tag(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if ix >= self.tags.len() {
+            return;
+        }
+
+        self.tags.remove(ix);
+        cx.emit(TagInputEvent::Change(self.tags.clone()));
+        cx.notify();
+    }
+
+    fn push_tag(&mut self, tag: SharedString) -> bool {
+        let tag: SharedString = tag.trim().to_string().into();
+        if tag.is_empty() || self.tags.contains(&tag) {
+            return false;
+        }
+        if let Some(max_tags) = self.max_tags {
+            if self.tags.len() >= max_tags {
+                return false;
+            }
+        }
+
+        self.tags.push(tag);
+        true
+    }
+
+    fn on_input_event(
+        &mut self,
+        input: View<TextInput>,
+        event: &InputEvent,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let text = match event {
+            InputEvent::Change(text) if text.contains(',') => text.clone(),
+            InputEvent::PressEnter => input.read(cx).text(),
+            _ => return,
+        };
+
+        let mut changed = false;
+        for tag in text.split(',') {
+            changed |= self.push_tag(tag.to_string().into());
+        }
+        input.update(cx, |input, cx| input.set_text("", cx));
+
+        if changed {
+            cx.emit(TagInputEvent::Change(self.tags.clone()));
+            cx.notify();
+        }
+    }
+
+    fn on_backspace(&mut self, _: &Backspace, cx: &mut ViewContext<Self>) {
+        if !self.input.read(cx).text().is_empty() || self.tags.is_empty() {
+            return;
+        }
+
+        cx.stop_propagation();
+        self.remove_tag(self.tags.len() - 1, cx);
+    }
+}
+
+impl EventEmitter<TagInputEvent> for TagInput {}
+
+impl FocusableView for TagInput {
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        self.input.focus_handle(cx)
+    }
+}
+
+impl Render for TagInput {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let focused = self.input.focus_handle(cx).is_focused(cx);
+
+        h_flex()
+            .id("tag-input")
+            .w_full()
+            .flex_wrap()
+            .gap_1()
+            .px_2()
+            .py_1()
+            .min_h_8()
+            .bg(cx.theme().background)
+            .border_1()
+            .border_color(cx.theme().input)
+            .rounded(px(cx.theme().radius))
+            .when(cx.theme().shadow, |this| this.shadow_sm())
+            .when(focused, |this| this.outline(cx))
+            // Capture the Backspace before the input to remove the last tag.
+            .capture_action(cx.listener(Self::on_backspace))
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(|this, _, cx| this.input.focus_handle(cx).focus(cx)),
+            )
+            .children(self.tags.iter().enumerate().map(|(ix, tag)| {
+                h_flex()
+                    .id(("tag", ix))
+                    .gap_1()
+                    .pl_2()
+                    .pr_1()
+                    .rounded(px(cx.theme().radius))
+                    .bg(cx.theme().secondary)
+                    .text_color(cx.theme().secondary_foreground)
+                    .text_sm()
+                    .child(tag.clone())
+                    .child(
+                        Button::new(("remove-tag", ix))
+                            .icon(IconName::Close)
+                            .ghost()
+                            .with_size(px(12.))
+                            .on_click(cx.listener(move |this, _, cx| this.remove_tag(ix, cx))),
+                    )
+            }))
+            .child(div().flex_1().min_w(px(80.)).child(self.input.clone()))
+    }
+}
@@ -0,0 +1,88 @@
+use std::ops::Range;
+use std::rc::Rc;
+
+use gpui::{SharedString, WindowContext};
+
+/// A trigger character of a [`super::TextInput`], e.g.: `@` for mentions or `/` for commands.
+#[derive(Clone)]
+pub(crate) struct Trigger {
+    pub(crate) char: char,
+    /// Returns the candidates for the query typed after the trigger character.
+    pub(crate) provider: Rc<dyn Fn(&str, &mut WindowContext) -> Vec<SharedString>>,
+}
+
+/// The trigger that is being typed at the cursor.
+pub(crate) struct ActiveTrigger {
+    pub(crate) char: char,
+    /// The offset of the trigger character.
+    pub(crate) start: usize,
+    pub(crate) items: Vec<SharedString>,
+    pub(crate) selected_index: usize,
+}
+
+/// Find the trigger of the word before the cursor, returns the trigger character and its offset.
+///
+/// The trigger character must be at the start of the word, so `user@example` is not a mention.
+pub(crate) fn trigger_at(text: &str, cursor: usize, chars: &[char]) -> Option<(char, usize)> {
+    let word_start = text[..cursor]
+        .char_indices()
+        .rev()
+        .find(|(_, c)| c.is_whitespace())
+        .map_or(0, |(ix, c)| ix + c.len_utf8());
+
+    let c = text[word_start..cursor].chars().next()?;
+    chars.contains(&c).then_some((c, word_start))
+}
+
+/// Update the token ranges after replacing the `range` with a text of `new_len`.
+///
+/// The tokens overlapped with the range are removed, the tokens after the range are shifted.
+pub(crate) fn shift_tokens(tokens: &mut Vec<Range<usize>>, range: &Range<usize>, new_len: usize) {
+    tokens.retain(|token| {
+        if range.is_empty() {
+            !(token.start < range.start && range.start < token.end)
+        } else {
+            token.end <= range.start || token.start >= range.end
+        }
+    });
+
+    for token in tokens.iter_mut() {
+        if token.start >= range.end {
+            token.start = token.start - range.end + range.start + new_len;
+            token.end = token.end - range.end + range.start + new_len;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{shift_tokens, trigger_at};
+
+    #[test]
+    fn test_trigger_at() {
+        let chars = ['@', '/'];
+        assert_eq!(trigger_at("", 0, &chars), None);
+        assert_eq!(trigger_at("@", 1, &chars), Some(('@', 0)));
+        assert_eq!(trigger_at("hello @jo", 9, &chars), Some(('@', 6)));
+        assert_eq!(trigger_at("hello @jo", 8, &chars), Some(('@', 6)));
+        assert_eq!(trigger_at("hello @jo ", 10, &chars), None);
+        assert_eq!(trigger_at("user@example", 12, &chars), None);
+        assert_eq!(trigger_at("line\n/cmd", 9, &chars), Some(('/', 5)));
+    }
+
+    #[test]
+    fn test_shift_tokens() {
+        let mut tokens = vec![0..5, 10..15];
+        shift_tokens(&mut tokens, &(6..6), 2);
+        assert_eq!(tokens, vec![0..5, 12..17]);
+
+        shift_tokens(&mut tokens, &(5..5), 1);
+        assert_eq!(tokens, vec![0..5, 13..18]);
+
+        shift_tokens(&mut tokens, &(2..2), 1);
+        assert_eq!(tokens, vec![14..19]);
+
+        shift_tokens(&mut tokens, &(16..17), 0);
+        assert_eq!(tokens, vec![]);
+    }
+}
@@ -0,0 +1,188 @@
+use gpui::{
+    div, Action, IntoElement, Keystroke, ParentElement as _, RenderOnce, Styled as _, WindowContext,
+};
+
+use crate::{h_flex, theme::ActiveTheme as _};
+
+/// Return the label of the key in the platform convention, e.g.: "⏎" on macOS, "Enter" on others.
+fn format_key(key: &str, mac: bool) -> String {
+    let label = match (key, mac) {
+        ("enter", true) => "⏎",
+        ("enter", false) => "Enter",
+        ("escape", true) => "⎋",
+        ("escape", false) => "Esc",
+        ("backspace", true) => "⌫",
+        ("backspace", false) => "Backspace",
+        ("delete", true) => "⌦",
+        ("delete", false) => "Delete",
+        ("tab", true) => "⇥",
+        ("tab", false) => "Tab",
+        ("space", _) => "Space",
+        ("up", _) => "↑",
+        ("down", _) => "↓",
+        ("left", _) => "←",
+        ("right", _) => "→",
+        ("pageup", _) => "PageUp",
+        ("pagedown", _) => "PageDown",
+        ("home", _) => "Home",
+        ("end", _) => "End",
+        _ => {
+            let mut chars = key.chars();
+            return match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            };
+        }
+    };
+
+    label.to_string()
+}
+
+fn format_keystroke_for(keystroke: &Keystroke, mac: bool) -> String {
+    let modifiers = &keystroke.modifiers;
+    let key = format_key(&keystroke.key, mac);
+
+    if mac {
+        let mut label = String::new();
+        if modifiers.function {
+            label.push_str("fn");
+        }
+        if modifiers.control {
+            label.push('⌃');
+        }
+        if modifiers.alt {
+            label.push('⌥');
+        }
+        if modifiers.shift {
+            label.push('⇧');
+        }
+        if modifiers.platform {
+            label.push('⌘');
+        }
+        label.push_str(&key);
+        label
+    } else {
+        let mut parts = vec![];
+        if modifiers.function {
+            parts.push("Fn");
+        }
+        if modifiers.control {
+            parts.push("Ctrl");
+        }
+        if modifiers.alt {
+            parts.push("Alt");
+        }
+        if modifiers.shift {
+            parts.push("Shift");
+        }
+        if modifiers.platform {
+            parts.push(if cfg!(target_os = "windows") {
+                "Win"
+            } else {
+                "Super"
+            });
+        }
+        parts.push(&key);
+        parts.join("+")
+    }
+}
+
+/// Return the label of the keystroke in the platform convention,
+/// e.g.: `cmd-shift-p` is "⌘⇧P" on macOS and "Ctrl+Shift+P" on others.
+pub fn format_keystroke(keystroke: &Keystroke) -> String {
+    format_keystroke_for(keystroke, cfg!(target_os = "macos"))
+}
+
+/// Return the label of the keystrokes of a key binding, separated by the space.
+pub fn format_keystrokes(keystrokes: &[Keystroke]) -> String {
+    keystrokes
+        .iter()
+        .map(format_keystroke)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A keyboard shortcut in the keycap style.
+#[derive(IntoElement, Clone)]
+pub struct Kbd {
+    keystrokes: Vec<Keystroke>,
+    appearance: bool,
+}
+
+impl Kbd {
+    pub fn new(keystroke: Keystroke) -> Self {
+        Self::keystrokes(vec![keystroke])
+    }
+
+    /// Create with the keystrokes of a key binding, e.g.: `cmd-k cmd-s`.
+    pub fn keystrokes(keystrokes: Vec<Keystroke>) -> Self {
+        Self {
+            keystrokes,
+            appearance: true,
+        }
+    }
+
+    /// Create with the first key binding of the action, return None if the action is not bound.
+    pub fn binding_for_action(action: &dyn Action, cx: &WindowContext) -> Option<Self> {
+        let binding = cx.bindings_for_action(action).into_iter().next()?;
+        Some(Self::keystrokes(binding.keystrokes().to_vec()))
+    }
+
+    /// Set false to render as the plain text without the keycap, default: true.
+    pub fn appearance(mut self, appearance: bool) -> Self {
+        self.appearance = appearance;
+        self
+    }
+}
+
+impl RenderOnce for Kbd {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        if !self.appearance {
+            return div()
+                .text_color(cx.theme().muted_foreground)
+                .child(format_keystrokes(&self.keystrokes));
+        }
+
+        h_flex()
+            .gap_1()
+            .children(self.keystrokes.iter().map(|keystroke| {
+                div()
+                    .flex_shrink_0()
+                    .px_1()
+                    .min_w_5()
+                    .text_center()
+                    .rounded_sm()
+                    .border_1()
+                    .border_b_2()
+                    .border_color(cx.theme().border)
+                    .bg(cx.theme().muted)
+                    .text_xs()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(format_keystroke(keystroke))
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::Keystroke;
+
+    use super::format_keystroke_for;
+
+    #[test]
+    fn test_format_keystroke() {
+        let keystroke = Keystroke::parse("cmd-shift-p").unwrap();
+        assert_eq!(format_keystroke_for(&keystroke, true), "⌘⇧P");
+
+        let keystroke = Keystroke::parse("ctrl-shift-p").unwrap();
+        assert_eq!(format_keystroke_for(&keystroke, false), "Ctrl+Shift+P");
+
+        let keystroke = Keystroke::parse("alt-enter").unwrap();
+        assert_eq!(format_keystroke_for(&keystroke, true), "⌥⏎");
+        assert_eq!(format_keystroke_for(&keystroke, false), "Alt+Enter");
+        assert_eq!(
+            format_keystroke_for(&Keystroke::parse("f12").unwrap(), false),
+            "F12"
+        );
+    }
+}
@@ -1,6 +1,8 @@
+use std::ops::Range;
+
 use gpui::{
-    div, prelude::FluentBuilder, rems, Div, IntoElement, ParentElement, RenderOnce, SharedString,
-    Styled, WindowContext,
+    div, prelude::FluentBuilder, rems, AnyElement, Div, FontWeight, HighlightStyle, IntoElement,
+    ParentElement, RenderOnce, SharedString, Styled, StyledText, WindowContext,
 };
 
 use crate::{h_flex, theme::ActiveTheme};
@@ -19,6 +21,7 @@ pub struct Label {
     label: SharedString,
     align: TextAlign,
     marked: bool,
+    highlights: Vec<Range<usize>>,
 }
 
 impl Label {
@@ -28,6 +31,7 @@ impl Label {
             label: label.into(),
             align: TextAlign::default(),
             marked: false,
+            highlights: vec![],
         }
     }
 
@@ -55,6 +59,12 @@ impl Label {
         self.marked = masked;
         self
     }
+
+    /// Highlight the text in the byte ranges, e.g.: the matched ranges of [`crate::list::fuzzy_match`].
+    pub fn highlights(mut self, ranges: impl Into<Vec<Range<usize>>>) -> Self {
+        self.highlights = ranges.into();
+        self
+    }
 }
 
 impl Styled for Label {
@@ -69,10 +79,22 @@ impl RenderOnce for Label {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
         let text = self.label;
 
-        let text_display = if self.marked {
-            MASKED.repeat(text.chars().count())
+        let text_display: AnyElement = if self.marked {
+            MASKED.repeat(text.chars().count()).into_any_element()
+        } else if !self.highlights.is_empty() {
+            let highlight = HighlightStyle {
+                color: Some(cx.theme().primary),
+                font_weight: Some(FontWeight::BOLD),
+                ..Default::default()
+            };
+            StyledText::new(text)
+                .with_highlights(
+                    &cx.text_style(),
+                    self.highlights.into_iter().map(|range| (range, highlight)),
+                )
+                .into_any_element()
         } else {
-            text.to_string()
+            text.into_any_element()
         };
 
         div().text_color(cx.theme().foreground).child(
@@ -2,44 +2,73 @@ mod colors;
 mod event;
 mod focusable;
 mod icon;
+mod overflow;
 mod root;
 mod styled;
 mod svg_img;
 mod time;
 
+pub mod alert;
 pub mod animation;
+pub mod avatar;
+pub mod breadcrumb;
 pub mod button;
 pub mod button_group;
+pub mod carousel;
+pub mod cascader;
+pub mod chart;
 pub mod checkbox;
 pub mod clipboard;
 pub mod color_picker;
+pub mod combobox;
+pub mod command_palette;
 pub mod context_menu;
+pub mod dialog;
 pub mod divider;
 pub mod dock;
 pub mod drawer;
 pub mod dropdown;
+pub mod empty_state;
+pub mod form;
 pub mod history;
+pub mod image_viewer;
 pub mod indicator;
 pub mod input;
+pub mod kbd;
 pub mod label;
 pub mod link;
 pub mod list;
+pub mod markdown;
 pub mod modal;
+pub mod multi_select;
 pub mod notification;
+pub mod pagination;
+pub mod popconfirm;
 pub mod popover;
 pub mod popup_menu;
 pub mod prelude;
 pub mod progress;
 pub mod radio;
+pub mod rating;
 pub mod resizable;
+pub mod result;
 pub mod scroll;
+pub mod segmented_control;
+pub mod sidebar;
 pub mod skeleton;
 pub mod slider;
+pub mod spinner;
+pub mod statistic;
+pub mod steps;
 pub mod switch;
 pub mod tab;
+pub mod tag;
 pub mod table;
 pub mod theme;
+pub mod toolbar;
 pub mod tooltip;
+pub mod transfer;
+pub mod tree;
 pub mod webview;
 
 // re-export
@@ -48,7 +77,7 @@ pub use wry;
 pub use crate::Disableable;
 pub use event::InteractiveElementExt;
 pub use focusable::FocusableCycle;
-pub use root::{ContextModal, Root};
+pub use root::{ContextModal, ModalStack, Root};
 pub use styled::*;
 pub use time::*;
 
@@ -59,16 +88,28 @@ pub use svg_img::*;
 /// Initialize the UI module.
 pub fn init(cx: &mut gpui::AppContext) {
     theme::init(cx);
+    carousel::init(cx);
+    cascader::init(cx);
+    checkbox::init(cx);
+    combobox::init(cx);
     context_menu::init(cx);
     date_picker::init(cx);
     dock::init(cx);
     dropdown::init(cx);
+    image_viewer::init(cx);
     input::init(cx);
     list::init(cx);
     modal::init(cx);
+    multi_select::init(cx);
+    popconfirm::init(cx);
     popover::init(cx);
     popup_menu::init(cx);
+    radio::init(cx);
+    segmented_control::init(cx);
+    slider::init(cx);
+    tab::init(cx);
     table::init(cx);
+    tree::init(cx);
     webview::init(cx);
 }
 
@@ -57,14 +57,14 @@ impl RenderOnce for Link {
             .text_decoration_1()
             .text_decoration_color(cx.theme().link)
             .hover(|this| {
-                this.text_color(cx.theme().link.opacity(0.8))
+                this.text_color(cx.theme().translucent(cx.theme().link, 0.8))
                     .text_decoration_1()
             })
             .cursor_pointer()
             .child(
                 self.base
                     .active(|this| {
-                        this.text_color(cx.theme().link.opacity(0.6))
+                        this.text_color(cx.theme().translucent(cx.theme().link, 0.6))
                             .text_decoration_1()
                     })
                     .on_mouse_down(MouseButton::Left, |_, cx| {
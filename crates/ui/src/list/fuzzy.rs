@@ -0,0 +1,95 @@
+use std::ops::Range;
+
+/// The result of [`fuzzy_match`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// The higher score means the better match.
+    pub score: i64,
+    /// The byte ranges of the matched characters in the text.
+    pub ranges: Vec<Range<usize>>,
+}
+
+/// Match the query characters in order against the text (case insensitive).
+///
+/// Return None if the text doesn't contain all the query characters. The whitespaces in the query are ignored.
+///
+/// The score prefers consecutive matches and matches at the start of words.
+pub fn fuzzy_match(query: &str, text: &str) -> Option<FuzzyMatch> {
+    let mut query_chars = query.chars().filter(|c| !c.is_whitespace()).peekable();
+    let mut ranges: Vec<Range<usize>> = vec![];
+    let mut score = 0;
+    let mut prev_char: Option<char> = None;
+    let mut prev_matched = false;
+
+    for (ix, ch) in text.char_indices() {
+        let Some(&query_char) = query_chars.peek() else {
+            break;
+        };
+
+        if ch.to_lowercase().eq(query_char.to_lowercase()) {
+            query_chars.next();
+            score += 1;
+
+            let word_start = match prev_char {
+                None => true,
+                Some(prev) => !prev.is_alphanumeric() || (prev.is_lowercase() && ch.is_uppercase()),
+            };
+            if word_start {
+                score += 3;
+            }
+
+            let end = ix + ch.len_utf8();
+            match ranges.last_mut() {
+                Some(range) if prev_matched => {
+                    range.end = end;
+                    score += 5;
+                }
+                _ => ranges.push(ix..end),
+            }
+            prev_matched = true;
+        } else {
+            prev_matched = false;
+        }
+        prev_char = Some(ch);
+    }
+
+    if query_chars.peek().is_some() {
+        return None;
+    }
+
+    // Each gap between the matched ranges lowers the score.
+    score -= ranges.len().saturating_sub(1) as i64;
+
+    Some(FuzzyMatch { score, ranges })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fuzzy_match;
+
+    #[test]
+    fn test_fuzzy_match() {
+        let m = fuzzy_match("lst", "List").unwrap();
+        assert_eq!(m.ranges, vec![0..1, 2..4]);
+
+        let m = fuzzy_match("open file", "Open File").unwrap();
+        assert_eq!(m.ranges, vec![0..4, 5..9]);
+
+        assert_eq!(fuzzy_match("abc", "ab"), None);
+        assert_eq!(fuzzy_match("ba", "abc"), None);
+        assert_eq!(fuzzy_match("", "abc").unwrap().ranges, vec![]);
+
+        // Multi-byte characters
+        let m = fuzzy_match("中文", "中的文字").unwrap();
+        assert_eq!(m.ranges, vec![0..3, 6..9]);
+
+        // Prefer the consecutive and word start matches.
+        let consecutive = fuzzy_match("fo", "foo").unwrap();
+        let scattered = fuzzy_match("fo", "afxo").unwrap();
+        assert!(consecutive.score > scattered.score);
+
+        let word_start = fuzzy_match("sf", "save_file").unwrap();
+        let middle = fuzzy_match("sf", "isofix").unwrap();
+        assert!(word_start.score > middle.score);
+    }
+}
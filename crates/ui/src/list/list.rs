@@ -1,21 +1,37 @@
+use std::ops::Range;
 use std::time::Duration;
 use std::{cell::Cell, rc::Rc};
 
+use super::fuzzy_match;
 use crate::{
+    h_flex,
     input::{InputEvent, TextInput},
-    scroll::{Scrollbar, ScrollbarState},
+    scroll::{ScrollHandleOffsetable as _, Scrollbar, ScrollbarState},
     theme::ActiveTheme,
-    v_flex, IconName, Size,
+    v_flex, Icon, IconName, Size,
 };
 use gpui::{
-    actions, div, prelude::FluentBuilder, uniform_list, AnyElement, AppContext, Entity,
-    FocusHandle, FocusableView, InteractiveElement, IntoElement, KeyBinding, Length,
-    ListSizingBehavior, MouseButton, ParentElement, Render, SharedString, Styled, Task,
-    UniformListScrollHandle, View, ViewContext, VisualContext, WindowContext,
+    actions, div, point, prelude::FluentBuilder, px, uniform_list, AnyElement, AppContext, Bounds,
+    DragMoveEvent, Entity, EntityId, EventEmitter, FocusHandle, FocusableView, InteractiveElement,
+    IntoElement, KeyBinding, Length, ListSizingBehavior, MouseButton, ParentElement, Pixels, Point,
+    Render, SharedString, StatefulInteractiveElement as _, Styled, Task, UniformListScrollHandle,
+    View, ViewContext, VisualContext, WindowContext,
 };
 use smol::Timer;
 
-actions!(list, [Cancel, Confirm, SelectPrev, SelectNext]);
+actions!(
+    list,
+    [
+        Cancel,
+        Confirm,
+        SelectPrev,
+        SelectNext,
+        SelectFirst,
+        SelectLast,
+        SelectPageUp,
+        SelectPageDown
+    ]
+);
 
 pub fn init(cx: &mut AppContext) {
     let context: Option<&str> = Some("List");
@@ -24,9 +40,51 @@ pub fn init(cx: &mut AppContext) {
         KeyBinding::new("enter", Confirm, context),
         KeyBinding::new("up", SelectPrev, context),
         KeyBinding::new("down", SelectNext, context),
+        KeyBinding::new("home", SelectFirst, context),
+        KeyBinding::new("end", SelectLast, context),
+        KeyBinding::new("pageup", SelectPageUp, context),
+        KeyBinding::new("pagedown", SelectPageDown, context),
     ]);
 }
 
+/// Events emitted by the List.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ListEvent {
+    /// The item at the index has been confirmed, e.g.: clicked or pressed Enter.
+    Confirm(usize),
+    /// The list has been cancelled, e.g.: pressed ESC.
+    Cancel,
+    /// The item has been moved by drag and drop, `to` is the new index of the item.
+    Move { from: usize, to: usize },
+}
+
+/// The distance to the edges of the list to start auto scrolling when dragging an item.
+const AUTO_SCROLL_EDGE: Pixels = px(24.);
+/// The distance to scroll for each auto scrolling step.
+const AUTO_SCROLL_STEP: Pixels = px(8.);
+
+#[derive(Clone)]
+pub(crate) struct DragListItem {
+    entity_id: EntityId,
+    ix: usize,
+    size: gpui::Size<Pixels>,
+}
+
+impl Render for DragListItem {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        h_flex()
+            .w(self.size.width)
+            .h(self.size.height)
+            .px_2()
+            .bg(cx.theme().list_active)
+            .border_1()
+            .border_color(cx.theme().drag_border)
+            .shadow_md()
+            .opacity(cx.theme().element_opacity(0.8))
+            .child(Icon::new(IconName::GripVertical).text_color(cx.theme().muted_foreground))
+    }
+}
+
 /// A delegate for the List.
 #[allow(unused)]
 pub trait ListDelegate: Sized + 'static {
@@ -46,6 +104,39 @@ pub trait ListDelegate: Sized + 'static {
     /// Return None will skip the item.
     fn render_item(&self, ix: usize, cx: &mut ViewContext<List<Self>>) -> Option<Self::Item>;
 
+    /// Return true if the item at the given index is a section header.
+    ///
+    /// Section headers are rendered by `render_item` like other items (so they must have the same height),
+    /// they can't be selected, and the header of the current section sticks to the top of the list while scrolling.
+    fn is_section_header(&self, ix: usize) -> bool {
+        false
+    }
+
+    /// Return true if the item at the given index is disabled, the disabled items can't be selected or confirmed.
+    fn is_item_disabled(&self, ix: usize) -> bool {
+        false
+    }
+
+    /// Return the text of the item for the built-in fuzzy filtering by [`List::filter`].
+    ///
+    /// Items return None are hidden while filtering, default is None for all items that means the filtering is disabled.
+    fn item_text(&self, ix: usize) -> Option<SharedString> {
+        None
+    }
+
+    /// Render the item with the matched character ranges of [`ListDelegate::item_text`] when the list is filtered,
+    /// so the label can highlight the matches, see [`crate::label::Label::highlights`].
+    ///
+    /// Default is to call `render_item`.
+    fn render_matched_item(
+        &self,
+        ix: usize,
+        ranges: &[Range<usize>],
+        cx: &mut ViewContext<List<Self>>,
+    ) -> Option<Self::Item> {
+        self.render_item(ix, cx)
+    }
+
     /// Return a Element to show when list is empty.
     fn render_empty(&self, cx: &mut ViewContext<List<Self>>) -> impl IntoElement {
         div()
@@ -75,6 +166,20 @@ pub trait ListDelegate: Sized + 'static {
 
     /// Cancel the selection, e.g.: Pressed ESC.
     fn cancel(&mut self, cx: &mut ViewContext<List<Self>>) {}
+
+    /// Return true if the item at the given index can be moved by drag and drop, only used when the list is reorderable.
+    fn can_move_item(&self, ix: usize) -> bool {
+        true
+    }
+
+    /// Move the item from the `from` index to the `to` index, the delegate should reorder its items here.
+    fn move_item(&mut self, from: usize, to: usize, cx: &mut ViewContext<List<Self>>) {}
+}
+
+/// The item matched by the filter query.
+struct ListMatch {
+    ix: usize,
+    ranges: Vec<Range<usize>>,
 }
 
 pub struct List<D: ListDelegate> {
@@ -92,6 +197,14 @@ pub struct List<D: ListDelegate> {
     pub(crate) size: Size,
     selected_index: Option<usize>,
     _search_task: Task<()>,
+
+    /// The filtered items by [`List::filter`], None if not filtered.
+    matches: Option<Vec<ListMatch>>,
+
+    reorderable: bool,
+    /// The bounds of the list and the mouse position of the current dragging item.
+    drag_position: Option<(Bounds<Pixels>, Point<Pixels>)>,
+    _auto_scroll_task: Task<()>,
 }
 
 impl<D> List<D>
@@ -123,6 +236,10 @@ where
             loading: false,
             size: Size::default(),
             _search_task: Task::Ready(None),
+            matches: None,
+            reorderable: false,
+            drag_position: None,
+            _auto_scroll_task: Task::Ready(None),
         }
     }
 
@@ -146,6 +263,12 @@ where
         self
     }
 
+    /// Set to allow reordering the items by dragging the drag handle, default is false.
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
     pub fn no_query(mut self) -> Self {
         self.query_input = None;
         self
@@ -169,15 +292,93 @@ where
         self.focus_handle(cx).focus(cx);
     }
 
+    /// Set the selected index of the displayed items, it's the same as the item index if the list is not filtered.
     pub fn set_selected_index(&mut self, ix: Option<usize>, cx: &mut ViewContext<Self>) {
         self.selected_index = ix;
-        self.delegate.set_selected_index(ix, cx);
+        let item_ix = ix.map(|ix| self.item_ix(ix));
+        self.delegate.set_selected_index(item_ix, cx);
     }
 
+    /// Return the selected index of the displayed items.
     pub fn selected_index(&self) -> Option<usize> {
         self.selected_index
     }
 
+    /// Filter the items by fuzzy matching the query with [`ListDelegate::item_text`], the best matches come first.
+    ///
+    /// An empty query clears the filter. This is called after `perform_search` when the query input changed.
+    pub fn filter(&mut self, query: &str, cx: &mut ViewContext<Self>) {
+        let query = query.trim();
+        let was_filtered = self.matches.is_some();
+        self.matches = if query.is_empty() {
+            None
+        } else {
+            let mut filterable = false;
+            let mut matches = vec![];
+            for ix in 0..self.delegate.items_count() {
+                if self.delegate.is_section_header(ix) {
+                    continue;
+                }
+                let Some(text) = self.delegate.item_text(ix) else {
+                    continue;
+                };
+
+                filterable = true;
+                if let Some(m) = fuzzy_match(query, &text) {
+                    matches.push((
+                        m.score,
+                        ListMatch {
+                            ix,
+                            ranges: m.ranges,
+                        },
+                    ));
+                }
+            }
+
+            // The sort is stable, so the items with the same score keep their order.
+            matches.sort_by(|(a, _), (b, _)| b.cmp(a));
+            filterable.then(|| matches.into_iter().map(|(_, m)| m).collect())
+        };
+
+        if was_filtered || self.matches.is_some() {
+            let selected_index = self.find_selectable(0..self.items_count());
+            self.set_selected_index(selected_index, cx);
+            self.vertical_scroll_handle.scroll_to_item(0);
+        }
+        cx.notify();
+    }
+
+    /// Return the number of the displayed items.
+    fn items_count(&self) -> usize {
+        match &self.matches {
+            Some(matches) => matches.len(),
+            None => self.delegate.items_count(),
+        }
+    }
+
+    /// Return the item index of the displayed item.
+    fn item_ix(&self, ix: usize) -> usize {
+        match &self.matches {
+            Some(matches) => matches.get(ix).map_or(ix, |m| m.ix),
+            None => ix,
+        }
+    }
+
+    /// Section headers are hidden when the list is filtered.
+    fn is_section_header(&self, ix: usize) -> bool {
+        self.matches.is_none() && self.delegate.is_section_header(ix)
+    }
+
+    fn render_item(&self, ix: usize, cx: &mut ViewContext<Self>) -> Option<D::Item> {
+        match &self.matches {
+            Some(matches) => {
+                let m = matches.get(ix)?;
+                self.delegate.render_matched_item(m.ix, &m.ranges, cx)
+            }
+            None => self.delegate.render_item(ix, cx),
+        }
+    }
+
     /// Set the query_input text
     pub fn set_query(&mut self, query: &str, cx: &mut ViewContext<Self>) {
         if let Some(query_input) = &self.query_input {
@@ -200,11 +401,32 @@ where
             cx.view().entity_id(),
             self.scrollbar_state.clone(),
             self.vertical_scroll_handle.clone(),
-            self.delegate.items_count(),
+            self.items_count(),
         ))
     }
 
-    fn scroll_to_selected_item(&mut self, _cx: &mut ViewContext<Self>) {
+    /// Select the item at the given index and scroll it into view.
+    fn select_item(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        self.set_selected_index(Some(ix), cx);
+        self.scroll_to_selected_item(cx);
+        cx.notify();
+    }
+
+    /// Return the number of items that fit in the visible area, at least 1.
+    fn page_size(&self) -> usize {
+        let state = self.vertical_scroll_handle.0.borrow();
+        let Some(item_height) = state.last_item_height else {
+            return 1;
+        };
+        if item_height <= px(0.) {
+            return 1;
+        }
+
+        let viewport_height = state.base_handle.bounds().size.height;
+        ((viewport_height / item_height).floor() as usize).max(1)
+    }
+
+    pub(crate) fn scroll_to_selected_item(&mut self, _cx: &mut ViewContext<Self>) {
         if let Some(ix) = self.selected_index {
             self.vertical_scroll_handle.scroll_to_item(ix);
         }
@@ -229,8 +451,9 @@ where
                 self._search_task = cx.spawn(|this, mut cx| async move {
                     search.await;
 
-                    let _ = this.update(&mut cx, |this, _| {
+                    let _ = this.update(&mut cx, |this, cx| {
                         this.vertical_scroll_handle.scroll_to_item(0);
+                        this.filter(&text, cx);
                         this.last_query = Some(text);
                     });
 
@@ -257,54 +480,374 @@ where
     fn on_action_cancel(&mut self, _: &Cancel, cx: &mut ViewContext<Self>) {
         self.set_selected_index(None, cx);
         self.delegate.cancel(cx);
+        cx.emit(ListEvent::Cancel);
         cx.notify();
     }
 
     fn on_action_confirm(&mut self, _: &Confirm, cx: &mut ViewContext<Self>) {
-        if self.delegate.items_count() == 0 {
+        if self.items_count() == 0 {
             return;
         }
+        if let Some(ix) = self.selected_index {
+            if !self.is_selectable(ix) {
+                return;
+            }
+        }
 
-        self.delegate.confirm(self.selected_index, cx);
+        let item_ix = self.selected_index.map(|ix| self.item_ix(ix));
+        self.delegate.confirm(item_ix, cx);
+        if let Some(ix) = item_ix {
+            cx.emit(ListEvent::Confirm(ix));
+        }
         cx.notify();
     }
 
+    /// Return true if the displayed item is not a section header or disabled.
+    fn is_selectable(&self, ix: usize) -> bool {
+        !self.is_section_header(ix) && !self.delegate.is_item_disabled(self.item_ix(ix))
+    }
+
+    /// Return the first selectable item in the given indices.
+    fn find_selectable(&self, mut indices: impl Iterator<Item = usize>) -> Option<usize> {
+        indices.find(|ix| self.is_selectable(*ix))
+    }
+
     fn on_action_select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
-        if self.delegate.items_count() == 0 {
+        let items_count = self.items_count();
+        let ix = self
+            .selected_index
+            .and_then(|ix| self.find_selectable((0..ix).rev()))
+            .or_else(|| self.find_selectable((0..items_count).rev()));
+
+        if let Some(ix) = ix {
+            self.select_item(ix, cx);
+        }
+    }
+
+    fn on_action_select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
+        let items_count = self.items_count();
+        let ix = self
+            .selected_index
+            .and_then(|ix| self.find_selectable(ix + 1..items_count))
+            .or_else(|| self.find_selectable(0..items_count));
+
+        if let Some(ix) = ix {
+            self.select_item(ix, cx);
+        }
+    }
+
+    fn on_action_select_first(&mut self, _: &SelectFirst, cx: &mut ViewContext<Self>) {
+        if let Some(ix) = self.find_selectable(0..self.items_count()) {
+            self.select_item(ix, cx);
+        }
+    }
+
+    fn on_action_select_last(&mut self, _: &SelectLast, cx: &mut ViewContext<Self>) {
+        if let Some(ix) = self.find_selectable((0..self.items_count()).rev()) {
+            self.select_item(ix, cx);
+        }
+    }
+
+    fn on_action_select_page_up(&mut self, _: &SelectPageUp, cx: &mut ViewContext<Self>) {
+        let items_count = self.items_count();
+        if items_count == 0 {
+            return;
+        }
+
+        let target = self
+            .selected_index
+            .unwrap_or(0)
+            .saturating_sub(self.page_size());
+        let ix = self
+            .find_selectable((0..=target).rev())
+            .or_else(|| self.find_selectable(target..items_count));
+
+        if let Some(ix) = ix {
+            self.select_item(ix, cx);
+        }
+    }
+
+    fn on_action_select_page_down(&mut self, _: &SelectPageDown, cx: &mut ViewContext<Self>) {
+        let items_count = self.items_count();
+        if items_count == 0 {
             return;
         }
 
-        let selected_index = self.selected_index.unwrap_or(0);
-        if selected_index > 0 {
-            self.selected_index = Some(selected_index - 1);
+        let target = match self.selected_index {
+            Some(ix) => (ix + self.page_size()).min(items_count - 1),
+            None => 0,
+        };
+        let ix = self
+            .find_selectable(target..items_count)
+            .or_else(|| self.find_selectable((0..=target).rev()));
+
+        if let Some(ix) = ix {
+            self.select_item(ix, cx);
+        }
+    }
+
+    /// Render the header of the section at the top of the viewport, it will be pushed up by the next section header.
+    fn render_sticky_header(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        let items_count = self.items_count();
+        let (item_height, scroll_top) = {
+            let state = self.vertical_scroll_handle.0.borrow();
+            (state.last_item_height?, -state.base_handle.offset().y)
+        };
+        if item_height <= px(0.) || scroll_top <= px(0.) {
+            return None;
+        }
+
+        let top_ix = (scroll_top / item_height).floor() as usize;
+        if top_ix >= items_count {
+            return None;
+        }
+        let header_ix = (0..=top_ix).rev().find(|ix| self.is_section_header(*ix))?;
+
+        let mut top = px(0.);
+        if top_ix + 1 < items_count && self.is_section_header(top_ix + 1) {
+            let next_top = item_height * (top_ix + 1) as f32 - scroll_top;
+            if next_top < item_height {
+                top = next_top - item_height;
+            }
+        }
+
+        Some(
+            div()
+                .absolute()
+                .top(top)
+                .left_0()
+                .right_0()
+                .bg(cx.theme().background)
+                .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+                .children(self.render_item(header_ix, cx)),
+        )
+    }
+}
+
+impl<D> List<D>
+where
+    D: ListDelegate,
+{
+    /// Return the index to insert the dragging item, in `0..=items_count`.
+    fn drop_index(&self) -> Option<usize> {
+        let (bounds, position) = self.drag_position?;
+        let item_height = self.vertical_scroll_handle.0.borrow().last_item_height?;
+        if item_height <= px(0.) {
+            return None;
+        }
+
+        let y = (position.y - bounds.top() - self.vertical_scroll_handle.offset().y).max(px(0.));
+        Some(((y / item_height).round() as usize).min(self.items_count()))
+    }
+
+    /// Return the distance to scroll when the dragging item is near the edges of the list.
+    fn auto_scroll_delta(&self) -> Option<Pixels> {
+        let (bounds, position) = self.drag_position?;
+        if position.y < bounds.top() + AUTO_SCROLL_EDGE {
+            Some(-AUTO_SCROLL_STEP)
+        } else if position.y > bounds.bottom() - AUTO_SCROLL_EDGE {
+            Some(AUTO_SCROLL_STEP)
         } else {
-            self.selected_index = Some(self.delegate.items_count() - 1);
+            None
         }
+    }
 
-        self.scroll_to_selected_item(cx);
+    fn on_item_drag_move(
+        &mut self,
+        event: &DragMoveEvent<DragListItem>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if event.drag(cx).entity_id != cx.entity_id() {
+            return;
+        }
+
+        let was_auto_scrolling = self.auto_scroll_delta().is_some();
+        if event.bounds.contains(&event.event.position) {
+            self.drag_position = Some((event.bounds, event.event.position));
+        } else {
+            self.drag_position = None;
+        }
+
+        if !was_auto_scrolling && self.auto_scroll_delta().is_some() {
+            self.start_auto_scroll(cx);
+        }
         cx.notify();
     }
 
-    fn on_action_select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
-        if self.delegate.items_count() == 0 {
+    /// Scroll the list while the dragging item is near the edges, until the drag ends or leaves the edges.
+    fn start_auto_scroll(&mut self, cx: &mut ViewContext<Self>) {
+        self._auto_scroll_task = cx.spawn(|this, mut cx| async move {
+            loop {
+                Timer::after(Duration::from_millis(16)).await;
+                let scrolling = this
+                    .update(&mut cx, |this, cx| {
+                        if !cx.has_active_drag() {
+                            this.drag_position = None;
+                            cx.notify();
+                            return false;
+                        }
+
+                        let Some(delta) = this.auto_scroll_delta() else {
+                            return false;
+                        };
+                        this.scroll_by(delta);
+                        cx.notify();
+                        true
+                    })
+                    .unwrap_or(false);
+
+                if !scrolling {
+                    break;
+                }
+            }
+        });
+    }
+
+    fn scroll_by(&mut self, delta: Pixels) {
+        let (item_height, viewport_height) = {
+            let state = self.vertical_scroll_handle.0.borrow();
+            let Some(item_height) = state.last_item_height else {
+                return;
+            };
+            (item_height, state.base_handle.bounds().size.height)
+        };
+
+        let max_offset = (item_height * self.items_count() as f32 - viewport_height).max(px(0.));
+        let offset = self.vertical_scroll_handle.offset();
+        let y = (offset.y - delta).min(px(0.)).max(-max_offset);
+        self.vertical_scroll_handle.set_offset(point(offset.x, y));
+    }
+
+    fn on_item_drop(&mut self, drag: &DragListItem, cx: &mut ViewContext<Self>) {
+        if drag.entity_id != cx.entity_id() {
             return;
         }
 
-        if let Some(selected_index) = self.selected_index {
-            if selected_index < self.delegate.items_count() - 1 {
-                self.selected_index = Some(selected_index + 1);
+        let drop_ix = self.drop_index();
+        self.drag_position = None;
+        if let Some(drop_ix) = drop_ix {
+            // The item is removed before inserting, so the index after it is shifted by one.
+            let to = if drop_ix > drag.ix {
+                drop_ix - 1
             } else {
-                self.selected_index = Some(0);
+                drop_ix
+            };
+            if to != drag.ix {
+                self.move_item(drag.ix, to, cx);
             }
-        } else {
-            self.selected_index = Some(0);
         }
-
-        self.scroll_to_selected_item(cx);
         cx.notify();
     }
+
+    fn move_item(&mut self, from: usize, to: usize, cx: &mut ViewContext<Self>) {
+        self.delegate.move_item(from, to, cx);
+        if let Some(ix) = self.selected_index {
+            self.set_selected_index(Some(moved_index(ix, from, to)), cx);
+        }
+        cx.emit(ListEvent::Move { from, to });
+    }
+
+    fn render_drag_handle(&self, ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let entity_id = cx.entity_id();
+        let list_width = self
+            .drag_position
+            .map(|(bounds, _)| bounds.size.width)
+            .unwrap_or(px(300.));
+
+        div()
+            .id(("list-drag-handle", ix))
+            .flex_shrink_0()
+            .px_1()
+            .cursor_grab()
+            .text_color(cx.theme().muted_foreground)
+            .child(Icon::new(IconName::GripVertical).size_4())
+            .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+            .on_drag(
+                DragListItem {
+                    entity_id,
+                    ix,
+                    size: gpui::size(
+                        list_width,
+                        self.vertical_scroll_handle
+                            .0
+                            .borrow()
+                            .last_item_height
+                            .unwrap_or(px(30.)),
+                    ),
+                },
+                |drag, cx| {
+                    cx.stop_propagation();
+                    cx.new_view(|_| drag.clone())
+                },
+            )
+    }
+
+    fn render_drop_indicator(&self, cx: &mut ViewContext<Self>) -> Option<impl IntoElement> {
+        if !cx.has_active_drag() {
+            return None;
+        }
+
+        let drop_ix = self.drop_index()?;
+        let item_height = self.vertical_scroll_handle.0.borrow().last_item_height?;
+        let top = item_height * drop_ix as f32 + self.vertical_scroll_handle.offset().y;
+
+        Some(
+            div()
+                .absolute()
+                .left_0()
+                .right_0()
+                .top(top - px(1.))
+                .h(px(2.))
+                .bg(cx.theme().drag_border),
+        )
+    }
+}
+
+/// Return the index of the previous or next enabled item from the `current`, wrapping around at the ends.
+///
+/// It starts from the first or the last item if there is no `current`, `None` if no item is enabled.
+pub(crate) fn step_index(
+    count: usize,
+    current: Option<usize>,
+    forward: bool,
+    enabled: impl Fn(usize) -> bool,
+) -> Option<usize> {
+    if count == 0 {
+        return None;
+    }
+
+    let start = match (current, forward) {
+        (Some(ix), _) => ix,
+        (None, true) => count - 1,
+        (None, false) => 0,
+    };
+
+    (1..=count)
+        .map(|step| {
+            if forward {
+                (start + step) % count
+            } else {
+                (start + count - step % count) % count
+            }
+        })
+        .find(|ix| enabled(*ix))
+}
+
+/// Return the new index of the item at `ix` after moving the item from `from` to `to`.
+pub(crate) fn moved_index(ix: usize, from: usize, to: usize) -> usize {
+    if ix == from {
+        to
+    } else if from < ix && ix <= to {
+        ix - 1
+    } else if to <= ix && ix < from {
+        ix + 1
+    } else {
+        ix
+    }
 }
 
+impl<D> EventEmitter<ListEvent> for List<D> where D: ListDelegate {}
+
 impl<D> FocusableView for List<D>
 where
     D: ListDelegate,
@@ -325,7 +868,7 @@ where
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let view = cx.view().clone();
         let vertical_scroll_handle = self.vertical_scroll_handle.clone();
-        let items_count = self.delegate.items_count();
+        let items_count = self.items_count();
         let sizing_behavior = if self.max_height.is_some() {
             ListSizingBehavior::Infer
         } else {
@@ -333,6 +876,7 @@ where
         };
 
         let selected_bg = cx.theme().list_active;
+        let hover_bg = cx.theme().list_hover;
 
         let inital_view = if let Some(input) = &self.query_input {
             if input.read(cx).text().is_empty() {
@@ -355,6 +899,10 @@ where
             .on_action(cx.listener(Self::on_action_confirm))
             .on_action(cx.listener(Self::on_action_select_next))
             .on_action(cx.listener(Self::on_action_select_prev))
+            .on_action(cx.listener(Self::on_action_select_first))
+            .on_action(cx.listener(Self::on_action_select_last))
+            .on_action(cx.listener(Self::on_action_select_page_up))
+            .on_action(cx.listener(Self::on_action_select_page_down))
             .when_some(self.query_input.clone(), |this, input| {
                 this.child(
                     div()
@@ -386,10 +934,51 @@ where
                                         move |list, visible_range, cx| {
                                             visible_range
                                                 .map(|ix| {
+                                                    let is_header = list.is_section_header(ix);
+                                                    let selectable = list.is_selectable(ix);
+
+                                                    let draggable = list.reorderable
+                                                        && list.matches.is_none()
+                                                        && !is_header
+                                                        && list.delegate.can_move_item(ix);
+
                                                     div()
                                                         .id("list-item")
                                                         .w_full()
-                                                        .children(list.delegate.render_item(ix, cx))
+                                                        .map(|this| {
+                                                            if !list.reorderable {
+                                                                return this.children(
+                                                                    list.render_item(ix, cx),
+                                                                );
+                                                            }
+
+                                                            this.flex()
+                                                                .items_center()
+                                                                .when(draggable, |this| {
+                                                                    this.child(
+                                                                        list.render_drag_handle(
+                                                                            ix, cx,
+                                                                        ),
+                                                                    )
+                                                                })
+                                                                .child(
+                                                                    div()
+                                                                        .flex_1()
+                                                                        .overflow_hidden()
+                                                                        .children(
+                                                                            list.render_item(
+                                                                                ix, cx,
+                                                                            ),
+                                                                        ),
+                                                                )
+                                                        })
+                                                        .when(
+                                                            selectable
+                                                                && list.selected_index != Some(ix),
+                                                            |this| {
+                                                                this.hover(|this| this.bg(hover_bg))
+                                                            },
+                                                        )
                                                         .when_some(
                                                             list.selected_index,
                                                             |this, selected_index| {
@@ -399,16 +988,21 @@ where
                                                                 )
                                                             },
                                                         )
-                                                        .on_mouse_down(
-                                                            MouseButton::Left,
-                                                            cx.listener(move |this, _, cx| {
-                                                                cx.stop_propagation();
-                                                                this.selected_index = Some(ix);
-                                                                this.on_action_confirm(
-                                                                    &Confirm, cx,
-                                                                );
-                                                            }),
-                                                        )
+                                                        .when(selectable, |this| {
+                                                            this.on_mouse_down(
+                                                                MouseButton::Left,
+                                                                cx.listener(move |this, _, cx| {
+                                                                    cx.stop_propagation();
+                                                                    this.set_selected_index(
+                                                                        Some(ix),
+                                                                        cx,
+                                                                    );
+                                                                    this.on_action_confirm(
+                                                                        &Confirm, cx,
+                                                                    );
+                                                                }),
+                                                            )
+                                                        })
                                                 })
                                                 .collect::<Vec<_>>()
                                         }
@@ -419,9 +1013,51 @@ where
                                     .into_any_element(),
                                 )
                             })
+                            .when(self.reorderable, |this| {
+                                this.on_drag_move(cx.listener(Self::on_item_drag_move))
+                                    .on_drop(cx.listener(Self::on_item_drop))
+                            })
+                            .children(self.render_sticky_header(cx))
+                            .children(self.render_drop_indicator(cx))
                             .children(self.render_scrollbar(cx)),
                     )
                 }
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{moved_index, step_index};
+
+    #[test]
+    fn test_step_index() {
+        let enabled = [true, false, true, true];
+        let enabled = |ix: usize| enabled[ix];
+        assert_eq!(step_index(4, Some(0), true, enabled), Some(2));
+        assert_eq!(step_index(4, Some(3), true, enabled), Some(0));
+        assert_eq!(step_index(4, Some(2), false, enabled), Some(0));
+        assert_eq!(step_index(4, Some(0), false, enabled), Some(3));
+        assert_eq!(step_index(4, None, true, enabled), Some(0));
+        assert_eq!(step_index(4, None, false, enabled), Some(3));
+        assert_eq!(step_index(2, Some(0), true, |_| false), None);
+        assert_eq!(step_index(0, None, true, |_| true), None);
+    }
+
+    #[test]
+    fn test_moved_index() {
+        // Move down: 1 -> 3
+        assert_eq!(moved_index(0, 1, 3), 0);
+        assert_eq!(moved_index(1, 1, 3), 3);
+        assert_eq!(moved_index(2, 1, 3), 1);
+        assert_eq!(moved_index(3, 1, 3), 2);
+        assert_eq!(moved_index(4, 1, 3), 4);
+
+        // Move up: 3 -> 1
+        assert_eq!(moved_index(0, 3, 1), 0);
+        assert_eq!(moved_index(1, 3, 1), 2);
+        assert_eq!(moved_index(2, 3, 1), 3);
+        assert_eq!(moved_index(3, 3, 1), 1);
+        assert_eq!(moved_index(4, 3, 1), 4);
+    }
+}
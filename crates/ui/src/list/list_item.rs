@@ -1,7 +1,7 @@
 use gpui::{
-    div, prelude::FluentBuilder as _, AnyElement, ClickEvent, Div, ElementId, InteractiveElement,
-    IntoElement, MouseButton, MouseMoveEvent, ParentElement, RenderOnce, SharedString, Stateful,
-    StatefulInteractiveElement as _, Styled, WindowContext,
+    div, prelude::FluentBuilder as _, px, AnyElement, ClickEvent, Div, ElementId,
+    InteractiveElement, IntoElement, MouseButton, MouseMoveEvent, ParentElement, RenderOnce,
+    SharedString, Stateful, StatefulInteractiveElement as _, Styled, WindowContext,
 };
 use smallvec::SmallVec;
 
@@ -24,7 +24,7 @@ pub struct ListItem {
 impl ListItem {
     pub fn new(id: impl Into<ElementId>) -> Self {
         Self {
-            base: h_flex().id(id.into()).gap_x_1().py_1().px_2().text_base(),
+            base: h_flex().id(id.into()).gap_x_1().px_2().text_base(),
             disabled: false,
             selected: false,
             confirmed: false,
@@ -120,8 +120,14 @@ impl RenderOnce for ListItem {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
         let is_active = self.selected || self.confirmed;
 
-        self.base
-            .when_some(self.group_id, |this, group_id| this.group(group_id))
+        // The default vertical padding follows the density, unless it's set by the caller.
+        let mut base = self.base;
+        let padding = &base.style().padding;
+        if padding.top.is_none() && padding.bottom.is_none() {
+            base = base.py(cx.theme().spacing(px(4.)));
+        }
+
+        base.when_some(self.group_id, |this, group_id| this.group(group_id))
             .text_color(cx.theme().foreground)
             .relative()
             .items_center()
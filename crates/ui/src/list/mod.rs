@@ -1,5 +1,9 @@
+mod fuzzy;
 mod list;
 mod list_item;
+mod type_ahead;
 
+pub use fuzzy::*;
 pub use list::*;
 pub use list_item::*;
+pub(crate) use type_ahead::*;
@@ -0,0 +1,123 @@
+use std::time::{Duration, Instant};
+
+use gpui::SharedString;
+
+/// The typed characters are reset after this duration without typing.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// The characters typed to select an item by the beginning of its title, e.g.: in the
+/// [`crate::dropdown::Dropdown`] and the [`crate::popup_menu::PopupMenu`].
+#[derive(Debug, Default)]
+pub(crate) struct TypeAhead {
+    query: String,
+    typed_at: Option<Instant>,
+}
+
+impl TypeAhead {
+    /// Add the typed key to the query, the query is reset after a pause of typing.
+    ///
+    /// Return false if the key is not a character, or it's a space at the start of the query.
+    pub(crate) fn push(&mut self, key: &str) -> bool {
+        let mut chars = key.chars();
+        let (Some(ch), None) = (chars.next(), chars.next()) else {
+            return false;
+        };
+
+        let now = Instant::now();
+        if self
+            .typed_at
+            .map_or(true, |at| now.duration_since(at) > TYPE_AHEAD_TIMEOUT)
+        {
+            self.query.clear();
+        }
+        if ch.is_control() || (ch.is_whitespace() && self.query.is_empty()) {
+            return false;
+        }
+
+        self.query.push(ch);
+        self.typed_at = Some(now);
+        true
+    }
+
+    /// Return the index of the item matches the query, see [`type_ahead_index`].
+    pub(crate) fn find(
+        &self,
+        current: Option<usize>,
+        count: usize,
+        title: impl Fn(usize) -> Option<SharedString>,
+    ) -> Option<usize> {
+        type_ahead_index(&self.query, current, count, title)
+    }
+}
+
+/// Return the index of the item whose title starts with the query (case insensitive), search from the `current` item and wrap around.
+///
+/// If the query is a repeated character, e.g.: "aa", search the next item starting with "a".
+///
+/// The `title` returns None for the items can't be selected.
+fn type_ahead_index(
+    query: &str,
+    current: Option<usize>,
+    count: usize,
+    title: impl Fn(usize) -> Option<SharedString>,
+) -> Option<usize> {
+    let query = query.to_lowercase();
+    let mut chars = query.chars();
+    let first = chars.next()?;
+    let (query, skip_current) = if chars.all(|c| c == first) {
+        (first.to_string(), true)
+    } else {
+        (query, false)
+    };
+
+    let start = match current {
+        Some(ix) if skip_current => ix + 1,
+        Some(ix) => ix,
+        None => 0,
+    };
+    (0..count)
+        .map(|i| (start + i) % count)
+        .find(|ix| title(*ix).map_or(false, |title| title.to_lowercase().starts_with(&query)))
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::SharedString;
+
+    use super::{type_ahead_index, TypeAhead};
+
+    #[test]
+    fn test_type_ahead_index() {
+        let items: Vec<Option<SharedString>> = vec![
+            Some("Apple".into()),
+            Some("Avocado".into()),
+            Some("Banana".into()),
+            None,
+            Some("Blueberry".into()),
+        ];
+        let title = |ix: usize| items[ix].clone();
+
+        assert_eq!(type_ahead_index("b", None, 5, title), Some(2));
+        assert_eq!(type_ahead_index("av", Some(0), 5, title), Some(1));
+        assert_eq!(type_ahead_index("AV", None, 5, title), Some(1));
+        // Repeated character cycles through the items, and skips the items can't be selected.
+        assert_eq!(type_ahead_index("b", Some(2), 5, title), Some(4));
+        assert_eq!(type_ahead_index("bb", Some(4), 5, title), Some(2));
+        assert_eq!(type_ahead_index("a", Some(1), 5, title), Some(0));
+        // Keep the current item if it still matches the longer query.
+        assert_eq!(type_ahead_index("ban", Some(2), 5, title), Some(2));
+        assert_eq!(type_ahead_index("c", None, 5, title), None);
+        assert_eq!(type_ahead_index("a", None, 0, title), None);
+    }
+
+    #[test]
+    fn test_type_ahead_push() {
+        let mut type_ahead = TypeAhead::default();
+        assert!(!type_ahead.push(" "));
+        assert!(!type_ahead.push("enter"));
+        assert!(type_ahead.push("s"));
+        assert!(type_ahead.push(" "));
+        assert!(type_ahead.push("A"));
+        assert_eq!(type_ahead.query, "s A");
+    }
+}
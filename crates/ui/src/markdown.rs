@@ -0,0 +1,604 @@
+use std::{iter::Peekable, ops::Range, rc::Rc};
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnyElement, Div, ElementId, FontStyle, FontWeight,
+    HighlightStyle, InteractiveElement as _, InteractiveText, IntoElement, ParentElement as _,
+    RenderOnce, SharedString, StatefulInteractiveElement as _, StrikethroughStyle, Styled,
+    StyledText, TextStyle, UnderlineStyle, WindowContext,
+};
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, Options, Parser, Tag};
+
+use crate::{h_flex, theme::ActiveTheme as _, v_flex};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct InlineStyle {
+    bold: bool,
+    italic: bool,
+    strikethrough: bool,
+    code: bool,
+    link: bool,
+}
+
+/// A run of the text with the inline styles, and the urls of the links in it.
+#[derive(Debug, Default, Clone, PartialEq)]
+struct Paragraph {
+    text: String,
+    styles: Vec<(Range<usize>, InlineStyle)>,
+    links: Vec<(Range<usize>, SharedString)>,
+}
+
+impl Paragraph {
+    fn push(&mut self, text: &str, style: InlineStyle, link: Option<&SharedString>) {
+        let range = self.text.len()..self.text.len() + text.len();
+        self.text.push_str(text);
+        if style != InlineStyle::default() {
+            self.styles.push((range.clone(), style));
+        }
+        if let Some(link) = link {
+            self.links.push((range, link.clone()));
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Block {
+    Heading(u8, Paragraph),
+    Paragraph(Paragraph),
+    CodeBlock {
+        lang: Option<SharedString>,
+        code: SharedString,
+    },
+    BlockQuote(Vec<Block>),
+    List {
+        start: Option<u64>,
+        items: Vec<Vec<Block>>,
+    },
+    Table {
+        alignments: Vec<Alignment>,
+        head: Vec<Paragraph>,
+        rows: Vec<Vec<Paragraph>>,
+    },
+    Rule,
+}
+
+fn parse(source: &str) -> Vec<Block> {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    parse_blocks(&mut Parser::new_ext(source, options).peekable())
+}
+
+fn is_inline(event: &Event) -> bool {
+    match event {
+        Event::Start(tag) => matches!(
+            tag,
+            Tag::Emphasis | Tag::Strong | Tag::Strikethrough | Tag::Link { .. } | Tag::Image { .. }
+        ),
+        Event::End(_) | Event::Rule | Event::Html(_) => false,
+        _ => true,
+    }
+}
+
+/// Parse the blocks until the end of the parent block, the end event is not consumed.
+fn parse_blocks<'a>(events: &mut Peekable<impl Iterator<Item = Event<'a>>>) -> Vec<Block> {
+    let mut blocks = Vec::new();
+
+    while let Some(event) = events.peek() {
+        if matches!(event, Event::End(_)) {
+            break;
+        }
+        // The text without the paragraph, e.g.: in the tight list items.
+        if is_inline(event) {
+            blocks.push(Block::Paragraph(parse_inlines(events)));
+            continue;
+        }
+
+        match events.next() {
+            Some(Event::Start(tag)) => {
+                let block = match tag {
+                    Tag::Paragraph => Some(Block::Paragraph(parse_inlines(events))),
+                    Tag::Heading { level, .. } => {
+                        Some(Block::Heading(level as u8, parse_inlines(events)))
+                    }
+                    Tag::BlockQuote(..) => Some(Block::BlockQuote(parse_blocks(events))),
+                    Tag::CodeBlock(kind) => {
+                        let mut code = String::new();
+                        while let Some(Event::Text(text)) = events.peek() {
+                            code.push_str(text);
+                            events.next();
+                        }
+                        let lang = match kind {
+                            CodeBlockKind::Fenced(lang) if !lang.is_empty() => {
+                                Some(lang.to_string().into())
+                            }
+                            _ => None,
+                        };
+                        Some(Block::CodeBlock {
+                            lang,
+                            code: code.trim_end_matches('\n').to_string().into(),
+                        })
+                    }
+                    Tag::List(start) => {
+                        let mut items = Vec::new();
+                        while let Some(Event::Start(Tag::Item)) = events.peek() {
+                            events.next();
+                            items.push(parse_blocks(events));
+                            events.next();
+                        }
+                        Some(Block::List { start, items })
+                    }
+                    Tag::Table(alignments) => Some(parse_table(alignments, events)),
+                    _ => {
+                        // Not supported, e.g.: the html blocks, footnotes.
+                        skip(events);
+                        None
+                    }
+                };
+                blocks.extend(block);
+                // The end of the block.
+                events.next();
+            }
+            Some(Event::Rule) => blocks.push(Block::Rule),
+            _ => {}
+        }
+    }
+
+    blocks
+}
+
+/// Parse the inline content until the end of the parent block, the end event is not consumed.
+fn parse_inlines<'a>(events: &mut Peekable<impl Iterator<Item = Event<'a>>>) -> Paragraph {
+    let mut paragraph = Paragraph::default();
+    let mut styles = vec![InlineStyle::default()];
+    let mut links: Vec<Option<SharedString>> = vec![None];
+
+    while let Some(event) = events.peek() {
+        // Stop at the end or the nested blocks of the parent.
+        match event {
+            Event::End(_) if styles.len() == 1 => break,
+            Event::End(_) => {}
+            _ if !is_inline(event) => break,
+            _ => {}
+        }
+
+        let style = *styles.last().unwrap();
+        let link = links.last().cloned().flatten();
+        match events.next() {
+            Some(Event::Start(tag)) => {
+                let mut style = style;
+                let mut link = link;
+                match tag {
+                    Tag::Strong => style.bold = true,
+                    Tag::Emphasis => style.italic = true,
+                    Tag::Strikethrough => style.strikethrough = true,
+                    Tag::Link { dest_url, .. } => {
+                        style.link = true;
+                        link = Some(dest_url.to_string().into());
+                    }
+                    // Show the alt text of the image.
+                    Tag::Image { .. } => style.italic = true,
+                    _ => {}
+                }
+                styles.push(style);
+                links.push(link);
+            }
+            Some(Event::End(_)) => {
+                styles.pop();
+                links.pop();
+            }
+            Some(Event::Text(text)) => paragraph.push(&text, style, link.as_ref()),
+            Some(Event::Code(text)) => paragraph.push(
+                &text,
+                InlineStyle {
+                    code: true,
+                    ..style
+                },
+                link.as_ref(),
+            ),
+            Some(Event::SoftBreak) => paragraph.push(" ", style, link.as_ref()),
+            Some(Event::HardBreak) => paragraph.push("\n", style, link.as_ref()),
+            Some(Event::TaskListMarker(checked)) => {
+                paragraph.push(if checked { "☑ " } else { "☐ " }, style, None)
+            }
+            _ => {}
+        }
+    }
+
+    paragraph
+}
+
+fn parse_table<'a>(
+    alignments: Vec<Alignment>,
+    events: &mut Peekable<impl Iterator<Item = Event<'a>>>,
+) -> Block {
+    let mut head = Vec::new();
+    let mut rows = Vec::new();
+
+    // The head and the rows, the cells are in the head directly.
+    while matches!(events.peek(), Some(Event::Start(_))) {
+        let is_head = matches!(events.next(), Some(Event::Start(Tag::TableHead)));
+        let mut cells = Vec::new();
+        while let Some(Event::Start(Tag::TableCell)) = events.peek() {
+            events.next();
+            cells.push(parse_inlines(events));
+            events.next();
+        }
+        events.next();
+
+        if is_head {
+            head = cells;
+        } else {
+            rows.push(cells);
+        }
+    }
+
+    Block::Table {
+        alignments,
+        head,
+        rows,
+    }
+}
+
+/// Skip the events until the end of the current block, the end event is not consumed.
+fn skip<'a>(events: &mut Peekable<impl Iterator<Item = Event<'a>>>) {
+    let mut depth = 0;
+    while let Some(event) = events.peek() {
+        match event {
+            Event::Start(_) => depth += 1,
+            Event::End(_) if depth == 0 => break,
+            Event::End(_) => depth -= 1,
+            _ => {}
+        }
+        events.next();
+    }
+}
+
+/// The blocks parsed from the markdown source.
+///
+/// Parse it once and keep it, e.g.: in the view, the [`Markdown`] is created on every render.
+#[derive(Debug, Clone)]
+pub struct ParsedMarkdown {
+    blocks: Rc<[Block]>,
+}
+
+impl ParsedMarkdown {
+    pub fn new(source: &str) -> Self {
+        Self {
+            blocks: parse(source).into(),
+        }
+    }
+}
+
+/// Render the CommonMark text, with the tables, strikethrough and task lists extensions.
+///
+/// The html and images are not supported, the alt text of the images is shown.
+#[derive(IntoElement)]
+pub struct Markdown {
+    id: ElementId,
+    base: Div,
+    markdown: ParsedMarkdown,
+    on_link_click: Option<Rc<dyn Fn(&SharedString, &mut WindowContext)>>,
+}
+
+impl Markdown {
+    pub fn new(id: impl Into<ElementId>, markdown: &ParsedMarkdown) -> Self {
+        Self {
+            id: id.into(),
+            base: v_flex(),
+            markdown: markdown.clone(),
+            on_link_click: None,
+        }
+    }
+
+    /// Set the handler of the link click with the url, default: open the url in the browser.
+    pub fn on_link_click(
+        mut self,
+        handler: impl Fn(&SharedString, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_link_click = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl Styled for Markdown {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+struct RenderState {
+    on_link_click: Option<Rc<dyn Fn(&SharedString, &mut WindowContext)>>,
+    /// The count of the rendered paragraphs, for the element ids.
+    paragraph_ix: usize,
+}
+
+impl RenderState {
+    fn render_paragraph(
+        &mut self,
+        paragraph: &Paragraph,
+        text_style: &TextStyle,
+        cx: &WindowContext,
+    ) -> AnyElement {
+        let theme = cx.theme();
+        let highlights = paragraph.styles.iter().map(|(range, style)| {
+            let mut highlight = HighlightStyle::default();
+            if style.bold {
+                highlight.font_weight = Some(FontWeight::BOLD);
+            }
+            if style.italic {
+                highlight.font_style = Some(FontStyle::Italic);
+            }
+            if style.strikethrough {
+                highlight.strikethrough = Some(StrikethroughStyle {
+                    thickness: px(1.),
+                    color: None,
+                });
+            }
+            if style.code {
+                highlight.background_color = Some(theme.muted);
+            }
+            if style.link {
+                highlight.color = Some(theme.link);
+                highlight.underline = Some(UnderlineStyle {
+                    thickness: px(1.),
+                    color: Some(theme.link),
+                    wavy: false,
+                });
+            }
+            (range.clone(), highlight)
+        });
+        let text = StyledText::new(paragraph.text.clone()).with_highlights(text_style, highlights);
+        if paragraph.links.is_empty() {
+            return text.into_any_element();
+        }
+
+        self.paragraph_ix += 1;
+        let (ranges, urls): (Vec<_>, Vec<_>) = paragraph.links.iter().cloned().unzip();
+        let on_link_click = self.on_link_click.clone();
+        InteractiveText::new(("markdown-paragraph", self.paragraph_ix), text)
+            .on_click(ranges, move |ix, cx| match &on_link_click {
+                Some(on_link_click) => on_link_click(&urls[ix], cx),
+                None => cx.open_url(&urls[ix]),
+            })
+            .into_any_element()
+    }
+
+    fn render_blocks(&mut self, blocks: &[Block], muted: bool, cx: &WindowContext) -> Div {
+        let mut text_style = cx.text_style();
+        text_style.color = if muted {
+            cx.theme().muted_foreground
+        } else {
+            cx.theme().foreground
+        };
+
+        v_flex().gap_3().children(
+            blocks
+                .iter()
+                .map(|block| self.render_block(block, &text_style, muted, cx)),
+        )
+    }
+
+    fn render_block(
+        &mut self,
+        block: &Block,
+        text_style: &TextStyle,
+        muted: bool,
+        cx: &WindowContext,
+    ) -> AnyElement {
+        match block {
+            Block::Heading(level, paragraph) => {
+                let mut text_style = text_style.clone();
+                text_style.font_weight = FontWeight::SEMIBOLD;
+                div()
+                    .map(|this| match level {
+                        1 => this.text_2xl().mt_2(),
+                        2 => this.text_xl().mt_2(),
+                        3 => this.text_lg().mt_1(),
+                        _ => this.text_base(),
+                    })
+                    .child(self.render_paragraph(paragraph, &text_style, cx))
+                    .into_any_element()
+            }
+            Block::Paragraph(paragraph) => div()
+                .child(self.render_paragraph(paragraph, text_style, cx))
+                .into_any_element(),
+            Block::CodeBlock { lang, code } => {
+                self.paragraph_ix += 1;
+                div()
+                    .id(("markdown-code", self.paragraph_ix))
+                    .relative()
+                    .p_3()
+                    .rounded_md()
+                    .bg(cx.theme().muted)
+                    .overflow_x_scroll()
+                    .font_family(cx.theme().mono_font_family.clone())
+                    .text_sm()
+                    .child(code.clone())
+                    .when_some(lang.clone(), |this, lang| {
+                        this.child(
+                            div()
+                                .absolute()
+                                .top_1()
+                                .right_2()
+                                .text_xs()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(lang),
+                        )
+                    })
+                    .into_any_element()
+            }
+            Block::BlockQuote(blocks) => div()
+                .pl_3()
+                .border_l_4()
+                .border_color(cx.theme().border)
+                .child(self.render_blocks(blocks, true, cx))
+                .into_any_element(),
+            Block::List { start, items } => v_flex()
+                .gap_1()
+                .children(items.iter().enumerate().map(|(ix, item)| {
+                    let marker = match *start {
+                        Some(start) => format!("{}.", start + ix as u64),
+                        None => "•".to_string(),
+                    };
+                    h_flex()
+                        .items_start()
+                        .gap_2()
+                        .child(
+                            div()
+                                .min_w_4()
+                                .flex_shrink_0()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(marker),
+                        )
+                        .child(self.render_blocks(item, muted, cx).gap_1().flex_1())
+                }))
+                .into_any_element(),
+            Block::Table {
+                alignments,
+                head,
+                rows,
+            } => {
+                let mut head_style = text_style.clone();
+                head_style.font_weight = FontWeight::SEMIBOLD;
+                let mut render_row = |cells: &[Paragraph], style: &TextStyle, is_head: bool| {
+                    h_flex()
+                        .when(is_head, |this| this.bg(cx.theme().muted))
+                        .children(cells.iter().enumerate().map(|(ix, cell)| {
+                            h_flex()
+                                .flex_1()
+                                .px_2()
+                                .py_1()
+                                .when(ix > 0, |this| {
+                                    this.border_l_1().border_color(cx.theme().border)
+                                })
+                                .map(|this| match alignments.get(ix) {
+                                    Some(Alignment::Center) => this.justify_center(),
+                                    Some(Alignment::Right) => this.justify_end(),
+                                    _ => this.justify_start(),
+                                })
+                                .child(self.render_paragraph(cell, style, cx))
+                        }))
+                };
+
+                let head = render_row(head, &head_style, true);
+                let rows = rows
+                    .iter()
+                    .map(|row| {
+                        render_row(row, text_style, false)
+                            .border_t_1()
+                            .border_color(cx.theme().border)
+                    })
+                    .collect::<Vec<_>>();
+
+                v_flex()
+                    .text_sm()
+                    .rounded_md()
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .overflow_hidden()
+                    .child(head)
+                    .children(rows)
+                    .into_any_element()
+            }
+            Block::Rule => div().h(px(1.)).bg(cx.theme().border).into_any_element(),
+        }
+    }
+}
+
+impl RenderOnce for Markdown {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let mut state = RenderState {
+            on_link_click: self.on_link_click,
+            paragraph_ix: 0,
+        };
+        let blocks = state.render_blocks(&self.markdown.blocks, false, cx);
+
+        self.base.id(self.id).child(blocks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Block, InlineStyle, Paragraph};
+
+    fn text(text: &str) -> Paragraph {
+        Paragraph {
+            text: text.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let blocks =
+            parse("# Title\n\nHello **world**, [docs](https://example.com).\n\n- a\n- b\n");
+        assert_eq!(blocks[0], Block::Heading(1, text("Title")));
+
+        let Block::Paragraph(paragraph) = &blocks[1] else {
+            panic!("expected a paragraph");
+        };
+        assert_eq!(paragraph.text, "Hello world, docs.");
+        assert_eq!(
+            paragraph.styles,
+            vec![
+                (
+                    6..11,
+                    InlineStyle {
+                        bold: true,
+                        ..Default::default()
+                    }
+                ),
+                (
+                    13..17,
+                    InlineStyle {
+                        link: true,
+                        ..Default::default()
+                    }
+                ),
+            ]
+        );
+        assert_eq!(
+            paragraph.links,
+            vec![(13..17, "https://example.com".into())]
+        );
+
+        assert_eq!(
+            blocks[2],
+            Block::List {
+                start: None,
+                items: vec![
+                    vec![Block::Paragraph(text("a"))],
+                    vec![Block::Paragraph(text("b"))]
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_nested() {
+        let blocks = parse("> quote\n\n1. one\n   - nested\n\n```rust\nfn main() {}\n```\n");
+        assert_eq!(
+            blocks[0],
+            Block::BlockQuote(vec![Block::Paragraph(text("quote"))])
+        );
+        assert_eq!(
+            blocks[1],
+            Block::List {
+                start: Some(1),
+                items: vec![vec![
+                    Block::Paragraph(text("one")),
+                    Block::List {
+                        start: None,
+                        items: vec![vec![Block::Paragraph(text("nested"))]],
+                    },
+                ]],
+            }
+        );
+        assert_eq!(
+            blocks[2],
+            Block::CodeBlock {
+                lang: Some("rust".into()),
+                code: "fn main() {}".into(),
+            }
+        );
+    }
+}
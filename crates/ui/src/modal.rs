@@ -2,25 +2,51 @@ use std::{rc::Rc, time::Duration};
 
 use gpui::{
     actions, anchored, div, hsla, prelude::FluentBuilder, px, relative, Animation,
-    AnimationExt as _, AnyElement, AppContext, Bounds, ClickEvent, Div, FocusHandle, Hsla,
-    InteractiveElement, IntoElement, KeyBinding, MouseButton, ParentElement, Pixels, Point,
-    RenderOnce, SharedString, Styled, WindowContext,
+    AnimationExt as _, AnyElement, AppContext, Bounds, ClickEvent, Div, ElementId, FocusHandle,
+    Hsla, InteractiveElement, IntoElement, KeyBinding, MouseButton, ParentElement, Pixels, Point,
+    RenderOnce, SharedString, Styled, Task, WindowContext,
 };
 
 use crate::{
     animation::cubic_bezier,
     button::{Button, ButtonStyled as _},
     theme::ActiveTheme as _,
-    v_flex, ContextModal, IconName, Sizable as _,
+    v_flex, IconName, Root, Sizable as _,
 };
 
 actions!(modal, [Escape]);
 
+/// The duration of the close animation, the modal is removed after it.
+pub(crate) const CLOSE_DURATION: Duration = Duration::from_millis(150);
+
 const CONTEXT: &str = "Modal";
 pub fn init(cx: &mut AppContext) {
     cx.bind_keys([KeyBinding::new("escape", Escape, Some(CONTEXT))])
 }
 
+/// The callback to decide whether the modal or drawer can be closed, resolve to `true` to allow.
+pub(crate) type BeforeCloseFn = Rc<dyn Fn(&mut WindowContext) -> Task<bool> + 'static>;
+
+/// Close the modal or drawer by the user, wait for the `on_before_close` to allow it first.
+pub(crate) fn request_close(
+    on_before_close: Option<BeforeCloseFn>,
+    close: impl FnOnce(&mut WindowContext) + 'static,
+    cx: &mut WindowContext,
+) {
+    let Some(on_before_close) = on_before_close else {
+        close(cx);
+        return;
+    };
+
+    let allow = on_before_close(cx);
+    cx.spawn(|mut cx| async move {
+        if allow.await {
+            _ = cx.update(close);
+        }
+    })
+    .detach();
+}
+
 #[derive(IntoElement)]
 pub struct Modal {
     base: Div,
@@ -32,13 +58,16 @@ pub struct Modal {
     margin_top: Option<Pixels>,
 
     on_close: Rc<dyn Fn(&ClickEvent, &mut WindowContext) + 'static>,
+    on_before_close: Option<BeforeCloseFn>,
     show_close: bool,
     overlay: bool,
+    overlay_closable: bool,
+    keyboard: bool,
 
     /// This will be change when open the modal, the focus handle is create when open the modal.
     pub(crate) focus_handle: FocusHandle,
     pub(crate) layer_ix: usize,
-    pub(crate) overlay_visible: bool,
+    pub(crate) closing: bool,
 }
 
 pub(crate) fn overlay_color(overlay: bool, cx: &WindowContext) -> Hsla {
@@ -46,6 +75,8 @@ pub(crate) fn overlay_color(overlay: bool, cx: &WindowContext) -> Hsla {
         return hsla(0., 0., 0., 0.);
     }
 
+    // Not the `Theme::translucent`, the overlay must keep the layers under it visible,
+    // even if the reduced transparency is set.
     if cx.theme().mode.is_dark() {
         hsla(0., 1., 1., 0.06)
     } else {
@@ -75,9 +106,12 @@ impl Modal {
             width: px(480.),
             max_width: None,
             overlay: true,
+            overlay_closable: true,
+            keyboard: true,
             layer_ix: 0,
-            overlay_visible: true,
+            closing: false,
             on_close: Rc::new(|_, _| {}),
+            on_before_close: None,
             show_close: true,
         }
     }
@@ -103,6 +137,19 @@ impl Modal {
         self
     }
 
+    /// Sets the callback to decide whether the modal can be closed by the user,
+    /// e.g.: ask "You have unsaved changes, discard?" and keep the modal open until the user decides.
+    ///
+    /// The returned task resolves to `true` to allow closing. This is checked for the close button,
+    /// the Escape key and clicking the overlay.
+    pub fn on_before_close(
+        mut self,
+        on_before_close: impl Fn(&mut WindowContext) -> Task<bool> + 'static,
+    ) -> Self {
+        self.on_before_close = Some(Rc::new(on_before_close));
+        self
+    }
+
     /// Sets the false to hide close icon, default: true
     pub fn show_close(mut self, show_close: bool) -> Self {
         self.show_close = show_close;
@@ -133,8 +180,16 @@ impl Modal {
         self
     }
 
-    pub(crate) fn has_overlay(&self) -> bool {
-        self.overlay
+    /// Set false to not close the modal when clicking the overlay, defaults to `true`.
+    pub fn overlay_closable(mut self, overlay_closable: bool) -> Self {
+        self.overlay_closable = overlay_closable;
+        self
+    }
+
+    /// Set false to not close the modal by the Escape key, defaults to `true`.
+    pub fn keyboard(mut self, keyboard: bool) -> Self {
+        self.keyboard = keyboard;
+        self
     }
 }
 
@@ -153,7 +208,27 @@ impl Styled for Modal {
 impl RenderOnce for Modal {
     fn render(self, cx: &mut WindowContext) -> impl gpui::IntoElement {
         let layer_ix = self.layer_ix;
-        let on_close = self.on_close.clone();
+        let closing = self.closing;
+        let close = {
+            let on_close = self.on_close.clone();
+            let on_before_close = self.on_before_close.clone();
+            let focus_handle = self.focus_handle.clone();
+            Rc::new(move |cx: &mut WindowContext| {
+                let on_close = on_close.clone();
+                let focus_handle = focus_handle.clone();
+                request_close(
+                    on_before_close.clone(),
+                    move |cx| {
+                        on_close(&ClickEvent::default(), cx);
+                        // Close this layer only, it may be not the top one after `on_before_close`.
+                        Root::update(cx, move |root, cx| {
+                            root.close_modal_layer(&focus_handle, cx)
+                        });
+                    },
+                    cx,
+                );
+            })
+        };
         let view_size = cx.viewport_size();
         let bounds = Bounds {
             origin: Point::default(),
@@ -168,16 +243,12 @@ impl RenderOnce for Modal {
                 .occlude()
                 .w(view_size.width)
                 .h(view_size.height)
-                .when(self.overlay_visible, |this| {
-                    this.bg(overlay_color(self.overlay, cx))
-                })
-                .when(self.overlay, |this| {
+                // Each layer has its own overlay to dim the layers below it.
+                .bg(overlay_color(self.overlay, cx))
+                .when(self.overlay && self.overlay_closable && !closing, |this| {
                     this.on_mouse_down(MouseButton::Left, {
-                        let on_close = self.on_close.clone();
-                        move |_, cx| {
-                            on_close(&ClickEvent::default(), cx);
-                            cx.close_modal();
-                        }
+                        let close = close.clone();
+                        move |_, cx| close(cx)
                     })
                 })
                 .child(
@@ -185,16 +256,10 @@ impl RenderOnce for Modal {
                         .id(SharedString::from(format!("modal-{layer_ix}")))
                         .key_context(CONTEXT)
                         .track_focus(&self.focus_handle)
-                        .on_action({
-                            let on_close = self.on_close.clone();
-                            move |_: &Escape, cx| {
-                                // FIXME:
-                                //
-                                // Here some Modal have no focus_handle, so it will not work will Escape key.
-                                // But by now, we `cx.close_modal()` going to close the last active model, so the Escape is unexpected to work.
-                                on_close(&ClickEvent::default(), cx);
-                                cx.close_modal();
-                            }
+                        .when(self.keyboard, |this| {
+                            let close = close.clone();
+                            // The Escape is only dispatched to the focused (top) modal.
+                            this.on_action(move |_: &Escape, cx| close(cx))
                         })
                         .absolute()
                         .occlude()
@@ -215,21 +280,26 @@ impl RenderOnce for Modal {
                                     .small()
                                     .ghost()
                                     .icon(IconName::Close)
-                                    .on_click(move |_, cx| {
-                                        on_close(&ClickEvent::default(), cx);
-                                        cx.close_modal();
-                                    }),
+                                    .on_click(move |_, cx| close(cx)),
                             )
                         })
                         .child(self.content)
                         .children(self.footer)
                         .with_animation(
-                            "slide-down",
-                            Animation::new(Duration::from_secs_f64(0.25))
-                                .with_easing(cubic_bezier(0.32, 0.72, 0., 1.)),
+                            ElementId::NamedInteger("slide-down".into(), closing as usize),
+                            Animation::new(if closing {
+                                CLOSE_DURATION
+                            } else {
+                                Duration::from_secs_f64(0.25)
+                            })
+                            .with_easing(cubic_bezier(0.32, 0.72, 0., 1.)),
                             move |this, delta| {
-                                let y_offset = px(0.) + delta * px(30.);
-                                this.top(y + y_offset).opacity(delta)
+                                if closing {
+                                    this.top(y + px(30.) - delta * px(30.)).opacity(1. - delta)
+                                } else {
+                                    let y_offset = px(0.) + delta * px(30.);
+                                    this.top(y + y_offset).opacity(delta)
+                                }
                             },
                         ),
                 ),
@@ -0,0 +1,477 @@
+use gpui::{
+    actions, anchored, canvas, deferred, div, prelude::FluentBuilder, px, rems, AppContext, Bounds,
+    ClickEvent, ElementId, EventEmitter, FocusHandle, FocusableView, InteractiveElement,
+    IntoElement, KeyBinding, Length, ParentElement, Pixels, Render, SharedString,
+    StatefulInteractiveElement, Styled, ViewContext, WindowContext,
+};
+use rust_i18n::t;
+
+use crate::{
+    button::{Button, ButtonStyled as _},
+    checkbox::Checkbox,
+    dropdown::DropdownItem,
+    h_flex,
+    list::step_index,
+    theme::ActiveTheme,
+    v_flex, Disableable, Icon, IconName, Sizable, Size, StyleSized, StyledExt,
+};
+
+actions!(multi_select, [Up, Down, Enter, Escape]);
+
+const CONTEXT: &str = "MultiSelect";
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("up", Up, Some(CONTEXT)),
+        KeyBinding::new("down", Down, Some(CONTEXT)),
+        KeyBinding::new("enter", Enter, Some(CONTEXT)),
+        KeyBinding::new("escape", Escape, Some(CONTEXT)),
+    ])
+}
+
+pub enum MultiSelectEvent<T: DropdownItem + 'static> {
+    /// The selected values have been changed.
+    Change(Vec<T::Value>),
+}
+
+/// A dropdown to select multiple values, the selected values are shown as removable chips.
+pub struct MultiSelect<T: DropdownItem + Clone + 'static> {
+    id: ElementId,
+    focus_handle: FocusHandle,
+    items: Vec<T>,
+    selected_values: Vec<T::Value>,
+    /// The highlighted item in the menu by the keyboard.
+    highlighted_index: Option<usize>,
+    open: bool,
+    size: Size,
+    placeholder: Option<SharedString>,
+    /// The max number of chips to show, the rest are collapsed to "+N more".
+    max_chips: usize,
+    width: Length,
+    disabled: bool,
+    /// Store the bounds of the input
+    bounds: Bounds<Pixels>,
+}
+
+impl<T> MultiSelect<T>
+where
+    T: DropdownItem + Clone + 'static,
+    T::Value: PartialEq,
+{
+    pub fn new(id: impl Into<ElementId>, items: Vec<T>, cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            id: id.into(),
+            focus_handle: cx.focus_handle(),
+            items,
+            selected_values: vec![],
+            highlighted_index: None,
+            open: false,
+            size: Size::Medium,
+            placeholder: None,
+            max_chips: 3,
+            width: Length::Auto,
+            disabled: false,
+            bounds: Bounds::default(),
+        }
+    }
+
+    /// Set the placeholder for display when no value is selected.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Set the max number of chips to show, default is 3.
+    pub fn max_chips(mut self, max_chips: usize) -> Self {
+        self.max_chips = max_chips;
+        self
+    }
+
+    /// Set the width of the input, default: Length::Auto
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Set the disable state for the multi select.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Return the selected values in the order of the items.
+    pub fn selected_values(&self) -> &[T::Value] {
+        &self.selected_values
+    }
+
+    pub fn set_selected_values(&mut self, values: Vec<T::Value>, cx: &mut ViewContext<Self>) {
+        self.selected_values = self
+            .items
+            .iter()
+            .map(|item| item.value())
+            .filter(|value| values.contains(value))
+            .cloned()
+            .collect();
+        cx.notify();
+    }
+
+    pub fn focus(&self, cx: &mut WindowContext) {
+        self.focus_handle.focus(cx);
+    }
+
+    fn is_selected(&self, item: &T) -> bool {
+        self.selected_values.contains(item.value())
+    }
+
+    /// Select or unselect the item at the given index.
+    pub fn toggle(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        let Some(item) = self.items.get(ix) else {
+            return;
+        };
+        if item.disabled() {
+            return;
+        }
+
+        let mut values = self.selected_values.clone();
+        if self.is_selected(item) {
+            values.retain(|value| value != item.value());
+        } else {
+            values.push(item.value().clone());
+        }
+        self.update_selected_values(values, cx);
+    }
+
+    /// Select all the items except the disabled items.
+    pub fn select_all(&mut self, cx: &mut ViewContext<Self>) {
+        let values = self
+            .items
+            .iter()
+            .filter(|item| !item.disabled() || self.is_selected(item))
+            .map(|item| item.value().clone())
+            .collect();
+        self.update_selected_values(values, cx);
+    }
+
+    /// Unselect all the items.
+    pub fn clear_all(&mut self, cx: &mut ViewContext<Self>) {
+        self.update_selected_values(vec![], cx);
+    }
+
+    fn update_selected_values(&mut self, values: Vec<T::Value>, cx: &mut ViewContext<Self>) {
+        self.set_selected_values(values, cx);
+        cx.emit(MultiSelectEvent::Change(self.selected_values.clone()));
+    }
+
+    fn remove_value(&mut self, value: &T::Value, cx: &mut ViewContext<Self>) {
+        let mut values = self.selected_values.clone();
+        values.retain(|v| v != value);
+        self.update_selected_values(values, cx);
+    }
+
+    /// Move the highlighted item by the offset, skip the disabled items.
+    fn move_highlight(&mut self, forward: bool, cx: &mut ViewContext<Self>) {
+        let next = step_index(self.items.len(), self.highlighted_index, forward, |ix| {
+            !self.items[ix].disabled()
+        });
+
+        self.highlighted_index = next;
+        cx.notify();
+    }
+
+    fn up(&mut self, _: &Up, cx: &mut ViewContext<Self>) {
+        if !self.open {
+            cx.propagate();
+            return;
+        }
+        self.move_highlight(false, cx);
+    }
+
+    fn down(&mut self, _: &Down, cx: &mut ViewContext<Self>) {
+        if !self.open {
+            self.open = true;
+        }
+        self.move_highlight(true, cx);
+    }
+
+    fn enter(&mut self, _: &Enter, cx: &mut ViewContext<Self>) {
+        if !self.open {
+            // Propagate the event to the parent view, for example to the Modal to support ENTER to confirm.
+            cx.propagate();
+            self.open = true;
+            cx.notify();
+            return;
+        }
+
+        if let Some(ix) = self.highlighted_index {
+            self.toggle(ix, cx);
+        }
+    }
+
+    fn escape(&mut self, _: &Escape, cx: &mut ViewContext<Self>) {
+        if !self.open {
+            // Propagate the event to the parent view, for example to the Modal to support ESC to close.
+            cx.propagate();
+            return;
+        }
+
+        self.open = false;
+        cx.notify();
+    }
+
+    fn toggle_menu(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
+        self.open = !self.open;
+        self.focus_handle.focus(cx);
+        cx.notify();
+    }
+
+    fn render_chip(&self, ix: usize, item: &T, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let value = item.value().clone();
+
+        h_flex()
+            .id(("chip", ix))
+            .flex_none()
+            .gap_1()
+            .px_1p5()
+            .rounded(px(cx.theme().radius))
+            .bg(cx.theme().secondary)
+            .text_color(cx.theme().secondary_foreground)
+            .text_sm()
+            .child(item.title())
+            .when(!self.disabled, |this| {
+                this.child(
+                    div()
+                        .id("remove")
+                        .cursor_pointer()
+                        .text_color(cx.theme().muted_foreground)
+                        .hover(|this| this.text_color(cx.theme().foreground))
+                        .child(Icon::new(IconName::Close).xsmall())
+                        .on_click(cx.listener(move |this, _, cx| {
+                            cx.stop_propagation();
+                            this.remove_value(&value, cx);
+                        })),
+                )
+            })
+    }
+
+    fn render_chips(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let selected_items = self
+            .items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| self.is_selected(item))
+            .collect::<Vec<_>>();
+        let more_count = selected_items.len().saturating_sub(self.max_chips);
+
+        if selected_items.is_empty() {
+            return div()
+                .text_color(cx.theme().accent_foreground)
+                .child(
+                    self.placeholder
+                        .clone()
+                        .unwrap_or_else(|| t!("Dropdown.placeholder").into()),
+                )
+                .into_any_element();
+        }
+
+        h_flex()
+            .gap_1()
+            .children(
+                selected_items
+                    .into_iter()
+                    .take(self.max_chips)
+                    .map(|(ix, item)| self.render_chip(ix, item, cx)),
+            )
+            .when(more_count > 0, |this| {
+                this.child(
+                    div()
+                        .flex_none()
+                        .px_1p5()
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(t!("MultiSelect.more", count = more_count).to_string()),
+                )
+            })
+            .into_any_element()
+    }
+
+    fn render_menu(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .occlude()
+            .mt_1p5()
+            .bg(cx.theme().background)
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(px(cx.theme().radius))
+            .shadow_md()
+            .child(
+                h_flex()
+                    .justify_between()
+                    .px_2()
+                    .py_1()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .child(
+                        Button::new("select-all")
+                            .ghost()
+                            .xsmall()
+                            .label(t!("MultiSelect.Select all"))
+                            .on_click(cx.listener(|this, _, cx| this.select_all(cx))),
+                    )
+                    .child(
+                        Button::new("clear-all")
+                            .ghost()
+                            .xsmall()
+                            .label(t!("MultiSelect.Clear all"))
+                            .on_click(cx.listener(|this, _, cx| this.clear_all(cx))),
+                    ),
+            )
+            .child(
+                v_flex()
+                    .id("multi-select-items")
+                    .p_1()
+                    .max_h(rems(20.))
+                    .overflow_y_scroll()
+                    .children(self.items.iter().enumerate().map(|(ix, item)| {
+                        let disabled = item.disabled();
+
+                        h_flex()
+                            .id(("multi-select-item", ix))
+                            .gap_2()
+                            .rounded(px(cx.theme().radius))
+                            .list_size(self.size, cx)
+                            .when(self.highlighted_index == Some(ix), |this| {
+                                this.bg(cx.theme().list_active)
+                            })
+                            .when(disabled, |this| {
+                                this.cursor_not_allowed()
+                                    .text_color(cx.theme().muted_foreground)
+                            })
+                            .when(!disabled, |this| {
+                                this.cursor_pointer()
+                                    .hover(|this| this.bg(cx.theme().list_hover))
+                                    .on_click(cx.listener(move |this, _, cx| {
+                                        this.highlighted_index = Some(ix);
+                                        this.toggle(ix, cx);
+                                    }))
+                            })
+                            .child(
+                                Checkbox::new(("multi-select-check", ix))
+                                    .checked(self.is_selected(item))
+                                    .disabled(disabled),
+                            )
+                            .child(div().whitespace_nowrap().child(item.title()))
+                    })),
+            )
+    }
+}
+
+impl<T> Sizable for MultiSelect<T>
+where
+    T: DropdownItem + Clone + 'static,
+{
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl<T> EventEmitter<MultiSelectEvent<T>> for MultiSelect<T> where T: DropdownItem + Clone + 'static {}
+
+impl<T> FocusableView for MultiSelect<T>
+where
+    T: DropdownItem + Clone + 'static,
+{
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl<T> Render for MultiSelect<T>
+where
+    T: DropdownItem + Clone + 'static,
+    T::Value: PartialEq,
+{
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let bounds = self.bounds;
+        let is_focused = self.focus_handle.is_focused(cx);
+        let outline_visible = self.open || is_focused && !self.disabled;
+
+        div()
+            .id(self.id.clone())
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::up))
+            .on_action(cx.listener(Self::down))
+            .on_action(cx.listener(Self::enter))
+            .on_action(cx.listener(Self::escape))
+            .relative()
+            .map(|this| match self.width {
+                Length::Definite(l) => this.flex_none().w(l),
+                Length::Auto => this.w_full(),
+            })
+            .input_text_size(self.size)
+            .child(
+                h_flex()
+                    .id("multi-select-input")
+                    .w_full()
+                    .input_h(self.size, cx)
+                    .input_px(self.size, cx)
+                    .gap_1()
+                    .justify_between()
+                    .bg(cx.theme().background)
+                    .border_1()
+                    .border_color(cx.theme().input)
+                    .rounded(px(cx.theme().radius))
+                    .when(cx.theme().shadow, |this| this.shadow_sm())
+                    .when(outline_visible, |this| this.outline(cx))
+                    .map(|this| {
+                        if self.disabled {
+                            this.cursor_not_allowed()
+                                .text_color(cx.theme().muted_foreground)
+                        } else {
+                            this.cursor_pointer()
+                                .on_click(cx.listener(Self::toggle_menu))
+                        }
+                    })
+                    .child(
+                        div()
+                            .flex_1()
+                            .overflow_hidden()
+                            .child(self.render_chips(cx)),
+                    )
+                    .child(
+                        Icon::new(if self.open {
+                            IconName::ChevronUp
+                        } else {
+                            IconName::ChevronDown
+                        })
+                        .text_color(cx.theme().muted_foreground),
+                    ),
+            )
+            .child(
+                canvas(
+                    move |bounds, cx| view.update(cx, |r, _| r.bounds = bounds),
+                    |_, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+            .when(self.open, |this| {
+                this.child(
+                    deferred(
+                        anchored().snap_to_window_with_margin(px(8.)).child(
+                            div()
+                                .occlude()
+                                .w(bounds.size.width)
+                                .child(self.render_menu(cx))
+                                .on_mouse_down_out(cx.listener(|this, _, cx| {
+                                    this.open = false;
+                                    cx.notify();
+                                })),
+                        ),
+                    )
+                    .with_priority(1),
+                )
+            })
+    }
+}
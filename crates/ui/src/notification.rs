@@ -1,20 +1,47 @@
-use std::{any::TypeId, collections::VecDeque, sync::Arc, time::Duration};
+use std::{
+    any::TypeId,
+    collections::VecDeque,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use gpui::{
-    div, prelude::FluentBuilder, px, Animation, AnimationExt, ClickEvent, DismissEvent, ElementId,
-    EventEmitter, InteractiveElement as _, IntoElement, ParentElement as _, Render, SharedString,
-    StatefulInteractiveElement, Styled, View, ViewContext, VisualContext, WindowContext,
+    canvas, div, prelude::FluentBuilder, px, Animation, AnimationExt, AnyElement, Bounds,
+    ClickEvent, DismissEvent, ElementId, EventEmitter, Hsla, InteractiveElement as _, IntoElement,
+    ParentElement as _, Pixels, Render, SharedString, StatefulInteractiveElement, Styled, Task,
+    View, ViewContext, VisualContext, WindowContext,
 };
+use rust_i18n::t;
 use smol::Timer;
 
 use crate::{
     animation::cubic_bezier,
     button::{Button, ButtonStyled as _},
     h_flex,
-    theme::ActiveTheme as _,
-    v_flex, Icon, IconName, Sizable as _, StyledExt,
+    indicator::Indicator,
+    progress::{Progress, ProgressStatus},
+    theme::{ActiveTheme as _, ComponentTheme, Theme},
+    v_flex, Icon, IconName, Root, Sizable as _, StyledExt,
 };
 
+/// The default duration of the notification to be auto hidden.
+const DEFAULT_DURATION: Duration = Duration::from_secs(5);
+/// The duration of the slide out animation when the notification is dismissed.
+const EXIT_DURATION: Duration = Duration::from_millis(150);
+/// The duration of the collapse animation after the slide out, to let the others move into the freed space.
+const COLLAPSE_DURATION: Duration = Duration::from_millis(150);
+/// The default max number of notifications to display, the others will be queued.
+const DEFAULT_MAX_VISIBLE: usize = 10;
+/// The default number of notifications to show the "Clear all" button.
+const DEFAULT_CLEAR_ALL_THRESHOLD: usize = 3;
+/// The default max number of dismissed notifications to keep in the history.
+const DEFAULT_HISTORY_LIMIT: usize = 50;
+/// The max number of the collapsed notifications behind the newest one in stacked mode.
+const MAX_STACK_DEPTH: usize = 2;
+/// The offset of each collapsed notification in stacked mode.
+const STACK_OFFSET: f32 = 8.;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NotificationType {
     Info,
     Success,
@@ -22,8 +49,125 @@ pub enum NotificationType {
     Error,
 }
 
+impl NotificationType {
+    pub(crate) fn color(&self) -> Hsla {
+        match self {
+            Self::Info => crate::blue_500(),
+            Self::Success => crate::green_500(),
+            Self::Warning => crate::yellow_500(),
+            Self::Error => crate::red_500(),
+        }
+    }
+
+    /// The default icon of the type, in the color of the type.
+    pub(crate) fn icon(&self) -> Icon {
+        let icon = match self {
+            Self::Info => IconName::Info,
+            Self::Success => IconName::CircleCheck,
+            Self::Warning => IconName::TriangleAlert,
+            Self::Error => IconName::CircleX,
+        };
+        Icon::new(icon).text_color(self.color())
+    }
+}
+
+/// A handle of the pushed [`Notification`], used to dismiss or update it later.
+///
+/// ```rs
+/// let handle = cx.push_notification(Notification::progress("Connecting..."));
+/// // When the socket is opened.
+/// handle.dismiss(cx);
+/// ```
+#[derive(Debug, Clone)]
+pub struct NotificationHandle {
+    pub(crate) id: NotificationId,
+}
+
+impl NotificationHandle {
+    /// Returns the id of the notification.
+    pub fn id(&self) -> &NotificationId {
+        &self.id
+    }
+
+    /// Dismiss the notification from the [`NotificationList`] of the window [`Root`].
+    pub fn dismiss(&self, cx: &mut WindowContext) {
+        let id = self.id.clone();
+        Root::update(cx, move |root, cx| {
+            root.notification
+                .update(cx, |list, cx| list.dismiss(id, cx));
+        })
+    }
+
+    /// Update the notification in place, see [`NotificationList::update`].
+    pub fn update(
+        &self,
+        cx: &mut WindowContext,
+        f: impl FnOnce(&mut Notification, &mut ViewContext<Notification>) + 'static,
+    ) {
+        let id = self.id.clone();
+        Root::update(cx, move |root, cx| {
+            root.notification.update(cx, |list, cx| {
+                list.update(id, f, cx);
+            });
+        })
+    }
+}
+
+/// The reason why the [`Notification`] is dismissed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DismissReason {
+    /// Auto hidden after the duration.
+    Timeout,
+    /// The close button is clicked.
+    CloseButton,
+    /// Dismissed by the code, e.g.: [`NotificationList::clear`], or replaced by the same id.
+    Programmatic,
+    /// The notification or one of its actions is clicked.
+    Action,
+}
+
+/// The placement of the [`NotificationList`] in the window.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Placement {
+    TopLeft,
+    #[default]
+    TopRight,
+    TopCenter,
+    BottomLeft,
+    BottomRight,
+    BottomCenter,
+}
+
+impl Placement {
+    pub fn is_top(&self) -> bool {
+        matches!(self, Self::TopLeft | Self::TopRight | Self::TopCenter)
+    }
+
+    pub fn is_bottom(&self) -> bool {
+        !self.is_top()
+    }
+
+    pub fn is_left(&self) -> bool {
+        matches!(self, Self::TopLeft | Self::BottomLeft)
+    }
+
+    pub fn is_right(&self) -> bool {
+        matches!(self, Self::TopRight | Self::BottomRight)
+    }
+
+    pub fn is_center(&self) -> bool {
+        matches!(self, Self::TopCenter | Self::BottomCenter)
+    }
+}
+
+/// The id of the [`Notification`], used to find the notification in the [`NotificationList`].
+///
+/// ```rs
+/// struct UploadNotification;
+/// let id = NotificationId::from(TypeId::of::<UploadNotification>());
+/// ```
 #[derive(Debug, PartialEq, Clone)]
-pub(crate) enum NotificationId {
+pub enum NotificationId {
     Id(TypeId),
     IdAndElementId(TypeId, ElementId),
 }
@@ -40,19 +184,72 @@ impl From<(TypeId, ElementId)> for NotificationId {
     }
 }
 
+/// The style tokens of the [`Notification`], override them by [`Theme::override_component`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NotificationTheme {
+    pub background: Hsla,
+    pub foreground: Hsla,
+    pub border: Hsla,
+    pub radius: Pixels,
+    pub width: Pixels,
+}
+
+impl ComponentTheme for NotificationTheme {
+    fn from_theme(theme: &Theme) -> Self {
+        Self {
+            background: theme.popover,
+            foreground: theme.popover_foreground,
+            border: theme.border,
+            radius: px(6.),
+            width: px(384.),
+        }
+    }
+}
+
 pub struct Notification {
     /// The id is used make the notification unique.
     /// Then you push a notification with the same id, the previous notification will be replaced.
     ///
     /// None means the notification will be added to the end of the list.
-    id: NotificationId,
+    pub(crate) id: NotificationId,
     type_: NotificationType,
     title: Option<SharedString>,
     message: SharedString,
+    /// The custom content element, it will be rendered instead of the message.
+    content: Option<Arc<dyn Fn(&mut WindowContext) -> AnyElement>>,
     icon: Option<Icon>,
+    /// Show a loading indicator instead of the icon.
+    loading: bool,
+    /// The progress value (0..100), None means no progress bar.
+    value: Option<f32>,
     autohide: bool,
+    /// The duration before the notification is auto hidden.
+    ///
+    /// None means use the default duration of the [`NotificationList`].
+    duration: Option<Duration>,
+    /// The default duration of the [`NotificationList`], used when the `duration` is not set.
+    default_duration: Duration,
     on_click: Option<Arc<dyn Fn(&ClickEvent, &mut WindowContext)>>,
+    on_close: Option<Arc<dyn Fn(&DismissReason, &mut WindowContext)>>,
+    actions: Vec<NotificationAction>,
     closing: bool,
+    /// The height is collapsing to 0 after the slide out animation.
+    collapsing: bool,
+    bounds: Bounds<Pixels>,
+    /// The placement of the list, used to decide the animation direction.
+    placement: Placement,
+    /// The mouse is hovering, the auto hide timer is paused.
+    hovered: bool,
+    /// The remaining time before auto hide, it will be paused when the mouse is hovering.
+    autohide_remaining: Option<Duration>,
+    autohide_started_at: Option<Instant>,
+    _autohide_task: Option<Task<()>>,
+}
+
+/// An action button in the footer of the [`Notification`].
+struct NotificationAction {
+    label: SharedString,
+    on_click: Arc<dyn Fn(&ClickEvent, &mut WindowContext)>,
 }
 
 impl From<SharedString> for Notification {
@@ -92,11 +289,25 @@ impl Notification {
             id: id.into(),
             title: None,
             message: message.into(),
+            content: None,
             type_: NotificationType::Info,
             icon: None,
+            loading: false,
+            value: None,
             autohide: true,
+            duration: None,
+            default_duration: DEFAULT_DURATION,
             on_click: None,
+            on_close: None,
+            actions: Vec::new(),
             closing: false,
+            collapsing: false,
+            bounds: Bounds::default(),
+            placement: Placement::default(),
+            hovered: false,
+            autohide_remaining: None,
+            autohide_started_at: None,
+            _autohide_task: None,
         }
     }
 
@@ -116,6 +327,14 @@ impl Notification {
         Self::new(message).with_type(NotificationType::Error)
     }
 
+    /// Create a progress notification with a loading indicator, it will not be auto hidden.
+    ///
+    /// Use [`Notification::value`] to display a progress bar, and use [`NotificationList::update`]
+    /// to update the notification in place when the task is finished.
+    pub fn progress(message: impl Into<SharedString>) -> Self {
+        Self::new(message).loading(true).autohide(false)
+    }
+
     /// Set the type for unique identification of the notification.
     ///
     /// ```rs
@@ -141,6 +360,18 @@ impl Notification {
         self
     }
 
+    /// Set a custom content element of the notification, e.g.: a link, a code snippet.
+    ///
+    /// The content element will be rendered instead of the message,
+    /// the message is still used as the plain text of the notification.
+    pub fn content_element(
+        mut self,
+        content: impl Fn(&mut WindowContext) -> AnyElement + 'static,
+    ) -> Self {
+        self.content = Some(Arc::new(content));
+        self
+    }
+
     /// Set the icon of the notification.
     ///
     /// If icon is None, the notification will use the default icon of the type.
@@ -161,6 +392,58 @@ impl Notification {
         self
     }
 
+    /// Set true to show a loading indicator instead of the icon, default is false.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Set the progress value (0..100) of the notification to display a progress bar.
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Set the title of the notification.
+    pub fn set_title(&mut self, title: Option<SharedString>) {
+        self.title = title;
+    }
+
+    /// Set the message of the notification.
+    pub fn set_message(&mut self, message: impl Into<SharedString>) {
+        self.message = message.into();
+    }
+
+    /// Set the type of the notification.
+    pub fn set_type(&mut self, type_: NotificationType) {
+        self.type_ = type_;
+    }
+
+    /// Set the loading state of the notification.
+    pub fn set_loading(&mut self, loading: bool) {
+        self.loading = loading;
+    }
+
+    /// Set the progress value (0..100) of the notification, None to hide the progress bar.
+    pub fn set_value(&mut self, value: Option<f32>) {
+        self.value = value;
+    }
+
+    /// Set the auto hide of the notification.
+    ///
+    /// When it is changed to true in [`NotificationList::update`], the auto hide timer will be started.
+    pub fn set_autohide(&mut self, autohide: bool) {
+        self.autohide = autohide;
+    }
+
+    /// Set the duration before the notification is auto hidden.
+    ///
+    /// Default is None, to use the default duration of the [`NotificationList`] (5s).
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
     /// Set the click callback of the notification.
     pub fn on_click(
         mut self,
@@ -170,20 +453,107 @@ impl Notification {
         self
     }
 
-    fn dismiss(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
+    /// Set the callback when the notification is dismissed, with the [`DismissReason`].
+    pub fn on_close(
+        mut self,
+        on_close: impl Fn(&DismissReason, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_close = Some(Arc::new(on_close));
+        self
+    }
+
+    /// Add an action button to the footer of the notification, e.g.: "Undo", "Retry".
+    ///
+    /// The notification will be dismissed after the action is clicked,
+    /// and the `on_click` callback of the notification will not be triggered.
+    ///
+    /// Only the first two actions will be displayed.
+    pub fn action(
+        mut self,
+        label: impl Into<SharedString>,
+        on_click: impl Fn(&ClickEvent, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.actions.push(NotificationAction {
+            label: label.into(),
+            on_click: Arc::new(on_click),
+        });
+        self
+    }
+
+    fn render_icon(&self) -> Icon {
+        match self.icon.clone() {
+            Some(icon) => icon,
+            None => self.type_.icon(),
+        }
+    }
+
+    /// Start the timer to auto hide the notification, if `autohide` is enabled.
+    ///
+    /// If the timer was paused, it will continue with the remaining time.
+    /// It's not started while the mouse is hovering, until the mouse leaves.
+    fn perform_autohide(&mut self, cx: &mut ViewContext<Self>) {
+        if !self.autohide || self.closing || self.hovered || self.autohide_started_at.is_some() {
+            return;
+        }
+
+        let duration = self
+            .autohide_remaining
+            .unwrap_or(self.duration.unwrap_or(self.default_duration));
+        self.autohide_remaining = Some(duration);
+        self.autohide_started_at = Some(Instant::now());
+        self._autohide_task = Some(cx.spawn(|view, mut cx| async move {
+            Timer::after(duration).await;
+            _ = view.update(&mut cx, |view, cx| view.dismiss(DismissReason::Timeout, cx));
+        }));
+    }
+
+    /// Set the default duration of the [`NotificationList`], the timer is restarted with it
+    /// if the `duration` is not set.
+    fn set_default_duration(&mut self, duration: Duration, cx: &mut ViewContext<Self>) {
+        self.default_duration = duration;
+        if self.duration.is_none() && self.autohide_remaining.is_some() {
+            self.pause_autohide();
+            self.autohide_remaining = None;
+            self.perform_autohide(cx);
+        }
+    }
+
+    /// Pause the auto hide timer, and keep the remaining time.
+    fn pause_autohide(&mut self) {
+        if let Some(started_at) = self.autohide_started_at.take() {
+            self._autohide_task = None;
+            self.autohide_remaining = self
+                .autohide_remaining
+                .map(|remaining| remaining.saturating_sub(started_at.elapsed()));
+        }
+    }
+
+    fn dismiss(&mut self, reason: DismissReason, cx: &mut ViewContext<Self>) {
+        if self.closing {
+            return;
+        }
+
         self.closing = true;
+        if let Some(on_close) = self.on_close.clone() {
+            // Defer to avoid the `on_close` to update the NotificationList while it is updating.
+            cx.defer(move |_, cx| on_close(&reason, cx));
+        }
         cx.notify();
 
-        // Dismiss the notification after 0.15s to show the animation.
+        // Slide out the notification, then collapse the height to let the others move into
+        // the freed space, and finally remove it from the list.
         cx.spawn(|view, mut cx| async move {
-            Timer::after(Duration::from_secs_f32(0.15)).await;
-            cx.update(|cx| {
-                if let Some(view) = view.upgrade() {
-                    view.update(cx, |view, cx| {
-                        view.closing = false;
-                        cx.emit(DismissEvent);
-                    });
-                }
+            Timer::after(EXIT_DURATION).await;
+            view.update(&mut cx, |view, cx| {
+                view.collapsing = true;
+                cx.notify();
+            })?;
+
+            Timer::after(COLLAPSE_DURATION).await;
+            view.update(&mut cx, |view, cx| {
+                view.closing = false;
+                view.collapsing = false;
+                cx.emit(DismissEvent);
             })
         })
         .detach()
@@ -194,37 +564,61 @@ impl FluentBuilder for Notification {}
 impl Render for Notification {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let closing = self.closing;
-        let icon = match self.icon.clone() {
-            Some(icon) => icon,
-            None => match self.type_ {
-                NotificationType::Info => Icon::new(IconName::Info).text_color(crate::blue_500()),
-                NotificationType::Success => {
-                    Icon::new(IconName::CircleCheck).text_color(crate::green_500())
-                }
-                NotificationType::Warning => {
-                    Icon::new(IconName::TriangleAlert).text_color(crate::yellow_500())
-                }
-                NotificationType::Error => {
-                    Icon::new(IconName::CircleX).text_color(crate::red_500())
-                }
-            },
-        };
+        let placement = self.placement;
+        let icon = self.render_icon();
+        let view = cx.view().clone();
+
+        if self.collapsing {
+            let height = self.bounds.size.height;
+            return div()
+                .id("notification")
+                .w(self.bounds.size.width)
+                .h(height)
+                .with_animation(
+                    "collapse",
+                    Animation::new(COLLAPSE_DURATION).with_easing(cubic_bezier(0.4, 0., 0.2, 1.)),
+                    move |this, delta| {
+                        // Also remove the `gap_3` space of the NotificationList.
+                        this.h(height * (1. - delta)).mb(px(-12.) * delta)
+                    },
+                )
+                .into_any_element();
+        }
 
+        let notification = cx.theme().component::<NotificationTheme>();
         div()
             .id("notification")
             .group("")
             .occlude()
             .relative()
-            .w_96()
+            .w(notification.width)
             .border_1()
-            .border_color(cx.theme().border)
-            .bg(cx.theme().popover)
-            .rounded_md()
+            .border_color(notification.border)
+            .bg(notification.background)
+            .text_color(notification.foreground)
+            .rounded(notification.radius)
             .shadow_md()
             .py_2()
             .px_4()
             .gap_3()
-            .child(div().absolute().top_3().left_4().child(icon))
+            .on_hover(cx.listener(|view, hovered, cx| {
+                view.hovered = *hovered;
+                if *hovered {
+                    view.pause_autohide();
+                } else {
+                    view.perform_autohide(cx);
+                }
+            }))
+            .child(
+                div()
+                    .absolute()
+                    .top_3()
+                    .left_4()
+                    .map(|this| match self.loading {
+                        true => this.child(Indicator::new().color(cx.theme().muted_foreground)),
+                        false => this.child(icon),
+                    }),
+            )
             .child(
                 v_flex()
                     .pl_6()
@@ -233,12 +627,44 @@ impl Render for Notification {
                         this.child(div().text_sm().font_semibold().child(title))
                     })
                     .overflow_hidden()
-                    .child(div().text_sm().child(self.message.clone())),
+                    .map(|this| match self.content.clone() {
+                        Some(content) => this.child(content(cx)),
+                        None => this.child(div().text_sm().child(self.message.clone())),
+                    })
+                    .when_some(self.value, |this, value| {
+                        let status = match self.type_ {
+                            NotificationType::Success => ProgressStatus::Success,
+                            NotificationType::Error => ProgressStatus::Error,
+                            _ => ProgressStatus::Normal,
+                        };
+
+                        this.child(
+                            div()
+                                .py_1()
+                                .child(Progress::new().value(value).status(status).small()),
+                        )
+                    })
+                    .when(!self.actions.is_empty(), |this| {
+                        this.child(h_flex().pt_1().gap_2().children(
+                            self.actions.iter().take(2).enumerate().map(|(ix, action)| {
+                                let on_click = action.on_click.clone();
+                                Button::new(("action", ix))
+                                    .label(action.label.clone())
+                                    .small()
+                                    .when(ix == 0, |this| this.primary())
+                                    .on_click(cx.listener(move |view, event, cx| {
+                                        cx.stop_propagation();
+                                        view.dismiss(DismissReason::Action, cx);
+                                        on_click(event, cx);
+                                    }))
+                            }),
+                        ))
+                    }),
             )
             .when_some(self.on_click.clone(), |this, on_click| {
                 this.cursor_pointer()
                     .on_click(cx.listener(move |view, event, cx| {
-                        view.dismiss(event, cx);
+                        view.dismiss(DismissReason::Action, cx);
                         on_click(event, cx);
                     }))
             })
@@ -255,106 +681,504 @@ impl Render for Notification {
                                 .icon(IconName::Close)
                                 .ghost()
                                 .xsmall()
-                                .on_click(cx.listener(Self::dismiss)),
+                                .on_click(cx.listener(|view, _, cx| {
+                                    view.dismiss(DismissReason::CloseButton, cx)
+                                })),
                         ),
                 )
             })
+            .child(
+                canvas(
+                    move |bounds, cx| view.update(cx, |r, _| r.bounds = bounds),
+                    |_, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
             .with_animation(
                 ElementId::NamedInteger("slide-down".into(), closing as usize),
-                Animation::new(Duration::from_secs_f64(0.15))
-                    .with_easing(cubic_bezier(0.4, 0., 0.2, 1.)),
+                Animation::new(EXIT_DURATION).with_easing(cubic_bezier(0.4, 0., 0.2, 1.)),
                 move |this, delta| {
                     if closing {
-                        let x_offset = px(0.) + delta * px(45.);
-                        this.left(px(0.) + x_offset).opacity(1. - delta)
+                        let x_offset = delta * px(45.);
+                        match placement {
+                            p if p.is_left() => this.left(-x_offset),
+                            p if p.is_right() => this.left(x_offset),
+                            _ => this,
+                        }
+                        .opacity(1. - delta)
                     } else {
-                        let y_offset = px(-45.) + delta * px(45.);
-                        this.top(px(0.) + y_offset).opacity(delta)
+                        let y_offset = px(45.) - delta * px(45.);
+                        if placement.is_top() {
+                            this.top(-y_offset).opacity(delta)
+                        } else {
+                            this.top(y_offset).opacity(delta)
+                        }
                     }
                 },
             )
+            .into_any_element()
     }
 }
 
 /// A list of notifications.
 pub struct NotificationList {
-    /// Notifications that will be auto hidden.
+    /// The visible notifications.
     pub(crate) notifications: VecDeque<View<Notification>>,
+    /// The queued notifications, will be displayed when the visible notifications are dismissed.
+    pending: VecDeque<View<Notification>>,
     expanded: bool,
+    placement: Placement,
+    /// The default duration of the notifications to be auto hidden.
+    duration: Duration,
+    /// The max number of visible notifications, the others will be queued.
+    max_visible: usize,
+    /// Collapse the older notifications behind the newest one, expand on hover.
+    stacked: bool,
+    /// Show the "Clear all" button when the number of notifications is more than this.
+    clear_all_threshold: usize,
+    /// The dismissed notifications, the newest is the first.
+    history: VecDeque<View<Notification>>,
+    history_limit: usize,
 }
 
 impl NotificationList {
     pub fn new(_cx: &mut ViewContext<Self>) -> Self {
         Self {
             notifications: VecDeque::new(),
+            pending: VecDeque::new(),
             expanded: false,
+            placement: Placement::default(),
+            duration: DEFAULT_DURATION,
+            max_visible: DEFAULT_MAX_VISIBLE,
+            stacked: false,
+            clear_all_threshold: DEFAULT_CLEAR_ALL_THRESHOLD,
+            history: VecDeque::new(),
+            history_limit: DEFAULT_HISTORY_LIMIT,
+        }
+    }
+
+    /// Set the placement of the notifications, default is [`Placement::TopRight`].
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Change the placement of the notifications.
+    pub fn set_placement(&mut self, placement: Placement, cx: &mut ViewContext<Self>) {
+        self.placement = placement;
+        for note in self.notifications.iter() {
+            note.update(cx, |note, cx| {
+                note.placement = placement;
+                cx.notify();
+            });
+        }
+        cx.notify();
+    }
+
+    /// Set the default duration of the notifications to be auto hidden, default is 5s.
+    ///
+    /// This is used when the [`Notification`] has no `duration` set.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Change the default duration of the notifications to be auto hidden.
+    ///
+    /// The visible notifications without the `duration` restart the timer with it.
+    pub fn set_duration(&mut self, duration: Duration, cx: &mut ViewContext<Self>) {
+        self.duration = duration;
+        for note in self.notifications.iter().chain(self.pending.iter()) {
+            note.update(cx, |note, cx| note.set_default_duration(duration, cx));
         }
+        cx.notify();
+    }
+
+    /// Set the max number of visible notifications, default is 10.
+    ///
+    /// The extra notifications will be queued, and displayed in order when the visible ones are dismissed.
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible;
+        self
+    }
+
+    /// Change the max number of visible notifications.
+    pub fn set_max_visible(&mut self, max_visible: usize, cx: &mut ViewContext<Self>) {
+        self.max_visible = max_visible;
+        self.show_pending(cx);
+        cx.notify();
+    }
+
+    /// Set true to collapse the older notifications behind the newest one,
+    /// they will be expanded when the mouse is hovering, default is false.
+    pub fn stacked(mut self, stacked: bool) -> Self {
+        self.stacked = stacked;
+        self
+    }
+
+    /// Change the stacked mode of the notifications.
+    pub fn set_stacked(&mut self, stacked: bool, cx: &mut ViewContext<Self>) {
+        self.stacked = stacked;
+        cx.notify();
+    }
+
+    /// Set the number of notifications to show the "Clear all" button, default is 3.
+    pub fn clear_all_threshold(mut self, threshold: usize) -> Self {
+        self.clear_all_threshold = threshold;
+        self
     }
 
-    pub fn push(&mut self, notification: impl Into<Notification>, cx: &mut ViewContext<Self>) {
-        let notification = notification.into();
+    /// Set the max number of dismissed notifications to keep in the history, default is 50.
+    pub fn history_limit(mut self, limit: usize) -> Self {
+        self.history_limit = limit;
+        self
+    }
+
+    /// Push a notification to the list, returns a [`NotificationHandle`] to dismiss or update it later.
+    ///
+    /// If there is a notification with the same id, it will be replaced and moved to the history.
+    pub fn push(
+        &mut self,
+        notification: impl Into<Notification>,
+        cx: &mut ViewContext<Self>,
+    ) -> NotificationHandle {
+        let mut notification = notification.into();
+        notification.placement = self.placement;
+        notification.default_duration = self.duration;
         let id = notification.id.clone();
-        let autohide = notification.autohide;
+        let handle_id = id.clone();
 
         // Remove the notification by id, for keep unique.
+        let replaced: Vec<_> = self
+            .notifications
+            .iter()
+            .chain(self.pending.iter())
+            .filter(|note| note.read(cx).id == id)
+            .cloned()
+            .collect();
         self.notifications.retain(|note| note.read(cx).id != id);
+        self.pending.retain(|note| note.read(cx).id != id);
+        for note in replaced {
+            if let Some(on_close) = note.read(cx).on_close.clone() {
+                cx.defer(move |_, cx| on_close(&DismissReason::Programmatic, cx));
+            }
+            self.push_history(note);
+        }
 
         let notification = cx.new_view(|_| notification);
-        cx.subscribe(&notification, move |view, _, _: &DismissEvent, cx| {
+        cx.subscribe(&notification, move |view, note, _: &DismissEvent, cx| {
             view.notifications.retain(|note| id != note.read(cx).id);
+            view.push_history(note);
+            view.show_pending(cx);
+            cx.notify();
         })
         .detach();
 
-        self.notifications.push_back(notification.clone());
-        if autohide {
-            // Sleep for 5 seconds to autohide the notification
-            cx.spawn(|_, mut cx| async move {
-                Timer::after(Duration::from_secs(5)).await;
+        self.pending.push_back(notification);
+        self.show_pending(cx);
+        cx.notify();
 
-                notification
-                    .update(&mut cx, |note, cx| note.dismiss(&ClickEvent::default(), cx))
-                    .expect("failed to auto hide notification");
-            })
-            .detach();
+        NotificationHandle { id: handle_id }
+    }
+
+    /// Dismiss the notification by id, returns false if the notification is not found.
+    ///
+    /// If the notification is queued, it will be moved to the history directly.
+    pub fn dismiss(&mut self, id: impl Into<NotificationId>, cx: &mut ViewContext<Self>) -> bool {
+        let id = id.into();
+        if let Some(ix) = self.pending.iter().position(|note| note.read(cx).id == id) {
+            if let Some(note) = self.pending.remove(ix) {
+                self.close_pending(note, cx);
+            }
+            cx.notify();
+            return true;
         }
+
+        let Some(notification) = self
+            .notifications
+            .iter()
+            .find(|note| note.read(cx).id == id)
+            .cloned()
+        else {
+            return false;
+        };
+
+        notification.update(cx, |note, cx| note.dismiss(DismissReason::Programmatic, cx));
+        true
+    }
+
+    /// Update the notification by id in place, e.g.: change a progress notification to success.
+    ///
+    /// Returns false if the notification is not found.
+    pub fn update(
+        &mut self,
+        id: impl Into<NotificationId>,
+        f: impl FnOnce(&mut Notification, &mut ViewContext<Notification>),
+        cx: &mut ViewContext<Self>,
+    ) -> bool {
+        let id = id.into();
+        let visible = self.notifications.iter().any(|note| note.read(cx).id == id);
+        let Some(notification) = self
+            .notifications
+            .iter()
+            .chain(self.pending.iter())
+            .find(|note| note.read(cx).id == id)
+            .cloned()
+        else {
+            return false;
+        };
+
+        notification.update(cx, |note, cx| {
+            f(note, cx);
+            // The queued notification will start the timer when it is displayed.
+            if visible {
+                note.perform_autohide(cx);
+            }
+            cx.notify();
+        });
         cx.notify();
+        true
     }
 
+    /// Dismiss all notifications include the queued, they will be moved to the history.
     pub fn clear(&mut self, cx: &mut ViewContext<Self>) {
-        self.notifications.clear();
+        let pending: Vec<_> = self.pending.drain(..).collect();
+        for note in pending {
+            self.close_pending(note, cx);
+        }
+        for note in self.notifications.iter() {
+            note.update(cx, |note, cx| note.dismiss(DismissReason::Programmatic, cx));
+        }
         cx.notify();
     }
 
+    /// Display the queued notifications until the visible notifications reach the `max_visible`.
+    fn show_pending(&mut self, cx: &mut ViewContext<Self>) {
+        while self.notifications.len() < self.max_visible {
+            let Some(note) = self.pending.pop_front() else {
+                break;
+            };
+
+            let placement = self.placement;
+            note.update(cx, |note, cx| {
+                note.placement = placement;
+                note.perform_autohide(cx);
+            });
+            self.notifications.push_back(note);
+        }
+    }
+
+    /// Close a queued notification without display it.
+    fn close_pending(&mut self, note: View<Notification>, cx: &mut ViewContext<Self>) {
+        if let Some(on_close) = note.read(cx).on_close.clone() {
+            cx.defer(move |_, cx| on_close(&DismissReason::Programmatic, cx));
+        }
+        self.push_history(note);
+    }
+
+    /// Returns the visible notifications.
     pub fn notifications(&self) -> Vec<View<Notification>> {
         self.notifications.iter().cloned().collect()
     }
+
+    /// Returns the queued notifications, they will be displayed in order.
+    pub fn pending(&self) -> Vec<View<Notification>> {
+        self.pending.iter().cloned().collect()
+    }
+
+    /// Returns the dismissed notifications, the newest is the first.
+    pub fn history(&self) -> Vec<View<Notification>> {
+        self.history.iter().cloned().collect()
+    }
+
+    /// Clear the history of the dismissed notifications.
+    pub fn clear_history(&mut self, cx: &mut ViewContext<Self>) {
+        self.history.clear();
+        cx.notify();
+    }
+
+    fn push_history(&mut self, note: View<Notification>) {
+        self.history.push_front(note);
+        self.history.truncate(self.history_limit);
+    }
 }
 
 impl Render for NotificationList {
     fn render(&mut self, cx: &mut gpui::ViewContext<Self>) -> impl IntoElement {
         let size = cx.viewport_size();
-        let items = self.notifications.iter().rev().take(10).rev().cloned();
+        let placement = self.placement;
+        let items: Vec<_> = self.notifications.iter().cloned().collect();
+        let collapsed = self.stacked && !self.expanded && items.len() > 1;
+        let mut clear_all_button = (items.len() > self.clear_all_threshold).then(|| {
+            Button::new("clear-all")
+                .label(t!("Notification.Clear all"))
+                .small()
+                .on_click(cx.listener(|view, _, cx| view.clear(cx)))
+        });
 
         div()
             .absolute()
             .flex()
             .top_4()
             .bottom_4()
-            .right_4()
-            .justify_end()
+            .map(|this| match placement {
+                p if p.is_left() => this.left_4().justify_start(),
+                p if p.is_right() => this.right_4().justify_end(),
+                _ => this.left_4().right_4().justify_center(),
+            })
             .child(
                 v_flex()
                     .id("notification-list")
                     .absolute()
                     .relative()
-                    .right_0()
+                    .map(|this| match placement {
+                        p if p.is_left() => this.left_0().items_start(),
+                        p if p.is_right() => this.right_0().items_end(),
+                        _ => this.items_center(),
+                    })
+                    .when(placement.is_bottom(), |this| this.justify_end())
                     .h(size.height - px(8.))
                     .on_hover(cx.listener(|view, hovered, cx| {
                         view.expanded = *hovered;
                         cx.notify()
                     }))
                     .gap_3()
-                    .children(items),
+                    .when(placement.is_bottom(), |this| {
+                        this.children(clear_all_button.take())
+                    })
+                    .map(|this| {
+                        if !collapsed {
+                            return this.children(items);
+                        }
+
+                        // Render the older notifications behind the newest one,
+                        // the newest is the last child to be painted on the top.
+                        let last_ix = items.len() - 1;
+                        this.child(div().relative().children(
+                            items.into_iter().enumerate().filter_map(|(ix, item)| {
+                                let depth = last_ix - ix;
+                                if depth == 0 {
+                                    return Some(div().child(item));
+                                }
+                                if depth > MAX_STACK_DEPTH {
+                                    return None;
+                                }
+
+                                let offset = px(STACK_OFFSET * depth as f32);
+                                Some(
+                                    div()
+                                        .absolute()
+                                        .left_0()
+                                        .right_0()
+                                        .map(|this| match placement.is_top() {
+                                            true => this.top(offset),
+                                            false => this.bottom(offset),
+                                        })
+                                        .opacity(
+                                            cx.theme().element_opacity(1. - 0.3 * depth as f32),
+                                        )
+                                        .child(item),
+                                )
+                            }),
+                        ))
+                    })
+                    .children(clear_all_button),
+            )
+    }
+}
+
+/// A view to review the recent dismissed notifications of the [`NotificationList`].
+///
+/// ```rs
+/// let center = NotificationCenter::view(cx);
+/// cx.open_drawer(move |drawer, _| drawer.child(center.clone()));
+/// ```
+pub struct NotificationCenter {
+    list: View<NotificationList>,
+}
+
+impl NotificationCenter {
+    pub fn new(list: View<NotificationList>, cx: &mut ViewContext<Self>) -> Self {
+        cx.observe(&list, |_, _, cx| cx.notify()).detach();
+        Self { list }
+    }
+
+    /// Create a NotificationCenter view for the [`NotificationList`] of the window [`crate::Root`].
+    pub fn view(cx: &mut WindowContext) -> View<Self> {
+        let list = crate::Root::read(cx).notification.clone();
+        cx.new_view(|cx| Self::new(list, cx))
+    }
+}
+
+impl Render for NotificationCenter {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let history = self.list.read(cx).history();
+
+        v_flex()
+            .id("notification-center")
+            .size_full()
+            .gap_2()
+            .child(
+                h_flex()
+                    .justify_between()
+                    .child(
+                        div()
+                            .text_sm()
+                            .font_semibold()
+                            .child(t!("Notification.Notifications").to_string()),
+                    )
+                    .when(!history.is_empty(), |this| {
+                        this.child(
+                            Button::new("clear-history")
+                                .label(t!("Notification.Clear all"))
+                                .ghost()
+                                .xsmall()
+                                .on_click(cx.listener(|view, _, cx| {
+                                    view.list.update(cx, |list, cx| list.clear_history(cx))
+                                })),
+                        )
+                    }),
             )
+            .when(history.is_empty(), |this| {
+                this.child(
+                    div()
+                        .py_4()
+                        .text_sm()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(t!("Notification.No notifications").to_string()),
+                )
+            })
+            .children(history.into_iter().map(|note| {
+                let (icon, title, message, content) = {
+                    let note = note.read(cx);
+                    (
+                        note.render_icon(),
+                        note.title.clone(),
+                        note.message.clone(),
+                        note.content.clone(),
+                    )
+                };
+
+                h_flex()
+                    .items_start()
+                    .gap_3()
+                    .py_2()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .child(div().pt_0p5().child(icon))
+                    .child(
+                        v_flex()
+                            .gap_1()
+                            .overflow_hidden()
+                            .when_some(title, |this, title| {
+                                this.child(div().text_sm().font_semibold().child(title))
+                            })
+                            .map(|this| match content {
+                                Some(content) => this.child(content(cx)),
+                                None => this.child(div().text_sm().child(message)),
+                            }),
+                    )
+            }))
     }
 }
@@ -0,0 +1,58 @@
+use gpui::{canvas, px, IntoElement, Pixels, Styled as _, View};
+
+/// The measured widths of a row and its items, to move the items that don't fit into a menu,
+/// e.g.: the [`crate::breadcrumb::Breadcrumb`] and the [`crate::toolbar::Toolbar`].
+pub(crate) struct OverflowWidths {
+    /// The last measured widths of the items, to keep the widths of the hidden items.
+    pub(crate) items: Vec<Pixels>,
+    pub(crate) width: Pixels,
+}
+
+impl OverflowWidths {
+    pub(crate) fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            width: px(0.),
+        }
+    }
+
+    /// Add an item that is not measured yet.
+    pub(crate) fn push(&mut self) {
+        self.items.push(px(0.));
+    }
+
+    /// Clear the widths of the items, e.g.: when the items are replaced.
+    pub(crate) fn reset(&mut self, count: usize) {
+        self.items = vec![px(0.); count];
+    }
+
+    /// Return a canvas to fill the parent to measure its width when painting,
+    /// `ix` is the index of the item, or `None` to measure the row.
+    pub(crate) fn measure<V: 'static>(
+        view: View<V>,
+        ix: Option<usize>,
+        widths: fn(&mut V) -> &mut OverflowWidths,
+    ) -> impl IntoElement {
+        canvas(
+            move |bounds, cx| {
+                view.update(cx, |this, cx| {
+                    let widths = widths(this);
+                    let width = match ix {
+                        Some(ix) => widths.items.get_mut(ix),
+                        None => Some(&mut widths.width),
+                    };
+
+                    if let Some(width) = width {
+                        if *width != bounds.size.width {
+                            *width = bounds.size.width;
+                            cx.notify();
+                        }
+                    }
+                })
+            },
+            |_, _, _| {},
+        )
+        .absolute()
+        .size_full()
+    }
+}
@@ -0,0 +1,360 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, IntoElement, ParentElement as _, Render, SharedString,
+    Styled as _, View, ViewContext, VisualContext as _, WindowContext,
+};
+use rust_i18n::t;
+
+use crate::{
+    button::{Button, ButtonStyled as _},
+    dropdown::{Dropdown, DropdownEvent, DropdownItem},
+    h_flex,
+    input::{InputEvent, TextInput},
+    theme::ActiveTheme as _,
+    Disableable as _, IconName, Selectable as _, Sizable as _,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PageItem {
+    Page(usize),
+    Ellipsis,
+}
+
+/// Return the total pages of the items, at least 1 page.
+fn total_pages(total: usize, page_size: usize) -> usize {
+    total.div_ceil(page_size.max(1)).max(1)
+}
+
+/// Return the page buttons to show, the first and the last pages are always visible,
+/// and the pages far away from the current page are collapsed into the ellipsis.
+fn page_items(page: usize, total_pages: usize, siblings: usize) -> Vec<PageItem> {
+    // The first, last, current, 2 ellipsis and the siblings.
+    let count = 5 + siblings * 2;
+    if total_pages <= count {
+        return (1..=total_pages).map(PageItem::Page).collect();
+    }
+
+    let left = page.saturating_sub(siblings).max(1);
+    let right = (page + siblings).min(total_pages);
+    let left_ellipsis = left > 3;
+    let right_ellipsis = right + 2 < total_pages;
+
+    let mut items = vec![];
+    match (left_ellipsis, right_ellipsis) {
+        (false, _) => {
+            items.extend((1..=count - 2).map(PageItem::Page));
+            items.push(PageItem::Ellipsis);
+            items.push(PageItem::Page(total_pages));
+        }
+        (true, false) => {
+            items.push(PageItem::Page(1));
+            items.push(PageItem::Ellipsis);
+            items.extend((total_pages + 3 - count..=total_pages).map(PageItem::Page));
+        }
+        (true, true) => {
+            items.push(PageItem::Page(1));
+            items.push(PageItem::Ellipsis);
+            items.extend((left..=right).map(PageItem::Page));
+            items.push(PageItem::Ellipsis);
+            items.push(PageItem::Page(total_pages));
+        }
+    }
+    items
+}
+
+#[derive(Clone)]
+struct PageSizeItem {
+    size: usize,
+    title: SharedString,
+}
+
+impl DropdownItem for PageSizeItem {
+    type Value = usize;
+
+    fn title(&self) -> SharedString {
+        self.title.clone()
+    }
+
+    fn value(&self) -> &Self::Value {
+        &self.size
+    }
+}
+
+/// A control to switch the pages of the data, e.g.: the rows of the table.
+pub struct Pagination {
+    /// The current page, start from 1.
+    page: usize,
+    total: usize,
+    page_size: usize,
+    siblings: usize,
+    jumper: bool,
+    jump_input: View<TextInput>,
+    page_size_dropdown: Option<View<Dropdown<Vec<PageSizeItem>>>>,
+    on_page_change: Option<Rc<dyn Fn(usize, &mut WindowContext)>>,
+    on_page_size_change: Option<Rc<dyn Fn(usize, &mut WindowContext)>>,
+}
+
+impl Pagination {
+    pub fn new(total: usize, cx: &mut ViewContext<Self>) -> Self {
+        let jump_input = cx.new_view(|cx| TextInput::new(cx).small());
+        cx.subscribe(
+            &jump_input,
+            |this: &mut Self, input, event: &InputEvent, cx| {
+                if let InputEvent::PressEnter = event {
+                    let text = input.read(cx).text();
+                    if let Ok(page) = text.trim().parse::<usize>() {
+                        this.go_to(page, cx);
+                    }
+                    input.update(cx, |input, cx| input.set_text("", cx));
+                }
+            },
+        )
+        .detach();
+
+        Self {
+            page: 1,
+            total,
+            page_size: 10,
+            siblings: 1,
+            jumper: false,
+            jump_input,
+            page_size_dropdown: None,
+            on_page_change: None,
+            on_page_size_change: None,
+        }
+    }
+
+    /// Set the number of the items in a page, default: 10.
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Set the number of the pages shown on each side of the current page, default: 1.
+    pub fn siblings(mut self, siblings: usize) -> Self {
+        self.siblings = siblings;
+        self
+    }
+
+    /// Set true to show the input to jump to a page.
+    pub fn jumper(mut self, jumper: bool) -> Self {
+        self.jumper = jumper;
+        self
+    }
+
+    /// Show the selector of the page sizes, e.g.: `[10, 20, 50]`, the `0` is ignored.
+    pub fn page_sizes(mut self, mut sizes: Vec<usize>, cx: &mut ViewContext<Self>) -> Self {
+        sizes.retain(|size| *size > 0);
+        if !sizes.contains(&self.page_size) {
+            self.page_size = sizes.first().copied().unwrap_or(self.page_size);
+        }
+
+        let selected_index = sizes.iter().position(|size| *size == self.page_size);
+        let items = sizes
+            .into_iter()
+            .map(|size| PageSizeItem {
+                size,
+                title: t!("Pagination.per page", count = size).into(),
+            })
+            .collect::<Vec<_>>();
+
+        let dropdown = cx.new_view(|cx| {
+            Dropdown::new("page-size", items, selected_index, cx)
+                .small()
+                .width(px(110.))
+        });
+        cx.subscribe(&dropdown, |this, _, event, cx| {
+            if let DropdownEvent::Confirm(Some(size)) = event {
+                this.change_page_size(*size, cx);
+            }
+        })
+        .detach();
+
+        self.page_size_dropdown = Some(dropdown);
+        self
+    }
+
+    /// Set the handler when the page is changed by the user, with the new page that start from 1.
+    pub fn on_page_change(mut self, handler: impl Fn(usize, &mut WindowContext) + 'static) -> Self {
+        self.on_page_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Set the handler when the page size is changed by the selector.
+    pub fn on_page_size_change(
+        mut self,
+        handler: impl Fn(usize, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_page_size_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Return the current page, start from 1.
+    pub fn current_page(&self) -> usize {
+        self.page
+    }
+
+    pub fn current_page_size(&self) -> usize {
+        self.page_size
+    }
+
+    pub fn total_pages(&self) -> usize {
+        total_pages(self.total, self.page_size)
+    }
+
+    /// Set the current page without calling the `on_page_change`, the page is clamped in the total pages.
+    pub fn set_page(&mut self, page: usize, cx: &mut ViewContext<Self>) {
+        self.page = page.clamp(1, self.total_pages());
+        cx.notify();
+    }
+
+    /// Set the number of the total items, e.g.: when the data is reloaded.
+    pub fn set_total(&mut self, total: usize, cx: &mut ViewContext<Self>) {
+        self.total = total;
+        self.set_page(self.page, cx);
+    }
+
+    fn go_to(&mut self, page: usize, cx: &mut ViewContext<Self>) {
+        let page = page.clamp(1, self.total_pages());
+        if page == self.page {
+            return;
+        }
+
+        self.page = page;
+        if let Some(on_page_change) = self.on_page_change.clone() {
+            on_page_change(page, cx);
+        }
+        cx.notify();
+    }
+
+    fn change_page_size(&mut self, page_size: usize, cx: &mut ViewContext<Self>) {
+        if page_size == self.page_size {
+            return;
+        }
+
+        // Keep the first item of the current page visible.
+        let first = (self.page - 1) * self.page_size;
+        self.page_size = page_size;
+        if let Some(on_page_size_change) = self.on_page_size_change.clone() {
+            on_page_size_change(page_size, cx);
+        }
+        self.go_to(first / page_size + 1, cx);
+        cx.notify();
+    }
+}
+
+impl Render for Pagination {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let page = self.page;
+        let total_pages = self.total_pages();
+
+        h_flex()
+            .gap_1()
+            .child(
+                Button::new("prev")
+                    .icon(IconName::ChevronLeft)
+                    .ghost()
+                    .small()
+                    .disabled(page <= 1)
+                    .on_click(cx.listener(move |this, _, cx| this.go_to(page - 1, cx))),
+            )
+            .children(
+                page_items(page, total_pages, self.siblings)
+                    .into_iter()
+                    .map(|item| match item {
+                        PageItem::Page(p) => Button::new(("page", p))
+                            .ghost()
+                            .small()
+                            .min_w(px(28.))
+                            .label(p.to_string())
+                            .selected(p == page)
+                            .on_click(cx.listener(move |this, _, cx| this.go_to(p, cx)))
+                            .into_any_element(),
+                        PageItem::Ellipsis => div()
+                            .px_1()
+                            .text_color(cx.theme().muted_foreground)
+                            .child("…")
+                            .into_any_element(),
+                    }),
+            )
+            .child(
+                Button::new("next")
+                    .icon(IconName::ChevronRight)
+                    .ghost()
+                    .small()
+                    .disabled(page >= total_pages)
+                    .on_click(cx.listener(move |this, _, cx| this.go_to(page + 1, cx))),
+            )
+            .when_some(self.page_size_dropdown.clone(), |this, dropdown| {
+                this.child(div().ml_2().child(dropdown))
+            })
+            .when(self.jumper, |this| {
+                this.child(
+                    h_flex()
+                        .ml_2()
+                        .gap_2()
+                        .text_sm()
+                        .child(t!("Pagination.Go to").to_string())
+                        .child(div().w(px(56.)).child(self.jump_input.clone())),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{page_items, total_pages, PageItem::*};
+
+    #[test]
+    fn test_total_pages() {
+        assert_eq!(total_pages(0, 10), 1);
+        assert_eq!(total_pages(95, 10), 10);
+        assert_eq!(total_pages(100, 10), 10);
+        assert_eq!(total_pages(101, 10), 11);
+    }
+
+    #[test]
+    fn test_page_items() {
+        assert_eq!(
+            page_items(2, 5, 1),
+            vec![Page(1), Page(2), Page(3), Page(4), Page(5)]
+        );
+        assert_eq!(
+            page_items(1, 10, 1),
+            vec![
+                Page(1),
+                Page(2),
+                Page(3),
+                Page(4),
+                Page(5),
+                Ellipsis,
+                Page(10)
+            ]
+        );
+        assert_eq!(
+            page_items(5, 10, 1),
+            vec![
+                Page(1),
+                Ellipsis,
+                Page(4),
+                Page(5),
+                Page(6),
+                Ellipsis,
+                Page(10)
+            ]
+        );
+        assert_eq!(
+            page_items(10, 10, 1),
+            vec![
+                Page(1),
+                Ellipsis,
+                Page(6),
+                Page(7),
+                Page(8),
+                Page(9),
+                Page(10)
+            ]
+        );
+    }
+}
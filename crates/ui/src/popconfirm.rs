@@ -0,0 +1,251 @@
+use std::rc::Rc;
+
+use gpui::{
+    actions, div, prelude::FluentBuilder as _, px, AppContext, DismissEvent, ElementId,
+    EventEmitter, FocusHandle, FocusableView, InteractiveElement as _, IntoElement, KeyBinding,
+    ParentElement, Render, RenderOnce, SharedString, Styled, ViewContext, VisualContext as _,
+    WindowContext,
+};
+use rust_i18n::t;
+
+use crate::{
+    button::{Button, ButtonStyled as _},
+    h_flex,
+    popover::{Escape, Popover},
+    theme::ActiveTheme as _,
+    v_flex, IconName, Placement, Selectable, Sizable as _,
+};
+
+actions!(popconfirm, [Confirm]);
+
+const CONTEXT: &str = "Popconfirm";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("enter", Confirm, Some(CONTEXT)),
+        KeyBinding::new("escape", Escape, Some(CONTEXT)),
+    ])
+}
+
+/// A small confirmation popover anchored to the trigger, for the lightweight destructive actions.
+///
+/// ```ignore
+/// Popconfirm::new("delete")
+///     .trigger(Button::new("delete").danger().label("Delete"))
+///     .title("Delete this item?")
+///     .on_confirm(|cx| { ... })
+/// ```
+#[derive(IntoElement)]
+pub struct Popconfirm {
+    base: Popover<PopconfirmContent>,
+    title: Option<SharedString>,
+    description: Option<SharedString>,
+    ok_text: Option<SharedString>,
+    cancel_text: Option<SharedString>,
+    danger: bool,
+    on_confirm: Option<Rc<dyn Fn(&mut WindowContext)>>,
+    on_cancel: Option<Rc<dyn Fn(&mut WindowContext)>>,
+}
+
+impl Popconfirm {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            base: Popover::new(id).placement(Placement::Top).arrow(true),
+            title: None,
+            description: None,
+            ok_text: None,
+            cancel_text: None,
+            danger: false,
+            on_confirm: None,
+            on_cancel: None,
+        }
+    }
+
+    /// Set the trigger element, click it to open the Popconfirm.
+    pub fn trigger<T>(mut self, trigger: T) -> Self
+    where
+        T: Selectable + IntoElement + 'static,
+    {
+        self.base = self.base.trigger(trigger);
+        self
+    }
+
+    /// Set the preferred side of the trigger to show the Popconfirm, default is `Placement::Top`.
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.base = self.base.placement(placement);
+        self
+    }
+
+    /// Set the title, defaults to "Are you sure?".
+    pub fn title(mut self, title: impl Into<SharedString>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the description below the title.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the text of the OK button, defaults to "OK".
+    pub fn ok_text(mut self, ok_text: impl Into<SharedString>) -> Self {
+        self.ok_text = Some(ok_text.into());
+        self
+    }
+
+    /// Set the text of the Cancel button, defaults to "Cancel".
+    pub fn cancel_text(mut self, cancel_text: impl Into<SharedString>) -> Self {
+        self.cancel_text = Some(cancel_text.into());
+        self
+    }
+
+    /// Set true to show the OK button as danger, default is `false`.
+    pub fn danger(mut self, danger: bool) -> Self {
+        self.danger = danger;
+        self
+    }
+
+    /// Set the callback when the OK button is clicked or Enter is pressed.
+    pub fn on_confirm(mut self, on_confirm: impl Fn(&mut WindowContext) + 'static) -> Self {
+        self.on_confirm = Some(Rc::new(on_confirm));
+        self
+    }
+
+    /// Set the callback when the Cancel button is clicked or Escape is pressed.
+    pub fn on_cancel(mut self, on_cancel: impl Fn(&mut WindowContext) + 'static) -> Self {
+        self.on_cancel = Some(Rc::new(on_cancel));
+        self
+    }
+}
+
+impl RenderOnce for Popconfirm {
+    fn render(self, _: &mut WindowContext) -> impl IntoElement {
+        let title = self
+            .title
+            .unwrap_or_else(|| t!("Popconfirm.Are you sure?").into());
+        let description = self.description;
+        let ok_text = self.ok_text.unwrap_or_else(|| t!("Dialog.OK").into());
+        let cancel_text = self
+            .cancel_text
+            .unwrap_or_else(|| t!("Dialog.Cancel").into());
+        let danger = self.danger;
+        let on_confirm = self.on_confirm;
+        let on_cancel = self.on_cancel;
+
+        self.base.content(move |cx| {
+            cx.new_view(|cx| PopconfirmContent {
+                focus_handle: cx.focus_handle(),
+                title: title.clone(),
+                description: description.clone(),
+                ok_text: ok_text.clone(),
+                cancel_text: cancel_text.clone(),
+                danger,
+                on_confirm: on_confirm.clone(),
+                on_cancel: on_cancel.clone(),
+            })
+        })
+    }
+}
+
+/// The content view of the [`Popconfirm`].
+pub struct PopconfirmContent {
+    focus_handle: FocusHandle,
+    title: SharedString,
+    description: Option<SharedString>,
+    ok_text: SharedString,
+    cancel_text: SharedString,
+    danger: bool,
+    on_confirm: Option<Rc<dyn Fn(&mut WindowContext)>>,
+    on_cancel: Option<Rc<dyn Fn(&mut WindowContext)>>,
+}
+
+impl PopconfirmContent {
+    fn confirm(&mut self, _: &Confirm, cx: &mut ViewContext<Self>) {
+        if let Some(on_confirm) = self.on_confirm.clone() {
+            on_confirm(cx);
+        }
+        cx.emit(DismissEvent);
+    }
+
+    fn cancel(&mut self, _: &Escape, cx: &mut ViewContext<Self>) {
+        if let Some(on_cancel) = self.on_cancel.clone() {
+            on_cancel(cx);
+        }
+        cx.emit(DismissEvent);
+    }
+}
+
+impl EventEmitter<DismissEvent> for PopconfirmContent {}
+
+impl FocusableView for PopconfirmContent {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for PopconfirmContent {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::confirm))
+            .on_action(cx.listener(Self::cancel))
+            .p_3()
+            .gap_3()
+            .max_w(px(320.))
+            .child(
+                h_flex()
+                    .items_start()
+                    .gap_2()
+                    .child(
+                        div()
+                            .mt_0p5()
+                            .text_color(if self.danger {
+                                cx.theme().destructive
+                            } else {
+                                crate::yellow_500()
+                            })
+                            .child(IconName::TriangleAlert),
+                    )
+                    .child(
+                        v_flex()
+                            .gap_1()
+                            .child(div().text_sm().font_semibold().child(self.title.clone()))
+                            .when_some(self.description.clone(), |this, description| {
+                                this.child(
+                                    div()
+                                        .text_sm()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(description),
+                                )
+                            }),
+                    ),
+            )
+            .child(
+                h_flex()
+                    .justify_end()
+                    .gap_2()
+                    .child(
+                        Button::new("popconfirm-cancel")
+                            .small()
+                            .outline()
+                            .label(self.cancel_text.clone())
+                            .on_click(cx.listener(|this, _, cx| this.cancel(&Escape, cx))),
+                    )
+                    .child(
+                        Button::new("popconfirm-ok")
+                            .small()
+                            .map(|this| {
+                                if self.danger {
+                                    this.danger()
+                                } else {
+                                    this.primary()
+                                }
+                            })
+                            .label(self.ok_text.clone())
+                            .on_click(cx.listener(|this, _, cx| this.confirm(&Confirm, cx))),
+                    ),
+            )
+    }
+}
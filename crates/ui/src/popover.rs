@@ -1,15 +1,21 @@
 use gpui::{
-    actions, anchored, deferred, div, prelude::FluentBuilder as _, px, AnchorCorner, AnyElement,
-    AppContext, Bounds, DismissEvent, DispatchPhase, Element, ElementId, EventEmitter, FocusHandle,
-    FocusableView, GlobalElementId, Hitbox, InteractiveElement as _, IntoElement, KeyBinding,
-    LayoutId, ManagedView, MouseButton, MouseDownEvent, ParentElement, Pixels, Point, Render,
-    Style, Styled, View, ViewContext, VisualContext, WindowContext,
+    actions, anchored, canvas, deferred, div, point, prelude::FluentBuilder as _, px, AnchorCorner,
+    AnyElement, AppContext, Bounds, DismissEvent, DispatchPhase, Element, ElementId, EventEmitter,
+    FocusHandle, FocusableView, GlobalElementId, Hitbox, InteractiveElement as _, IntoElement,
+    KeyBinding, LayoutId, ManagedView, MouseButton, MouseDownEvent, ParentElement, Path, Pixels,
+    Point, Render, Size, Style, Styled, View, ViewContext, VisualContext, WindowContext,
+};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
 };
-use std::{cell::RefCell, rc::Rc};
 
-use crate::{Selectable, StyledExt as _};
+use crate::{theme::ActiveTheme as _, Placement, Selectable, StyledExt as _};
 
 const CONTEXT: &str = "Popover";
+/// The minimum distance between the popover and the window edges.
+const WINDOW_MARGIN: Pixels = px(8.);
+const ARROW_SIZE: Pixels = px(6.);
 
 actions!(popover, [Escape]);
 
@@ -62,9 +68,20 @@ impl Render for PopoverContent {
     }
 }
 
+/// The alignment of the popover along the edge of the trigger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Align {
+    Start,
+    Center,
+    End,
+}
+
 pub struct Popover<M: ManagedView> {
     id: ElementId,
-    anchor: AnchorCorner,
+    placement: Placement,
+    align: Align,
+    offset: Pixels,
+    arrow: bool,
     trigger: Option<Box<dyn FnOnce(bool, &WindowContext) -> AnyElement + 'static>>,
     content: Option<Rc<dyn Fn(&mut WindowContext) -> View<M> + 'static>>,
     mouse_button: MouseButton,
@@ -79,7 +96,10 @@ where
     pub fn new(id: impl Into<ElementId>) -> Self {
         Self {
             id: id.into(),
-            anchor: AnchorCorner::TopLeft,
+            placement: Placement::Bottom,
+            align: Align::Start,
+            offset: px(6.),
+            arrow: false,
             trigger: None,
             content: None,
             mouse_button: MouseButton::Left,
@@ -87,8 +107,38 @@ where
         }
     }
 
+    /// Set the corner of the popover to anchor at the trigger, default is `AnchorCorner::TopLeft`.
+    ///
+    /// For example, `AnchorCorner::TopLeft` shows the popover below the trigger and aligned to its left edge.
     pub fn anchor(mut self, anchor: AnchorCorner) -> Self {
-        self.anchor = anchor;
+        (self.placement, self.align) = match anchor {
+            AnchorCorner::TopLeft => (Placement::Bottom, Align::Start),
+            AnchorCorner::TopRight => (Placement::Bottom, Align::End),
+            AnchorCorner::BottomLeft => (Placement::Top, Align::Start),
+            AnchorCorner::BottomRight => (Placement::Top, Align::End),
+        };
+        self
+    }
+
+    /// Set the preferred side of the trigger to show the popover, the popover is centered to the trigger.
+    ///
+    /// The popover will flip to the opposite side if there is not enough space,
+    /// and shift along the edge to keep it inside the window.
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self.align = Align::Center;
+        self
+    }
+
+    /// Set the distance between the popover and the trigger, default is 6px.
+    pub fn offset(mut self, offset: impl Into<Pixels>) -> Self {
+        self.offset = offset.into();
+        self
+    }
+
+    /// Set true to show an arrow pointing at the trigger, default is `false`.
+    pub fn arrow(mut self, arrow: bool) -> Self {
+        self.arrow = arrow;
         self
     }
 
@@ -142,14 +192,68 @@ where
         base.child((trigger)(is_open, cx)).into_element()
     }
 
-    fn resolved_corner(&self, bounds: Bounds<Pixels>) -> Point<Pixels> {
-        match self.anchor {
-            AnchorCorner::TopLeft => AnchorCorner::BottomLeft,
-            AnchorCorner::TopRight => AnchorCorner::BottomRight,
-            AnchorCorner::BottomLeft => AnchorCorner::TopLeft,
-            AnchorCorner::BottomRight => AnchorCorner::TopRight,
-        }
-        .corner(bounds)
+    fn render_arrow(
+        placement: Placement,
+        arrow_offset: Pixels,
+        cx: &WindowContext,
+    ) -> impl IntoElement {
+        let bg = cx.theme().popover;
+        let border = cx.theme().border;
+        let (width, height) = if placement.is_horizontal() {
+            (ARROW_SIZE, ARROW_SIZE * 2.)
+        } else {
+            (ARROW_SIZE * 2., ARROW_SIZE)
+        };
+
+        canvas(
+            |_, _| {},
+            move |bounds, _, cx| {
+                // The arrow points at the trigger, the base of it covers the border of the popover.
+                let triangle = |inset: Pixels| {
+                    let o = bounds.origin;
+                    let (w, h) = (bounds.size.width, bounds.size.height);
+                    let points = match placement {
+                        Placement::Bottom => [
+                            point(o.x + inset, o.y + h + px(1.)),
+                            point(o.x + w / 2., o.y + inset),
+                            point(o.x + w - inset, o.y + h + px(1.)),
+                        ],
+                        Placement::Top => [
+                            point(o.x + inset, o.y - px(1.)),
+                            point(o.x + w / 2., o.y + h - inset),
+                            point(o.x + w - inset, o.y - px(1.)),
+                        ],
+                        Placement::Right => [
+                            point(o.x + w + px(1.), o.y + inset),
+                            point(o.x + inset, o.y + h / 2.),
+                            point(o.x + w + px(1.), o.y + h - inset),
+                        ],
+                        Placement::Left => [
+                            point(o.x - px(1.), o.y + inset),
+                            point(o.x + w - inset, o.y + h / 2.),
+                            point(o.x - px(1.), o.y + h - inset),
+                        ],
+                    };
+                    let mut path = Path::new(points[0]);
+                    path.line_to(points[1]);
+                    path.line_to(points[2]);
+                    path.line_to(points[0]);
+                    path
+                };
+
+                cx.paint_path(triangle(px(0.)), border);
+                cx.paint_path(triangle(px(1.)), bg);
+            },
+        )
+        .absolute()
+        .w(width)
+        .h(height)
+        .map(|this| match placement {
+            Placement::Bottom => this.top(-ARROW_SIZE).left(arrow_offset - ARROW_SIZE),
+            Placement::Top => this.bottom(-ARROW_SIZE).left(arrow_offset - ARROW_SIZE),
+            Placement::Right => this.left(-ARROW_SIZE).top(arrow_offset - ARROW_SIZE),
+            Placement::Left => this.right(-ARROW_SIZE).top(arrow_offset - ARROW_SIZE),
+        })
     }
 
     fn with_element_state<R>(
@@ -188,6 +292,8 @@ pub struct PopoverElementState<M> {
     content_view: Rc<RefCell<Option<View<M>>>>,
     /// Trigger bounds for positioning the popover.
     trigger_bounds: Option<Bounds<Pixels>>,
+    /// The size of the popover in the last frame, for flipping and shifting it.
+    popover_size: Rc<Cell<Option<Size<Pixels>>>>,
 }
 
 impl<M> Default for PopoverElementState<M> {
@@ -199,6 +305,7 @@ impl<M> Default for PopoverElementState<M> {
             trigger_element: None,
             content_view: Rc::new(RefCell::new(None)),
             trigger_bounds: None,
+            popover_size: Rc::new(Cell::new(None)),
         }
     }
 }
@@ -230,41 +337,70 @@ impl<M: ManagedView> Element for Popover<M> {
             if let Some(content_view) = element_state.content_view.borrow_mut().as_mut() {
                 is_open = true;
 
-                let mut anchored = anchored()
-                    .snap_to_window_with_margin(px(8.))
-                    .anchor(view.anchor);
-                if let Some(trigger_bounds) = element_state.trigger_bounds {
-                    anchored = anchored.position(view.resolved_corner(trigger_bounds));
-                }
+                let trigger_bounds = element_state.trigger_bounds.unwrap_or_default();
+                let popover_size = element_state.popover_size.get();
+                let show_arrow = view.arrow && !view.no_style;
+                let offset = if show_arrow {
+                    view.offset + ARROW_SIZE
+                } else {
+                    view.offset
+                };
+                let (placement, origin) = compute_position(
+                    view.placement,
+                    view.align,
+                    trigger_bounds,
+                    popover_size.unwrap_or_default(),
+                    cx.viewport_size(),
+                    offset,
+                );
+                let arrow_offset = compute_arrow_offset(
+                    placement,
+                    trigger_bounds,
+                    Bounds::new(origin, popover_size.unwrap_or_default()),
+                    px(cx.theme().radius),
+                );
 
                 let mut element = {
                     let content_view_mut = element_state.content_view.clone();
-                    let anchor = view.anchor;
+                    let size_state = element_state.popover_size.clone();
                     let no_style = view.no_style;
                     deferred(
-                        anchored.child(
-                            div()
-                                .size_full()
-                                .occlude()
-                                .when(!no_style, |this| this.popover_style(cx))
-                                .map(|this| match anchor {
-                                    AnchorCorner::TopLeft | AnchorCorner::TopRight => {
-                                        this.top_1p5()
-                                    }
-                                    AnchorCorner::BottomLeft | AnchorCorner::BottomRight => {
-                                        this.bottom_1p5()
-                                    }
-                                })
-                                .child(content_view.clone())
-                                .when(!no_style, |this| {
-                                    this.on_mouse_down_out(move |_, cx| {
-                                        // Update the element_state.content_view to `None`,
-                                        // so that the `paint`` method will not paint it.
-                                        *content_view_mut.borrow_mut() = None;
-                                        cx.refresh();
+                        anchored()
+                            .snap_to_window_with_margin(WINDOW_MARGIN)
+                            .position(origin)
+                            .child(
+                                div()
+                                    .size_full()
+                                    .occlude()
+                                    .when(!no_style, |this| this.popover_style(cx))
+                                    // Hide it until the size is measured, to avoid flashing at the wrong position.
+                                    .when(popover_size.is_none(), |this| this.opacity(0.))
+                                    .child(content_view.clone())
+                                    .child(
+                                        canvas(
+                                            move |bounds, cx| {
+                                                if size_state.get() != Some(bounds.size) {
+                                                    size_state.set(Some(bounds.size));
+                                                    cx.refresh();
+                                                }
+                                            },
+                                            |_, _, _| {},
+                                        )
+                                        .absolute()
+                                        .size_full(),
+                                    )
+                                    .when(show_arrow, |this| {
+                                        this.child(Self::render_arrow(placement, arrow_offset, cx))
                                     })
-                                }),
-                        ),
+                                    .when(!no_style, |this| {
+                                        this.on_mouse_down_out(move |_, cx| {
+                                            // Update the element_state.content_view to `None`,
+                                            // so that the `paint`` method will not paint it.
+                                            *content_view_mut.borrow_mut() = None;
+                                            cx.refresh();
+                                        })
+                                    }),
+                            ),
                     )
                     .with_priority(1)
                     .into_any()
@@ -386,3 +522,200 @@ impl<M: ManagedView> Element for Popover<M> {
         });
     }
 }
+
+/// Returns the placement after flipping and the origin of the popover.
+///
+/// The popover is flipped to the opposite side of the trigger if it overflows the window and
+/// there is more space there, then shifted along the edge of the trigger to keep it inside the window.
+fn compute_position(
+    placement: Placement,
+    align: Align,
+    trigger_bounds: Bounds<Pixels>,
+    popover_size: Size<Pixels>,
+    viewport_size: Size<Pixels>,
+    offset: Pixels,
+) -> (Placement, Point<Pixels>) {
+    let space = |placement: Placement| match placement {
+        Placement::Top => trigger_bounds.top() - offset - WINDOW_MARGIN,
+        Placement::Bottom => {
+            viewport_size.height - trigger_bounds.bottom() - offset - WINDOW_MARGIN
+        }
+        Placement::Left => trigger_bounds.left() - offset - WINDOW_MARGIN,
+        Placement::Right => viewport_size.width - trigger_bounds.right() - offset - WINDOW_MARGIN,
+    };
+    let opposite = match placement {
+        Placement::Top => Placement::Bottom,
+        Placement::Bottom => Placement::Top,
+        Placement::Left => Placement::Right,
+        Placement::Right => Placement::Left,
+    };
+    let main_size = if placement.is_horizontal() {
+        popover_size.width
+    } else {
+        popover_size.height
+    };
+    let placement = if space(placement) < main_size && space(opposite) > space(placement) {
+        opposite
+    } else {
+        placement
+    };
+
+    let align_to = |start: Pixels, end: Pixels, size: Pixels, viewport: Pixels| {
+        let pos = match align {
+            Align::Start => start,
+            Align::Center => start + (end - start - size) / 2.,
+            Align::End => end - size,
+        };
+        let max = (viewport - size - WINDOW_MARGIN).max(WINDOW_MARGIN);
+        pos.max(WINDOW_MARGIN).min(max)
+    };
+
+    let origin = match placement {
+        Placement::Top | Placement::Bottom => {
+            let x = align_to(
+                trigger_bounds.left(),
+                trigger_bounds.right(),
+                popover_size.width,
+                viewport_size.width,
+            );
+            let y = if placement == Placement::Top {
+                trigger_bounds.top() - offset - popover_size.height
+            } else {
+                trigger_bounds.bottom() + offset
+            };
+            point(x, y)
+        }
+        Placement::Left | Placement::Right => {
+            let y = align_to(
+                trigger_bounds.top(),
+                trigger_bounds.bottom(),
+                popover_size.height,
+                viewport_size.height,
+            );
+            let x = if placement == Placement::Left {
+                trigger_bounds.left() - offset - popover_size.width
+            } else {
+                trigger_bounds.right() + offset
+            };
+            point(x, y)
+        }
+    };
+
+    (placement, origin)
+}
+
+/// Returns the offset of the arrow center along the edge of the popover, pointing at the trigger center.
+fn compute_arrow_offset(
+    placement: Placement,
+    trigger_bounds: Bounds<Pixels>,
+    popover_bounds: Bounds<Pixels>,
+    radius: Pixels,
+) -> Pixels {
+    let (offset, len) = if placement.is_horizontal() {
+        (
+            trigger_bounds.center().y - popover_bounds.top(),
+            popover_bounds.size.height,
+        )
+    } else {
+        (
+            trigger_bounds.center().x - popover_bounds.left(),
+            popover_bounds.size.width,
+        )
+    };
+
+    let min = radius + ARROW_SIZE;
+    offset.min(len - min).max(min)
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::size;
+
+    use super::*;
+
+    #[test]
+    fn test_compute_position() {
+        let viewport = size(px(800.), px(600.));
+        let popover = size(px(200.), px(100.));
+        let trigger = Bounds::new(point(px(300.), px(100.)), size(px(100.), px(30.)));
+
+        // Enough space at the bottom.
+        let (placement, origin) = compute_position(
+            Placement::Bottom,
+            Align::Center,
+            trigger,
+            popover,
+            viewport,
+            px(6.),
+        );
+        assert_eq!(placement, Placement::Bottom);
+        assert_eq!(origin, point(px(250.), px(136.)));
+
+        // Not enough space at the top, flip to the bottom.
+        let (placement, origin) = compute_position(
+            Placement::Top,
+            Align::Start,
+            trigger,
+            popover,
+            viewport,
+            px(6.),
+        );
+        assert_eq!(placement, Placement::Bottom);
+        assert_eq!(origin, point(px(300.), px(136.)));
+
+        // Not enough space at the bottom, flip to the top.
+        let trigger = Bounds::new(point(px(300.), px(520.)), size(px(100.), px(30.)));
+        let (placement, origin) = compute_position(
+            Placement::Bottom,
+            Align::Start,
+            trigger,
+            popover,
+            viewport,
+            px(6.),
+        );
+        assert_eq!(placement, Placement::Top);
+        assert_eq!(origin, point(px(300.), px(414.)));
+
+        // Shift to keep inside the window.
+        let trigger = Bounds::new(point(px(740.), px(100.)), size(px(50.), px(30.)));
+        let (placement, origin) = compute_position(
+            Placement::Bottom,
+            Align::Center,
+            trigger,
+            popover,
+            viewport,
+            px(6.),
+        );
+        assert_eq!(placement, Placement::Bottom);
+        assert_eq!(origin, point(px(592.), px(136.)));
+
+        // Flip from right to left.
+        let (placement, origin) = compute_position(
+            Placement::Right,
+            Align::Center,
+            trigger,
+            popover,
+            viewport,
+            px(6.),
+        );
+        assert_eq!(placement, Placement::Left);
+        assert_eq!(origin, point(px(534.), px(65.)));
+    }
+
+    #[test]
+    fn test_compute_arrow_offset() {
+        let popover = Bounds::new(point(px(592.), px(136.)), size(px(200.), px(100.)));
+        let trigger = Bounds::new(point(px(700.), px(100.)), size(px(50.), px(30.)));
+        assert_eq!(
+            compute_arrow_offset(Placement::Bottom, trigger, popover, px(4.)),
+            px(133.)
+        );
+
+        // Keep the arrow away from the rounded corners.
+        let trigger = Bounds::new(point(px(780.), px(100.)), size(px(20.), px(30.)));
+        assert_eq!(
+            compute_arrow_offset(Placement::Bottom, trigger, popover, px(4.)),
+            px(190.)
+        );
+    }
+}
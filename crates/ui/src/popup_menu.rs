@@ -3,18 +3,33 @@ use std::rc::Rc;
 
 use gpui::{
     actions, div, prelude::FluentBuilder, px, Action, AppContext, DismissEvent, EventEmitter,
-    FocusHandle, InteractiveElement, IntoElement, KeyBinding, ParentElement, Pixels, Render,
-    SharedString, Styled as _, View, ViewContext, VisualContext as _, WindowContext,
+    FocusHandle, InteractiveElement, IntoElement, KeyBinding, KeyDownEvent, ParentElement, Pixels,
+    Render, SharedString, Styled as _, View, ViewContext, VisualContext as _, WindowContext,
 };
 use gpui::{anchored, canvas, rems, AnchorCorner, Bounds, FocusableView, WeakView};
 
 use crate::StyledExt;
 use crate::{
-    button::Button, h_flex, list::ListItem, popover::Popover, theme::ActiveTheme, v_flex, Icon,
-    IconName, Selectable, Sizable as _,
+    button::Button,
+    h_flex,
+    kbd::Kbd,
+    list::{ListItem, TypeAhead},
+    popover::Popover,
+    theme::ActiveTheme,
+    v_flex, Icon, IconName, Selectable, Sizable as _,
 };
 
-actions!(menu, [Confirm, Dismiss, SelectNext, SelectPrev]);
+actions!(
+    menu,
+    [
+        Confirm,
+        Dismiss,
+        SelectNext,
+        SelectPrev,
+        SelectLeft,
+        SelectRight
+    ]
+);
 
 pub fn init(cx: &mut AppContext) {
     let context = Some("PopupMenu");
@@ -23,6 +38,8 @@ pub fn init(cx: &mut AppContext) {
         KeyBinding::new("escape", Dismiss, context),
         KeyBinding::new("up", SelectPrev, context),
         KeyBinding::new("down", SelectNext, context),
+        KeyBinding::new("left", SelectLeft, context),
+        KeyBinding::new("right", SelectRight, context),
     ]);
 }
 
@@ -45,6 +62,9 @@ enum PopupMenuItem {
         icon: Option<Icon>,
         label: SharedString,
         action: Option<Box<dyn Action>>,
+        disabled: bool,
+        /// Some for the radio item, the value is checked or not.
+        radio: Option<bool>,
         handler: Rc<dyn Fn(&mut WindowContext)>,
     },
     Submenu {
@@ -56,7 +76,10 @@ enum PopupMenuItem {
 
 impl PopupMenuItem {
     fn is_clickable(&self) -> bool {
-        !matches!(self, PopupMenuItem::Separator)
+        !matches!(
+            self,
+            PopupMenuItem::Separator | PopupMenuItem::Item { disabled: true, .. }
+        )
     }
 
     fn is_separator(&self) -> bool {
@@ -64,7 +87,17 @@ impl PopupMenuItem {
     }
 
     fn has_icon(&self) -> bool {
-        matches!(self, PopupMenuItem::Item { icon: Some(_), .. })
+        matches!(
+            self,
+            PopupMenuItem::Item { icon: Some(_), .. } | PopupMenuItem::Item { radio: Some(_), .. }
+        )
+    }
+
+    fn label(&self) -> Option<&SharedString> {
+        match self {
+            PopupMenuItem::Separator => None,
+            PopupMenuItem::Item { label, .. } | PopupMenuItem::Submenu { label, .. } => Some(label),
+        }
     }
 }
 
@@ -79,6 +112,8 @@ pub struct PopupMenu {
     max_width: Pixels,
     hovered_menu_ix: Option<usize>,
     bounds: Bounds<Pixels>,
+    /// The typed characters to select the item by the label.
+    type_ahead: TypeAhead,
 
     action_focus_handle: Option<FocusHandle>,
     _subscriptions: [gpui::Subscription; 1],
@@ -92,6 +127,12 @@ impl PopupMenu {
         cx.new_view(|cx| {
             let focus_handle = cx.focus_handle();
             let _on_blur_subscription = cx.on_blur(&focus_handle, |this: &mut PopupMenu, cx| {
+                // The focus is moved back to the parent menu from this submenu.
+                if let Some(parent_menu) = this.parent_menu.as_ref().and_then(|m| m.upgrade()) {
+                    if parent_menu.focus_handle(cx).is_focused(cx) {
+                        return;
+                    }
+                }
                 this.dismiss(&Dismiss, cx)
             });
 
@@ -106,6 +147,7 @@ impl PopupMenu {
                 has_icon: false,
                 hovered_menu_ix: None,
                 bounds: Bounds::default(),
+                type_ahead: TypeAhead::default(),
                 _subscriptions: [_on_blur_subscription],
             };
             cx.refresh();
@@ -133,7 +175,32 @@ impl PopupMenu {
 
     /// Add Menu Item
     pub fn menu(mut self, label: impl Into<SharedString>, action: Box<dyn Action>) -> Self {
-        self.add_menu_item(label, None, action);
+        self.add_menu_item(label, None, action, false);
+        self
+    }
+
+    /// Add Menu Item with disabled state, the disabled item can't be clicked or selected by keyboard.
+    pub fn menu_with_disabled(
+        mut self,
+        label: impl Into<SharedString>,
+        action: Box<dyn Action>,
+        disabled: bool,
+    ) -> Self {
+        self.add_menu_item(label, None, action, disabled);
+        self
+    }
+
+    /// Add Menu Item with radio indicator, use it for a group of items that only one can be checked.
+    pub fn menu_with_radio(
+        mut self,
+        label: impl Into<SharedString>,
+        checked: bool,
+        action: Box<dyn Action>,
+    ) -> Self {
+        self.add_menu_item(label, None, action, false);
+        if let Some(PopupMenuItem::Item { radio, .. }) = self.menu_items.last_mut() {
+            *radio = Some(checked);
+        }
         self
     }
 
@@ -144,6 +211,8 @@ impl PopupMenu {
             icon: None,
             label: label.into(),
             action: None,
+            disabled: false,
+            radio: None,
             handler: Rc::new(move |cx| cx.open_url(&href)),
         });
         self
@@ -161,11 +230,35 @@ impl PopupMenu {
             icon: Some(icon.into()),
             label: label.into(),
             action: None,
+            disabled: false,
+            radio: None,
             handler: Rc::new(move |cx| cx.open_url(&href)),
         });
         self
     }
 
+    /// Add Menu Item to call the handler when clicked, instead of dispatching an action.
+    pub fn menu_with_handler(
+        mut self,
+        label: impl Into<SharedString>,
+        icon: Option<Icon>,
+        handler: impl Fn(&mut WindowContext) + 'static,
+    ) -> Self {
+        if icon.is_some() {
+            self.has_icon = true;
+        }
+
+        self.menu_items.push(PopupMenuItem::Item {
+            icon,
+            label: label.into(),
+            action: None,
+            disabled: false,
+            radio: None,
+            handler: Rc::new(handler),
+        });
+        self
+    }
+
     /// Add Menu Item with Icon
     pub fn menu_with_icon(
         mut self,
@@ -173,7 +266,7 @@ impl PopupMenu {
         icon: impl Into<Icon>,
         action: Box<dyn Action>,
     ) -> Self {
-        self.add_menu_item(label, Some(icon.into()), action);
+        self.add_menu_item(label, Some(icon.into()), action, false);
         self
     }
 
@@ -185,9 +278,9 @@ impl PopupMenu {
         action: Box<dyn Action>,
     ) -> Self {
         if checked {
-            self.add_menu_item(label, Some(IconName::Check.into()), action);
+            self.add_menu_item(label, Some(IconName::Check.into()), action, false);
         } else {
-            self.add_menu_item(label, None, action);
+            self.add_menu_item(label, None, action, false);
         }
 
         self
@@ -198,6 +291,7 @@ impl PopupMenu {
         label: impl Into<SharedString>,
         icon: Option<Icon>,
         action: Box<dyn Action>,
+        disabled: bool,
     ) -> &mut Self {
         if icon.is_some() {
             self.has_icon = true;
@@ -209,6 +303,8 @@ impl PopupMenu {
             icon,
             label: label.into(),
             action: Some(action.boxed_clone()),
+            disabled,
+            radio: None,
             handler: Rc::new(move |cx| {
                 cx.activate_window();
 
@@ -311,10 +407,15 @@ impl PopupMenu {
             Some(index) => {
                 let item = self.menu_items.get(index);
                 match item {
-                    Some(PopupMenuItem::Item { handler, .. }) => {
+                    Some(PopupMenuItem::Item {
+                        handler,
+                        disabled: false,
+                        ..
+                    }) => {
                         handler(cx);
                         self.dismiss(&Dismiss, cx)
                     }
+                    Some(PopupMenuItem::Submenu { .. }) => self.select_right(&SelectRight, cx),
                     _ => {}
                 }
             }
@@ -323,28 +424,103 @@ impl PopupMenu {
     }
 
     fn select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
-        let count = self.clickable_menu_items().count();
-        if count > 0 {
-            let ix = self
-                .selected_index
-                .map(|index| if index == count - 1 { 0 } else { index + 1 })
-                .unwrap_or(0);
-
-            self.selected_index = Some(ix);
-            cx.notify();
+        let ixs = self
+            .clickable_menu_items()
+            .map(|(ix, _)| ix)
+            .collect::<Vec<_>>();
+        if ixs.is_empty() {
+            return;
         }
+
+        let pos = self
+            .selected_index
+            .and_then(|selected| ixs.iter().position(|ix| *ix == selected));
+        self.selected_index = Some(match pos {
+            Some(pos) => ixs[(pos + 1) % ixs.len()],
+            None => ixs[0],
+        });
+        cx.notify();
     }
 
     fn select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
-        let count = self.clickable_menu_items().count();
-        if count > 0 {
-            let ix = self
-                .selected_index
-                .map(|index| if index == count - 1 { 0 } else { index - 1 })
-                .unwrap_or(count - 1);
+        let ixs = self
+            .clickable_menu_items()
+            .map(|(ix, _)| ix)
+            .collect::<Vec<_>>();
+        if ixs.is_empty() {
+            return;
+        }
+
+        let pos = self
+            .selected_index
+            .and_then(|selected| ixs.iter().position(|ix| *ix == selected));
+        self.selected_index = Some(match pos {
+            Some(pos) => ixs[(pos + ixs.len() - 1) % ixs.len()],
+            None => ixs[ixs.len() - 1],
+        });
+        cx.notify();
+    }
+
+    /// Close this submenu, and focus back to the parent menu.
+    fn select_left(&mut self, _: &SelectLeft, cx: &mut ViewContext<Self>) {
+        let Some(parent_menu) = self.parent_menu.as_ref().and_then(|m| m.upgrade()) else {
+            return;
+        };
+
+        self.selected_index = None;
+        parent_menu.update(cx, |view, cx| {
+            view.hovered_menu_ix = None;
+            view.focus_handle.focus(cx);
+            cx.notify();
+        });
+    }
+
+    /// Open the selected submenu, and focus it.
+    fn select_right(&mut self, _: &SelectRight, cx: &mut ViewContext<Self>) {
+        let Some(ix) = self.selected_index else {
+            return;
+        };
+        let Some(PopupMenuItem::Submenu { menu, .. }) = self.menu_items.get(ix) else {
+            return;
+        };
+
+        let menu = menu.clone();
+        // Set the hovered menu before focus the submenu, so the blur of this menu will not dismiss it.
+        self.hovered_menu_ix = Some(ix);
+        menu.update(cx, |view, cx| {
+            view.selected_index = view.clickable_menu_items().map(|(ix, _)| ix).next();
+            view.focus_handle.focus(cx);
+            cx.notify();
+        });
+        cx.notify();
+    }
+
+    /// Select the item by typing the beginning of the label.
+    fn on_key_down(&mut self, event: &KeyDownEvent, cx: &mut ViewContext<Self>) {
+        let keystroke = &event.keystroke;
+        let modifiers = &keystroke.modifiers;
+        if modifiers.control || modifiers.alt || modifiers.platform || modifiers.function {
+            return;
+        }
+
+        let key = keystroke.ime_key.as_ref().unwrap_or(&keystroke.key);
+        if !self.type_ahead.push(key) {
+            return;
+        }
+
+        let ix = self
+            .type_ahead
+            .find(self.selected_index, self.menu_items.len(), |ix| {
+                self.menu_items
+                    .get(ix)
+                    .filter(|item| item.is_clickable())
+                    .and_then(|item| item.label().cloned())
+            });
+        if let Some(ix) = ix {
             self.selected_index = Some(ix);
             cx.notify();
         }
+        cx.stop_propagation();
     }
 
     fn dismiss(&mut self, _: &Dismiss, cx: &mut ViewContext<Self>) {
@@ -366,26 +542,16 @@ impl PopupMenu {
         action: Option<Box<dyn Action>>,
         cx: &ViewContext<Self>,
     ) -> Option<impl IntoElement> {
-        if let Some(action) = action {
-            if let Some(keybinding) = cx.bindings_for_action(action.deref()).first() {
-                let el = div().text_color(cx.theme().muted_foreground).children(
-                    keybinding
-                        .keystrokes()
-                        .into_iter()
-                        .map(|keystroke| format!("{}", keystroke)),
-                );
-
-                return Some(el);
-            }
-        }
-
-        return None;
+        action
+            .and_then(|action| Kbd::binding_for_action(action.deref(), cx))
+            .map(|kbd| kbd.appearance(false))
     }
 
     fn render_icon(
         has_icon: bool,
         icon: Option<Icon>,
-        _: &ViewContext<Self>,
+        radio: Option<bool>,
+        cx: &ViewContext<Self>,
     ) -> Option<impl IntoElement> {
         let icon_placeholder = if has_icon { Some(Icon::empty()) } else { None };
 
@@ -400,7 +566,16 @@ impl PopupMenu {
             .justify_center()
             .text_sm()
             .map(|this| {
-                if let Some(icon) = icon {
+                if let Some(checked) = radio {
+                    this.when(checked, |this| {
+                        this.child(
+                            div()
+                                .size_1p5()
+                                .rounded_full()
+                                .bg(cx.theme().popover_foreground),
+                        )
+                    })
+                } else if let Some(icon) = icon {
                     this.child(icon.clone().small().clone())
                 } else {
                     this.children(icon_placeholder.clone())
@@ -434,6 +609,9 @@ impl Render for PopupMenu {
             .on_action(cx.listener(Self::select_prev))
             .on_action(cx.listener(Self::confirm))
             .on_action(cx.listener(Self::dismiss))
+            .on_action(cx.listener(Self::select_left))
+            .on_action(cx.listener(Self::select_right))
+            .on_key_down(cx.listener(Self::on_key_down))
             .on_mouse_down_out(cx.listener(|this, _, cx| this.dismiss(&Dismiss, cx)))
             .max_h(self.max_width)
             .min_w(self.min_width)
@@ -469,8 +647,24 @@ impl Render for PopupMenu {
                             .h(px(28.))
                             .rounded_md()
                             .items_center()
+                            .selected(self.selected_index == Some(ix))
                             .on_mouse_enter(cx.listener(move |this, _, cx| {
+                                // Move the focus back from the submenu opened by keyboard.
+                                if this.hovered_menu_ix != Some(ix)
+                                    && !this.focus_handle.is_focused(cx)
+                                    && this.focus_handle.contains_focused(cx)
+                                {
+                                    this.focus_handle.focus(cx);
+                                }
+
                                 this.hovered_menu_ix = Some(ix);
+                                if this
+                                    .menu_items
+                                    .get(ix)
+                                    .map_or(false, |item| item.is_clickable())
+                                {
+                                    this.selected_index = Some(ix);
+                                }
                                 cx.notify();
                             }));
 
@@ -487,17 +681,32 @@ impl Render for PopupMenu {
                                 icon,
                                 label,
                                 action,
+                                disabled,
+                                radio,
                                 ..
                             } => {
                                 let action = action.as_ref().map(|action| action.boxed_clone());
                                 let key = Self::render_keybinding(action, cx);
 
-                                this.on_click(cx.listener(move |this, _, cx| this.on_click(ix, cx)))
+                                this.disabled(*disabled)
+                                    .when(*disabled, |this| {
+                                        this.text_color(cx.theme().muted_foreground)
+                                    })
+                                    .when(!*disabled, |this| {
+                                        this.on_click(
+                                            cx.listener(move |this, _, cx| this.on_click(ix, cx)),
+                                        )
+                                    })
                                     .child(
                                         h_flex()
                                             .items_center()
                                             .gap_x_1p5()
-                                            .children(Self::render_icon(has_icon, icon.clone(), cx))
+                                            .children(Self::render_icon(
+                                                has_icon,
+                                                icon.clone(),
+                                                *radio,
+                                                cx,
+                                            ))
                                             .child(
                                                 h_flex()
                                                     .flex_1()
@@ -522,6 +731,7 @@ impl Render for PopupMenu {
                                                 .children(Self::render_icon(
                                                     has_icon,
                                                     icon.clone(),
+                                                    None,
                                                     cx,
                                                 ))
                                                 .child(
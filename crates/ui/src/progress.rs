@@ -1,56 +1,315 @@
+use std::{
+    f32::consts::{FRAC_PI_2, TAU},
+    time::Duration,
+};
+
 use gpui::{
-    div, prelude::FluentBuilder, px, relative, IntoElement, ParentElement, RenderOnce, Styled,
+    canvas, div, ease_in_out, point, prelude::FluentBuilder, px, relative, Animation,
+    AnimationExt as _, Bounds, Hsla, IntoElement, ParentElement, Path, Pixels, RenderOnce, Styled,
     WindowContext,
 };
 
-use crate::theme::ActiveTheme;
+use crate::{
+    h_flex,
+    theme::{ActiveTheme, ComponentTheme, Theme},
+    Sizable, Size,
+};
+
+/// The style tokens of the [`Progress`] and [`CircularProgress`], override them by [`Theme::override_component`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProgressTheme {
+    pub bar: Hsla,
+    /// The background of the bar or the ring.
+    pub track: Hsla,
+    pub success: Hsla,
+    pub error: Hsla,
+}
+
+impl ComponentTheme for ProgressTheme {
+    fn from_theme(theme: &Theme) -> Self {
+        Self {
+            bar: theme.progress_bar,
+            track: theme.translucent(theme.progress_bar, 0.2),
+            success: crate::green_500(),
+            error: theme.destructive,
+        }
+    }
+}
 
+/// The status of the progress, the bar is colored by the status.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressStatus {
+    #[default]
+    Normal,
+    Success,
+    Error,
+}
+
+impl ProgressStatus {
+    fn color(&self, theme: &ProgressTheme) -> Hsla {
+        match self {
+            Self::Normal => theme.bar,
+            Self::Success => theme.success,
+            Self::Error => theme.error,
+        }
+    }
+}
+
+/// Return the value in 0..100 as 0.0..1.0.
+fn relative_value(value: f32) -> f32 {
+    (value / 100.).clamp(0., 1.)
+}
+
+/// A linear progress bar.
 #[derive(IntoElement)]
 pub struct Progress {
     value: f32,
-    height: f32,
+    size: Size,
+    indeterminate: bool,
+    status: ProgressStatus,
+    color: Option<Hsla>,
 }
 
 impl Progress {
     pub fn new() -> Self {
         Progress {
             value: Default::default(),
-            height: 8.,
+            size: Size::default(),
+            indeterminate: false,
+            status: ProgressStatus::default(),
+            color: None,
         }
     }
 
+    /// Set the percent of the progress, range of 0..100.
     pub fn value(mut self, value: f32) -> Self {
         self.value = value;
         self
     }
+
+    /// Set true to show the moving shimmer when the progress is unknown, the value is ignored.
+    pub fn indeterminate(mut self, indeterminate: bool) -> Self {
+        self.indeterminate = indeterminate;
+        self
+    }
+
+    pub fn status(mut self, status: ProgressStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Set the color of the bar, default is decided by the status.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+impl Sizable for Progress {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
 }
 
 impl RenderOnce for Progress {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
-        let rounded = px(self.height / 2.);
-        let relative_w = relative(match self.value {
-            v if v < 0. => 0.,
-            v if v > 100. => 1.,
-            v => v / 100.,
-        });
+        let theme = cx.theme().component::<ProgressTheme>();
+        let color = self.color.unwrap_or(self.status.color(&theme));
+        let height = match self.size {
+            Size::Size(size) => size,
+            Size::XSmall => px(2.),
+            Size::Small => px(4.),
+            Size::Medium => px(8.),
+            Size::Large => px(12.),
+        };
+        let rounded = height / 2.;
+        let relative_w = relative(relative_value(self.value));
 
         div()
             .relative()
-            .h(px(self.height))
+            .h(height)
             .rounded(rounded)
-            .bg(cx.theme().progress_bar.opacity(0.2))
+            .overflow_hidden()
+            .bg(theme.track)
+            .map(|this| {
+                if self.indeterminate {
+                    this.child(
+                        div()
+                            .absolute()
+                            .top_0()
+                            .h_full()
+                            .w(relative(0.4))
+                            .rounded(rounded)
+                            .bg(color)
+                            .with_animation(
+                                "progress-indeterminate",
+                                Animation::new(Duration::from_secs_f64(1.5))
+                                    .repeat()
+                                    .with_easing(ease_in_out),
+                                // Move from the outside of the left to the outside of the right.
+                                |this, delta| this.left(relative(delta * 1.4 - 0.4)),
+                            ),
+                    )
+                } else {
+                    this.child(
+                        div()
+                            .absolute()
+                            .top_0()
+                            .left_0()
+                            .h_full()
+                            .w(relative_w)
+                            .bg(color)
+                            .map(|this| match self.value {
+                                v if v >= 100. => this.rounded(rounded),
+                                _ => this.rounded_l(rounded),
+                            }),
+                    )
+                }
+            })
+    }
+}
+
+/// A circular progress ring with the percentage label in the center.
+#[derive(IntoElement)]
+pub struct CircularProgress {
+    value: f32,
+    size: Size,
+    status: ProgressStatus,
+    color: Option<Hsla>,
+    label: bool,
+}
+
+impl CircularProgress {
+    pub fn new() -> Self {
+        Self {
+            value: Default::default(),
+            size: Size::default(),
+            status: ProgressStatus::default(),
+            color: None,
+            label: true,
+        }
+    }
+
+    /// Set the percent of the progress, range of 0..100.
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = value;
+        self
+    }
+
+    pub fn status(mut self, status: ProgressStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Set the color of the ring, default is decided by the status.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Set false to hide the percentage label, the label is always hidden when the ring is smaller than 32px.
+    pub fn label(mut self, label: bool) -> Self {
+        self.label = label;
+        self
+    }
+}
+
+impl Sizable for CircularProgress {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+/// Paint the arc of the ring from the top clockwise, as a path between the outer and inner circles.
+fn paint_arc(
+    bounds: Bounds<Pixels>,
+    stroke: Pixels,
+    relative: f32,
+    color: Hsla,
+    cx: &mut WindowContext,
+) {
+    if relative <= 0. {
+        return;
+    }
+
+    let center = bounds.center();
+    let outer = bounds.size.width.min(bounds.size.height) / 2.;
+    let inner = outer - stroke;
+    let sweep = TAU * relative;
+    // A segment in every ~3 degrees.
+    let steps = ((sweep / 0.05).ceil() as usize).max(1);
+    let point_at = |radius: Pixels, step: usize| {
+        let angle = sweep * step as f32 / steps as f32 - FRAC_PI_2;
+        point(
+            center.x + radius * angle.cos(),
+            center.y + radius * angle.sin(),
+        )
+    };
+
+    let mut path = Path::new(point_at(outer, 0));
+    for step in 1..=steps {
+        path.line_to(point_at(outer, step));
+    }
+    for step in (0..=steps).rev() {
+        path.line_to(point_at(inner, step));
+    }
+    cx.paint_path(path, color);
+}
+
+impl RenderOnce for CircularProgress {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let theme = cx.theme().component::<ProgressTheme>();
+        let color = self.color.unwrap_or(self.status.color(&theme));
+        let track = theme.track;
+        let diameter = match self.size {
+            Size::Size(size) => size,
+            Size::XSmall => px(16.),
+            Size::Small => px(24.),
+            Size::Medium => px(40.),
+            Size::Large => px(64.),
+        };
+        let stroke = (diameter / 10.).max(px(2.));
+        let relative = relative_value(self.value);
+        let show_label = self.label && diameter >= px(32.);
+
+        div()
+            .relative()
+            .flex_shrink_0()
+            .size(diameter)
             .child(
-                div()
-                    .absolute()
-                    .top_0()
-                    .left_0()
-                    .h_full()
-                    .w(relative_w)
-                    .bg(cx.theme().progress_bar)
-                    .map(|this| match self.value {
-                        v if v >= 100. => this.rounded(rounded),
-                        _ => this.rounded_l(rounded),
-                    }),
+                canvas(
+                    |_, _| {},
+                    move |bounds, _, cx| {
+                        paint_arc(bounds, stroke, 1., track, cx);
+                        paint_arc(bounds, stroke, relative, color, cx);
+                    },
+                )
+                .absolute()
+                .size_full(),
             )
+            .when(show_label, |this| {
+                this.child(
+                    h_flex()
+                        .absolute()
+                        .size_full()
+                        .justify_center()
+                        .text_size(diameter * 0.26)
+                        .child(format!("{}%", (relative * 100.).round())),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::relative_value;
+
+    #[test]
+    fn test_relative_value() {
+        assert_eq!(relative_value(-10.), 0.);
+        assert_eq!(relative_value(25.), 0.25);
+        assert_eq!(relative_value(120.), 1.);
     }
 }
@@ -1,18 +1,35 @@
+use std::rc::Rc;
+
 use gpui::{
-    div, prelude::FluentBuilder, relative, svg, CursorStyle, ElementId, InteractiveElement,
-    IntoElement, ParentElement, RenderOnce, SharedString, StatefulInteractiveElement, Styled,
-    WindowContext,
+    actions, div, prelude::FluentBuilder, relative, svg, AppContext, CursorStyle, ElementId,
+    EventEmitter, FocusHandle, FocusableView, InteractiveElement, IntoElement, KeyBinding,
+    ParentElement, Render, RenderOnce, SharedString, StatefulInteractiveElement, Styled,
+    ViewContext, WindowContext,
 };
 
-use crate::{h_flex, theme::ActiveTheme, IconName};
+use crate::{h_flex, list::step_index, theme::ActiveTheme, v_flex, IconName, StyledExt};
+
+actions!(radio, [SelectPrev, SelectNext]);
+
+const CONTEXT: &str = "RadioGroup";
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("up", SelectPrev, Some(CONTEXT)),
+        KeyBinding::new("left", SelectPrev, Some(CONTEXT)),
+        KeyBinding::new("down", SelectNext, Some(CONTEXT)),
+        KeyBinding::new("right", SelectNext, Some(CONTEXT)),
+    ]);
+}
 
 #[derive(IntoElement)]
 pub struct Radio {
     id: ElementId,
     label: Option<SharedString>,
+    description: Option<SharedString>,
     checked: bool,
     disabled: bool,
-    on_click: Option<Box<dyn Fn(&bool, &mut WindowContext) + 'static>>,
+    card: bool,
+    on_click: Option<Rc<dyn Fn(&bool, &mut WindowContext) + 'static>>,
 }
 
 impl Radio {
@@ -20,8 +37,10 @@ impl Radio {
         Self {
             id: id.into(),
             label: None,
+            description: None,
             checked: false,
             disabled: false,
+            card: false,
             on_click: None,
         }
     }
@@ -31,6 +50,12 @@ impl Radio {
         self
     }
 
+    /// Set the description below the label.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
     pub fn checked(mut self, checked: bool) -> Self {
         self.checked = checked;
         self
@@ -41,8 +66,14 @@ impl Radio {
         self
     }
 
+    /// Set true to render as a bordered card, the card is highlighted when checked.
+    pub fn card(mut self, card: bool) -> Self {
+        self.card = card;
+        self
+    }
+
     pub fn on_click(mut self, handler: impl Fn(&bool, &mut WindowContext) + 'static) -> Self {
-        self.on_click = Some(Box::new(handler));
+        self.on_click = Some(Rc::new(handler));
         self
     }
 }
@@ -50,7 +81,7 @@ impl Radio {
 impl RenderOnce for Radio {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
         let color = if self.disabled {
-            cx.theme().primary.opacity(0.5)
+            cx.theme().translucent(cx.theme().primary, 0.5)
         } else {
             cx.theme().primary
         };
@@ -60,8 +91,27 @@ impl RenderOnce for Radio {
             .gap_x_2()
             .cursor(CursorStyle::PointingHand)
             .text_color(cx.theme().foreground)
-            .items_center()
+            .map(|this| {
+                if self.description.is_some() {
+                    this.items_start()
+                } else {
+                    this.items_center()
+                }
+            })
             .line_height(relative(1.))
+            .when(self.card, |this| {
+                this.p_3()
+                    .rounded_lg()
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .when(self.checked, |this| {
+                        this.border_color(color).bg(cx.theme().accent)
+                    })
+                    .when(!self.disabled && !self.checked, |this| {
+                        this.hover(|this| this.bg(cx.theme().translucent(cx.theme().accent, 0.5)))
+                    })
+            })
+            .when(self.disabled, |this| this.cursor_not_allowed())
             .child(
                 div()
                     .relative()
@@ -87,14 +137,31 @@ impl RenderOnce for Radio {
                             }),
                     ),
             )
-            .when_some(self.label, |this, label| {
+            .when(self.label.is_some() || self.description.is_some(), |this| {
                 this.child(
-                    div()
+                    v_flex()
                         .size_full()
+                        .gap_1()
                         .overflow_x_hidden()
-                        .text_ellipsis()
-                        .line_height(relative(1.))
-                        .child(label),
+                        .when_some(self.label, |this, label| {
+                            this.child(
+                                div()
+                                    .w_full()
+                                    .overflow_x_hidden()
+                                    .text_ellipsis()
+                                    .line_height(relative(1.))
+                                    .when(self.card, |this| this.font_medium())
+                                    .child(label),
+                            )
+                        })
+                        .when_some(self.description, |this, description| {
+                            this.child(
+                                div()
+                                    .text_xs()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(description),
+                            )
+                        }),
                 )
             })
             .when_some(
@@ -107,3 +174,194 @@ impl RenderOnce for Radio {
             )
     }
 }
+
+/// An option of the [`RadioGroup`].
+#[derive(Clone)]
+pub struct RadioGroupItem {
+    value: SharedString,
+    label: SharedString,
+    description: Option<SharedString>,
+    disabled: bool,
+}
+
+impl RadioGroupItem {
+    pub fn new(value: impl Into<SharedString>, label: impl Into<SharedString>) -> Self {
+        Self {
+            value: value.into(),
+            label: label.into(),
+            description: None,
+            disabled: false,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// Events emitted by the [`RadioGroup`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum RadioGroupEvent {
+    /// The selected value is changed.
+    Change(SharedString),
+}
+
+/// A group of radios with only one selected, use the arrow keys to select the previous or next option when focused.
+pub struct RadioGroup {
+    focus_handle: FocusHandle,
+    items: Vec<RadioGroupItem>,
+    selected_ix: Option<usize>,
+    horizontal: bool,
+    card: bool,
+    disabled: bool,
+}
+
+impl RadioGroup {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            items: vec![],
+            selected_ix: None,
+            horizontal: false,
+            card: false,
+            disabled: false,
+        }
+    }
+
+    pub fn items(mut self, items: Vec<RadioGroupItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    /// Set the initial selected value, call it after the [`RadioGroup::items`].
+    pub fn selected_value(mut self, value: impl Into<SharedString>) -> Self {
+        let value = value.into();
+        self.selected_ix = self.items.iter().position(|item| item.value == value);
+        self
+    }
+
+    /// Lay out the radios in a row, default is in a column.
+    pub fn horizontal(mut self) -> Self {
+        self.horizontal = true;
+        self
+    }
+
+    /// Render the radios as cards, useful for the option pickers.
+    pub fn card(mut self) -> Self {
+        self.card = true;
+        self
+    }
+
+    pub fn selected_index(&self) -> Option<usize> {
+        self.selected_ix
+    }
+
+    /// Return the value of the selected option.
+    pub fn value(&self) -> Option<&SharedString> {
+        self.selected_ix
+            .and_then(|ix| self.items.get(ix))
+            .map(|item| &item.value)
+    }
+
+    /// Select the option with the value without emitting the change, `None` to clear the selection.
+    pub fn set_selected_value(&mut self, value: Option<&str>, cx: &mut ViewContext<Self>) {
+        self.selected_ix = value.and_then(|value| {
+            self.items
+                .iter()
+                .position(|item| item.value.as_ref() == value)
+        });
+        cx.notify();
+    }
+
+    pub fn set_disabled(&mut self, disabled: bool, cx: &mut ViewContext<Self>) {
+        self.disabled = disabled;
+        cx.notify();
+    }
+
+    fn select(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if self.selected_ix == Some(ix) {
+            return;
+        }
+
+        let Some(item) = self.items.get(ix) else {
+            return;
+        };
+
+        self.selected_ix = Some(ix);
+        cx.emit(RadioGroupEvent::Change(item.value.clone()));
+        cx.notify();
+    }
+
+    /// Select the previous or next enabled item, wrapping around at the ends.
+    fn select_step(&mut self, forward: bool, cx: &mut ViewContext<Self>) {
+        let next = step_index(self.items.len(), self.selected_ix, forward, |ix| {
+            !self.disabled && !self.items[ix].disabled
+        });
+        if let Some(ix) = next {
+            self.select(ix, cx);
+        }
+    }
+
+    fn select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
+        self.select_step(false, cx);
+    }
+
+    fn select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
+        self.select_step(true, cx);
+    }
+}
+
+impl EventEmitter<RadioGroupEvent> for RadioGroup {}
+
+impl FocusableView for RadioGroup {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for RadioGroup {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let focused = self.focus_handle.is_focused(cx);
+
+        div()
+            .id("radio-group")
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::select_prev))
+            .on_action(cx.listener(Self::select_next))
+            .flex()
+            .gap_3()
+            .map(|this| {
+                if self.horizontal {
+                    this.flex_row().flex_wrap()
+                } else {
+                    this.flex_col()
+                }
+            })
+            .p_1()
+            .rounded_md()
+            .border_1()
+            .border_color(cx.theme().transparent)
+            .when(focused, |this| this.outline(cx))
+            .children(self.items.iter().enumerate().map(|(ix, item)| {
+                Radio::new(("radio-group-item", ix))
+                    .label(item.label.clone())
+                    .when_some(item.description.clone(), |this, description| {
+                        this.description(description)
+                    })
+                    .checked(self.selected_ix == Some(ix))
+                    .disabled(self.disabled || item.disabled)
+                    .card(self.card)
+                    .on_click(cx.listener(move |this, _, cx| {
+                        cx.focus(&this.focus_handle);
+                        this.select(ix, cx);
+                    }))
+            }))
+    }
+}
@@ -0,0 +1,214 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, relative, Hsla, InteractiveElement as _, IntoElement,
+    ParentElement as _, Render, StatefulInteractiveElement as _, Styled as _, ViewContext,
+    WindowContext,
+};
+
+use crate::{h_flex, theme::ActiveTheme as _, Icon, IconName, Sizable, Size};
+
+/// Return the filled fraction of the icon at `ix` for the value, in 0.0..1.0.
+fn icon_fill(ix: usize, value: f32) -> f32 {
+    (value - ix as f32).clamp(0., 1.)
+}
+
+/// A row of the stars to rate, with the hover preview and the half star precision.
+pub struct Rating {
+    value: f32,
+    hover_value: Option<f32>,
+    count: usize,
+    half: bool,
+    icon: Icon,
+    color: Option<Hsla>,
+    readonly: bool,
+    size: Size,
+    on_change: Option<Rc<dyn Fn(f32, &mut WindowContext)>>,
+}
+
+impl Rating {
+    pub fn new(_: &mut ViewContext<Self>) -> Self {
+        Self {
+            value: 0.,
+            hover_value: None,
+            count: 5,
+            half: false,
+            icon: Icon::new(IconName::Star),
+            color: None,
+            readonly: false,
+            size: Size::Medium,
+            on_change: None,
+        }
+    }
+
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Set the number of the icons, default: 5.
+    pub fn count(mut self, count: usize) -> Self {
+        self.count = count;
+        self
+    }
+
+    /// Set true to allow to rate by the half icon.
+    pub fn half(mut self, half: bool) -> Self {
+        self.half = half;
+        self
+    }
+
+    /// Set the icon to rate, default: [`IconName::Star`].
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = icon.into();
+        self
+    }
+
+    /// Set the color of the active icons, default: yellow.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Set true to only display the value.
+    pub fn readonly(mut self, readonly: bool) -> Self {
+        self.readonly = readonly;
+        self
+    }
+
+    /// Set the handler when the value is changed by clicking an icon.
+    pub fn on_change(mut self, handler: impl Fn(f32, &mut WindowContext) + 'static) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn current_value(&self) -> f32 {
+        self.value
+    }
+
+    pub fn set_value(&mut self, value: f32, cx: &mut ViewContext<Self>) {
+        self.value = value;
+        cx.notify();
+    }
+
+    fn change(&mut self, value: f32, cx: &mut ViewContext<Self>) {
+        if self.readonly || value == self.value {
+            return;
+        }
+
+        self.value = value;
+        if let Some(on_change) = self.on_change.clone() {
+            on_change(value, cx);
+        }
+        cx.notify();
+    }
+
+    fn set_hover_value(&mut self, value: Option<f32>, cx: &mut ViewContext<Self>) {
+        if self.hover_value != value {
+            self.hover_value = value;
+            cx.notify();
+        }
+    }
+}
+
+impl Sizable for Rating {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl Render for Rating {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let value = self.hover_value.unwrap_or(self.value);
+        let color = self.color.unwrap_or(crate::yellow_500());
+        let empty_color = cx.theme().translucent(cx.theme().muted_foreground, 0.4);
+        let icon_size = match self.size {
+            Size::XSmall => px(12.),
+            Size::Small => px(16.),
+            Size::Medium => px(20.),
+            Size::Large => px(28.),
+            Size::Size(size) => size,
+        };
+        // The (left, width, value) of the hover areas on each icon.
+        let areas: &[(f32, f32, f32)] = if self.half {
+            &[(0., 0.5, 0.5), (0.5, 0.5, 1.)]
+        } else {
+            &[(0., 1., 1.)]
+        };
+
+        h_flex()
+            .id("rating")
+            .gap_0p5()
+            .when(!self.readonly, |this| {
+                this.on_hover(cx.listener(|this, hovered: &bool, cx| {
+                    if !hovered {
+                        this.set_hover_value(None, cx);
+                    }
+                }))
+            })
+            .children((0..self.count).map(|ix| {
+                let fill = icon_fill(ix, value);
+
+                div()
+                    .relative()
+                    .flex_shrink_0()
+                    .size(icon_size)
+                    .child(
+                        self.icon
+                            .clone()
+                            .with_size(Size::Size(icon_size))
+                            .text_color(empty_color),
+                    )
+                    .when(fill > 0., |this| {
+                        this.child(
+                            div()
+                                .absolute()
+                                .top_0()
+                                .left_0()
+                                .h_full()
+                                .w(relative(fill))
+                                .overflow_hidden()
+                                .child(
+                                    self.icon
+                                        .clone()
+                                        .with_size(Size::Size(icon_size))
+                                        .text_color(color),
+                                ),
+                        )
+                    })
+                    .when(!self.readonly, |this| {
+                        this.cursor_pointer().children(areas.iter().enumerate().map(
+                            |(i, (left, width, v))| {
+                                let v = ix as f32 + v;
+                                div()
+                                    .id(("rating-area", ix * 2 + i))
+                                    .absolute()
+                                    .top_0()
+                                    .h_full()
+                                    .left(relative(*left))
+                                    .w(relative(*width))
+                                    .on_hover(cx.listener(move |this, hovered: &bool, cx| {
+                                        if *hovered {
+                                            this.set_hover_value(Some(v), cx);
+                                        }
+                                    }))
+                                    .on_click(cx.listener(move |this, _, cx| this.change(v, cx)))
+                            },
+                        ))
+                    })
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::icon_fill;
+
+    #[test]
+    fn test_icon_fill() {
+        assert_eq!(icon_fill(0, 2.5), 1.);
+        assert_eq!(icon_fill(2, 2.5), 0.5);
+        assert_eq!(icon_fill(3, 2.5), 0.);
+    }
+}
@@ -1,8 +1,8 @@
 use std::rc::Rc;
 
 use gpui::{
-    canvas, div, prelude::FluentBuilder, px, Along, AnyElement, AnyView, Axis, Bounds, Element,
-    Entity, EntityId, EventEmitter, InteractiveElement as _, IntoElement, MouseMoveEvent,
+    canvas, div, prelude::FluentBuilder, px, Along, AnyElement, AnyView, Axis, Bounds, ClickEvent,
+    Element, Entity, EntityId, EventEmitter, InteractiveElement as _, IntoElement, MouseMoveEvent,
     MouseUpEvent, ParentElement, Pixels, Render, StatefulInteractiveElement, Style, Styled, View,
     ViewContext, VisualContext as _, WindowContext,
 };
@@ -10,6 +10,7 @@ use gpui::{
 use crate::{h_flex, theme::ActiveTheme, v_flex, AxisExt};
 
 const PANEL_MIN_SIZE: Pixels = px(100.);
+const PANEL_MAX_SIZE: Pixels = px(f32::MAX);
 const HANDLE_PADDING: Pixels = px(4.);
 
 pub enum ResizablePanelEvent {
@@ -19,6 +20,73 @@ pub enum ResizablePanelEvent {
 #[derive(Clone, Render)]
 pub struct DragPanel(pub (EntityId, usize, Axis));
 
+/// The size limits of a [`ResizablePanel`] along the axis of the group.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct PanelLimits {
+    min: Pixels,
+    max: Pixels,
+    collapsible: bool,
+}
+
+impl Default for PanelLimits {
+    fn default() -> Self {
+        Self {
+            min: PANEL_MIN_SIZE,
+            max: PANEL_MAX_SIZE,
+            collapsible: false,
+        }
+    }
+}
+
+/// Return the new sizes to move the handle after the `ix` panel, to make the panel to the `size`.
+///
+/// The next panels are shrunk to their min sizes to grow the panel, and the next one is grown to
+/// its max size to shrink the panel. A collapsible panel is collapsed to zero if it's resized to
+/// less than half of its min size.
+fn resize_sizes(sizes: &[Pixels], limits: &[PanelLimits], ix: usize, size: Pixels) -> Vec<Pixels> {
+    let mut new_sizes = sizes.to_vec();
+    if ix + 1 >= sizes.len() {
+        return new_sizes;
+    }
+
+    let limit = limits[ix];
+    let target = if limit.collapsible && size < limit.min / 2. {
+        px(0.)
+    } else {
+        size.max(limit.min).min(limit.max)
+    };
+
+    let changed = target - sizes[ix];
+    if changed > px(0.) {
+        let mut remaining = changed;
+        for i in ix + 1..sizes.len() {
+            if remaining <= px(0.) {
+                break;
+            }
+            let next = limits[i];
+            // Collapse the next panel if the panel still fits its max size with the space of it.
+            let collapse = next.collapsible
+                && new_sizes[i] - remaining < next.min / 2.
+                && sizes[ix] + changed - remaining + new_sizes[i] <= limit.max;
+            let to_reduce = if collapse {
+                new_sizes[i]
+            } else {
+                remaining.min((new_sizes[i] - next.min).max(px(0.)))
+            };
+            new_sizes[i] -= to_reduce;
+            remaining -= to_reduce;
+        }
+        new_sizes[ix] += changed - remaining;
+    } else if changed < px(0.) {
+        let available = (limits[ix + 1].max - new_sizes[ix + 1]).max(px(0.));
+        let to_increase = (-changed).min(available);
+        new_sizes[ix] -= to_increase;
+        new_sizes[ix + 1] += to_increase;
+    }
+
+    new_sizes
+}
+
 #[derive(Clone)]
 pub struct ResizablePanelGroup {
     panels: Vec<View<ResizablePanel>>,
@@ -93,11 +161,25 @@ impl ResizablePanelGroup {
         self
     }
 
-    /// Returns the sizes of the resizable panels.
-    pub(crate) fn sizes(&self) -> Vec<Pixels> {
+    /// Returns the sizes of the resizable panels, they can be serialized to restore the layout
+    /// by [`ResizablePanelGroup::set_sizes`].
+    pub fn sizes(&self) -> Vec<Pixels> {
         self.sizes.clone()
     }
 
+    /// Restore the sizes of the panels, e.g.: the sizes saved in the last session.
+    ///
+    /// The sizes are ignored if the length is not the same as the panels.
+    pub fn set_sizes(&mut self, sizes: Vec<Pixels>, cx: &mut ViewContext<Self>) {
+        if sizes.len() != self.panels.len() {
+            return;
+        }
+
+        self.sizes = sizes;
+        self.apply_sizes(cx);
+        cx.notify();
+    }
+
     pub fn add_child(&mut self, panel: ResizablePanel, cx: &mut ViewContext<Self>) {
         let mut panel = panel;
         panel.axis = self.axis;
@@ -185,6 +267,11 @@ impl ResizablePanelGroup {
                         this.w_full().h(self.handle_size)
                     }),
             )
+            .on_click(cx.listener(move |view, e: &ClickEvent, cx| {
+                if e.up.click_count == 2 {
+                    view.reset_panel(ix, cx);
+                }
+            }))
             .on_drag(
                 DragPanel((cx.entity_id(), ix, axis)),
                 move |drag_panel, cx| {
@@ -209,10 +296,23 @@ impl ResizablePanelGroup {
         }
     }
 
+    fn limits(&self, cx: &WindowContext) -> Vec<PanelLimits> {
+        self.panels
+            .iter()
+            .map(|panel| panel.read(cx).limits)
+            .collect()
+    }
+
+    fn apply_sizes(&mut self, cx: &mut ViewContext<Self>) {
+        for (panel, size) in self.panels.iter().zip(self.sizes.iter()) {
+            let size = *size;
+            panel.update(cx, |this, _| this.size = Some(size));
+        }
+    }
+
     /// The `ix`` is the index of the panel to resize,
     /// and the `size` is the new size for the panel.
     fn resize_panels(&mut self, ix: usize, size: Pixels, cx: &mut ViewContext<Self>) {
-        let mut ix = ix;
         // Only resize the left panels.
         if ix >= self.panels.len() - 1 {
             return;
@@ -221,53 +321,41 @@ impl ResizablePanelGroup {
         let container_size = self.bounds.size.along(self.axis);
 
         self.sync_real_panel_sizes(cx);
-
-        let mut changed = size - self.sizes[ix];
-        let is_expand = changed > px(0.);
-
-        let main_ix = ix;
-        let mut new_sizes = self.sizes.clone();
-
-        if is_expand {
-            new_sizes[ix] = size;
-
-            // Now to expand logic is correct.
-            while changed > px(0.) && ix < self.panels.len() - 1 {
-                ix += 1;
-                let available_size = (new_sizes[ix] - PANEL_MIN_SIZE).max(px(0.));
-                let to_reduce = changed.min(available_size);
-                new_sizes[ix] -= to_reduce;
-                changed -= to_reduce;
-            }
-        } else {
-            let new_size = size.max(PANEL_MIN_SIZE);
-            new_sizes[ix] = new_size;
-            changed = size - PANEL_MIN_SIZE;
-            new_sizes[ix + 1] += self.sizes[ix] - new_size;
-
-            while changed < px(0.) && ix > 0 {
-                ix -= 1;
-                let available_size = self.sizes[ix] - PANEL_MIN_SIZE;
-                let to_increase = (changed).min(available_size);
-                new_sizes[ix] += to_increase;
-                changed += to_increase;
-            }
-        }
+        let limits = self.limits(cx);
+        let mut new_sizes = resize_sizes(&self.sizes, &limits, ix, size);
 
         // If total size exceeds container size, adjust the main panel
         let total_size: Pixels = new_sizes.iter().map(|s| s.0).sum::<f32>().into();
         if total_size > container_size {
-            let overflow = total_size - container_size;
-            new_sizes[main_ix] = (new_sizes[main_ix] - overflow).max(PANEL_MIN_SIZE);
+            let limit = limits[ix];
+            let size = new_sizes[ix] - (total_size - container_size);
+            // Collapse the collapsible panel, and keep the min size of the others.
+            new_sizes[ix] = if limit.collapsible && size < limit.min {
+                px(0.)
+            } else {
+                size.max(limit.min)
+            };
         }
 
         self.sizes = new_sizes;
-        for (i, panel) in self.panels.iter().enumerate() {
-            let size = self.sizes[i];
-            if size > px(0.) {
-                panel.update(cx, |this, _| this.size = Some(size));
-            }
+        self.apply_sizes(cx);
+    }
+
+    /// Reset the `ix` panel to its initial size, or split the space with the next panel evenly,
+    /// the collapsed panel is expanded by this.
+    fn reset_panel(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if ix + 1 >= self.panels.len() {
+            return;
         }
+
+        self.sync_real_panel_sizes(cx);
+        let size = self.panels[ix]
+            .read(cx)
+            .initial_size
+            .unwrap_or((self.sizes[ix] + self.sizes[ix + 1]) / 2.);
+        self.resize_panels(ix, size, cx);
+        self.done_resizing(cx);
+        cx.notify();
     }
 }
 impl EventEmitter<ResizablePanelEvent> for ResizablePanelGroup {}
@@ -313,6 +401,7 @@ pub struct ResizablePanel {
     initial_size: Option<Pixels>,
     /// size is the size that the panel has when it is resized or ajusted by flex layout.
     size: Option<Pixels>,
+    limits: PanelLimits,
     axis: Axis,
     content_builder: Option<Rc<dyn Fn(&mut WindowContext) -> AnyElement>>,
     content_view: Option<AnyView>,
@@ -327,6 +416,7 @@ impl ResizablePanel {
             group: None,
             initial_size: None,
             size: None,
+            limits: PanelLimits::default(),
             axis: Axis::Horizontal,
             content_builder: None,
             content_view: None,
@@ -354,6 +444,25 @@ impl ResizablePanel {
         self
     }
 
+    /// Set the min size of the panel, default is 100px.
+    pub fn min_size(mut self, min_size: Pixels) -> Self {
+        self.limits.min = min_size;
+        self
+    }
+
+    /// Set the max size of the panel, default is unlimited.
+    pub fn max_size(mut self, max_size: Pixels) -> Self {
+        self.limits.max = max_size;
+        self
+    }
+
+    /// Set true to collapse the panel to zero when it is dragged below half of the min size,
+    /// double click the resize handle to expand it again, default is `false`.
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.limits.collapsible = collapsible;
+        self
+    }
+
     /// Save the real panel size, and update group sizes
     fn update_size(&mut self, bounds: Bounds<Pixels>, cx: &mut ViewContext<Self>) {
         let new_size = bounds.size.along(self.axis);
@@ -381,6 +490,9 @@ impl FluentBuilder for ResizablePanel {}
 impl Render for ResizablePanel {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let view = cx.view().clone();
+        let collapsed = self.size == Some(px(0.));
+        let min_size = if collapsed { px(0.) } else { self.limits.min };
+        let max_size = self.limits.max;
 
         div()
             .flex()
@@ -388,8 +500,14 @@ impl Render for ResizablePanel {
             .size_full()
             .relative()
             .when(self.initial_size.is_none(), |this| this.flex_shrink())
-            .when(self.axis.is_vertical(), |this| this.min_h(PANEL_MIN_SIZE))
-            .when(self.axis.is_horizontal(), |this| this.min_w(PANEL_MIN_SIZE))
+            .when(self.axis.is_vertical(), |this| {
+                this.min_h(min_size)
+                    .when(max_size < PANEL_MAX_SIZE, |this| this.max_h(max_size))
+            })
+            .when(self.axis.is_horizontal(), |this| {
+                this.min_w(min_size)
+                    .when(max_size < PANEL_MAX_SIZE, |this| this.max_w(max_size))
+            })
             .when_some(self.initial_size, |this, size| {
                 // The `self.size` is None, that mean the initial size for the panel, so we need set flex_shrink_0
                 // To let it keep the initial size.
@@ -397,6 +515,7 @@ impl Render for ResizablePanel {
                     .flex_basis(size)
             })
             .when_some(self.size, |this, size| this.flex_basis(size))
+            .when(collapsed, |this| this.flex_none().overflow_hidden())
             .child({
                 canvas(
                     move |bounds, cx| view.update(cx, |r, cx| r.update_size(bounds, cx)),
@@ -497,3 +616,91 @@ impl Element for ResizePanelGroupElement {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limits(min: f32, max: f32, collapsible: bool) -> PanelLimits {
+        PanelLimits {
+            min: px(min),
+            max: px(max),
+            collapsible,
+        }
+    }
+
+    #[test]
+    fn test_resize_sizes() {
+        let sizes = [px(200.), px(200.), px(200.)];
+        let all = [PanelLimits::default(); 3];
+
+        assert_eq!(
+            resize_sizes(&sizes, &all, 0, px(250.)),
+            vec![px(250.), px(150.), px(200.)]
+        );
+        assert_eq!(
+            resize_sizes(&sizes, &all, 0, px(350.)),
+            vec![px(350.), px(100.), px(150.)]
+        );
+        // Shrink the next panels to the min size in order.
+        assert_eq!(
+            resize_sizes(&sizes, &all, 0, px(450.)),
+            vec![px(400.), px(100.), px(100.)]
+        );
+        // Keep the min size.
+        assert_eq!(
+            resize_sizes(&sizes, &all, 0, px(20.)),
+            vec![px(100.), px(300.), px(200.)]
+        );
+        // The last panel has no handle after it.
+        assert_eq!(resize_sizes(&sizes, &all, 2, px(100.)), sizes.to_vec());
+    }
+
+    #[test]
+    fn test_resize_sizes_with_limits() {
+        let sizes = [px(200.), px(200.)];
+
+        let panels = [limits(100., 240., false), PanelLimits::default()];
+        assert_eq!(
+            resize_sizes(&sizes, &panels, 0, px(300.)),
+            vec![px(240.), px(160.)]
+        );
+
+        let panels = [PanelLimits::default(), limits(100., 250., false)];
+        assert_eq!(
+            resize_sizes(&sizes, &panels, 0, px(120.)),
+            vec![px(150.), px(250.)]
+        );
+
+        let panels = [limits(100., f32::MAX, true), PanelLimits::default()];
+        assert_eq!(
+            resize_sizes(&sizes, &panels, 0, px(60.)),
+            vec![px(100.), px(300.)]
+        );
+        assert_eq!(
+            resize_sizes(&sizes, &panels, 0, px(40.)),
+            vec![px(0.), px(400.)]
+        );
+
+        // Collapse the next collapsible panel when it's less than half of its min size.
+        let panels = [PanelLimits::default(), limits(100., f32::MAX, true)];
+        assert_eq!(
+            resize_sizes(&sizes, &panels, 0, px(260.)),
+            vec![px(260.), px(140.)]
+        );
+        assert_eq!(
+            resize_sizes(&sizes, &panels, 0, px(320.)),
+            vec![px(300.), px(100.)]
+        );
+        assert_eq!(
+            resize_sizes(&sizes, &panels, 0, px(360.)),
+            vec![px(400.), px(0.)]
+        );
+        // Not collapsed if the panel can't take the space.
+        let panels = [limits(100., 360., false), limits(100., f32::MAX, true)];
+        assert_eq!(
+            resize_sizes(&sizes, &panels, 0, px(360.)),
+            vec![px(300.), px(100.)]
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, px, IntoElement, ParentElement as _, RenderOnce,
+    SharedString, Styled as _, WindowContext,
+};
+use rust_i18n::t;
+
+use crate::{
+    button::Button, h_flex, notification::NotificationType, theme::ActiveTheme as _, v_flex, Icon,
+    StyledExt as _,
+};
+
+/// The outcome shown by the [`Result`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultStatus {
+    Success,
+    Info,
+    Warning,
+    Error,
+    /// 403, the user is not allowed to access the page.
+    Forbidden,
+    /// 404, the page does not exist.
+    NotFound,
+    /// 500, the server is failed to process the request.
+    ServerError,
+}
+
+impl ResultStatus {
+    /// Return the HTTP status code of the status, None for the others.
+    fn code(&self) -> Option<u16> {
+        match self {
+            Self::Forbidden => Some(403),
+            Self::NotFound => Some(404),
+            Self::ServerError => Some(500),
+            _ => None,
+        }
+    }
+
+    fn default_title(&self) -> Option<SharedString> {
+        match self {
+            Self::Forbidden => Some(t!("Result.Forbidden").into()),
+            Self::NotFound => Some(t!("Result.Not Found").into()),
+            Self::ServerError => Some(t!("Result.Server Error").into()),
+            _ => None,
+        }
+    }
+}
+
+/// A full pane outcome of an operation or a page, e.g.: a submitted form, a 404 page.
+#[derive(IntoElement)]
+pub struct Result {
+    status: ResultStatus,
+    icon: Option<Icon>,
+    title: Option<SharedString>,
+    description: Option<SharedString>,
+    actions: Vec<Button>,
+}
+
+impl Result {
+    pub fn new(status: ResultStatus) -> Self {
+        Self {
+            status,
+            icon: None,
+            title: None,
+            description: None,
+            actions: Vec::new(),
+        }
+    }
+
+    /// Set the icon to replace the default icon or the status code.
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the title, the 403, 404 and 500 status have a default title.
+    pub fn title(mut self, title: impl Into<SharedString>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Add a button below the description, e.g.: "Back Home".
+    pub fn action(mut self, button: Button) -> Self {
+        self.actions.push(button);
+        self
+    }
+}
+
+impl RenderOnce for Result {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let type_ = match self.status {
+            ResultStatus::Success => Some(NotificationType::Success),
+            ResultStatus::Info => Some(NotificationType::Info),
+            ResultStatus::Warning => Some(NotificationType::Warning),
+            ResultStatus::Error => Some(NotificationType::Error),
+            _ => None,
+        };
+        let title = self.title.or_else(|| self.status.default_title());
+
+        v_flex()
+            .size_full()
+            .items_center()
+            .justify_center()
+            .gap_3()
+            .p_8()
+            .text_center()
+            .map(|this| match (self.icon, type_, self.status.code()) {
+                (Some(icon), _, _) => this.child(icon.size_16()),
+                (None, Some(type_), _) => this.child(type_.icon().size_16()),
+                (None, None, Some(code)) => this.child(
+                    div()
+                        .text_size(px(72.))
+                        .font_bold()
+                        .text_color(cx.theme().translucent(cx.theme().muted_foreground, 0.4))
+                        .child(code.to_string()),
+                ),
+                (None, None, None) => this,
+            })
+            .when_some(title, |this, title| {
+                this.child(div().text_xl().font_semibold().child(title))
+            })
+            .when_some(self.description, |this, description| {
+                this.child(
+                    div()
+                        .max_w(px(480.))
+                        .text_color(cx.theme().muted_foreground)
+                        .child(description),
+                )
+            })
+            .when(!self.actions.is_empty(), |this| {
+                this.child(h_flex().mt_3().gap_2().children(self.actions))
+            })
+    }
+}
@@ -1,6 +1,7 @@
 use gpui::{
-    div, AnyView, FocusHandle, InteractiveElement, IntoElement, ParentElement as _, Render, Styled,
-    View, ViewContext, VisualContext as _, WindowContext,
+    div, prelude::FluentBuilder as _, AnyView, FocusHandle, InteractiveElement, IntoElement,
+    ParentElement as _, Pixels, Render, Styled, Timer, View, ViewContext, VisualContext as _,
+    WindowContext,
 };
 use std::{
     ops::{Deref, DerefMut},
@@ -9,9 +10,9 @@ use std::{
 
 use crate::{
     drawer::Drawer,
-    modal::Modal,
-    notification::{Notification, NotificationList},
-    theme::ActiveTheme,
+    modal::{self, Modal},
+    notification::{Notification, NotificationHandle, NotificationList},
+    theme::{ActiveTheme, Theme},
 };
 
 /// Extension trait for [`WindowContext`] and [`ViewContext`] to add drawer functionality.
@@ -41,8 +42,13 @@ pub trait ContextModal: Sized {
     /// Closes all active Modals.
     fn close_all_modals(&mut self);
 
+    /// Returns the [`ModalStack`] of the active Modals, to query or close the layers.
+    fn modal_stack(&self) -> ModalStack;
+
     /// Pushes a notification to the notification list.
-    fn push_notification(&mut self, note: impl Into<Notification>);
+    ///
+    /// Returns a [`NotificationHandle`] to dismiss or update the notification later.
+    fn push_notification(&mut self, note: impl Into<Notification>) -> NotificationHandle;
     fn clear_notifications(&mut self);
     /// Returns number of notifications.
     fn notifications(&self) -> Rc<Vec<View<Notification>>>;
@@ -54,10 +60,15 @@ impl<'a> ContextModal for WindowContext<'a> {
         F: Fn(Drawer, &mut WindowContext) -> Drawer + 'static,
     {
         Root::update(self, move |root, cx| {
-            if root.active_drawer.is_none() {
+            if root.active_drawer.is_none() && root.top_modal().is_none() {
                 root.previous_focus_handle = cx.focused();
             }
-            root.active_drawer = Some(Rc::new(build));
+            root.active_drawer = Some(ActiveDrawer {
+                builder: Rc::new(build),
+                size: None,
+                // Open the drawer from a modal, it should be stacked above the modal.
+                above_modal: root.top_modal().is_some(),
+            });
             cx.notify();
         })
     }
@@ -69,7 +80,11 @@ impl<'a> ContextModal for WindowContext<'a> {
     fn close_drawer(&mut self) {
         Root::update(self, |root, cx| {
             root.active_drawer = None;
-            root.focus_back(cx);
+            if let Some(top_modal) = root.top_modal() {
+                top_modal.focus_handle.focus(cx);
+            } else {
+                root.focus_back(cx);
+            }
             cx.notify();
         })
     }
@@ -81,7 +96,7 @@ impl<'a> ContextModal for WindowContext<'a> {
         Root::update(self, move |root, cx| {
             // Only save focus handle if there are no active modals.
             // This is used to restore focus when all modals are closed.
-            if root.active_modals.len() == 0 {
+            if root.top_modal().is_none() {
                 root.previous_focus_handle = cx.focused();
             }
 
@@ -91,44 +106,55 @@ impl<'a> ContextModal for WindowContext<'a> {
             root.active_modals.push(ActiveModal {
                 focus_handle,
                 builder: Rc::new(build),
+                closing: false,
             });
             cx.notify();
         })
     }
 
     fn has_active_modal(&self) -> bool {
-        Root::read(&self).active_modals.len() > 0
+        Root::read(&self).top_modal().is_some()
     }
 
     fn close_modal(&mut self) {
         Root::update(self, move |root, cx| {
-            root.active_modals.pop();
-
-            if let Some(top_modal) = root.active_modals.last() {
-                // Focus the next modal.
-                top_modal.focus_handle.focus(cx);
-            } else {
-                // Restore focus if there are no more modals.
-                root.focus_back(cx);
+            if let Some(focus_handle) = root.top_modal().map(|modal| modal.focus_handle.clone()) {
+                root.close_modal_layer(&focus_handle, cx);
             }
-            cx.notify();
         })
     }
 
     fn close_all_modals(&mut self) {
         Root::update(self, |root, cx| {
-            root.active_modals.clear();
+            for ix in 0..root.active_modals.len() {
+                root.close_modal_at(ix, cx);
+            }
             root.focus_back(cx);
             cx.notify();
         })
     }
 
-    fn push_notification(&mut self, note: impl Into<Notification>) {
+    fn modal_stack(&self) -> ModalStack {
+        ModalStack {
+            layers: Root::read(&self)
+                .active_modals
+                .iter()
+                .filter(|modal| !modal.closing)
+                .map(|modal| modal.focus_handle.clone())
+                .collect(),
+        }
+    }
+
+    fn push_notification(&mut self, note: impl Into<Notification>) -> NotificationHandle {
         let note = note.into();
+        let handle = NotificationHandle {
+            id: note.id.clone(),
+        };
         Root::update(self, move |root, cx| {
             root.notification.update(cx, |view, cx| view.push(note, cx));
             cx.notify();
-        })
+        });
+        handle
     }
 
     fn clear_notifications(&mut self) {
@@ -179,7 +205,11 @@ impl<'a, V> ContextModal for ViewContext<'a, V> {
         self.deref_mut().close_all_modals()
     }
 
-    fn push_notification(&mut self, note: impl Into<Notification>) {
+    fn modal_stack(&self) -> ModalStack {
+        self.deref().modal_stack()
+    }
+
+    fn push_notification(&mut self, note: impl Into<Notification>) -> NotificationHandle {
         self.deref_mut().push_notification(note)
     }
 
@@ -192,6 +222,52 @@ impl<'a, V> ContextModal for ViewContext<'a, V> {
     }
 }
 
+/// The layers of the active Modals in the window, from the bottom to the top.
+///
+/// This is a snapshot returned by [`ContextModal::modal_stack`], the closing Modals are not included.
+#[derive(Clone, Default)]
+pub struct ModalStack {
+    layers: Vec<FocusHandle>,
+}
+
+impl ModalStack {
+    /// Returns the number of the modal layers.
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Return true, if there is no active Modal.
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Returns the layer index of the Modal that contains the focused element.
+    pub fn focused_layer(&self, cx: &WindowContext) -> Option<usize> {
+        self.layers
+            .iter()
+            .rposition(|focus_handle| focus_handle.contains_focused(cx))
+    }
+
+    /// Closes the Modal at the layer index, the other layers are kept.
+    ///
+    /// This will not call the `on_before_close` and `on_close` of the Modal.
+    pub fn close(&self, ix: usize, cx: &mut WindowContext) {
+        let Some(focus_handle) = self.layers.get(ix).cloned() else {
+            return;
+        };
+        Root::update(cx, move |root, cx| {
+            root.close_modal_layer(&focus_handle, cx)
+        })
+    }
+
+    /// Closes the top Modal.
+    pub fn close_top(&self, cx: &mut WindowContext) {
+        if let Some(ix) = self.len().checked_sub(1) {
+            self.close(ix, cx);
+        }
+    }
+}
+
 /// Root is a view for the App window for as the top level view (Must be the first view in the window).
 ///
 /// It is used to manage the Drawer, Modal, and Notification.
@@ -199,20 +275,33 @@ pub struct Root {
     /// Used to store the focus handle of the previus revious view.
     /// When the Modal, Drawer closes, we will focus back to the previous view.
     previous_focus_handle: Option<FocusHandle>,
-    active_drawer: Option<Rc<dyn Fn(Drawer, &mut WindowContext) -> Drawer + 'static>>,
+    active_drawer: Option<ActiveDrawer>,
     active_modals: Vec<ActiveModal>,
     pub notification: View<NotificationList>,
     child: AnyView,
 }
 
+#[derive(Clone)]
+struct ActiveDrawer {
+    builder: Rc<dyn Fn(Drawer, &mut WindowContext) -> Drawer + 'static>,
+    /// The size of the drawer after resized by the user.
+    size: Option<Pixels>,
+    above_modal: bool,
+}
+
 #[derive(Clone)]
 struct ActiveModal {
     focus_handle: FocusHandle,
     builder: Rc<dyn Fn(Modal, &mut WindowContext) -> Modal + 'static>,
+    /// The modal is playing the close animation, it will be removed after that.
+    closing: bool,
 }
 
 impl Root {
     pub fn new(child: AnyView, cx: &mut ViewContext<Self>) -> Self {
+        cx.observe_window_appearance(|_, cx| Theme::sync_system_appearance(cx))
+            .detach();
+
         Self {
             previous_focus_handle: None,
             active_drawer: None,
@@ -245,6 +334,118 @@ impl Root {
         root.read(cx)
     }
 
+    /// Return the top modal that is not closing.
+    fn top_modal(&self) -> Option<&ActiveModal> {
+        self.active_modals.iter().rev().find(|modal| !modal.closing)
+    }
+
+    /// Mark the modal as closing to play the close animation, and remove it after the animation.
+    fn close_modal_at(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        let Some(active_modal) = self.active_modals.get_mut(ix) else {
+            return;
+        };
+        if active_modal.closing {
+            return;
+        }
+        active_modal.closing = true;
+
+        let focus_handle = active_modal.focus_handle.clone();
+        cx.spawn(|root, mut cx| async move {
+            Timer::after(modal::CLOSE_DURATION).await;
+            root.update(&mut cx, |root, cx| {
+                root.active_modals
+                    .retain(|modal| modal.focus_handle != focus_handle);
+                cx.notify();
+            })
+        })
+        .detach();
+    }
+
+    /// Close the modal with the focus handle, and focus the top modal.
+    pub(crate) fn close_modal_layer(
+        &mut self,
+        focus_handle: &FocusHandle,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let Some(ix) = self
+            .active_modals
+            .iter()
+            .position(|modal| &modal.focus_handle == focus_handle)
+        else {
+            return;
+        };
+        self.close_modal_at(ix, cx);
+
+        if let Some(top_modal) = self.top_modal() {
+            // Focus the next modal, if the focus is in the closed one.
+            if !top_modal.focus_handle.contains_focused(cx) {
+                top_modal.focus_handle.focus(cx);
+            }
+        } else {
+            // Restore focus if there are no more modals.
+            self.focus_back(cx);
+        }
+        cx.notify();
+    }
+
+    /// Trap the focus in the top modal, focus it back if the focus has moved outside.
+    fn trap_focus(&self, cx: &mut ViewContext<Self>) {
+        let Some(top_modal) = self.top_modal() else {
+            return;
+        };
+        // The focus is in the drawer stacked above the modal.
+        if self.is_drawer_above_modal() {
+            return;
+        }
+        if top_modal.focus_handle.contains_focused(cx) {
+            return;
+        }
+
+        // Check again in the next frame, the focused element may be not rendered yet,
+        // for example a popup menu opened in the modal.
+        let focus_handle = top_modal.focus_handle.clone();
+        let root = cx.view().downgrade();
+        cx.on_next_frame(move |cx| {
+            let is_top_modal = root
+                .upgrade()
+                .and_then(|root| root.read(cx).top_modal().map(|m| m.focus_handle.clone()))
+                .map_or(false, |handle| handle == focus_handle);
+            if is_top_modal && !focus_handle.contains_focused(cx) {
+                focus_handle.focus(cx);
+            }
+        });
+    }
+
+    fn is_drawer_above_modal(&self) -> bool {
+        !self.active_modals.is_empty()
+            && self
+                .active_drawer
+                .as_ref()
+                .map_or(false, |drawer| drawer.above_modal)
+    }
+
+    pub(crate) fn resize_drawer(&mut self, size: Pixels, cx: &mut ViewContext<Self>) {
+        if let Some(active_drawer) = self.active_drawer.as_mut() {
+            active_drawer.size = Some(size);
+            cx.notify();
+        }
+    }
+
+    fn build_drawer(cx: &mut WindowContext) -> Option<Drawer> {
+        let root = cx
+            .window_handle()
+            .downcast::<Root>()
+            .and_then(|w| w.root_view(cx).ok())
+            .expect("The window root view should be of type `ui::Root`.");
+
+        let active_drawer = root.read(cx).active_drawer.clone()?;
+        let mut drawer = (active_drawer.builder)(Drawer::new(cx), cx);
+        if let Some(size) = active_drawer.size {
+            drawer.size = size.into();
+        }
+        Some(drawer)
+    }
+
     fn focus_back(&mut self, cx: &mut WindowContext) {
         if let Some(handle) = self.previous_focus_handle.clone() {
             cx.focus(&handle);
@@ -263,6 +464,8 @@ impl Root {
     }
 
     /// Render the Drawer layer.
+    ///
+    /// The drawer opened from a modal is rendered in the Modal layer to stack above the modal.
     pub fn render_drawer_layer(cx: &mut WindowContext) -> Option<impl IntoElement> {
         let root = cx
             .window_handle()
@@ -270,12 +473,11 @@ impl Root {
             .and_then(|w| w.root_view(cx).ok())
             .expect("The window root view should be of type `ui::Root`.");
 
-        if let Some(builder) = root.read(cx).active_drawer.clone() {
-            let drawer = Drawer::new(cx);
-            return Some(builder(drawer, cx));
+        if root.read(cx).is_drawer_above_modal() {
+            return None;
         }
 
-        None
+        Self::build_drawer(cx)
     }
 
     /// Render the Modal layer.
@@ -287,43 +489,45 @@ impl Root {
             .expect("The window root view should be of type `ui::Root`.");
 
         let active_modals = root.read(cx).active_modals.clone();
-        let mut has_overlay = false;
+        let drawer_above_modal = root.read(cx).is_drawer_above_modal();
 
         if active_modals.is_empty() {
             return None;
         }
 
         Some(
-            div().children(active_modals.iter().enumerate().map(|(i, active_modal)| {
-                let mut modal = Modal::new(cx);
-
-                modal = (active_modal.builder)(modal, cx);
-                modal.layer_ix = i;
-                // Give the modal the focus handle, because `modal` is a temporary value, is not possible to
-                // keep the focus handle in the modal.
-                //
-                // So we keep the focus handle in the `active_modal`, this is owned by the `Root`.
-                modal.focus_handle = active_modal.focus_handle.clone();
-
-                // Keep only have one overlay, we only render the first modal with overlay.
-                if has_overlay {
-                    modal.overlay_visible = false;
-                }
-                if modal.has_overlay() {
-                    has_overlay = true;
-                }
-
-                modal
-            })),
+            div()
+                .children(active_modals.iter().enumerate().map(|(i, active_modal)| {
+                    let mut modal = Modal::new(cx);
+
+                    modal = (active_modal.builder)(modal, cx);
+                    modal.layer_ix = i;
+                    // Give the modal the focus handle, because `modal` is a temporary value, is not possible to
+                    // keep the focus handle in the modal.
+                    //
+                    // So we keep the focus handle in the `active_modal`, this is owned by the `Root`.
+                    modal.focus_handle = active_modal.focus_handle.clone();
+                    modal.closing = active_modal.closing;
+                    modal
+                }))
+                .when(drawer_above_modal, |this| {
+                    this.children(Self::build_drawer(cx))
+                }),
         )
     }
 }
 
 impl Render for Root {
     fn render(&mut self, cx: &mut gpui::ViewContext<Self>) -> impl IntoElement {
+        self.trap_focus(cx);
+        let rem_size = cx.theme().rem_size();
+        cx.set_rem_size(rem_size);
+
         div()
             .id("root")
             .size_full()
+            .font_family(cx.theme().font_family.clone())
+            .text_size(cx.theme().text_size())
             .text_color(cx.theme().foreground)
             .child(self.child.clone())
     }
@@ -0,0 +1,230 @@
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    rc::Rc,
+};
+
+use gpui::{
+    canvas, div, point, prelude::FluentBuilder as _, px, AnyElement, Div, ElementId, EntityId,
+    InteractiveElement as _, IntoElement, ParentElement, Pixels, Point, RenderOnce, ScrollHandle,
+    Size, StatefulInteractiveElement as _, Styled, ViewContext, WindowContext,
+};
+
+use super::{Scrollbar, ScrollbarAxis, ScrollbarState};
+
+/// The handle of the [`ScrollArea`], keep it in the view to scroll the area programmatically.
+#[derive(Clone)]
+pub struct ScrollAreaHandle {
+    view_id: EntityId,
+    base: ScrollHandle,
+    state: Rc<Cell<ScrollbarState>>,
+    scroll_size: Rc<Cell<Size<Pixels>>>,
+    /// The positions of the anchors in the content.
+    anchors: Rc<RefCell<HashMap<ElementId, Point<Pixels>>>>,
+}
+
+impl ScrollAreaHandle {
+    pub fn new<V: 'static>(cx: &ViewContext<V>) -> Self {
+        Self {
+            view_id: cx.view().entity_id(),
+            base: ScrollHandle::new(),
+            state: Rc::new(Cell::new(ScrollbarState::default())),
+            scroll_size: Rc::new(Cell::new(Size::default())),
+            anchors: Rc::new(RefCell::new(HashMap::new())),
+        }
+    }
+
+    /// Return the scroll position, it's the distance from the top left of the content.
+    pub fn offset(&self) -> Point<Pixels> {
+        let offset = self.base.offset();
+        point(-offset.x, -offset.y)
+    }
+
+    /// Scroll to the position from the top left of the content, the position is clamped to the content.
+    pub fn scroll_to(&self, offset: Point<Pixels>, cx: &mut WindowContext) {
+        let offset = clamp_offset(offset, self.scroll_size.get(), self.base.bounds().size);
+        self.base.set_offset(point(-offset.x, -offset.y));
+        cx.notify(self.view_id);
+    }
+
+    /// Scroll to the top left of the anchor with the `id`, see [`ScrollAreaHandle::anchor`].
+    ///
+    /// The anchor is unknown until it's painted, it does nothing in that case.
+    pub fn scroll_to_element(&self, id: impl Into<ElementId>, cx: &mut WindowContext) {
+        let position = self.anchors.borrow().get(&id.into()).copied();
+        if let Some(position) = position {
+            self.scroll_to(position, cx);
+        }
+    }
+
+    /// Wrap the child in an anchor, to scroll to it by [`ScrollAreaHandle::scroll_to_element`].
+    pub fn anchor(&self, id: impl Into<ElementId>, child: impl IntoElement) -> Div {
+        let id = id.into();
+        let base = self.base.clone();
+        let anchors = self.anchors.clone();
+
+        div().relative().child(child).child(
+            canvas(
+                move |bounds, _| {
+                    let position = bounds.origin - base.bounds().origin - base.offset();
+                    anchors.borrow_mut().insert(id, position);
+                },
+                |_, _, _| {},
+            )
+            .absolute()
+            .size_full(),
+        )
+    }
+}
+
+/// Clamp the scroll position to the scrollable range of the content.
+fn clamp_offset(
+    offset: Point<Pixels>,
+    content_size: Size<Pixels>,
+    viewport_size: Size<Pixels>,
+) -> Point<Pixels> {
+    let max_x = (content_size.width - viewport_size.width).max(px(0.));
+    let max_y = (content_size.height - viewport_size.height).max(px(0.));
+
+    point(
+        offset.x.max(px(0.)).min(max_x),
+        offset.y.max(px(0.)).min(max_y),
+    )
+}
+
+/// A scroll container with the themed overlay scrollbars for any content.
+///
+/// The scrollbars are auto hidden, and shown when scrolling or hovering the bar.
+///
+/// ```ignore
+/// ScrollArea::new("content", &self.scroll_handle)
+///     .vertical()
+///     .child(self.scroll_handle.anchor("section-1", section))
+/// ```
+#[derive(IntoElement)]
+pub struct ScrollArea {
+    id: ElementId,
+    handle: ScrollAreaHandle,
+    axis: ScrollbarAxis,
+    base: Div,
+    children: Vec<AnyElement>,
+}
+
+impl ScrollArea {
+    pub fn new(id: impl Into<ElementId>, handle: &ScrollAreaHandle) -> Self {
+        Self {
+            id: id.into(),
+            handle: handle.clone(),
+            axis: ScrollbarAxis::Both,
+            base: div(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Set only a vertical scrollbar.
+    pub fn vertical(mut self) -> Self {
+        self.axis = ScrollbarAxis::Vertical;
+        self
+    }
+
+    /// Set only a horizontal scrollbar.
+    pub fn horizontal(mut self) -> Self {
+        self.axis = ScrollbarAxis::Horizontal;
+        self
+    }
+
+    /// Set the axis to scroll, default is `ScrollbarAxis::Both`.
+    pub fn axis(mut self, axis: ScrollbarAxis) -> Self {
+        self.axis = axis;
+        self
+    }
+}
+
+impl ParentElement for ScrollArea {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
+
+impl Styled for ScrollArea {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for ScrollArea {
+    fn render(self, _: &mut WindowContext) -> impl IntoElement {
+        let handle = self.handle;
+        let axis = self.axis;
+        let scroll_size = handle.scroll_size.clone();
+
+        div()
+            .relative()
+            .size_full()
+            .overflow_hidden()
+            .child(
+                div()
+                    .id(self.id)
+                    .track_scroll(&handle.base)
+                    .map(|this| match axis {
+                        ScrollbarAxis::Vertical => this.overflow_y_scroll(),
+                        ScrollbarAxis::Horizontal => this.overflow_x_scroll(),
+                        ScrollbarAxis::Both => this.overflow_scroll(),
+                    })
+                    .relative()
+                    .size_full()
+                    .child(
+                        self.base.relative().children(self.children).child(
+                            canvas(move |bounds, _| scroll_size.set(bounds.size), |_, _, _| {})
+                                .absolute()
+                                .size_full(),
+                        ),
+                    ),
+            )
+            .child(
+                div()
+                    .absolute()
+                    .top_0()
+                    .left_0()
+                    .right_0()
+                    .bottom_0()
+                    .child(
+                        Scrollbar::both(
+                            handle.view_id,
+                            handle.state.clone(),
+                            handle.base.clone(),
+                            handle.scroll_size.get(),
+                        )
+                        .axis(axis)
+                        .auto_hide(true),
+                    ),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{point, px, size};
+
+    use super::clamp_offset;
+
+    #[test]
+    fn test_clamp_offset() {
+        let content = size(px(500.), px(1000.));
+        let viewport = size(px(200.), px(300.));
+
+        assert_eq!(
+            clamp_offset(point(px(100.), px(200.)), content, viewport),
+            point(px(100.), px(200.))
+        );
+        assert_eq!(
+            clamp_offset(point(px(-10.), px(900.)), content, viewport),
+            point(px(0.), px(700.))
+        );
+        // The content is smaller than the viewport.
+        assert_eq!(
+            clamp_offset(point(px(10.), px(10.)), size(px(100.), px(100.)), viewport),
+            point(px(0.), px(0.))
+        );
+    }
+}
@@ -1,15 +1,24 @@
-use std::{cell::Cell, rc::Rc};
+use std::{
+    cell::Cell,
+    rc::Rc,
+    time::{Duration, Instant},
+};
 
 use crate::theme::ActiveTheme;
 use gpui::{
     fill, point, px, relative, size, Bounds, ContentMask, Edges, Element, EntityId, Hitbox,
     IntoElement, MouseDownEvent, MouseMoveEvent, MouseUpEvent, PaintQuad, Pixels, Point, Position,
-    ScrollHandle, Style, UniformListScrollHandle,
+    ScrollHandle, ScrollWheelEvent, Style, UniformListScrollHandle,
 };
 
 const MIN_THUMB_SIZE: f32 = 80.;
 const THUMB_RADIUS: Pixels = Pixels(5.0);
 const THUMB_INSET: Pixels = Pixels(2.);
+/// The inset of the thin thumb of the auto hide scrollbar, it's thickened when hovered.
+const THIN_THUMB_INSET: Pixels = Pixels(3.5);
+/// The auto hide scrollbar keeps visible for this duration after scrolling.
+const HIDE_DELAY: Duration = Duration::from_millis(1000);
+const FADE_DURATION: Duration = Duration::from_millis(300);
 
 pub trait ScrollHandleOffsetable {
     fn offset(&self) -> Point<Pixels>;
@@ -49,6 +58,7 @@ pub struct ScrollbarState {
     dragged_axis: Option<ScrollbarAxis>,
     drag_pos: Point<Pixels>,
     visible: bool,
+    last_scroll_at: Option<Instant>,
 }
 
 impl Default for ScrollbarState {
@@ -58,6 +68,7 @@ impl Default for ScrollbarState {
             dragged_axis: None,
             drag_pos: point(px(0.), px(0.)),
             visible: false,
+            last_scroll_at: None,
         }
     }
 }
@@ -96,6 +107,32 @@ impl ScrollbarState {
         state.visible = visiable;
         state
     }
+
+    fn with_last_scroll_at(&self, last_scroll_at: Option<Instant>) -> Self {
+        let mut state = *self;
+        state.last_scroll_at = last_scroll_at;
+        state
+    }
+
+    /// The opacity of the auto hide scrollbar, it's fully visible when hovered, dragging or just scrolled.
+    fn auto_hide_opacity(&self) -> f32 {
+        if self.hovered_axis.is_some() || self.dragged_axis.is_some() {
+            return 1.;
+        }
+
+        self.last_scroll_at
+            .map(|at| fade_opacity(at.elapsed()))
+            .unwrap_or(0.)
+    }
+}
+
+/// Return the opacity of the auto hide scrollbar after the `elapsed` time since the last scroll.
+fn fade_opacity(elapsed: Duration) -> f32 {
+    if elapsed <= HIDE_DELAY {
+        return 1.;
+    }
+
+    (1. - (elapsed - HIDE_DELAY).as_secs_f32() / FADE_DURATION.as_secs_f32()).max(0.)
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -147,6 +184,7 @@ pub struct Scrollbar {
     scroll_handle: Rc<Box<dyn ScrollHandleOffsetable>>,
     scroll_size: gpui::Size<Pixels>,
     state: Rc<Cell<ScrollbarState>>,
+    auto_hide: bool,
 }
 
 impl Scrollbar {
@@ -164,6 +202,7 @@ impl Scrollbar {
             scroll_size,
             width: px(11.),
             scroll_handle: Rc::new(Box::new(scroll_handle)),
+            auto_hide: false,
         }
     }
 
@@ -240,6 +279,14 @@ impl Scrollbar {
         self.axis = axis;
         self
     }
+
+    /// Set true to show the scrollbar only when scrolling or hovering the bar, and fade out after that.
+    ///
+    /// The thumb is thin by default, and thickened when hovered, default is `false`.
+    pub fn auto_hide(mut self, auto_hide: bool) -> Self {
+        self.auto_hide = auto_hide;
+        self
+    }
 }
 
 impl IntoElement for Scrollbar {
@@ -296,6 +343,31 @@ impl Element for Scrollbar {
     ) {
         let hitbox_bounds = hitbox.bounds;
         let mut has_both = self.axis.is_both();
+        let auto_hide = self.auto_hide;
+        let opacity = if auto_hide {
+            self.state.get().auto_hide_opacity()
+        } else if self.state.get().visible {
+            1.
+        } else {
+            0.
+        };
+
+        // Keep painting to hide the scrollbar after the delay.
+        if auto_hide && opacity > 0. && self.state.get().last_scroll_at.is_some() {
+            cx.request_animation_frame();
+        }
+
+        if auto_hide {
+            let state = self.state.clone();
+            let view_id = self.view_id;
+
+            cx.on_mouse_event(move |event: &ScrollWheelEvent, phase, cx| {
+                if phase.bubble() && hitbox_bounds.contains(&event.position) {
+                    state.set(state.get().with_last_scroll_at(Some(Instant::now())));
+                    cx.notify(view_id);
+                }
+            });
+        }
 
         cx.with_content_mask(
             Some(ContentMask {
@@ -372,12 +444,22 @@ impl Element for Scrollbar {
                             (thumb_bg, cx.theme().scrollbar, cx.theme().border, px(1.))
                         } else {
                             (
-                                thumb_bg.opacity(0.3),
+                                cx.theme()
+                                    .translucent(thumb_bg, if auto_hide { 0.6 } else { 0.3 }),
                                 cx.theme().transparent,
                                 gpui::transparent_black(),
-                                THUMB_INSET,
+                                if auto_hide {
+                                    THIN_THUMB_INSET
+                                } else {
+                                    THUMB_INSET
+                                },
                             )
                         };
+                    let (thumb_bg, bar_bg, bar_border) = (
+                        thumb_bg.opacity(opacity),
+                        bar_bg.opacity(opacity),
+                        bar_border.opacity(opacity),
+                    );
 
                     let border_width = px(0.);
                     let thumb_bounds = if is_vertical {
@@ -404,7 +486,7 @@ impl Element for Scrollbar {
                         )
                     };
 
-                    if state.get().visible {
+                    if opacity > 0. {
                         cx.paint_quad(fill(bounds, bar_bg));
 
                         cx.paint_quad(PaintQuad {
@@ -566,3 +648,19 @@ impl Element for Scrollbar {
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{fade_opacity, FADE_DURATION, HIDE_DELAY};
+
+    #[test]
+    fn test_fade_opacity() {
+        assert_eq!(fade_opacity(Duration::ZERO), 1.);
+        assert_eq!(fade_opacity(HIDE_DELAY), 1.);
+        assert!((fade_opacity(HIDE_DELAY + FADE_DURATION / 2) - 0.5).abs() < 1e-4);
+        assert_eq!(fade_opacity(HIDE_DELAY + FADE_DURATION), 0.);
+        assert_eq!(fade_opacity(HIDE_DELAY * 2 + FADE_DURATION), 0.);
+    }
+}
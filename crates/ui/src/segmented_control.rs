@@ -0,0 +1,319 @@
+use std::time::Duration;
+
+use gpui::{
+    actions, canvas, div, ease_in_out, prelude::FluentBuilder as _, px, Animation,
+    AnimationExt as _, AppContext, Bounds, EventEmitter, FocusHandle, FocusableView,
+    InteractiveElement as _, IntoElement, KeyBinding, ParentElement as _, Pixels, Render,
+    SharedString, StatefulInteractiveElement as _, Styled as _, ViewContext,
+};
+
+use crate::{
+    h_flex, list::step_index, theme::ActiveTheme as _, Icon, Sizable, Size, StyleSized as _,
+};
+
+actions!(segmented_control, [SelectPrev, SelectNext, ToggleSelected]);
+
+const CONTEXT: &str = "SegmentedControl";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("left", SelectPrev, Some(CONTEXT)),
+        KeyBinding::new("right", SelectNext, Some(CONTEXT)),
+        KeyBinding::new("space", ToggleSelected, Some(CONTEXT)),
+    ]);
+}
+
+/// A segment of the [`SegmentedControl`].
+#[derive(Clone)]
+pub struct SegmentItem {
+    label: Option<SharedString>,
+    icon: Option<Icon>,
+}
+
+impl SegmentItem {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: Some(label.into()),
+            icon: None,
+        }
+    }
+
+    /// A segment with only the icon.
+    pub fn icon(icon: impl Into<Icon>) -> Self {
+        Self {
+            label: None,
+            icon: Some(icon.into()),
+        }
+    }
+
+    /// Set the icon before the label.
+    pub fn with_icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+}
+
+pub enum SegmentedControlEvent {
+    /// The selected segments are changed, with the indices of the selected segments.
+    Change(Vec<usize>),
+}
+
+fn lerp(from: Pixels, to: Pixels, delta: f32) -> Pixels {
+    from + (to - from) * delta
+}
+
+/// A row of the segments that one or several of them are active, with a sliding highlight in the single mode.
+pub struct SegmentedControl {
+    focus_handle: FocusHandle,
+    items: Vec<SegmentItem>,
+    selected: Vec<usize>,
+    multiple: bool,
+    size: Size,
+    /// The segment moved by the arrow keys in the multiple mode, toggle it by the space key.
+    focused_ix: usize,
+    bounds: Bounds<Pixels>,
+    segment_bounds: Vec<Bounds<Pixels>>,
+    /// The left and the width of the highlight to animate from.
+    highlight_from: Option<(Pixels, Pixels)>,
+    animation_ix: usize,
+}
+
+impl EventEmitter<SegmentedControlEvent> for SegmentedControl {}
+
+impl SegmentedControl {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            items: Vec::new(),
+            selected: vec![0],
+            multiple: false,
+            size: Size::Medium,
+            focused_ix: 0,
+            bounds: Bounds::default(),
+            segment_bounds: Vec::new(),
+            highlight_from: None,
+            animation_ix: 0,
+        }
+    }
+
+    pub fn items(mut self, items: impl IntoIterator<Item = SegmentItem>) -> Self {
+        self.items = items.into_iter().collect();
+        self.segment_bounds = vec![Bounds::default(); self.items.len()];
+        self
+    }
+
+    /// Set true to allow several segments to be active, default: false.
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    /// Set the indices of the selected segments, default: `[0]`.
+    pub fn selected(mut self, selected: Vec<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    pub fn selected_indices(&self) -> &[usize] {
+        &self.selected
+    }
+
+    /// Set the selected segments without the event.
+    pub fn set_selected(&mut self, selected: Vec<usize>, cx: &mut ViewContext<Self>) {
+        self.highlight_from = self.highlight_rect();
+        self.animation_ix += 1;
+        self.selected = selected;
+        cx.notify();
+    }
+
+    /// Return the left and the width of the selected segment in the single mode.
+    fn highlight_rect(&self) -> Option<(Pixels, Pixels)> {
+        let bounds = self.segment_bounds.get(*self.selected.first()?)?;
+        if bounds.size.width <= px(0.) {
+            return None;
+        }
+
+        Some((bounds.origin.x - self.bounds.origin.x, bounds.size.width))
+    }
+
+    fn select(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        self.focused_ix = ix;
+        let selected = if self.multiple {
+            let mut selected = self.selected.clone();
+            match selected.iter().position(|i| *i == ix) {
+                Some(pos) => {
+                    selected.remove(pos);
+                }
+                None => selected.push(ix),
+            }
+            selected
+        } else if self.selected == [ix] {
+            return;
+        } else {
+            vec![ix]
+        };
+
+        self.set_selected(selected, cx);
+        cx.emit(SegmentedControlEvent::Change(self.selected.clone()));
+    }
+
+    fn move_selection(&mut self, forward: bool, cx: &mut ViewContext<Self>) {
+        let current = if self.multiple {
+            self.focused_ix
+        } else {
+            self.selected.first().copied().unwrap_or(0)
+        };
+        let Some(ix) = step_index(self.items.len(), Some(current), forward, |_| true) else {
+            return;
+        };
+
+        if self.multiple {
+            self.focused_ix = ix;
+            cx.notify();
+        } else {
+            self.select(ix, cx);
+        }
+    }
+
+    fn select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
+        self.move_selection(false, cx);
+    }
+
+    fn select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
+        self.move_selection(true, cx);
+    }
+
+    fn toggle_selected(&mut self, _: &ToggleSelected, cx: &mut ViewContext<Self>) {
+        if self.multiple {
+            self.select(self.focused_ix, cx);
+        }
+    }
+}
+
+impl Sizable for SegmentedControl {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl FocusableView for SegmentedControl {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for SegmentedControl {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let focused = self.focus_handle.is_focused(cx);
+        let highlight = if self.multiple {
+            None
+        } else {
+            self.highlight_rect()
+        };
+        let segment_h = match self.size {
+            Size::XSmall => px(20.),
+            Size::Small => px(24.),
+            Size::Medium => px(28.),
+            Size::Large => px(36.),
+            Size::Size(size) => size,
+        };
+
+        h_flex()
+            .id("segmented-control")
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::select_prev))
+            .on_action(cx.listener(Self::select_next))
+            .on_action(cx.listener(Self::toggle_selected))
+            .relative()
+            .p_0p5()
+            .gap_0p5()
+            .rounded_md()
+            .bg(cx.theme().muted)
+            .child({
+                let view = view.clone();
+                canvas(
+                    move |bounds, cx| view.update(cx, |this, _| this.bounds = bounds),
+                    |_, _, _| {},
+                )
+                .absolute()
+                .size_full()
+            })
+            .when_some(highlight, |this, to| {
+                let highlight = div()
+                    .absolute()
+                    .top_0p5()
+                    .bottom_0p5()
+                    .rounded_md()
+                    .bg(cx.theme().background)
+                    .shadow_sm();
+
+                this.child(match self.highlight_from {
+                    Some(from) if from != to => highlight
+                        .with_animation(
+                            ("segment-highlight", self.animation_ix),
+                            Animation::new(Duration::from_millis(150)).with_easing(ease_in_out),
+                            move |this, delta| {
+                                this.left(lerp(from.0, to.0, delta))
+                                    .w(lerp(from.1, to.1, delta))
+                            },
+                        )
+                        .into_any_element(),
+                    _ => highlight.left(to.0).w(to.1).into_any_element(),
+                })
+            })
+            .children(self.items.iter().enumerate().map(|(ix, item)| {
+                let view = view.clone();
+                let selected = self.selected.contains(&ix);
+
+                h_flex()
+                    .id(ix)
+                    .relative()
+                    .flex_1()
+                    .gap_1()
+                    .justify_center()
+                    .h(segment_h)
+                    .input_px(self.size, cx)
+                    .input_text_size(self.size)
+                    .rounded_md()
+                    .cursor_pointer()
+                    .map(|this| {
+                        if selected {
+                            this.text_color(cx.theme().foreground)
+                        } else {
+                            this.text_color(cx.theme().muted_foreground)
+                                .hover(|this| this.text_color(cx.theme().foreground))
+                        }
+                    })
+                    // The highlight is only shown in the single mode, or before the bounds is measured.
+                    .when(selected && highlight.is_none(), |this| {
+                        this.bg(cx.theme().background).shadow_sm()
+                    })
+                    .when(self.multiple && focused && self.focused_ix == ix, |this| {
+                        this.border_1().border_color(cx.theme().ring)
+                    })
+                    .when_some(item.icon.clone(), |this, icon| this.child(icon.small()))
+                    .when_some(item.label.clone(), |this, label| this.child(label))
+                    .on_click(cx.listener(move |this, _, cx| this.select(ix, cx)))
+                    .child(
+                        canvas(
+                            move |bounds, cx| {
+                                view.update(cx, |this, cx| {
+                                    if let Some(b) = this.segment_bounds.get_mut(ix) {
+                                        if *b != bounds {
+                                            *b = bounds;
+                                            cx.notify();
+                                        }
+                                    }
+                                })
+                            },
+                            |_, _, _| {},
+                        )
+                        .absolute()
+                        .size_full(),
+                    )
+            }))
+    }
+}
@@ -0,0 +1,451 @@
+use std::{collections::HashSet, rc::Rc};
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnyElement, EventEmitter, Hsla, InteractiveElement as _,
+    IntoElement, ParentElement, Pixels, Render, SharedString, StatefulInteractiveElement as _,
+    Styled, ViewContext, WindowContext,
+};
+
+use crate::{
+    button::{Button, ButtonStyled as _},
+    h_flex,
+    theme::{ActiveTheme as _, ComponentTheme, Theme},
+    tooltip::Tooltip,
+    v_flex, Icon, IconName, Sizable as _, StyledExt as _,
+};
+
+/// The width of each nested level.
+const INDENT_WIDTH: Pixels = px(12.);
+
+/// The style tokens of the [`Sidebar`], override them by [`Theme::override_component`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SidebarTheme {
+    pub background: Hsla,
+    pub foreground: Hsla,
+    pub border: Hsla,
+    pub hover: Hsla,
+    pub active: Hsla,
+    pub active_foreground: Hsla,
+    pub width: Pixels,
+    /// The width of the collapsed rail.
+    pub collapsed_width: Pixels,
+}
+
+impl ComponentTheme for SidebarTheme {
+    fn from_theme(theme: &Theme) -> Self {
+        Self {
+            background: theme.panel,
+            foreground: theme.foreground,
+            border: theme.border,
+            hover: theme.accent,
+            active: theme.list_active,
+            active_foreground: theme.accent_foreground,
+            width: px(240.),
+            collapsed_width: px(48.),
+        }
+    }
+}
+
+/// A nav item in the [`Sidebar`], it's a collapsible group when it has children.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SidebarItem {
+    pub id: SharedString,
+    pub label: SharedString,
+    pub icon: Option<IconName>,
+    pub badge: Option<SharedString>,
+    pub children: Vec<SidebarItem>,
+}
+
+impl SidebarItem {
+    pub fn new(id: impl Into<SharedString>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            icon: None,
+            badge: None,
+            children: vec![],
+        }
+    }
+
+    pub fn icon(mut self, icon: IconName) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    /// Set the badge after the label, e.g.: the unread count.
+    pub fn badge(mut self, badge: impl Into<SharedString>) -> Self {
+        self.badge = Some(badge.into());
+        self
+    }
+
+    /// Set the nested items, they can be collapsed by clicking this item.
+    pub fn children(mut self, children: impl IntoIterator<Item = SidebarItem>) -> Self {
+        self.children = children.into_iter().collect();
+        self
+    }
+
+    /// Return true if the item or any of its descendants has the `id`.
+    fn contains(&self, id: &str) -> bool {
+        self.id.as_ref() == id || self.children.iter().any(|child| child.contains(id))
+    }
+}
+
+/// A group of items in the [`Sidebar`], with an optional header label.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SidebarGroup {
+    pub label: Option<SharedString>,
+    pub items: Vec<SidebarItem>,
+}
+
+impl SidebarGroup {
+    pub fn new(items: impl IntoIterator<Item = SidebarItem>) -> Self {
+        Self {
+            label: None,
+            items: items.into_iter().collect(),
+        }
+    }
+
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+/// Events emitted by the [`Sidebar`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum SidebarEvent {
+    /// The item without children has been selected, with the id of it.
+    Select(SharedString),
+}
+
+/// A navigation sidebar with the grouped items, it can be collapsed to a rail of icons.
+///
+/// ```ignore
+/// cx.new_view(|cx| {
+///     Sidebar::new(cx).groups(vec![SidebarGroup::new(vec![
+///         SidebarItem::new("inbox", "Inbox").icon(IconName::Inbox).badge("3"),
+///     ])
+///     .label("Mail")])
+/// })
+/// ```
+pub struct Sidebar {
+    groups: Vec<SidebarGroup>,
+    active_id: Option<SharedString>,
+    /// The ids of the expanded items with children.
+    expanded: HashSet<SharedString>,
+    collapsed: bool,
+    on_select: Option<Rc<dyn Fn(&SharedString, &mut WindowContext)>>,
+}
+
+impl Sidebar {
+    pub fn new(_: &mut ViewContext<Self>) -> Self {
+        Self {
+            groups: vec![],
+            active_id: None,
+            expanded: HashSet::new(),
+            collapsed: false,
+            on_select: None,
+        }
+    }
+
+    /// Set the groups of the items.
+    pub fn groups(mut self, groups: impl IntoIterator<Item = SidebarGroup>) -> Self {
+        self.groups = groups.into_iter().collect();
+        self
+    }
+
+    /// Set the callback when an item without children is selected.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(&SharedString, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_select = Some(Rc::new(on_select));
+        self
+    }
+
+    pub fn active_id(&self) -> Option<&SharedString> {
+        self.active_id.as_ref()
+    }
+
+    /// Set the active item, the groups containing it are expanded.
+    pub fn set_active(&mut self, id: impl Into<SharedString>, cx: &mut ViewContext<Self>) {
+        let id = id.into();
+        for group in &self.groups {
+            expand_ancestors(&group.items, &id, &mut self.expanded);
+        }
+        self.active_id = Some(id);
+        cx.notify();
+    }
+
+    pub fn is_collapsed(&self) -> bool {
+        self.collapsed
+    }
+
+    /// Set true to collapse the sidebar to a rail of icons, the labels are shown as tooltips.
+    pub fn set_collapsed(&mut self, collapsed: bool, cx: &mut ViewContext<Self>) {
+        self.collapsed = collapsed;
+        cx.notify();
+    }
+
+    pub fn toggle_collapsed(&mut self, cx: &mut ViewContext<Self>) {
+        self.set_collapsed(!self.collapsed, cx);
+    }
+
+    /// Expand or collapse the item with children.
+    pub fn set_expanded(
+        &mut self,
+        id: impl Into<SharedString>,
+        expanded: bool,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let id = id.into();
+        if expanded {
+            self.expanded.insert(id);
+        } else {
+            self.expanded.remove(&id);
+        }
+        cx.notify();
+    }
+
+    fn on_click_item(&mut self, item: &SidebarItem, cx: &mut ViewContext<Self>) {
+        if item.children.is_empty() {
+            self.set_active(item.id.clone(), cx);
+            if let Some(on_select) = self.on_select.clone() {
+                on_select(&item.id, cx);
+            }
+            cx.emit(SidebarEvent::Select(item.id.clone()));
+            return;
+        }
+
+        // The nested items are not shown in the rail, expand the sidebar to show them.
+        if self.collapsed {
+            self.collapsed = false;
+            self.set_expanded(item.id.clone(), true, cx);
+        } else {
+            let expanded = self.expanded.contains(&item.id);
+            self.set_expanded(item.id.clone(), !expanded, cx);
+        }
+    }
+
+    fn render_item(
+        &self,
+        item: &SidebarItem,
+        depth: usize,
+        cx: &mut ViewContext<Self>,
+    ) -> AnyElement {
+        let theme = cx.theme().component::<SidebarTheme>();
+        let is_group = !item.children.is_empty();
+        let expanded = self.expanded.contains(&item.id);
+        let active = match self.active_id.as_ref() {
+            // Highlight the collapsed group or the rail icon of the active item.
+            Some(id) if self.collapsed || (is_group && !expanded) => item.contains(id),
+            Some(id) => id == &item.id,
+            None => false,
+        };
+
+        let row = h_flex()
+            .id(SharedString::from(format!("sidebar-item-{}", item.id)))
+            .relative()
+            .gap_2()
+            .rounded(px(cx.theme().radius))
+            .cursor_pointer()
+            .text_sm()
+            .map(|this| {
+                if self.collapsed {
+                    this.justify_center().size(px(32.))
+                } else {
+                    this.w_full()
+                        .py_1p5()
+                        .pr_2()
+                        .pl(px(8.) + INDENT_WIDTH * depth as f32)
+                }
+            })
+            .when(active, |this| {
+                this.bg(theme.active)
+                    .text_color(theme.active_foreground)
+                    .font_medium()
+            })
+            .when(!active, |this| this.hover(|this| this.bg(theme.hover)))
+            .when_some(item.icon.clone(), |this, icon| {
+                this.child(Icon::new(icon).small())
+            })
+            .map(|this| {
+                if self.collapsed {
+                    let label = item.label.clone();
+                    this.when(item.icon.is_none(), |this| {
+                        this.child(item.label.chars().take(1).collect::<String>())
+                    })
+                    .when(item.badge.is_some(), |this| {
+                        this.child(
+                            div()
+                                .absolute()
+                                .top(px(4.))
+                                .right(px(4.))
+                                .size(px(6.))
+                                .rounded_full()
+                                .bg(cx.theme().primary),
+                        )
+                    })
+                    .tooltip(move |cx| Tooltip::new(label.clone(), cx))
+                } else {
+                    this.child(
+                        div()
+                            .flex_1()
+                            .overflow_hidden()
+                            .text_ellipsis()
+                            .child(item.label.clone()),
+                    )
+                    .when_some(item.badge.clone(), |this, badge| {
+                        this.child(
+                            div()
+                                .px_1p5()
+                                .rounded_full()
+                                .bg(cx.theme().primary)
+                                .text_color(cx.theme().primary_foreground)
+                                .text_xs()
+                                .child(badge),
+                        )
+                    })
+                    .when(is_group, |this| {
+                        this.child(
+                            Icon::new(if expanded {
+                                IconName::ChevronDown
+                            } else {
+                                IconName::ChevronRight
+                            })
+                            .small()
+                            .text_color(cx.theme().muted_foreground),
+                        )
+                    })
+                }
+            })
+            .on_click({
+                let item = item.clone();
+                cx.listener(move |this, _, cx| this.on_click_item(&item, cx))
+            });
+
+        v_flex()
+            .w_full()
+            .when(self.collapsed, |this| this.items_center())
+            .child(row)
+            .when(is_group && expanded && !self.collapsed, |this| {
+                this.children(
+                    item.children
+                        .iter()
+                        .map(|child| self.render_item(child, depth + 1, cx)),
+                )
+            })
+            .into_any_element()
+    }
+}
+
+/// Insert the ids of the items containing the `id` to the `expanded`, return true if found.
+fn expand_ancestors(items: &[SidebarItem], id: &str, expanded: &mut HashSet<SharedString>) -> bool {
+    for item in items {
+        if item.id.as_ref() == id {
+            return true;
+        }
+        if expand_ancestors(&item.children, id, expanded) {
+            expanded.insert(item.id.clone());
+            return true;
+        }
+    }
+    false
+}
+
+impl EventEmitter<SidebarEvent> for Sidebar {}
+
+impl Render for Sidebar {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let theme = cx.theme().component::<SidebarTheme>();
+        let collapsed = self.collapsed;
+
+        v_flex()
+            .id("sidebar")
+            .h_full()
+            .flex_shrink_0()
+            .w(if collapsed {
+                theme.collapsed_width
+            } else {
+                theme.width
+            })
+            .bg(theme.background)
+            .text_color(theme.foreground)
+            .border_r_1()
+            .border_color(theme.border)
+            .child(
+                v_flex()
+                    .id("sidebar-content")
+                    .flex_1()
+                    .overflow_y_scroll()
+                    .p_2()
+                    .gap_3()
+                    .children(self.groups.iter().enumerate().map(|(ix, group)| {
+                        v_flex()
+                            .gap_0p5()
+                            .when(collapsed && ix > 0, |this| {
+                                this.pt_3().border_t_1().border_color(theme.border)
+                            })
+                            .when_some(group.label.clone().filter(|_| !collapsed), |this, label| {
+                                this.child(
+                                    div()
+                                        .px_2()
+                                        .py_1()
+                                        .text_xs()
+                                        .font_medium()
+                                        .text_color(cx.theme().muted_foreground)
+                                        .child(label),
+                                )
+                            })
+                            .children(group.items.iter().map(|item| self.render_item(item, 0, cx)))
+                    })),
+            )
+            .child(
+                h_flex()
+                    .p_2()
+                    .border_t_1()
+                    .border_color(theme.border)
+                    .when(collapsed, |this| this.justify_center())
+                    .when(!collapsed, |this| this.justify_end())
+                    .child(
+                        Button::new("toggle-sidebar")
+                            .icon(IconName::PanelLeft)
+                            .small()
+                            .ghost()
+                            .on_click(cx.listener(|this, _, cx| this.toggle_collapsed(cx))),
+                    ),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{expand_ancestors, SidebarItem};
+
+    #[test]
+    fn test_expand_ancestors() {
+        let items = vec![
+            SidebarItem::new("home", "Home"),
+            SidebarItem::new("settings", "Settings").children(vec![
+                SidebarItem::new("general", "General"),
+                SidebarItem::new("advanced", "Advanced")
+                    .children(vec![SidebarItem::new("network", "Network")]),
+            ]),
+        ];
+
+        let mut expanded = HashSet::new();
+        assert!(expand_ancestors(&items, "network", &mut expanded));
+        assert_eq!(
+            expanded,
+            HashSet::from(["settings".into(), "advanced".into()])
+        );
+        assert!(items[1].contains("network"));
+        assert!(!items[0].contains("network"));
+
+        let mut expanded = HashSet::new();
+        assert!(!expand_ancestors(&items, "unknown", &mut expanded));
+        assert!(expanded.is_empty());
+    }
+}
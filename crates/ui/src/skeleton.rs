@@ -1,12 +1,13 @@
 use std::time::Duration;
 
 use gpui::{
-    bounce, div, ease_in_out, Animation, AnimationExt, Div, IntoElement, ParentElement as _,
-    RenderOnce, Styled,
+    div, ease_in_out, prelude::FluentBuilder as _, relative, Animation, AnimationExt, Div,
+    IntoElement, ParentElement as _, RenderOnce, Styled, WindowContext,
 };
 
-use crate::theme::ActiveTheme;
+use crate::{h_flex, theme::ActiveTheme, v_flex};
 
+/// A placeholder with the shimmer animation to show the structure of the content while loading.
 #[derive(IntoElement)]
 pub struct Skeleton {
     base: Div,
@@ -18,6 +19,25 @@ impl Skeleton {
             base: div().w_full().h_4().rounded_md(),
         }
     }
+
+    /// A line of text, it's the same as [`Skeleton::new`].
+    pub fn text() -> Self {
+        Self::new()
+    }
+
+    /// A circle, e.g.: the avatar.
+    pub fn circle() -> Self {
+        Self {
+            base: div().flex_shrink_0().size_10().rounded_full(),
+        }
+    }
+
+    /// A rectangle, e.g.: the image or the card.
+    pub fn rect() -> Self {
+        Self {
+            base: div().w_full().h_24().rounded_lg(),
+        }
+    }
 }
 
 impl Styled for Skeleton {
@@ -27,18 +47,117 @@ impl Styled for Skeleton {
 }
 
 impl RenderOnce for Skeleton {
-    fn render(self, cx: &mut gpui::WindowContext) -> impl IntoElement {
-        div().child(
-            self.base.bg(cx.theme().skeleton).with_animation(
-                "skeleton",
-                Animation::new(Duration::from_secs(2))
-                    .repeat()
-                    .with_easing(bounce(ease_in_out)),
-                move |this, delta| {
-                    let v = 1.0 - delta * 0.5;
-                    this.opacity(v)
-                },
-            ),
-        )
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let shimmer = cx.theme().translucent(cx.theme().background, 0.35);
+
+        self.base
+            .flex()
+            .overflow_hidden()
+            .bg(cx.theme().skeleton)
+            .child(
+                div()
+                    .flex_shrink_0()
+                    .h_full()
+                    .w(relative(0.4))
+                    .bg(shimmer)
+                    .with_animation(
+                        "skeleton",
+                        Animation::new(Duration::from_secs(2))
+                            .repeat()
+                            .with_easing(ease_in_out),
+                        // Move the band from the outside of the left to the outside of the right.
+                        |this, delta| this.ml(relative(delta * 1.4 - 0.4)),
+                    ),
+            )
+    }
+}
+
+/// Return the relative width of the line at `ix`, so the lines are not aligned like a grid.
+fn line_width(ix: usize) -> f32 {
+    [0.9, 0.75, 0.6][ix % 3]
+}
+
+/// A placeholder of the list, each row has an avatar and two lines.
+#[derive(IntoElement)]
+pub struct SkeletonList {
+    rows: usize,
+    avatar: bool,
+}
+
+impl SkeletonList {
+    pub fn new(rows: usize) -> Self {
+        Self { rows, avatar: true }
+    }
+
+    /// Set false to hide the avatar of the rows, default: true.
+    pub fn avatar(mut self, avatar: bool) -> Self {
+        self.avatar = avatar;
+        self
+    }
+}
+
+impl RenderOnce for SkeletonList {
+    fn render(self, _: &mut WindowContext) -> impl IntoElement {
+        v_flex().w_full().gap_4().children((0..self.rows).map(|ix| {
+            h_flex()
+                .gap_3()
+                .when(self.avatar, |this| this.child(Skeleton::circle()))
+                .child(
+                    v_flex()
+                        .flex_1()
+                        .gap_2()
+                        .child(Skeleton::text().w(relative(0.4)))
+                        .child(Skeleton::text().h_3().w(relative(line_width(ix)))),
+                )
+        }))
+    }
+}
+
+/// A placeholder of the table, with the head row and the rows of cells.
+#[derive(IntoElement)]
+pub struct SkeletonTable {
+    rows: usize,
+    cols: usize,
+}
+
+impl SkeletonTable {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self { rows, cols }
+    }
+}
+
+impl RenderOnce for SkeletonTable {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let cols = self.cols;
+
+        v_flex()
+            .w_full()
+            .overflow_hidden()
+            .rounded_md()
+            .border_1()
+            .border_color(cx.theme().border)
+            .child(
+                h_flex()
+                    .gap_4()
+                    .px_3()
+                    .py_2p5()
+                    .bg(cx.theme().table_head)
+                    .children(
+                        (0..cols).map(|_| div().flex_1().child(Skeleton::text().h_3().w_1_2())),
+                    ),
+            )
+            .children((0..self.rows).map(|row| {
+                h_flex()
+                    .gap_4()
+                    .px_3()
+                    .py_2p5()
+                    .border_t_1()
+                    .border_color(cx.theme().border)
+                    .children((0..cols).map(move |col| {
+                        div()
+                            .flex_1()
+                            .child(Skeleton::text().h_3().w(relative(line_width(row + col))))
+                    }))
+            }))
     }
 }
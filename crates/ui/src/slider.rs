@@ -1,13 +1,31 @@
-use crate::{theme::ActiveTheme, tooltip::Tooltip};
+use crate::{h_flex, theme::ActiveTheme};
 use gpui::{
-    canvas, div, prelude::FluentBuilder as _, px, relative, Axis, Bounds, DragMoveEvent, EntityId,
-    EventEmitter, InteractiveElement, IntoElement, MouseButton, MouseDownEvent, ParentElement as _,
-    Pixels, Point, Render, StatefulInteractiveElement as _, Styled, ViewContext,
-    VisualContext as _,
+    actions, canvas, div, prelude::FluentBuilder as _, px, relative, AppContext, Axis, Bounds,
+    DragMoveEvent, EntityId, EventEmitter, FocusHandle, FocusableView, InteractiveElement,
+    IntoElement, KeyBinding, MouseButton, MouseDownEvent, MouseUpEvent, ParentElement as _, Pixels,
+    Point, Render, StatefulInteractiveElement as _, Styled, ViewContext, VisualContext as _,
 };
 
+actions!(slider, [Increase, Decrease, Home, End]);
+
+const CONTEXT: &str = "Slider";
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("left", Decrease, Some(CONTEXT)),
+        KeyBinding::new("down", Decrease, Some(CONTEXT)),
+        KeyBinding::new("right", Increase, Some(CONTEXT)),
+        KeyBinding::new("up", Increase, Some(CONTEXT)),
+        KeyBinding::new("home", Home, Some(CONTEXT)),
+        KeyBinding::new("end", End, Some(CONTEXT)),
+    ]);
+}
+
+/// The max number of the tick marks, the ticks are hidden if there are more steps than this.
+const MAX_TICKS: usize = 100;
+
+/// The drag payload of the thumb, with the index of the thumb in the slider.
 #[derive(Clone, Render)]
-pub struct DragThumb(EntityId);
+pub struct DragThumb(EntityId, usize);
 
 pub enum SliderEvent {
     Change(f32),
@@ -15,28 +33,34 @@ pub enum SliderEvent {
 
 /// A slider component.
 pub struct Slider {
+    focus_handle: FocusHandle,
     axis: Axis,
     min: f32,
     max: f32,
     step: f32,
     value: f32,
+    ticks: bool,
+    dragging: bool,
     bounds: Bounds<Pixels>,
 }
 
 impl Slider {
-    fn new(axis: Axis) -> Self {
+    fn new(axis: Axis, cx: &mut ViewContext<Self>) -> Self {
         Self {
+            focus_handle: cx.focus_handle(),
             axis,
             min: 0.0,
             max: 100.0,
             step: 1.0,
             value: 0.0,
+            ticks: false,
+            dragging: false,
             bounds: Bounds::default(),
         }
     }
 
-    pub fn horizontal() -> Self {
-        Self::new(Axis::Horizontal)
+    pub fn horizontal(cx: &mut ViewContext<Self>) -> Self {
+        Self::new(Axis::Horizontal, cx)
     }
 
     /// Set the minimum value of the slider, default: 0.0
@@ -63,6 +87,17 @@ impl Slider {
         self
     }
 
+    /// Set true to show a tick mark at every step, default: false
+    pub fn ticks(mut self, ticks: bool) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Return the value of the slider.
+    pub fn value(&self) -> f32 {
+        self.value
+    }
+
     /// Set the value of the slider.
     pub fn set_value(&mut self, value: f32, cx: &mut gpui::ViewContext<Self>) {
         self.value = value;
@@ -71,95 +106,87 @@ impl Slider {
 
     /// Return percentage value of the slider, range of 0.0..1.0
     fn relative_value(&self) -> f32 {
-        let step = self.step;
-        let value = self.value;
-        let min = self.min;
-        let max = self.max;
-
-        let relative_value = (value - min) / (max - min);
-        let relative_step = step / (max - min);
-
-        let relative_value = (relative_value / relative_step).round() * relative_step;
-        relative_value.clamp(0.0, 1.0)
+        relative_of(
+            snap_value(self.value, self.min, self.max, self.step),
+            self.min,
+            self.max,
+        )
     }
 
-    /// Update value by mouse position
-    fn update_value_by_position(
-        &mut self,
-        position: Point<Pixels>,
-        cx: &mut gpui::ViewContext<Self>,
-    ) {
-        let bounds = self.bounds;
-        let axis = self.axis;
-        let min = self.min;
-        let max = self.max;
-        let step = self.step;
-
-        let value = match axis {
-            Axis::Horizontal => {
-                let relative = (position.x - bounds.left()) / bounds.size.width;
-                min + (max - min) * relative
-            }
-            Axis::Vertical => {
-                let relative = (position.y - bounds.top()) / bounds.size.height;
-                max - (max - min) * relative
-            }
-        };
-
-        let value = (value / step).round() * step;
+    /// Update the value, and emit the change if it's changed.
+    fn update_value(&mut self, value: f32, cx: &mut ViewContext<Self>) {
+        let value = snap_value(value, self.min, self.max, self.step);
+        if value == self.value {
+            return;
+        }
 
-        self.value = value.clamp(self.min, self.max);
+        self.value = value;
         cx.emit(SliderEvent::Change(self.value));
         cx.notify();
     }
 
-    fn render_thumb(&self, cx: &mut ViewContext<Self>) -> impl gpui::IntoElement {
-        let value = self.value;
-        let entity_id = cx.entity_id();
+    fn increase(&mut self, _: &Increase, cx: &mut ViewContext<Self>) {
+        self.update_value(self.value + self.step, cx);
+    }
 
-        div()
-            .id("slider-thumb")
-            .on_drag(DragThumb(entity_id), |drag, cx| {
-                cx.stop_propagation();
-                cx.new_view(|_| drag.clone())
-            })
-            .on_drag_move(cx.listener(
-                move |view, e: &DragMoveEvent<DragThumb>, cx| match e.drag(cx) {
-                    DragThumb(id) => {
-                        if *id != entity_id {
-                            return;
-                        }
-
-                        // set value by mouse position
-                        view.update_value_by_position(e.event.position, cx)
-                    }
-                },
-            ))
-            .absolute()
-            .top(px(-5.))
-            .left(relative(self.relative_value()))
-            .ml(-px(8.))
-            .size_4()
-            .rounded_full()
-            .border_1()
-            .border_color(cx.theme().slider_bar.opacity(0.9))
-            .when(cx.theme().shadow, |this| this.shadow_md())
-            .bg(cx.theme().slider_thumb)
-            .tooltip(move |cx| Tooltip::new(format!("{}", value), cx))
+    fn decrease(&mut self, _: &Decrease, cx: &mut ViewContext<Self>) {
+        self.update_value(self.value - self.step, cx);
+    }
+
+    fn home(&mut self, _: &Home, cx: &mut ViewContext<Self>) {
+        self.update_value(self.min, cx);
+    }
+
+    fn end(&mut self, _: &End, cx: &mut ViewContext<Self>) {
+        self.update_value(self.max, cx);
     }
 
     fn on_mouse_down(&mut self, event: &MouseDownEvent, cx: &mut gpui::ViewContext<Self>) {
-        self.update_value_by_position(event.position, cx);
+        cx.focus(&self.focus_handle);
+        self.dragging = true;
+        self.move_thumb(0, event.position, cx);
+    }
+
+    fn on_mouse_up(&mut self, _: &MouseUpEvent, cx: &mut gpui::ViewContext<Self>) {
+        if self.dragging {
+            self.dragging = false;
+            cx.notify();
+        }
+    }
+}
+
+impl SliderThumbs for Slider {
+    /// Update value by mouse position
+    fn move_thumb(&mut self, _: usize, position: Point<Pixels>, cx: &mut ViewContext<Self>) {
+        let value = value_by_position(self.axis, self.bounds, position, self.min, self.max);
+        self.update_value(value, cx);
     }
 }
 
 impl EventEmitter<SliderEvent> for Slider {}
 
+impl FocusableView for Slider {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
 impl Render for Slider {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let relative_value = self.relative_value();
+        let focused = self.focus_handle.is_focused(cx);
+
         div()
             .id("slider")
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::increase))
+            .on_action(cx.listener(Self::decrease))
+            .on_action(cx.listener(Self::home))
+            .on_action(cx.listener(Self::end))
             .on_mouse_down(MouseButton::Left, cx.listener(Self::on_mouse_down))
+            .on_mouse_up(MouseButton::Left, cx.listener(Self::on_mouse_up))
+            .on_mouse_up_out(MouseButton::Left, cx.listener(Self::on_mouse_up))
             .h_5()
             .child(
                 div()
@@ -168,8 +195,8 @@ impl Render for Slider {
                     .w_full()
                     .my_1p5()
                     .h_1p5()
-                    .bg(cx.theme().slider_bar.opacity(0.2))
-                    .active(|this| this.bg(cx.theme().slider_bar.opacity(0.4)))
+                    .bg(cx.theme().translucent(cx.theme().slider_bar, 0.2))
+                    .active(|this| this.bg(cx.theme().translucent(cx.theme().slider_bar, 0.4)))
                     .rounded(px(3.))
                     .child(
                         div()
@@ -177,11 +204,27 @@ impl Render for Slider {
                             .top_0()
                             .left_0()
                             .h_full()
-                            .w(relative(self.relative_value()))
+                            .w(relative(relative_value))
                             .bg(cx.theme().slider_bar)
                             .rounded_l(px(3.)),
                     )
-                    .child(self.render_thumb(cx))
+                    .when(self.ticks, |this| {
+                        this.children(render_ticks(
+                            self.min,
+                            self.max,
+                            self.step,
+                            (0., relative_value),
+                            cx,
+                        ))
+                    })
+                    .child(render_thumb(
+                        0,
+                        relative_value,
+                        format_value(self.value, self.step),
+                        self.dragging,
+                        focused,
+                        cx,
+                    ))
                     .child({
                         let view = cx.view().clone();
                         canvas(
@@ -194,3 +237,431 @@ impl Render for Slider {
             )
     }
 }
+
+pub enum RangeSliderEvent {
+    /// The range is changed, with the start and end values.
+    Change(f32, f32),
+}
+
+/// A slider with two thumbs to select a range, e.g.: filter the price from 100 to 500.
+pub struct RangeSlider {
+    focus_handle: FocusHandle,
+    axis: Axis,
+    min: f32,
+    max: f32,
+    step: f32,
+    start: f32,
+    end: f32,
+    ticks: bool,
+    /// The index of the thumb to move by the keyboard, the last dragged one.
+    active_ix: usize,
+    dragging: bool,
+    bounds: Bounds<Pixels>,
+}
+
+impl RangeSlider {
+    fn new(axis: Axis, cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            axis,
+            min: 0.0,
+            max: 100.0,
+            step: 1.0,
+            start: 0.0,
+            end: 100.0,
+            ticks: false,
+            active_ix: 0,
+            dragging: false,
+            bounds: Bounds::default(),
+        }
+    }
+
+    pub fn horizontal(cx: &mut ViewContext<Self>) -> Self {
+        Self::new(Axis::Horizontal, cx)
+    }
+
+    /// Set the minimum value of the slider, default: 0.0
+    pub fn min(mut self, min: f32) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Set the maximum value of the slider, default: 100.0
+    pub fn max(mut self, max: f32) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Set the step value of the slider, default: 1.0
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Set the default range of the slider, default: 0.0..100.0
+    pub fn default_range(mut self, start: f32, end: f32) -> Self {
+        self.start = start.min(end);
+        self.end = end.max(start);
+        self
+    }
+
+    /// Set true to show a tick mark at every step, default: false
+    pub fn ticks(mut self, ticks: bool) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Return the start and end values of the range.
+    pub fn range(&self) -> (f32, f32) {
+        (self.start, self.end)
+    }
+
+    /// Set the range of the slider.
+    pub fn set_range(&mut self, start: f32, end: f32, cx: &mut ViewContext<Self>) {
+        self.start = start.min(end);
+        self.end = end.max(start);
+        cx.notify();
+    }
+
+    /// Update the value of the thumb, the thumbs can't cross each other.
+    fn update_value(&mut self, ix: usize, value: f32, cx: &mut ViewContext<Self>) {
+        let value = snap_value(value, self.min, self.max, self.step);
+        let (start, end) = match ix {
+            0 => (value.min(self.end), self.end),
+            _ => (self.start, value.max(self.start)),
+        };
+        if (start, end) == (self.start, self.end) {
+            return;
+        }
+
+        self.start = start;
+        self.end = end;
+        cx.emit(RangeSliderEvent::Change(start, end));
+        cx.notify();
+    }
+
+    fn active_value(&self) -> f32 {
+        match self.active_ix {
+            0 => self.start,
+            _ => self.end,
+        }
+    }
+
+    fn increase(&mut self, _: &Increase, cx: &mut ViewContext<Self>) {
+        self.update_value(self.active_ix, self.active_value() + self.step, cx);
+    }
+
+    fn decrease(&mut self, _: &Decrease, cx: &mut ViewContext<Self>) {
+        self.update_value(self.active_ix, self.active_value() - self.step, cx);
+    }
+
+    fn home(&mut self, _: &Home, cx: &mut ViewContext<Self>) {
+        self.update_value(self.active_ix, self.min, cx);
+    }
+
+    fn end(&mut self, _: &End, cx: &mut ViewContext<Self>) {
+        self.update_value(self.active_ix, self.max, cx);
+    }
+
+    fn on_mouse_down(&mut self, event: &MouseDownEvent, cx: &mut ViewContext<Self>) {
+        let value = value_by_position(self.axis, self.bounds, event.position, self.min, self.max);
+        // Move the nearest thumb, or the end thumb if they are at the same position.
+        self.active_ix = if (value - self.start).abs() < (value - self.end).abs()
+            || (self.start == self.end && value < self.start)
+        {
+            0
+        } else {
+            1
+        };
+
+        cx.focus(&self.focus_handle);
+        self.dragging = true;
+        self.move_thumb(self.active_ix, event.position, cx);
+    }
+
+    fn on_mouse_up(&mut self, _: &MouseUpEvent, cx: &mut ViewContext<Self>) {
+        if self.dragging {
+            self.dragging = false;
+            cx.notify();
+        }
+    }
+}
+
+impl SliderThumbs for RangeSlider {
+    fn move_thumb(&mut self, ix: usize, position: Point<Pixels>, cx: &mut ViewContext<Self>) {
+        let value = value_by_position(self.axis, self.bounds, position, self.min, self.max);
+        self.active_ix = ix;
+        self.update_value(ix, value, cx);
+    }
+}
+
+impl EventEmitter<RangeSliderEvent> for RangeSlider {}
+
+impl FocusableView for RangeSlider {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for RangeSlider {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let start = relative_of(self.start, self.min, self.max);
+        let end = relative_of(self.end, self.min, self.max);
+        let focused = self.focus_handle.is_focused(cx);
+
+        div()
+            .id("range-slider")
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::increase))
+            .on_action(cx.listener(Self::decrease))
+            .on_action(cx.listener(Self::home))
+            .on_action(cx.listener(Self::end))
+            .on_mouse_down(MouseButton::Left, cx.listener(Self::on_mouse_down))
+            .on_mouse_up(MouseButton::Left, cx.listener(Self::on_mouse_up))
+            .on_mouse_up_out(MouseButton::Left, cx.listener(Self::on_mouse_up))
+            .h_5()
+            .child(
+                div()
+                    .id("slider-bar")
+                    .relative()
+                    .w_full()
+                    .my_1p5()
+                    .h_1p5()
+                    .bg(cx.theme().translucent(cx.theme().slider_bar, 0.2))
+                    .active(|this| this.bg(cx.theme().translucent(cx.theme().slider_bar, 0.4)))
+                    .rounded(px(3.))
+                    .child(
+                        div()
+                            .absolute()
+                            .top_0()
+                            .left(relative(start))
+                            .h_full()
+                            .w(relative(end - start))
+                            .bg(cx.theme().slider_bar),
+                    )
+                    .when(self.ticks, |this| {
+                        this.children(render_ticks(
+                            self.min,
+                            self.max,
+                            self.step,
+                            (start, end),
+                            cx,
+                        ))
+                    })
+                    .child(render_thumb(
+                        0,
+                        start,
+                        format_value(self.start, self.step),
+                        self.dragging && self.active_ix == 0,
+                        focused && self.active_ix == 0,
+                        cx,
+                    ))
+                    .child(render_thumb(
+                        1,
+                        end,
+                        format_value(self.end, self.step),
+                        self.dragging && self.active_ix == 1,
+                        focused && self.active_ix == 1,
+                        cx,
+                    ))
+                    .child({
+                        let view = cx.view().clone();
+                        canvas(
+                            move |bounds, cx| view.update(cx, |r, _| r.bounds = bounds),
+                            |_, _, _| {},
+                        )
+                        .absolute()
+                        .size_full()
+                    }),
+            )
+    }
+}
+
+/// The sliders with the draggable thumbs.
+trait SliderThumbs: Render {
+    /// Move the thumb at `ix` to the mouse position.
+    fn move_thumb(&mut self, ix: usize, position: Point<Pixels>, cx: &mut ViewContext<Self>);
+}
+
+fn render_thumb<V: SliderThumbs>(
+    ix: usize,
+    relative_value: f32,
+    label: String,
+    dragging: bool,
+    focused: bool,
+    cx: &mut ViewContext<V>,
+) -> impl IntoElement {
+    let entity_id = cx.entity_id();
+
+    div()
+        .id(("slider-thumb", ix))
+        .on_drag(DragThumb(entity_id, ix), |drag, cx| {
+            cx.stop_propagation();
+            cx.new_view(|_| drag.clone())
+        })
+        .on_drag_move(cx.listener(
+            move |view, e: &DragMoveEvent<DragThumb>, cx| match e.drag(cx) {
+                DragThumb(id, thumb_ix) => {
+                    if *id != entity_id || *thumb_ix != ix {
+                        return;
+                    }
+
+                    // set value by mouse position
+                    view.move_thumb(ix, e.event.position, cx)
+                }
+            },
+        ))
+        .absolute()
+        .top(px(-5.))
+        .left(relative(relative_value))
+        .ml(-px(8.))
+        .size_4()
+        .rounded_full()
+        .border_1()
+        .border_color(cx.theme().translucent(cx.theme().slider_bar, 0.9))
+        .when(focused, |this| this.border_color(cx.theme().ring))
+        .when(cx.theme().shadow, |this| this.shadow_md())
+        .bg(cx.theme().slider_thumb)
+        .when(dragging, |this| {
+            // Show the value above the thumb while dragging.
+            this.child(
+                h_flex()
+                    .absolute()
+                    .bottom(px(22.))
+                    .left_0()
+                    .right_0()
+                    .justify_center()
+                    .child(
+                        div()
+                            .whitespace_nowrap()
+                            .bg(cx.theme().popover)
+                            .text_color(cx.theme().popover_foreground)
+                            .border_1()
+                            .border_color(cx.theme().border)
+                            .shadow_md()
+                            .rounded(px(6.))
+                            .py_0p5()
+                            .px_2()
+                            .text_xs()
+                            .child(label),
+                    ),
+            )
+        })
+}
+
+/// Render the tick marks at every step, the ticks in the `filled` range are highlighted.
+fn render_ticks<V: 'static>(
+    min: f32,
+    max: f32,
+    step: f32,
+    filled: (f32, f32),
+    cx: &ViewContext<V>,
+) -> Vec<gpui::Div> {
+    let count = if step > 0. {
+        ((max - min) / step).floor() as usize
+    } else {
+        0
+    };
+    if count == 0 || count > MAX_TICKS {
+        return vec![];
+    }
+
+    (0..=count)
+        .map(|ix| {
+            let relative_value = relative_of(min + step * ix as f32, min, max);
+            let color = if relative_value >= filled.0 && relative_value <= filled.1 {
+                cx.theme().slider_thumb
+            } else {
+                cx.theme().translucent(cx.theme().slider_bar, 0.5)
+            };
+
+            div()
+                .absolute()
+                .top(px(1.))
+                .left(relative(relative_value))
+                .ml(-px(1.))
+                .size(px(2.))
+                .rounded_full()
+                .bg(color)
+        })
+        .collect()
+}
+
+/// Return the value at the mouse position of the bar, it's not snapped to the step.
+fn value_by_position(
+    axis: Axis,
+    bounds: Bounds<Pixels>,
+    position: Point<Pixels>,
+    min: f32,
+    max: f32,
+) -> f32 {
+    match axis {
+        Axis::Horizontal => {
+            let relative = (position.x - bounds.left()) / bounds.size.width;
+            min + (max - min) * relative
+        }
+        Axis::Vertical => {
+            let relative = (position.y - bounds.top()) / bounds.size.height;
+            max - (max - min) * relative
+        }
+    }
+}
+
+/// Snap the value to the nearest step from the `min`, and clamp it to `min..=max`.
+fn snap_value(value: f32, min: f32, max: f32, step: f32) -> f32 {
+    let value = if step > 0. {
+        min + ((value - min) / step).round() * step
+    } else {
+        value
+    };
+
+    value.clamp(min, max)
+}
+
+/// Return percentage of the value between `min` and `max`, range of 0.0..1.0
+fn relative_of(value: f32, min: f32, max: f32) -> f32 {
+    if max <= min {
+        return 0.;
+    }
+
+    ((value - min) / (max - min)).clamp(0.0, 1.0)
+}
+
+/// Format the value with the decimal places of the step, e.g.: `0.26` with step `0.1` is `0.3`.
+fn format_value(value: f32, step: f32) -> String {
+    let step = format!("{}", step);
+    let decimals = step.split_once('.').map_or(0, |(_, fract)| fract.len());
+    format!("{:.*}", decimals, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_value, relative_of, snap_value};
+
+    #[test]
+    fn test_snap_value() {
+        assert_eq!(snap_value(17., 0., 100., 5.), 15.);
+        assert_eq!(snap_value(18., 0., 100., 5.), 20.);
+        // The steps start from the min.
+        assert_eq!(snap_value(4., -3., 10., 5.), 2.);
+        assert_eq!(snap_value(120., 0., 100., 5.), 100.);
+        assert_eq!(snap_value(-10., 0., 100., 5.), 0.);
+        assert_eq!(snap_value(3.3, 0., 10., 0.), 3.3);
+    }
+
+    #[test]
+    fn test_relative_of() {
+        assert_eq!(relative_of(50., 0., 200.), 0.25);
+        assert_eq!(relative_of(-255., -255., 255.), 0.);
+        assert_eq!(relative_of(300., 0., 200.), 1.);
+        assert_eq!(relative_of(10., 10., 10.), 0.);
+    }
+
+    #[test]
+    fn test_format_value() {
+        assert_eq!(format_value(15., 1.), "15");
+        assert_eq!(format_value(0.25, 0.1), "0.2");
+        assert_eq!(format_value(2.5, 0.05), "2.50");
+    }
+}
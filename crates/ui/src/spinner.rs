@@ -0,0 +1,147 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, AnyElement, Div, Hsla, InteractiveElement as _, IntoElement,
+    ParentElement, RenderOnce, SharedString, Styled, WindowContext,
+};
+
+use crate::{
+    indicator::Indicator,
+    theme::{ActiveTheme as _, ComponentTheme, Theme},
+    v_flex, IconName, Sizable, Size,
+};
+
+/// The style tokens of the [`Spinner`] and [`LoadingOverlay`], override them by [`Theme::override_component`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpinnerTheme {
+    pub color: Hsla,
+    /// The background of the [`LoadingOverlay`] to dim the content.
+    pub overlay: Hsla,
+}
+
+impl ComponentTheme for SpinnerTheme {
+    fn from_theme(theme: &Theme) -> Self {
+        Self {
+            color: theme.muted_foreground,
+            overlay: theme.translucent(theme.background, 0.6),
+        }
+    }
+}
+
+/// A spinning loader in the theme color.
+#[derive(IntoElement)]
+pub struct Spinner {
+    size: Size,
+    color: Option<Hsla>,
+}
+
+impl Spinner {
+    pub fn new() -> Self {
+        Self {
+            size: Size::Medium,
+            color: None,
+        }
+    }
+
+    /// Set the color of the spinner, default is the [`SpinnerTheme::color`].
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+impl Sizable for Spinner {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl RenderOnce for Spinner {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let color = self
+            .color
+            .unwrap_or(cx.theme().component::<SpinnerTheme>().color);
+
+        Indicator::new()
+            .icon(IconName::LoaderCircle)
+            .with_size(self.size)
+            .color(color)
+    }
+}
+
+/// A wrapper to dim the children and block the mouse interaction with a [`Spinner`] while loading.
+#[derive(IntoElement)]
+pub struct LoadingOverlay {
+    base: Div,
+    loading: bool,
+    label: Option<SharedString>,
+    size: Size,
+    children: Vec<AnyElement>,
+}
+
+impl LoadingOverlay {
+    pub fn new(loading: bool) -> Self {
+        Self {
+            base: div(),
+            loading,
+            label: None,
+            size: Size::Medium,
+            children: Vec::new(),
+        }
+    }
+
+    /// Set the label below the spinner, e.g.: "Loading..."
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+impl Sizable for LoadingOverlay {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl Styled for LoadingOverlay {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl ParentElement for LoadingOverlay {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements)
+    }
+}
+
+impl RenderOnce for LoadingOverlay {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let theme = cx.theme().component::<SpinnerTheme>();
+
+        self.base
+            .relative()
+            .children(self.children)
+            .when(self.loading, |this| {
+                this.child(
+                    v_flex()
+                        .occlude()
+                        .absolute()
+                        .inset_0()
+                        .items_center()
+                        .justify_center()
+                        .gap_2()
+                        .bg(theme.overlay)
+                        .child(Spinner::new().with_size(self.size))
+                        .when_some(self.label, |this, label| {
+                            this.child(
+                                div()
+                                    .text_sm()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(label),
+                            )
+                        }),
+                )
+            })
+    }
+}
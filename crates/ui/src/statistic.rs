@@ -0,0 +1,197 @@
+use gpui::{
+    div, prelude::FluentBuilder as _, px, Div, IntoElement, ParentElement as _, RenderOnce,
+    SharedString, Styled, WindowContext,
+};
+
+use crate::{
+    h_flex, input::format_number, skeleton::Skeleton, theme::ActiveTheme as _, v_flex, Icon,
+    IconName, Sizable as _, StyledExt as _,
+};
+
+/// Return true if the delta is good, the decrease is good if `invert` is true, None for no change.
+fn is_good_trend(delta: f64, invert: bool) -> Option<bool> {
+    if delta == 0. {
+        return None;
+    }
+
+    Some((delta > 0.) != invert)
+}
+
+/// A large number with a label, e.g.: the KPI on the dashboard.
+#[derive(IntoElement)]
+pub struct Statistic {
+    base: Div,
+    label: SharedString,
+    value: f64,
+    precision: Option<usize>,
+    thousands_separator: bool,
+    prefix: Option<SharedString>,
+    suffix: Option<SharedString>,
+    delta: Option<f64>,
+    invert_trend: bool,
+    loading: bool,
+    card: bool,
+}
+
+impl Statistic {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            base: v_flex(),
+            label: label.into(),
+            value: 0.,
+            precision: None,
+            thousands_separator: true,
+            prefix: None,
+            suffix: None,
+            delta: None,
+            invert_trend: false,
+            loading: false,
+            card: false,
+        }
+    }
+
+    pub fn value(mut self, value: f64) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Set the number of the decimals, default: as many as the value has.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Set false to not format the value with thousands separators, default: true.
+    pub fn thousands_separator(mut self, thousands_separator: bool) -> Self {
+        self.thousands_separator = thousands_separator;
+        self
+    }
+
+    /// Set the unit before the value, e.g.: "$".
+    pub fn prefix(mut self, prefix: impl Into<SharedString>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Set the unit after the value, e.g.: "ms", "%".
+    pub fn suffix(mut self, suffix: impl Into<SharedString>) -> Self {
+        self.suffix = Some(suffix.into());
+        self
+    }
+
+    /// Show the trend arrow with the change in percent, e.g.: `12.5` for +12.5%.
+    pub fn delta(mut self, delta: f64) -> Self {
+        self.delta = Some(delta);
+        self
+    }
+
+    /// Set true if the decrease is good, e.g.: the cost or the latency, to show it in green.
+    pub fn invert_trend(mut self, invert_trend: bool) -> Self {
+        self.invert_trend = invert_trend;
+        self
+    }
+
+    /// Set true to show the skeleton instead of the value.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Set true to show in a bordered card.
+    pub fn card(mut self, card: bool) -> Self {
+        self.card = card;
+        self
+    }
+}
+
+impl Styled for Statistic {
+    fn style(&mut self) -> &mut gpui::StyleRefinement {
+        self.base.style()
+    }
+}
+
+impl RenderOnce for Statistic {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let value = format_number(self.value, self.precision, self.thousands_separator);
+
+        self.base
+            .gap_1()
+            .when(self.card, |this| {
+                this.p_4()
+                    .rounded_lg()
+                    .border_1()
+                    .border_color(cx.theme().border)
+                    .bg(cx.theme().background)
+            })
+            .child(
+                div()
+                    .text_sm()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(self.label),
+            )
+            .map(|this| {
+                if self.loading {
+                    return this.child(Skeleton::new().w(px(120.)).h_8());
+                }
+
+                this.child(
+                    h_flex()
+                        .items_end()
+                        .gap_1()
+                        .when_some(self.prefix, |this, prefix| {
+                            this.child(div().text_lg().child(prefix))
+                        })
+                        .child(div().text_3xl().font_semibold().child(value))
+                        .when_some(self.suffix, |this, suffix| {
+                            this.child(
+                                div()
+                                    .text_sm()
+                                    .text_color(cx.theme().muted_foreground)
+                                    .child(suffix),
+                            )
+                        }),
+                )
+                .when_some(self.delta, |this, delta| {
+                    let (icon, color) = match is_good_trend(delta, self.invert_trend) {
+                        Some(good) => (
+                            Some(if delta > 0. {
+                                IconName::ArrowUp
+                            } else {
+                                IconName::ArrowDown
+                            }),
+                            if good {
+                                crate::green_500()
+                            } else {
+                                crate::red_500()
+                            },
+                        ),
+                        None => (None, cx.theme().muted_foreground),
+                    };
+
+                    this.child(
+                        h_flex()
+                            .gap_0p5()
+                            .text_sm()
+                            .text_color(color)
+                            .when_some(icon, |this, icon| {
+                                this.child(Icon::new(icon).xsmall().text_color(color))
+                            })
+                            .child(format!("{}%", format_number(delta.abs(), Some(1), true))),
+                    )
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_good_trend;
+
+    #[test]
+    fn test_is_good_trend() {
+        assert_eq!(is_good_trend(12.5, false), Some(true));
+        assert_eq!(is_good_trend(-3., false), Some(false));
+        assert_eq!(is_good_trend(-3., true), Some(true));
+        assert_eq!(is_good_trend(0., true), None);
+    }
+}
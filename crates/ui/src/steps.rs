@@ -0,0 +1,442 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnyView, Axis, ElementId, EventEmitter,
+    InteractiveElement as _, IntoElement, ParentElement as _, Render, RenderOnce, SharedString,
+    StatefulInteractiveElement as _, Styled as _, ViewContext, WindowContext,
+};
+use rust_i18n::t;
+
+use crate::{
+    button::{Button, ButtonStyled as _},
+    h_flex,
+    theme::ActiveTheme as _,
+    v_flex, Disableable as _, Icon, IconName, Sizable as _, StyledExt as _,
+};
+
+/// The status of a step in the [`Steps`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepStatus {
+    Pending,
+    Current,
+    Completed,
+    Error,
+}
+
+/// Return the status of the step at `ix`, the steps before the `current` are completed.
+fn step_status(ix: usize, current: usize, error: bool) -> StepStatus {
+    if ix < current {
+        StepStatus::Completed
+    } else if ix == current {
+        if error {
+            StepStatus::Error
+        } else {
+            StepStatus::Current
+        }
+    } else {
+        StepStatus::Pending
+    }
+}
+
+/// A step of the [`Steps`].
+#[derive(Clone)]
+pub struct StepItem {
+    title: SharedString,
+    description: Option<SharedString>,
+    icon: Option<Icon>,
+}
+
+impl StepItem {
+    pub fn new(title: impl Into<SharedString>) -> Self {
+        Self {
+            title: title.into(),
+            description: None,
+            icon: None,
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set the icon to replace the step number.
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+}
+
+/// A list of the numbered steps to show the progress of a process.
+#[derive(IntoElement)]
+pub struct Steps {
+    id: ElementId,
+    items: Vec<StepItem>,
+    current: usize,
+    error: bool,
+    axis: Axis,
+    on_click: Option<Rc<dyn Fn(&usize, &mut WindowContext)>>,
+}
+
+impl Steps {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            items: Vec::new(),
+            current: 0,
+            error: false,
+            axis: Axis::Horizontal,
+            on_click: None,
+        }
+    }
+
+    pub fn item(mut self, item: StepItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    pub fn items(mut self, items: impl IntoIterator<Item = StepItem>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
+    /// Set the index of the current step, the steps before it are completed.
+    pub fn current(mut self, current: usize) -> Self {
+        self.current = current;
+        self
+    }
+
+    /// Set true to show the current step as error.
+    pub fn error(mut self, error: bool) -> Self {
+        self.error = error;
+        self
+    }
+
+    pub fn vertical(mut self) -> Self {
+        self.axis = Axis::Vertical;
+        self
+    }
+
+    /// Set the handler when a completed step is clicked, with the index of the step.
+    pub fn on_click(mut self, handler: impl Fn(&usize, &mut WindowContext) + 'static) -> Self {
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+
+    fn render_indicator(
+        ix: usize,
+        item: &StepItem,
+        status: StepStatus,
+        cx: &WindowContext,
+    ) -> impl IntoElement {
+        let theme = cx.theme();
+
+        h_flex()
+            .flex_shrink_0()
+            .size_7()
+            .justify_center()
+            .rounded_full()
+            .border_1()
+            .text_sm()
+            .map(|this| match status {
+                StepStatus::Completed => this
+                    .border_color(theme.primary)
+                    .bg(theme.primary)
+                    .text_color(theme.primary_foreground),
+                StepStatus::Current => this.border_color(theme.primary).text_color(theme.primary),
+                StepStatus::Error => this
+                    .border_color(theme.destructive)
+                    .bg(theme.destructive)
+                    .text_color(theme.destructive_foreground),
+                StepStatus::Pending => this
+                    .border_color(theme.border)
+                    .text_color(theme.muted_foreground),
+            })
+            .map(|this| match (status, item.icon.clone()) {
+                (StepStatus::Completed, _) => this.child(Icon::new(IconName::Check).small()),
+                (StepStatus::Error, _) => this.child(Icon::new(IconName::Close).small()),
+                (_, Some(icon)) => this.child(icon.small()),
+                (_, None) => this.child((ix + 1).to_string()),
+            })
+    }
+
+    fn render_label(item: &StepItem, status: StepStatus, cx: &WindowContext) -> impl IntoElement {
+        let theme = cx.theme();
+
+        v_flex()
+            .gap_0p5()
+            .child(
+                div()
+                    .text_sm()
+                    .font_medium()
+                    .map(|this| match status {
+                        StepStatus::Pending => this.text_color(theme.muted_foreground),
+                        StepStatus::Error => this.text_color(theme.destructive),
+                        _ => this.text_color(theme.foreground),
+                    })
+                    .child(item.title.clone()),
+            )
+            .when_some(item.description.clone(), |this, description| {
+                this.child(
+                    div()
+                        .text_xs()
+                        .text_color(theme.muted_foreground)
+                        .child(description),
+                )
+            })
+    }
+}
+
+impl RenderOnce for Steps {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let count = self.items.len();
+        let axis = self.axis;
+        let connector = |completed: bool, cx: &WindowContext| {
+            div()
+                .flex_1()
+                .map(|this| match axis {
+                    Axis::Horizontal => this.h(px(1.)).min_w_6(),
+                    Axis::Vertical => this.w(px(1.)).min_h_6(),
+                })
+                .bg(if completed {
+                    cx.theme().primary
+                } else {
+                    cx.theme().border
+                })
+        };
+
+        div()
+            .id(self.id)
+            .flex()
+            .map(|this| match axis {
+                Axis::Horizontal => this.flex_row().items_center().w_full().gap_3(),
+                Axis::Vertical => this.flex_col(),
+            })
+            .children(self.items.iter().enumerate().map(|(ix, item)| {
+                let status = step_status(ix, self.current, self.error);
+                let is_last = ix + 1 == count;
+                let on_click = self
+                    .on_click
+                    .clone()
+                    .filter(|_| status == StepStatus::Completed);
+
+                let step = h_flex()
+                    .id(ix)
+                    .gap_2()
+                    .when(axis == Axis::Vertical, |this| this.items_start())
+                    .when_some(on_click, |this, on_click| {
+                        this.cursor_pointer()
+                            .on_click(move |_, cx| on_click(&ix, cx))
+                    });
+
+                match axis {
+                    Axis::Horizontal => h_flex()
+                        .gap_3()
+                        .when(!is_last, |this| this.flex_1())
+                        .child(
+                            step.child(Self::render_indicator(ix, item, status, cx))
+                                .child(Self::render_label(item, status, cx)),
+                        )
+                        .when(!is_last, |this| {
+                            this.child(connector(status == StepStatus::Completed, cx))
+                        })
+                        .into_any_element(),
+                    Axis::Vertical => step
+                        .child(
+                            v_flex()
+                                .self_stretch()
+                                .items_center()
+                                .gap_1()
+                                .child(Self::render_indicator(ix, item, status, cx))
+                                .when(!is_last, |this| {
+                                    this.child(connector(status == StepStatus::Completed, cx))
+                                }),
+                        )
+                        .child(
+                            div()
+                                .pt_1()
+                                .when(!is_last, |this| this.pb_5())
+                                .child(Self::render_label(item, status, cx)),
+                        )
+                        .into_any_element(),
+                }
+            }))
+    }
+}
+
+pub enum WizardEvent {
+    /// The current step is changed, with the index of the step.
+    Change(usize),
+    /// The Finish button on the last step is clicked and passed the validation.
+    Finish,
+}
+
+/// A multi-step form with the [`Steps`] on the top and the content of the current step.
+pub struct Wizard {
+    steps: Vec<(StepItem, AnyView)>,
+    current: usize,
+    error: bool,
+    before_next: Option<Rc<dyn Fn(usize, &mut WindowContext) -> bool>>,
+    before_back: Option<Rc<dyn Fn(usize, &mut WindowContext) -> bool>>,
+}
+
+impl EventEmitter<WizardEvent> for Wizard {}
+
+impl Wizard {
+    pub fn new(_: &mut ViewContext<Self>) -> Self {
+        Self {
+            steps: Vec::new(),
+            current: 0,
+            error: false,
+            before_next: None,
+            before_back: None,
+        }
+    }
+
+    /// Add a step with the view of its content.
+    pub fn step(mut self, item: StepItem, content: impl Into<AnyView>) -> Self {
+        self.steps.push((item, content.into()));
+        self
+    }
+
+    /// Set the hook to validate the step before moving to the next step or finish,
+    /// return false to stay on the step and show it as error.
+    pub fn before_next(
+        mut self,
+        hook: impl Fn(usize, &mut WindowContext) -> bool + 'static,
+    ) -> Self {
+        self.before_next = Some(Rc::new(hook));
+        self
+    }
+
+    /// Set the hook to check the step before moving back, return false to stay on the step.
+    pub fn before_back(
+        mut self,
+        hook: impl Fn(usize, &mut WindowContext) -> bool + 'static,
+    ) -> Self {
+        self.before_back = Some(Rc::new(hook));
+        self
+    }
+
+    /// Return the index of the current step.
+    pub fn current(&self) -> usize {
+        self.current
+    }
+
+    /// Set the current step without the validation.
+    pub fn set_current(&mut self, current: usize, cx: &mut ViewContext<Self>) {
+        self.current = current.min(self.steps.len().saturating_sub(1));
+        self.error = false;
+        cx.notify();
+    }
+
+    fn is_last(&self) -> bool {
+        self.current + 1 >= self.steps.len()
+    }
+
+    fn go_to(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if ix == self.current {
+            return;
+        }
+
+        self.set_current(ix, cx);
+        cx.emit(WizardEvent::Change(self.current));
+    }
+
+    pub fn next(&mut self, cx: &mut ViewContext<Self>) {
+        if let Some(before_next) = self.before_next.clone() {
+            if !before_next(self.current, cx) {
+                self.error = true;
+                cx.notify();
+                return;
+            }
+        }
+
+        if self.is_last() {
+            self.error = false;
+            cx.emit(WizardEvent::Finish);
+            cx.notify();
+        } else {
+            self.go_to(self.current + 1, cx);
+        }
+    }
+
+    pub fn back(&mut self, cx: &mut ViewContext<Self>) {
+        if self.current == 0 {
+            return;
+        }
+
+        self.back_to(self.current - 1, cx);
+    }
+
+    /// Move back to a previous step, e.g.: a completed step is clicked, if the `before_back` allows.
+    fn back_to(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if ix >= self.current {
+            return;
+        }
+
+        if let Some(before_back) = self.before_back.clone() {
+            if !before_back(self.current, cx) {
+                return;
+            }
+        }
+
+        self.go_to(ix, cx);
+    }
+}
+
+impl Render for Wizard {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let content = self.steps.get(self.current).map(|(_, view)| view.clone());
+
+        v_flex()
+            .gap_4()
+            .child(
+                Steps::new("wizard-steps")
+                    .items(self.steps.iter().map(|(item, _)| item.clone()))
+                    .current(self.current)
+                    .error(self.error)
+                    .on_click(cx.listener(|this, ix: &usize, cx| this.back_to(*ix, cx))),
+            )
+            .child(div().flex_1().children(content))
+            .child(
+                h_flex()
+                    .justify_end()
+                    .gap_2()
+                    .child(
+                        Button::new("back")
+                            .outline()
+                            .small()
+                            .label(t!("Wizard.Back"))
+                            .disabled(self.current == 0)
+                            .on_click(cx.listener(|this, _, cx| this.back(cx))),
+                    )
+                    .child(
+                        Button::new("next")
+                            .primary()
+                            .small()
+                            .map(|this| {
+                                if self.is_last() {
+                                    this.label(t!("Wizard.Finish"))
+                                } else {
+                                    this.label(t!("Wizard.Next"))
+                                }
+                            })
+                            .on_click(cx.listener(|this, _, cx| this.next(cx))),
+                    ),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{step_status, StepStatus};
+
+    #[test]
+    fn test_step_status() {
+        assert_eq!(step_status(0, 1, false), StepStatus::Completed);
+        assert_eq!(step_status(1, 1, false), StepStatus::Current);
+        assert_eq!(step_status(1, 1, true), StepStatus::Error);
+        assert_eq!(step_status(2, 1, true), StepStatus::Pending);
+    }
+}
@@ -2,9 +2,11 @@ use std::fmt::{self, Display, Formatter};
 
 use crate::{
     scroll::{Scrollable, ScrollbarAxis},
-    theme::ActiveTheme,
+    theme::{ActiveTheme, Breakpoint},
+};
+use gpui::{
+    div, px, Axis, Div, Element, EntityId, FocusHandle, IntoElement, Pixels, Styled, WindowContext,
 };
-use gpui::{div, px, Axis, Div, Element, EntityId, FocusHandle, Pixels, Styled, WindowContext};
 
 /// Returns a `Div` as horizontal flex layout.
 pub fn h_flex() -> Div {
@@ -132,6 +134,70 @@ pub trait StyledExt: Styled + Sized {
 
 impl<E: Styled> StyledExt for E {}
 
+/// Extends the elements to restructure by the window width, e.g.: stack the columns when it's narrow.
+///
+/// The widths of the [`Breakpoint`]s are configured by the `breakpoints` of the theme.
+///
+/// ```ignore
+/// h_flex()
+///     .when_below_breakpoint(Breakpoint::Md, cx, |this| this.flex_col())
+///     .child(sidebar)
+///     .child(content)
+/// ```
+pub trait Responsive: IntoElement + Sized {
+    /// Apply the `f` when the window is at least the `width` wide.
+    fn when_width_at_least(
+        self,
+        width: impl Into<Pixels>,
+        cx: &WindowContext,
+        f: impl FnOnce(Self) -> Self,
+    ) -> Self {
+        if cx.viewport_size().width >= width.into() {
+            f(self)
+        } else {
+            self
+        }
+    }
+
+    /// Apply the `f` when the window is narrower than the `width`.
+    fn when_width_below(
+        self,
+        width: impl Into<Pixels>,
+        cx: &WindowContext,
+        f: impl FnOnce(Self) -> Self,
+    ) -> Self {
+        if cx.viewport_size().width < width.into() {
+            f(self)
+        } else {
+            self
+        }
+    }
+
+    /// Apply the `f` when the window reaches the breakpoint.
+    fn when_breakpoint(
+        self,
+        breakpoint: Breakpoint,
+        cx: &WindowContext,
+        f: impl FnOnce(Self) -> Self,
+    ) -> Self {
+        let width = cx.theme().breakpoints.width(breakpoint);
+        self.when_width_at_least(width, cx, f)
+    }
+
+    /// Apply the `f` when the window is narrower than the breakpoint.
+    fn when_below_breakpoint(
+        self,
+        breakpoint: Breakpoint,
+        cx: &WindowContext,
+        f: impl FnOnce(Self) -> Self,
+    ) -> Self {
+        let width = cx.theme().breakpoints.width(breakpoint);
+        self.when_width_below(width, cx, f)
+    }
+}
+
+impl<E: IntoElement> Responsive for E {}
+
 /// A size for elements.
 #[derive(Clone, Default, Copy, PartialEq, Eq, Debug)]
 pub enum Size {
@@ -185,22 +251,32 @@ pub trait Sizable: Sized {
     }
 }
 
+/// The sizes of the inputs and lists, the spacing is scaled by [`crate::theme::Theme::spacing`].
 #[allow(unused)]
 pub trait StyleSized<T: Styled> {
     fn input_text_size(self, size: Size) -> Self;
-    fn input_size(self, size: Size) -> Self;
-    fn input_pl(self, size: Size) -> Self;
-    fn input_pr(self, size: Size) -> Self;
-    fn input_px(self, size: Size) -> Self;
-    fn input_py(self, size: Size) -> Self;
-    fn input_h(self, size: Size) -> Self;
-    fn list_size(self, size: Size) -> Self;
-    fn list_px(self, size: Size) -> Self;
-    fn list_py(self, size: Size) -> Self;
+    fn input_size(self, size: Size, cx: &WindowContext) -> Self;
+    fn input_pl(self, size: Size, cx: &WindowContext) -> Self;
+    fn input_pr(self, size: Size, cx: &WindowContext) -> Self;
+    fn input_px(self, size: Size, cx: &WindowContext) -> Self;
+    fn input_py(self, size: Size, cx: &WindowContext) -> Self;
+    fn input_h(self, size: Size, cx: &WindowContext) -> Self;
+    fn list_size(self, size: Size, cx: &WindowContext) -> Self;
+    fn list_px(self, size: Size, cx: &WindowContext) -> Self;
+    fn list_py(self, size: Size, cx: &WindowContext) -> Self;
     /// Apply size with the given `Size`.
     fn size_with(self, size: Size) -> Self;
 }
 
+/// Return the horizontal padding of the input.
+fn input_padding_x(size: Size, cx: &WindowContext) -> Pixels {
+    cx.theme().spacing(match size {
+        Size::Large => px(20.),
+        Size::Medium => px(12.),
+        _ => px(8.),
+    })
+}
+
 impl<T: Styled> StyleSized<T> for T {
     fn input_text_size(self, size: Size) -> Self {
         match size {
@@ -212,69 +288,58 @@ impl<T: Styled> StyleSized<T> for T {
         }
     }
 
-    fn input_size(self, size: Size) -> Self {
-        self.input_px(size).input_py(size).input_h(size)
+    fn input_size(self, size: Size, cx: &WindowContext) -> Self {
+        self.input_px(size, cx).input_py(size, cx).input_h(size, cx)
     }
 
-    fn input_pl(self, size: Size) -> Self {
-        match size {
-            Size::Large => self.pl_5(),
-            Size::Medium => self.pl_3(),
-            _ => self.pl_2(),
-        }
+    fn input_pl(self, size: Size, cx: &WindowContext) -> Self {
+        self.pl(input_padding_x(size, cx))
     }
 
-    fn input_pr(self, size: Size) -> Self {
-        match size {
-            Size::Large => self.pr_5(),
-            Size::Medium => self.pr_3(),
-            _ => self.pr_2(),
-        }
+    fn input_pr(self, size: Size, cx: &WindowContext) -> Self {
+        self.pr(input_padding_x(size, cx))
     }
 
-    fn input_px(self, size: Size) -> Self {
-        match size {
-            Size::Large => self.px_5(),
-            Size::Medium => self.px_3(),
-            _ => self.px_2(),
-        }
+    fn input_px(self, size: Size, cx: &WindowContext) -> Self {
+        self.px(input_padding_x(size, cx))
     }
 
-    fn input_py(self, size: Size) -> Self {
-        match size {
-            Size::Large => self.py_5(),
-            Size::Medium => self.py_2(),
-            _ => self.py_1(),
-        }
+    fn input_py(self, size: Size, cx: &WindowContext) -> Self {
+        self.py(cx.theme().spacing(match size {
+            Size::Large => px(20.),
+            Size::Medium => px(8.),
+            _ => px(4.),
+        }))
     }
 
-    fn input_h(self, size: Size) -> Self {
-        match size {
-            Size::Large => self.h_11(),
-            Size::Medium => self.h_8(),
-            _ => self.h(px(26.)),
-        }
+    fn input_h(self, size: Size, cx: &WindowContext) -> Self {
+        self.h(cx.theme().spacing(match size {
+            Size::Large => px(44.),
+            Size::Medium => px(32.),
+            _ => px(26.),
+        }))
         .input_text_size(size)
     }
 
-    fn list_size(self, size: Size) -> Self {
-        self.list_px(size).list_py(size).input_text_size(size)
+    fn list_size(self, size: Size, cx: &WindowContext) -> Self {
+        self.list_px(size, cx)
+            .list_py(size, cx)
+            .input_text_size(size)
     }
 
-    fn list_px(self, size: Size) -> Self {
-        match size {
-            Size::Small => self.px_2(),
-            _ => self.px_3(),
-        }
+    fn list_px(self, size: Size, cx: &WindowContext) -> Self {
+        self.px(cx.theme().spacing(match size {
+            Size::Small => px(8.),
+            _ => px(12.),
+        }))
     }
 
-    fn list_py(self, size: Size) -> Self {
-        match size {
-            Size::Large => self.py_2(),
-            Size::Medium => self.py_1(),
-            Size::Small => self.py_0p5(),
-            _ => self.py_1(),
-        }
+    fn list_py(self, size: Size, cx: &WindowContext) -> Self {
+        self.py(cx.theme().spacing(match size {
+            Size::Large => px(8.),
+            Size::Small => px(2.),
+            _ => px(4.),
+        }))
     }
 
     fn size_with(self, size: Size) -> Self {
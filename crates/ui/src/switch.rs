@@ -1,10 +1,10 @@
 use std::time::Duration;
 
-use crate::{h_flex, theme::ActiveTheme, Disableable, Sizable, Size};
+use crate::{h_flex, indicator::Indicator, theme::ActiveTheme, Disableable, Icon, Sizable, Size};
 use gpui::{
-    div, prelude::FluentBuilder as _, px, Animation, AnimationExt as _, Div, ElementId,
-    InteractiveElement, IntoElement, ParentElement as _, RenderOnce, SharedString, Stateful,
-    Styled as _, WindowContext,
+    div, prelude::FluentBuilder as _, px, Animation, AnimationExt as _, AnyElement, Div, ElementId,
+    Hsla, InteractiveElement, IntoElement, ParentElement as _, Pixels, RenderOnce, SharedString,
+    Stateful, Styled as _, WindowContext,
 };
 
 type OnClick = Box<dyn Fn(&bool, &mut WindowContext) + 'static>;
@@ -20,6 +20,35 @@ impl LabelSide {
     }
 }
 
+/// The content inside the track of the [`Switch`], shown on the opposite side of the thumb.
+enum TrackContent {
+    Label(SharedString),
+    Icon(Icon),
+}
+
+impl TrackContent {
+    fn render(self, color: Hsla, size: Size) -> AnyElement {
+        match self {
+            Self::Label(label) => div()
+                .text_color(color)
+                .text_size(match size {
+                    Size::XSmall | Size::Small => px(8.),
+                    _ => px(10.),
+                })
+                .line_height(px(10.))
+                .child(label)
+                .into_any_element(),
+            Self::Icon(icon) => icon
+                .text_color(color)
+                .with_size(match size {
+                    Size::XSmall | Size::Small => px(8.),
+                    _ => px(10.),
+                })
+                .into_any_element(),
+        }
+    }
+}
+
 #[derive(IntoElement)]
 pub struct Switch {
     id: ElementId,
@@ -28,6 +57,9 @@ pub struct Switch {
     disabled: bool,
     label: Option<SharedString>,
     label_side: LabelSide,
+    on_content: Option<TrackContent>,
+    off_content: Option<TrackContent>,
+    loading: bool,
     on_click: Option<OnClick>,
     size: Size,
 }
@@ -43,6 +75,9 @@ impl Switch {
             label: None,
             on_click: None,
             label_side: LabelSide::Right,
+            on_content: None,
+            off_content: None,
+            loading: false,
             size: Size::Medium,
         }
     }
@@ -66,6 +101,48 @@ impl Switch {
         self.label_side = label_side;
         self
     }
+
+    /// Set the labels inside the track when on and off, e.g.: "ON" and "OFF".
+    pub fn track_labels(
+        mut self,
+        on: impl Into<SharedString>,
+        off: impl Into<SharedString>,
+    ) -> Self {
+        self.on_content = Some(TrackContent::Label(on.into()));
+        self.off_content = Some(TrackContent::Label(off.into()));
+        self
+    }
+
+    /// Set the icons inside the track when on and off.
+    pub fn track_icons(mut self, on: impl Into<Icon>, off: impl Into<Icon>) -> Self {
+        self.on_content = Some(TrackContent::Icon(on.into()));
+        self.off_content = Some(TrackContent::Icon(off.into()));
+        self
+    }
+
+    /// Set true to show a spinner in the thumb and ignore the clicks,
+    /// e.g.: while the new state is being saved by an async handler.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+}
+
+/// Return the width and height of the track, and the size of the thumb.
+fn track_size(size: Size, has_content: bool) -> (Pixels, Pixels, Pixels) {
+    let (width, height, thumb) = match size {
+        Size::XSmall => (px(24.), px(14.), px(10.)),
+        Size::Small => (px(28.), px(16.), px(12.)),
+        Size::Large => (px(44.), px(24.), px(20.)),
+        _ => (px(36.), px(20.), px(16.)),
+    };
+
+    // Make room for the content beside the thumb.
+    if has_content {
+        (width + height / 2., height, thumb)
+    } else {
+        (width, height, thumb)
+    }
 }
 
 impl Sizable for Switch {
@@ -93,17 +170,22 @@ impl RenderOnce for Switch {
         };
 
         let (bg, toggle_bg) = match self.disabled {
-            true => (bg.opacity(0.3), toggle_bg.opacity(0.8)),
+            true => (
+                theme.translucent(bg, 0.3),
+                theme.translucent(toggle_bg, 0.8),
+            ),
             false => (bg, toggle_bg),
         };
 
-        let (bg_width, bg_height) = match self.size {
-            Size::XSmall | Size::Small => (px(28.), px(16.)),
-            _ => (px(36.), px(20.)),
-        };
-        let bar_width = match self.size {
-            Size::XSmall | Size::Small => px(12.),
-            _ => px(16.),
+        let has_content = self.on_content.is_some() || self.off_content.is_some();
+        let (bg_width, bg_height, bar_width) = track_size(self.size, has_content);
+        let track_content = match checked {
+            true => self
+                .on_content
+                .map(|content| content.render(theme.primary_foreground, self.size)),
+            false => self
+                .off_content
+                .map(|content| content.render(theme.muted_foreground, self.size)),
         };
         let inset = px(2.);
 
@@ -123,13 +205,36 @@ impl RenderOnce for Switch {
                     .border(inset)
                     .border_color(theme.transparent)
                     .bg(bg)
-                    .when(!self.disabled, |this| this.cursor_pointer())
+                    .relative()
+                    .when(!self.disabled && !self.loading, |this| {
+                        this.cursor_pointer()
+                    })
+                    .when_some(track_content, |this, content| {
+                        // The content is on the opposite side of the thumb.
+                        this.child(
+                            h_flex()
+                                .absolute()
+                                .top_0()
+                                .bottom_0()
+                                .map(|this| match checked {
+                                    true => this.left(inset * 2),
+                                    false => this.right(inset * 2),
+                                })
+                                .child(content),
+                        )
+                    })
                     .child(
                         // Switch Toggle
-                        div()
+                        h_flex()
+                            .justify_center()
                             .rounded_full()
                             .bg(toggle_bg)
                             .size(bar_width)
+                            .when(self.loading, |this| {
+                                this.child(
+                                    Indicator::new().with_size(bar_width - inset * 2).color(bg),
+                                )
+                            })
                             .with_animation(
                                 ElementId::NamedInteger("move".into(), checked as usize),
                                 Animation::new(Duration::from_secs_f64(0.15)),
@@ -152,7 +257,7 @@ impl RenderOnce for Switch {
                 }))
             })
             .when_some(
-                self.on_click.filter(|_| !self.disabled),
+                self.on_click.filter(|_| !self.disabled && !self.loading),
                 |this, on_click| {
                     this.on_mouse_down(gpui::MouseButton::Left, move |_, cx| {
                         cx.stop_propagation();
@@ -1,5 +1,7 @@
 mod tab;
 mod tab_bar;
+mod tabs;
 
 pub use tab::*;
 pub use tab_bar::*;
+pub use tabs::*;
@@ -1,9 +1,12 @@
+use std::rc::Rc;
+
+use crate::button::{Button, ButtonStyled as _};
 use crate::theme::ActiveTheme;
-use crate::Selectable;
+use crate::{IconName, Selectable, Sizable as _};
 use gpui::prelude::FluentBuilder as _;
 use gpui::{
-    div, px, AnyElement, Div, ElementId, InteractiveElement, IntoElement, ParentElement as _,
-    RenderOnce, Stateful, StatefulInteractiveElement, Styled, WindowContext,
+    div, px, AnyElement, ClickEvent, Div, ElementId, InteractiveElement, IntoElement,
+    ParentElement as _, RenderOnce, Stateful, StatefulInteractiveElement, Styled, WindowContext,
 };
 
 #[derive(IntoElement)]
@@ -14,6 +17,8 @@ pub struct Tab {
     suffix: Option<AnyElement>,
     disabled: bool,
     selected: bool,
+    closable: bool,
+    on_close: Option<Rc<dyn Fn(&ClickEvent, &mut WindowContext)>>,
 }
 
 impl Tab {
@@ -25,6 +30,8 @@ impl Tab {
             selected: false,
             prefix: None,
             suffix: None,
+            closable: false,
+            on_close: None,
         }
     }
 
@@ -39,6 +46,22 @@ impl Tab {
         self.suffix = Some(suffix.into());
         self
     }
+
+    /// Set true to show a close button, it is visible when the tab is selected or hovered.
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Set the callback when the close button is clicked, this also makes the tab closable.
+    pub fn on_close(
+        mut self,
+        on_close: impl Fn(&ClickEvent, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.closable = true;
+        self.on_close = Some(Rc::new(on_close));
+        self
+    }
 }
 
 impl Selectable for Tab {
@@ -66,7 +89,10 @@ impl RenderOnce for Tab {
     fn render(self, cx: &mut WindowContext) -> impl IntoElement {
         let (text_color, bg_color) = match (self.selected, self.disabled) {
             (true, _) => (cx.theme().tab_active_foreground, cx.theme().tab_active),
-            (false, true) => (cx.theme().tab_foreground.opacity(0.5), cx.theme().tab),
+            (false, true) => (
+                cx.theme().translucent(cx.theme().tab_foreground, 0.5),
+                cx.theme().tab,
+            ),
             (false, false) => (cx.theme().muted_foreground, cx.theme().tab),
         };
 
@@ -88,5 +114,23 @@ impl RenderOnce for Tab {
             })
             .child(div().text_ellipsis().child(self.label))
             .when_some(self.suffix, |this, suffix| this.child(suffix))
+            .when(self.closable, |this| {
+                let on_close = self.on_close;
+                this.group("tab").child(
+                    div()
+                        .when(!self.selected, |this| {
+                            this.invisible().group_hover("tab", |this| this.visible())
+                        })
+                        .child(
+                            Button::new("close")
+                                .icon(IconName::Close)
+                                .xsmall()
+                                .ghost()
+                                .when_some(on_close, |this, on_close| {
+                                    this.on_click(move |event, cx| on_close(event, cx))
+                                }),
+                        ),
+                )
+            })
     }
 }
@@ -0,0 +1,368 @@
+use gpui::{
+    actions, div, impl_actions, prelude::FluentBuilder as _, AnchorCorner, AnyView, AppContext,
+    EntityId, EventEmitter, FocusHandle, FocusableView, InteractiveElement as _, IntoElement,
+    KeyBinding, ParentElement, Render, ScrollHandle, SharedString, StatefulInteractiveElement as _,
+    Styled, ViewContext, VisualContext as _,
+};
+use serde::Deserialize;
+
+use crate::{
+    button::{Button, ButtonStyled as _},
+    h_flex,
+    list::moved_index,
+    popup_menu::PopupMenuExt as _,
+    theme::ActiveTheme as _,
+    v_flex, IconName, Selectable as _, Sizable as _,
+};
+
+use super::{Tab, TabBar};
+
+actions!(tabs, [NextTab, PrevTab]);
+
+/// Activate the tab at the given index.
+#[derive(Clone, PartialEq, Deserialize)]
+pub struct ActivateTab(pub usize);
+
+impl_actions!(tabs, [ActivateTab]);
+
+const CONTEXT: &str = "Tabs";
+
+pub fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("ctrl-tab", NextTab, Some(CONTEXT)),
+        KeyBinding::new("ctrl-shift-tab", PrevTab, Some(CONTEXT)),
+    ]);
+
+    #[cfg(target_os = "macos")]
+    let modifier = "cmd";
+    #[cfg(not(target_os = "macos"))]
+    let modifier = "ctrl";
+    cx.bind_keys((1..=9).map(|n| {
+        KeyBinding::new(
+            &format!("{}-{}", modifier, n),
+            ActivateTab(n - 1),
+            Some(CONTEXT),
+        )
+    }));
+}
+
+/// A tab in the [`Tabs`].
+#[derive(Clone)]
+pub struct TabItem {
+    label: SharedString,
+    closable: bool,
+    content: Option<AnyView>,
+}
+
+impl TabItem {
+    pub fn new(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: label.into(),
+            closable: false,
+            content: None,
+        }
+    }
+
+    /// Set true to show a close button on the tab, default is `false`.
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Set the content to show when the tab is active.
+    pub fn content(mut self, content: impl Into<AnyView>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    pub fn label(&self) -> &SharedString {
+        &self.label
+    }
+}
+
+pub enum TabsEvent {
+    /// The active tab is changed to the index.
+    Change(usize),
+    /// The tab at the index is closed and removed.
+    Close(usize),
+    /// The tab is moved from an index to another by dragging.
+    Move { from: usize, to: usize },
+}
+
+#[derive(Clone)]
+struct DragTab {
+    tabs_id: EntityId,
+    ix: usize,
+    label: SharedString,
+}
+
+impl Render for DragTab {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        div()
+            .py_1()
+            .px_3()
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded_md()
+            .text_sm()
+            .text_color(cx.theme().tab_foreground)
+            .bg(cx.theme().tab_active)
+            .opacity(cx.theme().element_opacity(0.75))
+            .child(self.label.clone())
+    }
+}
+
+/// A stateful tabs with the active tab, closable tabs, drag to reorder and keyboard switching.
+///
+/// A menu to list all the tabs is shown when the tabs overflow the width.
+pub struct Tabs {
+    focus_handle: FocusHandle,
+    items: Vec<TabItem>,
+    active_ix: usize,
+    scroll_handle: ScrollHandle,
+}
+
+impl Tabs {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            items: Vec::new(),
+            active_ix: 0,
+            scroll_handle: ScrollHandle::new(),
+        }
+    }
+
+    /// Set the tabs.
+    pub fn items(mut self, items: impl IntoIterator<Item = TabItem>) -> Self {
+        self.items = items.into_iter().collect();
+        self
+    }
+
+    pub fn active_ix(&self) -> usize {
+        self.active_ix
+    }
+
+    pub fn tab_items(&self) -> &[TabItem] {
+        &self.items
+    }
+
+    /// Set the active tab, the index out of range is ignored.
+    pub fn set_active_ix(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if ix >= self.items.len() {
+            return;
+        }
+
+        if ix != self.active_ix {
+            self.active_ix = ix;
+            cx.emit(TabsEvent::Change(ix));
+        }
+        self.scroll_handle.scroll_to_item(ix);
+        cx.notify();
+    }
+
+    /// Add a tab to the end and active it.
+    pub fn add_tab(&mut self, item: TabItem, cx: &mut ViewContext<Self>) {
+        self.items.push(item);
+        self.set_active_ix(self.items.len() - 1, cx);
+    }
+
+    /// Close the tab at the index.
+    pub fn close_tab(&mut self, ix: usize, cx: &mut ViewContext<Self>) {
+        if ix >= self.items.len() {
+            return;
+        }
+
+        self.items.remove(ix);
+        let active_ix = closed_index(self.active_ix, ix);
+        cx.emit(TabsEvent::Close(ix));
+        if active_ix != self.active_ix || ix == self.active_ix {
+            self.active_ix = active_ix;
+            cx.emit(TabsEvent::Change(active_ix));
+        }
+        cx.notify();
+    }
+
+    /// Move the tab from an index to another, the active tab is kept.
+    pub fn move_tab(&mut self, from: usize, to: usize, cx: &mut ViewContext<Self>) {
+        if from == to || from >= self.items.len() || to >= self.items.len() {
+            return;
+        }
+
+        let item = self.items.remove(from);
+        self.items.insert(to, item);
+        self.active_ix = moved_index(self.active_ix, from, to);
+        cx.emit(TabsEvent::Move { from, to });
+        cx.notify();
+    }
+
+    fn next_tab(&mut self, _: &NextTab, cx: &mut ViewContext<Self>) {
+        if self.items.is_empty() {
+            return;
+        }
+        self.set_active_ix((self.active_ix + 1) % self.items.len(), cx);
+    }
+
+    fn prev_tab(&mut self, _: &PrevTab, cx: &mut ViewContext<Self>) {
+        if self.items.is_empty() {
+            return;
+        }
+        let len = self.items.len();
+        self.set_active_ix((self.active_ix + len - 1) % len, cx);
+    }
+
+    fn activate_tab(&mut self, action: &ActivateTab, cx: &mut ViewContext<Self>) {
+        self.set_active_ix(action.0, cx);
+    }
+
+    /// Return true if the tabs are wider than the tab bar, the bounds are known after the first paint.
+    fn is_overflow(&self) -> bool {
+        let bounds = self.scroll_handle.bounds();
+        let first = self.scroll_handle.bounds_for_item(0);
+        let last = self
+            .scroll_handle
+            .bounds_for_item(self.items.len().saturating_sub(1));
+
+        match (first, last) {
+            (Some(first), Some(last)) => {
+                first.left() < bounds.left() || last.right() > bounds.right()
+            }
+            _ => false,
+        }
+    }
+
+    fn render_overflow_menu(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let labels = self
+            .items
+            .iter()
+            .map(|item| item.label.clone())
+            .collect::<Vec<_>>();
+        let active_ix = self.active_ix;
+        let focus_handle = self.focus_handle.clone();
+
+        h_flex()
+            .h_full()
+            .px_1()
+            .border_l_1()
+            .border_b_1()
+            .border_color(cx.theme().border)
+            .bg(cx.theme().tab_bar)
+            .child(
+                Button::new("overflow-menu")
+                    .icon(IconName::ChevronDown)
+                    .xsmall()
+                    .ghost()
+                    .popup_menu(move |menu, _| {
+                        labels.iter().enumerate().fold(
+                            menu.track_focus(&focus_handle),
+                            |menu, (ix, label)| {
+                                menu.menu_with_check(
+                                    label.clone(),
+                                    ix == active_ix,
+                                    Box::new(ActivateTab(ix)),
+                                )
+                            },
+                        )
+                    })
+                    .anchor(AnchorCorner::TopRight),
+            )
+    }
+}
+
+/// Return the index of the active tab after the tab at `closed_ix` is removed.
+fn closed_index(active_ix: usize, closed_ix: usize) -> usize {
+    if closed_ix < active_ix {
+        active_ix - 1
+    } else if closed_ix == active_ix {
+        active_ix.saturating_sub(1)
+    } else {
+        active_ix
+    }
+}
+
+impl EventEmitter<TabsEvent> for Tabs {}
+
+impl FocusableView for Tabs {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for Tabs {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let tabs_id = cx.view().entity_id();
+        let content = self
+            .items
+            .get(self.active_ix)
+            .and_then(|item| item.content.clone());
+
+        v_flex()
+            .key_context(CONTEXT)
+            .track_focus(&self.focus_handle)
+            .on_action(cx.listener(Self::next_tab))
+            .on_action(cx.listener(Self::prev_tab))
+            .on_action(cx.listener(Self::activate_tab))
+            .size_full()
+            .child(
+                TabBar::new("tabs")
+                    .track_scroll(self.scroll_handle.clone())
+                    .children(self.items.iter().enumerate().map(|(ix, item)| {
+                        Tab::new(("tab", ix), item.label.clone())
+                            .selected(ix == self.active_ix)
+                            .closable(item.closable)
+                            .when(item.closable, |this| {
+                                this.on_close(cx.listener(move |this, _, cx| {
+                                    this.close_tab(ix, cx);
+                                }))
+                            })
+                            .on_click(cx.listener(move |this, _, cx| {
+                                cx.focus(&this.focus_handle);
+                                this.set_active_ix(ix, cx);
+                            }))
+                            .on_drag(
+                                DragTab {
+                                    tabs_id,
+                                    ix,
+                                    label: item.label.clone(),
+                                },
+                                |drag, cx| {
+                                    cx.stop_propagation();
+                                    cx.new_view(|_| drag.clone())
+                                },
+                            )
+                            .drag_over::<DragTab>(|this, _, cx| {
+                                this.border_l_2().border_color(cx.theme().drag_border)
+                            })
+                            .on_drop(cx.listener(move |this, drag: &DragTab, cx| {
+                                if drag.tabs_id == tabs_id {
+                                    this.move_tab(drag.ix, ix, cx);
+                                }
+                            }))
+                    }))
+                    .when(self.is_overflow(), |this| {
+                        this.suffix(self.render_overflow_menu(cx))
+                    }),
+            )
+            .when_some(content, |this, content| {
+                this.child(
+                    div()
+                        .id("tab-content")
+                        .flex_1()
+                        .overflow_hidden()
+                        .child(content),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::closed_index;
+
+    #[test]
+    fn test_closed_index() {
+        assert_eq!(closed_index(2, 0), 1);
+        assert_eq!(closed_index(2, 2), 1);
+        assert_eq!(closed_index(0, 0), 0);
+        assert_eq!(closed_index(2, 3), 2);
+    }
+}
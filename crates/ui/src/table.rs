@@ -1,16 +1,30 @@
-use std::{cell::Cell, ops::Range, rc::Rc};
+use std::{
+    cell::{Cell, RefCell},
+    collections::{BTreeMap, BTreeSet, HashMap},
+    ops::Range,
+    rc::Rc,
+};
+
+use serde::{Deserialize, Serialize};
 
 use crate::{
+    checkbox::Checkbox,
+    context_menu::ContextMenuExt,
+    dropdown::{Dropdown, DropdownEvent},
     h_flex,
-    scroll::{ScrollableAxis, ScrollableMask, Scrollbar, ScrollbarState},
-    theme::ActiveTheme,
+    indicator::Indicator,
+    input::{InputEvent, NumberInput, NumberInputEvent, TextInput},
+    scroll::{ScrollHandleOffsetable, ScrollableAxis, ScrollableMask, Scrollbar, ScrollbarState},
+    theme::{ActiveTheme, ComponentTheme, Theme},
     v_flex, Icon, IconName, Sizable, Size, StyledExt,
 };
 use gpui::{
-    actions, canvas, div, prelude::FluentBuilder, px, uniform_list, AppContext, Bounds, Div,
-    DragMoveEvent, Entity, EntityId, EventEmitter, FocusHandle, FocusableView, InteractiveElement,
-    IntoElement, KeyBinding, MouseButton, ParentElement, Pixels, Point, Render, ScrollHandle,
-    SharedString, StatefulInteractiveElement as _, Styled, UniformListScrollHandle, ViewContext,
+    actions, canvas, div, impl_actions, list, point, prelude::FluentBuilder, px, size,
+    uniform_list, AnyElement, AnyView, AppContext, Bounds, ClickEvent, ClipboardItem, Div,
+    DragMoveEvent, ElementId, Entity, EntityId, EventEmitter, FocusHandle, FocusableView, Hsla,
+    InteractiveElement, IntoElement, KeyBinding, ListAlignment, ListOffset, ListState, Modifiers,
+    MouseButton, MouseDownEvent, ParentElement, Pixels, Point, Render, ScrollHandle, SharedString,
+    Stateful, StatefulInteractiveElement as _, Styled, Subscription, View, ViewContext,
     VisualContext as _, WindowContext,
 };
 
@@ -20,17 +34,27 @@ actions!(
         Cancel,
         SelectPrev,
         SelectNext,
+        SelectToPrev,
+        SelectToNext,
         SelectPrevColumn,
         SelectNextColumn
     ]
 );
 
+/// Show or hide the column at the given index.
+#[derive(Clone, PartialEq, Deserialize)]
+struct ToggleColumn(usize);
+
+impl_actions!(table, [ToggleColumn]);
+
 pub fn init(cx: &mut AppContext) {
     let context = Some("Table");
     cx.bind_keys([
         KeyBinding::new("escape", Cancel, context),
         KeyBinding::new("up", SelectPrev, context),
         KeyBinding::new("down", SelectNext, context),
+        KeyBinding::new("shift-up", SelectToPrev, context),
+        KeyBinding::new("shift-down", SelectToNext, context),
         KeyBinding::new("left", SelectPrevColumn, context),
         KeyBinding::new("right", SelectNextColumn, context),
     ]);
@@ -41,6 +65,8 @@ struct ColGroup {
     width: Option<Pixels>,
     bounds: Bounds<Pixels>,
     sort: Option<ColSort>,
+    fixed: Option<ColFixed>,
+    hidden: bool,
 }
 
 #[derive(Clone)]
@@ -61,14 +87,82 @@ pub enum ColSort {
     Descending,
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ColFixed {
+    /// Pin the column to the left edge of the table.
+    Left,
+    /// Pin the column to the right edge of the table.
+    Right,
+}
+
+/// The editor to edit a table cell by double click.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CellEditor {
+    /// Edit the cell with a text input.
+    Input,
+    /// Edit the cell with a number input.
+    Number,
+    /// Select the cell value from the options with a dropdown.
+    Dropdown(Vec<SharedString>),
+}
+
+enum CellEditorView {
+    Input(View<TextInput>),
+    Number(View<NumberInput>),
+    Dropdown(View<Dropdown<Vec<SharedString>>>),
+}
+
+impl CellEditorView {
+    fn value(&self, cx: &AppContext) -> SharedString {
+        match self {
+            Self::Input(input) => input.read(cx).text(),
+            Self::Number(input) => input
+                .read(cx)
+                .value(cx)
+                .map(|value| value.to_string().into())
+                .unwrap_or_default(),
+            Self::Dropdown(dropdown) => dropdown
+                .read(cx)
+                .selected_value()
+                .cloned()
+                .unwrap_or_default(),
+        }
+    }
+
+    fn focus_handle(&self, cx: &AppContext) -> FocusHandle {
+        match self {
+            Self::Input(input) => input.focus_handle(cx),
+            Self::Number(input) => input.focus_handle(cx),
+            Self::Dropdown(dropdown) => dropdown.focus_handle(cx),
+        }
+    }
+
+    fn view(&self) -> AnyView {
+        match self {
+            Self::Input(input) => input.clone().into(),
+            Self::Number(input) => input.clone().into(),
+            Self::Dropdown(dropdown) => dropdown.clone().into(),
+        }
+    }
+}
+
+/// The cell that is being edited.
+struct EditingCell {
+    row_ix: usize,
+    col_ix: usize,
+    editor: CellEditorView,
+    _subscription: Subscription,
+}
+
 impl Render for DragCol {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let table = cx.theme().component::<TableTheme>();
         div()
             .px_4()
             .py_1()
-            .bg(cx.theme().table_head)
+            .bg(table.head)
             .border_1()
-            .border_color(cx.theme().border)
+            .border_color(table.border)
             .shadow_md()
             .when_some(self.width, |this, width| this.w(width))
             .min_w(px(100.))
@@ -77,6 +171,14 @@ impl Render for DragCol {
     }
 }
 
+/// The widths of the table columns, it can be serialized to persist the user-adjusted widths
+/// and restored by [`Table::set_column_layout`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ColumnLayout {
+    /// The widths of the columns, `None` to use the auto width.
+    pub widths: Vec<Option<Pixels>>,
+}
+
 #[derive(Clone, Render)]
 pub struct ResizeCol(pub (EntityId, usize));
 
@@ -91,8 +193,132 @@ pub enum TableEvent {
     SelectRow(usize),
     SelectCol(usize),
     ColWidthsChanged(Vec<Option<Pixels>>),
+    /// The sorting has been changed, the sorted columns in the order of priority.
+    Sort(Vec<(usize, ColSort)>),
+    /// The column has been moved from the first index to the second index by dragging.
+    MoveCol(usize, usize),
+    /// The selected rows have been changed, the row indices are in ascending order.
+    SelectionChanged(Vec<usize>),
 }
 
+/// The width of the checkbox column in multiple selection mode.
+const CHECKBOX_COL_WIDTH: Pixels = px(36.);
+/// The width of the chevron column of the expandable rows.
+const EXPAND_COL_WIDTH: Pixels = px(28.);
+/// The indentation of each depth level in the tree table.
+const TREE_INDENT: Pixels = px(16.);
+
+/// The heights of the rows to convert between the pixel offset and the row offset,
+/// all rows have the same height, and the expanded rows have an extra detail height.
+#[derive(Debug, Default)]
+struct RowHeights {
+    row_height: Pixels,
+    /// The measured detail heights of the expanded rows.
+    details: BTreeMap<usize, Pixels>,
+}
+
+impl RowHeights {
+    /// Returns the top offset of the row, it is also the total height of the rows before it.
+    fn row_top(&self, row_ix: usize) -> Pixels {
+        self.details
+            .range(..row_ix)
+            .fold(self.row_height * row_ix as f32, |top, (_, height)| {
+                top + *height
+            })
+    }
+
+    /// Returns the row at the top offset, and the offset in the row.
+    fn row_at(&self, top: Pixels) -> (usize, Pixels) {
+        if self.row_height <= px(0.) {
+            return (0, px(0.));
+        }
+
+        let top = top.max(px(0.));
+        // The first row after the last passed detail, and its top offset.
+        let mut start_ix = 0;
+        let mut start_top = px(0.);
+        for (&row_ix, &height) in &self.details {
+            let detail_top = start_top + self.row_height * (row_ix + 1 - start_ix) as f32;
+            if top < detail_top {
+                break;
+            }
+            if top < detail_top + height {
+                // The detail belongs to the expanded row.
+                return (row_ix, top - detail_top + self.row_height);
+            }
+
+            start_ix = row_ix + 1;
+            start_top = detail_top + height;
+        }
+
+        let rows = ((top - start_top) / self.row_height).floor() as usize;
+        (
+            start_ix + rows,
+            top - start_top - self.row_height * rows as f32,
+        )
+    }
+}
+
+/// The vertical scroll handle of the table rows, the rows are rendered by a [`ListState`]
+/// to support the variable heights of the expanded rows.
+#[derive(Clone)]
+struct RowsScrollHandle {
+    list_state: ListState,
+    heights: Rc<RefCell<RowHeights>>,
+}
+
+impl RowsScrollHandle {
+    fn scroll_to_item(&self, ix: usize) {
+        self.list_state.scroll_to_reveal_item(ix);
+    }
+}
+
+impl ScrollHandleOffsetable for RowsScrollHandle {
+    fn offset(&self) -> Point<Pixels> {
+        let scroll_top = self.list_state.logical_scroll_top();
+        let top = self.heights.borrow().row_top(scroll_top.item_ix) + scroll_top.offset_in_item;
+        point(px(0.), -top)
+    }
+
+    fn set_offset(&self, offset: Point<Pixels>) {
+        let (item_ix, offset_in_item) = self.heights.borrow().row_at(-offset.y);
+        let item_ix = item_ix.min(self.list_state.item_count().saturating_sub(1));
+        self.list_state.scroll_to(ListOffset {
+            item_ix,
+            offset_in_item,
+        });
+    }
+}
+
+/// The style tokens of the [`Table`], override them by [`Theme::override_component`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TableTheme {
+    pub background: Hsla,
+    pub head: Hsla,
+    /// The background of the even rows when the table is striped.
+    pub even: Hsla,
+    pub hover: Hsla,
+    pub active: Hsla,
+    pub border: Hsla,
+}
+
+impl ComponentTheme for TableTheme {
+    fn from_theme(theme: &Theme) -> Self {
+        Self {
+            background: theme.table,
+            head: theme.table_head,
+            even: theme.table_even,
+            hover: theme.table_hover,
+            active: theme.table_active,
+            border: theme.border,
+        }
+    }
+}
+
+/// A table view with a sticky header row.
+///
+/// The rows are virtualized, only the visible rows are rendered, so the table can display
+/// large datasets. The data is supplied by a [`TableDelegate`].
 pub struct Table<D: TableDelegate> {
     focus_handle: FocusHandle,
     delegate: D,
@@ -102,8 +328,14 @@ pub struct Table<D: TableDelegate> {
     head_content_bounds: Bounds<Pixels>,
 
     col_groups: Vec<ColGroup>,
-
-    vertical_scroll_handle: UniformListScrollHandle,
+    /// The indices of the sorted columns, in the order of priority.
+    sorted_cols: Vec<usize>,
+
+    vertical_scroll_handle: RowsScrollHandle,
+    /// The bounds of the table body.
+    body_bounds: Bounds<Pixels>,
+    /// The range of the rows that are rendered in the last frame.
+    visible_range: Range<usize>,
     scrollbar_state: Rc<Cell<ScrollbarState>>,
     horizontal_scroll_handle: ScrollHandle,
     horizontal_scrollbar_state: Rc<Cell<ScrollbarState>>,
@@ -111,9 +343,20 @@ pub struct Table<D: TableDelegate> {
     selection_state: SelectionState,
     selected_row: Option<usize>,
     selected_col: Option<usize>,
+    /// The selected row indices in ascending order.
+    selected_rows: Vec<usize>,
+    /// The row to start the range selection with Shift.
+    selection_anchor: Option<usize>,
+    multi_select: bool,
+    expandable: bool,
+    expanded_rows: BTreeSet<usize>,
+    tree: bool,
 
     /// The column index that is being resized.
     resizing_col: Option<usize>,
+    /// The column index to fit the width to its content, and the measured width.
+    autofit_col: Option<(usize, Pixels)>,
+    editing_cell: Option<EditingCell>,
 
     /// Set stripe style of the table.
     stripe: bool,
@@ -121,6 +364,8 @@ pub struct Table<D: TableDelegate> {
     border: bool,
     /// The cell size of the table.
     size: Size,
+    /// The style tokens resolved in the last render, for the rows rendered by the list.
+    theme: TableTheme,
 }
 
 #[allow(unused)]
@@ -138,6 +383,16 @@ pub trait TableDelegate: Sized + 'static {
         true
     }
 
+    /// Returns the minimum width of the column at the given index when resizing. Default: 10px
+    fn col_min_width(&self, col_ix: usize) -> Pixels {
+        px(10.)
+    }
+
+    /// Returns the maximum width of the column at the given index when resizing. Default: 1200px
+    fn col_max_width(&self, col_ix: usize) -> Pixels {
+        px(1200.)
+    }
+
     /// Returns whether the column at the given index can be selected. Default: false
     fn can_select_col(&self, col_ix: usize) -> bool {
         false
@@ -156,9 +411,32 @@ pub trait TableDelegate: Sized + 'static {
         None
     }
 
+    /// Return the edge to pin the column at the given index, the pinned columns stay visible
+    /// while the other columns scroll horizontally.
+    ///
+    /// This is only called when the table initializes.
+    fn col_fixed(&self, col_ix: usize) -> Option<ColFixed> {
+        None
+    }
+
     /// Perform sort on the column at the given index.
+    ///
+    /// The `sort` is `ColSort::Default` when the sorting of the column is cleared.
     fn perform_sort(&mut self, col_ix: usize, sort: ColSort, cx: &mut ViewContext<Table<Self>>) {}
 
+    /// Perform sort on multiple columns, when the user Shift-clicks the column headers.
+    ///
+    /// The `sorts` are in the order of priority, default to sort by the last sorted column only.
+    fn perform_multi_sort(
+        &mut self,
+        sorts: &[(usize, ColSort)],
+        cx: &mut ViewContext<Table<Self>>,
+    ) {
+        if let Some(&(col_ix, sort)) = sorts.last() {
+            self.perform_sort(col_ix, sort, cx);
+        }
+    }
+
     /// Render the header cell at the given column index, default to the column name.
     fn render_th(&self, col_ix: usize, cx: &mut ViewContext<Table<Self>>) -> impl IntoElement {
         div().size_full().child(self.col_name(col_ix))
@@ -177,6 +455,85 @@ pub trait TableDelegate: Sized + 'static {
         cx: &mut ViewContext<Table<Self>>,
     ) -> impl IntoElement;
 
+    /// Return the editor to edit the cell by double click, `None` for a read-only cell.
+    ///
+    /// Default: None
+    fn cell_editor(&self, row_ix: usize, col_ix: usize) -> Option<CellEditor> {
+        None
+    }
+
+    /// Return the text value of the cell, it is used to initialize the editor
+    /// and to export the table, see [`Table::export_csv`].
+    fn cell_value(&self, row_ix: usize, col_ix: usize) -> SharedString {
+        SharedString::default()
+    }
+
+    /// Called when the editing of the cell is committed by Enter or losing focus,
+    /// with the value of the editor.
+    fn on_cell_edited(
+        &mut self,
+        row_ix: usize,
+        col_ix: usize,
+        value: SharedString,
+        cx: &mut ViewContext<Table<Self>>,
+    ) {
+    }
+
+    /// Return true if the row can be expanded to show the detail, see [`Table::expandable`].
+    ///
+    /// Default: true
+    fn can_expand_row(&self, row_ix: usize) -> bool {
+        true
+    }
+
+    /// Return the id of the row at the given index, to keep the rows expanded after sorting.
+    ///
+    /// Default: None, the expanded rows are collapsed when sorting.
+    fn row_id(&self, row_ix: usize) -> Option<ElementId> {
+        None
+    }
+
+    /// Render the detail of the expanded row below the row, the detail can be any height.
+    fn render_row_detail(
+        &self,
+        row_ix: usize,
+        cx: &mut ViewContext<Table<Self>>,
+    ) -> impl IntoElement {
+        div()
+    }
+
+    /// Return the depth of the row in the tree table, see [`Table::tree`].
+    ///
+    /// Default: 0
+    fn row_depth(&self, row_ix: usize) -> usize {
+        0
+    }
+
+    /// Return true if the row has children in the tree table.
+    ///
+    /// Default: false
+    fn row_has_children(&self, row_ix: usize) -> bool {
+        false
+    }
+
+    /// Return true if the children of the row are expanded in the tree table.
+    fn row_children_expanded(&self, row_ix: usize) -> bool {
+        false
+    }
+
+    /// Expand or collapse the children of the row in the tree table.
+    ///
+    /// The rows of the delegate are the visible rows of the flattened tree,
+    /// so the delegate must insert the visible descendant rows after the row when expanding,
+    /// and remove them when collapsing.
+    fn set_row_children_expanded(
+        &mut self,
+        row_ix: usize,
+        expanded: bool,
+        cx: &mut ViewContext<Table<Self>>,
+    ) {
+    }
+
     /// Return true to enable loop selection on the table.
     ///
     /// When the prev/next selection is out of the table bounds, the selection will loop to the other side.
@@ -187,6 +544,9 @@ pub trait TableDelegate: Sized + 'static {
     }
 
     /// Return true to enable column order change.
+    ///
+    /// The column that returns false is fixed, it can't be dragged or used as the drop target.
+    /// The pinned columns of [`TableDelegate::col_fixed`] should return false.
     fn can_move_col(&self, col_ix: usize) -> bool {
         false
     }
@@ -200,7 +560,7 @@ pub trait TableDelegate: Sized + 'static {
             .size_full()
             .justify_center()
             .py_6()
-            .text_color(cx.theme().muted_foreground.opacity(0.6))
+            .text_color(cx.theme().translucent(cx.theme().muted_foreground, 0.6))
             .child(Icon::new(IconName::Inbox).size_12())
             .into_any_element()
     }
@@ -224,9 +584,32 @@ pub trait TableDelegate: Sized + 'static {
     ///
     /// This will performed in a background task.
     ///
-    /// This is always called when the table is near the bottom,
-    /// so you must check if there is more data to load or lock the loading state.
+    /// This is called when the table is near the bottom and not [`TableDelegate::loading`],
+    /// so you must check if there is more data to load, and set the loading state until it's loaded.
+    ///
+    /// ```ignore
+    /// fn load_more(&mut self, cx: &mut ViewContext<Table<Self>>) {
+    ///     self.loading = true;
+    ///     cx.spawn(|view, mut cx| async move {
+    ///         let rows = fetch_next_page().await;
+    ///         _ = view.update(&mut cx, |view, cx| {
+    ///             view.delegate_mut().rows.extend(rows);
+    ///             view.delegate_mut().loading = false;
+    ///             cx.notify();
+    ///         });
+    ///     })
+    ///     .detach();
+    /// }
+    /// ```
     fn load_more(&mut self, cx: &mut ViewContext<Table<Self>>) {}
+
+    /// Return true to show a loading row at the bottom of the table, while loading more data,
+    /// the `load_more` is not called again until it's false.
+    ///
+    /// Default: false
+    fn loading(&self) -> bool {
+        false
+    }
 }
 
 impl<D> Table<D>
@@ -234,23 +617,50 @@ where
     D: TableDelegate,
 {
     pub fn new(delegate: D, cx: &mut ViewContext<Self>) -> Self {
+        let view = cx.view().downgrade();
+        let list_state = ListState::new(0, ListAlignment::Top, px(1000.), move |ix, cx| {
+            view.upgrade()
+                .map(|view| view.update(cx, |table, cx| table.render_list_item(ix, cx)))
+                .unwrap_or_else(|| div().into_any_element())
+        });
+        let view = cx.view().downgrade();
+        // Update the visible range and load more rows after scrolling.
+        list_state.set_scroll_handler(move |_, cx| {
+            _ = view.update(cx, |_, cx| cx.notify());
+        });
+
         let mut this = Self {
             focus_handle: cx.focus_handle(),
             delegate,
             col_groups: Vec::new(),
+            sorted_cols: Vec::new(),
             horizontal_scroll_handle: ScrollHandle::new(),
-            vertical_scroll_handle: UniformListScrollHandle::new(),
+            vertical_scroll_handle: RowsScrollHandle {
+                list_state,
+                heights: Rc::new(RefCell::new(RowHeights::default())),
+            },
+            body_bounds: Bounds::default(),
+            visible_range: 0..0,
             scrollbar_state: Rc::new(Cell::new(ScrollbarState::new())),
             horizontal_scrollbar_state: Rc::new(Cell::new(ScrollbarState::new())),
             selection_state: SelectionState::Row,
             selected_row: None,
             selected_col: None,
+            selected_rows: Vec::new(),
+            selection_anchor: None,
+            multi_select: false,
+            expandable: false,
+            tree: false,
+            expanded_rows: BTreeSet::new(),
             resizing_col: None,
+            autofit_col: None,
+            editing_cell: None,
             bounds: Bounds::default(),
             head_content_bounds: Bounds::default(),
             stripe: false,
             border: true,
             size: Size::default(),
+            theme: cx.theme().component::<TableTheme>(),
         };
 
         this.prepare_col_groups(cx);
@@ -282,24 +692,351 @@ where
         self
     }
 
+    /// Set to allow selecting multiple rows, default to false.
+    ///
+    /// Cmd/Ctrl-click to toggle a row, Shift-click or Shift-Up/Down to select a range of rows,
+    /// and a checkbox column is shown to select all rows.
+    pub fn multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+
+    /// Set to show a chevron column to expand the rows with the detail, default to false.
+    ///
+    /// The detail is rendered by [`TableDelegate::render_row_detail`].
+    pub fn expandable(mut self, expandable: bool) -> Self {
+        self.expandable = expandable;
+        self
+    }
+
+    /// Set to render the rows as a tree, default to false.
+    ///
+    /// The first column is indented by [`TableDelegate::row_depth`],
+    /// with a toggle to expand or collapse the rows that have children.
+    pub fn tree(mut self, tree: bool) -> Self {
+        self.tree = tree;
+        self
+    }
+
+    /// Expand or collapse the children of the row in the tree table.
+    pub fn toggle_row_children(&mut self, row_ix: usize, cx: &mut ViewContext<Self>) {
+        let expanded = self.delegate.row_children_expanded(row_ix);
+        self.set_row_children_expanded(row_ix, !expanded, cx);
+    }
+
+    /// Expand all rows of the tree table.
+    pub fn expand_all(&mut self, cx: &mut ViewContext<Self>) {
+        // The expanded children are visited later, so the whole tree is expanded.
+        let mut row_ix = 0;
+        while row_ix < self.delegate.rows_count() {
+            self.set_row_children_expanded(row_ix, true, cx);
+            row_ix += 1;
+        }
+    }
+
+    /// Collapse all rows of the tree table.
+    pub fn collapse_all(&mut self, cx: &mut ViewContext<Self>) {
+        for row_ix in (0..self.delegate.rows_count()).rev() {
+            self.set_row_children_expanded(row_ix, false, cx);
+        }
+    }
+
+    fn set_row_children_expanded(
+        &mut self,
+        row_ix: usize,
+        expanded: bool,
+        cx: &mut ViewContext<Self>,
+    ) {
+        if !self.delegate.row_has_children(row_ix)
+            || self.delegate.row_children_expanded(row_ix) == expanded
+        {
+            return;
+        }
+
+        self.commit_cell_edit(cx);
+        let old_rows_count = self.delegate.rows_count();
+        self.delegate
+            .set_row_children_expanded(row_ix, expanded, cx);
+        let rows_count = self.delegate.rows_count();
+
+        if rows_count >= old_rows_count {
+            self.splice_rows(row_ix, 0, rows_count - old_rows_count, cx);
+        } else {
+            self.splice_rows(row_ix, old_rows_count - rows_count, 0, cx);
+        }
+        cx.notify();
+    }
+
+    /// Keep the row states pointing to the same rows, after the `removed` rows after the `row_ix`
+    /// are replaced by the `inserted` rows.
+    fn splice_rows(
+        &mut self,
+        row_ix: usize,
+        removed: usize,
+        inserted: usize,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let remap = |ix: usize| {
+            if ix <= row_ix {
+                Some(ix)
+            } else if ix <= row_ix + removed {
+                None
+            } else {
+                Some(ix - removed + inserted)
+            }
+        };
+
+        self.selected_row = self.selected_row.and_then(remap);
+        self.selection_anchor = self.selection_anchor.and_then(remap);
+        self.expanded_rows = self
+            .expanded_rows
+            .iter()
+            .filter_map(|&ix| remap(ix))
+            .collect();
+        {
+            let mut heights = self.vertical_scroll_handle.heights.borrow_mut();
+            heights.details = std::mem::take(&mut heights.details)
+                .into_iter()
+                .filter_map(|(ix, height)| Some((remap(ix)?, height)))
+                .collect();
+        }
+
+        let list_state = &self.vertical_scroll_handle.list_state;
+        let start = row_ix + 1;
+        if start + removed <= list_state.item_count() {
+            list_state.splice(start..start + removed, inserted);
+        }
+
+        let selected_rows = self
+            .selected_rows
+            .iter()
+            .filter_map(|&ix| remap(ix))
+            .collect();
+        self.update_selected_rows(selected_rows, cx);
+    }
+
+    fn render_tree_toggle(&self, row_ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let depth = self.delegate.row_depth(row_ix);
+
+        h_flex()
+            .id(("tree-toggle", row_ix))
+            .flex_shrink_0()
+            .size_4()
+            .ml(TREE_INDENT * depth as f32)
+            .mr_1()
+            .justify_center()
+            .when(self.delegate.row_has_children(row_ix), |this| {
+                this.cursor_pointer()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(
+                        Icon::new(if self.delegate.row_children_expanded(row_ix) {
+                            IconName::ChevronDown
+                        } else {
+                            IconName::ChevronRight
+                        })
+                        .size_4(),
+                    )
+                    // Avoid the row click to change the selection.
+                    .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+                    .on_click(cx.listener(move |table, _, cx| {
+                        table.toggle_row_children(row_ix, cx);
+                    }))
+            })
+    }
+
+    /// Returns true if the row is expanded.
+    pub fn is_row_expanded(&self, row_ix: usize) -> bool {
+        self.expanded_rows.contains(&row_ix)
+    }
+
+    /// Expand or collapse the row.
+    pub fn set_row_expanded(&mut self, row_ix: usize, expanded: bool, cx: &mut ViewContext<Self>) {
+        let changed = if expanded {
+            self.expanded_rows.insert(row_ix)
+        } else {
+            self.expanded_rows.remove(&row_ix)
+        };
+        if !changed {
+            return;
+        }
+
+        if !expanded {
+            self.vertical_scroll_handle
+                .heights
+                .borrow_mut()
+                .details
+                .remove(&row_ix);
+        }
+        // Measure the row again with the changed height.
+        if row_ix < self.vertical_scroll_handle.list_state.item_count() {
+            self.vertical_scroll_handle
+                .list_state
+                .splice(row_ix..row_ix + 1, 1);
+        }
+        cx.notify();
+    }
+
+    /// Returns true if the column is visible.
+    pub fn is_col_visible(&self, col_ix: usize) -> bool {
+        self.col_groups
+            .get(col_ix)
+            .map_or(false, |col_group| !col_group.hidden)
+    }
+
+    /// Show or hide the column, the hidden column can be shown again by the header context menu.
+    pub fn set_col_visible(&mut self, col_ix: usize, visible: bool, cx: &mut ViewContext<Self>) {
+        let Some(col_group) = self.col_groups.get_mut(col_ix) else {
+            return;
+        };
+
+        col_group.hidden = !visible;
+        if !visible && self.selected_col == Some(col_ix) {
+            self.selected_col = None;
+        }
+        cx.notify();
+    }
+
+    fn action_toggle_column(&mut self, action: &ToggleColumn, cx: &mut ViewContext<Self>) {
+        let col_ix = action.0;
+        self.set_col_visible(col_ix, !self.is_col_visible(col_ix), cx);
+    }
+
+    /// Serialize the rows to text, the visible columns are separated by the `separator`,
+    /// and the first line is the column names.
+    fn serialize_rows(&self, rows: impl IntoIterator<Item = usize>, separator: char) -> String {
+        let cols = (0..self.col_groups.len())
+            .filter(|&col_ix| self.is_col_visible(col_ix))
+            .collect::<Vec<_>>();
+
+        let mut lines = vec![cols
+            .iter()
+            .map(|&col_ix| escape_field(&self.delegate.col_name(col_ix), separator))
+            .collect::<Vec<_>>()
+            .join(&separator.to_string())];
+        lines.extend(rows.into_iter().map(|row_ix| {
+            cols.iter()
+                .map(|&col_ix| escape_field(&self.delegate.cell_value(row_ix, col_ix), separator))
+                .collect::<Vec<_>>()
+                .join(&separator.to_string())
+        }));
+
+        lines.join("\n")
+    }
+
+    /// Export the rows with the visible columns to CSV,
+    /// the cell values are returned by [`TableDelegate::cell_value`].
+    pub fn export_csv(&self) -> String {
+        self.serialize_rows(0..self.delegate.rows_count(), ',')
+    }
+
+    /// Copy the selected rows with the visible columns to the clipboard as TSV,
+    /// so they can be pasted into a spreadsheet.
+    pub fn copy_selection_to_clipboard(&self, cx: &mut WindowContext) {
+        let rows = if self.selected_rows.is_empty() {
+            self.selected_row.into_iter().collect()
+        } else {
+            self.selected_rows.clone()
+        };
+        if rows.is_empty() {
+            return;
+        }
+
+        cx.write_to_clipboard(ClipboardItem::new_string(self.serialize_rows(rows, '\t')));
+    }
+
+    /// Returns the selected row indices in ascending order.
+    pub fn selected_rows(&self) -> &[usize] {
+        &self.selected_rows
+    }
+
+    /// Set the selected rows, the invalid row indices are ignored.
+    pub fn set_selected_rows(&mut self, rows: Vec<usize>, cx: &mut ViewContext<Self>) {
+        let rows_count = self.delegate.rows_count();
+        let rows = rows.into_iter().filter(|&ix| ix < rows_count).collect();
+        self.selection_state = SelectionState::Row;
+        self.update_selected_rows(rows, cx);
+    }
+
+    /// Select all rows, only works in multiple selection mode.
+    pub fn select_all(&mut self, cx: &mut ViewContext<Self>) {
+        if !self.multi_select {
+            return;
+        }
+
+        self.selection_state = SelectionState::Row;
+        self.update_selected_rows((0..self.delegate.rows_count()).collect(), cx);
+    }
+
+    /// Clear the selected rows.
+    pub fn clear_selection(&mut self, cx: &mut ViewContext<Self>) {
+        self.selected_row = None;
+        self.selection_anchor = None;
+        self.update_selected_rows(vec![], cx);
+    }
+
     /// Set the size to the table.
     pub fn set_size(&mut self, size: Size, cx: &mut ViewContext<Self>) {
         self.size = size;
         cx.notify();
     }
 
+    /// Returns the current widths of the columns.
+    pub fn column_layout(&self) -> ColumnLayout {
+        ColumnLayout {
+            widths: self.col_groups.iter().map(|g| g.width).collect(),
+        }
+    }
+
+    /// Restore the widths of the columns, e.g.: from the layout saved in the last session.
+    ///
+    /// The widths are clamped to the min/max width of the column.
+    pub fn set_column_layout(&mut self, layout: ColumnLayout, cx: &mut ViewContext<Self>) {
+        for (col_ix, width) in layout.widths.into_iter().enumerate() {
+            let min_width = self.delegate.col_min_width(col_ix);
+            let max_width = self.delegate.col_max_width(col_ix);
+            if let Some(col_group) = self.col_groups.get_mut(col_ix) {
+                col_group.width = width.map(|width| width.max(min_width).min(max_width));
+            }
+        }
+        cx.notify();
+    }
+
     fn prepare_col_groups(&mut self, cx: &mut ViewContext<Self>) {
         self.col_groups = (0..self.delegate.cols_count())
             .map(|col_ix| ColGroup {
                 width: self.delegate.col_width(col_ix),
                 bounds: Bounds::default(),
                 sort: self.delegate.col_sort(col_ix),
+                fixed: self.delegate.col_fixed(col_ix),
+                // Keep the columns hidden by the user.
+                hidden: self
+                    .col_groups
+                    .get(col_ix)
+                    .map_or(false, |col_group| col_group.hidden),
             })
             .collect();
+        self.sorted_cols = self
+            .col_groups
+            .iter()
+            .enumerate()
+            .filter(|(_, col_group)| {
+                matches!(
+                    col_group.sort,
+                    Some(ColSort::Ascending | ColSort::Descending)
+                )
+            })
+            .map(|(ix, _)| ix)
+            .collect();
         cx.notify();
     }
 
-    fn scroll_to_row(&mut self, row_ix: usize, cx: &mut ViewContext<Self>) {
+    /// Returns the range of the visible rows, the delegate can use it to load the data of these rows only.
+    pub fn visible_range(&self) -> Range<usize> {
+        self.visible_range.clone()
+    }
+
+    /// Scroll to make the row at the given index visible.
+    pub fn scroll_to_row(&mut self, row_ix: usize, cx: &mut ViewContext<Self>) {
         self.vertical_scroll_handle.scroll_to_item(row_ix);
         cx.notify();
     }
@@ -307,13 +1044,61 @@ where
     fn set_selected_row(&mut self, row_ix: usize, cx: &mut ViewContext<Self>) {
         self.selection_state = SelectionState::Row;
         self.selected_row = Some(row_ix);
+        self.selection_anchor = Some(row_ix);
         if let Some(row_ix) = self.selected_row {
             self.vertical_scroll_handle.scroll_to_item(row_ix);
         }
         cx.emit(TableEvent::SelectRow(row_ix));
+        self.update_selected_rows(vec![row_ix], cx);
         cx.notify();
     }
 
+    fn update_selected_rows(&mut self, mut rows: Vec<usize>, cx: &mut ViewContext<Self>) {
+        rows.sort_unstable();
+        rows.dedup();
+        if rows == self.selected_rows {
+            return;
+        }
+
+        self.selected_rows = rows;
+        cx.emit(TableEvent::SelectionChanged(self.selected_rows.clone()));
+        cx.notify();
+    }
+
+    /// Select the rows from the selection anchor to the given row.
+    fn select_rows_to(&mut self, row_ix: usize, cx: &mut ViewContext<Self>) {
+        let anchor = self
+            .selection_anchor
+            .or(self.selected_row)
+            .unwrap_or(row_ix);
+        self.selection_anchor = Some(anchor);
+        self.selection_state = SelectionState::Row;
+        self.selected_row = Some(row_ix);
+        self.vertical_scroll_handle.scroll_to_item(row_ix);
+        self.update_selected_rows((anchor.min(row_ix)..=anchor.max(row_ix)).collect(), cx);
+    }
+
+    /// Add or remove the row from the selected rows.
+    fn toggle_selected_row(&mut self, row_ix: usize, cx: &mut ViewContext<Self>) {
+        let mut rows = self.selected_rows.clone();
+        match rows.binary_search(&row_ix) {
+            Ok(ix) => {
+                rows.remove(ix);
+            }
+            Err(ix) => rows.insert(ix, row_ix),
+        }
+
+        self.selection_state = SelectionState::Row;
+        self.selected_row = Some(row_ix);
+        self.selection_anchor = Some(row_ix);
+        self.update_selected_rows(rows, cx);
+    }
+
+    fn is_row_selected(&self, row_ix: usize) -> bool {
+        self.selection_state == SelectionState::Row
+            && self.selected_rows.binary_search(&row_ix).is_ok()
+    }
+
     fn set_selected_col(&mut self, col_ix: usize, cx: &mut ViewContext<Self>) {
         self.selection_state = SelectionState::Column;
         self.selected_col = Some(col_ix);
@@ -324,8 +1109,22 @@ where
         cx.notify();
     }
 
-    fn on_row_click(&mut self, row_ix: usize, cx: &mut ViewContext<Self>) {
-        self.set_selected_row(row_ix, cx)
+    fn on_row_click(&mut self, row_ix: usize, modifiers: Modifiers, cx: &mut ViewContext<Self>) {
+        if self
+            .editing_cell
+            .as_ref()
+            .is_some_and(|cell| cell.row_ix != row_ix)
+        {
+            self.commit_cell_edit(cx);
+        }
+
+        if self.multi_select && modifiers.shift {
+            self.select_rows_to(row_ix, cx)
+        } else if self.multi_select && modifiers.secondary() {
+            self.toggle_selected_row(row_ix, cx)
+        } else {
+            self.set_selected_row(row_ix, cx)
+        }
     }
 
     fn on_col_head_click(&mut self, col_ix: usize, cx: &mut ViewContext<Self>) {
@@ -336,10 +1135,117 @@ where
         self.set_selected_col(col_ix, cx)
     }
 
-    fn action_cancel(&mut self, _: &Cancel, cx: &mut ViewContext<Self>) {
+    /// Returns the sorted columns in the order of priority.
+    pub fn sorts(&self) -> Vec<(usize, ColSort)> {
+        self.sorted_cols
+            .iter()
+            .filter_map(|&ix| Some((ix, self.col_groups.get(ix)?.sort?)))
+            .collect()
+    }
+
+    /// Start editing the cell with the editor of [`TableDelegate::cell_editor`].
+    pub fn edit_cell(&mut self, row_ix: usize, col_ix: usize, cx: &mut ViewContext<Self>) {
+        let Some(editor) = self.delegate.cell_editor(row_ix, col_ix) else {
+            return;
+        };
+        self.commit_cell_edit(cx);
+
+        let value = self.delegate.cell_value(row_ix, col_ix);
+        let size = self.size;
+        let (editor, _subscription) = match editor {
+            CellEditor::Input => {
+                let input = cx.new_view(|cx| {
+                    let mut input = TextInput::new(cx).with_size(size);
+                    input.set_text(value, cx);
+                    input
+                });
+                let subscription =
+                    cx.subscribe(&input, |this, _, event: &InputEvent, cx| match event {
+                        InputEvent::PressEnter | InputEvent::Blur => this.commit_cell_edit(cx),
+                        _ => {}
+                    });
+                (CellEditorView::Input(input), subscription)
+            }
+            CellEditor::Number => {
+                let input = cx.new_view(|cx| {
+                    let mut input = NumberInput::new(cx);
+                    if let Ok(value) = value.parse::<f64>() {
+                        input.set_value(value, cx);
+                    }
+                    input
+                });
+                let subscription =
+                    cx.subscribe(
+                        &input,
+                        |this, _, event: &NumberInputEvent, cx| match event {
+                            NumberInputEvent::PressEnter | NumberInputEvent::Blur => {
+                                this.commit_cell_edit(cx)
+                            }
+                            _ => {}
+                        },
+                    );
+                (CellEditorView::Number(input), subscription)
+            }
+            CellEditor::Dropdown(options) => {
+                let selected_index = options.iter().position(|option| option == &value);
+                let dropdown = cx.new_view(|cx| {
+                    Dropdown::new("table-cell-editor", options, selected_index, cx).with_size(size)
+                });
+                let subscription = cx.subscribe(
+                    &dropdown,
+                    |this, _, event: &DropdownEvent<Vec<SharedString>>, cx| match event {
+                        DropdownEvent::Confirm(Some(_)) => this.commit_cell_edit(cx),
+                        DropdownEvent::Confirm(None) => this.cancel_cell_edit(cx),
+                        DropdownEvent::Create(_) => {}
+                    },
+                );
+                (CellEditorView::Dropdown(dropdown), subscription)
+            }
+        };
+
+        editor.focus_handle(cx).focus(cx);
+        self.editing_cell = Some(EditingCell {
+            row_ix,
+            col_ix,
+            editor,
+            _subscription,
+        });
+        cx.notify();
+    }
+
+    /// Commit the editing cell with the value of the editor.
+    fn commit_cell_edit(&mut self, cx: &mut ViewContext<Self>) {
+        let Some(cell) = self.editing_cell.take() else {
+            return;
+        };
+
+        let value = cell.editor.value(cx);
+        // Return the focus to the table, if the editing is committed by Enter.
+        if cell.editor.focus_handle(cx).is_focused(cx) {
+            self.focus_handle.focus(cx);
+        }
+        self.delegate
+            .on_cell_edited(cell.row_ix, cell.col_ix, value, cx);
+        cx.notify();
+    }
+
+    /// Cancel the editing cell without changing the value.
+    fn cancel_cell_edit(&mut self, cx: &mut ViewContext<Self>) {
+        if self.editing_cell.take().is_some() {
+            self.focus_handle.focus(cx);
+            cx.notify();
+        }
+    }
+
+    fn action_cancel(&mut self, _: &Cancel, cx: &mut ViewContext<Self>) {
+        if self.editing_cell.is_some() {
+            self.cancel_cell_edit(cx);
+            return;
+        }
+
         self.selection_state = SelectionState::Row;
-        self.selected_row = None;
         self.selected_col = None;
+        self.clear_selection(cx);
         cx.notify();
     }
 
@@ -370,45 +1276,167 @@ where
         self.set_selected_row(selected_row, cx);
     }
 
+    fn action_select_to_prev(&mut self, _: &SelectToPrev, cx: &mut ViewContext<Self>) {
+        if !self.multi_select {
+            self.action_select_prev(&SelectPrev, cx);
+            return;
+        }
+
+        let row_ix = self.selected_row.unwrap_or(0).saturating_sub(1);
+        self.select_rows_to(row_ix, cx);
+    }
+
+    fn action_select_to_next(&mut self, _: &SelectToNext, cx: &mut ViewContext<Self>) {
+        if !self.multi_select {
+            self.action_select_next(&SelectNext, cx);
+            return;
+        }
+
+        let rows_count = self.delegate.rows_count();
+        if rows_count == 0 {
+            return;
+        }
+        let row_ix = self.selected_row.map_or(0, |ix| ix + 1).min(rows_count - 1);
+        self.select_rows_to(row_ix, cx);
+    }
+
+    fn render_checkbox_cell(&self, id: impl Into<ElementId>, checked: bool) -> Div {
+        h_flex()
+            .w(CHECKBOX_COL_WIDTH)
+            .h_full()
+            .flex_shrink_0()
+            .justify_center()
+            // Avoid the row click to change the selection.
+            .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+            .child(Checkbox::new(id).checked(checked))
+    }
+
+    fn render_select_all(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let rows_count = self.delegate.rows_count();
+        let checked = rows_count > 0 && self.selected_rows.len() == rows_count;
+
+        self.render_checkbox_cell("select-all", checked)
+            .on_mouse_up(
+                MouseButton::Left,
+                cx.listener(move |table, _, cx| {
+                    if checked {
+                        table.clear_selection(cx);
+                    } else {
+                        table.select_all(cx);
+                    }
+                }),
+            )
+    }
+
+    fn render_expand_cell(&self, row_ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let expanded = self.is_row_expanded(row_ix);
+
+        h_flex()
+            .id(("expand-row", row_ix))
+            .w(EXPAND_COL_WIDTH)
+            .h_full()
+            .flex_shrink_0()
+            .justify_center()
+            .when(self.delegate.can_expand_row(row_ix), |this| {
+                this.cursor_pointer()
+                    .text_color(cx.theme().muted_foreground)
+                    .child(
+                        Icon::new(if expanded {
+                            IconName::ChevronDown
+                        } else {
+                            IconName::ChevronRight
+                        })
+                        .size_4(),
+                    )
+                    // Avoid the row click to change the selection.
+                    .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
+                    .on_click(cx.listener(move |table, _, cx| {
+                        table.set_row_expanded(row_ix, !expanded, cx);
+                    }))
+            })
+    }
+
+    fn render_row_checkbox(&self, row_ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        self.render_checkbox_cell(("select-row", row_ix), self.is_row_selected(row_ix))
+            .on_mouse_up(
+                MouseButton::Left,
+                cx.listener(move |table, _, cx| table.toggle_selected_row(row_ix, cx)),
+            )
+    }
+
+    /// Return the nearest visible column from the `col_ix` in the direction, it wraps around
+    /// if the delegate can loop select.
+    fn visible_col_from(&self, col_ix: usize, forward: bool) -> Option<usize> {
+        let cols_count = self.delegate.cols_count() as isize;
+        let loop_select = self.delegate.can_loop_select();
+
+        (1..cols_count)
+            .map(|step| col_ix as isize + if forward { step } else { -step })
+            .map_while(|ix| {
+                if loop_select {
+                    Some(ix.rem_euclid(cols_count) as usize)
+                } else {
+                    (0..cols_count).contains(&ix).then_some(ix as usize)
+                }
+            })
+            .find(|ix| self.is_col_visible(*ix))
+    }
+
     fn action_select_prev_col(&mut self, _: &SelectPrevColumn, cx: &mut ViewContext<Self>) {
-        let mut selected_col = self.selected_col.unwrap_or(0);
-        let cols_count = self.delegate.cols_count();
-        if selected_col > 0 {
-            selected_col -= 1;
-        } else {
-            if self.delegate.can_loop_select() {
-                selected_col = cols_count - 1;
-            }
+        let selected_col = self.selected_col.unwrap_or(0);
+        if let Some(col_ix) = self.visible_col_from(selected_col, false) {
+            self.set_selected_col(col_ix, cx);
         }
-        self.set_selected_col(selected_col, cx);
     }
 
     fn action_select_next_col(&mut self, _: &SelectNextColumn, cx: &mut ViewContext<Self>) {
-        let mut selected_col = self.selected_col.unwrap_or(0);
-        if selected_col < self.delegate.cols_count() - 1 {
-            selected_col += 1;
-        } else {
-            if self.delegate.can_loop_select() {
-                selected_col = 0;
-            }
+        let selected_col = self.selected_col.unwrap_or(0);
+        if let Some(col_ix) = self.visible_col_from(selected_col, true) {
+            self.set_selected_col(col_ix, cx);
         }
-
-        self.set_selected_col(selected_col, cx);
     }
 
-    fn render_cell(&self, col_ix: usize, _cx: &mut ViewContext<Self>) -> Div {
-        let col_width = self.col_groups[col_ix].width;
+    fn render_cell(&self, col_ix: usize, cx: &mut ViewContext<Self>) -> Div {
+        let autofit = self.autofit_col.map(|(ix, _)| ix) == Some(col_ix);
+        let col_width = if autofit {
+            None
+        } else {
+            self.col_groups[col_ix].width
+        };
 
         div()
             .when_some(col_width, |this, width| this.w(width))
+            // Measure the content width of the cells to fit the column.
+            .when(autofit, |this| {
+                let view = cx.view().clone();
+                this.child(
+                    canvas(
+                        move |bounds, cx| {
+                            view.update(cx, |table, _| {
+                                if let Some((_, width)) = table.autofit_col.as_mut() {
+                                    *width = width.max(bounds.size.width);
+                                }
+                            });
+                            cx.refresh();
+                        },
+                        |_, _, _| {},
+                    )
+                    .absolute()
+                    .size_full(),
+                )
+            })
             .flex_shrink_0()
             .overflow_hidden()
             .whitespace_nowrap()
-            .map(|this| match self.size {
-                Size::XSmall => this.text_sm().py_0().px_1(),
-                Size::Small => this.text_sm().py_0p5().px_1p5(),
-                Size::Large => this.py_1p5().px_3(),
-                _ => this.py_1().px_2(),
+            .map(|this| {
+                let theme = cx.theme();
+                let (this, py, px_) = match self.size {
+                    Size::XSmall => (this.text_sm(), px(0.), px(4.)),
+                    Size::Small => (this.text_sm(), px(2.), px(6.)),
+                    Size::Large => (this, px(6.), px(12.)),
+                    _ => (this, px(4.), px(8.)),
+                };
+                this.py(theme.spacing(py)).px(theme.spacing(px_))
             })
     }
 
@@ -418,7 +1446,7 @@ where
             && self.selected_col == Some(col_ix)
             && self.selection_state == SelectionState::Column
         {
-            h_flex().bg(cx.theme().table_active)
+            h_flex().bg(self.theme.active)
         } else {
             h_flex()
         }
@@ -434,11 +1462,17 @@ where
                 .left_0()
                 .right_0()
                 .bottom_0()
-                .child(Scrollbar::uniform_scroll(
+                .child(Scrollbar::vertical(
                     cx.view().entity_id(),
                     state,
                     self.vertical_scroll_handle.clone(),
-                    self.delegate.rows_count(),
+                    size(
+                        px(0.),
+                        self.vertical_scroll_handle
+                            .heights
+                            .borrow()
+                            .row_top(self.delegate.rows_count()),
+                    ),
                 )),
         )
     }
@@ -482,7 +1516,7 @@ where
                     .h_full()
                     .h_5()
                     .justify_center()
-                    .bg(cx.theme().border)
+                    .bg(self.theme.border)
                     .w(px(1.)),
             )
             .on_drag_move(cx.listener(move |view, e: &DragMoveEvent<ResizeCol>, cx| {
@@ -513,6 +1547,13 @@ where
                     }
                 };
             }))
+            // Double click to fit the column width to its content.
+            .on_click(cx.listener(move |view, e: &ClickEvent, cx| {
+                if e.up.click_count == 2 {
+                    view.autofit_col = Some((ix, px(0.)));
+                    cx.notify();
+                }
+            }))
             .on_drag(ResizeCol((cx.entity_id(), ix)), |drag, cx| {
                 cx.stop_propagation();
                 cx.new_view(|_| drag.clone())
@@ -557,25 +1598,21 @@ where
     /// The `ix`` is the index of the col to resize,
     /// and the `size` is the new size for the col.
     fn resize_cols(&mut self, ix: usize, size: Pixels, cx: &mut ViewContext<Self>) {
-        const MIN_WIDTH: Pixels = px(10.0);
-        const MAX_WIDTH: Pixels = px(1200.0);
-
         if !self.delegate.can_resize_col(ix) {
             return;
         }
         let size = size.floor();
 
         let old_width = self.col_groups[ix].width.unwrap_or_default();
-        let new_width = size;
-        if new_width < MIN_WIDTH {
-            return;
-        }
+        let new_width = size
+            .max(self.delegate.col_min_width(ix))
+            .min(self.delegate.col_max_width(ix));
         let changed_width = new_width - old_width;
         // If change size is less than 1px, do nothing.
         if changed_width > px(-1.0) && changed_width < px(1.0) {
             return;
         }
-        self.col_groups[ix].width = Some(new_width.min(MAX_WIDTH));
+        self.col_groups[ix].width = Some(new_width);
 
         // Resize next col, table not need to resize the right cols.
         // let next_width = self.col_groups[ix + 1].width.unwrap_or_default();
@@ -585,29 +1622,82 @@ where
         cx.notify();
     }
 
-    fn perform_sort(&mut self, col_ix: usize, cx: &mut ViewContext<Self>) {
-        let sort = self.col_groups.get(col_ix).and_then(|g| g.sort);
-        if sort.is_none() {
+    /// Find the expanded rows by the ids after the rows are reordered, with the old indices.
+    fn reorder_expanded_rows(&mut self, expanded_ids: HashMap<ElementId, usize>) {
+        if self.expanded_rows.is_empty() {
             return;
         }
 
-        let sort = sort.unwrap();
+        let mut heights = self.vertical_scroll_handle.heights.borrow_mut();
+        let old_details = std::mem::take(&mut heights.details);
+        self.expanded_rows.clear();
+        if !expanded_ids.is_empty() {
+            for row_ix in 0..self.delegate.rows_count() {
+                let Some(old_ix) = self
+                    .delegate
+                    .row_id(row_ix)
+                    .and_then(|id| expanded_ids.get(&id))
+                else {
+                    continue;
+                };
+
+                self.expanded_rows.insert(row_ix);
+                if let Some(&height) = old_details.get(old_ix) {
+                    heights.details.insert(row_ix, height);
+                }
+            }
+        }
+
+        // Measure the rows again, the expanded rows are moved.
+        let list_state = &self.vertical_scroll_handle.list_state;
+        let count = list_state.item_count();
+        list_state.splice(0..count, count);
+    }
+
+    /// Toggle the sort of the column in the order: ascending, descending, none.
+    ///
+    /// If `multiple` is true, the sorts of the other columns are kept.
+    fn perform_sort(&mut self, col_ix: usize, multiple: bool, cx: &mut ViewContext<Self>) {
+        let Some(sort) = self.col_groups.get(col_ix).and_then(|g| g.sort) else {
+            return;
+        };
+
         let sort = match sort {
-            ColSort::Ascending => ColSort::Descending,
-            ColSort::Descending => ColSort::Ascending,
             ColSort::Default => ColSort::Ascending,
+            ColSort::Ascending => ColSort::Descending,
+            ColSort::Descending => ColSort::Default,
         };
 
-        for (ix, col_group) in self.col_groups.iter_mut().enumerate() {
-            if ix == col_ix {
-                col_group.sort = Some(sort);
-            } else {
-                col_group.sort = Some(ColSort::Default);
+        if !multiple {
+            for &ix in self.sorted_cols.iter() {
+                if let Some(col_group) = self.col_groups.get_mut(ix) {
+                    col_group.sort = Some(ColSort::Default);
+                }
             }
+            self.sorted_cols.clear();
+        }
+
+        self.col_groups[col_ix].sort = Some(sort);
+        self.sorted_cols.retain(|&ix| ix != col_ix);
+        if sort != ColSort::Default {
+            self.sorted_cols.push(col_ix);
         }
 
-        self.delegate_mut().perform_sort(col_ix, sort, cx);
+        let expanded_ids = self
+            .expanded_rows
+            .iter()
+            .filter_map(|&ix| Some((self.delegate.row_id(ix)?, ix)))
+            .collect::<HashMap<_, _>>();
 
+        let sorts = self.sorts();
+        if multiple {
+            self.delegate.perform_multi_sort(&sorts, cx);
+        } else {
+            self.delegate.perform_sort(col_ix, sort, cx);
+        }
+        self.reorder_expanded_rows(expanded_ids);
+
+        cx.emit(TableEvent::Sort(sorts));
         cx.notify();
     }
 
@@ -616,12 +1706,7 @@ where
         col_ix: usize,
         cx: &mut ViewContext<Self>,
     ) -> Option<impl IntoElement> {
-        let sort = self.delegate().col_sort(col_ix);
-        if sort.is_none() {
-            return None;
-        }
-
-        let sort = sort.unwrap();
+        let sort = self.col_groups.get(col_ix)?.sort?;
 
         let icon = match sort {
             ColSort::Ascending => IconName::SortAscending,
@@ -629,22 +1714,25 @@ where
             ColSort::Default => IconName::ChevronsUpDown,
         };
 
+        // Show the priority of the column when sorting by multiple columns.
+        let priority = self
+            .sorted_cols
+            .iter()
+            .position(|&ix| ix == col_ix)
+            .filter(|_| self.sorted_cols.len() > 1);
+
         Some(
-            div()
-                .id(("icon-sort", col_ix))
-                .cursor_pointer()
+            h_flex()
                 .ml_2()
                 .p(px(2.))
+                .gap_0p5()
                 .rounded_sm()
-                .hover(|this| this.bg(cx.theme().secondary))
-                .active(|this| this.bg(cx.theme().secondary_active))
-                .on_mouse_down(MouseButton::Left, |_, cx| cx.stop_propagation())
-                .on_click(cx.listener(move |table, _, cx| table.perform_sort(col_ix, cx)))
-                .child(
-                    Icon::new(icon)
-                        .size_3()
-                        .text_color(cx.theme().secondary_foreground),
-                ),
+                .text_xs()
+                .text_color(cx.theme().secondary_foreground)
+                .child(Icon::new(icon).size_3())
+                .when_some(priority, |this, priority| {
+                    this.child(format!("{}", priority + 1))
+                }),
         )
     }
 
@@ -668,6 +1756,14 @@ where
                             this.on_col_head_click(col_ix, cx);
                         }),
                     )
+                    .when(col_group.sort.is_some(), |this| {
+                        this.cursor_pointer()
+                            .hover(|this| this.bg(cx.theme().secondary))
+                            .on_click(cx.listener(move |table, e: &ClickEvent, cx| {
+                                // Shift-click to sort by multiple columns.
+                                table.perform_sort(col_ix, e.down.modifiers.shift, cx)
+                            }))
+                    })
                     .child(
                         h_flex()
                             .size_full()
@@ -689,11 +1785,13 @@ where
                                 cx.new_view(|_| drag.clone())
                             },
                         )
-                        .drag_over::<DragCol>(|this, _, cx| {
-                            this.rounded_l_none()
-                                .border_l_2()
-                                .border_r_0()
-                                .border_color(cx.theme().drag_border)
+                        // Show the drop indicator on the side where the column will be inserted.
+                        .drag_over::<DragCol>(move |this, drag, cx| {
+                            if drag.col_ix < col_ix {
+                                this.border_r_2().border_color(cx.theme().drag_border)
+                            } else {
+                                this.border_l_2().border_color(cx.theme().drag_border)
+                            }
                         })
                         .on_drop(cx.listener(
                             move |table, drag: &DragCol, cx| {
@@ -721,30 +1819,374 @@ where
             })
     }
 
+    /// Returns the column indices of the left pinned, the scrollable and the right pinned columns.
+    fn fixed_cols(&self) -> (Vec<usize>, Vec<usize>, Vec<usize>) {
+        let mut left_cols = vec![];
+        let mut cols = vec![];
+        let mut right_cols = vec![];
+        for (col_ix, col_group) in self.col_groups.iter().enumerate() {
+            if col_group.hidden {
+                continue;
+            }
+
+            match col_group.fixed {
+                Some(ColFixed::Left) => left_cols.push(col_ix),
+                Some(ColFixed::Right) => right_cols.push(col_ix),
+                None => cols.push(col_ix),
+            }
+        }
+
+        (left_cols, cols, right_cols)
+    }
+
+    /// Render the container of the pinned columns, with a border on the side of the scrollable
+    /// columns, and a shadow when the scrollable columns are scrolled under it.
+    fn render_fixed_cols(
+        &self,
+        fixed: ColFixed,
+        has_cols: bool,
+        cx: &mut ViewContext<Self>,
+    ) -> Div {
+        let offset = self.horizontal_scroll_handle.offset();
+        let viewport_width = self.horizontal_scroll_handle.bounds().size.width;
+        let scrolled = match fixed {
+            ColFixed::Left => offset.x < px(0.),
+            ColFixed::Right => {
+                self.head_content_bounds.size.width + offset.x > viewport_width + px(1.)
+            }
+        };
+
+        h_flex()
+            .relative()
+            .h_full()
+            .flex_shrink_0()
+            .when(has_cols, |this| {
+                match fixed {
+                    ColFixed::Left => this.border_r_1(),
+                    ColFixed::Right => this.border_l_1(),
+                }
+                .border_color(self.theme.border)
+                .when(scrolled, |this| {
+                    this.child(
+                        div()
+                            .absolute()
+                            .top_0()
+                            .bottom_0()
+                            .w(px(4.))
+                            .map(|this| match fixed {
+                                ColFixed::Left => this.right(px(-5.)),
+                                ColFixed::Right => this.left(px(-5.)),
+                            })
+                            .bg(cx.theme().translucent(self.theme.border, 0.5)),
+                    )
+                })
+            })
+    }
+
+    fn render_table_head(&self, cx: &mut ViewContext<Self>) -> Stateful<Div> {
+        let view = cx.view().clone();
+        let (left_cols, cols, right_cols) = self.fixed_cols();
+        let focus_handle = self.focus_handle.clone();
+        let col_items = (0..self.col_groups.len())
+            .map(|col_ix| (self.delegate.col_name(col_ix), self.is_col_visible(col_ix)))
+            .collect::<Vec<_>>();
+
+        h_flex()
+            .id("table-head-row")
+            .relative()
+            .w_full()
+            .h(cx.theme().spacing(px(40.)))
+            .bg(self.theme.head)
+            .child(
+                self.render_fixed_cols(
+                    ColFixed::Left,
+                    self.multi_select || self.expandable || !left_cols.is_empty(),
+                    cx,
+                )
+                .when(self.multi_select, |this| {
+                    this.child(self.render_select_all(cx))
+                })
+                .when(self.expandable, |this| {
+                    this.child(h_flex().w(EXPAND_COL_WIDTH).flex_shrink_0())
+                })
+                .children(left_cols.iter().map(|&col_ix| self.render_th(col_ix, cx))),
+            )
+            .child(
+                h_flex()
+                    .id("table-head")
+                    .flex_1()
+                    .min_w(px(0.))
+                    .h_full()
+                    .overflow_scroll()
+                    .track_scroll(&self.horizontal_scroll_handle)
+                    .child(
+                        div()
+                            .h_flex()
+                            .relative()
+                            .children(cols.iter().map(|&col_ix| self.render_th(col_ix, cx)))
+                            .child(last_empty_col())
+                            .child(
+                                canvas(
+                                    move |bounds, cx| {
+                                        view.update(cx, |r, _| r.head_content_bounds = bounds)
+                                    },
+                                    |_, _, _| {},
+                                )
+                                .absolute()
+                                .size_full(),
+                            ),
+                    ),
+            )
+            .child(
+                self.render_fixed_cols(ColFixed::Right, !right_cols.is_empty(), cx)
+                    .children(right_cols.iter().map(|&col_ix| self.render_th(col_ix, cx))),
+            )
+            // Right click the header to show or hide the columns.
+            .context_menu(move |menu, _| {
+                col_items.iter().enumerate().fold(
+                    menu.track_focus(&focus_handle),
+                    |menu, (col_ix, (name, checked))| {
+                        menu.menu_with_check(name.clone(), *checked, Box::new(ToggleColumn(col_ix)))
+                    },
+                )
+            })
+    }
+
+    /// Render the cell of the row, the row out of the rows count is a fake row to fill the table.
+    fn render_row_cell(
+        &self,
+        row_ix: usize,
+        col_ix: usize,
+        rows_count: usize,
+        cx: &mut ViewContext<Self>,
+    ) -> Div {
+        if let Some(cell) = self
+            .editing_cell
+            .as_ref()
+            .filter(|cell| cell.row_ix == row_ix && cell.col_ix == col_ix)
+        {
+            self.col_wrap(col_ix, cx)
+                .child(self.render_cell(col_ix, cx).child(cell.editor.view()))
+        } else if row_ix < rows_count {
+            self.col_wrap(col_ix, cx)
+                .child(self.render_cell(col_ix, cx).map(|this| {
+                    if self.tree && col_ix == 0 {
+                        this.child(
+                            h_flex()
+                                .child(self.render_tree_toggle(row_ix, cx))
+                                .child(self.delegate.render_td(row_ix, col_ix, cx)),
+                        )
+                    } else {
+                        this.child(self.delegate.render_td(row_ix, col_ix, cx))
+                    }
+                }))
+                .on_mouse_down(
+                    MouseButton::Left,
+                    cx.listener(move |this, e: &MouseDownEvent, cx| {
+                        if e.click_count == 2 && this.delegate.cell_editor(row_ix, col_ix).is_some()
+                        {
+                            this.edit_cell(row_ix, col_ix, cx);
+                            // Keep the focus on the editor.
+                            cx.prevent_default();
+                        }
+                    }),
+                )
+        } else {
+            h_flex().child(self.render_cell(col_ix, cx))
+        }
+    }
+
+    fn render_table_row(
+        &self,
+        row_ix: usize,
+        rows_count: usize,
+        cx: &mut ViewContext<Self>,
+    ) -> Stateful<Div> {
+        let is_fake = row_ix >= rows_count;
+        let (left_cols, cols, right_cols) = self.fixed_cols();
+
+        self.delegate
+            .render_tr(row_ix, cx)
+            .id(if is_fake {
+                ("table-row-fake", row_ix)
+            } else {
+                ("table-row", row_ix)
+            })
+            .w_full()
+            .when(is_fake, |this| {
+                this.h(self.vertical_scroll_handle.heights.borrow().row_height)
+            })
+            .when(row_ix > 0 || is_fake, |this| {
+                this.border_t_1().border_color(self.theme.border)
+            })
+            .when(self.stripe && row_ix % 2 != 0, |this| {
+                this.bg(self.theme.even)
+            })
+            .child(
+                self.render_fixed_cols(
+                    ColFixed::Left,
+                    self.multi_select || self.expandable || !left_cols.is_empty(),
+                    cx,
+                )
+                .when(self.multi_select, |this| {
+                    if is_fake {
+                        this.child(h_flex().w(CHECKBOX_COL_WIDTH).flex_shrink_0())
+                    } else {
+                        this.child(self.render_row_checkbox(row_ix, cx))
+                    }
+                })
+                .when(self.expandable, |this| {
+                    if is_fake {
+                        this.child(h_flex().w(EXPAND_COL_WIDTH).flex_shrink_0())
+                    } else {
+                        this.child(self.render_expand_cell(row_ix, cx))
+                    }
+                })
+                .children(
+                    left_cols
+                        .iter()
+                        .map(|&col_ix| self.render_row_cell(row_ix, col_ix, rows_count, cx)),
+                ),
+            )
+            .child(
+                h_flex()
+                    .flex_1()
+                    .min_w(px(0.))
+                    .h_full()
+                    .overflow_hidden()
+                    .child(
+                        // Make the row scroll sync with the horizontal_scroll_handle
+                        // to support horizontal scrolling.
+                        h_flex()
+                            .h_full()
+                            .left(self.horizontal_scroll_handle.offset().x)
+                            .children(cols.iter().map(|&col_ix| {
+                                self.render_row_cell(row_ix, col_ix, rows_count, cx)
+                            }))
+                            .child(last_empty_col()),
+                    ),
+            )
+            .child(
+                self.render_fixed_cols(ColFixed::Right, !right_cols.is_empty(), cx)
+                    .children(
+                        right_cols
+                            .iter()
+                            .map(|&col_ix| self.render_row_cell(row_ix, col_ix, rows_count, cx)),
+                    ),
+            )
+            .when(!is_fake, |this| {
+                this.hover(|this| {
+                    if self.is_row_selected(row_ix) {
+                        this
+                    } else {
+                        this.bg(self.theme.hover)
+                    }
+                })
+                // Row selected style
+                .when(self.is_row_selected(row_ix), |this| {
+                    this.bg(self.theme.active)
+                })
+                .on_mouse_down(
+                    MouseButton::Left,
+                    cx.listener(move |this, e: &MouseDownEvent, cx| {
+                        this.on_row_click(row_ix, e.modifiers, cx);
+                    }),
+                )
+            })
+    }
+
+    /// Render the row of the list, with the detail if the row is expanded.
+    fn render_list_item(&self, row_ix: usize, cx: &mut ViewContext<Self>) -> AnyElement {
+        let rows_count = self.delegate.rows_count();
+        if row_ix == rows_count && self.delegate.loading() {
+            return self.render_loading_row(cx);
+        }
+
+        let row = self.render_table_row(row_ix, rows_count, cx);
+        let heights = self.vertical_scroll_handle.heights.clone();
+
+        if row_ix < rows_count && self.is_row_expanded(row_ix) {
+            v_flex()
+                .w_full()
+                .child(row)
+                .child(
+                    div()
+                        .relative()
+                        .w_full()
+                        .border_t_1()
+                        .border_color(self.theme.border)
+                        .child(self.delegate.render_row_detail(row_ix, cx))
+                        .child(
+                            canvas(
+                                move |bounds, cx| {
+                                    let height = bounds.size.height;
+                                    let mut heights = heights.borrow_mut();
+                                    if heights.details.insert(row_ix, height) != Some(height) {
+                                        cx.refresh();
+                                    }
+                                },
+                                |_, _, _| {},
+                            )
+                            .absolute()
+                            .size_full(),
+                        ),
+                )
+                .into_any_element()
+        } else if row_ix < rows_count {
+            row.relative()
+                .child(
+                    canvas(
+                        move |bounds, cx| {
+                            let mut heights = heights.borrow_mut();
+                            if heights.row_height != bounds.size.height {
+                                heights.row_height = bounds.size.height;
+                                cx.refresh();
+                            }
+                        },
+                        |_, _, _| {},
+                    )
+                    .absolute()
+                    .size_full(),
+                )
+                .into_any_element()
+        } else {
+            row.into_any_element()
+        }
+    }
+
     /// Dispatch delegate's `load_more` method when the visible range is near the end.
     fn load_more(&mut self, visible_range: Range<usize>, cx: &mut ViewContext<Self>) {
-        if !self.delegate.can_load_more() {
+        if !self.delegate.can_load_more() || self.delegate.loading() {
             return;
         }
 
         let row_count = self.delegate.rows_count();
         let load_more_count = self.delegate.load_more_threshold();
 
-        // Securely handle subtract logic to prevent attempt to subtract with overflow
-        if row_count >= load_more_count {
-            if visible_range.end >= row_count - load_more_count {
-                cx.spawn(|view, mut cx| async move {
-                    cx.update(|cx| {
-                        view.update(cx, |view, cx| {
-                            view.delegate.load_more(cx);
-                        })
+        if visible_range.end >= row_count.saturating_sub(load_more_count) {
+            cx.spawn(|view, mut cx| async move {
+                cx.update(|cx| {
+                    view.update(cx, |view, cx| {
+                        view.delegate.load_more(cx);
                     })
                 })
-                .detach()
-            }
+            })
+            .detach()
         }
     }
 
+    fn render_loading_row(&self, cx: &mut ViewContext<Self>) -> AnyElement {
+        h_flex()
+            .id("table-loading-row")
+            .w_full()
+            .py_2()
+            .justify_center()
+            .border_t_1()
+            .border_color(self.theme.border)
+            .child(Indicator::new().small())
+            .into_any_element()
+    }
+
     fn move_col(&mut self, col_ix: usize, to_ix: usize, cx: &mut ViewContext<Self>) {
         if col_ix == to_ix {
             return;
@@ -754,10 +2196,42 @@ where
         let col_group = self.col_groups.remove(col_ix);
         self.col_groups.insert(to_ix, col_group);
 
+        // Keep the selected and sorted columns pointing to the moved columns.
+        let moved_ix = |ix: usize| {
+            if ix == col_ix {
+                return to_ix;
+            }
+            let ix = if ix > col_ix { ix - 1 } else { ix };
+            if ix >= to_ix {
+                ix + 1
+            } else {
+                ix
+            }
+        };
+        self.selected_col = self.selected_col.map(moved_ix);
+        for ix in self.sorted_cols.iter_mut() {
+            *ix = moved_ix(*ix);
+        }
+
+        cx.emit(TableEvent::MoveCol(col_ix, to_ix));
+
         cx.notify();
     }
 }
 
+/// Quote the field if it contains the separator, quotes or line breaks.
+fn escape_field(value: &str, separator: char) -> String {
+    if value.contains([separator, '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn last_empty_col() -> Div {
+    h_flex().w(px(100.)).h_full().flex_shrink_0()
+}
+
 impl<D> Sizable for Table<D>
 where
     D: TableDelegate,
@@ -783,40 +2257,58 @@ where
     D: TableDelegate,
 {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        self.theme = cx.theme().component::<TableTheme>();
+
+        // Apply the content width measured in the last frame to the autofit column.
+        if let Some((col_ix, width)) = self.autofit_col {
+            if width > px(0.) {
+                self.autofit_col = None;
+                self.resize_cols(col_ix, width, cx);
+                cx.emit(TableEvent::ColWidthsChanged(self.column_layout().widths));
+            }
+        }
+
         let view = cx.view().clone();
-        let vertical_scroll_handle = self.vertical_scroll_handle.clone();
         let horizontal_scroll_handle = self.horizontal_scroll_handle.clone();
-        let cols_count: usize = self.delegate.cols_count();
         let rows_count = self.delegate.rows_count();
-
-        let row_height = self.vertical_scroll_handle.0.borrow().last_item_height;
-        let total_height = self
-            .vertical_scroll_handle
-            .0
-            .borrow()
-            .base_handle
-            .bounds()
-            .size
-            .height;
+        let body_height = self.body_bounds.size.height;
+
+        let list_state = self.vertical_scroll_handle.list_state.clone();
+        let heights = self.vertical_scroll_handle.heights.clone();
+        let heights = heights.borrow();
+        let row_height = heights.row_height;
+
+        // Update the visible range with the scroll position.
+        let scroll_top = list_state.logical_scroll_top();
+        let top = heights.row_top(scroll_top.item_ix) + scroll_top.offset_in_item;
+        let (bottom_ix, _) = heights.row_at(top + body_height);
+        self.visible_range = scroll_top.item_ix.min(rows_count)..(bottom_ix + 1).min(rows_count);
+        if rows_count > 0 {
+            self.load_more(self.visible_range.clone(), cx);
+        }
 
         // Calculate the extra rows needed to fill the table for stripe style.
         let mut extra_rows_needed = 0;
-        if let Some(row_height) = row_height {
-            if row_height > px(0.) {
-                let actual_height = row_height * rows_count as f32;
-                let remaining_height = total_height - actual_height;
-                if remaining_height > px(0.) {
-                    extra_rows_needed = (remaining_height / row_height).ceil() as usize;
-                }
+        if row_height > px(0.) {
+            let remaining_height = body_height - heights.row_top(rows_count);
+            if remaining_height > px(0.) {
+                extra_rows_needed = (remaining_height / row_height).ceil() as usize;
             }
         }
-
-        fn last_empty_col(_: &mut WindowContext) -> Div {
-            h_flex().w(px(100.)).h_full().flex_shrink_0()
-        }
-
-        fn tr(_: &mut WindowContext) -> Div {
-            h_flex()
+        drop(heights);
+
+        // The loading row is rendered after the rows.
+        let loading_rows = self.delegate.loading() as usize;
+        let items_count =
+            rows_count + loading_rows + extra_rows_needed.saturating_sub(loading_rows);
+        let old_items_count = list_state.item_count();
+        if items_count > old_items_count {
+            list_state.splice(
+                old_items_count..old_items_count,
+                items_count - old_items_count,
+            );
+        } else if items_count < old_items_count {
+            list_state.splice(items_count..old_items_count, 0);
         }
 
         let inner_table = v_flex()
@@ -826,59 +2318,24 @@ where
             .on_action(cx.listener(Self::action_cancel))
             .on_action(cx.listener(Self::action_select_next))
             .on_action(cx.listener(Self::action_select_prev))
+            .on_action(cx.listener(Self::action_select_to_next))
+            .on_action(cx.listener(Self::action_select_to_prev))
             .on_action(cx.listener(Self::action_select_next_col))
             .on_action(cx.listener(Self::action_select_prev_col))
+            .on_action(cx.listener(Self::action_toggle_column))
             .size_full()
             .overflow_hidden()
             .child(
                 v_flex()
                     .flex_grow()
-                    .h_10()
+                    .h(cx.theme().spacing(px(40.)))
                     .w_full()
                     .flex_shrink_0()
                     .border_b_1()
-                    .border_color(cx.theme().border)
+                    .border_color(self.theme.border)
                     .child(
                         uniform_list(view.clone(), "table-uniform-list-head", 1, {
-                            let horizontal_scroll_handle = horizontal_scroll_handle.clone();
-                            let view = view.clone();
-                            move |table, _, cx| {
-                                let view = view.clone();
-                                // Columns
-                                tr(cx)
-                                    .id("table-head")
-                                    .w_full()
-                                    .h_10()
-                                    .overflow_scroll()
-                                    .track_scroll(&horizontal_scroll_handle)
-                                    .bg(cx.theme().table_head)
-                                    .child(
-                                        div()
-                                            .h_flex()
-                                            .relative()
-                                            .children(
-                                                table
-                                                    .col_groups
-                                                    .iter()
-                                                    .enumerate()
-                                                    .map(|(col_ix, _)| table.render_th(col_ix, cx)),
-                                            )
-                                            .child(last_empty_col(cx))
-                                            .child(
-                                                canvas(
-                                                    move |bounds, cx| {
-                                                        view.update(cx, |r, _| {
-                                                            r.head_content_bounds = bounds
-                                                        })
-                                                    },
-                                                    |_, _, _| {},
-                                                )
-                                                .absolute()
-                                                .size_full(),
-                                            ),
-                                    )
-                                    .map(|this| vec![this])
-                            }
+                            move |table, _, cx| vec![table.render_table_head(cx)]
                         })
                         .size_full(),
                     ),
@@ -888,132 +2345,22 @@ where
                     this.child(div().size_full().child(self.delegate.render_empty(cx)))
                 } else {
                     this.child(
-                        h_flex().id("table-body").flex_grow().size_full().child(
-                            uniform_list(
-                                view,
-                                "table-uniform-list",
-                                rows_count + extra_rows_needed,
-                                {
-                                    let horizontal_scroll_handle = horizontal_scroll_handle.clone();
-                                    move |table, visible_range, cx| {
-                                        table.load_more(visible_range.clone(), cx);
-
-                                        if visible_range.end > rows_count {
-                                            table.scroll_to_row(
-                                                std::cmp::min(visible_range.start, rows_count - 1),
-                                                cx,
-                                            );
-                                        }
-
-                                        // Render fake rows to fill the table
-                                        visible_range
-                                            .map(|row_ix| {
-                                                // Render real rows for available data
-                                                if row_ix < rows_count {
-                                                    table
-                                                        .delegate
-                                                        .render_tr(row_ix, cx)
-                                                        .id(("table-row", row_ix))
-                                                        .w_full()
-                                                        .when(row_ix > 0, |this| {
-                                                            this.border_t_1()
-                                                                .border_color(cx.theme().border)
-                                                        })
-                                                        .when(
-                                                            table.stripe && row_ix % 2 != 0,
-                                                            |this| this.bg(cx.theme().table_even),
-                                                        )
-                                                        .hover(|this| {
-                                                            if table.selected_row == Some(row_ix) {
-                                                                this
-                                                            } else {
-                                                                this.bg(cx.theme().table_hover)
-                                                            }
-                                                        })
-                                                        .children((0..cols_count).map(|col_ix| {
-                                                            table
-                                                                // Make the row scroll sync with the
-                                                                // horizontal_scroll_handle to support horizontal scrolling.
-                                                                .col_wrap(col_ix, cx)
-                                                                .left(
-                                                                    horizontal_scroll_handle
-                                                                        .offset()
-                                                                        .x,
-                                                                )
-                                                                .child(
-                                                                    table
-                                                                        .render_cell(col_ix, cx)
-                                                                        .child(
-                                                                            table
-                                                                                .delegate
-                                                                                .render_td(
-                                                                                    row_ix, col_ix,
-                                                                                    cx,
-                                                                                ),
-                                                                        ),
-                                                                )
-                                                        }))
-                                                        .child(last_empty_col(cx))
-                                                        // Row selected style
-                                                        .when_some(
-                                                            table.selected_row,
-                                                            |this, selected_row| {
-                                                                this.when(
-                                                                    row_ix == selected_row
-                                                                        && table.selection_state
-                                                                            == SelectionState::Row,
-                                                                    |this| {
-                                                                        this.bg(cx
-                                                                            .theme()
-                                                                            .table_active)
-                                                                    },
-                                                                )
-                                                            },
-                                                        )
-                                                        .on_mouse_down(
-                                                            MouseButton::Left,
-                                                            cx.listener(move |this, _, cx| {
-                                                                this.on_row_click(row_ix, cx);
-                                                            }),
-                                                        )
-                                                } else {
-                                                    // Render fake rows to fill the rest table space
-                                                    table
-                                                        .delegate
-                                                        .render_tr(row_ix, cx)
-                                                        .id(("table-row-fake", row_ix))
-                                                        .w_full()
-                                                        .h_full()
-                                                        .border_t_1()
-                                                        .border_color(cx.theme().border)
-                                                        .when(
-                                                            table.stripe && row_ix % 2 != 0,
-                                                            |this| this.bg(cx.theme().table_even),
-                                                        )
-                                                        .children((0..cols_count).map(|col_ix| {
-                                                            h_flex()
-                                                                .left(
-                                                                    horizontal_scroll_handle
-                                                                        .offset()
-                                                                        .x,
-                                                                )
-                                                                .child(
-                                                                    table.render_cell(col_ix, cx),
-                                                                )
-                                                        }))
-                                                        .child(last_empty_col(cx))
-                                                }
-                                            })
-                                            .collect::<Vec<_>>()
-                                    }
-                                },
-                            )
+                        h_flex()
+                            .id("table-body")
+                            .relative()
                             .flex_grow()
                             .size_full()
-                            .with_sizing_behavior(gpui::ListSizingBehavior::Auto)
-                            .track_scroll(vertical_scroll_handle)
-                            .into_any_element(),
-                        ),
+                            .child(list(list_state).flex_grow().size_full())
+                            .child(
+                                canvas(
+                                    move |bounds, cx| {
+                                        view.update(cx, |r, _| r.body_bounds = bounds)
+                                    },
+                                    |_, _, _| {},
+                                )
+                                .absolute()
+                                .size_full(),
+                            ),
                     )
                 }
             });
@@ -1022,9 +2369,9 @@ where
         div()
             .size_full()
             .when(self.border, |this| {
-                this.rounded_md().border_1().border_color(cx.theme().border)
+                this.rounded_md().border_1().border_color(self.theme.border)
             })
-            .bg(cx.theme().table)
+            .bg(self.theme.background)
             .child(inner_table)
             .child(ScrollableMask::new(
                 cx.view().clone(),
@@ -1041,3 +2388,41 @@ where
             })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::px;
+
+    use super::{escape_field, RowHeights};
+
+    #[test]
+    fn test_row_heights() {
+        let mut heights = RowHeights {
+            row_height: px(10.),
+            ..Default::default()
+        };
+        assert_eq!(heights.row_at(px(25.)), (2, px(5.)));
+
+        heights.details.insert(1, px(50.));
+        heights.details.insert(3, px(20.));
+        assert_eq!(heights.row_top(0), px(0.));
+        assert_eq!(heights.row_top(2), px(70.));
+        assert_eq!(heights.row_top(4), px(110.));
+
+        assert_eq!(heights.row_at(px(15.)), (1, px(5.)));
+        // The detail of the expanded row belongs to the row.
+        assert_eq!(heights.row_at(px(45.)), (1, px(35.)));
+        assert_eq!(heights.row_at(px(75.)), (2, px(5.)));
+        assert_eq!(heights.row_at(px(95.)), (3, px(15.)));
+        assert_eq!(heights.row_at(px(115.)), (4, px(5.)));
+    }
+
+    #[test]
+    fn test_escape_field() {
+        assert_eq!(escape_field("Apple", ','), "Apple");
+        assert_eq!(escape_field("Apple, Inc.", ','), "\"Apple, Inc.\"");
+        assert_eq!(escape_field("Apple, Inc.", '\t'), "Apple, Inc.");
+        assert_eq!(escape_field("5\" Disk", ','), "\"5\"\" Disk\"");
+        assert_eq!(escape_field("line\nbreak", '\t'), "\"line\nbreak\"");
+    }
+}
@@ -0,0 +1,192 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, ClickEvent, ElementId, Hsla, InteractiveElement as _,
+    IntoElement, ParentElement as _, Pixels, RenderOnce, SharedString,
+    StatefulInteractiveElement as _, Styled as _, WindowContext,
+};
+
+use crate::{
+    color_scale, h_flex, theme::ActiveTheme as _, tooltip::Tooltip, Icon, IconName, Sizable, Size,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TagVariant {
+    #[default]
+    Solid,
+    Outline,
+    Soft,
+}
+
+/// Return the (background, border, foreground) colors of the tag, picked from the [`color_scale`] of the accent.
+fn tag_colors(variant: TagVariant, accent: Hsla, is_dark: bool) -> (Hsla, Hsla, Hsla) {
+    let scale = color_scale(accent);
+    let pick = |light: usize, dark: usize| scale.get(if is_dark { dark } else { light });
+
+    match variant {
+        TagVariant::Solid => {
+            let foreground = if accent.l < 0.6 {
+                crate::white()
+            } else {
+                scale.get(950)
+            };
+            (accent, accent, foreground)
+        }
+        TagVariant::Outline => (Hsla::transparent_black(), pick(300, 700), pick(700, 300)),
+        TagVariant::Soft => {
+            let background = pick(100, 900);
+            (background, background, pick(700, 300))
+        }
+    }
+}
+
+/// A small label to categorize the items, e.g.: the labels of an issue.
+#[derive(IntoElement)]
+pub struct Tag {
+    id: ElementId,
+    label: SharedString,
+    icon: Option<Icon>,
+    variant: TagVariant,
+    color: Option<Hsla>,
+    size: Size,
+    max_width: Option<Pixels>,
+    on_remove: Option<Rc<dyn Fn(&ClickEvent, &mut WindowContext)>>,
+}
+
+impl Tag {
+    pub fn new(id: impl Into<ElementId>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            icon: None,
+            variant: TagVariant::default(),
+            color: None,
+            size: Size::Medium,
+            max_width: None,
+            on_remove: None,
+        }
+    }
+
+    pub fn outline(mut self) -> Self {
+        self.variant = TagVariant::Outline;
+        self
+    }
+
+    pub fn soft(mut self) -> Self {
+        self.variant = TagVariant::Soft;
+        self
+    }
+
+    /// Set the accent color, e.g.: `blue_500()`, default: the primary color of the theme.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Set the icon before the label.
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Truncate the label longer than the max width, and show the full label in the tooltip.
+    pub fn max_w(mut self, max_width: impl Into<Pixels>) -> Self {
+        self.max_width = Some(max_width.into());
+        self
+    }
+
+    /// Show the close button, and call the handler when it is clicked.
+    pub fn on_remove(
+        mut self,
+        handler: impl Fn(&ClickEvent, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_remove = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl Sizable for Tag {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl RenderOnce for Tag {
+    fn render(self, cx: &mut WindowContext) -> impl IntoElement {
+        let accent = self.color.unwrap_or(cx.theme().primary);
+        let (bg, border, fg) = tag_colors(self.variant, accent, cx.theme().mode.is_dark());
+        let small = matches!(self.size, Size::XSmall | Size::Small);
+        let label = self.label.clone();
+
+        h_flex()
+            .id(self.id)
+            .flex_shrink_0()
+            .gap_1()
+            .map(|this| if small { this.px_1p5() } else { this.px_2() })
+            .py_0p5()
+            .rounded_md()
+            .border_1()
+            .border_color(border)
+            .bg(bg)
+            .text_color(fg)
+            .map(|this| {
+                if small {
+                    this.text_xs()
+                } else {
+                    this.text_sm()
+                }
+            })
+            .when_some(self.max_width, |this, max_width| {
+                this.max_w(max_width)
+                    .tooltip(move |cx| Tooltip::new(label.clone(), cx))
+            })
+            .when_some(self.icon, |this, icon| this.child(icon.xsmall()))
+            .child(
+                div()
+                    .flex_1()
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .text_ellipsis()
+                    .child(self.label),
+            )
+            .when_some(self.on_remove, |this, on_remove| {
+                this.child(
+                    div()
+                        .id("remove")
+                        .flex_shrink_0()
+                        .rounded_sm()
+                        .cursor_pointer()
+                        .opacity(cx.theme().element_opacity(0.7))
+                        .hover(|this| this.opacity(1.))
+                        .child(Icon::new(IconName::Close).xsmall())
+                        .on_click(move |event, cx| {
+                            cx.stop_propagation();
+                            on_remove(event, cx);
+                        }),
+                )
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tag_colors, TagVariant};
+
+    #[test]
+    fn test_tag_colors() {
+        let accent = crate::blue_600();
+        let (bg, border, fg) = tag_colors(TagVariant::Solid, accent, false);
+        assert_eq!((bg, border), (accent, accent));
+        assert_eq!(fg, crate::white());
+
+        let (bg, border, _) = tag_colors(TagVariant::Soft, accent, false);
+        assert_eq!(bg, border);
+        assert!(bg.l > accent.l);
+        let (bg, _, _) = tag_colors(TagVariant::Soft, accent, true);
+        assert!(bg.l < accent.l);
+
+        let (bg, _, _) = tag_colors(TagVariant::Outline, accent, false);
+        assert_eq!(bg.a, 0.);
+    }
+}
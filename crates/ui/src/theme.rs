@@ -1,12 +1,22 @@
-use std::ops::Deref;
+use std::{
+    any::{Any, TypeId},
+    collections::{BTreeMap, HashMap},
+    fmt,
+    ops::Deref,
+    rc::Rc,
+};
 
+use anyhow::{anyhow, Context as _, Result};
 use gpui::{
-    hsla, point, AppContext, BoxShadow, Global, Hsla, ModelContext, Pixels, SharedString,
-    ViewContext, WindowAppearance, WindowContext,
+    hsla, point, px, AppContext, BoxShadow, Global, Hsla, ModelContext, Pixels, Rgba, SharedString,
+    Subscription, ViewContext, WindowAppearance, WindowContext,
 };
+use serde::{Deserialize, Serialize};
+
+use crate::colors::{color_scale, ColorExt as _};
 
 pub fn init(cx: &mut AppContext) {
-    Theme::sync_system_appearance(cx)
+    Theme::change(ThemeMode::System, cx)
 }
 
 pub trait ActiveTheme {
@@ -255,6 +265,58 @@ impl Colors {
             drop_target: hsl(235.0, 30., 44.0).opacity(0.1),
         }
     }
+
+    /// The light colors with stronger text, borders and focus ring.
+    fn light_high_contrast() -> Colors {
+        Colors {
+            foreground: hsl(0.0, 0.0, 0.0),
+            card_foreground: hsl(0.0, 0.0, 0.0),
+            popover_foreground: hsl(0.0, 0.0, 0.0),
+            primary: hsl(223.0, 5.9, 5.0),
+            secondary_hover: hsl(240.0, 5.0, 88.0),
+            secondary_active: hsl(240.0, 5.0, 82.0),
+            secondary_foreground: hsl(0.0, 0.0, 0.0),
+            muted_foreground: hsl(240.0, 5.0, 25.0),
+            accent_foreground: hsl(0.0, 0.0, 0.0),
+            border: hsl(240.0, 5.0, 35.0),
+            input: hsl(240.0, 5.0, 35.0),
+            ring: hsl(211.0, 100.0, 40.0),
+            selection: hsl(211.0, 97.0, 75.0),
+            scrollbar_thumb: hsl(0., 0., 40.),
+            list_active: hsl(240.0, 7., 80.0),
+            link: hsl(221.0, 90.0, 40.0),
+            ..Colors::light()
+        }
+    }
+
+    /// The dark colors with stronger text, borders and focus ring.
+    fn dark_high_contrast() -> Colors {
+        Colors {
+            background: hsl(0.0, 0.0, 0.0),
+            foreground: hsl(0.0, 0.0, 100.0),
+            card: hsl(0.0, 0.0, 4.0),
+            card_foreground: hsl(0.0, 0.0, 100.0),
+            popover: hsl(0.0, 0.0, 4.0),
+            popover_foreground: hsl(0.0, 0.0, 100.0),
+            primary: hsl(0.0, 0.0, 100.0),
+            secondary_hover: hsl(240.0, 4.0, 22.0),
+            secondary_active: hsl(240.0, 4.0, 28.0),
+            secondary_foreground: hsl(0.0, 0.0, 100.0),
+            muted_foreground: hsl(240.0, 5.0, 80.0),
+            accent_foreground: hsl(0.0, 0.0, 100.0),
+            border: hsl(240.0, 5.0, 65.0),
+            input: hsl(240.0, 5.0, 65.0),
+            ring: hsl(211.0, 100.0, 65.0),
+            selection: hsl(211.0, 97.0, 35.0),
+            scrollbar_thumb: hsl(0., 0., 75.),
+            panel: hsl(0.0, 0.0, 4.0),
+            list: hsl(0.0, 0.0, 0.0),
+            list_active: hsl(240.0, 4.0, 25.0),
+            list_head: hsl(0.0, 0.0, 0.0),
+            link: hsl(213.0, 94.0, 70.0),
+            ..Colors::dark()
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -262,9 +324,13 @@ pub struct Theme {
     pub mode: ThemeMode,
     pub transparent: Hsla,
     pub title_bar_background: Hsla,
-    /// Basic font size
+    /// The base font size in pixels, the text of the components is sized relative to it.
     pub font_size: f32,
     pub font_family: SharedString,
+    /// The font family for the code and other monospaced text.
+    pub mono_font_family: SharedString,
+    /// The UI scale to zoom the text and spacing, see [`Theme::set_scale`].
+    pub scale: f32,
     pub background: Hsla,
     pub foreground: Hsla,
     pub card: Hsla,
@@ -321,14 +387,108 @@ pub struct Theme {
     pub link_hover: Hsla,
     pub link_active: Hsla,
     pub skeleton: Hsla,
+    /// The theme is the high contrast variant, see [`Theme::set_high_contrast`].
+    pub high_contrast: bool,
+    /// The translucent colors are opaque, the components should avoid the translucent effects
+    /// by [`Theme::translucent`] and [`Theme::element_opacity`], see [`Theme::set_reduced_transparency`].
+    pub reduced_transparency: bool,
+    /// The spacing density of the components, see [`Theme::set_density`].
+    pub density: Density,
+    /// The window widths to restructure the layouts, see [`crate::Responsive`].
+    pub breakpoints: Breakpoints,
+    /// The component tokens overrides, see [`Theme::override_component`].
+    overrides: ComponentOverrides,
 }
 
 impl Global for Theme {}
 
+/// The style tokens of a component family, e.g.: [`crate::button::ButtonTheme`].
+///
+/// The tokens cascade from the [`Theme`], they are derived from the theme colors by `from_theme`,
+/// and then customized by [`Theme::override_component`], so the components read them by [`Theme::component`].
+pub trait ComponentTheme: Sized + 'static {
+    fn from_theme(theme: &Theme) -> Self;
+}
+
+type OverrideFn = Rc<dyn Fn(&mut dyn Any)>;
+
+#[derive(Clone, Default)]
+struct ComponentOverrides(HashMap<TypeId, OverrideFn>);
+
+impl fmt::Debug for ComponentOverrides {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ComponentOverrides")
+            .field("len", &self.0.len())
+            .finish()
+    }
+}
+
 impl Theme {
     pub fn get_global(cx: &AppContext) -> &Self {
         cx.global::<Self>()
     }
+
+    /// Return the tokens of the component family, with the overrides applied.
+    pub fn component<T: ComponentTheme>(&self) -> T {
+        let mut tokens = T::from_theme(self);
+        if let Some(f) = self.overrides.0.get(&TypeId::of::<T>()) {
+            f(&mut tokens);
+        }
+        tokens
+    }
+
+    /// Override the tokens of a component family, without forking the render code, e.g.:
+    ///
+    /// ```ignore
+    /// cx.global_mut::<Theme>().override_component::<ButtonTheme>(|button| {
+    ///     button.radius = px(12.);
+    /// });
+    /// ```
+    ///
+    /// The overrides of the same family are applied in order, and they are kept when the
+    /// built-in theme is rebuilt by [`Theme::change`].
+    pub fn override_component<T: ComponentTheme>(&mut self, f: impl Fn(&mut T) + 'static) {
+        let prev = self.overrides.0.remove(&TypeId::of::<T>());
+        self.overrides.0.insert(
+            TypeId::of::<T>(),
+            Rc::new(move |tokens: &mut dyn Any| {
+                if let Some(prev) = prev.as_ref() {
+                    prev(tokens);
+                }
+                if let Some(tokens) = tokens.downcast_mut::<T>() {
+                    f(tokens);
+                }
+            }),
+        );
+    }
+
+    /// Remove the overrides of a component family.
+    pub fn clear_override<T: ComponentTheme>(&mut self) {
+        self.overrides.0.remove(&TypeId::of::<T>());
+    }
+
+    /// Return the `rem` size of the window, the [`crate::Root`] applies it.
+    ///
+    /// It follows the base font size and the UI scale, so the text and spacing in `rems` of all
+    /// the components are zoomed together.
+    pub fn rem_size(&self) -> Pixels {
+        px(BASE_REM_SIZE * self.zoom())
+    }
+
+    /// Return the base text size in the current scale.
+    pub fn text_size(&self) -> Pixels {
+        px(self.font_size * self.scale)
+    }
+
+    /// Scale the spacing in pixels by the [`Density`] and the zoom of [`Theme::rem_size`],
+    /// rounded to whole pixels.
+    pub fn spacing(&self, value: Pixels) -> Pixels {
+        (value * self.density.scale() * self.zoom()).round()
+    }
+
+    fn zoom(&self) -> f32 {
+        self.scale * self.font_size / DEFAULT_FONT_SIZE
+    }
 }
 
 impl From<Colors> for Theme {
@@ -336,7 +496,7 @@ impl From<Colors> for Theme {
         Theme {
             mode: ThemeMode::default(),
             transparent: Hsla::transparent_black(),
-            font_size: 14.0,
+            font_size: DEFAULT_FONT_SIZE,
             font_family: if cfg!(target_os = "macos") {
                 ".SystemUIFont".into()
             } else if cfg!(target_os = "windows") {
@@ -344,6 +504,14 @@ impl From<Colors> for Theme {
             } else {
                 "FreeMono".into()
             },
+            mono_font_family: if cfg!(target_os = "macos") {
+                "Menlo".into()
+            } else if cfg!(target_os = "windows") {
+                "Consolas".into()
+            } else {
+                "DejaVu Sans Mono".into()
+            },
+            scale: 1.,
             radius: 4.0,
             shadow: true,
             title_bar_background: colors.title_bar_background,
@@ -400,46 +568,812 @@ impl From<Colors> for Theme {
             link_hover: colors.link.lighten(0.2),
             link_active: colors.link.darken(0.2),
             skeleton: hsla(colors.primary.h, colors.primary.s, colors.primary.l, 0.1),
+            high_contrast: false,
+            reduced_transparency: false,
+            density: Density::default(),
+            breakpoints: Breakpoints::default(),
+            overrides: ComponentOverrides::default(),
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum ThemeMode {
     Light,
     #[default]
     Dark,
+    /// Follow the system appearance, this is only used for [`Theme::change`],
+    /// the `mode` of the active [`Theme`] is always resolved to `Light` or `Dark`.
+    System,
 }
 
 impl ThemeMode {
     pub fn is_dark(&self) -> bool {
         matches!(self, Self::Dark)
     }
+
+    /// Resolve the `System` mode by the window appearance.
+    fn resolve(self, appearance: WindowAppearance) -> Self {
+        match self {
+            Self::System => match appearance {
+                WindowAppearance::Dark | WindowAppearance::VibrantDark => Self::Dark,
+                WindowAppearance::Light | WindowAppearance::VibrantLight => Self::Light,
+            },
+            mode => mode,
+        }
+    }
+}
+
+/// The spacing density of the components, it scales the paddings, row heights and the default
+/// control sizes of the buttons, inputs, lists and tables.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Density {
+    /// For the data dense UIs.
+    Compact,
+    #[default]
+    Comfortable,
+    Spacious,
+}
+
+impl Density {
+    /// Return the factor to scale the spacing.
+    pub fn scale(&self) -> f32 {
+        match self {
+            Self::Compact => 0.75,
+            Self::Comfortable => 1.,
+            Self::Spacious => 1.25,
+        }
+    }
+
+    /// Scale the spacing of the `Comfortable` density, rounded to whole pixels.
+    pub fn apply(&self, value: Pixels) -> Pixels {
+        (value * self.scale()).round()
+    }
+}
+
+/// A responsive breakpoint, the window is at least the width of it in [`Breakpoints`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Breakpoint {
+    Sm,
+    Md,
+    Lg,
+    Xl,
+}
+
+/// The min window widths of the [`Breakpoint`]s, defaults to the Tailwind CSS ones.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Breakpoints {
+    pub sm: Pixels,
+    pub md: Pixels,
+    pub lg: Pixels,
+    pub xl: Pixels,
+}
+
+impl Default for Breakpoints {
+    fn default() -> Self {
+        Self {
+            sm: px(640.),
+            md: px(768.),
+            lg: px(1024.),
+            xl: px(1280.),
+        }
+    }
+}
+
+impl Breakpoints {
+    /// Return the min width of the breakpoint.
+    pub fn width(&self, breakpoint: Breakpoint) -> Pixels {
+        match breakpoint {
+            Breakpoint::Sm => self.sm,
+            Breakpoint::Md => self.md,
+            Breakpoint::Lg => self.lg,
+            Breakpoint::Xl => self.xl,
+        }
+    }
+
+    /// Return the largest breakpoint reached by the width, `None` if it's narrower than `sm`.
+    pub fn breakpoint(&self, width: Pixels) -> Option<Breakpoint> {
+        [
+            Breakpoint::Xl,
+            Breakpoint::Lg,
+            Breakpoint::Md,
+            Breakpoint::Sm,
+        ]
+        .into_iter()
+        .find(|breakpoint| width >= self.width(*breakpoint))
+    }
+}
+
+/// The min UI scale of [`Theme::set_scale`].
+pub const MIN_SCALE: f32 = 0.5;
+/// The max UI scale of [`Theme::set_scale`].
+pub const MAX_SCALE: f32 = 3.;
+/// The `rem` size of GPUI in the `1.0` scale.
+const BASE_REM_SIZE: f32 = 16.;
+const DEFAULT_FONT_SIZE: f32 = 14.;
+
+/// The options chosen by [`Theme::change`] and the other setters, they are kept when the
+/// theme is rebuilt, e.g.: the system appearance changed.
+#[derive(Debug, Clone)]
+struct ThemeSettings {
+    /// The theme set by [`Theme::apply`], the rebuilds in its mode start from it.
+    base: Option<Theme>,
+    mode: ThemeMode,
+    high_contrast: bool,
+    reduced_transparency: bool,
+    density: Density,
+    font_family: Option<SharedString>,
+    mono_font_family: Option<SharedString>,
+    font_size: Option<f32>,
+    scale: f32,
+    breakpoints: Breakpoints,
+    accent: Option<Hsla>,
+}
+
+impl Default for ThemeSettings {
+    fn default() -> Self {
+        Self {
+            base: None,
+            mode: ThemeMode::System,
+            high_contrast: false,
+            reduced_transparency: false,
+            density: Density::default(),
+            font_family: None,
+            mono_font_family: None,
+            font_size: None,
+            scale: 1.,
+            breakpoints: Breakpoints::default(),
+            accent: None,
+        }
+    }
+}
+
+impl Global for ThemeSettings {}
+
+impl ThemeSettings {
+    /// Build the theme on top of the base theme, or the built-in theme if the base is in
+    /// the other mode.
+    fn build(&self, appearance: WindowAppearance) -> Theme {
+        let mode = self.mode.resolve(appearance);
+        let mut theme = match self.base.clone() {
+            Some(base)
+                if base.mode.is_dark() == mode.is_dark()
+                    && base.high_contrast == self.high_contrast =>
+            {
+                base
+            }
+            _ => Theme::built_in(mode, self.high_contrast),
+        };
+        if let Some(accent) = self.accent {
+            theme.apply_accent(accent);
+        }
+        if self.reduced_transparency {
+            theme.reduce_transparency();
+        }
+        theme.density = self.density;
+        if let Some(font_family) = self.font_family.clone() {
+            theme.font_family = font_family;
+        }
+        if let Some(mono_font_family) = self.mono_font_family.clone() {
+            theme.mono_font_family = mono_font_family;
+        }
+        if let Some(font_size) = self.font_size {
+            theme.font_size = font_size;
+        }
+        theme.scale = self.scale;
+        theme.breakpoints = self.breakpoints;
+        theme
+    }
 }
 
 impl Theme {
-    /// Sync the theme with the system appearance
+    /// Sync the theme with the system appearance, if the mode is `ThemeMode::System`.
+    ///
+    /// The [`crate::Root`] calls this when the window appearance changed.
     pub fn sync_system_appearance(cx: &mut AppContext) {
-        match cx.window_appearance() {
-            WindowAppearance::Dark | WindowAppearance::VibrantDark => {
-                Self::change(ThemeMode::Dark, cx)
-            }
-            WindowAppearance::Light | WindowAppearance::VibrantLight => {
-                Self::change(ThemeMode::Light, cx)
-            }
+        if Self::mode_setting(cx) != ThemeMode::System {
+            return;
+        }
+
+        let mode = ThemeMode::System.resolve(cx.window_appearance());
+        if cx.has_global::<Theme>() && cx.theme().mode == mode {
+            return;
         }
+        Self::rebuild(cx);
     }
 
+    /// Change the theme mode, `ThemeMode::System` to follow the system appearance.
     pub fn change(mode: ThemeMode, cx: &mut AppContext) {
-        let colors = match mode {
-            ThemeMode::Light => Colors::light(),
-            ThemeMode::Dark => Colors::dark(),
+        Self::update_settings(cx, |settings| settings.mode = mode);
+    }
+
+    /// Set true to use the high contrast theme, with stronger text, borders and focus ring.
+    pub fn set_high_contrast(high_contrast: bool, cx: &mut AppContext) {
+        Self::update_settings(cx, |settings| settings.high_contrast = high_contrast);
+    }
+
+    /// Set true to make the translucent colors opaque, e.g.: for the users prefer reduced transparency.
+    pub fn set_reduced_transparency(reduced_transparency: bool, cx: &mut AppContext) {
+        Self::update_settings(cx, |settings| {
+            settings.reduced_transparency = reduced_transparency
+        });
+    }
+
+    /// Set the spacing density of the components, default is `Density::Comfortable`.
+    pub fn set_density(density: Density, cx: &mut AppContext) {
+        Self::update_settings(cx, |settings| settings.density = density);
+    }
+
+    /// Set the font family of the components, `None` to use the default system UI font.
+    pub fn set_font_family(font_family: Option<SharedString>, cx: &mut AppContext) {
+        Self::update_settings(cx, |settings| settings.font_family = font_family);
+    }
+
+    /// Set the monospaced font family, `None` to use the default one.
+    pub fn set_mono_font_family(mono_font_family: Option<SharedString>, cx: &mut AppContext) {
+        Self::update_settings(cx, |settings| settings.mono_font_family = mono_font_family);
+    }
+
+    /// Set the base font size in pixels, `None` to use the default 14px.
+    pub fn set_font_size(font_size: Option<f32>, cx: &mut AppContext) {
+        Self::update_settings(cx, |settings| settings.font_size = font_size);
+    }
+
+    /// Set the UI scale to zoom the text and spacing of all the components, e.g.: for a zoom slider.
+    ///
+    /// The scale is clamped to `MIN_SCALE..=MAX_SCALE`, default is `1.0`.
+    pub fn set_scale(scale: f32, cx: &mut AppContext) {
+        Self::update_settings(cx, |settings| {
+            settings.scale = scale.clamp(MIN_SCALE, MAX_SCALE)
+        });
+    }
+
+    /// Set the window widths of the responsive breakpoints.
+    pub fn set_breakpoints(breakpoints: Breakpoints, cx: &mut AppContext) {
+        Self::update_settings(cx, |settings| settings.breakpoints = breakpoints);
+    }
+
+    /// Rebrand the theme with an accent color, `None` to use the primary colors of the theme.
+    ///
+    /// The primary, link, ring, selection and other accent colors are picked from the
+    /// [`color_scale`] of it by the mode, so they follow the mode changes.
+    pub fn set_accent(accent: Option<Hsla>, cx: &mut AppContext) {
+        Self::update_settings(cx, |settings| settings.accent = accent);
+    }
+
+    /// Return the mode chosen by [`Theme::change`], it may be `ThemeMode::System`.
+    pub fn mode_setting(cx: &AppContext) -> ThemeMode {
+        Self::settings(cx).mode
+    }
+
+    /// Observe the `mode` changes of the active theme, e.g.: the system appearance changed.
+    pub fn observe_mode(
+        cx: &mut AppContext,
+        mut f: impl FnMut(ThemeMode, &mut AppContext) + 'static,
+    ) -> Subscription {
+        let mut last_mode = cx.theme().mode;
+        cx.observe_global::<Theme>(move |cx| {
+            let mode = cx.theme().mode;
+            if mode != last_mode {
+                last_mode = mode;
+                f(mode, cx);
+            }
+        })
+    }
+
+    fn settings(cx: &AppContext) -> ThemeSettings {
+        cx.try_global::<ThemeSettings>()
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn update_settings(cx: &mut AppContext, f: impl FnOnce(&mut ThemeSettings)) {
+        let mut settings = Self::settings(cx);
+        f(&mut settings);
+        cx.set_global(settings);
+        Self::rebuild(cx);
+    }
+
+    /// Apply the theme built by the settings.
+    fn rebuild(cx: &mut AppContext) {
+        let mut theme = Self::settings(cx).build(cx.window_appearance());
+        if let Some(current) = cx.try_global::<Theme>() {
+            theme.overrides = current.overrides.clone();
+        }
+        cx.set_global(theme);
+        cx.refresh();
+    }
+
+    /// Return the built-in theme of the resolved mode.
+    fn built_in(mode: ThemeMode, high_contrast: bool) -> Self {
+        let (mode, colors) = match (mode.is_dark(), high_contrast) {
+            (true, false) => (ThemeMode::Dark, Colors::dark()),
+            (true, true) => (ThemeMode::Dark, Colors::dark_high_contrast()),
+            (false, false) => (ThemeMode::Light, Colors::light()),
+            (false, true) => (ThemeMode::Light, Colors::light_high_contrast()),
         };
 
         let mut theme = Theme::from(colors);
         theme.mode = mode;
+        theme.high_contrast = high_contrast;
+        theme
+    }
 
-        cx.set_global(theme);
-        cx.refresh();
+    /// Blend the translucent colors onto the background to make them opaque.
+    ///
+    /// The fully transparent colors are kept, they are used to show the parent background.
+    fn reduce_transparency(&mut self) {
+        for (name, color) in self.colors() {
+            if color.a <= 0. || color.a >= 1. {
+                continue;
+            }
+
+            let opaque = self.blend_background(color);
+            if let Some(color) = self.color_mut(name) {
+                *color = opaque;
+            }
+        }
+        self.reduced_transparency = true;
+    }
+
+    /// Blend the color onto the background to make it opaque.
+    fn blend_background(&self, color: Hsla) -> Hsla {
+        let background = self.background.to_rgb();
+        let rgb = color.to_rgb();
+        let blend = |c: f32, bg: f32| c * color.a + bg * (1. - color.a);
+        Rgba {
+            r: blend(rgb.r, background.r),
+            g: blend(rgb.g, background.g),
+            b: blend(rgb.b, background.b),
+            a: 1.,
+        }
+        .into()
+    }
+
+    /// Return the color with the opacity, e.g.: the tints and overlays of the components.
+    ///
+    /// It's blended onto the background to be opaque if [`Theme::reduced_transparency`] is set.
+    pub fn translucent(&self, color: Hsla, opacity: f32) -> Hsla {
+        let color = color.opacity(opacity);
+        if self.reduced_transparency && color.a > 0. {
+            self.blend_background(color)
+        } else {
+            color
+        }
+    }
+
+    /// Return the opacity of the elements, e.g.: the drag previews, `1.0` if
+    /// [`Theme::reduced_transparency`] is set.
+    pub fn element_opacity(&self, opacity: f32) -> f32 {
+        if self.reduced_transparency {
+            1.
+        } else {
+            opacity
+        }
+    }
+}
+
+/// Define the colors of the [`Theme`] that can be loaded from a theme file, by the field names.
+macro_rules! theme_colors {
+    ($($name:ident),* $(,)?) => {
+        impl Theme {
+            fn colors(&self) -> Vec<(&'static str, Hsla)> {
+                vec![$((stringify!($name), self.$name)),*]
+            }
+
+            fn color_mut(&mut self, name: &str) -> Option<&mut Hsla> {
+                match name {
+                    $(stringify!($name) => Some(&mut self.$name),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+theme_colors!(
+    title_bar_background,
+    background,
+    foreground,
+    card,
+    card_foreground,
+    popover,
+    popover_foreground,
+    primary,
+    primary_hover,
+    primary_active,
+    primary_foreground,
+    secondary,
+    secondary_hover,
+    secondary_active,
+    secondary_foreground,
+    destructive,
+    destructive_hover,
+    destructive_active,
+    destructive_foreground,
+    muted,
+    muted_foreground,
+    accent,
+    accent_foreground,
+    border,
+    input,
+    ring,
+    selection,
+    scrollbar,
+    scrollbar_thumb,
+    panel,
+    drag_border,
+    drop_target,
+    tab_bar,
+    tab,
+    tab_active,
+    tab_foreground,
+    tab_active_foreground,
+    progress_bar,
+    slider_bar,
+    slider_thumb,
+    list,
+    list_even,
+    list_head,
+    list_active,
+    list_hover,
+    table,
+    table_even,
+    table_head,
+    table_active,
+    table_hover,
+    link,
+    link_hover,
+    link_active,
+    skeleton,
+);
+
+/// The theme file format, the colors are hex strings like `#3B82F6` or `#3B82F680` (with alpha).
+///
+/// All fields are optional, the missing ones use the built-in theme of the `mode`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+struct ThemeFile {
+    mode: Option<ThemeMode>,
+    font_size: Option<f32>,
+    font_family: Option<String>,
+    mono_font_family: Option<String>,
+    radius: Option<f32>,
+    shadow: Option<bool>,
+    density: Option<Density>,
+    /// Use the high contrast built-in theme as the base.
+    high_contrast: Option<bool>,
+    /// Make the translucent colors opaque, applied after the `colors`.
+    reduced_transparency: Option<bool>,
+    /// The accent color to generate the primary colors like [`Theme::set_accent`], applied before the `colors`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    accent: Option<String>,
+    colors: BTreeMap<String, String>,
+}
+
+impl Theme {
+    /// Load a theme from a JSON theme file, e.g.:
+    ///
+    /// ```json
+    /// {
+    ///   "mode": "light",
+    ///   "radius": 6.0,
+    ///   "colors": { "primary": "#3B82F6", "primary_foreground": "#FFFFFF" }
+    /// }
+    /// ```
+    ///
+    /// Only the overridden fields are required, the others use the built-in light or dark theme.
+    pub fn from_json(json: &str) -> Result<Self> {
+        Self::from_file(serde_json::from_str(json)?)
+    }
+
+    /// Load a theme from a TOML theme file, it has the same fields as [`Theme::from_json`].
+    pub fn from_toml(toml: &str) -> Result<Self> {
+        Self::from_file(toml::from_str(toml)?)
+    }
+
+    /// Serialize the theme to JSON with all the colors, it can be loaded by [`Theme::from_json`].
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(&self.to_file())?)
+    }
+
+    /// Serialize the theme to TOML with all the colors, it can be loaded by [`Theme::from_toml`].
+    pub fn to_toml(&self) -> Result<String> {
+        Ok(toml::to_string_pretty(&self.to_file())?)
+    }
+
+    /// Pick the accent colors from the [`color_scale`] of the accent, see [`Theme::set_accent`].
+    fn apply_accent(&mut self, accent: Hsla) {
+        let scale = color_scale(accent);
+        let is_dark = self.mode.is_dark();
+        let pick = |light: usize, dark: usize| scale.get(if is_dark { dark } else { light });
+
+        self.primary = scale.get(500);
+        self.primary_hover = pick(600, 400);
+        self.primary_active = pick(700, 300);
+        self.primary_foreground = if self.primary.l < 0.6 {
+            crate::white()
+        } else {
+            scale.get(950)
+        };
+        self.ring = pick(300, 700);
+        self.selection = pick(200, 900);
+        self.link = pick(600, 400);
+        self.link_hover = pick(500, 300);
+        self.link_active = pick(700, 500);
+        self.drag_border = scale.get(500);
+        self.progress_bar = self.primary;
+        self.slider_bar = self.primary;
+        self.skeleton = self.primary.opacity(0.1);
+        if self.reduced_transparency {
+            self.reduce_transparency();
+        }
+    }
+
+    /// Set the theme as the active theme, and refresh the windows to apply it.
+    ///
+    /// The theme is kept as the base of the settings, so [`Theme::set_scale`] and the other setters
+    /// are applied on top of it, and the mode is set to the mode of the theme.
+    /// Note that [`Theme::change`] to the other mode will use the built-in theme of that mode.
+    ///
+    /// The overrides of the active theme are kept, see [`Theme::override_component`].
+    pub fn apply(self, cx: &mut AppContext) {
+        Self::update_settings(cx, |settings| {
+            settings.mode = self.mode;
+            settings.high_contrast = self.high_contrast;
+            settings.reduced_transparency = self.reduced_transparency;
+            settings.density = self.density;
+            settings.font_family = Some(self.font_family.clone());
+            settings.mono_font_family = Some(self.mono_font_family.clone());
+            settings.font_size = Some(self.font_size);
+            settings.scale = self.scale;
+            settings.breakpoints = self.breakpoints;
+            settings.accent = None;
+            settings.base = Some(self);
+        });
+    }
+
+    fn from_file(file: ThemeFile) -> Result<Self> {
+        let mode = file.mode.unwrap_or_default();
+        if mode == ThemeMode::System {
+            return Err(anyhow!("the mode of theme file must be light or dark"));
+        }
+        let mut theme = Self::built_in(mode, file.high_contrast.unwrap_or_default());
+
+        if let Some(font_size) = file.font_size {
+            theme.font_size = font_size;
+        }
+        if let Some(font_family) = file.font_family {
+            theme.font_family = font_family.into();
+        }
+        if let Some(mono_font_family) = file.mono_font_family {
+            theme.mono_font_family = mono_font_family.into();
+        }
+        if let Some(radius) = file.radius {
+            theme.radius = radius;
+        }
+        if let Some(shadow) = file.shadow {
+            theme.shadow = shadow;
+        }
+        if let Some(density) = file.density {
+            theme.density = density;
+        }
+        if let Some(accent) = file.accent {
+            let accent = Hsla::parse_hex_string(&accent)
+                .with_context(|| format!("invalid accent color: {}", accent))?;
+            theme.apply_accent(accent);
+        }
+
+        for (name, value) in file.colors {
+            let color = theme
+                .color_mut(&name)
+                .ok_or_else(|| anyhow!("unknown theme color: {}", name))?;
+            *color = Hsla::parse_hex_string(&value)
+                .with_context(|| format!("invalid color of {}: {}", name, value))?;
+        }
+        if file.reduced_transparency.unwrap_or_default() {
+            theme.reduce_transparency();
+        }
+
+        Ok(theme)
+    }
+
+    fn to_file(&self) -> ThemeFile {
+        ThemeFile {
+            mode: Some(self.mode),
+            font_size: Some(self.font_size),
+            font_family: Some(self.font_family.to_string()),
+            mono_font_family: Some(self.mono_font_family.to_string()),
+            radius: Some(self.radius),
+            shadow: Some(self.shadow),
+            density: Some(self.density),
+            high_contrast: Some(self.high_contrast),
+            reduced_transparency: Some(self.reduced_transparency),
+            accent: None,
+            colors: self
+                .colors()
+                .into_iter()
+                .map(|(name, color)| (name.to_string(), color.to_hex_string()))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_json() {
+        let theme = Theme::from_json(
+            r##"{
+                "mode": "light",
+                "radius": 6.0,
+                "colors": { "primary": "#3B82F6", "border": "#3B82F680" }
+            }"##,
+        )
+        .unwrap();
+        assert_eq!(theme.mode, ThemeMode::Light);
+        assert_eq!(theme.radius, 6.0);
+        assert_eq!(theme.primary.to_hex_string(), "#3B82F6");
+        assert_eq!(theme.border.to_hex_string(), "#3B82F680");
+        // The missing fields use the built-in theme.
+        assert_eq!(theme.background, Theme::from(Colors::light()).background);
+
+        let theme = Theme::from_json(
+            r##"{ "mode": "dark", "accent": "#3B82F6", "colors": { "link": "#FF0000" } }"##,
+        )
+        .unwrap();
+        assert_eq!(theme.primary.to_hex_string(), "#3B82F6");
+        assert_eq!(theme.primary_hover, color_scale(theme.primary).get(400));
+        // The colors are applied after the accent.
+        assert_eq!(theme.link.to_hex_string(), "#FF0000");
+
+        assert!(Theme::from_json(r#"{ "colors": { "unknown": "#FFFFFF" } }"#).is_err());
+        assert!(Theme::from_json(r#"{ "colors": { "primary": "blue" } }"#).is_err());
+    }
+
+    #[test]
+    fn test_accessibility_themes() {
+        let theme = Theme::built_in(ThemeMode::Light, true);
+        assert!(theme.high_contrast);
+        assert_eq!(theme.mode, ThemeMode::Light);
+        assert!(theme.border.l < Theme::built_in(ThemeMode::Light, false).border.l);
+
+        let mut theme = Theme::built_in(ThemeMode::Dark, false);
+        assert!(theme.secondary_hover.a < 1.);
+        theme.reduce_transparency();
+        assert!(theme.reduced_transparency);
+        for (name, color) in theme.colors() {
+            assert!(color.a == 0. || color.a == 1., "{} is translucent", name);
+        }
+        // The fully transparent colors are kept.
+        assert_eq!(theme.tab.a, 0.);
+        assert_eq!(theme.translucent(theme.primary, 0.5).a, 1.);
+        assert_eq!(theme.element_opacity(0.5), 1.);
+    }
+
+    #[test]
+    fn test_scale() {
+        let mut theme = Theme::built_in(ThemeMode::Light, false);
+        theme.scale = 1.5;
+        assert_eq!(theme.rem_size(), px(24.));
+        assert_eq!(theme.text_size(), px(21.));
+        assert_eq!(theme.spacing(px(8.)), px(12.));
+        theme.density = Density::Compact;
+        assert_eq!(theme.spacing(px(8.)), px(9.));
+
+        theme.scale = 1.;
+        theme.font_size = 16.;
+        assert_eq!(theme.spacing(px(14.)), px(16.));
+        assert_eq!(theme.text_size(), px(16.));
+    }
+
+    #[test]
+    fn test_build_settings() {
+        let base =
+            Theme::from_json(r##"{ "mode": "dark", "colors": { "link": "#FF0000" } }"##).unwrap();
+        let mut settings = ThemeSettings {
+            base: Some(base),
+            mode: ThemeMode::Dark,
+            scale: 1.5,
+            ..Default::default()
+        };
+        let theme = settings.build(WindowAppearance::Light);
+        assert_eq!(theme.link.to_hex_string(), "#FF0000");
+        assert_eq!(theme.scale, 1.5);
+
+        // The accent is applied on top of the base theme.
+        let accent = Hsla::parse_hex_string("#3B82F6").unwrap();
+        settings.accent = Some(accent);
+        let theme = settings.build(WindowAppearance::Light);
+        assert_eq!(theme.primary.to_hex_string(), "#3B82F6");
+        assert_eq!(theme.link, color_scale(accent).get(400));
+
+        // The other mode uses the built-in theme.
+        settings.mode = ThemeMode::System;
+        let theme = settings.build(WindowAppearance::Light);
+        assert_eq!(theme.mode, ThemeMode::Light);
+        assert_eq!(theme.background, Theme::from(Colors::light()).background);
+    }
+
+    #[test]
+    fn test_breakpoints() {
+        let breakpoints = Breakpoints::default();
+        assert_eq!(breakpoints.breakpoint(px(320.)), None);
+        assert_eq!(breakpoints.breakpoint(px(640.)), Some(Breakpoint::Sm));
+        assert_eq!(breakpoints.breakpoint(px(1000.)), Some(Breakpoint::Md));
+        assert_eq!(breakpoints.breakpoint(px(1920.)), Some(Breakpoint::Xl));
+        assert!(Breakpoint::Lg > Breakpoint::Md);
+    }
+
+    #[test]
+    fn test_override_component() {
+        #[derive(Debug, PartialEq)]
+        struct FooTheme {
+            bg: Hsla,
+            radius: f32,
+        }
+
+        impl ComponentTheme for FooTheme {
+            fn from_theme(theme: &Theme) -> Self {
+                Self {
+                    bg: theme.primary,
+                    radius: theme.radius,
+                }
+            }
+        }
+
+        let mut theme = Theme::built_in(ThemeMode::Light, false);
+        let primary = theme.primary;
+        assert_eq!(theme.component::<FooTheme>().bg, primary);
+
+        theme.override_component::<FooTheme>(|foo| foo.radius = 8.);
+        theme.override_component::<FooTheme>(|foo| foo.radius *= 2.);
+        // The tokens still cascade from the theme.
+        theme.radius = 2.;
+        assert_eq!(
+            theme.component::<FooTheme>(),
+            FooTheme {
+                bg: primary,
+                radius: 16.
+            }
+        );
+
+        theme.clear_override::<FooTheme>();
+        assert_eq!(theme.component::<FooTheme>().radius, 2.);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut theme = Theme::from_toml(
+            r##"
+            mode = "dark"
+            font_family = "Inter"
+            density = "compact"
+
+            [colors]
+            primary = "#FF6600"
+            "##,
+        )
+        .unwrap();
+        assert_eq!(theme.font_family.as_ref(), "Inter");
+        assert_eq!(theme.density, Density::Compact);
+        assert_eq!(theme.density.apply(px(32.)), px(24.));
+        theme.shadow = false;
+
+        let json = theme.to_json().unwrap();
+        let toml = theme.to_toml().unwrap();
+        for loaded in [
+            Theme::from_json(&json).unwrap(),
+            Theme::from_toml(&toml).unwrap(),
+        ] {
+            assert_eq!(loaded.to_json().unwrap(), json);
+            assert_eq!(loaded.primary.to_hex_string(), "#FF6600");
+            assert!(!loaded.shadow);
+        }
     }
 }
@@ -67,6 +67,14 @@ impl Date {
         matches!(self, Self::Single(_))
     }
 
+    /// Check if the date is in the pending range from the start to the hovered date, before the end is selected.
+    fn is_in_preview(&self, v: &NaiveDate, hovered: Option<NaiveDate>) -> bool {
+        match (self, hovered) {
+            (Self::Range(Some(start), None), Some(hovered)) => *v >= *start && *v <= hovered,
+            _ => false,
+        }
+    }
+
     fn is_in_range(&self, v: &NaiveDate) -> bool {
         let v = *v;
         match self {
@@ -159,6 +167,8 @@ pub struct Calendar {
     year_page: i32,
     /// Number of the months view to show.
     number_of_months: usize,
+    /// The hovered date to preview the range.
+    hovered_date: Option<NaiveDate>,
 }
 
 impl Calendar {
@@ -173,6 +183,7 @@ impl Calendar {
             years: vec![],
             year_page: 0,
             number_of_months: 1,
+            hovered_date: None,
         }
         .year_range((today.year() - 50, today.year() + 50))
     }
@@ -182,6 +193,7 @@ impl Calendar {
     /// When you set a range date, the mode will be automatically set to `Mode::Range`.
     pub fn set_date(&mut self, date: impl Into<Date>, cx: &mut ViewContext<Self>) {
         self.date = date.into();
+        self.hovered_date = None;
 
         match self.date {
             Date::Single(Some(date)) => {
@@ -251,7 +263,8 @@ impl Calendar {
     fn days(&self) -> Vec<Vec<NaiveDate>> {
         (0..self.number_of_months)
             .flat_map(|offset| {
-                days_in_month(self.current_year, self.current_month as u32 + offset as u32)
+                let (year, month) = self.offset_year_month(offset);
+                days_in_month(year, month)
             })
             .collect()
     }
@@ -362,11 +375,11 @@ impl Calendar {
             .justify_center()
             .cursor_pointer()
             .when(muted, |this| {
-                this.text_color(cx.theme().muted_foreground.opacity(0.3))
+                this.text_color(cx.theme().translucent(cx.theme().muted_foreground, 0.3))
             })
             .when(secondary_active, |this| {
                 this.bg(if muted {
-                    cx.theme().accent.opacity(0.5)
+                    cx.theme().translucent(cx.theme().accent, 0.5)
                 } else {
                     cx.theme().accent
                 })
@@ -396,7 +409,7 @@ impl Calendar {
         let day = d.day();
         let is_current_month = d.month() == month;
         let is_active = self.date.is_active(d) && is_current_month;
-        let is_in_range = self.date.is_in_range(d);
+        let is_in_range = self.date.is_in_range(d) || self.date.is_in_preview(d, self.hovered_date);
 
         let date = *d;
 
@@ -408,6 +421,16 @@ impl Calendar {
             !is_current_month,
             cx,
         )
+        .when(!self.date.is_single(), |this| {
+            this.on_hover(cx.listener(move |view, hovered: &bool, cx| {
+                if *hovered {
+                    view.hovered_date = Some(date);
+                } else if view.hovered_date == Some(date) {
+                    view.hovered_date = None;
+                }
+                cx.notify();
+            }))
+        })
         .on_click(cx.listener(move |view, _: &ClickEvent, cx| {
             if view.date.is_single() {
                 view.set_date(date, cx);
@@ -440,7 +463,8 @@ impl Calendar {
         cx.notify();
     }
 
-    fn months(&self) -> Vec<SharedString> {
+    /// Return the names of the months from January to December.
+    pub(super) fn months() -> Vec<SharedString> {
         [
             t!("Calendar.month.January"),
             t!("Calendar.month.February"),
@@ -460,6 +484,22 @@ impl Calendar {
         .collect()
     }
 
+    /// Return the short names of the weekdays from Sunday to Saturday.
+    pub(super) fn weekdays() -> Vec<SharedString> {
+        [
+            t!("Calendar.week.0"),
+            t!("Calendar.week.1"),
+            t!("Calendar.week.2"),
+            t!("Calendar.week.3"),
+            t!("Calendar.week.4"),
+            t!("Calendar.week.5"),
+            t!("Calendar.week.6"),
+        ]
+        .iter()
+        .map(|s| s.clone().into())
+        .collect()
+    }
+
     fn render_header(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
         let current_year = self.current_year;
         let disabled = self.view_mode.is_month();
@@ -522,11 +562,12 @@ impl Calendar {
             .when(multiple_months, |this| {
                 this.child(h_flex().flex_1().justify_around().children(
                     (0..self.number_of_months).map(|n| {
+                        let (year, _) = self.offset_year_month(n);
                         h_flex()
                             .justify_center()
                             .gap_3()
                             .child(self.month_name(n))
-                            .child(current_year.to_string())
+                            .child(year.to_string())
                     }),
                 ))
             })
@@ -546,15 +587,7 @@ impl Calendar {
     }
 
     fn render_days(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
-        let weeks = [
-            t!("Calendar.week.0"),
-            t!("Calendar.week.1"),
-            t!("Calendar.week.2"),
-            t!("Calendar.week.3"),
-            t!("Calendar.week.4"),
-            t!("Calendar.week.5"),
-            t!("Calendar.week.6"),
-        ];
+        let weeks = Self::weekdays();
 
         h_flex().gap_4().justify_between().text_sm().children(
             self.days()
@@ -580,7 +613,7 @@ impl Calendar {
     }
 
     fn render_months(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
-        let months = self.months();
+        let months = Self::months();
 
         h_flex()
             .mt_3()
@@ -668,6 +701,19 @@ mod tests {
 
     use super::Date;
 
+    #[test]
+    fn test_is_in_preview() {
+        let date = |day| NaiveDate::from_ymd_opt(2024, 8, day).unwrap();
+        let pending = Date::Range(Some(date(3)), None);
+
+        assert!(pending.is_in_preview(&date(5), Some(date(7))));
+        assert!(!pending.is_in_preview(&date(8), Some(date(7))));
+        // Hover before the start, it will be the new start when clicked.
+        assert!(!pending.is_in_preview(&date(2), Some(date(1))));
+        assert!(!pending.is_in_preview(&date(5), None));
+        assert!(!Date::Range(Some(date(3)), Some(date(9))).is_in_preview(&date(5), Some(date(7))));
+    }
+
     #[test]
     fn test_date_to_string() {
         let date = Date::Single(Some(NaiveDate::from_ymd_opt(2024, 8, 3).unwrap()));
@@ -1,21 +1,20 @@
 use gpui::{
-    anchored, deferred, div, prelude::FluentBuilder as _, px, AppContext, ElementId, EventEmitter,
-    FocusHandle, FocusableView, InteractiveElement as _, KeyBinding, Length, MouseButton,
-    ParentElement as _, Render, SharedString, StatefulInteractiveElement as _, Styled as _, View,
-    ViewContext, VisualContext as _,
+    div, px, AnyElement, AppContext, ElementId, EventEmitter, FocusHandle, FocusableView,
+    IntoElement, Length, ParentElement as _, Render, SharedString, Styled as _, View, ViewContext,
+    VisualContext as _,
 };
 use rust_i18n::t;
 
-use crate::{
-    dropdown::Escape, h_flex, input::ClearButton, theme::ActiveTheme as _, Icon, IconName, Sizable,
-    Size, StyleSized as _, StyledExt as _,
-};
+use crate::{Sizable, Size};
 
-use super::calendar::{Calendar, CalendarEvent, Date};
+use super::{
+    calendar::{Calendar, CalendarEvent, Date},
+    picker::{self, render_picker, Picker, PickerState},
+};
 
+/// Bind the keys of all the pickers.
 pub fn init(cx: &mut AppContext) {
-    let context = Some("DatePicker");
-    cx.bind_keys([KeyBinding::new("escape", Escape, context)])
+    picker::init(cx)
 }
 
 #[derive(Clone)]
@@ -24,14 +23,8 @@ pub enum DatePickerEvent {
 }
 
 pub struct DatePicker {
-    id: ElementId,
-    focus_handle: FocusHandle,
+    picker: PickerState,
     date: Date,
-    cleanable: bool,
-    placeholder: Option<SharedString>,
-    open: bool,
-    size: Size,
-    width: Length,
     date_format: SharedString,
     calendar: View<Calendar>,
     number_of_months: usize,
@@ -65,23 +58,17 @@ impl DatePicker {
         cx.subscribe(&calendar, |this, _, ev: &CalendarEvent, cx| match ev {
             CalendarEvent::Selected(date) => {
                 this.update_date(*date, true, cx);
-                this.focus_handle.focus(cx);
+                this.picker.focus_handle.focus(cx);
             }
         })
         .detach();
 
         Self {
-            id: id.into(),
-            focus_handle: cx.focus_handle(),
+            picker: PickerState::new(id, cx.focus_handle()),
             date,
             calendar,
-            open: false,
-            size: Size::default(),
-            width: Length::Auto,
             date_format: "%Y/%m/%d".into(),
-            cleanable: false,
             number_of_months: 1,
-            placeholder: None,
         }
     }
 
@@ -93,19 +80,19 @@ impl DatePicker {
 
     /// Set the placeholder of the date picker, default: "".
     pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
-        self.placeholder = Some(placeholder.into());
+        self.picker.placeholder = Some(placeholder.into());
         self
     }
 
     /// Set true to show the clear button when the input field is not empty.
     pub fn cleanable(mut self) -> Self {
-        self.cleanable = true;
+        self.picker.cleanable = true;
         self
     }
 
     /// Set width of the date picker input field, default is `Length::Auto`.
     pub fn width(mut self, width: impl Into<Length>) -> Self {
-        self.width = width.into();
+        self.picker.width = width.into();
         self
     }
 
@@ -130,62 +117,62 @@ impl DatePicker {
         self.calendar.update(cx, |view, cx| {
             view.set_date(date, cx);
         });
-        self.open = false;
+        self.picker.open = false;
         if emit {
             cx.emit(DatePickerEvent::Change(date));
         }
         cx.notify();
     }
-
-    fn escape(&mut self, _: &Escape, cx: &mut ViewContext<Self>) {
-        self.open = false;
-        self.focus_handle.focus(cx);
-        cx.notify();
-    }
-
-    fn clean(&mut self, _: &gpui::ClickEvent, cx: &mut ViewContext<Self>) {
-        match self.date {
-            Date::Single(_) => {
-                self.update_date(Date::Single(None), true, cx);
-            }
-            Date::Range(_, _) => {
-                self.update_date(Date::Range(None, None), true, cx);
-            }
-        }
-    }
-
-    fn toggle_calendar(&mut self, _: &gpui::ClickEvent, cx: &mut ViewContext<Self>) {
-        self.open = !self.open;
-        cx.notify();
-    }
 }
 
 impl EventEmitter<DatePickerEvent> for DatePicker {}
 impl Sizable for DatePicker {
     fn with_size(mut self, size: impl Into<Size>) -> Self {
-        self.size = size.into();
+        self.picker.size = size.into();
         self
     }
 }
 impl FocusableView for DatePicker {
     fn focus_handle(&self, _: &AppContext) -> FocusHandle {
-        self.focus_handle.clone()
+        self.picker.focus_handle.clone()
     }
 }
 
-impl Render for DatePicker {
-    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl gpui::IntoElement {
-        let is_focused = self.focus_handle.is_focused(cx);
-        let show_clean = self.cleanable && self.date.is_some();
-        let placeholder = self
-            .placeholder
-            .clone()
-            .unwrap_or_else(|| t!("DatePicker.placeholder").into());
-        let display_title = self
-            .date
-            .format(&self.date_format)
-            .unwrap_or(placeholder.clone());
+impl Picker for DatePicker {
+    const CONTEXT: &'static str = "DatePicker";
 
+    fn picker(&self) -> &PickerState {
+        &self.picker
+    }
+
+    fn picker_mut(&mut self) -> &mut PickerState {
+        &mut self.picker
+    }
+
+    fn title(&self) -> Option<SharedString> {
+        self.date.format(&self.date_format)
+    }
+
+    fn default_placeholder(&self) -> SharedString {
+        t!("DatePicker.placeholder").into()
+    }
+
+    fn has_value(&self) -> bool {
+        self.date.is_some()
+    }
+
+    fn clean(&mut self, cx: &mut ViewContext<Self>) {
+        match self.date {
+            Date::Single(_) => {
+                self.update_date(Date::Single(None), true, cx);
+            }
+            Date::Range(_, _) => {
+                self.update_date(Date::Range(None, None), true, cx);
+            }
+        }
+    }
+
+    fn render_popover(&mut self, cx: &mut ViewContext<Self>) -> AnyElement {
         self.calendar.update(cx, |view, cx| {
             view.set_number_of_months(self.number_of_months, cx);
         });
@@ -194,82 +181,15 @@ impl Render for DatePicker {
             285.0 * self.number_of_months as f32 + (self.number_of_months - 1) as f32 * 16.0;
 
         div()
-            .id(self.id.clone())
-            .key_context("DatePicker")
-            .track_focus(&self.focus_handle)
-            .when(self.open, |this| this.on_action(cx.listener(Self::escape)))
-            .w_full()
-            .relative()
-            .map(|this| match self.width {
-                Length::Definite(l) => this.flex_none().w(l),
-                Length::Auto => this.w_full(),
-            })
-            .input_text_size(self.size)
-            .child(
-                div()
-                    .id("date-picker-input")
-                    .relative()
-                    .flex()
-                    .items_center()
-                    .justify_between()
-                    .bg(cx.theme().background)
-                    .border_1()
-                    .border_color(cx.theme().input)
-                    .rounded(px(cx.theme().radius))
-                    .when(cx.theme().shadow, |this| this.shadow_sm())
-                    .cursor_pointer()
-                    .overflow_hidden()
-                    .input_text_size(self.size)
-                    .when(is_focused, |this| this.outline(cx))
-                    .input_size(self.size)
-                    .when(!self.open, |this| {
-                        this.on_click(cx.listener(Self::toggle_calendar))
-                    })
-                    .child(
-                        h_flex()
-                            .w_full()
-                            .items_center()
-                            .justify_between()
-                            .gap_1()
-                            .child(div().w_full().overflow_hidden().child(display_title))
-                            .when(show_clean, |this| {
-                                this.child(ClearButton::new(cx).on_click(cx.listener(Self::clean)))
-                            })
-                            .when(!show_clean, |this| {
-                                this.child(
-                                    Icon::new(IconName::Calendar)
-                                        .text_color(cx.theme().muted_foreground),
-                                )
-                            }),
-                    ),
-            )
-            .when(self.open, |this| {
-                this.child(
-                    deferred(
-                        anchored().snap_to_window_with_margin(px(8.)).child(
-                            div()
-                                .track_focus(&self.focus_handle)
-                                .occlude()
-                                .absolute()
-                                .mt_1p5()
-                                .overflow_hidden()
-                                .rounded_lg()
-                                .p_3()
-                                .w(px(popover_width))
-                                .border_1()
-                                .border_color(cx.theme().border)
-                                .shadow_lg()
-                                .rounded_lg()
-                                .bg(cx.theme().background)
-                                .on_mouse_up_out(
-                                    MouseButton::Left,
-                                    cx.listener(|view, _, cx| view.escape(&Escape, cx)),
-                                )
-                                .child(self.calendar.clone()),
-                        ),
-                    )
-                    .with_priority(2),
-                )
-            })
+            .p_3()
+            .w(px(popover_width))
+            .child(self.calendar.clone())
+            .into_any_element()
+    }
+}
+
+impl Render for DatePicker {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        render_picker(self, cx)
     }
 }
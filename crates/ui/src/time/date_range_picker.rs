@@ -0,0 +1,337 @@
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnyElement, AppContext, ElementId, EventEmitter,
+    FocusHandle, FocusableView, IntoElement, Length, ParentElement as _, Render, SharedString,
+    Styled as _, View, ViewContext, VisualContext as _,
+};
+use rust_i18n::t;
+
+use crate::{
+    button::{Button, ButtonStyled as _},
+    h_flex,
+    theme::ActiveTheme as _,
+    v_flex, Selectable as _, Sizable, Size,
+};
+
+use super::{
+    calendar::{Calendar, CalendarEvent, Date},
+    picker::{render_picker, Picker, PickerState},
+};
+
+/// The preset ranges relative to today, shown in the left column of the [`DateRangePicker`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateRangePreset {
+    Today,
+    Yesterday,
+    Last7Days,
+    Last30Days,
+    ThisMonth,
+    LastMonth,
+}
+
+impl DateRangePreset {
+    /// All the presets, the default presets of the [`DateRangePicker`].
+    pub fn all() -> Vec<Self> {
+        vec![
+            Self::Today,
+            Self::Yesterday,
+            Self::Last7Days,
+            Self::Last30Days,
+            Self::ThisMonth,
+            Self::LastMonth,
+        ]
+    }
+
+    pub fn label(&self) -> SharedString {
+        match self {
+            Self::Today => t!("DateRangePicker.Today"),
+            Self::Yesterday => t!("DateRangePicker.Yesterday"),
+            Self::Last7Days => t!("DateRangePicker.Last 7 days"),
+            Self::Last30Days => t!("DateRangePicker.Last 30 days"),
+            Self::ThisMonth => t!("DateRangePicker.This month"),
+            Self::LastMonth => t!("DateRangePicker.Last month"),
+        }
+        .into()
+    }
+
+    /// Return the start and end dates of the preset, both are inclusive.
+    pub fn range(&self, today: NaiveDate) -> (NaiveDate, NaiveDate) {
+        let first_day = today.with_day(1).unwrap_or(today);
+
+        match self {
+            Self::Today => (today, today),
+            Self::Yesterday => {
+                let yesterday = today - Duration::days(1);
+                (yesterday, yesterday)
+            }
+            Self::Last7Days => (today - Duration::days(6), today),
+            Self::Last30Days => (today - Duration::days(29), today),
+            Self::ThisMonth => (first_day, last_day_of_month(first_day)),
+            Self::LastMonth => {
+                let last_day = first_day - Duration::days(1);
+                (last_day.with_day(1).unwrap_or(last_day), last_day)
+            }
+        }
+    }
+}
+
+/// Return the last day of the month of the date.
+fn last_day_of_month(date: NaiveDate) -> NaiveDate {
+    let (year, month) = if date.month() == 12 {
+        (date.year() + 1, 1)
+    } else {
+        (date.year(), date.month() + 1)
+    };
+
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .map(|first_day| first_day - Duration::days(1))
+        .unwrap_or(date)
+}
+
+#[derive(Clone)]
+pub enum DateRangePickerEvent {
+    Change(Date),
+}
+
+/// A date picker to select a range with two months side by side, and the preset ranges.
+pub struct DateRangePicker {
+    picker: PickerState,
+    date: Date,
+    presets: Vec<DateRangePreset>,
+    date_format: SharedString,
+    calendar: View<Calendar>,
+}
+
+impl DateRangePicker {
+    pub fn new(id: impl Into<ElementId>, cx: &mut ViewContext<Self>) -> Self {
+        let date = Date::Range(None, None);
+
+        let calendar = cx.new_view(|cx| Calendar::new(cx).number_of_months(2));
+        calendar.update(cx, |view, cx| view.set_date(date, cx));
+
+        cx.subscribe(&calendar, |this, _, ev: &CalendarEvent, cx| match ev {
+            CalendarEvent::Selected(date) => {
+                this.update_date(*date, true, cx);
+                this.picker.focus_handle.focus(cx);
+            }
+        })
+        .detach();
+
+        Self {
+            picker: PickerState::new(id, cx.focus_handle()),
+            date,
+            presets: DateRangePreset::all(),
+            calendar,
+            date_format: "%Y/%m/%d".into(),
+        }
+    }
+
+    /// Set the presets to show, default is [`DateRangePreset::all`], empty to hide the presets column.
+    pub fn presets(mut self, presets: Vec<DateRangePreset>) -> Self {
+        self.presets = presets;
+        self
+    }
+
+    /// Set the date format of the date picker to display in Input, default: "%Y/%m/%d".
+    pub fn date_format(mut self, format: impl Into<SharedString>) -> Self {
+        self.date_format = format.into();
+        self
+    }
+
+    /// Set the placeholder of the date picker, default: "".
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.picker.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Set true to show the clear button when the input field is not empty.
+    pub fn cleanable(mut self) -> Self {
+        self.picker.cleanable = true;
+        self
+    }
+
+    /// Set width of the date picker input field, default is `Length::Auto`.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.picker.width = width.into();
+        self
+    }
+
+    /// Get the selected range.
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    /// Set the selected range.
+    pub fn set_date(&mut self, start: NaiveDate, end: NaiveDate, cx: &mut ViewContext<Self>) {
+        self.update_date(Date::Range(Some(start), Some(end)), false, cx);
+    }
+
+    /// Return the preset matching the selected range, `None` if it's a custom range.
+    pub fn active_preset(&self) -> Option<DateRangePreset> {
+        let today = Local::now().naive_local().date();
+        let (Some(start), Some(end)) = (self.date.start(), self.date.end()) else {
+            return None;
+        };
+
+        self.presets
+            .iter()
+            .find(|preset| preset.range(today) == (start, end))
+            .copied()
+    }
+
+    fn update_date(&mut self, date: Date, emit: bool, cx: &mut ViewContext<Self>) {
+        self.date = date;
+        self.calendar.update(cx, |view, cx| {
+            view.set_date(date, cx);
+        });
+        self.picker.open = false;
+        if emit {
+            cx.emit(DateRangePickerEvent::Change(date));
+        }
+        cx.notify();
+    }
+
+    fn select_preset(&mut self, preset: DateRangePreset, cx: &mut ViewContext<Self>) {
+        let (start, end) = preset.range(Local::now().naive_local().date());
+        self.update_date(Date::Range(Some(start), Some(end)), true, cx);
+        self.picker.focus_handle.focus(cx);
+    }
+
+    fn render_presets(&self, cx: &mut ViewContext<Self>) -> impl gpui::IntoElement {
+        let active_preset = self.active_preset();
+        let is_custom = active_preset.is_none() && self.date.is_complete();
+
+        v_flex()
+            .w(px(128.))
+            .flex_shrink_0()
+            .gap_0p5()
+            .pr_3()
+            .border_r_1()
+            .border_color(cx.theme().border)
+            .children(self.presets.iter().enumerate().map(|(ix, preset)| {
+                let preset = *preset;
+                Button::new(("preset", ix))
+                    .ghost()
+                    .small()
+                    .w_full()
+                    .label(preset.label())
+                    .selected(active_preset == Some(preset))
+                    .on_click(cx.listener(move |this, _, cx| this.select_preset(preset, cx)))
+            }))
+            // The custom range is selected by the calendar, so it's only an indicator.
+            .child(
+                Button::new("preset-custom")
+                    .ghost()
+                    .small()
+                    .w_full()
+                    .label(t!("DateRangePicker.Custom"))
+                    .selected(is_custom),
+            )
+    }
+}
+
+impl EventEmitter<DateRangePickerEvent> for DateRangePicker {}
+impl Sizable for DateRangePicker {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.picker.size = size.into();
+        self
+    }
+}
+impl FocusableView for DateRangePicker {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.picker.focus_handle.clone()
+    }
+}
+
+impl Picker for DateRangePicker {
+    const CONTEXT: &'static str = "DateRangePicker";
+
+    fn picker(&self) -> &PickerState {
+        &self.picker
+    }
+
+    fn picker_mut(&mut self) -> &mut PickerState {
+        &mut self.picker
+    }
+
+    fn title(&self) -> Option<SharedString> {
+        self.date.format(&self.date_format)
+    }
+
+    fn default_placeholder(&self) -> SharedString {
+        t!("DateRangePicker.placeholder").into()
+    }
+
+    fn has_value(&self) -> bool {
+        self.date.is_some()
+    }
+
+    fn clean(&mut self, cx: &mut ViewContext<Self>) {
+        self.update_date(Date::Range(None, None), true, cx);
+    }
+
+    fn render_popover(&mut self, cx: &mut ViewContext<Self>) -> AnyElement {
+        let has_presets = !self.presets.is_empty();
+        let popover_width = 285.0 * 2. + 16.0 + if has_presets { 144.0 } else { 0. };
+
+        h_flex()
+            .items_start()
+            .gap_3()
+            .p_3()
+            .w(px(popover_width))
+            .when(has_presets, |this| this.child(self.render_presets(cx)))
+            .child(div().flex_1().child(self.calendar.clone()))
+            .into_any_element()
+    }
+}
+
+impl Render for DateRangePicker {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        render_picker(self, cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::DateRangePreset;
+
+    #[test]
+    fn test_preset_range() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let today = date(2024, 3, 5);
+
+        assert_eq!(DateRangePreset::Today.range(today), (today, today));
+        assert_eq!(
+            DateRangePreset::Yesterday.range(today),
+            (date(2024, 3, 4), date(2024, 3, 4))
+        );
+        assert_eq!(
+            DateRangePreset::Last7Days.range(today),
+            (date(2024, 2, 28), today)
+        );
+        assert_eq!(
+            DateRangePreset::Last30Days.range(today),
+            (date(2024, 2, 5), today)
+        );
+        assert_eq!(
+            DateRangePreset::ThisMonth.range(today),
+            (date(2024, 3, 1), date(2024, 3, 31))
+        );
+        // Leap year.
+        assert_eq!(
+            DateRangePreset::LastMonth.range(today),
+            (date(2024, 2, 1), date(2024, 2, 29))
+        );
+        // Across the year.
+        assert_eq!(
+            DateRangePreset::LastMonth.range(date(2024, 1, 15)),
+            (date(2023, 12, 1), date(2023, 12, 31))
+        );
+        assert_eq!(
+            DateRangePreset::ThisMonth.range(date(2023, 12, 15)),
+            (date(2023, 12, 1), date(2023, 12, 31))
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use chrono::{Local, NaiveDate, NaiveDateTime, NaiveTime};
+use gpui::{
+    div, px, AnyElement, AppContext, ElementId, EventEmitter, FocusHandle, FocusableView,
+    IntoElement, Length, ParentElement as _, Render, SharedString, Styled as _, View, ViewContext,
+    VisualContext as _,
+};
+use rust_i18n::t;
+
+use crate::{h_flex, theme::ActiveTheme as _, Sizable, Size};
+
+use super::{
+    calendar::{Calendar, CalendarEvent, Date},
+    picker::{render_picker, Picker, PickerState},
+    time_picker::{TimeOptions, TimePanel, TimePanelEvent},
+    utils::clamp_option,
+};
+
+#[derive(Clone)]
+pub enum DateTimePickerEvent {
+    Change(Option<NaiveDateTime>),
+}
+
+/// A picker to select the date by the calendar and the time by the columns.
+pub struct DateTimePicker {
+    picker: PickerState,
+    value: Option<NaiveDateTime>,
+    options: TimeOptions,
+    min: Option<NaiveDateTime>,
+    max: Option<NaiveDateTime>,
+    date_format: SharedString,
+    calendar: View<Calendar>,
+    panel: View<TimePanel>,
+}
+
+impl DateTimePicker {
+    pub fn new(id: impl Into<ElementId>, cx: &mut ViewContext<Self>) -> Self {
+        let calendar = cx.new_view(Calendar::new);
+        let panel = cx.new_view(TimePanel::new);
+
+        cx.subscribe(&calendar, |this, _, ev: &CalendarEvent, cx| match ev {
+            CalendarEvent::Selected(date) => {
+                if let Some(date) = date.start() {
+                    let time = this
+                        .value
+                        .map(|value| value.time())
+                        .or_else(|| this.options.min)
+                        .unwrap_or_default();
+                    this.update_value(Some(date.and_time(time)), true, cx);
+                }
+            }
+        })
+        .detach();
+
+        cx.subscribe(&panel, |this, _, ev: &TimePanelEvent, cx| match ev {
+            TimePanelEvent::Selected(time) => {
+                let date = this
+                    .value
+                    .map(|value| value.date())
+                    .unwrap_or_else(|| Local::now().naive_local().date());
+                this.update_value(Some(date.and_time(*time)), true, cx);
+            }
+        })
+        .detach();
+
+        Self {
+            picker: PickerState::new(id, cx.focus_handle()),
+            value: None,
+            options: TimeOptions::default(),
+            min: None,
+            max: None,
+            date_format: "%Y/%m/%d".into(),
+            calendar,
+            panel,
+        }
+    }
+
+    /// Set true to use the 12-hour clock with AM/PM, default is the 24-hour clock.
+    pub fn twelve_hour(mut self, twelve_hour: bool) -> Self {
+        self.options.twelve_hour = twelve_hour;
+        self
+    }
+
+    /// Set true to show the seconds column, default: false.
+    pub fn seconds(mut self, seconds: bool) -> Self {
+        self.options.seconds = seconds;
+        self
+    }
+
+    /// Set the interval of the minutes, e.g.: 15 to only show 00, 15, 30 and 45, default: 1.
+    pub fn minute_step(mut self, step: u32) -> Self {
+        self.options.minute_step = step.max(1);
+        self
+    }
+
+    /// Set the interval of the seconds, default: 1.
+    pub fn second_step(mut self, step: u32) -> Self {
+        self.options.second_step = step.max(1);
+        self
+    }
+
+    /// Set the earliest date and time can be selected.
+    pub fn min(mut self, min: NaiveDateTime) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Set the latest date and time can be selected.
+    pub fn max(mut self, max: NaiveDateTime) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Set the date format to display in Input, default: "%Y/%m/%d", the time format follows the clock.
+    pub fn date_format(mut self, format: impl Into<SharedString>) -> Self {
+        self.date_format = format.into();
+        self
+    }
+
+    /// Set the placeholder of the picker, default: "Select date and time".
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.picker.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Set true to show the clear button when the input field is not empty.
+    pub fn cleanable(mut self) -> Self {
+        self.picker.cleanable = true;
+        self
+    }
+
+    /// Set width of the picker input field, default is `Length::Auto`.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.picker.width = width.into();
+        self
+    }
+
+    /// Get the date and time of the picker.
+    pub fn value(&self) -> Option<NaiveDateTime> {
+        self.value
+    }
+
+    /// Set the date and time of the picker, it's snapped to the steps and clamped to the min and max.
+    pub fn set_value(&mut self, value: Option<NaiveDateTime>, cx: &mut ViewContext<Self>) {
+        self.update_value(value, false, cx);
+    }
+
+    /// Return the time options of the panel, the min and max time are limited on the min and max date.
+    fn time_options(&self, date: Option<NaiveDate>) -> TimeOptions {
+        let on_date = |limit: Option<NaiveDateTime>| -> Option<NaiveTime> {
+            limit
+                .filter(|limit| Some(limit.date()) == date)
+                .map(|limit| limit.time())
+        };
+
+        TimeOptions {
+            min: on_date(self.min),
+            max: on_date(self.max),
+            ..self.options
+        }
+    }
+
+    fn update_value(
+        &mut self,
+        value: Option<NaiveDateTime>,
+        emit: bool,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let value = value.map(|value| {
+            let value = value.date().and_time(self.options.snap(value.time()));
+            clamp_option(value, self.min, self.max)
+        });
+        self.value = value;
+
+        let options = self.time_options(value.map(|value| value.date()));
+        self.calendar.update(cx, |view, cx| {
+            view.set_date(Date::Single(value.map(|value| value.date())), cx);
+        });
+        self.panel.update(cx, |view, cx| {
+            view.set_options(options, cx);
+            view.set_time(value.map(|value| value.time()), cx);
+        });
+
+        if emit {
+            cx.emit(DateTimePickerEvent::Change(value));
+        }
+        cx.notify();
+    }
+}
+
+impl EventEmitter<DateTimePickerEvent> for DateTimePicker {}
+impl Sizable for DateTimePicker {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.picker.size = size.into();
+        self
+    }
+}
+impl FocusableView for DateTimePicker {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.picker.focus_handle.clone()
+    }
+}
+
+impl Picker for DateTimePicker {
+    const CONTEXT: &'static str = "DateTimePicker";
+
+    fn picker(&self) -> &PickerState {
+        &self.picker
+    }
+
+    fn picker_mut(&mut self) -> &mut PickerState {
+        &mut self.picker
+    }
+
+    fn title(&self) -> Option<SharedString> {
+        self.value.map(|value| {
+            let format = format!("{} {}", self.date_format, self.options.format());
+            SharedString::from(value.format(&format).to_string())
+        })
+    }
+
+    fn default_placeholder(&self) -> SharedString {
+        t!("DateTimePicker.placeholder").into()
+    }
+
+    fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    fn clean(&mut self, cx: &mut ViewContext<Self>) {
+        self.update_value(None, true, cx);
+    }
+
+    fn opened(&mut self, cx: &mut ViewContext<Self>) {
+        self.update_value(self.value, false, cx);
+    }
+
+    fn render_popover(&mut self, cx: &mut ViewContext<Self>) -> AnyElement {
+        h_flex()
+            .items_start()
+            .gap_3()
+            .p_3()
+            .child(div().w(px(285.)).child(self.calendar.clone()))
+            .child(
+                div()
+                    .pl_3()
+                    .border_l_1()
+                    .border_color(cx.theme().border)
+                    .child(self.panel.clone()),
+            )
+            .into_any_element()
+    }
+}
+
+impl Render for DateTimePicker {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        render_picker(self, cx)
+    }
+}
@@ -0,0 +1,573 @@
+use std::rc::Rc;
+
+use chrono::{Datelike, Duration, Local, Months, NaiveDate};
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AppContext, ClickEvent, EventEmitter, FocusHandle,
+    FocusableView, Hsla, InteractiveElement as _, IntoElement, ParentElement as _, Render,
+    SharedString, StatefulInteractiveElement as _, Styled as _, ViewContext, WindowContext,
+};
+use rust_i18n::t;
+
+use crate::{
+    button::{Button, ButtonStyled as _},
+    h_flex,
+    theme::ActiveTheme as _,
+    v_flex, IconName, Selectable as _, Sizable as _, StyledExt as _,
+};
+
+use super::{calendar::Calendar, utils::days_in_month};
+
+/// An event on a day of the [`EventCalendar`].
+#[derive(Debug, Clone)]
+pub struct DayEvent {
+    title: SharedString,
+    color: Option<Hsla>,
+}
+
+impl DayEvent {
+    pub fn new(title: impl Into<SharedString>) -> Self {
+        Self {
+            title: title.into(),
+            color: None,
+        }
+    }
+
+    /// Set the color of the dot or chip, default is the primary color.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// The days shown by the [`EventCalendar`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CalendarMode {
+    #[default]
+    Month,
+    Week,
+}
+
+/// How the events are shown on the days of the month view, the week view always shows the chips.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EventDisplay {
+    #[default]
+    Dot,
+    Chip,
+}
+
+#[allow(unused)]
+pub trait EventCalendarDelegate: Sized + 'static {
+    /// Return the events on the date.
+    fn events(&self, date: NaiveDate, cx: &AppContext) -> Vec<DayEvent>;
+
+    /// Return true if the date can't be selected, default is false.
+    fn is_day_disabled(&self, date: NaiveDate) -> bool {
+        false
+    }
+}
+
+#[derive(Clone)]
+pub enum EventCalendarEvent {
+    /// The selected days are changed.
+    SelectionChange(Vec<NaiveDate>),
+    /// The visible days are changed, the start and end are inclusive, use it to load the events.
+    RangeChange(NaiveDate, NaiveDate),
+}
+
+/// A calendar to show the events of the days by month or week, the events are supplied by the [`EventCalendarDelegate`].
+pub struct EventCalendar<D: EventCalendarDelegate> {
+    focus_handle: FocusHandle,
+    delegate: D,
+    mode: CalendarMode,
+    display: EventDisplay,
+    /// The date to decide the visible month or week.
+    anchor: NaiveDate,
+    selected: Vec<NaiveDate>,
+    multiple: bool,
+    max_chips: usize,
+    on_day_click: Option<Rc<dyn Fn(&NaiveDate, &mut WindowContext) + 'static>>,
+}
+
+impl<D> EventCalendar<D>
+where
+    D: EventCalendarDelegate,
+{
+    pub fn new(delegate: D, cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            delegate,
+            mode: CalendarMode::default(),
+            display: EventDisplay::default(),
+            anchor: Local::now().naive_local().date(),
+            selected: vec![],
+            multiple: false,
+            max_chips: 3,
+            on_day_click: None,
+        }
+    }
+
+    /// Set the initial mode, default is [`CalendarMode::Month`].
+    pub fn mode(mut self, mode: CalendarMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set how the events are shown in the month view, default is [`EventDisplay::Dot`].
+    pub fn display(mut self, display: EventDisplay) -> Self {
+        self.display = display;
+        self
+    }
+
+    /// Set true to select multiple days by holding the `cmd` or `ctrl` key, default: false.
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    /// Set the max number of chips on a day of the month view, the rest are shown as "+N more", default: 3.
+    pub fn max_chips(mut self, max_chips: usize) -> Self {
+        self.max_chips = max_chips;
+        self
+    }
+
+    /// Set the callback when a day is clicked, it's called before the selection is changed.
+    pub fn on_day_click(
+        mut self,
+        handler: impl Fn(&NaiveDate, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_day_click = Some(Rc::new(handler));
+        self
+    }
+
+    pub fn delegate(&self) -> &D {
+        &self.delegate
+    }
+
+    /// Get the mutable delegate, call `cx.notify()` after the events are changed.
+    pub fn delegate_mut(&mut self) -> &mut D {
+        &mut self.delegate
+    }
+
+    pub fn current_mode(&self) -> CalendarMode {
+        self.mode
+    }
+
+    pub fn set_mode(&mut self, mode: CalendarMode, cx: &mut ViewContext<Self>) {
+        if self.mode == mode {
+            return;
+        }
+
+        self.mode = mode;
+        self.emit_range_change(cx);
+        cx.notify();
+    }
+
+    /// Return the selected days in ascending order.
+    pub fn selected_dates(&self) -> &[NaiveDate] {
+        &self.selected
+    }
+
+    /// Set the selected days without emitting the change.
+    pub fn set_selected_dates(&mut self, dates: Vec<NaiveDate>, cx: &mut ViewContext<Self>) {
+        self.selected = dates;
+        self.selected.sort();
+        self.selected.dedup();
+        cx.notify();
+    }
+
+    /// Show the month or week that contains the date.
+    pub fn go_to(&mut self, date: NaiveDate, cx: &mut ViewContext<Self>) {
+        let (start, end) = self.visible_range();
+        self.anchor = date;
+        if self.visible_range() != (start, end) {
+            self.emit_range_change(cx);
+        }
+        cx.notify();
+    }
+
+    /// Return the first and last visible days.
+    pub fn visible_range(&self) -> (NaiveDate, NaiveDate) {
+        let days = match self.mode {
+            CalendarMode::Month => month_weeks(self.anchor).concat(),
+            CalendarMode::Week => week_days(self.anchor),
+        };
+
+        (days[0], days[days.len() - 1])
+    }
+
+    fn emit_range_change(&self, cx: &mut ViewContext<Self>) {
+        let (start, end) = self.visible_range();
+        cx.emit(EventCalendarEvent::RangeChange(start, end));
+    }
+
+    fn prev(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
+        self.go_to(shift_anchor(self.anchor, self.mode, false), cx);
+    }
+
+    fn next(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
+        self.go_to(shift_anchor(self.anchor, self.mode, true), cx);
+    }
+
+    fn today(&mut self, _: &ClickEvent, cx: &mut ViewContext<Self>) {
+        self.go_to(Local::now().naive_local().date(), cx);
+    }
+
+    fn click_day(&mut self, date: NaiveDate, toggle: bool, cx: &mut ViewContext<Self>) {
+        if self.delegate.is_day_disabled(date) {
+            return;
+        }
+
+        if let Some(on_day_click) = self.on_day_click.clone() {
+            on_day_click(&date, cx);
+        }
+
+        if self.multiple && toggle {
+            if let Some(ix) = self.selected.iter().position(|d| *d == date) {
+                self.selected.remove(ix);
+            } else {
+                self.selected.push(date);
+                self.selected.sort();
+            }
+        } else if self.selected == [date] {
+            return;
+        } else {
+            self.selected = vec![date];
+        }
+
+        cx.emit(EventCalendarEvent::SelectionChange(self.selected.clone()));
+        cx.notify();
+    }
+
+    fn title(&self) -> SharedString {
+        let month = &Calendar::months()[self.anchor.month0() as usize];
+        format!("{} {}", month, self.anchor.year()).into()
+    }
+
+    fn render_header(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        h_flex()
+            .gap_2()
+            .justify_between()
+            .child(
+                h_flex()
+                    .gap_1()
+                    .child(
+                        Button::new("prev")
+                            .icon(IconName::ArrowLeft)
+                            .ghost()
+                            .small()
+                            .on_click(cx.listener(Self::prev)),
+                    )
+                    .child(
+                        Button::new("next")
+                            .icon(IconName::ArrowRight)
+                            .ghost()
+                            .small()
+                            .on_click(cx.listener(Self::next)),
+                    )
+                    .child(div().ml_1().font_semibold().child(self.title())),
+            )
+            .child(
+                h_flex()
+                    .gap_1()
+                    .child(
+                        Button::new("today")
+                            .outline()
+                            .small()
+                            .label(t!("EventCalendar.Today"))
+                            .on_click(cx.listener(Self::today)),
+                    )
+                    .child(
+                        Button::new("month")
+                            .ghost()
+                            .small()
+                            .label(t!("EventCalendar.Month"))
+                            .selected(self.mode == CalendarMode::Month)
+                            .on_click(
+                                cx.listener(|this, _, cx| this.set_mode(CalendarMode::Month, cx)),
+                            ),
+                    )
+                    .child(
+                        Button::new("week")
+                            .ghost()
+                            .small()
+                            .label(t!("EventCalendar.Week"))
+                            .selected(self.mode == CalendarMode::Week)
+                            .on_click(
+                                cx.listener(|this, _, cx| this.set_mode(CalendarMode::Week, cx)),
+                            ),
+                    ),
+            )
+    }
+
+    fn render_weekdays(&self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        h_flex().children(Calendar::weekdays().into_iter().map(|week| {
+            h_flex()
+                .flex_1()
+                .justify_center()
+                .py_1()
+                .text_xs()
+                .text_color(cx.theme().muted_foreground)
+                .child(week)
+        }))
+    }
+
+    fn render_chip(&self, event: &DayEvent, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let color = event.color.unwrap_or(cx.theme().primary);
+
+        div()
+            .w_full()
+            .px_1()
+            .rounded_sm()
+            .text_xs()
+            .overflow_hidden()
+            .text_ellipsis()
+            .whitespace_nowrap()
+            .bg(cx.theme().translucent(color, 0.15))
+            .text_color(cx.theme().foreground)
+            .border_l_2()
+            .border_color(color)
+            .child(event.title.clone())
+    }
+
+    fn render_events(&self, events: Vec<DayEvent>, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let chips = self.mode == CalendarMode::Week || self.display == EventDisplay::Chip;
+
+        if !chips {
+            return h_flex()
+                .gap_0p5()
+                .flex_wrap()
+                .justify_center()
+                .children(events.iter().map(|event| {
+                    div()
+                        .size_1p5()
+                        .rounded_full()
+                        .bg(event.color.unwrap_or(cx.theme().primary))
+                }));
+        }
+
+        let max = match self.mode {
+            CalendarMode::Month => self.max_chips,
+            CalendarMode::Week => usize::MAX,
+        };
+        let more_count = events.len().saturating_sub(max);
+
+        v_flex()
+            .w_full()
+            .gap_0p5()
+            .children(
+                events
+                    .iter()
+                    .take(max)
+                    .map(|event| self.render_chip(event, cx)),
+            )
+            .when(more_count > 0, |this| {
+                this.child(
+                    div()
+                        .px_1()
+                        .text_xs()
+                        .text_color(cx.theme().muted_foreground)
+                        .child(t!("EventCalendar.more", count = more_count).to_string()),
+                )
+            })
+    }
+
+    fn render_day(
+        &self,
+        date: NaiveDate,
+        today: NaiveDate,
+        cx: &mut ViewContext<Self>,
+    ) -> impl IntoElement {
+        let is_outside = self.mode == CalendarMode::Month && date.month() != self.anchor.month();
+        let is_selected = self.selected.contains(&date);
+        let is_disabled = self.delegate.is_day_disabled(date);
+        let events = self.delegate.events(date, cx);
+
+        v_flex()
+            .id(("day", date.num_days_from_ce() as usize))
+            .flex_1()
+            .min_w_0()
+            .map(|this| match self.mode {
+                CalendarMode::Month => this.h(px(96.)),
+                CalendarMode::Week => this.min_h(px(240.)),
+            })
+            .gap_1()
+            .p_1()
+            .border_t_1()
+            .border_l_1()
+            .border_color(cx.theme().border)
+            .when(is_outside, |this| {
+                this.bg(cx.theme().translucent(cx.theme().muted, 0.5))
+            })
+            .when(is_selected, |this| this.bg(cx.theme().accent))
+            .map(|this| {
+                if is_disabled {
+                    this.cursor_not_allowed()
+                        .text_color(cx.theme().translucent(cx.theme().muted_foreground, 0.5))
+                } else {
+                    this.cursor_pointer()
+                        .when(!is_selected, |this| {
+                            this.hover(|this| {
+                                this.bg(cx.theme().translucent(cx.theme().accent, 0.5))
+                            })
+                        })
+                        .on_click(cx.listener(move |this, e: &ClickEvent, cx| {
+                            this.click_day(date, e.down.modifiers.secondary(), cx)
+                        }))
+                }
+            })
+            .child(
+                h_flex().justify_center().child(
+                    h_flex()
+                        .size_6()
+                        .justify_center()
+                        .rounded_full()
+                        .text_sm()
+                        .when(is_outside, |this| {
+                            this.text_color(cx.theme().muted_foreground)
+                        })
+                        .when(date == today, |this| {
+                            this.bg(cx.theme().primary)
+                                .text_color(cx.theme().primary_foreground)
+                        })
+                        .child(date.day().to_string()),
+                ),
+            )
+            .child(self.render_events(events, cx))
+    }
+}
+
+/// Return the weeks from Sunday to Saturday that cover the month of the date, it's 4 to 6 weeks.
+fn month_weeks(date: NaiveDate) -> Vec<Vec<NaiveDate>> {
+    // The `days_in_month` always returns 5 weeks, remove the week out of the month or add the 6th week.
+    let mut weeks = days_in_month(date.year(), date.month());
+    weeks.retain(|week| week.iter().any(|day| day.month() == date.month()));
+
+    if let Some(last) = weeks.last().and_then(|week| week.last()).copied() {
+        if (last + Duration::days(1)).month() == date.month() {
+            weeks.push((1..=7).map(|n| last + Duration::days(n)).collect());
+        }
+    }
+
+    weeks
+}
+
+/// Return the days of the week from Sunday to Saturday that contains the date.
+fn week_days(date: NaiveDate) -> Vec<NaiveDate> {
+    month_weeks(date)
+        .into_iter()
+        .find(|week| week.contains(&date))
+        .unwrap_or_default()
+}
+
+/// Move the date to the previous or next month or week.
+fn shift_anchor(date: NaiveDate, mode: CalendarMode, forward: bool) -> NaiveDate {
+    match (mode, forward) {
+        (CalendarMode::Month, true) => date.checked_add_months(Months::new(1)),
+        (CalendarMode::Month, false) => date.checked_sub_months(Months::new(1)),
+        (CalendarMode::Week, true) => date.checked_add_signed(Duration::days(7)),
+        (CalendarMode::Week, false) => date.checked_sub_signed(Duration::days(7)),
+    }
+    .unwrap_or(date)
+}
+
+impl<D> EventEmitter<EventCalendarEvent> for EventCalendar<D> where D: EventCalendarDelegate {}
+
+impl<D> FocusableView for EventCalendar<D>
+where
+    D: EventCalendarDelegate,
+{
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl<D> Render for EventCalendar<D>
+where
+    D: EventCalendarDelegate,
+{
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let today = Local::now().naive_local().date();
+        let weeks = match self.mode {
+            CalendarMode::Month => month_weeks(self.anchor),
+            CalendarMode::Week => vec![week_days(self.anchor)],
+        };
+
+        v_flex()
+            .track_focus(&self.focus_handle)
+            .size_full()
+            .gap_2()
+            .child(self.render_header(cx))
+            .child(
+                v_flex()
+                    .border_r_1()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .rounded(px(cx.theme().radius))
+                    .overflow_hidden()
+                    .child(self.render_weekdays(cx))
+                    .children(weeks.into_iter().map(|week| {
+                        h_flex().items_start().children(
+                            week.into_iter()
+                                .map(|date| self.render_day(date, today, cx)),
+                        )
+                    })),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::{month_weeks, shift_anchor, week_days, CalendarMode};
+
+    #[test]
+    fn test_month_weeks() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // 2024-03 starts on Friday and ends on Sunday, it takes 6 weeks.
+        let weeks = month_weeks(date(2024, 3, 15));
+        assert_eq!(weeks.len(), 6);
+        assert_eq!(weeks[0][0], date(2024, 2, 25));
+        assert_eq!(weeks[5][0], date(2024, 3, 31));
+
+        // 2015-02 starts on Sunday and has 28 days, it takes 4 weeks.
+        let weeks = month_weeks(date(2015, 2, 1));
+        assert_eq!(weeks.len(), 4);
+        assert_eq!(weeks[3][6], date(2015, 2, 28));
+
+        let weeks = month_weeks(date(2024, 8, 1));
+        assert_eq!(weeks.len(), 5);
+        assert_eq!(weeks[0][0], date(2024, 7, 28));
+        assert_eq!(weeks[4][6], date(2024, 8, 31));
+    }
+
+    #[test]
+    fn test_week_days() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let days = week_days(date(2024, 8, 14));
+        assert_eq!(days.len(), 7);
+        assert_eq!(days[0], date(2024, 8, 11));
+        assert_eq!(days[6], date(2024, 8, 17));
+        assert_eq!(week_days(date(2024, 8, 11))[0], date(2024, 8, 11));
+    }
+
+    #[test]
+    fn test_shift_anchor() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(
+            shift_anchor(date(2024, 1, 31), CalendarMode::Month, true),
+            date(2024, 2, 29)
+        );
+        assert_eq!(
+            shift_anchor(date(2024, 1, 15), CalendarMode::Month, false),
+            date(2023, 12, 15)
+        );
+        assert_eq!(
+            shift_anchor(date(2024, 8, 30), CalendarMode::Week, true),
+            date(2024, 9, 6)
+        );
+    }
+}
@@ -1,3 +1,8 @@
 pub mod calendar;
 pub mod date_picker;
+pub mod date_range_picker;
+pub mod date_time_picker;
+pub mod event_calendar;
+pub mod time_picker;
+mod picker;
 mod utils;
@@ -0,0 +1,180 @@
+use gpui::{
+    anchored, deferred, div, prelude::FluentBuilder as _, px, AnyElement, AppContext, ClickEvent,
+    ElementId, FocusHandle, InteractiveElement as _, IntoElement, KeyBinding, Length, MouseButton,
+    ParentElement as _, Render, SharedString, StatefulInteractiveElement as _, Styled as _,
+    ViewContext,
+};
+
+use crate::{
+    dropdown::Escape, h_flex, input::ClearButton, theme::ActiveTheme as _, Icon, IconName, Size,
+    StyleSized as _, StyledExt as _,
+};
+
+use super::{
+    date_picker::DatePicker, date_range_picker::DateRangePicker, date_time_picker::DateTimePicker,
+    time_picker::TimePicker,
+};
+
+pub(super) fn init(cx: &mut AppContext) {
+    cx.bind_keys([
+        KeyBinding::new("escape", Escape, Some(DatePicker::CONTEXT)),
+        KeyBinding::new("escape", Escape, Some(DateRangePicker::CONTEXT)),
+        KeyBinding::new("escape", Escape, Some(DateTimePicker::CONTEXT)),
+        KeyBinding::new("escape", Escape, Some(TimePicker::CONTEXT)),
+    ])
+}
+
+/// The state of the input field and the popover, shared by the pickers.
+pub(super) struct PickerState {
+    pub(super) id: ElementId,
+    pub(super) focus_handle: FocusHandle,
+    pub(super) cleanable: bool,
+    pub(super) placeholder: Option<SharedString>,
+    pub(super) open: bool,
+    pub(super) size: Size,
+    pub(super) width: Length,
+}
+
+impl PickerState {
+    pub(super) fn new(id: impl Into<ElementId>, focus_handle: FocusHandle) -> Self {
+        Self {
+            id: id.into(),
+            focus_handle,
+            cleanable: false,
+            placeholder: None,
+            open: false,
+            size: Size::default(),
+            width: Length::Auto,
+        }
+    }
+}
+
+/// A view with the input field to show the value, and a popover to select it.
+pub(super) trait Picker: Render + Sized {
+    const CONTEXT: &'static str;
+    const ICON: IconName = IconName::Calendar;
+
+    fn picker(&self) -> &PickerState;
+    fn picker_mut(&mut self) -> &mut PickerState;
+
+    /// Return the formatted value, `None` to show the placeholder.
+    fn title(&self) -> Option<SharedString>;
+    /// Return the placeholder when the `placeholder` is not set.
+    fn default_placeholder(&self) -> SharedString;
+    fn has_value(&self) -> bool;
+    /// Clear the value and emit the change.
+    fn clean(&mut self, cx: &mut ViewContext<Self>);
+    /// Called when the popover is opened, to sync the panels with the value.
+    fn opened(&mut self, _: &mut ViewContext<Self>) {}
+    fn render_popover(&mut self, cx: &mut ViewContext<Self>) -> AnyElement;
+}
+
+fn escape<P: Picker>(this: &mut P, _: &Escape, cx: &mut ViewContext<P>) {
+    let picker = this.picker_mut();
+    picker.open = false;
+    picker.focus_handle.focus(cx);
+    cx.notify();
+}
+
+fn toggle<P: Picker>(this: &mut P, _: &ClickEvent, cx: &mut ViewContext<P>) {
+    let picker = this.picker_mut();
+    picker.open = !picker.open;
+    if picker.open {
+        this.opened(cx);
+    }
+    cx.notify();
+}
+
+/// Render the input field and the popover of the picker.
+pub(super) fn render_picker<P: Picker>(view: &mut P, cx: &mut ViewContext<P>) -> impl IntoElement {
+    let picker = view.picker();
+    let is_focused = picker.focus_handle.is_focused(cx);
+    let show_clean = picker.cleanable && view.has_value();
+    let display_title = view.title().unwrap_or_else(|| {
+        picker
+            .placeholder
+            .clone()
+            .unwrap_or_else(|| view.default_placeholder())
+    });
+    let (id, focus_handle, open, size, width) = (
+        picker.id.clone(),
+        picker.focus_handle.clone(),
+        picker.open,
+        picker.size,
+        picker.width,
+    );
+
+    div()
+        .id(id)
+        .key_context(P::CONTEXT)
+        .track_focus(&focus_handle)
+        .when(open, |this| this.on_action(cx.listener(escape)))
+        .w_full()
+        .relative()
+        .map(|this| match width {
+            Length::Definite(l) => this.flex_none().w(l),
+            Length::Auto => this.w_full(),
+        })
+        .input_text_size(size)
+        .child(
+            div()
+                .id("picker-input")
+                .relative()
+                .flex()
+                .items_center()
+                .justify_between()
+                .bg(cx.theme().background)
+                .border_1()
+                .border_color(cx.theme().input)
+                .rounded(px(cx.theme().radius))
+                .when(cx.theme().shadow, |this| this.shadow_sm())
+                .cursor_pointer()
+                .overflow_hidden()
+                .input_text_size(size)
+                .when(is_focused, |this| this.outline(cx))
+                .input_size(size, cx)
+                .when(!open, |this| this.on_click(cx.listener(toggle)))
+                .child(
+                    h_flex()
+                        .w_full()
+                        .items_center()
+                        .justify_between()
+                        .gap_1()
+                        .child(div().w_full().overflow_hidden().child(display_title))
+                        .when(show_clean, |this| {
+                            this.child(
+                                ClearButton::new(cx)
+                                    .on_click(cx.listener(|this, _, cx| this.clean(cx))),
+                            )
+                        })
+                        .when(!show_clean, |this| {
+                            this.child(Icon::new(P::ICON).text_color(cx.theme().muted_foreground))
+                        }),
+                ),
+        )
+        .when(open, |this| {
+            this.child(
+                deferred(
+                    anchored().snap_to_window_with_margin(px(8.)).child(
+                        div()
+                            .track_focus(&focus_handle)
+                            .occlude()
+                            .absolute()
+                            .mt_1p5()
+                            .overflow_hidden()
+                            .border_1()
+                            .border_color(cx.theme().border)
+                            .shadow_lg()
+                            .rounded_lg()
+                            .bg(cx.theme().background)
+                            .on_mouse_up_out(
+                                MouseButton::Left,
+                                cx.listener(|this, _, cx| escape(this, &Escape, cx)),
+                            )
+                            .child(view.render_popover(cx)),
+                    ),
+                )
+                .with_priority(2),
+            )
+        })
+}
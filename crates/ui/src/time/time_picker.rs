@@ -0,0 +1,534 @@
+use chrono::{NaiveTime, Timelike};
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnyElement, AppContext, ElementId, EventEmitter,
+    FocusHandle, FocusableView, InteractiveElement as _, IntoElement, Length, ParentElement as _,
+    Render, ScrollHandle, SharedString, StatefulInteractiveElement as _, Styled as _, View,
+    ViewContext, VisualContext as _,
+};
+use rust_i18n::t;
+
+use crate::{h_flex, theme::ActiveTheme as _, v_flex, IconName, Sizable, Size};
+
+use super::{
+    picker::{render_picker, Picker, PickerState},
+    utils::clamp_option,
+};
+
+/// The options of the time columns, shared by the [`TimePicker`] and the `DateTimePicker`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct TimeOptions {
+    pub(super) twelve_hour: bool,
+    pub(super) seconds: bool,
+    pub(super) minute_step: u32,
+    pub(super) second_step: u32,
+    pub(super) min: Option<NaiveTime>,
+    pub(super) max: Option<NaiveTime>,
+}
+
+impl Default for TimeOptions {
+    fn default() -> Self {
+        Self {
+            twelve_hour: false,
+            seconds: false,
+            minute_step: 1,
+            second_step: 1,
+            min: None,
+            max: None,
+        }
+    }
+}
+
+impl TimeOptions {
+    /// Return the format to display the time.
+    pub(super) fn format(&self) -> &'static str {
+        match (self.twelve_hour, self.seconds) {
+            (true, true) => "%I:%M:%S %p",
+            (true, false) => "%I:%M %p",
+            (false, true) => "%H:%M:%S",
+            (false, false) => "%H:%M",
+        }
+    }
+
+    /// Clamp the time to the `min` and `max`.
+    pub(super) fn clamp(&self, time: NaiveTime) -> NaiveTime {
+        clamp_option(time, self.min, self.max)
+    }
+
+    /// Round the minute and second down to the steps, then clamp the time to the `min` and `max`.
+    pub(super) fn snap(&self, time: NaiveTime) -> NaiveTime {
+        let minute = time.minute() - time.minute() % self.minute_step.max(1);
+        let second = if self.seconds {
+            time.second() - time.second() % self.second_step.max(1)
+        } else {
+            0
+        };
+        self.clamp(hms(time.hour(), minute, second))
+    }
+
+    /// Return true if no time from `start` to `end` is allowed by the `min` and `max`.
+    fn is_disabled(&self, start: NaiveTime, end: NaiveTime) -> bool {
+        self.min.map_or(false, |min| end < min) || self.max.map_or(false, |max| start > max)
+    }
+}
+
+/// Return the values from 0 to `count` by the `step`.
+fn column_values(count: u32, step: u32) -> Vec<u32> {
+    (0..count).step_by(step.max(1) as usize).collect()
+}
+
+fn hms(hour: u32, minute: u32, second: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(hour, minute, second).unwrap_or_default()
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Column {
+    Hour,
+    Minute,
+    Second,
+    Meridiem,
+}
+
+impl Column {
+    fn ix(&self) -> usize {
+        *self as usize
+    }
+}
+
+#[derive(Clone)]
+pub enum TimePanelEvent {
+    /// The user selected a time.
+    Selected(NaiveTime),
+}
+
+/// The columns to select the hour, minute, second and AM/PM, it's the panel of the [`TimePicker`].
+pub struct TimePanel {
+    focus_handle: FocusHandle,
+    time: Option<NaiveTime>,
+    options: TimeOptions,
+    scroll_handles: [ScrollHandle; 4],
+}
+
+impl TimePanel {
+    pub fn new(cx: &mut ViewContext<Self>) -> Self {
+        Self {
+            focus_handle: cx.focus_handle(),
+            time: None,
+            options: TimeOptions::default(),
+            scroll_handles: std::array::from_fn(|_| ScrollHandle::new()),
+        }
+    }
+
+    /// Get the time of the panel.
+    pub fn time(&self) -> Option<NaiveTime> {
+        self.time
+    }
+
+    /// Set the time of the panel, and scroll the columns to it.
+    pub fn set_time(&mut self, time: Option<NaiveTime>, cx: &mut ViewContext<Self>) {
+        self.time = time;
+        self.scroll_to_selected();
+        cx.notify();
+    }
+
+    pub(super) fn set_options(&mut self, options: TimeOptions, cx: &mut ViewContext<Self>) {
+        if self.options != options {
+            self.options = options;
+            cx.notify();
+        }
+    }
+
+    /// Scroll the columns to show the selected values.
+    fn scroll_to_selected(&self) {
+        let Some(time) = self.time else {
+            return;
+        };
+
+        let hour = if self.options.twelve_hour {
+            time.hour() % 12
+        } else {
+            time.hour()
+        };
+        let minute = time.minute() / self.options.minute_step.max(1);
+        let second = time.second() / self.options.second_step.max(1);
+
+        self.scroll_handles[Column::Hour.ix()].scroll_to_item(hour as usize);
+        self.scroll_handles[Column::Minute.ix()].scroll_to_item(minute as usize);
+        self.scroll_handles[Column::Second.ix()].scroll_to_item(second as usize);
+    }
+
+    fn select(&mut self, column: Column, value: u32, cx: &mut ViewContext<Self>) {
+        let current = self
+            .time
+            .unwrap_or_else(|| self.options.min.unwrap_or_default());
+
+        let time = match column {
+            Column::Hour if self.options.twelve_hour => {
+                let pm = current.hour() >= 12;
+                current.with_hour(value + if pm { 12 } else { 0 })
+            }
+            Column::Hour => current.with_hour(value),
+            Column::Minute => current.with_minute(value),
+            Column::Second => current.with_second(value),
+            Column::Meridiem => current.with_hour(current.hour() % 12 + value * 12),
+        }
+        .unwrap_or(current);
+
+        let time = self.options.clamp(time);
+        self.time = Some(time);
+        cx.emit(TimePanelEvent::Selected(time));
+        cx.notify();
+    }
+
+    /// Return the label and value of the items in the column, and if they are selected or disabled.
+    fn column_items(&self, column: Column) -> Vec<(SharedString, u32, bool, bool)> {
+        let time = self.time;
+        let options = &self.options;
+        let hour = time.map_or(0, |t| t.hour());
+        let minute = time.map_or(0, |t| t.minute());
+        let pm = hour >= 12;
+
+        match column {
+            Column::Hour if options.twelve_hour => column_values(12, 1)
+                .into_iter()
+                .map(|h| {
+                    let h24 = h + if pm { 12 } else { 0 };
+                    let label = if h == 0 { 12 } else { h };
+                    (
+                        format!("{:02}", label).into(),
+                        h,
+                        time.is_some() && h24 == hour,
+                        options.is_disabled(hms(h24, 0, 0), hms(h24, 59, 59)),
+                    )
+                })
+                .collect(),
+            Column::Hour => column_values(24, 1)
+                .into_iter()
+                .map(|h| {
+                    (
+                        format!("{:02}", h).into(),
+                        h,
+                        time.is_some() && h == hour,
+                        options.is_disabled(hms(h, 0, 0), hms(h, 59, 59)),
+                    )
+                })
+                .collect(),
+            Column::Minute => column_values(60, options.minute_step)
+                .into_iter()
+                .map(|m| {
+                    (
+                        format!("{:02}", m).into(),
+                        m,
+                        time.is_some() && m == minute,
+                        options.is_disabled(hms(hour, m, 0), hms(hour, m, 59)),
+                    )
+                })
+                .collect(),
+            Column::Second => column_values(60, options.second_step)
+                .into_iter()
+                .map(|s| {
+                    let t = hms(hour, minute, s);
+                    (
+                        format!("{:02}", s).into(),
+                        s,
+                        time.map_or(false, |time| time.second() == s),
+                        options.is_disabled(t, t),
+                    )
+                })
+                .collect(),
+            Column::Meridiem => [t!("TimePicker.AM"), t!("TimePicker.PM")]
+                .into_iter()
+                .enumerate()
+                .map(|(ix, label)| {
+                    let ix = ix as u32;
+                    (
+                        label.into(),
+                        ix,
+                        time.is_some() && pm as u32 == ix,
+                        options.is_disabled(hms(ix * 12, 0, 0), hms(ix * 12 + 11, 59, 59)),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn render_column(&self, column: Column, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        v_flex()
+            .id(("time-column", column.ix()))
+            .track_scroll(&self.scroll_handles[column.ix()])
+            .overflow_y_scroll()
+            .h(px(224.))
+            .w(px(52.))
+            .gap_0p5()
+            .text_sm()
+            .children(self.column_items(column).into_iter().map(
+                |(label, value, selected, disabled)| {
+                    h_flex()
+                        .id(("time-item", value as usize))
+                        .flex_shrink_0()
+                        .h_8()
+                        .rounded_md()
+                        .justify_center()
+                        .map(|this| {
+                            if selected {
+                                this.bg(cx.theme().primary)
+                                    .text_color(cx.theme().primary_foreground)
+                            } else if disabled {
+                                this.text_color(
+                                    cx.theme().translucent(cx.theme().muted_foreground, 0.5),
+                                )
+                                .cursor_not_allowed()
+                            } else {
+                                this.cursor_pointer().hover(|this| {
+                                    this.bg(cx.theme().accent)
+                                        .text_color(cx.theme().accent_foreground)
+                                })
+                            }
+                        })
+                        .when(!disabled, |this| {
+                            this.on_click(
+                                cx.listener(move |this, _, cx| this.select(column, value, cx)),
+                            )
+                        })
+                        .child(label)
+                },
+            ))
+    }
+}
+
+impl EventEmitter<TimePanelEvent> for TimePanel {}
+
+impl FocusableView for TimePanel {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl Render for TimePanel {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        h_flex()
+            .track_focus(&self.focus_handle)
+            .items_start()
+            .gap_1()
+            .child(self.render_column(Column::Hour, cx))
+            .child(self.render_column(Column::Minute, cx))
+            .when(self.options.seconds, |this| {
+                this.child(self.render_column(Column::Second, cx))
+            })
+            .when(self.options.twelve_hour, |this| {
+                this.child(self.render_column(Column::Meridiem, cx))
+            })
+    }
+}
+
+#[derive(Clone)]
+pub enum TimePickerEvent {
+    Change(Option<NaiveTime>),
+}
+
+/// A time picker with the hour, minute and second columns.
+pub struct TimePicker {
+    picker: PickerState,
+    time: Option<NaiveTime>,
+    options: TimeOptions,
+    panel: View<TimePanel>,
+}
+
+impl TimePicker {
+    pub fn new(id: impl Into<ElementId>, cx: &mut ViewContext<Self>) -> Self {
+        let panel = cx.new_view(TimePanel::new);
+
+        cx.subscribe(&panel, |this, _, ev: &TimePanelEvent, cx| match ev {
+            TimePanelEvent::Selected(time) => {
+                this.time = Some(*time);
+                cx.emit(TimePickerEvent::Change(this.time));
+                cx.notify();
+            }
+        })
+        .detach();
+
+        Self {
+            picker: PickerState::new(id, cx.focus_handle()),
+            time: None,
+            options: TimeOptions::default(),
+            panel,
+        }
+    }
+
+    /// Set true to use the 12-hour clock with AM/PM, default is the 24-hour clock.
+    pub fn twelve_hour(mut self, twelve_hour: bool) -> Self {
+        self.options.twelve_hour = twelve_hour;
+        self
+    }
+
+    /// Set true to show the seconds column, default: false.
+    pub fn seconds(mut self, seconds: bool) -> Self {
+        self.options.seconds = seconds;
+        self
+    }
+
+    /// Set the interval of the minutes, e.g.: 15 to only show 00, 15, 30 and 45, default: 1.
+    pub fn minute_step(mut self, step: u32) -> Self {
+        self.options.minute_step = step.max(1);
+        self
+    }
+
+    /// Set the interval of the seconds, default: 1.
+    pub fn second_step(mut self, step: u32) -> Self {
+        self.options.second_step = step.max(1);
+        self
+    }
+
+    /// Set the earliest time can be selected.
+    pub fn min(mut self, min: NaiveTime) -> Self {
+        self.options.min = Some(min);
+        self
+    }
+
+    /// Set the latest time can be selected.
+    pub fn max(mut self, max: NaiveTime) -> Self {
+        self.options.max = Some(max);
+        self
+    }
+
+    /// Set the placeholder of the time picker, default: "Select time".
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.picker.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Set true to show the clear button when the input field is not empty.
+    pub fn cleanable(mut self) -> Self {
+        self.picker.cleanable = true;
+        self
+    }
+
+    /// Set width of the time picker input field, default is `Length::Auto`.
+    pub fn width(mut self, width: impl Into<Length>) -> Self {
+        self.picker.width = width.into();
+        self
+    }
+
+    /// Get the time of the time picker.
+    pub fn time(&self) -> Option<NaiveTime> {
+        self.time
+    }
+
+    /// Set the time of the time picker, it's snapped to the steps and clamped to the min and max.
+    pub fn set_time(&mut self, time: Option<NaiveTime>, cx: &mut ViewContext<Self>) {
+        self.time = time.map(|time| self.options.snap(time));
+        cx.notify();
+    }
+}
+
+impl EventEmitter<TimePickerEvent> for TimePicker {}
+impl Sizable for TimePicker {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.picker.size = size.into();
+        self
+    }
+}
+impl FocusableView for TimePicker {
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.picker.focus_handle.clone()
+    }
+}
+
+impl Picker for TimePicker {
+    const CONTEXT: &'static str = "TimePicker";
+    const ICON: IconName = IconName::Clock;
+
+    fn picker(&self) -> &PickerState {
+        &self.picker
+    }
+
+    fn picker_mut(&mut self) -> &mut PickerState {
+        &mut self.picker
+    }
+
+    fn title(&self) -> Option<SharedString> {
+        self.time
+            .map(|time| SharedString::from(time.format(self.options.format()).to_string()))
+    }
+
+    fn default_placeholder(&self) -> SharedString {
+        t!("TimePicker.placeholder").into()
+    }
+
+    fn has_value(&self) -> bool {
+        self.time.is_some()
+    }
+
+    fn clean(&mut self, cx: &mut ViewContext<Self>) {
+        self.time = None;
+        cx.emit(TimePickerEvent::Change(None));
+        cx.notify();
+    }
+
+    fn opened(&mut self, cx: &mut ViewContext<Self>) {
+        let (time, options) = (self.time, self.options);
+        self.panel.update(cx, |panel, cx| {
+            panel.set_options(options, cx);
+            panel.set_time(time, cx);
+        });
+    }
+
+    fn render_popover(&mut self, _: &mut ViewContext<Self>) -> AnyElement {
+        div().p_1().child(self.panel.clone()).into_any_element()
+    }
+}
+
+impl Render for TimePicker {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        render_picker(self, cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{column_values, hms, TimeOptions};
+
+    #[test]
+    fn test_column_values() {
+        assert_eq!(column_values(60, 15), vec![0, 15, 30, 45]);
+        assert_eq!(column_values(12, 1).len(), 12);
+        assert_eq!(column_values(60, 0).len(), 60);
+    }
+
+    #[test]
+    fn test_time_options() {
+        let options = TimeOptions {
+            min: Some(hms(9, 30, 0)),
+            max: Some(hms(17, 0, 0)),
+            ..Default::default()
+        };
+
+        assert!(options.is_disabled(hms(8, 0, 0), hms(8, 59, 59)));
+        assert!(!options.is_disabled(hms(9, 0, 0), hms(9, 59, 59)));
+        assert!(!options.is_disabled(hms(17, 0, 0), hms(17, 59, 59)));
+        assert!(options.is_disabled(hms(17, 1, 0), hms(17, 1, 59)));
+
+        assert_eq!(options.clamp(hms(7, 0, 0)), hms(9, 30, 0));
+        assert_eq!(options.clamp(hms(18, 0, 0)), hms(17, 0, 0));
+        assert_eq!(options.clamp(hms(12, 0, 0)), hms(12, 0, 0));
+    }
+
+    #[test]
+    fn test_snap() {
+        let options = TimeOptions {
+            minute_step: 15,
+            min: Some(hms(9, 20, 0)),
+            ..Default::default()
+        };
+
+        assert_eq!(options.snap(hms(10, 7, 30)), hms(10, 0, 0));
+        assert_eq!(options.snap(hms(10, 59, 59)), hms(10, 45, 0));
+        assert_eq!(options.snap(hms(9, 25, 0)), hms(9, 20, 0));
+
+        let options = TimeOptions {
+            seconds: true,
+            second_step: 10,
+            ..Default::default()
+        };
+        assert_eq!(options.snap(hms(10, 7, 35)), hms(10, 7, 30));
+    }
+}
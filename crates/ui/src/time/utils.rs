@@ -85,6 +85,12 @@ pub(crate) fn days_in_month(year: i32, month: u32) -> Vec<Vec<NaiveDate>> {
     days
 }
 
+/// Clamp the value to the optional `min` and `max`.
+pub(crate) fn clamp_option<T: Ord + Copy>(value: T, min: Option<T>, max: Option<T>) -> T {
+    let value = min.map_or(value, |min| value.max(min));
+    max.map_or(value, |max| value.min(max))
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{Datelike, NaiveDate};
@@ -0,0 +1,402 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, AnyElement, ElementId, IntoElement, ParentElement as _,
+    Pixels, Render, SharedString, Styled as _, ViewContext, WindowContext,
+};
+
+use crate::{
+    button::{Button, ButtonStyled as _},
+    button_group::ButtonGroup,
+    divider::Divider,
+    h_flex,
+    overflow::OverflowWidths,
+    popup_menu::{PopupMenu, PopupMenuExt as _},
+    Disableable as _, Icon, IconName, Selectable as _, Sizable, Size,
+};
+
+/// The width of the more button for the overflowed items.
+const MORE_WIDTH: Pixels = px(32.);
+
+/// A button of the [`Toolbar`].
+#[derive(Clone)]
+pub struct ToolbarButton {
+    id: ElementId,
+    label: Option<SharedString>,
+    icon: Option<Icon>,
+    tooltip: Option<SharedString>,
+    disabled: bool,
+    on_click: Option<Rc<dyn Fn(&mut WindowContext)>>,
+}
+
+impl ToolbarButton {
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            label: None,
+            icon: None,
+            tooltip: None,
+            disabled: false,
+            on_click: None,
+        }
+    }
+
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn icon(mut self, icon: impl Into<Icon>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Set the tooltip, it's also used as the label in the overflow menu for the icon button.
+    pub fn tooltip(mut self, tooltip: impl Into<SharedString>) -> Self {
+        self.tooltip = Some(tooltip.into());
+        self
+    }
+
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    pub fn on_click(mut self, handler: impl Fn(&mut WindowContext) + 'static) -> Self {
+        self.on_click = Some(Rc::new(handler));
+        self
+    }
+
+    fn menu_label(&self) -> SharedString {
+        self.label
+            .clone()
+            .or(self.tooltip.clone())
+            .unwrap_or_default()
+    }
+
+    fn render(&self, selected: bool, size: Size) -> Button {
+        Button::new(self.id.clone())
+            .ghost()
+            .with_size(size)
+            .when_some(self.icon.clone(), |this, icon| this.icon(icon))
+            .when_some(self.label.clone(), |this, label| this.label(label))
+            .when_some(self.tooltip.clone(), |this, tooltip| this.tooltip(tooltip))
+            .disabled(self.disabled)
+            .selected(selected)
+    }
+}
+
+/// A group of the joined [`ToolbarButton`]s, the buttons can be toggled to share a selection.
+pub struct ToolbarGroup {
+    buttons: Vec<ToolbarButton>,
+    toggle: bool,
+    multiple: bool,
+    selected: Vec<usize>,
+    on_change: Option<Rc<dyn Fn(&Vec<usize>, &mut WindowContext)>>,
+}
+
+impl ToolbarGroup {
+    pub fn new() -> Self {
+        Self {
+            buttons: Vec::new(),
+            toggle: false,
+            multiple: false,
+            selected: Vec::new(),
+            on_change: None,
+        }
+    }
+
+    pub fn child(mut self, button: ToolbarButton) -> Self {
+        self.buttons.push(button);
+        self
+    }
+
+    /// Set true to toggle the selection of the buttons when clicked.
+    pub fn toggle(mut self, toggle: bool) -> Self {
+        self.toggle = toggle;
+        self
+    }
+
+    /// Set true to allow multiple buttons to be selected in the toggle mode.
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    /// Set the indices of the selected buttons.
+    pub fn selected(mut self, selected: Vec<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Set the handler when the selection is changed, with the indices of the selected buttons.
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(&Vec<usize>, &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    fn click(&mut self, ix: usize, cx: &mut WindowContext) {
+        if self.buttons.get(ix).map_or(true, |b| b.disabled) {
+            return;
+        }
+
+        if self.toggle {
+            if !self.multiple {
+                self.selected = vec![ix];
+            } else if let Some(pos) = self.selected.iter().position(|&i| i == ix) {
+                self.selected.remove(pos);
+            } else {
+                self.selected.push(ix);
+            }
+
+            if let Some(on_change) = &self.on_change {
+                on_change(&self.selected, cx);
+            }
+        }
+
+        if let Some(on_click) = self.buttons.get(ix).and_then(|b| b.on_click.clone()) {
+            on_click(cx);
+        }
+    }
+}
+
+enum ToolbarItem {
+    Button(ToolbarButton),
+    Group(ToolbarGroup),
+    Separator,
+}
+
+/// Return the number of the items that fit in the available width, with the more button for the rest.
+fn visible_count(widths: &[Pixels], available: Pixels) -> usize {
+    let total = widths.iter().fold(px(0.), |sum, width| sum + *width);
+    if total <= available {
+        return widths.len();
+    }
+
+    let mut width = MORE_WIDTH;
+    widths
+        .iter()
+        .take_while(|w| {
+            width = width + **w;
+            width <= available
+        })
+        .count()
+}
+
+/// Return the button that toggled between the old and the new selection of a multiple [`ButtonGroup`].
+fn toggled_ix(old: &[usize], new: &[usize]) -> Option<usize> {
+    new.iter()
+        .find(|ix| !old.contains(ix))
+        .or_else(|| old.iter().find(|ix| !new.contains(ix)))
+        .copied()
+}
+
+/// A row of the buttons and the groups, the items that not fit are moved into the more menu.
+pub struct Toolbar {
+    items: Vec<ToolbarItem>,
+    size: Size,
+    widths: OverflowWidths,
+}
+
+impl Toolbar {
+    pub fn new(_: &mut ViewContext<Self>) -> Self {
+        Self {
+            items: Vec::new(),
+            size: Size::Small,
+            widths: OverflowWidths::new(),
+        }
+    }
+
+    fn push(mut self, item: ToolbarItem) -> Self {
+        self.items.push(item);
+        self.widths.push();
+        self
+    }
+
+    pub fn button(self, button: ToolbarButton) -> Self {
+        self.push(ToolbarItem::Button(button))
+    }
+
+    pub fn group(self, group: ToolbarGroup) -> Self {
+        self.push(ToolbarItem::Group(group))
+    }
+
+    pub fn separator(self) -> Self {
+        self.push(ToolbarItem::Separator)
+    }
+
+    fn click(&mut self, item_ix: usize, button_ix: usize, cx: &mut ViewContext<Self>) {
+        match self.items.get_mut(item_ix) {
+            Some(ToolbarItem::Button(button)) if !button.disabled => {
+                if let Some(on_click) = button.on_click.clone() {
+                    on_click(cx);
+                }
+            }
+            Some(ToolbarItem::Group(group)) => group.click(button_ix, cx),
+            _ => {}
+        }
+        cx.notify();
+    }
+
+    fn on_group_click(
+        &mut self,
+        item_ix: usize,
+        selected: &Vec<usize>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let Some(ToolbarItem::Group(group)) = self.items.get(item_ix) else {
+            return;
+        };
+
+        let button_ix = if group.toggle && group.multiple {
+            toggled_ix(&group.selected, selected)
+        } else {
+            selected.first().copied()
+        };
+
+        if let Some(button_ix) = button_ix {
+            self.click(item_ix, button_ix, cx);
+        }
+    }
+
+    fn render_item(&self, ix: usize, cx: &mut ViewContext<Self>) -> AnyElement {
+        match &self.items[ix] {
+            ToolbarItem::Button(button) => button
+                .render(false, self.size)
+                .on_click(cx.listener(move |this, _, cx| this.click(ix, 0, cx)))
+                .into_any_element(),
+            ToolbarItem::Group(group) => ButtonGroup::new(("toolbar-group", ix))
+                .ghost()
+                .with_size(self.size)
+                .multiple(group.toggle && group.multiple)
+                .children(group.buttons.iter().enumerate().map(|(button_ix, button)| {
+                    button.render(
+                        group.toggle && group.selected.contains(&button_ix),
+                        self.size,
+                    )
+                }))
+                .on_click(cx.listener(move |this, selected: &Vec<usize>, cx| {
+                    this.on_group_click(ix, selected, cx)
+                }))
+                .into_any_element(),
+            ToolbarItem::Separator => div()
+                .h_5()
+                .mx_1()
+                .child(Divider::vertical())
+                .into_any_element(),
+        }
+    }
+
+    fn render_more(&self, start: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().downgrade();
+        // The (item_ix, button_ix, label, icon) of the menu items, None for the separator.
+        let entries = self.items[start..]
+            .iter()
+            .enumerate()
+            .flat_map(|(offset, item)| {
+                let item_ix = start + offset;
+                match item {
+                    ToolbarItem::Button(button) => {
+                        vec![Some((item_ix, 0, button.menu_label(), button.icon.clone()))]
+                    }
+                    ToolbarItem::Group(group) => group
+                        .buttons
+                        .iter()
+                        .enumerate()
+                        .map(|(button_ix, button)| {
+                            let icon = if group.toggle && group.selected.contains(&button_ix) {
+                                Some(Icon::new(IconName::Check))
+                            } else {
+                                button.icon.clone()
+                            };
+                            Some((item_ix, button_ix, button.menu_label(), icon))
+                        })
+                        .collect(),
+                    ToolbarItem::Separator => vec![None],
+                }
+            })
+            .collect::<Vec<_>>();
+
+        Button::new("toolbar-more")
+            .icon(IconName::Ellipsis)
+            .ghost()
+            .with_size(self.size)
+            .popup_menu(move |menu: PopupMenu, _| {
+                entries
+                    .iter()
+                    .fold(menu, |menu, entry| match entry.clone() {
+                        Some((item_ix, button_ix, label, icon)) => {
+                            let view = view.clone();
+                            menu.menu_with_handler(label, icon, move |cx| {
+                                _ = view.update(cx, |this, cx| this.click(item_ix, button_ix, cx));
+                            })
+                        }
+                        None => menu.separator(),
+                    })
+            })
+    }
+}
+
+impl Sizable for Toolbar {
+    fn with_size(mut self, size: impl Into<Size>) -> Self {
+        self.size = size.into();
+        self
+    }
+}
+
+impl Render for Toolbar {
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let visible = visible_count(&self.widths.items, self.widths.width);
+
+        h_flex()
+            .relative()
+            .w_full()
+            .overflow_hidden()
+            .children((0..visible).map(|ix| {
+                let view = view.clone();
+                h_flex()
+                    .relative()
+                    .flex_shrink_0()
+                    .pr_1()
+                    .child(self.render_item(ix, cx))
+                    .child(OverflowWidths::measure(
+                        view,
+                        Some(ix),
+                        |this: &mut Self| &mut this.widths,
+                    ))
+            }))
+            .when(visible < self.items.len(), |this| {
+                this.child(self.render_more(visible, cx))
+            })
+            .child(OverflowWidths::measure(view, None, |this: &mut Self| {
+                &mut this.widths
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::px;
+
+    use super::{toggled_ix, visible_count};
+
+    #[test]
+    fn test_visible_count() {
+        let widths = [px(40.), px(80.), px(10.), px(60.)];
+        assert_eq!(visible_count(&widths, px(200.)), 4);
+        assert_eq!(visible_count(&widths, px(180.)), 3);
+        assert_eq!(visible_count(&widths, px(100.)), 1);
+        assert_eq!(visible_count(&widths, px(50.)), 0);
+    }
+
+    #[test]
+    fn test_toggled_ix() {
+        assert_eq!(toggled_ix(&[0, 2], &[0, 1, 2]), Some(1));
+        assert_eq!(toggled_ix(&[0, 2], &[0]), Some(2));
+        assert_eq!(toggled_ix(&[0], &[0]), None);
+    }
+}
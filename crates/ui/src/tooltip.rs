@@ -1,26 +1,82 @@
+use std::{cell::RefCell, rc::Rc, time::Duration};
+
 use gpui::{
-    div, px, AnyView, IntoElement, ParentElement, Render, SharedString, Styled, ViewContext,
-    VisualContext, WindowContext,
+    anchored, deferred, div, point, prelude::FluentBuilder as _, px, Action, AnyElement, AnyView,
+    DispatchPhase, Element, ElementId, GlobalElementId, Hitbox, IntoElement, KeyDownEvent,
+    LayoutId, MouseDownEvent, MouseMoveEvent, ParentElement, Pixels, Point, Render,
+    ScrollWheelEvent, SharedString, Style, Styled, Task, Timer, ViewContext, VisualContext,
+    WindowContext,
 };
 
-use crate::theme::ActiveTheme;
+use crate::{h_flex, kbd::Kbd, theme::ActiveTheme};
+
+/// The default delay before showing the tooltip.
+const SHOW_DELAY: Duration = Duration::from_millis(500);
+
+enum TooltipContent {
+    Text(SharedString),
+    Element(Rc<dyn Fn(&mut WindowContext) -> AnyElement>),
+}
 
 pub struct Tooltip {
-    text: SharedString,
+    content: TooltipContent,
+    key_binding: Option<Kbd>,
 }
 
 impl Tooltip {
+    /// Create a tooltip with the text, the text can be multi-line.
     pub fn new(text: impl Into<SharedString>, cx: &mut WindowContext) -> AnyView {
-        cx.new_view(|_| Self { text: text.into() }).into()
+        cx.new_view(|_| Self {
+            content: TooltipContent::Text(text.into()),
+            key_binding: None,
+        })
+        .into()
+    }
+
+    /// Create a tooltip with the text and the key binding of the action, e.g.: "Save ⌘S".
+    pub fn for_action(
+        text: impl Into<SharedString>,
+        action: &dyn Action,
+        cx: &mut WindowContext,
+    ) -> AnyView {
+        let key_binding = Kbd::binding_for_action(action, cx);
+
+        cx.new_view(|_| Self {
+            content: TooltipContent::Text(text.into()),
+            key_binding,
+        })
+        .into()
+    }
+
+    /// Create a tooltip with the rich content.
+    pub fn element<E>(
+        content: impl Fn(&mut WindowContext) -> E + 'static,
+        cx: &mut WindowContext,
+    ) -> AnyView
+    where
+        E: IntoElement,
+    {
+        cx.new_view(|_| Self {
+            content: TooltipContent::Element(Rc::new(move |cx| content(cx).into_any_element())),
+            key_binding: None,
+        })
+        .into()
     }
 }
 
 impl Render for Tooltip {
     fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let content = match &self.content {
+            TooltipContent::Text(text) => text.clone().into_any_element(),
+            TooltipContent::Element(builder) => builder(cx),
+        };
+
         div().child(
             // Wrap in a child, to ensure the left margin is applied to the tooltip
-            div()
+            h_flex()
                 .m_3()
+                .gap_3()
+                .max_w(px(360.))
                 .bg(cx.theme().popover)
                 .text_color(cx.theme().popover_foreground)
                 .bg(cx.theme().popover)
@@ -31,7 +87,266 @@ impl Render for Tooltip {
                 .py_0p5()
                 .px_2()
                 .text_sm()
-                .child(self.text.clone()),
+                .child(div().flex_1().child(content))
+                .children(self.key_binding.clone()),
         )
     }
 }
+
+/// Extension trait to attach a [`WithTooltip`] to any element.
+pub trait TooltipExt: IntoElement + Sized {
+    /// Show the tooltip when hovering the element, with configurable show and hide delays.
+    ///
+    /// Unlike the `tooltip` of GPUI, the tooltip is dismissed on scroll or Escape.
+    fn with_tooltip(
+        self,
+        id: impl Into<ElementId>,
+        build: impl Fn(&mut WindowContext) -> AnyView + 'static,
+    ) -> WithTooltip {
+        WithTooltip::new(id, self, build)
+    }
+}
+
+impl<E: IntoElement> TooltipExt for E {}
+
+/// An element wrapper to show a [`Tooltip`] when hovering the child element.
+pub struct WithTooltip {
+    id: ElementId,
+    child: Option<AnyElement>,
+    build: Rc<dyn Fn(&mut WindowContext) -> AnyView>,
+    delay: Duration,
+    hide_delay: Duration,
+}
+
+impl WithTooltip {
+    pub fn new(
+        id: impl Into<ElementId>,
+        child: impl IntoElement,
+        build: impl Fn(&mut WindowContext) -> AnyView + 'static,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            child: Some(child.into_any_element()),
+            build: Rc::new(build),
+            delay: SHOW_DELAY,
+            hide_delay: Duration::ZERO,
+        }
+    }
+
+    /// Set the delay before showing the tooltip, default is 500ms.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Set the delay before hiding the tooltip after the mouse leaves, default is 0.
+    pub fn hide_delay(mut self, hide_delay: Duration) -> Self {
+        self.hide_delay = hide_delay;
+        self
+    }
+}
+
+impl IntoElement for WithTooltip {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+#[derive(Default)]
+struct TooltipState {
+    view: Option<AnyView>,
+    position: Point<Pixels>,
+    show_task: Option<Task<()>>,
+    hide_task: Option<Task<()>>,
+    /// Dismissed by scroll or Escape, will not show again until the mouse leaves.
+    dismissed: bool,
+}
+
+impl TooltipState {
+    fn hide(&mut self) {
+        self.view = None;
+        self.show_task = None;
+        self.hide_task = None;
+    }
+}
+
+pub struct WithTooltipLayoutState {
+    child_element: Option<AnyElement>,
+    tooltip_element: Option<AnyElement>,
+}
+
+impl Element for WithTooltip {
+    type RequestLayoutState = WithTooltipLayoutState;
+    type PrepaintState = Option<Hitbox>;
+
+    fn id(&self) -> Option<ElementId> {
+        Some(self.id.clone())
+    }
+
+    fn request_layout(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        cx: &mut WindowContext,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let state =
+            cx.with_element_state::<Rc<RefCell<TooltipState>>, _>(id.unwrap(), |state, _| {
+                let state = state.unwrap_or_default();
+                (state.clone(), state)
+            });
+
+        let mut child_element = self.child.take().unwrap();
+        let child_layout_id = child_element.request_layout(cx);
+
+        let mut tooltip_element = None;
+        let mut tooltip_layout_id = None;
+        if let Some(view) = state.borrow().view.clone() {
+            let mut element = deferred(
+                anchored()
+                    .snap_to_window_with_margin(px(8.))
+                    .position(state.borrow().position)
+                    .child(view),
+            )
+            .with_priority(2)
+            .into_any();
+            tooltip_layout_id = Some(element.request_layout(cx));
+            tooltip_element = Some(element);
+        }
+
+        let layout_id = cx.request_layout(
+            Style::default(),
+            Some(child_layout_id).into_iter().chain(tooltip_layout_id),
+        );
+
+        (
+            layout_id,
+            WithTooltipLayoutState {
+                child_element: Some(child_element),
+                tooltip_element,
+            },
+        )
+    }
+
+    fn prepaint(
+        &mut self,
+        _: Option<&GlobalElementId>,
+        bounds: gpui::Bounds<Pixels>,
+        request_layout: &mut Self::RequestLayoutState,
+        cx: &mut WindowContext,
+    ) -> Self::PrepaintState {
+        if let Some(element) = request_layout.child_element.as_mut() {
+            element.prepaint(cx);
+        }
+        if let Some(element) = request_layout.tooltip_element.as_mut() {
+            element.prepaint(cx);
+        }
+
+        Some(cx.insert_hitbox(bounds, false))
+    }
+
+    fn paint(
+        &mut self,
+        id: Option<&GlobalElementId>,
+        _: gpui::Bounds<Pixels>,
+        request_layout: &mut Self::RequestLayoutState,
+        hitbox: &mut Self::PrepaintState,
+        cx: &mut WindowContext,
+    ) {
+        if let Some(mut element) = request_layout.child_element.take() {
+            element.paint(cx);
+        }
+        if let Some(mut element) = request_layout.tooltip_element.take() {
+            element.paint(cx);
+        }
+
+        let Some(hitbox) = hitbox.take() else {
+            return;
+        };
+        let state =
+            cx.with_element_state::<Rc<RefCell<TooltipState>>, _>(id.unwrap(), |state, _| {
+                let state = state.unwrap_or_default();
+                (state.clone(), state)
+            });
+
+        cx.on_mouse_event({
+            let state = state.clone();
+            let build = self.build.clone();
+            let delay = self.delay;
+            let hide_delay = self.hide_delay;
+            move |event: &MouseMoveEvent, phase, cx| {
+                if phase != DispatchPhase::Bubble {
+                    return;
+                }
+
+                let mut this = state.borrow_mut();
+                if hitbox.is_hovered(cx) {
+                    this.hide_task = None;
+                    if this.view.is_some() || this.show_task.is_some() || this.dismissed {
+                        return;
+                    }
+
+                    this.position = event.position + point(px(0.), px(8.));
+                    let state = state.clone();
+                    let build = build.clone();
+                    this.show_task = Some(cx.spawn(|mut cx| async move {
+                        Timer::after(delay).await;
+                        _ = cx.update(|cx| {
+                            let view = build(cx);
+                            let mut this = state.borrow_mut();
+                            this.view = Some(view);
+                            this.show_task = None;
+                            cx.refresh();
+                        });
+                    }));
+                } else {
+                    this.show_task = None;
+                    this.dismissed = false;
+                    if this.view.is_none() || this.hide_task.is_some() {
+                        return;
+                    }
+
+                    let state = state.clone();
+                    this.hide_task = Some(cx.spawn(|mut cx| async move {
+                        Timer::after(hide_delay).await;
+                        _ = cx.update(|cx| {
+                            state.borrow_mut().hide();
+                            cx.refresh();
+                        });
+                    }));
+                }
+            }
+        });
+
+        let dismiss = move |state: &Rc<RefCell<TooltipState>>, cx: &mut WindowContext| {
+            let mut this = state.borrow_mut();
+            if this.view.is_some() || this.show_task.is_some() {
+                this.hide();
+                this.dismissed = true;
+                cx.refresh();
+            }
+        };
+
+        cx.on_mouse_event({
+            let state = state.clone();
+            move |_: &ScrollWheelEvent, phase, cx| {
+                if phase == DispatchPhase::Capture {
+                    dismiss(&state, cx);
+                }
+            }
+        });
+        cx.on_mouse_event({
+            let state = state.clone();
+            move |_: &MouseDownEvent, phase, cx| {
+                if phase == DispatchPhase::Capture {
+                    dismiss(&state, cx);
+                }
+            }
+        });
+        cx.on_key_event(move |event: &KeyDownEvent, phase, cx| {
+            if phase == DispatchPhase::Capture && event.keystroke.key == "escape" {
+                dismiss(&state, cx);
+            }
+        });
+    }
+}
@@ -0,0 +1,401 @@
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder as _, px, rems, AppContext, ElementId, InteractiveElement as _,
+    IntoElement, ParentElement as _, Render, SharedString, StatefulInteractiveElement as _,
+    Styled as _, Subscription, View, ViewContext, VisualContext as _, WindowContext,
+};
+use rust_i18n::t;
+
+use crate::{
+    button::Button,
+    checkbox::{Checkbox, CheckboxState},
+    dropdown::DropdownItem,
+    h_flex,
+    input::{InputEvent, TextInput},
+    list::fuzzy_match,
+    theme::ActiveTheme as _,
+    v_flex, Disableable as _, IconName, Sizable as _, StyledExt as _,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Available,
+    Selected,
+}
+
+impl Side {
+    fn id(&self) -> &'static str {
+        match self {
+            Self::Available => "transfer-available",
+            Self::Selected => "transfer-selected",
+        }
+    }
+}
+
+/// Move the `values` into or out of the `selected`, the result is in the order of the `all`.
+fn transfer<V: Clone + PartialEq>(
+    all: &[V],
+    selected: &[V],
+    values: &[V],
+    to_selected: bool,
+) -> Vec<V> {
+    all.iter()
+        .filter(|value| {
+            if to_selected {
+                selected.contains(value) || values.contains(value)
+            } else {
+                selected.contains(value) && !values.contains(value)
+            }
+        })
+        .cloned()
+        .collect()
+}
+
+/// Two searchable lists to move the items between the "Available" and the "Selected".
+pub struct Transfer<T: DropdownItem + Clone + 'static> {
+    id: ElementId,
+    items: Vec<T>,
+    selected_values: Vec<T::Value>,
+    /// The checked items to move, on both sides.
+    checked_values: Vec<T::Value>,
+    available_title: Option<SharedString>,
+    selected_title: Option<SharedString>,
+    available_input: View<TextInput>,
+    selected_input: View<TextInput>,
+    searchable: bool,
+    on_change: Option<Rc<dyn Fn(&[T::Value], &[T::Value], &mut WindowContext)>>,
+    _subscriptions: Vec<Subscription>,
+}
+
+impl<T> Transfer<T>
+where
+    T: DropdownItem + Clone + 'static,
+    T::Value: PartialEq,
+{
+    pub fn new(id: impl Into<ElementId>, items: Vec<T>, cx: &mut ViewContext<Self>) -> Self {
+        let new_input = |cx: &mut ViewContext<Self>| {
+            cx.new_view(|cx| {
+                TextInput::new(cx)
+                    .prefix(|_| IconName::Search)
+                    .placeholder(t!("Transfer.Search"))
+                    .cleanable()
+            })
+        };
+        let available_input = new_input(cx);
+        let selected_input = new_input(cx);
+        let _subscriptions = [&available_input, &selected_input]
+            .into_iter()
+            .map(|input| {
+                cx.subscribe(input, |_, _, event: &InputEvent, cx| {
+                    if let InputEvent::Change(_) = event {
+                        cx.notify();
+                    }
+                })
+            })
+            .collect();
+
+        Self {
+            id: id.into(),
+            items,
+            selected_values: Vec::new(),
+            checked_values: Vec::new(),
+            available_title: None,
+            selected_title: None,
+            available_input,
+            selected_input,
+            searchable: true,
+            on_change: None,
+            _subscriptions,
+        }
+    }
+
+    /// Set the titles of the lists, default: "Available" and "Selected".
+    pub fn titles(
+        mut self,
+        available: impl Into<SharedString>,
+        selected: impl Into<SharedString>,
+    ) -> Self {
+        self.available_title = Some(available.into());
+        self.selected_title = Some(selected.into());
+        self
+    }
+
+    /// Set false to hide the search inputs, default: true.
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.searchable = searchable;
+        self
+    }
+
+    /// Set the handler of the moves, with the available and the selected values.
+    pub fn on_change(
+        mut self,
+        handler: impl Fn(&[T::Value], &[T::Value], &mut WindowContext) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Return the selected values in the order of the items.
+    pub fn selected_values(&self) -> &[T::Value] {
+        &self.selected_values
+    }
+
+    /// Return the available values in the order of the items.
+    pub fn available_values(&self) -> Vec<T::Value> {
+        self.items
+            .iter()
+            .filter(|item| !self.is_selected(item))
+            .map(|item| item.value().clone())
+            .collect()
+    }
+
+    pub fn set_selected_values(&mut self, values: Vec<T::Value>, cx: &mut ViewContext<Self>) {
+        let all = self.all_values();
+        self.selected_values = transfer(&all, &[], &values, true);
+        self.checked_values.clear();
+        cx.notify();
+    }
+
+    fn all_values(&self) -> Vec<T::Value> {
+        self.items.iter().map(|item| item.value().clone()).collect()
+    }
+
+    fn is_selected(&self, item: &T) -> bool {
+        self.selected_values.contains(item.value())
+    }
+
+    fn is_checked(&self, item: &T) -> bool {
+        self.checked_values.contains(item.value())
+    }
+
+    fn input(&self, side: Side) -> &View<TextInput> {
+        match side {
+            Side::Available => &self.available_input,
+            Side::Selected => &self.selected_input,
+        }
+    }
+
+    /// Return the items on the side, filtered by the query of the side.
+    fn side_items(&self, side: Side, cx: &AppContext) -> Vec<(usize, &T)> {
+        let query = self.input(side).read(cx).text();
+
+        self.items
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| self.is_selected(item) == (side == Side::Selected))
+            .filter(|(_, item)| query.is_empty() || fuzzy_match(&query, &item.title()).is_some())
+            .collect()
+    }
+
+    /// Return the checked values on the side that can be moved.
+    fn checked_on(&self, side: Side, cx: &AppContext) -> Vec<T::Value> {
+        self.side_items(side, cx)
+            .into_iter()
+            .filter(|(_, item)| !item.disabled() && self.is_checked(item))
+            .map(|(_, item)| item.value().clone())
+            .collect()
+    }
+
+    fn toggle_checked(&mut self, value: &T::Value, cx: &mut ViewContext<Self>) {
+        if self.checked_values.contains(value) {
+            self.checked_values.retain(|v| v != value);
+        } else {
+            self.checked_values.push(value.clone());
+        }
+        cx.notify();
+    }
+
+    /// Check or uncheck all the visible items on the side.
+    fn check_all(&mut self, side: Side, checked: bool, cx: &mut ViewContext<Self>) {
+        let values: Vec<T::Value> = self
+            .side_items(side, cx)
+            .into_iter()
+            .filter(|(_, item)| !item.disabled())
+            .map(|(_, item)| item.value().clone())
+            .collect();
+
+        self.checked_values.retain(|value| !values.contains(value));
+        if checked {
+            self.checked_values.extend(values);
+        }
+        cx.notify();
+    }
+
+    /// Move the checked items to the side.
+    fn move_to(&mut self, side: Side, cx: &mut ViewContext<Self>) {
+        let from = match side {
+            Side::Available => Side::Selected,
+            Side::Selected => Side::Available,
+        };
+        let values = self.checked_on(from, cx);
+        if values.is_empty() {
+            return;
+        }
+
+        let all = self.all_values();
+        self.selected_values =
+            transfer(&all, &self.selected_values, &values, side == Side::Selected);
+        self.checked_values.retain(|value| !values.contains(value));
+        cx.notify();
+
+        if let Some(on_change) = self.on_change.clone() {
+            on_change(&self.available_values(), &self.selected_values, cx);
+        }
+    }
+
+    fn render_list(&self, side: Side, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let items = self.side_items(side, cx);
+        let checkable = items.iter().filter(|(_, item)| !item.disabled()).count();
+        let checked = items
+            .iter()
+            .filter(|(_, item)| !item.disabled() && self.is_checked(item))
+            .count();
+        let total = self
+            .items
+            .iter()
+            .filter(|item| self.is_selected(item) == (side == Side::Selected))
+            .count();
+        let title = match side {
+            Side::Available => self
+                .available_title
+                .clone()
+                .unwrap_or_else(|| t!("Transfer.Available").into()),
+            Side::Selected => self
+                .selected_title
+                .clone()
+                .unwrap_or_else(|| t!("Transfer.Selected").into()),
+        };
+
+        v_flex()
+            .id(side.id())
+            .flex_1()
+            .min_w(px(200.))
+            .border_1()
+            .border_color(cx.theme().border)
+            .rounded(px(cx.theme().radius))
+            .overflow_hidden()
+            .child(
+                h_flex()
+                    .gap_2()
+                    .px_3()
+                    .py_2()
+                    .border_b_1()
+                    .border_color(cx.theme().border)
+                    .bg(cx.theme().muted)
+                    .child(
+                        Checkbox::new("check-all")
+                            .state(CheckboxState::from_count(checked, checkable))
+                            .disabled(checkable == 0)
+                            .on_click(cx.listener(move |this, checked: &bool, cx| {
+                                this.check_all(side, *checked, cx)
+                            })),
+                    )
+                    .child(div().flex_1().text_sm().font_medium().child(title))
+                    .child(
+                        div()
+                            .text_xs()
+                            .text_color(cx.theme().muted_foreground)
+                            .child(format!("{}/{}", checked, total)),
+                    ),
+            )
+            .when(self.searchable, |this| {
+                this.child(div().p_2().pb_0().child(self.input(side).clone()))
+            })
+            .child(
+                v_flex()
+                    .id("items")
+                    .h(rems(16.))
+                    .p_1()
+                    .overflow_y_scroll()
+                    .when(items.is_empty(), |this| {
+                        this.child(
+                            h_flex()
+                                .size_full()
+                                .justify_center()
+                                .text_sm()
+                                .text_color(cx.theme().muted_foreground)
+                                .child(t!("Transfer.No data").to_string()),
+                        )
+                    })
+                    .children(items.into_iter().map(|(ix, item)| {
+                        let disabled = item.disabled();
+                        let value = item.value().clone();
+
+                        h_flex()
+                            .id(("item", ix))
+                            .gap_2()
+                            .px_2()
+                            .py_1()
+                            .rounded(px(cx.theme().radius))
+                            .text_sm()
+                            .when(disabled, |this| {
+                                this.cursor_not_allowed()
+                                    .text_color(cx.theme().muted_foreground)
+                            })
+                            .when(!disabled, |this| {
+                                this.cursor_pointer()
+                                    .hover(|this| this.bg(cx.theme().list_hover))
+                                    .on_click(cx.listener(move |this, _, cx| {
+                                        this.toggle_checked(&value, cx)
+                                    }))
+                            })
+                            .child(
+                                Checkbox::new(("item-check", ix))
+                                    .checked(self.is_checked(item))
+                                    .disabled(disabled),
+                            )
+                            .child(div().whitespace_nowrap().child(item.title()))
+                    })),
+            )
+    }
+}
+
+impl<T> Render for Transfer<T>
+where
+    T: DropdownItem + Clone + 'static,
+    T::Value: PartialEq,
+{
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let can_select = !self.checked_on(Side::Available, cx).is_empty();
+        let can_unselect = !self.checked_on(Side::Selected, cx).is_empty();
+
+        h_flex()
+            .id(self.id.clone())
+            .w_full()
+            .gap_3()
+            .child(self.render_list(Side::Available, cx))
+            .child(
+                v_flex()
+                    .gap_2()
+                    .child(
+                        Button::new("to-selected")
+                            .icon(IconName::ChevronRight)
+                            .small()
+                            .disabled(!can_select)
+                            .on_click(cx.listener(|this, _, cx| this.move_to(Side::Selected, cx))),
+                    )
+                    .child(
+                        Button::new("to-available")
+                            .icon(IconName::ChevronLeft)
+                            .small()
+                            .disabled(!can_unselect)
+                            .on_click(cx.listener(|this, _, cx| this.move_to(Side::Available, cx))),
+                    ),
+            )
+            .child(self.render_list(Side::Selected, cx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::transfer;
+
+    #[test]
+    fn test_transfer() {
+        let all = [1, 2, 3, 4];
+        assert_eq!(transfer(&all, &[2], &[4, 1], true), vec![1, 2, 4]);
+        assert_eq!(transfer(&all, &[1, 2, 4], &[2], false), vec![1, 4]);
+        assert_eq!(transfer(&all, &[], &[5], true), Vec::<i32>::new());
+    }
+}
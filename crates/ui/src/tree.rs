@@ -0,0 +1,539 @@
+use std::{cell::Cell, rc::Rc};
+
+use anyhow::Result;
+use gpui::{
+    actions, div, prelude::FluentBuilder as _, px, uniform_list, AppContext, Entity as _,
+    EventEmitter, FocusHandle, FocusableView, InteractiveElement as _, IntoElement, KeyBinding,
+    MouseButton, ParentElement as _, Pixels, Render, SharedString, StatefulInteractiveElement as _,
+    Styled, Task, UniformListScrollHandle, ViewContext,
+};
+
+use crate::{
+    h_flex,
+    indicator::Indicator,
+    scroll::{Scrollbar, ScrollbarState},
+    theme::ActiveTheme as _,
+    v_flex, Icon, IconName, Sizable as _,
+};
+
+actions!(tree, [SelectPrev, SelectNext, Expand, Collapse, Confirm]);
+
+pub fn init(cx: &mut AppContext) {
+    let context = Some("TreeView");
+    cx.bind_keys([
+        KeyBinding::new("up", SelectPrev, context),
+        KeyBinding::new("down", SelectNext, context),
+        KeyBinding::new("right", Expand, context),
+        KeyBinding::new("left", Collapse, context),
+        KeyBinding::new("enter", Confirm, context),
+    ]);
+}
+
+/// The width of each indentation level.
+const INDENT_WIDTH: Pixels = px(16.);
+
+/// A node of the TreeView.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TreeNode {
+    pub id: SharedString,
+    pub label: SharedString,
+    pub icon: Option<IconName>,
+    /// If true, the node can be expanded, and its children will be loaded by [`TreeDelegate::load_children`].
+    pub expandable: bool,
+}
+
+impl TreeNode {
+    pub fn new(id: impl Into<SharedString>, label: impl Into<SharedString>) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into(),
+            icon: None,
+            expandable: false,
+        }
+    }
+
+    pub fn icon(mut self, icon: IconName) -> Self {
+        self.icon = Some(icon);
+        self
+    }
+
+    pub fn expandable(mut self, expandable: bool) -> Self {
+        self.expandable = expandable;
+        self
+    }
+}
+
+/// A delegate for the TreeView.
+#[allow(unused)]
+pub trait TreeDelegate: Sized + 'static {
+    /// Load the children of the node, or the root nodes if the `node` is None.
+    ///
+    /// This is called the first time a node is expanded, a loading indicator is shown on the node until the task is done.
+    fn load_children(
+        &mut self,
+        node: Option<&TreeNode>,
+        cx: &mut ViewContext<TreeView<Self>>,
+    ) -> Task<Result<Vec<TreeNode>>>;
+
+    /// Called when the node is confirmed, e.g.: double clicked or pressed Enter.
+    fn confirm(&mut self, node: &TreeNode, cx: &mut ViewContext<TreeView<Self>>) {}
+}
+
+/// Events emitted by the TreeView.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TreeEvent {
+    /// The node has been selected.
+    Select(TreeNode),
+    /// The node has been confirmed, e.g.: double clicked or pressed Enter.
+    Confirm(TreeNode),
+}
+
+struct TreeEntry {
+    node: TreeNode,
+    depth: usize,
+    parent: Option<usize>,
+    /// The indices of the children in the entries, None if not loaded yet.
+    children: Option<Vec<usize>>,
+    expanded: bool,
+    loading: bool,
+}
+
+/// A tree view with lazy loaded children, e.g.: a file explorer.
+pub struct TreeView<D: TreeDelegate> {
+    focus_handle: FocusHandle,
+    delegate: D,
+    entries: Vec<TreeEntry>,
+    roots: Option<Vec<usize>>,
+    /// The indices of the visible entries in the display order.
+    visible_entries: Vec<usize>,
+    selected_entry: Option<usize>,
+    vertical_scroll_handle: UniformListScrollHandle,
+    scrollbar_state: Rc<Cell<ScrollbarState>>,
+}
+
+impl<D> TreeView<D>
+where
+    D: TreeDelegate,
+{
+    pub fn new(delegate: D, cx: &mut ViewContext<Self>) -> Self {
+        let mut this = Self {
+            focus_handle: cx.focus_handle(),
+            delegate,
+            entries: vec![],
+            roots: None,
+            visible_entries: vec![],
+            selected_entry: None,
+            vertical_scroll_handle: UniformListScrollHandle::new(),
+            scrollbar_state: Rc::new(Cell::new(ScrollbarState::new())),
+        };
+        this.reload(cx);
+        this
+    }
+
+    pub fn delegate(&self) -> &D {
+        &self.delegate
+    }
+
+    pub fn delegate_mut(&mut self) -> &mut D {
+        &mut self.delegate
+    }
+
+    /// Clear all nodes and load the root nodes again.
+    pub fn reload(&mut self, cx: &mut ViewContext<Self>) {
+        self.entries.clear();
+        self.roots = None;
+        self.selected_entry = None;
+        self.update_visible_entries();
+
+        let task = self.delegate.load_children(None, cx);
+        cx.spawn(|this, mut cx| async move {
+            let result = task.await;
+            this.update(&mut cx, |this, cx| this.insert_children(None, result, cx))
+        })
+        .detach();
+        cx.notify();
+    }
+
+    /// Return the selected node.
+    pub fn selected_node(&self) -> Option<&TreeNode> {
+        self.selected_entry.map(|ix| &self.entries[ix].node)
+    }
+
+    fn insert_children(
+        &mut self,
+        parent: Option<usize>,
+        result: Result<Vec<TreeNode>>,
+        cx: &mut ViewContext<Self>,
+    ) {
+        let depth = parent.map_or(0, |ix| self.entries[ix].depth + 1);
+        let children = result.ok().map(|nodes| {
+            nodes
+                .into_iter()
+                .map(|node| {
+                    self.entries.push(TreeEntry {
+                        node,
+                        depth,
+                        parent,
+                        children: None,
+                        expanded: false,
+                        loading: false,
+                    });
+                    self.entries.len() - 1
+                })
+                .collect::<Vec<_>>()
+        });
+
+        match parent {
+            Some(ix) => {
+                let entry = &mut self.entries[ix];
+                entry.loading = false;
+                // Collapse the node if the loading failed, it will be loaded again on the next expanding.
+                if children.is_none() {
+                    entry.expanded = false;
+                }
+                entry.children = children;
+            }
+            None => self.roots = Some(children.unwrap_or_default()),
+        }
+
+        self.update_visible_entries();
+        cx.notify();
+    }
+
+    fn update_visible_entries(&mut self) {
+        self.visible_entries = match &self.roots {
+            Some(roots) => visible_entries(&self.entries, roots),
+            None => vec![],
+        };
+    }
+
+    fn set_expanded(&mut self, entry_ix: usize, expanded: bool, cx: &mut ViewContext<Self>) {
+        let entry = &mut self.entries[entry_ix];
+        if !entry.node.expandable || entry.expanded == expanded {
+            return;
+        }
+
+        entry.expanded = expanded;
+        if expanded && entry.children.is_none() && !entry.loading {
+            entry.loading = true;
+            let node_id = entry.node.id.clone();
+            let task = self.delegate.load_children(Some(&entry.node), cx);
+            cx.spawn(|this, mut cx| async move {
+                let result = task.await;
+                this.update(&mut cx, |this, cx| {
+                    // Ignore the result if the tree has been reloaded.
+                    if this.entries.get(entry_ix).map(|entry| &entry.node.id) != Some(&node_id) {
+                        return;
+                    }
+                    this.insert_children(Some(entry_ix), result, cx)
+                })
+            })
+            .detach();
+        }
+
+        // Move the selection to the collapsed node if it was in the collapsed children.
+        if !expanded {
+            if let Some(selected_ix) = self.selected_entry {
+                if self.is_descendant(selected_ix, entry_ix) {
+                    self.selected_entry = Some(entry_ix);
+                }
+            }
+        }
+
+        self.update_visible_entries();
+        cx.notify();
+    }
+
+    fn toggle_expanded(&mut self, entry_ix: usize, cx: &mut ViewContext<Self>) {
+        let expanded = self.entries[entry_ix].expanded;
+        self.set_expanded(entry_ix, !expanded, cx);
+    }
+
+    fn is_descendant(&self, entry_ix: usize, ancestor_ix: usize) -> bool {
+        let mut parent = self.entries[entry_ix].parent;
+        while let Some(ix) = parent {
+            if ix == ancestor_ix {
+                return true;
+            }
+            parent = self.entries[ix].parent;
+        }
+        false
+    }
+
+    fn select_entry(&mut self, entry_ix: usize, cx: &mut ViewContext<Self>) {
+        self.selected_entry = Some(entry_ix);
+        if let Some(visible_ix) = self.visible_entries.iter().position(|ix| *ix == entry_ix) {
+            self.vertical_scroll_handle.scroll_to_item(visible_ix);
+        }
+        cx.emit(TreeEvent::Select(self.entries[entry_ix].node.clone()));
+        cx.notify();
+    }
+
+    fn selected_visible_ix(&self) -> Option<usize> {
+        let selected_ix = self.selected_entry?;
+        self.visible_entries
+            .iter()
+            .position(|ix| *ix == selected_ix)
+    }
+
+    fn on_action_select_prev(&mut self, _: &SelectPrev, cx: &mut ViewContext<Self>) {
+        let visible_ix = match self.selected_visible_ix() {
+            Some(ix) => ix.saturating_sub(1),
+            None => 0,
+        };
+        if let Some(entry_ix) = self.visible_entries.get(visible_ix).copied() {
+            self.select_entry(entry_ix, cx);
+        }
+    }
+
+    fn on_action_select_next(&mut self, _: &SelectNext, cx: &mut ViewContext<Self>) {
+        let visible_ix = match self.selected_visible_ix() {
+            Some(ix) => (ix + 1).min(self.visible_entries.len().saturating_sub(1)),
+            None => 0,
+        };
+        if let Some(entry_ix) = self.visible_entries.get(visible_ix).copied() {
+            self.select_entry(entry_ix, cx);
+        }
+    }
+
+    /// Expand the selected node, or select its first child if it's already expanded.
+    fn on_action_expand(&mut self, _: &Expand, cx: &mut ViewContext<Self>) {
+        let Some(entry_ix) = self.selected_entry else {
+            return;
+        };
+
+        let entry = &self.entries[entry_ix];
+        if !entry.expanded {
+            self.set_expanded(entry_ix, true, cx);
+        } else if let Some(child_ix) = entry.children.as_ref().and_then(|c| c.first()).copied() {
+            self.select_entry(child_ix, cx);
+        }
+    }
+
+    /// Collapse the selected node, or select its parent if it's already collapsed.
+    fn on_action_collapse(&mut self, _: &Collapse, cx: &mut ViewContext<Self>) {
+        let Some(entry_ix) = self.selected_entry else {
+            return;
+        };
+
+        let entry = &self.entries[entry_ix];
+        if entry.expanded {
+            self.set_expanded(entry_ix, false, cx);
+        } else if let Some(parent_ix) = entry.parent {
+            self.select_entry(parent_ix, cx);
+        }
+    }
+
+    fn on_action_confirm(&mut self, _: &Confirm, cx: &mut ViewContext<Self>) {
+        if let Some(entry_ix) = self.selected_entry {
+            self.confirm_entry(entry_ix, cx);
+        }
+    }
+
+    fn confirm_entry(&mut self, entry_ix: usize, cx: &mut ViewContext<Self>) {
+        let node = self.entries[entry_ix].node.clone();
+        if node.expandable {
+            self.toggle_expanded(entry_ix, cx);
+        }
+        self.delegate.confirm(&node, cx);
+        cx.emit(TreeEvent::Confirm(node));
+    }
+
+    fn render_entry(&self, entry_ix: usize, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let entry = &self.entries[entry_ix];
+        let selected = self.selected_entry == Some(entry_ix);
+        let hover_bg = cx.theme().list_hover;
+        let toggle_icon = if entry.expanded {
+            IconName::ChevronDown
+        } else {
+            IconName::ChevronRight
+        };
+
+        h_flex()
+            .id(("tree-entry", entry_ix))
+            .relative()
+            .w_full()
+            .h_7()
+            .pr_2()
+            .pl(INDENT_WIDTH * entry.depth as f32)
+            .gap_1()
+            .text_sm()
+            .when(selected, |this| this.bg(cx.theme().list_active))
+            .when(!selected, |this| this.hover(|this| this.bg(hover_bg)))
+            // Indentation guides
+            .children((0..entry.depth).map(|depth| {
+                div()
+                    .absolute()
+                    .top_0()
+                    .bottom_0()
+                    .left(INDENT_WIDTH * depth as f32 + INDENT_WIDTH / 2.)
+                    .w(px(1.))
+                    .bg(cx.theme().border)
+            }))
+            .child(
+                div()
+                    .id("toggle")
+                    .flex_none()
+                    .size(INDENT_WIDTH)
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .when(entry.loading, |this| this.child(Indicator::new().xsmall()))
+                    .when(!entry.loading && entry.node.expandable, |this| {
+                        this.child(
+                            Icon::new(toggle_icon)
+                                .xsmall()
+                                .text_color(cx.theme().muted_foreground),
+                        )
+                        .on_mouse_down(
+                            MouseButton::Left,
+                            cx.listener(move |this, _, cx| {
+                                cx.stop_propagation();
+                                this.toggle_expanded(entry_ix, cx);
+                            }),
+                        )
+                    }),
+            )
+            .when_some(entry.node.icon, |this, icon| {
+                this.child(
+                    Icon::new(icon)
+                        .small()
+                        .text_color(cx.theme().muted_foreground),
+                )
+            })
+            .child(
+                div()
+                    .flex_1()
+                    .overflow_hidden()
+                    .whitespace_nowrap()
+                    .child(entry.node.label.clone()),
+            )
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(move |this, e: &gpui::MouseDownEvent, cx| {
+                    this.focus_handle.focus(cx);
+                    this.select_entry(entry_ix, cx);
+                    if e.click_count == 2 {
+                        this.confirm_entry(entry_ix, cx);
+                    }
+                }),
+            )
+    }
+}
+
+/// Return the entries in display order, the children of the collapsed entries are skipped.
+fn visible_entries(entries: &[TreeEntry], roots: &[usize]) -> Vec<usize> {
+    let mut result = vec![];
+    let mut stack = roots.iter().rev().copied().collect::<Vec<_>>();
+    while let Some(ix) = stack.pop() {
+        result.push(ix);
+        let entry = &entries[ix];
+        if entry.expanded {
+            if let Some(children) = &entry.children {
+                stack.extend(children.iter().rev().copied());
+            }
+        }
+    }
+    result
+}
+
+impl<D> FocusableView for TreeView<D>
+where
+    D: TreeDelegate,
+{
+    fn focus_handle(&self, _: &AppContext) -> FocusHandle {
+        self.focus_handle.clone()
+    }
+}
+
+impl<D> EventEmitter<TreeEvent> for TreeView<D> where D: TreeDelegate {}
+
+impl<D> Render for TreeView<D>
+where
+    D: TreeDelegate,
+{
+    fn render(&mut self, cx: &mut ViewContext<Self>) -> impl IntoElement {
+        let view = cx.view().clone();
+        let items_count = self.visible_entries.len();
+
+        v_flex()
+            .key_context("TreeView")
+            .id("tree-view")
+            .track_focus(&self.focus_handle)
+            .size_full()
+            .relative()
+            .overflow_hidden()
+            .on_action(cx.listener(Self::on_action_select_prev))
+            .on_action(cx.listener(Self::on_action_select_next))
+            .on_action(cx.listener(Self::on_action_expand))
+            .on_action(cx.listener(Self::on_action_collapse))
+            .on_action(cx.listener(Self::on_action_confirm))
+            .when(self.roots.is_none(), |this| {
+                this.child(
+                    h_flex()
+                        .p_2()
+                        .justify_center()
+                        .child(Indicator::new().small()),
+                )
+            })
+            .child(
+                uniform_list(view, "tree-entries", items_count, {
+                    move |tree, visible_range, cx| {
+                        visible_range
+                            .map(|ix| tree.render_entry(tree.visible_entries[ix], cx))
+                            .collect::<Vec<_>>()
+                    }
+                })
+                .flex_grow()
+                .track_scroll(self.vertical_scroll_handle.clone()),
+            )
+            .child(Scrollbar::uniform_scroll(
+                cx.view().entity_id(),
+                self.scrollbar_state.clone(),
+                self.vertical_scroll_handle.clone(),
+                items_count,
+            ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(parent: Option<usize>, children: Option<Vec<usize>>, expanded: bool) -> TreeEntry {
+        TreeEntry {
+            node: TreeNode::new("", ""),
+            depth: 0,
+            parent,
+            children,
+            expanded,
+            loading: false,
+        }
+    }
+
+    #[test]
+    fn test_visible_entries() {
+        // 0
+        // ├─ 2
+        // │  └─ 4
+        // └─ 3
+        // 1
+        // └─ 5
+        let mut entries = vec![
+            entry(None, Some(vec![2, 3]), true),
+            entry(None, Some(vec![5]), false),
+            entry(Some(0), Some(vec![4]), true),
+            entry(Some(0), None, false),
+            entry(Some(2), None, false),
+            entry(Some(1), None, false),
+        ];
+        assert_eq!(visible_entries(&entries, &[0, 1]), vec![0, 2, 4, 3, 1]);
+
+        entries[1].expanded = true;
+        entries[2].expanded = false;
+        assert_eq!(visible_entries(&entries, &[0, 1]), vec![0, 2, 3, 1, 5]);
+
+        entries[0].expanded = false;
+        assert_eq!(visible_entries(&entries, &[0, 1]), vec![0, 1, 5]);
+    }
+}